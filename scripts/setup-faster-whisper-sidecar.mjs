@@ -3,6 +3,7 @@ import { promises as fs } from "node:fs";
 import path from "node:path";
 import process from "node:process";
 import { spawnSync } from "node:child_process";
+import { createHash } from "node:crypto";
 import { fileURLToPath } from "node:url";
 
 const __dirname = path.dirname(fileURLToPath(import.meta.url));
@@ -282,11 +283,17 @@ function smokeTestWorkerExecutable() {
   process.stdout.write("faster-whisper worker smoke test passed.\n");
 }
 
+async function sha256File(filePath) {
+  const contents = await fs.readFile(filePath);
+  return createHash("sha256").update(contents).digest("hex");
+}
+
 async function writeMetadata() {
   const metadataPath = path.join(outputDir, metadataFileName);
   const payload = {
     engine: "faster_whisper",
     executable: executableName,
+    sha256: await sha256File(path.join(outputDir, executableName)),
     generated_at: new Date().toISOString(),
     platform,
   };
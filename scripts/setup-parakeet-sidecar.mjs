@@ -3,6 +3,7 @@ import { promises as fs } from "node:fs";
 import path from "node:path";
 import process from "node:process";
 import { spawnSync } from "node:child_process";
+import { createHash } from "node:crypto";
 import { fileURLToPath } from "node:url";
 
 const __dirname = path.dirname(fileURLToPath(import.meta.url));
@@ -288,6 +289,7 @@ async function installDependencies(venvPython, backend, torchCudaChannel) {
     "scipy>=1.11",
     "sentencepiece",
     "safetensors",
+    "optimum[onnxruntime]>=1.21",
     "pyinstaller>=6.15,<7",
   ]);
 
@@ -346,6 +348,10 @@ async function buildWorkerBinary(venvPython) {
     "--collect-all",
     "tokenizers",
     "--collect-all",
+    "optimum",
+    "--collect-all",
+    "onnxruntime",
+    "--collect-all",
     "torch",
     "--collect-all",
     "numpy",
@@ -473,6 +479,11 @@ function smokeTestWorkerExecutable(executablePath, expectedBackend) {
   };
 }
 
+async function sha256File(filePath) {
+  const contents = await fs.readFile(filePath);
+  return createHash("sha256").update(contents).digest("hex");
+}
+
 async function writeMetadata(metadata) {
   const metadataPath = path.join(outputDir, metadataFileName);
   const payload = {
@@ -534,6 +545,7 @@ async function main() {
   await writeMetadata({
     mode: options.devLink ? "dev_link" : "bundled",
     runtime_path: runtimePath,
+    sha256: await sha256File(runtimePath),
     requested_backend: options.requestedBackend,
     resolved_backend: resolvedBackend,
     torch_cuda_channel: resolvedBackend === "cuda" ? torchCudaChannel : null,
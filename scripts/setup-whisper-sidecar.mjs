@@ -3,6 +3,7 @@ import { promises as fs } from "node:fs";
 import path from "node:path";
 import process from "node:process";
 import { spawnSync } from "node:child_process";
+import { createHash } from "node:crypto";
 import { fileURLToPath } from "node:url";
 
 const REPO_URL = "https://github.com/ggml-org/whisper.cpp.git";
@@ -351,12 +352,19 @@ async function copyRuntimeLibraries() {
   }
 }
 
+async function sha256File(filePath) {
+  const contents = await fs.readFile(filePath);
+  return createHash("sha256").update(contents).digest("hex");
+}
+
 async function writeBackendMetadata(backend) {
   await ensureDir(outputDir);
   const destination = path.join(outputDir, metadataFileName);
   const payload = {
     backend,
+    backends: backend === "cuda" ? ["cuda", "cpu"] : ["cpu"],
     platform,
+    sha256: await sha256File(path.join(outputDir, executableName)),
     generated_at: new Date().toISOString(),
   };
   await fs.writeFile(destination, `${JSON.stringify(payload, null, 2)}\n`, "utf8");
@@ -2,6 +2,7 @@
 # pyright: reportMissingImports=false
 
 import json
+import math
 import os
 import sys
 import tempfile
@@ -57,6 +58,7 @@ def handle_transcribe(runtime: ModelRuntime, request: dict):
     compute_type = str(request.get("compute_type", "int8")).strip() or "int8"
     language = str(request.get("language", "en")).strip() or "en"
     beam_size = int(request.get("beam_size", 1))
+    task = "translate" if str(request.get("task", "")).strip() == "translate" else "transcribe"
     condition_on_previous_text = bool(request.get("condition_on_previous_text", True))
     initial_prompt = request.get("initial_prompt", None)
     if initial_prompt is not None:
@@ -67,16 +69,27 @@ def handle_transcribe(runtime: ModelRuntime, request: dict):
     segments, _info = model.transcribe(
         audio_path,
         language=language,
+        task=task,
         beam_size=beam_size,
         condition_on_previous_text=condition_on_previous_text,
         initial_prompt=initial_prompt,
         vad_filter=False,
     )
     pieces = []
+    log_probs = []
+    segment_details = []
     for segment in segments:
         text = (segment.text or "").strip()
         if text:
             pieces.append(text)
+            log_probs.append(float(segment.avg_logprob))
+            segment_details.append(
+                {
+                    "text": text,
+                    "no_speech_prob": float(segment.no_speech_prob),
+                    "avg_logprob": float(segment.avg_logprob),
+                }
+            )
 
     duration_ms = int((perf_counter() - started_at) * 1000)
     write_response(
@@ -85,6 +98,8 @@ def handle_transcribe(runtime: ModelRuntime, request: dict):
             "ok": True,
             "text": " ".join(pieces).strip(),
             "inference_ms": duration_ms,
+            "confidence": math.exp(sum(log_probs) / len(log_probs)) if log_probs else None,
+            "segments": segment_details,
         }
     )
 
@@ -9,7 +9,19 @@ from time import perf_counter
 
 import numpy as np
 import torch
-from transformers import AutoModelForCTC, AutoProcessor
+from transformers import (
+    AutoModelForCTC,
+    AutoModelForTokenClassification,
+    AutoProcessor,
+    AutoTokenizer,
+)
+
+try:
+    from optimum.onnxruntime import ORTModelForTokenClassification
+except Exception:
+    ORTModelForTokenClassification = None
+
+PUNCTUATION_MARKS = {".", ",", "?", "-", ":"}
 
 
 def write_response(payload: dict):
@@ -95,6 +107,41 @@ class ModelRuntime:
         return processor, model
 
 
+class PunctuationRuntime:
+    def __init__(self):
+        self._key = None
+        self._tokenizer = None
+        self._model = None
+        self.backend = "none"
+
+    def get_model_bundle(self, model_name: str, device: str):
+        key = (model_name, device)
+        if self._model is not None and self._tokenizer is not None and self._key == key:
+            return self._tokenizer, self._model
+
+        cache_root = os.environ.get("SONORA_PARAKEET_MODEL_CACHE", "").strip() or None
+        tokenizer = AutoTokenizer.from_pretrained(model_name, cache_dir=cache_root)
+        model = None
+        if ORTModelForTokenClassification is not None and device == "cpu":
+            try:
+                model = ORTModelForTokenClassification.from_pretrained(
+                    model_name, cache_dir=cache_root, export=True
+                )
+                self.backend = "onnxruntime"
+            except Exception:
+                model = None
+        if model is None:
+            model = AutoModelForTokenClassification.from_pretrained(model_name, cache_dir=cache_root)
+            model.to(device)
+            model.eval()
+            self.backend = "torch"
+
+        self._tokenizer = tokenizer
+        self._model = model
+        self._key = key
+        return tokenizer, model
+
+
 def handle_ping(request: dict):
     request_id = str(request.get("id", ""))
     write_response(
@@ -195,8 +242,56 @@ def handle_transcribe(runtime: ModelRuntime, request: dict):
     )
 
 
+def handle_punctuate(punctuation: PunctuationRuntime, request: dict):
+    request_id = str(request.get("id", ""))
+    words = str(request.get("text", "")).split()
+    if not words:
+        write_response({"id": request_id, "ok": True, "text": ""})
+        return
+
+    model_name = str(request.get("model", "")).strip()
+    if not model_name:
+        write_response({"id": request_id, "ok": False, "error": "missing punctuation model"})
+        return
+    device = normalize_device(str(request.get("device", "cpu")))
+
+    started_at = perf_counter()
+    tokenizer, model = punctuation.get_model_bundle(model_name, device)
+    encoded = tokenizer(words, is_split_into_words=True, truncation=True, return_tensors="pt")
+    word_ids = encoded.word_ids()
+    if punctuation.backend == "torch":
+        encoded = {name: value.to(device) for name, value in encoded.items()}
+
+    with torch.inference_mode():
+        logits = model(**encoded).logits
+    predictions = torch.as_tensor(logits).argmax(dim=-1)[0].tolist()
+
+    # The label on a word's last sub-token is the mark that follows that word.
+    marks = [""] * len(words)
+    for token_index, word_index in enumerate(word_ids):
+        if word_index is None:
+            continue
+        label = str(model.config.id2label.get(predictions[token_index], "0"))
+        marks[word_index] = label if label in PUNCTUATION_MARKS else ""
+
+    text = " ".join(word + mark for word, mark in zip(words, marks))
+    if text and text[-1] not in ".?!":
+        text = text.rstrip(",:-") + "."
+
+    write_response(
+        {
+            "id": request_id,
+            "ok": True,
+            "text": text,
+            "backend": punctuation.backend,
+            "inference_ms": int((perf_counter() - started_at) * 1000),
+        }
+    )
+
+
 def main():
     runtime = ModelRuntime()
+    punctuation = PunctuationRuntime()
     for raw in sys.stdin:
         line = raw.strip()
         if not line:
@@ -217,6 +312,8 @@ def main():
                 handle_preload(runtime, request)
             elif op == "transcribe":
                 handle_transcribe(runtime, request)
+            elif op == "punctuate":
+                handle_punctuate(punctuation, request)
             else:
                 write_response({"id": request_id, "ok": False, "error": f"unsupported op: {op}"})
         except Exception as error:
@@ -0,0 +1,260 @@
+use serde::Serialize;
+
+pub const DEEP_LINK_SCHEME: &str = "sonora";
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ActivationAction {
+    ToggleDictation,
+    StartDictation,
+    StopDictation,
+    Show,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct ActivationPayload {
+    pub action: ActivationAction,
+}
+
+// Quick actions on a transcript held in the review queue, sent back from a
+// notification button.
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ReviewAction {
+    Insert,
+    Copy,
+    Discard,
+}
+
+impl ReviewAction {
+    pub const ALL: [ReviewAction; 3] = [
+        ReviewAction::Insert,
+        ReviewAction::Copy,
+        ReviewAction::Discard,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ReviewAction::Insert => "insert",
+            ReviewAction::Copy => "copy",
+            ReviewAction::Discard => "discard",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct ReviewResolvedPayload {
+    pub item_id: u64,
+    pub action: ReviewAction,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExternalCommand {
+    Activate(ActivationAction),
+    Insert(String),
+    Review { item_id: u64, action: ReviewAction },
+}
+
+// Any web page can open a sonora:// link, so links only carry review actions
+// that quote this per-launch secret. It reaches Windows through the toast XML
+// and nowhere else; empty when the OS has no randomness, which disables them.
+pub fn new_review_token() -> String {
+    let mut bytes = [0u8; 16];
+    match getrandom::fill(&mut bytes) {
+        Ok(()) => bytes.iter().map(|byte| format!("{byte:02x}")).collect(),
+        Err(_) => String::new(),
+    }
+}
+
+pub fn review_action_url(item_id: u64, action: ReviewAction, token: &str) -> String {
+    format!(
+        "{DEEP_LINK_SCHEME}://review/{}?id={item_id}&token={token}",
+        action.as_str()
+    )
+}
+
+// Links from outside the app may only toggle dictation or show the window;
+// starting, stopping and inserting text stay with the command line.
+pub fn parse_deep_link(url: &str, review_token: &str) -> Option<ExternalCommand> {
+    let prefix = format!("{DEEP_LINK_SCHEME}://");
+    let rest = url
+        .get(..prefix.len())
+        .filter(|scheme| scheme.eq_ignore_ascii_case(&prefix))
+        .map(|_| &url[prefix.len()..])?;
+    let (path, query) = rest.split_once('?').unwrap_or((rest, ""));
+    let action = path.trim_matches('/').to_ascii_lowercase();
+
+    if let Some(review) = action.strip_prefix("review/") {
+        let action = ReviewAction::ALL
+            .into_iter()
+            .find(|candidate| candidate.as_str() == review)?;
+        let item_id = query_value(query, "id")?.parse().ok()?;
+        query_value(query, "token")
+            .filter(|token| !review_token.is_empty() && token == review_token)?;
+        return Some(ExternalCommand::Review { item_id, action });
+    }
+
+    match named_action(&action)? {
+        action @ (ActivationAction::ToggleDictation | ActivationAction::Show) => {
+            Some(ExternalCommand::Activate(action))
+        }
+        ActivationAction::StartDictation | ActivationAction::StopDictation => None,
+    }
+}
+
+// A URL argument is how the OS hands a deep link to a new instance, so it gets
+// the same limits as one.
+pub fn parse_cli_args<S: AsRef<str>>(argv: &[S], review_token: &str) -> Option<ExternalCommand> {
+    let mut args = argv.iter().skip(1).map(AsRef::as_ref);
+    while let Some(arg) = args.next() {
+        if arg.contains("://") {
+            return parse_deep_link(arg, review_token);
+        }
+        if let Some(text) = arg.strip_prefix("--insert=") {
+            return non_empty_insert(text);
+        }
+        if arg == "--insert" {
+            return args.next().and_then(non_empty_insert);
+        }
+        if let Some(action) = arg.strip_prefix("--").and_then(named_action) {
+            return Some(ExternalCommand::Activate(action));
+        }
+    }
+    None
+}
+
+pub fn relaunch_command<S: AsRef<str>>(argv: &[S], review_token: &str) -> ExternalCommand {
+    parse_cli_args(argv, review_token)
+        .unwrap_or(ExternalCommand::Activate(ActivationAction::ToggleDictation))
+}
+
+fn named_action(name: &str) -> Option<ActivationAction> {
+    match name {
+        "toggle" => Some(ActivationAction::ToggleDictation),
+        "start" => Some(ActivationAction::StartDictation),
+        "stop" => Some(ActivationAction::StopDictation),
+        "show" | "focus" => Some(ActivationAction::Show),
+        _ => None,
+    }
+}
+
+fn non_empty_insert(text: &str) -> Option<ExternalCommand> {
+    (!text.trim().is_empty()).then(|| ExternalCommand::Insert(text.to_string()))
+}
+
+fn query_value(query: &str, key: &str) -> Option<String> {
+    query.split('&').find_map(|pair| {
+        let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+        (percent_decode(name) == key).then(|| percent_decode(value))
+    })
+}
+
+fn percent_decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        let escaped = (bytes[index] == b'%' && index + 2 < bytes.len())
+            .then(|| Some((hex_value(bytes[index + 1])? << 4) | hex_value(bytes[index + 2])?))
+            .flatten();
+        match (bytes[index], escaped) {
+            (_, Some(byte)) => {
+                decoded.push(byte);
+                index += 2;
+            }
+            (b'+', None) => decoded.push(b' '),
+            (byte, None) => decoded.push(byte),
+        }
+        index += 1;
+    }
+    String::from_utf8_lossy(&decoded).to_string()
+}
+
+fn hex_value(byte: u8) -> Option<u8> {
+    (byte as char).to_digit(16).map(|digit| digit as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deep_links_only_toggle_show_or_carry_a_review_token() {
+        assert_eq!(
+            parse_deep_link("sonora://toggle", "secret"),
+            Some(ExternalCommand::Activate(ActivationAction::ToggleDictation))
+        );
+        assert_eq!(
+            parse_deep_link("SONORA://show/", "secret"),
+            Some(ExternalCommand::Activate(ActivationAction::Show))
+        );
+        assert_eq!(parse_deep_link("sonora://start", "secret"), None);
+        assert_eq!(parse_deep_link("sonora://stop", "secret"), None);
+        assert_eq!(
+            parse_deep_link("sonora://insert?text=Hello%2C+world", "secret"),
+            None
+        );
+        assert_eq!(
+            parse_deep_link(
+                &review_action_url(7, ReviewAction::Copy, "secret"),
+                "secret"
+            ),
+            Some(ExternalCommand::Review {
+                item_id: 7,
+                action: ReviewAction::Copy
+            })
+        );
+        assert_eq!(
+            parse_deep_link("sonora://review/insert?id=7", "secret"),
+            None
+        );
+        assert_eq!(
+            parse_deep_link(
+                &review_action_url(7, ReviewAction::Insert, "guess"),
+                "secret"
+            ),
+            None
+        );
+        assert_eq!(
+            parse_deep_link(&review_action_url(7, ReviewAction::Insert, ""), ""),
+            None
+        );
+        assert_eq!(
+            parse_deep_link("sonora://review/approve?id=7&token=secret", "secret"),
+            None
+        );
+        assert_eq!(parse_deep_link("sonora://unknown", "secret"), None);
+        assert_eq!(parse_deep_link("https://toggle", "secret"), None);
+        assert_eq!(new_review_token().len(), 32);
+        assert_ne!(new_review_token(), new_review_token());
+    }
+
+    #[test]
+    fn parses_cli_arguments_and_defaults_relaunch_to_toggle() {
+        assert_eq!(
+            parse_cli_args(&["sonora", "--start"], ""),
+            Some(ExternalCommand::Activate(ActivationAction::StartDictation))
+        );
+        assert_eq!(
+            parse_cli_args(&["sonora", "--insert", "signed, Alex"], ""),
+            Some(ExternalCommand::Insert("signed, Alex".to_string()))
+        );
+        assert_eq!(
+            parse_cli_args(&["sonora", "--insert=ok"], ""),
+            Some(ExternalCommand::Insert("ok".to_string()))
+        );
+        assert_eq!(
+            parse_cli_args(&["sonora", "sonora://show"], ""),
+            Some(ExternalCommand::Activate(ActivationAction::Show))
+        );
+        assert_eq!(
+            parse_cli_args(&["sonora", "sonora://insert?text=rm+-rf"], ""),
+            None
+        );
+        assert_eq!(parse_cli_args(&["--toggle"], ""), None);
+        assert_eq!(
+            relaunch_command(&["sonora", "sonora://start"], ""),
+            ExternalCommand::Activate(ActivationAction::ToggleDictation)
+        );
+    }
+}
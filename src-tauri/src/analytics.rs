@@ -0,0 +1,192 @@
+use serde::Serialize;
+
+const SAMPLE_RATE_HZ: u64 = 16_000;
+
+#[derive(Debug, Clone, Default, Serialize, PartialEq)]
+pub struct SessionStats {
+    pub started_unix_ms: Option<u64>,
+    pub utterance_count: u64,
+    pub word_count: u64,
+    pub speech_ms: u64,
+    pub pause_ms: u64,
+    pub words_per_minute: f32,
+    pub pause_ratio: f32,
+    pub average_utterance_words: f32,
+    pub latency: LatencyStats,
+    pub hallucinations_suppressed: u64,
+}
+
+// Milliseconds measured from the last speech sample of an utterance.
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+pub struct UtteranceLatency {
+    pub speech_end_unix_ms: u64,
+    pub transcript_ms: u64,
+    pub finalized_ms: u64,
+    pub inserted_ms: Option<u64>,
+}
+
+impl UtteranceLatency {
+    pub fn new(speech_end_unix_ms: u64, transcript_unix_ms: u64, finalized_unix_ms: u64) -> Self {
+        Self {
+            speech_end_unix_ms,
+            transcript_ms: transcript_unix_ms.saturating_sub(speech_end_unix_ms),
+            finalized_ms: finalized_unix_ms.saturating_sub(speech_end_unix_ms),
+            inserted_ms: None,
+        }
+    }
+
+    pub fn inserted_at(self, inserted_unix_ms: u64) -> Self {
+        Self {
+            inserted_ms: Some(inserted_unix_ms.saturating_sub(self.speech_end_unix_ms)),
+            ..self
+        }
+    }
+
+    // What the user waited for: insertion when it happened, otherwise finalization.
+    pub fn end_to_end_ms(&self) -> u64 {
+        self.inserted_ms.unwrap_or(self.finalized_ms)
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, PartialEq)]
+pub struct LatencyStats {
+    pub utterances: u64,
+    pub avg_transcript_ms: f32,
+    pub avg_end_to_end_ms: f32,
+    pub max_end_to_end_ms: u64,
+    pub last: Option<UtteranceLatency>,
+}
+
+impl LatencyStats {
+    pub fn record(&mut self, latency: UtteranceLatency) {
+        let previous = self.utterances as f32;
+        self.utterances = self.utterances.saturating_add(1);
+        let count = self.utterances as f32;
+        self.avg_transcript_ms =
+            (self.avg_transcript_ms * previous + latency.transcript_ms as f32) / count;
+        self.avg_end_to_end_ms =
+            (self.avg_end_to_end_ms * previous + latency.end_to_end_ms() as f32) / count;
+        self.max_end_to_end_ms = self.max_end_to_end_ms.max(latency.end_to_end_ms());
+        self.last = Some(latency);
+    }
+}
+
+impl SessionStats {
+    pub fn started_at(now_unix_ms: u64) -> Self {
+        Self {
+            started_unix_ms: Some(now_unix_ms),
+            ..Self::default()
+        }
+    }
+
+    pub fn record_audio(&mut self, total_samples: usize, speech_samples: usize) {
+        let speech_samples = speech_samples.min(total_samples);
+        let pause_samples = total_samples - speech_samples;
+        self.speech_ms = self.speech_ms.saturating_add(samples_to_ms(speech_samples));
+        self.pause_ms = self.pause_ms.saturating_add(samples_to_ms(pause_samples));
+        self.refresh_derived();
+    }
+
+    pub fn record_utterance(&mut self, text: &str) {
+        let words = count_words(text);
+        if words == 0 {
+            return;
+        }
+
+        self.utterance_count = self.utterance_count.saturating_add(1);
+        self.word_count = self.word_count.saturating_add(words);
+        self.refresh_derived();
+    }
+
+    fn refresh_derived(&mut self) {
+        self.words_per_minute = if self.speech_ms > 0 {
+            self.word_count as f32 * 60_000.0 / self.speech_ms as f32
+        } else {
+            0.0
+        };
+
+        let listened_ms = self.speech_ms.saturating_add(self.pause_ms);
+        self.pause_ratio = if listened_ms > 0 {
+            self.pause_ms as f32 / listened_ms as f32
+        } else {
+            0.0
+        };
+
+        self.average_utterance_words = if self.utterance_count > 0 {
+            self.word_count as f32 / self.utterance_count as f32
+        } else {
+            0.0
+        };
+    }
+}
+
+pub fn count_words(text: &str) -> u64 {
+    text.split_whitespace()
+        .filter(|token| token.chars().any(char::is_alphanumeric))
+        .count() as u64
+}
+
+fn samples_to_ms(samples: usize) -> u64 {
+    (samples as u64).saturating_mul(1_000) / SAMPLE_RATE_HZ
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_words_ignoring_punctuation_tokens() {
+        assert_eq!(count_words("Hello, world - again."), 3);
+        assert_eq!(count_words("   "), 0);
+    }
+
+    #[test]
+    fn computes_words_per_minute_from_speech_time() {
+        let mut stats = SessionStats::started_at(1_000);
+        stats.record_audio(16_000 * 30, 16_000 * 30);
+        stats.record_utterance("one two three four five six seven eight nine ten");
+        stats.record_utterance("eleven twelve thirteen fourteen fifteen");
+
+        assert_eq!(stats.started_unix_ms, Some(1_000));
+        assert_eq!(stats.utterance_count, 2);
+        assert_eq!(stats.word_count, 15);
+        assert_eq!(stats.speech_ms, 30_000);
+        assert!((stats.words_per_minute - 30.0).abs() < f32::EPSILON);
+        assert!((stats.average_utterance_words - 7.5).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn pause_ratio_tracks_non_speech_audio() {
+        let mut stats = SessionStats::default();
+        stats.record_audio(32_000, 8_000);
+
+        assert_eq!(stats.speech_ms, 500);
+        assert_eq!(stats.pause_ms, 1_500);
+        assert!((stats.pause_ratio - 0.75).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn end_to_end_latency_includes_insertion() {
+        let mut stats = SessionStats::default();
+        stats
+            .latency
+            .record(UtteranceLatency::new(10_000, 10_400, 11_200).inserted_at(11_500));
+        stats
+            .latency
+            .record(UtteranceLatency::new(20_000, 20_200, 20_900));
+
+        assert_eq!(stats.latency.utterances, 2);
+        assert_eq!(stats.latency.last.and_then(|last| last.inserted_ms), None);
+        assert!((stats.latency.avg_transcript_ms - 300.0).abs() < f32::EPSILON);
+        assert!((stats.latency.avg_end_to_end_ms - 1_200.0).abs() < f32::EPSILON);
+        assert_eq!(stats.latency.max_end_to_end_ms, 1_500);
+    }
+
+    #[test]
+    fn empty_utterances_are_not_counted() {
+        let mut stats = SessionStats::default();
+        stats.record_utterance("  ...  ");
+        assert_eq!(stats.utterance_count, 0);
+        assert_eq!(stats.average_utterance_words, 0.0);
+    }
+}
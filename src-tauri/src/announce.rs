@@ -0,0 +1,172 @@
+use serde::Serialize;
+use std::process::{Command, Stdio};
+
+use crate::insertion::{InsertionRecord, InsertionStatus};
+use crate::pipeline::DictationState;
+
+// Long dictations are cut so the screen reader confirms them without reading a page.
+const MAX_ANNOUNCED_CHARS: usize = 160;
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Politeness {
+    Polite,
+    Assertive,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct Announcement {
+    pub text: String,
+    pub politeness: Politeness,
+    // True when a native speech channel already spoke it, so the webview live
+    // region stays quiet instead of announcing twice.
+    pub spoken_natively: bool,
+}
+
+impl Announcement {
+    fn new(text: impl Into<String>, politeness: Politeness) -> Self {
+        Self {
+            text: text.into(),
+            politeness,
+            spoken_natively: false,
+        }
+    }
+}
+
+pub fn state_change(previous: DictationState, current: DictationState) -> Option<Announcement> {
+    match (previous, current) {
+        (DictationState::Idle, DictationState::Listening) => Some(Announcement::new(
+            "Dictation started",
+            Politeness::Assertive,
+        )),
+        (DictationState::Listening, DictationState::Idle) => Some(Announcement::new(
+            "Dictation stopped",
+            Politeness::Assertive,
+        )),
+        (DictationState::Listening, DictationState::Transcribing) => Some(Announcement::new(
+            "Dictation stopped, transcribing",
+            Politeness::Assertive,
+        )),
+        _ => None,
+    }
+}
+
+pub fn cancelled() -> Announcement {
+    Announcement::new("Dictation cancelled", Politeness::Assertive)
+}
+
+pub fn held_for_review(text: &str) -> Announcement {
+    Announcement::new(
+        format!("Held for review: {}", shorten(text)),
+        Politeness::Polite,
+    )
+}
+
+pub fn insertion(record: &InsertionRecord) -> Announcement {
+    match record.status {
+        InsertionStatus::Failure => Announcement::new(
+            format!("Insertion failed: {}", shorten(&record.text)),
+            Politeness::Assertive,
+        ),
+        InsertionStatus::Fallback => Announcement::new(
+            format!("Copied to clipboard: {}", shorten(&record.text)),
+            Politeness::Polite,
+        ),
+        _ => Announcement::new(
+            format!("Inserted: {}", shorten(&record.text)),
+            Politeness::Polite,
+        ),
+    }
+}
+
+fn shorten(text: &str) -> String {
+    let text = text.trim();
+    if text.chars().count() <= MAX_ANNOUNCED_CHARS {
+        return text.to_string();
+    }
+    let cut = text.chars().take(MAX_ANNOUNCED_CHARS).collect::<String>();
+    let cut = cut
+        .rsplit_once(' ')
+        .map(|(head, _)| head.to_string())
+        .unwrap_or(cut);
+    format!("{cut}…")
+}
+
+// Orca speaks through speech-dispatcher and VoiceOver takes AppleScript output.
+// Windows has no command-line path to UIA notifications, so NVDA and JAWS get
+// the announcement from the webview live region instead.
+pub fn native_command(announcement: &Announcement) -> Option<Command> {
+    #[cfg(target_os = "linux")]
+    {
+        let mut command = Command::new("spd-say");
+        let priority = match announcement.politeness {
+            Politeness::Assertive => "important",
+            Politeness::Polite => "message",
+        };
+        command.args(["--priority", priority, "--", &announcement.text]);
+        Some(command)
+    }
+    #[cfg(target_os = "macos")]
+    {
+        let escaped = announcement.text.replace('\\', "\\\\").replace('"', "\\\"");
+        let mut command = Command::new("osascript");
+        command.args([
+            "-e",
+            &format!(
+                "if application \"VoiceOver\" is running then tell application \"VoiceOver\" to output \"{escaped}\""
+            ),
+        ]);
+        Some(command)
+    }
+    #[cfg(not(any(target_os = "linux", target_os = "macos")))]
+    {
+        let _ = announcement;
+        None
+    }
+}
+
+pub fn speak_natively(announcement: &Announcement) -> bool {
+    let Some(mut command) = native_command(announcement) else {
+        return false;
+    };
+    command
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map(|mut child| {
+            // Reap off-thread so announcing never waits on the speech queue.
+            std::thread::spawn(move || {
+                let _ = child.wait();
+            });
+        })
+        .is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn announces_start_stop_and_insertions() {
+        assert_eq!(
+            state_change(DictationState::Idle, DictationState::Listening)
+                .map(|announcement| announcement.text),
+            Some("Dictation started".to_string())
+        );
+        assert!(state_change(DictationState::Transcribing, DictationState::Listening).is_none());
+
+        let failed = InsertionRecord::new(1, "hello there".to_string(), InsertionStatus::Failure);
+        let announcement = insertion(&failed);
+        assert_eq!(announcement.text, "Insertion failed: hello there");
+        assert_eq!(announcement.politeness, Politeness::Assertive);
+    }
+
+    #[test]
+    fn shortens_long_transcripts_on_a_word_boundary() {
+        let long = "word ".repeat(60);
+        let announced = held_for_review(&long).text;
+        assert!(announced.ends_with("word…"));
+        assert!(announced.chars().count() <= "Held for review: ".len() + MAX_ANNOUNCED_CHARS + 1);
+    }
+}
@@ -1,5 +1,14 @@
 pub const SAMPLE_RATE_HZ: u32 = 16_000;
 pub const CHANNELS: u16 = 1;
+pub const NORMALIZATION_TARGET_RMS: f32 = 0.08;
+pub const NORMALIZATION_PEAK_CEILING: f32 = 0.95;
+pub const NORMALIZATION_MAX_GAIN: f32 = 6.0;
+pub const HIGH_PASS_CUTOFF_HZ: f32 = 80.0;
+const AGC_WINDOW_SAMPLES: usize = 320;
+const AGC_SMOOTHING: f32 = 0.2;
+const AGC_SILENCE_RMS: f32 = 0.002;
+#[cfg(feature = "desktop")]
+const CAPTURE_RECV_TIMEOUT_MS: u64 = 60;
 
 #[cfg(feature = "desktop")]
 use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
@@ -8,7 +17,20 @@ use cpal::{SampleFormat, Stream};
 #[cfg(feature = "desktop")]
 use serde::Serialize;
 #[cfg(feature = "desktop")]
-use std::sync::mpsc::SyncSender;
+use std::path::PathBuf;
+#[cfg(feature = "desktop")]
+use std::sync::mpsc::{self, RecvTimeoutError, SyncSender};
+#[cfg(feature = "desktop")]
+use std::sync::{Arc, Mutex};
+#[cfg(feature = "desktop")]
+use std::time::{Duration, Instant};
+
+use crate::config::{PreprocessStage, PreprocessStep};
+#[cfg(feature = "desktop")]
+use crate::monitor::MonitorBuffer;
+use crate::noise_profile::{spectral_subtract, NoiseProfile};
+#[cfg(feature = "desktop")]
+use crate::runtime_log;
 
 pub fn validate_audio_format(sample_rate_hz: u32, channels: u16) -> Result<(), String> {
     if sample_rate_hz != SAMPLE_RATE_HZ {
@@ -32,12 +54,186 @@ pub fn pcm_i16_to_f32(samples: &[i16]) -> Vec<f32> {
         .collect()
 }
 
+pub fn normalize_chunk_energy(samples: &[f32]) -> Vec<f32> {
+    if samples.is_empty() {
+        return Vec::new();
+    }
+
+    let mut energy_sum = 0f32;
+    let mut peak = 0f32;
+    for sample in samples {
+        energy_sum += sample * sample;
+        peak = peak.max(sample.abs());
+    }
+
+    let rms = (energy_sum / samples.len() as f32).sqrt();
+    if rms <= f32::EPSILON || peak <= f32::EPSILON {
+        return samples.to_vec();
+    }
+
+    let gain = (NORMALIZATION_TARGET_RMS / rms)
+        .min(NORMALIZATION_PEAK_CEILING / peak)
+        .clamp(1.0, NORMALIZATION_MAX_GAIN);
+    if (gain - 1.0).abs() < f32::EPSILON {
+        return samples.to_vec();
+    }
+
+    samples
+        .iter()
+        .map(|sample| (sample * gain).clamp(-1.0, 1.0))
+        .collect()
+}
+
+// One step of the preprocessing chain, applied to a whole 16 kHz chunk.
+pub trait AudioStage: Send + Sync {
+    fn process(&self, samples: &[f32]) -> Vec<f32>;
+
+    // Gain stages are left out of the VAD input so its thresholds keep seeing
+    // the captured level.
+    fn adjusts_level(&self) -> bool {
+        false
+    }
+}
+
+pub struct HighPassFilter {
+    alpha: f32,
+}
+
+impl HighPassFilter {
+    pub fn new(cutoff_hz: f32, sample_rate_hz: u32) -> Self {
+        let rc = 1.0 / (2.0 * std::f32::consts::PI * cutoff_hz.max(1.0));
+        let dt = 1.0 / sample_rate_hz.max(1) as f32;
+        Self {
+            alpha: rc / (rc + dt),
+        }
+    }
+}
+
+impl AudioStage for HighPassFilter {
+    fn process(&self, samples: &[f32]) -> Vec<f32> {
+        let mut output = Vec::with_capacity(samples.len());
+        let mut previous_input = samples.first().copied().unwrap_or(0.0);
+        let mut previous_output = 0f32;
+        for sample in samples {
+            previous_output = self.alpha * (previous_output + sample - previous_input);
+            previous_input = *sample;
+            output.push(previous_output);
+        }
+        output
+    }
+}
+
+pub struct Denoiser {
+    profile: NoiseProfile,
+}
+
+impl AudioStage for Denoiser {
+    fn process(&self, samples: &[f32]) -> Vec<f32> {
+        spectral_subtract(samples, &self.profile)
+    }
+}
+
+// Unlike Normalizer, which applies one gain to the whole chunk, AGC follows the
+// level in 20 ms windows so a quiet tail is lifted without pumping the loud start.
+pub struct AutomaticGainControl;
+
+impl AudioStage for AutomaticGainControl {
+    fn process(&self, samples: &[f32]) -> Vec<f32> {
+        let mut gain = 1f32;
+        let mut output = Vec::with_capacity(samples.len());
+        for window in samples.chunks(AGC_WINDOW_SAMPLES) {
+            let rms = (window.iter().map(|sample| sample * sample).sum::<f32>()
+                / window.len() as f32)
+                .sqrt();
+            if rms > AGC_SILENCE_RMS {
+                let target = (NORMALIZATION_TARGET_RMS / rms).clamp(1.0, NORMALIZATION_MAX_GAIN);
+                gain += (target - gain) * AGC_SMOOTHING;
+            }
+            output.extend(window.iter().map(|sample| (sample * gain).clamp(-1.0, 1.0)));
+        }
+        output
+    }
+
+    fn adjusts_level(&self) -> bool {
+        true
+    }
+}
+
+pub struct Normalizer;
+
+impl AudioStage for Normalizer {
+    fn process(&self, samples: &[f32]) -> Vec<f32> {
+        normalize_chunk_energy(samples)
+    }
+
+    fn adjusts_level(&self) -> bool {
+        true
+    }
+}
+
+#[derive(Default)]
+pub struct PreprocessChain {
+    stages: Vec<Box<dyn AudioStage>>,
+}
+
+impl PreprocessChain {
+    // Denoise is dropped when no noise profile has been calibrated for the mic.
+    pub fn from_steps(steps: &[PreprocessStep], noise_profile: Option<&NoiseProfile>) -> Self {
+        let stages = steps
+            .iter()
+            .filter(|step| step.enabled)
+            .filter_map(|step| -> Option<Box<dyn AudioStage>> {
+                match step.stage {
+                    PreprocessStage::HighPass => Some(Box::new(HighPassFilter::new(
+                        HIGH_PASS_CUTOFF_HZ,
+                        SAMPLE_RATE_HZ,
+                    ))),
+                    PreprocessStage::Denoise => noise_profile.map(|profile| {
+                        Box::new(Denoiser {
+                            profile: profile.clone(),
+                        }) as Box<dyn AudioStage>
+                    }),
+                    PreprocessStage::Agc => Some(Box::new(AutomaticGainControl)),
+                    PreprocessStage::Normalize => Some(Box::new(Normalizer)),
+                }
+            })
+            .collect();
+        Self { stages }
+    }
+
+    pub fn len(&self) -> usize {
+        self.stages.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.stages.is_empty()
+    }
+
+    // Returns (vad_input, transcriber_input).
+    pub fn process(&self, samples: &[f32]) -> (Vec<f32>, Vec<f32>) {
+        let mut output = samples.to_vec();
+        let mut vad_input: Option<Vec<f32>> = None;
+        for stage in &self.stages {
+            if stage.adjusts_level() {
+                vad_input.get_or_insert_with(|| output.clone());
+            } else if let Some(vad) = vad_input.as_mut() {
+                *vad = stage.process(vad);
+            }
+            output = stage.process(&output);
+        }
+        (vad_input.unwrap_or_else(|| output.clone()), output)
+    }
+}
+
 #[cfg(feature = "desktop")]
 #[derive(Debug, Clone, Serialize)]
 pub struct InputMicrophone {
     pub id: String,
     pub label: String,
     pub is_default: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snr_db: Option<f32>,
+    pub recommended: bool,
 }
 
 #[cfg(feature = "desktop")]
@@ -54,6 +250,20 @@ pub struct LiveInputStream {
     pub sample_rate_hz: u32,
 }
 
+#[cfg(feature = "desktop")]
+#[derive(Debug, Clone, Serialize)]
+pub struct OutputDevice {
+    pub id: String,
+    pub label: String,
+    pub is_default: bool,
+}
+
+#[cfg(feature = "desktop")]
+pub struct LiveOutputStream {
+    pub stream: Stream,
+    pub sample_rate_hz: u32,
+}
+
 #[cfg(feature = "desktop")]
 pub fn list_input_microphones() -> Result<Vec<InputMicrophone>, String> {
     let host = cpal::default_host();
@@ -74,15 +284,26 @@ pub fn list_input_microphones() -> Result<Vec<InputMicrophone>, String> {
             id: index.to_string(),
             label,
             is_default,
+            snr_db: None,
+            recommended: false,
         });
     }
 
     Ok(microphones)
 }
 
+#[cfg(feature = "desktop")]
+pub fn input_device_label(microphone_id: Option<&str>) -> Option<String> {
+    let host = cpal::default_host();
+    resolve_input_device(&host, microphone_id)
+        .ok()
+        .and_then(|device| device.name().ok())
+}
+
 #[cfg(feature = "desktop")]
 pub fn build_live_input_stream(
     microphone_id: Option<&str>,
+    input_channel: Option<u16>,
     frame_tx: SyncSender<Vec<f32>>,
 ) -> Result<LiveInputStream, String> {
     let host = cpal::default_host();
@@ -95,6 +316,7 @@ pub fn build_live_input_stream(
     let stream_config = supported.config();
     let sample_rate_hz = stream_config.sample_rate.0;
     let channels = usize::from(stream_config.channels.max(1));
+    let input_channel = input_channel.map(usize::from);
 
     let error_callback = move |error| {
         eprintln!("live input stream error: {error}");
@@ -107,7 +329,7 @@ pub fn build_live_input_stream(
                 .build_input_stream(
                     &stream_config,
                     move |data: &[f32], _| {
-                        let mono = interleaved_f32_to_mono(data, channels);
+                        let mono = interleaved_f32_to_mono(data, channels, input_channel);
                         let _ = tx.try_send(mono);
                     },
                     error_callback,
@@ -121,7 +343,7 @@ pub fn build_live_input_stream(
                 .build_input_stream(
                     &stream_config,
                     move |data: &[i16], _| {
-                        let mono = interleaved_i16_to_mono(data, channels);
+                        let mono = interleaved_i16_to_mono(data, channels, input_channel);
                         let _ = tx.try_send(mono);
                     },
                     error_callback,
@@ -135,7 +357,7 @@ pub fn build_live_input_stream(
                 .build_input_stream(
                     &stream_config,
                     move |data: &[u16], _| {
-                        let mono = interleaved_u16_to_mono(data, channels);
+                        let mono = interleaved_u16_to_mono(data, channels, input_channel);
                         let _ = tx.try_send(mono);
                     },
                     error_callback,
@@ -162,6 +384,144 @@ pub fn build_live_input_stream(
 }
 
 #[cfg(feature = "desktop")]
+pub fn capture_samples_16k(
+    microphone_id: Option<&str>,
+    input_channel: Option<u16>,
+    duration: Duration,
+) -> Result<Vec<f32>, String> {
+    let (capture_tx, capture_rx) = mpsc::sync_channel::<Vec<f32>>(48);
+    let input_stream = build_live_input_stream(microphone_id, input_channel, capture_tx)?;
+    let deadline = Instant::now() + duration;
+    let mut captured = Vec::<f32>::new();
+
+    while Instant::now() < deadline {
+        match capture_rx.recv_timeout(Duration::from_millis(CAPTURE_RECV_TIMEOUT_MS)) {
+            Ok(frame) => captured.extend_from_slice(&frame),
+            Err(RecvTimeoutError::Timeout) => continue,
+            Err(RecvTimeoutError::Disconnected) => break,
+        }
+    }
+    drop(input_stream.stream);
+
+    Ok(downsample_to_16k(&captured, input_stream.sample_rate_hz))
+}
+
+#[cfg(feature = "desktop")]
+pub fn list_output_devices() -> Result<Vec<OutputDevice>, String> {
+    let host = cpal::default_host();
+    let default_name = host
+        .default_output_device()
+        .and_then(|device| device.name().ok());
+    let devices = host
+        .output_devices()
+        .map_err(|error| format!("failed to enumerate output devices: {error}"))?;
+
+    let mut outputs = Vec::new();
+    for (index, device) in devices.enumerate() {
+        let label = device
+            .name()
+            .unwrap_or_else(|_| format!("Output {}", index + 1));
+        let is_default = default_name.as_deref() == Some(label.as_str());
+        outputs.push(OutputDevice {
+            id: index.to_string(),
+            label,
+            is_default,
+        });
+    }
+
+    Ok(outputs)
+}
+
+#[cfg(feature = "desktop")]
+pub fn build_monitor_output_stream(
+    output_device_id: Option<&str>,
+    buffer: Arc<Mutex<MonitorBuffer>>,
+    logs_path: PathBuf,
+) -> Result<LiveOutputStream, String> {
+    let host = cpal::default_host();
+    let device = resolve_output_device(&host, output_device_id)?;
+    let supported = device
+        .default_output_config()
+        .map_err(|error| format!("failed to get default output config: {error}"))?;
+
+    let sample_format = supported.sample_format();
+    let stream_config = supported.config();
+    let sample_rate_hz = stream_config.sample_rate.0;
+    let channels = usize::from(stream_config.channels.max(1));
+
+    let error_callback = move |error: cpal::StreamError| {
+        let _ = runtime_log::append(
+            &logs_path,
+            "error",
+            "mic.monitor",
+            &format!("monitor output stream error: {error}"),
+        );
+    };
+
+    let stream = match sample_format {
+        SampleFormat::F32 => device
+            .build_output_stream(
+                &stream_config,
+                move |data: &mut [f32], _| {
+                    fill_from_monitor(&buffer, data, channels);
+                },
+                error_callback,
+                None,
+            )
+            .map_err(|error| format!("failed to build f32 output stream: {error}"))?,
+        SampleFormat::I16 => {
+            let mut scratch = Vec::new();
+            device
+                .build_output_stream(
+                    &stream_config,
+                    move |data: &mut [i16], _| {
+                        scratch.resize(data.len(), 0.0);
+                        fill_from_monitor(&buffer, &mut scratch, channels);
+                        for (target, sample) in data.iter_mut().zip(&scratch) {
+                            *target = (sample.clamp(-1.0, 1.0) * i16::MAX as f32) as i16;
+                        }
+                    },
+                    error_callback,
+                    None,
+                )
+                .map_err(|error| format!("failed to build i16 output stream: {error}"))?
+        }
+        SampleFormat::U16 => {
+            let mut scratch = Vec::new();
+            device
+                .build_output_stream(
+                    &stream_config,
+                    move |data: &mut [u16], _| {
+                        scratch.resize(data.len(), 0.0);
+                        fill_from_monitor(&buffer, &mut scratch, channels);
+                        for (target, sample) in data.iter_mut().zip(&scratch) {
+                            *target =
+                                ((sample.clamp(-1.0, 1.0) + 1.0) * 0.5 * u16::MAX as f32) as u16;
+                        }
+                    },
+                    error_callback,
+                    None,
+                )
+                .map_err(|error| format!("failed to build u16 output stream: {error}"))?
+        }
+        _ => {
+            return Err(format!(
+                "unsupported output sample format: {:?}",
+                sample_format
+            ));
+        }
+    };
+
+    stream
+        .play()
+        .map_err(|error| format!("failed to start output stream: {error}"))?;
+
+    Ok(LiveOutputStream {
+        stream,
+        sample_rate_hz,
+    })
+}
+
 pub fn downsample_to_16k(input: &[f32], source_sample_rate_hz: u32) -> Vec<f32> {
     if source_sample_rate_hz == SAMPLE_RATE_HZ {
         return input.to_vec();
@@ -192,14 +552,57 @@ pub fn downsample_to_16k(input: &[f32], source_sample_rate_hz: u32) -> Vec<f32>
 }
 
 #[cfg(feature = "desktop")]
-pub fn measure_mic_level(samples: &[f32], previous_level: f32, previous_peak: f32) -> MicLevel {
-    if samples.is_empty() {
-        return MicLevel {
-            level: 0.0,
-            peak: previous_peak * 0.96,
-            active: false,
-        };
+const METER_LEVEL_SCALE: f32 = 14.0;
+
+#[cfg(feature = "desktop")]
+const METER_PEAK_RELEASE_MS: f32 = 250.0;
+
+#[cfg(feature = "desktop")]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MeterBallistics {
+    pub attack_ms: f32,
+    pub release_ms: f32,
+    pub peak_hold_ms: f32,
+}
+
+#[cfg(feature = "desktop")]
+impl MeterBallistics {
+    pub fn from_settings(attack_ms: u16, release_ms: u16, peak_hold_ms: u16) -> Self {
+        Self {
+            attack_ms: f32::from(attack_ms),
+            release_ms: f32::from(release_ms),
+            peak_hold_ms: f32::from(peak_hold_ms),
+        }
+    }
+}
+
+#[cfg(feature = "desktop")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MeterState {
+    level: f32,
+    peak: f32,
+    peak_age_ms: f32,
+}
+
+// Time constants rather than per-frame factors keep the meter feel independent of
+// the device's callback size.
+#[cfg(feature = "desktop")]
+fn smoothing_coefficient(frame_ms: f32, time_constant_ms: f32) -> f32 {
+    if time_constant_ms <= 0.0 {
+        0.0
+    } else {
+        (-frame_ms / time_constant_ms).exp()
     }
+}
+
+#[cfg(feature = "desktop")]
+pub fn measure_mic_level(
+    samples: &[f32],
+    sample_rate_hz: u32,
+    state: &mut MeterState,
+    ballistics: &MeterBallistics,
+) -> MicLevel {
+    let frame_ms = samples.len() as f32 * 1_000.0 / sample_rate_hz.max(1) as f32;
 
     let mut energy_sum = 0f32;
     let mut peak = 0f32;
@@ -211,20 +614,34 @@ pub fn measure_mic_level(samples: &[f32], previous_level: f32, previous_peak: f3
         }
     }
 
-    let rms = (energy_sum / samples.len() as f32).sqrt();
-    let scaled_level = (rms * 14.0).clamp(0.0, 1.0);
-    let level = if scaled_level >= previous_level {
-        scaled_level
+    let rms = if samples.is_empty() {
+        0.0
+    } else {
+        (energy_sum / samples.len() as f32).sqrt()
+    };
+    let scaled_level = (rms * METER_LEVEL_SCALE).clamp(0.0, 1.0);
+    let time_constant_ms = if scaled_level >= state.level {
+        ballistics.attack_ms
     } else {
-        previous_level * 0.84 + scaled_level * 0.16
+        ballistics.release_ms
     };
-    let combined_peak = (previous_peak * 0.96).max(peak);
-    let active = level > 0.08 || peak > 0.12;
+    let coefficient = smoothing_coefficient(frame_ms, time_constant_ms);
+    state.level = state.level * coefficient + scaled_level * (1.0 - coefficient);
+
+    if peak >= state.peak {
+        state.peak = peak;
+        state.peak_age_ms = 0.0;
+    } else if state.peak_age_ms < ballistics.peak_hold_ms {
+        state.peak_age_ms += frame_ms;
+    } else {
+        state.peak =
+            (state.peak * smoothing_coefficient(frame_ms, METER_PEAK_RELEASE_MS)).max(peak);
+    }
 
     MicLevel {
-        level,
-        peak: combined_peak,
-        active,
+        level: state.level,
+        peak: state.peak,
+        active: state.level > 0.08 || peak > 0.12,
     }
 }
 
@@ -261,55 +678,88 @@ fn resolve_input_device(
 }
 
 #[cfg(feature = "desktop")]
-fn interleaved_f32_to_mono(input: &[f32], channels: usize) -> Vec<f32> {
-    if channels <= 1 {
-        return input.to_vec();
+fn resolve_output_device(
+    host: &cpal::Host,
+    output_device_id: Option<&str>,
+) -> Result<cpal::Device, String> {
+    if let Some(raw_id) = output_device_id {
+        let trimmed = raw_id.trim();
+        if !trimmed.is_empty() {
+            let index = trimmed
+                .parse::<usize>()
+                .map_err(|_| format!("invalid output device id: {trimmed}"))?;
+            return host
+                .output_devices()
+                .map_err(|error| format!("failed to enumerate output devices: {error}"))?
+                .nth(index)
+                .ok_or_else(|| format!("output device not found for id {trimmed}"));
+        }
     }
 
-    let mut output = Vec::with_capacity(input.len() / channels);
-    for frame in input.chunks_exact(channels) {
-        let sum = frame.iter().copied().sum::<f32>();
-        output.push(sum / channels as f32);
-    }
-    output
+    host.default_output_device()
+        .ok_or_else(|| "no output device is available".to_string())
 }
 
 #[cfg(feature = "desktop")]
-fn interleaved_i16_to_mono(input: &[i16], channels: usize) -> Vec<f32> {
-    if channels <= 1 {
-        return pcm_i16_to_f32(input);
+fn fill_from_monitor(buffer: &Mutex<MonitorBuffer>, output: &mut [f32], channels: usize) {
+    match buffer.lock() {
+        Ok(mut buffer) => buffer.fill_interleaved(output, channels),
+        Err(_) => output.fill(0.0),
     }
+}
 
+#[cfg(feature = "desktop")]
+fn interleaved_f32_to_mono(
+    input: &[f32],
+    channels: usize,
+    input_channel: Option<usize>,
+) -> Vec<f32> {
+    downmix(input, channels, input_channel, |sample| sample)
+}
+
+#[cfg(feature = "desktop")]
+fn interleaved_i16_to_mono(
+    input: &[i16],
+    channels: usize,
+    input_channel: Option<usize>,
+) -> Vec<f32> {
     let scale = i16::MAX as f32;
-    let mut output = Vec::with_capacity(input.len() / channels);
-    for frame in input.chunks_exact(channels) {
-        let mut sum = 0f32;
-        for sample in frame {
-            sum += *sample as f32 / scale;
-        }
-        output.push(sum / channels as f32);
-    }
-    output
+    downmix(input, channels, input_channel, |sample| {
+        sample as f32 / scale
+    })
 }
 
 #[cfg(feature = "desktop")]
-fn interleaved_u16_to_mono(input: &[u16], channels: usize) -> Vec<f32> {
+fn interleaved_u16_to_mono(
+    input: &[u16],
+    channels: usize,
+    input_channel: Option<usize>,
+) -> Vec<f32> {
+    downmix(input, channels, input_channel, |sample| {
+        (sample as f32 / u16::MAX as f32) * 2.0 - 1.0
+    })
+}
+
+// A selected channel the device does not have falls back to averaging all channels.
+#[cfg(feature = "desktop")]
+fn downmix<T: Copy>(
+    input: &[T],
+    channels: usize,
+    input_channel: Option<usize>,
+    to_f32: impl Fn(T) -> f32,
+) -> Vec<f32> {
     if channels <= 1 {
-        return input
-            .iter()
-            .map(|sample| (*sample as f32 / u16::MAX as f32) * 2.0 - 1.0)
-            .collect();
+        return input.iter().map(|sample| to_f32(*sample)).collect();
     }
 
-    let mut output = Vec::with_capacity(input.len() / channels);
-    for frame in input.chunks_exact(channels) {
-        let mut sum = 0f32;
-        for sample in frame {
-            sum += (*sample as f32 / u16::MAX as f32) * 2.0 - 1.0;
-        }
-        output.push(sum / channels as f32);
-    }
-    output
+    let input_channel = input_channel.filter(|channel| *channel < channels);
+    input
+        .chunks_exact(channels)
+        .map(|frame| match input_channel {
+            Some(channel) => to_f32(frame[channel]),
+            None => frame.iter().map(|sample| to_f32(*sample)).sum::<f32>() / channels as f32,
+        })
+        .collect()
 }
 
 #[cfg(test)]
@@ -332,6 +782,89 @@ mod tests {
         assert!(output[2] > 0.99);
     }
 
+    #[test]
+    fn normalization_boosts_quiet_chunks_up_to_gain_cap() {
+        let quiet = vec![0.01_f32, -0.01_f32, 0.01_f32, -0.01_f32];
+        let boosted = normalize_chunk_energy(&quiet);
+        assert!((boosted[0] - 0.06).abs() < 1e-6);
+        assert!((boosted[1] + 0.06).abs() < 1e-6);
+    }
+
+    #[test]
+    fn normalization_respects_peak_ceiling_and_leaves_loud_audio() {
+        let mut spiky = vec![0.0_f32; 1_000];
+        spiky[0] = 0.9;
+        let normalized = normalize_chunk_energy(&spiky);
+        assert!((normalized[0] - 0.95).abs() < 1e-6);
+
+        let loud = vec![0.4_f32, -0.4_f32];
+        assert_eq!(normalize_chunk_energy(&loud), loud);
+        assert_eq!(normalize_chunk_energy(&[0.0, 0.0]), vec![0.0, 0.0]);
+    }
+
+    fn step(stage: PreprocessStage, enabled: bool) -> PreprocessStep {
+        PreprocessStep { stage, enabled }
+    }
+
+    #[test]
+    fn high_pass_removes_dc_offset() {
+        let offset = vec![0.3_f32; 4_000];
+        let filtered = HighPassFilter::new(HIGH_PASS_CUTOFF_HZ, SAMPLE_RATE_HZ).process(&offset);
+        assert!(filtered[3_999].abs() < 1e-3);
+    }
+
+    #[test]
+    fn denoiser_applies_the_noise_profile() {
+        let noise = (0..4_096)
+            .map(|index| if index % 2 == 0 { 0.02 } else { -0.02 })
+            .collect::<Vec<f32>>();
+        let profile =
+            crate::noise_profile::build_noise_profile("default", &noise, 0).expect("profile");
+        let denoised = Denoiser { profile }.process(&noise);
+        let energy = |samples: &[f32]| samples.iter().map(|sample| sample * sample).sum::<f32>();
+        assert!(energy(&denoised) < energy(&noise) * 0.5);
+    }
+
+    #[test]
+    fn agc_lifts_quiet_windows_gradually_and_holds_through_silence() {
+        let mut samples = vec![0.01_f32; AGC_WINDOW_SAMPLES * 20];
+        samples.extend(vec![0.0_f32; AGC_WINDOW_SAMPLES]);
+        let output = AutomaticGainControl.process(&samples);
+        assert!(output[0] > 0.01 && output[0] < 0.03);
+        assert!((output[AGC_WINDOW_SAMPLES * 20 - 1] - 0.06).abs() < 0.005);
+        assert_eq!(output[AGC_WINDOW_SAMPLES * 20], 0.0);
+    }
+
+    #[test]
+    fn normalizer_matches_chunk_normalization() {
+        let quiet = vec![0.01_f32, -0.01_f32];
+        assert_eq!(Normalizer.process(&quiet), normalize_chunk_energy(&quiet));
+        assert!(Normalizer.adjusts_level());
+    }
+
+    #[test]
+    fn chain_follows_order_and_keeps_gain_out_of_vad_input() {
+        let chain = PreprocessChain::from_steps(
+            &[
+                step(PreprocessStage::Normalize, true),
+                step(PreprocessStage::HighPass, true),
+                step(PreprocessStage::Agc, false),
+                step(PreprocessStage::Denoise, true),
+            ],
+            None,
+        );
+        assert_eq!(chain.len(), 2);
+
+        let quiet = vec![0.01_f32, -0.01_f32, 0.01_f32, -0.01_f32];
+        let (vad_input, output) = chain.process(&quiet);
+        let high_pass = HighPassFilter::new(HIGH_PASS_CUTOFF_HZ, SAMPLE_RATE_HZ);
+        assert_eq!(vad_input, high_pass.process(&quiet));
+        assert_eq!(output, high_pass.process(&normalize_chunk_energy(&quiet)));
+
+        let (vad_input, output) = PreprocessChain::default().process(&quiet);
+        assert_eq!((vad_input, output), (quiet.clone(), quiet));
+    }
+
     #[cfg(feature = "desktop")]
     #[test]
     fn downsamples_from_48k_to_16k() {
@@ -352,7 +885,8 @@ mod tests {
     #[test]
     fn computes_mic_levels() {
         let samples = vec![0.2_f32; 1024];
-        let level = measure_mic_level(&samples, 0.0, 0.0);
+        let ballistics = MeterBallistics::from_settings(0, 60, 0);
+        let level = measure_mic_level(&samples, 48_000, &mut MeterState::default(), &ballistics);
         assert!(level.level > 0.0);
         assert!(level.peak > 0.0);
         assert!(level.active);
@@ -361,17 +895,46 @@ mod tests {
     #[cfg(feature = "desktop")]
     #[test]
     fn decays_peak_when_silent() {
-        let silent = measure_mic_level(&[], 0.0, 0.75);
+        let ballistics = MeterBallistics::from_settings(0, 60, 0);
+        let mut state = MeterState {
+            peak: 0.75,
+            ..MeterState::default()
+        };
+        let silent = measure_mic_level(&[0.0; 480], 48_000, &mut state, &ballistics);
         assert!(!silent.active);
         assert!(silent.peak < 0.75);
         assert!(silent.peak > 0.70);
     }
 
+    #[cfg(feature = "desktop")]
+    #[test]
+    fn holds_peak_then_releases_level_over_time() {
+        let ballistics = MeterBallistics::from_settings(0, 100, 50);
+        let mut state = MeterState::default();
+        let loud = measure_mic_level(&[0.5; 480], 48_000, &mut state, &ballistics);
+        assert_eq!(loud.level, 1.0);
+
+        let held = measure_mic_level(&[0.0; 480], 48_000, &mut state, &ballistics);
+        assert_eq!(held.peak, 0.5);
+        assert!(held.level < 1.0 && held.level > 0.85);
+
+        for _ in 0..5 {
+            measure_mic_level(&[0.0; 480], 48_000, &mut state, &ballistics);
+        }
+        let released = measure_mic_level(&[0.0; 480], 48_000, &mut state, &ballistics);
+        assert!(released.peak < 0.5);
+    }
+
     #[cfg(feature = "desktop")]
     #[test]
     fn averages_interleaved_f32_channels_to_mono() {
         let stereo = vec![0.2_f32, 0.6_f32, -0.2_f32, 0.2_f32];
-        let mono = interleaved_f32_to_mono(&stereo, 2);
+        let mono = interleaved_f32_to_mono(&stereo, 2, None);
         assert_eq!(mono, vec![0.4_f32, 0.0_f32]);
+        assert_eq!(
+            interleaved_f32_to_mono(&stereo, 2, Some(1)),
+            vec![0.6_f32, 0.2_f32]
+        );
+        assert_eq!(interleaved_f32_to_mono(&stereo, 2, Some(4)), mono);
     }
 }
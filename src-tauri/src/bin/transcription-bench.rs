@@ -10,16 +10,26 @@ use sonora_dictation_lib::config::{
     DictationMode, FasterWhisperComputeType, ModelProfile, ParakeetComputeType, SttEngine,
     WhisperBackendPreference,
 };
+use sonora_dictation_lib::file_ingest;
+use sonora_dictation_lib::microbench::{self, Measurement};
 use sonora_dictation_lib::pipeline::DictationPipeline;
 use sonora_dictation_lib::postprocess::{merge_transcript_segments, normalize_transcript};
 use sonora_dictation_lib::profile::ProfileTuning;
+use sonora_dictation_lib::transcriber::Transcriber;
 use sonora_dictation_lib::transcriber::{build_runtime_engine, EngineSpec};
-use sonora_dictation_lib::vad::VadConfig;
+use sonora_dictation_lib::vad::{self, VadConfig};
 
 const SAMPLE_RATE_HZ: usize = 16_000;
 const DEFAULT_CHUNK_MS: u64 = 1_800;
 const DEFAULT_PARTIAL_CADENCE_MS: u64 = 900;
 const DEFAULT_SESSION_GAP_MS: u64 = 2_000;
+const DEFAULT_MICRO_BUDGET_MS: u64 = 1_000;
+const DEFAULT_MICRO_TOLERANCE_PERCENT: f64 = 10.0;
+// Capture rate the resampler bench converts from; most devices run at 48 kHz.
+const MICRO_CAPTURE_RATE_HZ: u32 = 48_000;
+const MICRO_FIXTURE_SECONDS: u32 = 10;
+// One live chunk; engines are timed on this rather than the whole fixture.
+const MICRO_ENGINE_CHUNK_SECONDS: usize = 3;
 
 #[derive(Debug, Clone)]
 struct RunOptions {
@@ -44,6 +54,19 @@ struct RecordOptions {
     sensitivity_percent: u16,
 }
 
+#[derive(Debug, Clone)]
+struct MicroOptions {
+    fixtures_dir: PathBuf,
+    audio_path: Option<PathBuf>,
+    resource_dir: PathBuf,
+    backend: WhisperBackendPreference,
+    budget: Duration,
+    case_names: Vec<String>,
+    baseline_path: Option<PathBuf>,
+    save_baseline_path: Option<PathBuf>,
+    tolerance_percent: f64,
+}
+
 #[derive(Debug, Clone)]
 struct BenchCase {
     name: &'static str,
@@ -175,6 +198,10 @@ fn run_main() -> Result<(), String> {
             let options = parse_run_options(&args[1..])?;
             run_benchmark(options)
         }
+        "micro" => {
+            let options = parse_micro_options(&args[1..])?;
+            run_micro_benchmarks(options)
+        }
         _ => Err(usage()),
     }
 }
@@ -319,6 +346,258 @@ fn parse_run_options(args: &[String]) -> Result<RunOptions, String> {
     })
 }
 
+fn parse_micro_options(args: &[String]) -> Result<MicroOptions, String> {
+    let args = if args.first().map(|value| value.as_str()) == Some("--") {
+        &args[1..]
+    } else {
+        args
+    };
+
+    let mut options = MicroOptions {
+        fixtures_dir: PathBuf::from("benchmark/fixtures"),
+        audio_path: None,
+        resource_dir: PathBuf::from("src-tauri/resources"),
+        backend: WhisperBackendPreference::Auto,
+        budget: Duration::from_millis(DEFAULT_MICRO_BUDGET_MS),
+        case_names: Vec::new(),
+        baseline_path: None,
+        save_baseline_path: None,
+        tolerance_percent: DEFAULT_MICRO_TOLERANCE_PERCENT,
+    };
+
+    let mut index = 0;
+    while index < args.len() {
+        match args[index].as_str() {
+            "--fixtures" => {
+                options.fixtures_dir = PathBuf::from(next_arg(args, &mut index, "--fixtures")?);
+            }
+            "--audio" => {
+                options.audio_path = Some(PathBuf::from(next_arg(args, &mut index, "--audio")?));
+            }
+            "--resource-dir" => {
+                options.resource_dir = PathBuf::from(next_arg(args, &mut index, "--resource-dir")?);
+            }
+            "--backend" => {
+                options.backend = parse_backend(next_arg(args, &mut index, "--backend")?)?;
+            }
+            "--budget-ms" => {
+                let budget = parse_u64(next_arg(args, &mut index, "--budget-ms")?, "budget-ms")?;
+                options.budget = Duration::from_millis(budget.clamp(50, 60_000));
+            }
+            "--case" => {
+                options
+                    .case_names
+                    .push(next_arg(args, &mut index, "--case")?.to_string());
+            }
+            "--baseline" => {
+                options.baseline_path =
+                    Some(PathBuf::from(next_arg(args, &mut index, "--baseline")?));
+            }
+            "--save-baseline" => {
+                options.save_baseline_path = Some(PathBuf::from(next_arg(
+                    args,
+                    &mut index,
+                    "--save-baseline",
+                )?));
+            }
+            "--tolerance" => {
+                let value = next_arg(args, &mut index, "--tolerance")?;
+                options.tolerance_percent = value
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|value| *value >= 0.0)
+                    .ok_or_else(|| format!("invalid tolerance: {value}"))?;
+            }
+            unexpected => {
+                return Err(format!("unknown micro option: {unexpected}"));
+            }
+        }
+        index += 1;
+    }
+
+    Ok(options)
+}
+
+// Times the audio and text stages on bundled fixtures, plus any engine cases
+// whose model and sidecar are installed. Exits non-zero when a median is
+// slower than the baseline by more than the tolerance.
+fn run_micro_benchmarks(options: MicroOptions) -> Result<(), String> {
+    let transcripts_path = options.fixtures_dir.join("transcripts.txt");
+    let transcripts = fs::read_to_string(&transcripts_path)
+        .map_err(|error| {
+            format!(
+                "failed to read fixture '{}': {error}",
+                transcripts_path.to_string_lossy()
+            )
+        })?
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(str::to_string)
+        .collect::<Vec<_>>();
+
+    let (capture, capture_rate_hz) = match &options.audio_path {
+        Some(path) => (read_audio_16k_mono(path)?, SAMPLE_RATE_HZ as u32),
+        None => (
+            microbench::fixture_audio(MICRO_CAPTURE_RATE_HZ, MICRO_FIXTURE_SECONDS),
+            MICRO_CAPTURE_RATE_HZ,
+        ),
+    };
+    let audio_ms = capture.len() as u64 * 1_000 / u64::from(capture_rate_hz);
+    let samples = audio::downsample_to_16k(&capture, capture_rate_hz);
+    let vad_config = VadConfig::default();
+
+    let mut results = vec![
+        microbench::measure("audio/downsample_to_16k", options.budget, || {
+            audio::downsample_to_16k(&capture, capture_rate_hz)
+        })
+        .with_audio_ms(audio_ms),
+        microbench::measure("audio/normalize_chunk_energy", options.budget, || {
+            audio::normalize_chunk_energy(&samples)
+        })
+        .with_audio_ms(audio_ms),
+        microbench::measure("vad/detect_speech_segments", options.budget, || {
+            vad::detect_speech_segments(&samples, &vad_config)
+        })
+        .with_audio_ms(audio_ms),
+        microbench::measure("postprocess/normalize_transcript", options.budget, || {
+            transcripts
+                .iter()
+                .map(|line| normalize_transcript(line).len())
+                .sum::<usize>()
+        }),
+        microbench::measure("postprocess/merge_segments", options.budget, || {
+            transcripts.iter().fold(String::new(), |merged, line| {
+                merge_transcript_segments(&merged, line)
+            })
+        }),
+    ];
+
+    let chunk = &samples[..samples
+        .len()
+        .min(SAMPLE_RATE_HZ * MICRO_ENGINE_CHUNK_SECONDS)];
+    let chunk_ms = chunk.len() as u64 * 1_000 / SAMPLE_RATE_HZ as u64;
+    for case_name in &options.case_names {
+        let case = resolve_case(case_name)?;
+        let runtime = build_runtime_engine(build_case_spec(
+            &case,
+            &options.resource_dir,
+            options.backend,
+        )?);
+        if !runtime.diagnostics.ready {
+            println!(
+                "skipping engine/{case_name}: {}",
+                runtime.diagnostics.description
+            );
+            continue;
+        }
+        runtime.transcriber.prepare()?;
+        let transcriber = runtime.transcriber;
+        results.push(
+            microbench::measure(&format!("engine/{case_name}"), options.budget, || {
+                transcriber.transcribe(chunk)
+            })
+            .with_audio_ms(chunk_ms),
+        );
+        transcriber.unload();
+    }
+
+    print_micro_report(&options, &results);
+
+    if let Some(path) = &options.save_baseline_path {
+        let payload = serde_json::to_string_pretty(&results)
+            .map_err(|error| format!("failed to serialize micro benchmark results: {error}"))?;
+        fs::write(path, payload).map_err(|error| {
+            format!(
+                "failed to write baseline '{}': {error}",
+                path.to_string_lossy()
+            )
+        })?;
+        println!("\nsaved baseline to {}", path.to_string_lossy());
+    }
+
+    let Some(path) = &options.baseline_path else {
+        return Ok(());
+    };
+    let baseline = fs::read_to_string(path)
+        .map_err(|error| {
+            format!(
+                "failed to read baseline '{}': {error}",
+                path.to_string_lossy()
+            )
+        })
+        .and_then(|raw| {
+            serde_json::from_str::<Vec<Measurement>>(&raw)
+                .map_err(|error| format!("invalid baseline file: {error}"))
+        })?;
+    let regressions = microbench::regressions(&baseline, &results, options.tolerance_percent);
+    if regressions.is_empty() {
+        println!(
+            "\nno regressions beyond {:.1}% against {}",
+            options.tolerance_percent,
+            path.to_string_lossy()
+        );
+        return Ok(());
+    }
+    for regression in &regressions {
+        println!(
+            "regression: {} median {} -> {} ({:+.1}%)",
+            regression.name,
+            format_ns(regression.baseline_median_ns),
+            format_ns(regression.current_median_ns),
+            regression.change_percent
+        );
+    }
+    Err(format!(
+        "{} benchmark(s) regressed beyond {:.1}%",
+        regressions.len(),
+        options.tolerance_percent
+    ))
+}
+
+fn print_micro_report(options: &MicroOptions, results: &[Measurement]) {
+    println!("Sonora Micro Benchmarks");
+    println!(
+        "fixtures: {} audio: {} budget={}ms",
+        options.fixtures_dir.to_string_lossy(),
+        options
+            .audio_path
+            .as_ref()
+            .map(|path| path.to_string_lossy().to_string())
+            .unwrap_or_else(|| "synthetic".to_string()),
+        options.budget.as_millis()
+    );
+    println!();
+    println!(
+        "{:<36} {:>10} {:>10} {:>10} {:>10} {:>9}",
+        "bench", "median", "mean", "p95", "min", "x realtime"
+    );
+    println!("{}", "-".repeat(90));
+    for result in results {
+        println!(
+            "{:<36} {:>10} {:>10} {:>10} {:>10} {:>9}",
+            truncate(&result.name, 36),
+            format_ns(result.median_ns),
+            format_ns(result.mean_ns),
+            format_ns(result.p95_ns),
+            format_ns(result.min_ns),
+            result
+                .realtime_factor
+                .map(|value| format!("{value:.1}"))
+                .unwrap_or_else(|| "-".to_string()),
+        );
+    }
+}
+
+fn format_ns(value: u64) -> String {
+    match value {
+        0..=9_999 => format!("{value}ns"),
+        10_000..=9_999_999 => format!("{:.1}us", value as f64 / 1_000.0),
+        10_000_000..=9_999_999_999 => format!("{:.1}ms", value as f64 / 1_000_000.0),
+        _ => format!("{:.2}s", value as f64 / 1_000_000_000.0),
+    }
+}
+
 fn record_sample(options: RecordOptions) -> Result<(), String> {
     let parent = options
         .out_path
@@ -328,7 +607,7 @@ fn record_sample(options: RecordOptions) -> Result<(), String> {
         .map_err(|error| format!("failed to create output directory: {error}"))?;
 
     let (frame_tx, frame_rx) = mpsc::sync_channel::<Vec<f32>>(64);
-    let stream = audio::build_live_input_stream(options.microphone_id.as_deref(), frame_tx)?;
+    let stream = audio::build_live_input_stream(options.microphone_id.as_deref(), None, frame_tx)?;
     if stream.sample_rate_hz < SAMPLE_RATE_HZ as u32 {
         return Err(format!(
             "microphone sample rate {} Hz is below required {} Hz",
@@ -518,7 +797,7 @@ fn run_single_case(
     let mut error = None;
 
     for _ in 0..options.runs {
-        let spec = build_case_spec(&case, options)?;
+        let spec = build_case_spec(&case, &options.resource_dir, options.backend)?;
         let runtime = build_runtime_engine(spec);
         ready = runtime.diagnostics.ready;
         backend = runtime.diagnostics.compute_backend.clone();
@@ -702,19 +981,30 @@ fn benchmark_runtime_once(
     })
 }
 
-fn build_case_spec(case: &BenchCase, options: &RunOptions) -> Result<EngineSpec, String> {
-    let model_path = resolve_case_model_path(case, &options.resource_dir);
+fn build_case_spec(
+    case: &BenchCase,
+    resource_dir: &Path,
+    backend: WhisperBackendPreference,
+) -> Result<EngineSpec, String> {
+    let model_path = resolve_case_model_path(case, resource_dir);
 
     Ok(EngineSpec {
         engine: case.engine,
         language: "en".to_string(),
         model_profile: ModelProfile::Balanced,
         model_path,
-        whisper_backend_preference: options.backend,
+        whisper_backend_preference: backend,
         faster_whisper_compute_type: case.compute_type,
         faster_whisper_beam_size: case.beam_size,
+        faster_whisper_cache_dir: None,
         parakeet_compute_type: case.parakeet_compute_type,
-        resource_dir: Some(options.resource_dir.clone()),
+        resource_dir: Some(resource_dir.to_path_buf()),
+        sandbox_sidecars: false,
+        allow_path_sidecars: true,
+        offline_only: false,
+        punctuation_restoration: false,
+        gpu_device_index: None,
+        translate: false,
     })
 }
 
@@ -722,7 +1012,7 @@ fn resolve_case_model_path(case: &BenchCase, resource_dir: &Path) -> PathBuf {
     match case.engine {
         SttEngine::WhisperCpp => resource_dir.join(case.model_reference),
         SttEngine::FasterWhisper => PathBuf::from(case.model_reference),
-        SttEngine::Parakeet => PathBuf::from(case.model_reference),
+        SttEngine::Parakeet | SttEngine::Demo => PathBuf::from(case.model_reference),
     }
 }
 
@@ -813,6 +1103,7 @@ fn case_engine_label(engine: SttEngine) -> &'static str {
         SttEngine::WhisperCpp => "whisper_cpp",
         SttEngine::FasterWhisper => "faster_whisper",
         SttEngine::Parakeet => "parakeet",
+        SttEngine::Demo => "demo",
     }
 }
 
@@ -886,63 +1177,9 @@ fn print_report(options: &RunOptions, results: &[CaseResult]) {
 }
 
 fn read_audio_16k_mono(path: &Path) -> Result<Vec<f32>, String> {
-    let mut reader = hound::WavReader::open(path)
-        .map_err(|error| format!("failed to open wav '{}': {error}", path.to_string_lossy()))?;
-    let spec = reader.spec();
-    if spec.channels == 0 {
-        return Err("wav has invalid channel count 0".to_string());
-    }
-
-    let mut interleaved = Vec::<f32>::new();
-    match spec.sample_format {
-        hound::SampleFormat::Int => {
-            if spec.bits_per_sample <= 16 {
-                for sample in reader.samples::<i16>() {
-                    let value =
-                        sample.map_err(|error| format!("failed to read i16 sample: {error}"))?;
-                    interleaved.push(value as f32 / i16::MAX as f32);
-                }
-            } else {
-                let scale = ((1_i64 << (spec.bits_per_sample - 1)) - 1) as f32;
-                for sample in reader.samples::<i32>() {
-                    let value =
-                        sample.map_err(|error| format!("failed to read i32 sample: {error}"))?;
-                    interleaved.push((value as f32 / scale).clamp(-1.0, 1.0));
-                }
-            }
-        }
-        hound::SampleFormat::Float => {
-            for sample in reader.samples::<f32>() {
-                let value =
-                    sample.map_err(|error| format!("failed to read f32 sample: {error}"))?;
-                interleaved.push(value.clamp(-1.0, 1.0));
-            }
-        }
-    }
-
-    let mono = if spec.channels == 1 {
-        interleaved
-    } else {
-        let channels = spec.channels as usize;
-        let mut output = Vec::<f32>::with_capacity(interleaved.len() / channels);
-        for frame in interleaved.chunks_exact(channels) {
-            output.push(frame.iter().copied().sum::<f32>() / channels as f32);
-        }
-        output
-    };
-
-    if spec.sample_rate < SAMPLE_RATE_HZ as u32 {
-        return Err(format!(
-            "unsupported sample rate {} Hz (minimum is 16000 Hz)",
-            spec.sample_rate
-        ));
-    }
-
-    if spec.sample_rate == SAMPLE_RATE_HZ as u32 {
-        return Ok(mono);
-    }
-
-    Ok(audio::downsample_to_16k(&mono, spec.sample_rate))
+    file_ingest::decode_audio_file(path)
+        .map(|decoded| decoded.samples)
+        .map_err(String::from)
 }
 
 fn write_wav_f32(path: &Path, samples: &[f32]) -> Result<(), String> {
@@ -1131,6 +1368,7 @@ fn usage() -> String {
         "  transcription-bench devices",
         "  transcription-bench record --out <path.wav> [--seconds 20] [--microphone-id 0] [--sensitivity 170]",
         "  transcription-bench run --audio <path.wav> [--reference <path.txt>] [--case <name>]...",
+        "  transcription-bench micro [--case <name>]... [--baseline <path.json>] [--save-baseline <path.json>]",
         "",
         "run options:",
         "  --resource-dir <path>         default: src-tauri/resources",
@@ -1142,6 +1380,13 @@ fn usage() -> String {
         "  --disable-vad                 disable VAD for benchmark-only runs",
         "  --vad-threshold-milli <1..80> default: 9 (0.009)",
         "",
+        "micro options:",
+        "  --fixtures <dir>              default: benchmark/fixtures",
+        "  --audio <path.wav>            replace the synthetic fixture audio",
+        "  --budget-ms <50..60000>       time per bench, default: 1000",
+        "  --tolerance <percent>         allowed median slowdown vs baseline, default: 10",
+        "  --case <name>                 also time an installed engine on a 3 s chunk",
+        "",
         "available cases:",
         "  whisper-large-v3-turbo-q8",
         "  whisper-base-en-q8",
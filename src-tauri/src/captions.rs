@@ -0,0 +1,131 @@
+use serde::Serialize;
+use std::fs;
+use std::path::Path;
+
+pub const CAPTION_MAX_LINES: usize = 2;
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct CaptionFrame {
+    pub session_id: u64,
+    pub lines: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+}
+
+pub fn wrap_caption_lines(text: &str, max_line_chars: usize) -> Vec<String> {
+    let max_line_chars = max_line_chars.max(1);
+    let mut lines = Vec::new();
+    let mut current = String::new();
+
+    for word in text.split_whitespace() {
+        let mut word = word;
+        while word.chars().count() > max_line_chars {
+            if !current.is_empty() {
+                lines.push(std::mem::take(&mut current));
+            }
+            let split_at = word
+                .char_indices()
+                .nth(max_line_chars)
+                .map(|(index, _)| index)
+                .unwrap_or(word.len());
+            lines.push(word[..split_at].to_string());
+            word = &word[split_at..];
+        }
+        if word.is_empty() {
+            continue;
+        }
+
+        let needed =
+            current.chars().count() + usize::from(!current.is_empty()) + word.chars().count();
+        if !current.is_empty() && needed > max_line_chars {
+            lines.push(std::mem::take(&mut current));
+        }
+        if !current.is_empty() {
+            current.push(' ');
+        }
+        current.push_str(word);
+    }
+
+    if !current.is_empty() {
+        lines.push(current);
+    }
+    lines
+}
+
+pub fn caption_frame(
+    session_id: u64,
+    text: &str,
+    max_line_chars: usize,
+    language: Option<String>,
+) -> CaptionFrame {
+    let mut lines = wrap_caption_lines(text, max_line_chars);
+    let overflow = lines.len().saturating_sub(CAPTION_MAX_LINES);
+    lines.drain(..overflow);
+
+    CaptionFrame {
+        session_id,
+        lines,
+        language,
+    }
+}
+
+// OBS text sources with "Read from file" poll the file, so it is replaced in
+// one rename and never observed half-written.
+pub fn write_caption_file(path: &Path, lines: &[String]) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|error| error.to_string())?;
+    }
+    let staging = path.with_extension("tmp");
+    fs::write(&staging, lines.join("\n")).map_err(|error| error.to_string())?;
+    fs::rename(&staging, path).map_err(|error| error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wraps_words_into_bounded_lines() {
+        assert_eq!(
+            wrap_caption_lines("the quick brown fox jumps over the lazy dog", 15),
+            vec!["the quick brown", "fox jumps over", "the lazy dog"]
+        );
+        assert_eq!(
+            wrap_caption_lines("Donaudampfschifffahrt ok", 8),
+            vec!["Donaudam", "pfschiff", "fahrt ok"]
+        );
+        assert!(wrap_caption_lines("   ", 10).is_empty());
+    }
+
+    #[test]
+    fn keeps_only_the_latest_caption_lines() {
+        let frame = caption_frame(
+            4,
+            "the quick brown fox jumps over the lazy dog",
+            15,
+            Some("en".to_string()),
+        );
+        assert_eq!(frame.session_id, 4);
+        assert_eq!(frame.lines, vec!["fox jumps over", "the lazy dog"]);
+        assert_eq!(frame.language.as_deref(), Some("en"));
+    }
+
+    #[test]
+    fn caption_file_holds_only_the_latest_frame() {
+        let path = std::env::temp_dir().join(format!(
+            "sonora-obs-captions-{}.txt",
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .expect("clock should be set")
+                .as_nanos()
+        ));
+        let first = caption_frame(1, "hello stream", 15, None);
+        write_caption_file(&path, &first.lines).expect("caption file should write");
+        let second = caption_frame(1, "the quick brown fox jumps over the lazy dog", 15, None);
+        write_caption_file(&path, &second.lines).expect("caption file should write");
+
+        let contents = fs::read_to_string(&path).expect("caption file should exist");
+        let _ = fs::remove_file(&path);
+        assert_eq!(contents, "fox jumps over\nthe lazy dog");
+    }
+}
@@ -0,0 +1,116 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::VecDeque;
+use std::hash::{Hash, Hasher};
+
+// Enough to cover a retried utterance or a replayed backlog without holding
+// transcripts from long-finished sessions.
+pub const CHUNK_CACHE_CAPACITY: usize = 32;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CachedTranscript {
+    pub text: String,
+    pub confidence: Option<f32>,
+}
+
+// Identical audio only yields the same transcript under the same engine, model
+// and prompt, so all of them are part of the key.
+pub fn chunk_key(samples: &[f32], engine: &str, model: &str, context: Option<&str>) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    engine.hash(&mut hasher);
+    model.hash(&mut hasher);
+    context.hash(&mut hasher);
+    samples.len().hash(&mut hasher);
+    for sample in samples {
+        sample.to_bits().hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+// Least recently used entries sit at the back and are evicted first.
+#[derive(Debug, Clone)]
+pub struct ChunkCache {
+    capacity: usize,
+    entries: VecDeque<(u64, CachedTranscript)>,
+}
+
+impl Default for ChunkCache {
+    fn default() -> Self {
+        Self::new(CHUNK_CACHE_CAPACITY)
+    }
+}
+
+impl ChunkCache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn get(&mut self, key: u64) -> Option<CachedTranscript> {
+        let position = self.entries.iter().position(|(entry, _)| *entry == key)?;
+        let entry = self.entries.remove(position)?;
+        let transcript = entry.1.clone();
+        self.entries.push_front(entry);
+        Some(transcript)
+    }
+
+    pub fn insert(&mut self, key: u64, transcript: CachedTranscript) {
+        if self.capacity == 0 {
+            return;
+        }
+        self.entries.retain(|(entry, _)| *entry != key);
+        self.entries.push_front((key, transcript));
+        self.entries.truncate(self.capacity);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transcript(text: &str) -> CachedTranscript {
+        CachedTranscript {
+            text: text.to_string(),
+            confidence: None,
+        }
+    }
+
+    #[test]
+    fn keys_depend_on_audio_engine_and_context() {
+        let audio = vec![0.1, -0.2, 0.3];
+        let key = chunk_key(&audio, "whisper_cpp", "base", None);
+        assert_eq!(key, chunk_key(&audio.clone(), "whisper_cpp", "base", None));
+        assert_ne!(
+            key,
+            chunk_key(&[0.1, -0.2, 0.31], "whisper_cpp", "base", None)
+        );
+        assert_ne!(key, chunk_key(&audio, "parakeet", "base", None));
+        assert_ne!(
+            key,
+            chunk_key(&audio, "whisper_cpp", "base", Some("Sonora"))
+        );
+    }
+
+    #[test]
+    fn evicts_the_least_recently_used_entry() {
+        let mut cache = ChunkCache::new(2);
+        cache.insert(1, transcript("one"));
+        cache.insert(2, transcript("two"));
+        assert_eq!(cache.get(1), Some(transcript("one")));
+
+        cache.insert(3, transcript("three"));
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get(2).is_none());
+        assert_eq!(cache.get(1), Some(transcript("one")));
+        assert_eq!(cache.get(3), Some(transcript("three")));
+    }
+}
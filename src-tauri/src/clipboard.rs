@@ -0,0 +1,422 @@
+use serde::Serialize;
+use std::io::Write;
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::Duration;
+
+#[cfg(target_os = "windows")]
+use std::os::windows::process::CommandExt;
+
+#[cfg(target_os = "windows")]
+const CREATE_NO_WINDOW: u32 = 0x08000000;
+
+// Targets read the clipboard after handling the paste keystroke, so the
+// previous contents go back only once they have had time to.
+const PASTE_SETTLE: Duration = Duration::from_millis(250);
+
+// Windows clipboard formats that Ditto and the built-in clipboard history treat
+// as "do not record".
+const WINDOWS_HIDDEN_COPY_SCRIPT: &str = "[Console]::InputEncoding = [Text.Encoding]::UTF8; \
+Add-Type -AssemblyName System.Windows.Forms; \
+$data = New-Object Windows.Forms.DataObject; \
+$data.SetText([Console]::In.ReadToEnd()); \
+$data.SetData('Clipboard Viewer Ignore', ''); \
+$data.SetData('ExcludeClipboardContentFromMonitorProcessing', ''); \
+$data.SetData('CanIncludeInClipboardHistory', (New-Object IO.MemoryStream (,[byte[]](0,0,0,0)))); \
+[Windows.Forms.Clipboard]::SetDataObject($data, $true)";
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ClipboardManager {
+    #[serde(rename = "copyq")]
+    CopyQ,
+    Ditto,
+}
+
+impl ClipboardManager {
+    pub const ALL: [ClipboardManager; 2] = [ClipboardManager::CopyQ, ClipboardManager::Ditto];
+
+    fn process_name(self) -> &'static str {
+        match self {
+            ClipboardManager::CopyQ => "copyq",
+            ClipboardManager::Ditto => "ditto",
+        }
+    }
+}
+
+pub fn copy_text(text: &str) -> Result<(), String> {
+    let wayland = std::env::var_os("WAYLAND_DISPLAY").is_some();
+    copy_with_tools(text, clipboard_tools(std::env::consts::OS, wayland))
+}
+
+// Used for insertion fallbacks: the text is only on the clipboard to be pasted,
+// so clipboard history tools are asked to skip it.
+pub fn copy_text_transient(text: &str) -> Result<Option<CopyQPause>, String> {
+    let managers = detect_managers();
+    let pause = managers
+        .contains(&ClipboardManager::CopyQ)
+        .then(CopyQPause::start)
+        .flatten();
+    let wayland = std::env::var_os("WAYLAND_DISPLAY").is_some();
+    copy_with_tools(
+        text,
+        transient_clipboard_tools(std::env::consts::OS, wayland),
+    )?;
+    Ok(pause)
+}
+
+// Insertion fallback: puts the text on the clipboard, sends the paste
+// keystroke and then puts back what the user had copied. CopyQ stays paused
+// until the restore, so neither copy reaches its history. Only text is put
+// back; an image on the clipboard is lost.
+pub fn paste_transient(text: &str) -> Result<(), String> {
+    let previous = read_text();
+    let pause = copy_text_transient(text)?;
+    let wayland = std::env::var_os("WAYLAND_DISPLAY").is_some();
+    let pasted = copy_with_tools("", paste_tools(std::env::consts::OS, wayland));
+    thread::sleep(PASTE_SETTLE);
+    if let Some(previous) = previous {
+        let _ = copy_with_tools(
+            &previous,
+            transient_clipboard_tools(std::env::consts::OS, wayland),
+        );
+    }
+    drop(pause);
+    pasted
+}
+
+fn read_text() -> Option<String> {
+    let wayland = std::env::var_os("WAYLAND_DISPLAY").is_some();
+    let text = read_tools(std::env::consts::OS, wayland)
+        .into_iter()
+        .find_map(|(program, args)| run_capture(program, args))?;
+    // Get-Clipboard ends its output with a newline the clipboard did not have.
+    if cfg!(target_os = "windows") {
+        return Some(text.strip_suffix("\r\n").unwrap_or(&text).to_string());
+    }
+    Some(text)
+}
+
+fn read_tools(os: &str, wayland: bool) -> Vec<(&'static str, &'static [&'static str])> {
+    match os {
+        "linux" => {
+            let mut tools: Vec<(&'static str, &'static [&'static str])> = vec![
+                ("xclip", &["-selection", "clipboard", "-o"]),
+                ("xsel", &["--clipboard", "--output"]),
+            ];
+            if wayland {
+                tools.insert(0, ("wl-paste", &["--no-newline"]));
+            }
+            tools
+        }
+        "macos" => vec![("pbpaste", &[])],
+        "windows" => vec![(
+            "powershell",
+            &[
+                "-NoProfile",
+                "-NonInteractive",
+                "-Command",
+                "Get-Clipboard -Raw",
+            ],
+        )],
+        _ => Vec::new(),
+    }
+}
+
+fn paste_tools(os: &str, wayland: bool) -> Vec<(&'static str, &'static [&'static str])> {
+    match os {
+        "linux" => {
+            let mut tools: Vec<(&'static str, &'static [&'static str])> =
+                vec![("xdotool", &["key", "--clearmodifiers", "ctrl+v"])];
+            if wayland {
+                tools.insert(0, ("wtype", &["-M", "ctrl", "v", "-m", "ctrl"]));
+            }
+            tools
+        }
+        "macos" => vec![(
+            "osascript",
+            &[
+                "-e",
+                "tell application \"System Events\" to keystroke \"v\" using command down",
+            ],
+        )],
+        "windows" => vec![(
+            "powershell",
+            &[
+                "-NoProfile",
+                "-NonInteractive",
+                "-Command",
+                "Add-Type -AssemblyName System.Windows.Forms; [Windows.Forms.SendKeys]::SendWait('^v')",
+            ],
+        )],
+        _ => Vec::new(),
+    }
+}
+
+// CopyQ can stop recording for a moment; monitoring is turned back on when
+// this guard drops, and only if it was on to begin with.
+#[derive(Debug)]
+pub struct CopyQPause;
+
+impl CopyQPause {
+    fn start() -> Option<Self> {
+        let monitoring = run_capture("copyq", &["monitoring"])?;
+        if !parse_copyq_monitoring(&monitoring) {
+            return None;
+        }
+        run_capture("copyq", &["disable"]).map(|_| CopyQPause)
+    }
+}
+
+impl Drop for CopyQPause {
+    fn drop(&mut self) {
+        let _ = run_capture("copyq", &["enable"]);
+    }
+}
+
+pub fn detect_managers() -> Vec<ClipboardManager> {
+    managers_in(&running_process_names())
+}
+
+fn managers_in(process_names: &[String]) -> Vec<ClipboardManager> {
+    ClipboardManager::ALL
+        .into_iter()
+        .filter(|manager| {
+            process_names.iter().any(|name| {
+                let name = name.trim().to_ascii_lowercase();
+                let name = name.strip_suffix(".exe").unwrap_or(&name);
+                name == manager.process_name()
+            })
+        })
+        .collect()
+}
+
+fn parse_copyq_monitoring(output: &str) -> bool {
+    matches!(output.trim(), "true" | "1")
+}
+
+fn running_process_names() -> Vec<String> {
+    if cfg!(target_os = "linux") {
+        return std::fs::read_dir("/proc")
+            .map(|entries| {
+                entries
+                    .filter_map(Result::ok)
+                    .filter_map(|entry| std::fs::read_to_string(entry.path().join("comm")).ok())
+                    .collect()
+            })
+            .unwrap_or_default();
+    }
+    let listing = if cfg!(target_os = "windows") {
+        run_capture("tasklist", &["/FO", "CSV", "/NH"])
+    } else {
+        run_capture("ps", &["-axco", "comm="])
+    };
+    listing
+        .map(|output| {
+            output
+                .lines()
+                .filter_map(|line| line.split(',').next())
+                .map(|name| name.trim().trim_matches('"').to_string())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn run_capture(program: &str, args: &[&str]) -> Option<String> {
+    let mut command = Command::new(program);
+    command
+        .args(args)
+        .stdin(Stdio::null())
+        .stderr(Stdio::null());
+
+    #[cfg(target_os = "windows")]
+    {
+        command.creation_flags(CREATE_NO_WINDOW);
+    }
+
+    let output = command.output().ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+fn transient_clipboard_tools(
+    os: &str,
+    wayland: bool,
+) -> Vec<(&'static str, &'static [&'static str])> {
+    match os {
+        "windows" => vec![(
+            "powershell",
+            &[
+                "-NoProfile",
+                "-NonInteractive",
+                "-STA",
+                "-Command",
+                WINDOWS_HIDDEN_COPY_SCRIPT,
+            ],
+        )],
+        _ => clipboard_tools(os, wayland),
+    }
+}
+
+fn copy_with_tools(
+    text: &str,
+    tools: Vec<(&'static str, &'static [&'static str])>,
+) -> Result<(), String> {
+    let mut errors = Vec::new();
+    for (program, args) in tools {
+        match pipe_to(program, args, text) {
+            Ok(()) => return Ok(()),
+            Err(error) => errors.push(error),
+        }
+    }
+
+    if errors.is_empty() {
+        Err(format!(
+            "clipboard access is not supported on {}",
+            std::env::consts::OS
+        ))
+    } else {
+        Err(errors.join("; "))
+    }
+}
+
+fn clipboard_tools(os: &str, wayland: bool) -> Vec<(&'static str, &'static [&'static str])> {
+    match os {
+        "linux" => {
+            let mut tools: Vec<(&'static str, &'static [&'static str])> = vec![
+                ("xclip", &["-selection", "clipboard"]),
+                ("xsel", &["--clipboard", "--input"]),
+            ];
+            if wayland {
+                tools.insert(0, ("wl-copy", &[]));
+            }
+            tools
+        }
+        "macos" => vec![("pbcopy", &[])],
+        "windows" => vec![(
+            "powershell",
+            &[
+                "-NoProfile",
+                "-NonInteractive",
+                "-Command",
+                "[Console]::InputEncoding = [Text.Encoding]::UTF8; Set-Clipboard -Value ([Console]::In.ReadToEnd())",
+            ],
+        )],
+        _ => Vec::new(),
+    }
+}
+
+// xclip and xsel fork a background owner that keeps the selection alive and
+// inherits our stdio, so only the exit status is read; waiting on piped output
+// would block until another app takes the clipboard.
+fn pipe_to(program: &str, args: &[&str], text: &str) -> Result<(), String> {
+    let mut command = Command::new(program);
+    command
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null());
+
+    #[cfg(target_os = "windows")]
+    {
+        command.creation_flags(CREATE_NO_WINDOW);
+    }
+
+    let mut child = command
+        .spawn()
+        .map_err(|error| format!("failed to run {program}: {error}"))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin
+            .write_all(text.as_bytes())
+            .map_err(|error| format!("failed to write to {program}: {error}"))?;
+    }
+    let status = child
+        .wait()
+        .map_err(|error| format!("failed to wait for {program}: {error}"))?;
+    if !status.success() {
+        return Err(format!("{program} exited with status {status}"));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prefers_wl_copy_on_wayland_sessions() {
+        let programs = |os, wayland| {
+            clipboard_tools(os, wayland)
+                .into_iter()
+                .map(|(program, _)| program)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(programs("linux", true), vec!["wl-copy", "xclip", "xsel"]);
+        assert_eq!(programs("linux", false), vec!["xclip", "xsel"]);
+        assert_eq!(programs("macos", false), vec!["pbcopy"]);
+        assert!(programs("plan9", false).is_empty());
+
+        let pasters = |os, wayland| {
+            paste_tools(os, wayland)
+                .into_iter()
+                .map(|(program, _)| program)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(pasters("linux", true), vec!["wtype", "xdotool"]);
+        assert_eq!(pasters("macos", false), vec!["osascript"]);
+        assert_eq!(read_tools("linux", true)[0].0, "wl-paste");
+    }
+
+    #[test]
+    fn detects_history_managers_and_hides_windows_copies() {
+        let names = ["systemd", "CopyQ", "Ditto.exe", "firefox"]
+            .map(str::to_string)
+            .to_vec();
+        assert_eq!(
+            managers_in(&names),
+            vec![ClipboardManager::CopyQ, ClipboardManager::Ditto]
+        );
+        assert!(managers_in(&["copyqd".to_string()]).is_empty());
+        assert!(parse_copyq_monitoring("true\n"));
+        assert!(!parse_copyq_monitoring("false\n"));
+
+        let (program, args) = transient_clipboard_tools("windows", false)[0];
+        assert_eq!(program, "powershell");
+        assert!(args
+            .iter()
+            .any(|arg| arg.contains("Clipboard Viewer Ignore")));
+        assert_eq!(
+            transient_clipboard_tools("linux", true),
+            clipboard_tools("linux", true)
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn does_not_wait_for_a_forked_selection_owner() {
+        use std::os::unix::fs::PermissionsExt;
+        use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("clock should be set")
+            .as_nanos();
+        let dir = std::env::temp_dir().join(format!("sonora-clipboard-{nanos}"));
+        std::fs::create_dir_all(&dir).expect("temp dir should be created");
+        let program = dir.join("fake-xclip");
+        // Like xclip: consume the text, leave an owner running, exit at once.
+        std::fs::write(&program, "#!/bin/sh\ncat >/dev/null\nsleep 10 &\nexit 0\n")
+            .expect("script should write");
+        std::fs::set_permissions(&program, std::fs::Permissions::from_mode(0o755))
+            .expect("script should be executable");
+
+        let started = Instant::now();
+        let result = pipe_to(&program.to_string_lossy(), &[], "hello");
+        let elapsed = started.elapsed();
+        let _ = std::fs::remove_dir_all(&dir);
+        assert_eq!(result, Ok(()));
+        assert!(elapsed < Duration::from_secs(5), "{elapsed:?}");
+    }
+}
@@ -0,0 +1,205 @@
+use serde::Serialize;
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+
+pub const SLOW_COMMAND_MS: u64 = 250;
+
+#[derive(Debug, Clone, Default, Serialize, PartialEq, Eq)]
+pub struct CommandStats {
+    pub name: String,
+    pub invocations: u64,
+    pub errors: u64,
+    pub total_ms: u64,
+    pub max_ms: u64,
+    pub last_error_code: Option<String>,
+}
+
+#[derive(Debug, Default)]
+pub struct CommandMetrics {
+    stats: BTreeMap<&'static str, CommandStats>,
+}
+
+impl CommandMetrics {
+    pub fn record(&mut self, name: &'static str, duration_ms: u64, error_code: Option<&str>) {
+        let entry = self.stats.entry(name).or_insert_with(|| CommandStats {
+            name: name.to_string(),
+            ..CommandStats::default()
+        });
+        entry.invocations = entry.invocations.saturating_add(1);
+        entry.total_ms = entry.total_ms.saturating_add(duration_ms);
+        entry.max_ms = entry.max_ms.max(duration_ms);
+        if let Some(code) = error_code {
+            entry.errors = entry.errors.saturating_add(1);
+            entry.last_error_code = Some(code.to_string());
+        }
+    }
+
+    pub fn snapshot(&self) -> Vec<CommandStats> {
+        self.stats.values().cloned().collect()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct StartupPhase {
+    pub name: String,
+    pub started_ms: u64,
+    pub duration_ms: u64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, PartialEq, Eq)]
+pub struct StartupTimings {
+    pub phases: Vec<StartupPhase>,
+    pub engine_ready: bool,
+}
+
+impl StartupTimings {
+    pub fn record(&mut self, name: &str, started_ms: u64, duration_ms: u64) {
+        self.phases.retain(|phase| phase.name != name);
+        self.phases.push(StartupPhase {
+            name: name.to_string(),
+            started_ms,
+            duration_ms,
+        });
+        self.phases.sort_by_key(|phase| phase.started_ms);
+    }
+
+    pub fn total_ms(&self) -> u64 {
+        self.phases
+            .iter()
+            .map(|phase| phase.started_ms.saturating_add(phase.duration_ms))
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+// An outcome a command wants on record; the middleware logs it with the
+// invocation instead of the command appending a line of its own.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandNote {
+    pub level: &'static str,
+    pub detail: String,
+}
+
+thread_local! {
+    static PENDING_NOTE: RefCell<Option<CommandNote>> = const { RefCell::new(None) };
+}
+
+// Notes describe settings and counts, never dictated text, so they are kept in
+// privacy mode. The middleware runs the command body on the calling thread.
+pub fn note(level: &'static str, detail: impl Into<String>) {
+    PENDING_NOTE.with(|pending| {
+        *pending.borrow_mut() = Some(CommandNote {
+            level,
+            detail: detail.into(),
+        });
+    });
+}
+
+pub fn take_note() -> Option<CommandNote> {
+    PENDING_NOTE.with(|pending| pending.borrow_mut().take())
+}
+
+pub fn should_log_invocation(duration_ms: u64, failed: bool, noted: bool, verbose: bool) -> bool {
+    verbose || failed || noted || duration_ms >= SLOW_COMMAND_MS
+}
+
+pub fn format_command_log(
+    name: &str,
+    duration_ms: u64,
+    error: Option<(&str, &str)>,
+    note: Option<&str>,
+    redact_details: bool,
+) -> String {
+    match (error, note) {
+        (Some((code, _)), _) if redact_details => {
+            format!("{name} failed after {duration_ms} ms [{code}]")
+        }
+        (Some((code, message)), _) => {
+            format!("{name} failed after {duration_ms} ms [{code}]: {message}")
+        }
+        (None, Some(note)) => format!("{name} completed in {duration_ms} ms: {note}"),
+        (None, None) => format!("{name} completed in {duration_ms} ms"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn aggregates_invocations_and_errors_per_command() {
+        let mut metrics = CommandMetrics::default();
+        metrics.record("phase2_get_settings", 4, None);
+        metrics.record("phase2_get_settings", 10, Some("state_unavailable"));
+        metrics.record("phase1_get_status", 1, None);
+
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot.len(), 2);
+        assert_eq!(snapshot[0].name, "phase1_get_status");
+        assert_eq!(snapshot[1].invocations, 2);
+        assert_eq!(snapshot[1].errors, 1);
+        assert_eq!(snapshot[1].total_ms, 14);
+        assert_eq!(snapshot[1].max_ms, 10);
+        assert_eq!(
+            snapshot[1].last_error_code.as_deref(),
+            Some("state_unavailable")
+        );
+    }
+
+    #[test]
+    fn orders_startup_phases_and_reports_total() {
+        let mut timings = StartupTimings::default();
+        timings.record("engine.warmup", 40, 900);
+        timings.record("state.load", 0, 12);
+        timings.record("setup", 15, 3);
+        timings.record("setup", 15, 5);
+
+        let names = timings
+            .phases
+            .iter()
+            .map(|phase| phase.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["state.load", "setup", "engine.warmup"]);
+        assert_eq!(timings.phases[1].duration_ms, 5);
+        assert_eq!(timings.total_ms(), 940);
+    }
+
+    #[test]
+    fn logs_only_failures_notes_and_slow_commands_unless_verbose() {
+        assert!(!should_log_invocation(3, false, false, false));
+        assert!(should_log_invocation(3, true, false, false));
+        assert!(should_log_invocation(3, false, true, false));
+        assert!(should_log_invocation(SLOW_COMMAND_MS, false, false, false));
+        assert!(should_log_invocation(0, false, false, true));
+
+        note("info", "set model path");
+        assert_eq!(
+            take_note(),
+            Some(CommandNote {
+                level: "info",
+                detail: "set model path".to_string(),
+            })
+        );
+        assert_eq!(take_note(), None);
+    }
+
+    #[test]
+    fn redacts_error_details_in_privacy_mode() {
+        let error = Some(("insertion", "could not type 'secret text'"));
+        assert_eq!(
+            format_command_log("phase2_insert_text", 12, error, None, true),
+            "phase2_insert_text failed after 12 ms [insertion]"
+        );
+        assert!(
+            format_command_log("phase2_insert_text", 12, error, None, false).contains("secret")
+        );
+        assert_eq!(
+            format_command_log("phase1_get_status", 1, None, None, false),
+            "phase1_get_status completed in 1 ms"
+        );
+        assert_eq!(
+            format_command_log("phase1_panic", 2, None, Some("capture stopped"), true),
+            "phase1_panic completed in 2 ms: capture stopped"
+        );
+    }
+}
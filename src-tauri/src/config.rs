@@ -20,6 +20,9 @@ pub enum SttEngine {
     WhisperCpp,
     FasterWhisper,
     Parakeet,
+    // Canned transcripts with no model or sidecar, so onboarding and the UI
+    // flows can run on a bare machine.
+    Demo,
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
@@ -47,6 +50,278 @@ pub enum ParakeetComputeType {
     Float32,
 }
 
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CasingMode {
+    #[default]
+    Sentence,
+    Plain,
+    Lowercase,
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum LongTranscriptBehavior {
+    #[default]
+    Split,
+    Truncate,
+}
+
+// How much transcript history, recordings and logs are kept on disk.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RetentionMode {
+    #[default]
+    KeepAll,
+    KeepDays,
+    KeepEntries,
+    KeepNothing,
+}
+
+// What live capture does while other audio plays through the speakers, so the
+// far end of a call leaking into an open mic is not transcribed.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PlaybackGuard {
+    #[default]
+    Off,
+    RaiseVad,
+    Pause,
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum InsertionVerification {
+    #[default]
+    Off,
+    Canary,
+    Readback,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ScrubPreset {
+    CreditCard,
+    Ssn,
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ScrubAction {
+    #[default]
+    Mask,
+    Block,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ScrubbingSettings {
+    #[serde(default)]
+    pub presets: Vec<ScrubPreset>,
+    #[serde(default)]
+    pub custom_patterns: Vec<String>,
+    #[serde(default)]
+    pub action: ScrubAction,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AppRule {
+    pub app_name: String,
+    #[serde(default)]
+    pub casing: CasingMode,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PreprocessStage {
+    HighPass,
+    Denoise,
+    Agc,
+    Normalize,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PreprocessStep {
+    pub stage: PreprocessStage,
+    pub enabled: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PreprocessingChains {
+    #[serde(default = "default_preprocessing_chain")]
+    pub fast: Vec<PreprocessStep>,
+    #[serde(default = "default_preprocessing_chain")]
+    pub balanced: Vec<PreprocessStep>,
+}
+
+impl PreprocessingChains {
+    pub fn for_profile(&self, profile: ModelProfile) -> &[PreprocessStep] {
+        match profile {
+            ModelProfile::Fast => &self.fast,
+            ModelProfile::Balanced => &self.balanced,
+        }
+    }
+}
+
+impl Default for PreprocessingChains {
+    fn default() -> Self {
+        Self {
+            fast: default_preprocessing_chain(),
+            balanced: default_preprocessing_chain(),
+        }
+    }
+}
+
+// Denoise only acts once a noise profile has been calibrated, so it is on by default.
+pub fn default_preprocessing_chain() -> Vec<PreprocessStep> {
+    [
+        (PreprocessStage::HighPass, false),
+        (PreprocessStage::Denoise, true),
+        (PreprocessStage::Agc, false),
+        (PreprocessStage::Normalize, false),
+    ]
+    .into_iter()
+    .map(|(stage, enabled)| PreprocessStep { stage, enabled })
+    .collect()
+}
+
+// A user entry layered over a language pack's spoken phrases. It replaces the
+// built-in phrase of the same wording or adds a new one; an empty replacement
+// switches the built-in phrase off.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SpokenPhraseOverride {
+    pub language: String,
+    pub phrase: String,
+    pub replacement: String,
+}
+
+// Live chunks always run on the configured engine. With routing on, an
+// utterance whose buffered audio runs past `short_max_ms` is transcribed again
+// as a whole by the long engine when it finalizes.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct UtteranceRouting {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default = "default_routing_short_max_ms")]
+    pub short_max_ms: u32,
+    // None keeps the live engine and only switches the model profile.
+    #[serde(default)]
+    pub long_engine: Option<SttEngine>,
+    #[serde(default = "default_routing_long_model_profile")]
+    pub long_model_profile: ModelProfile,
+}
+
+impl Default for UtteranceRouting {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            short_max_ms: default_routing_short_max_ms(),
+            long_engine: None,
+            long_model_profile: default_routing_long_model_profile(),
+        }
+    }
+}
+
+fn default_routing_short_max_ms() -> u32 {
+    3_000
+}
+
+fn default_routing_long_model_profile() -> ModelProfile {
+    ModelProfile::Balanced
+}
+
+// Scheduling hints for live capture. The capture thread drains the device
+// callback and drops audio if it stalls, so it outranks inference.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct ThreadPriorities {
+    pub boost_capture: bool,
+    // Lowering is one-way on Linux without CAP_SYS_NICE; turning it off takes
+    // effect on the next launch.
+    pub lower_inference: bool,
+}
+
+impl Default for ThreadPriorities {
+    fn default() -> Self {
+        Self {
+            boost_capture: true,
+            lower_inference: true,
+        }
+    }
+}
+
+// When an exact repeat of the previous transcript is dropped. Engines often
+// re-emit the same text for the same audio, but a phrase said twice on purpose
+// should still come through.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct DuplicateSuppression {
+    // Repeats within this long of the previous sighting are dropped; 0 drops
+    // them regardless of the gap.
+    pub window_ms: u32,
+    // Consecutive repeats dropped before one is let through; 0 is no limit.
+    pub max_suppressed: u8,
+}
+
+impl Default for DuplicateSuppression {
+    fn default() -> Self {
+        Self {
+            window_ms: 3_000,
+            max_suppressed: 0,
+        }
+    }
+}
+
+// Whisper can answer near-silence by repeating an earlier utterance word for
+// word. A final that matches one of the last few is dropped when little of its
+// audio was speech.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct HallucinationGuard {
+    pub enabled: bool,
+    pub recent_finals: u8,
+    pub max_speech_ratio_percent: u8,
+}
+
+impl Default for HallucinationGuard {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            recent_finals: 5,
+            max_speech_ratio_percent: 35,
+        }
+    }
+}
+
+// Serves live captions read-only to other machines on the network, such as a
+// meeting-room display. Viewers open the page with the token from the URL.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct TranscriptViewer {
+    pub enabled: bool,
+    pub port: u16,
+}
+
+impl Default for TranscriptViewer {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            port: 47_615,
+        }
+    }
+}
+
+// Keyed by device name: cpal enumeration indexes shift when devices come and go.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct MicrophoneProfile {
+    pub device_label: String,
+    #[serde(default)]
+    pub mic_sensitivity_percent: Option<u16>,
+    #[serde(default)]
+    pub input_channel: Option<u16>,
+    #[serde(default)]
+    pub playback_guard: Option<PlaybackGuard>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct AppSettings {
     pub hotkey: String,
@@ -79,6 +354,140 @@ pub struct AppSettings {
     pub vad_disabled: bool,
     #[serde(default)]
     pub vad_rms_threshold_milli: Option<u16>,
+    #[serde(default)]
+    pub chunk_normalization: bool,
+    #[serde(default = "default_max_recent_insertions")]
+    pub max_recent_insertions: u16,
+    #[serde(default)]
+    pub focus_guard_enabled: bool,
+    #[serde(default = "default_focus_guard_timeout_ms")]
+    pub focus_guard_timeout_ms: u16,
+    #[serde(default)]
+    pub privacy_mode: bool,
+    #[serde(default)]
+    pub drop_folder_path: Option<String>,
+    #[serde(default = "default_drop_folder_poll_secs")]
+    pub drop_folder_poll_secs: u16,
+    #[serde(default)]
+    pub app_rules: Vec<AppRule>,
+    #[serde(default)]
+    pub meeting_transcript_enabled: bool,
+    #[serde(default = "default_meeting_paragraph_gap_secs")]
+    pub meeting_paragraph_gap_secs: u16,
+    #[serde(default)]
+    pub captions_enabled: bool,
+    #[serde(default = "default_caption_line_chars")]
+    pub caption_line_chars: u16,
+    #[serde(default)]
+    pub predictive_preload_enabled: bool,
+    #[serde(default)]
+    pub idle_unload_minutes: u16,
+    #[serde(default)]
+    pub sidecar_memory_cap_mb: u32,
+    #[serde(default)]
+    pub sidecar_sandbox_enabled: bool,
+    #[serde(default)]
+    pub allow_path_sidecars: bool,
+    #[serde(default)]
+    pub offline_only: bool,
+    #[serde(default)]
+    pub event_prefix: Option<String>,
+    #[serde(default)]
+    pub noise_suppression_enabled: bool,
+    #[serde(default)]
+    pub punctuation_restoration: bool,
+    #[serde(default)]
+    pub max_insertion_chars: u32,
+    #[serde(default)]
+    pub long_transcript_behavior: LongTranscriptBehavior,
+    #[serde(default)]
+    pub scrubbing: ScrubbingSettings,
+    #[serde(default)]
+    pub meter_attack_ms: u16,
+    #[serde(default = "default_meter_release_ms")]
+    pub meter_release_ms: u16,
+    #[serde(default)]
+    pub meter_peak_hold_ms: u16,
+    #[serde(default)]
+    pub performance_trace: bool,
+    #[serde(default = "default_auto_downgrade_profile")]
+    pub auto_downgrade_profile: bool,
+    #[serde(default)]
+    pub spoken_punctuation: bool,
+    #[serde(default)]
+    pub faster_whisper_cache_dir: Option<String>,
+    #[serde(default)]
+    pub insertion_verification: InsertionVerification,
+    #[serde(default)]
+    pub retry_unverified_insertion: bool,
+    #[serde(default = "default_panic_hotkey")]
+    pub panic_hotkey: String,
+    #[serde(default)]
+    pub auto_insert: bool,
+    #[serde(default = "default_auto_insert_min_confidence_percent")]
+    pub auto_insert_min_confidence_percent: u8,
+    #[serde(default)]
+    pub microphone_profiles: Vec<MicrophoneProfile>,
+    #[serde(default)]
+    pub preprocessing: PreprocessingChains,
+    #[serde(default)]
+    pub shadow_engine: Option<SttEngine>,
+    #[serde(default)]
+    pub journal_directory: Option<String>,
+    #[serde(default)]
+    pub journal_profile: Option<ModelProfile>,
+    #[serde(default)]
+    pub obs_caption_file: Option<String>,
+    #[serde(default)]
+    pub instant_words: bool,
+    #[serde(default = "default_profile_toggle_hotkey")]
+    pub profile_toggle_hotkey: String,
+    #[serde(default)]
+    pub number_locale: Option<String>,
+    #[serde(default)]
+    pub voice_activation: bool,
+    #[serde(default = "default_voice_activation_silence_ms")]
+    pub voice_activation_silence_ms: u16,
+    #[serde(default)]
+    pub utterance_audio: bool,
+    #[serde(default = "default_adaptive_cadence")]
+    pub adaptive_cadence: bool,
+    #[serde(default)]
+    pub retention_mode: RetentionMode,
+    #[serde(default = "default_retention_days")]
+    pub retention_days: u16,
+    #[serde(default = "default_retention_entries")]
+    pub retention_entries: u16,
+    #[serde(default)]
+    pub screen_reader_announcements: bool,
+    #[serde(default)]
+    pub gpu_device_index: Option<u32>,
+    #[serde(default)]
+    pub playback_guard: PlaybackGuard,
+    #[serde(default = "default_frame_recv_timeout_ms")]
+    pub frame_recv_timeout_ms: u16,
+    #[serde(default = "default_meter_refresh_hz")]
+    pub meter_refresh_hz: u16,
+    #[serde(default)]
+    pub spoken_phrase_overrides: Vec<SpokenPhraseOverride>,
+    #[serde(default)]
+    pub utterance_routing: UtteranceRouting,
+    #[serde(default)]
+    pub auto_quantize_models: bool,
+    #[serde(default)]
+    pub thread_priorities: ThreadPriorities,
+    #[serde(default = "default_review_toasts")]
+    pub review_toasts: bool,
+    #[serde(default)]
+    pub screen_recording_transcripts: bool,
+    #[serde(default)]
+    pub duplicate_suppression: DuplicateSuppression,
+    #[serde(default)]
+    pub hallucination_guard: HallucinationGuard,
+    #[serde(default)]
+    pub transcript_viewer: TranscriptViewer,
+    #[serde(default = "default_cold_start_fast_path")]
+    pub cold_start_fast_path: bool,
     pub clipboard_fallback: bool,
     pub launch_at_startup: bool,
 }
@@ -107,6 +516,78 @@ fn default_parakeet_compute_type() -> ParakeetComputeType {
     ParakeetComputeType::Auto
 }
 
+fn default_max_recent_insertions() -> u16 {
+    3
+}
+
+fn default_focus_guard_timeout_ms() -> u16 {
+    1_500
+}
+
+fn default_drop_folder_poll_secs() -> u16 {
+    30
+}
+
+fn default_meeting_paragraph_gap_secs() -> u16 {
+    8
+}
+
+fn default_caption_line_chars() -> u16 {
+    42
+}
+
+fn default_meter_release_ms() -> u16 {
+    60
+}
+
+fn default_auto_downgrade_profile() -> bool {
+    true
+}
+
+fn default_adaptive_cadence() -> bool {
+    true
+}
+
+fn default_panic_hotkey() -> String {
+    "CtrlOrCmd+Shift+Escape".to_string()
+}
+
+fn default_auto_insert_min_confidence_percent() -> u8 {
+    60
+}
+
+fn default_profile_toggle_hotkey() -> String {
+    "CtrlOrCmd+Shift+P".to_string()
+}
+
+fn default_voice_activation_silence_ms() -> u16 {
+    1_500
+}
+
+fn default_retention_days() -> u16 {
+    30
+}
+
+fn default_retention_entries() -> u16 {
+    200
+}
+
+fn default_frame_recv_timeout_ms() -> u16 {
+    60
+}
+
+fn default_meter_refresh_hz() -> u16 {
+    30
+}
+
+fn default_review_toasts() -> bool {
+    true
+}
+
+fn default_cold_start_fast_path() -> bool {
+    true
+}
+
 impl Default for AppSettings {
     fn default() -> Self {
         Self {
@@ -128,6 +609,73 @@ impl Default for AppSettings {
             parakeet_compute_type: default_parakeet_compute_type(),
             vad_disabled: false,
             vad_rms_threshold_milli: None,
+            chunk_normalization: false,
+            max_recent_insertions: default_max_recent_insertions(),
+            focus_guard_enabled: false,
+            focus_guard_timeout_ms: default_focus_guard_timeout_ms(),
+            privacy_mode: false,
+            drop_folder_path: None,
+            drop_folder_poll_secs: default_drop_folder_poll_secs(),
+            app_rules: Vec::new(),
+            meeting_transcript_enabled: false,
+            meeting_paragraph_gap_secs: default_meeting_paragraph_gap_secs(),
+            captions_enabled: false,
+            caption_line_chars: default_caption_line_chars(),
+            predictive_preload_enabled: false,
+            idle_unload_minutes: 0,
+            sidecar_memory_cap_mb: 0,
+            sidecar_sandbox_enabled: false,
+            allow_path_sidecars: false,
+            offline_only: false,
+            event_prefix: None,
+            noise_suppression_enabled: false,
+            punctuation_restoration: false,
+            max_insertion_chars: 0,
+            long_transcript_behavior: LongTranscriptBehavior::Split,
+            scrubbing: ScrubbingSettings::default(),
+            meter_attack_ms: 0,
+            meter_release_ms: default_meter_release_ms(),
+            meter_peak_hold_ms: 0,
+            performance_trace: false,
+            auto_downgrade_profile: true,
+            spoken_punctuation: false,
+            faster_whisper_cache_dir: None,
+            insertion_verification: InsertionVerification::Off,
+            retry_unverified_insertion: false,
+            panic_hotkey: default_panic_hotkey(),
+            auto_insert: false,
+            auto_insert_min_confidence_percent: default_auto_insert_min_confidence_percent(),
+            microphone_profiles: Vec::new(),
+            preprocessing: PreprocessingChains::default(),
+            shadow_engine: None,
+            journal_directory: None,
+            journal_profile: None,
+            obs_caption_file: None,
+            instant_words: false,
+            profile_toggle_hotkey: default_profile_toggle_hotkey(),
+            number_locale: None,
+            voice_activation: false,
+            voice_activation_silence_ms: default_voice_activation_silence_ms(),
+            utterance_audio: false,
+            adaptive_cadence: default_adaptive_cadence(),
+            retention_mode: RetentionMode::KeepAll,
+            retention_days: default_retention_days(),
+            retention_entries: default_retention_entries(),
+            screen_reader_announcements: false,
+            gpu_device_index: None,
+            playback_guard: PlaybackGuard::Off,
+            frame_recv_timeout_ms: default_frame_recv_timeout_ms(),
+            meter_refresh_hz: default_meter_refresh_hz(),
+            spoken_phrase_overrides: Vec::new(),
+            utterance_routing: UtteranceRouting::default(),
+            auto_quantize_models: false,
+            thread_priorities: ThreadPriorities::default(),
+            review_toasts: default_review_toasts(),
+            screen_recording_transcripts: false,
+            duplicate_suppression: DuplicateSuppression::default(),
+            hallucination_guard: HallucinationGuard::default(),
+            transcript_viewer: TranscriptViewer::default(),
+            cold_start_fast_path: true,
             clipboard_fallback: true,
             launch_at_startup: false,
         }
@@ -167,6 +715,79 @@ mod tests {
         assert_eq!(settings.parakeet_compute_type, ParakeetComputeType::Auto);
         assert!(!settings.vad_disabled);
         assert!(settings.vad_rms_threshold_milli.is_none());
+        assert!(!settings.chunk_normalization);
+        assert_eq!(settings.max_recent_insertions, 3);
+        assert!(!settings.focus_guard_enabled);
+        assert_eq!(settings.focus_guard_timeout_ms, 1_500);
+        assert!(!settings.privacy_mode);
+        assert!(settings.drop_folder_path.is_none());
+        assert_eq!(settings.drop_folder_poll_secs, 30);
+        assert!(settings.app_rules.is_empty());
+        assert!(!settings.meeting_transcript_enabled);
+        assert_eq!(settings.meeting_paragraph_gap_secs, 8);
+        assert!(!settings.captions_enabled);
+        assert_eq!(settings.caption_line_chars, 42);
+        assert!(!settings.predictive_preload_enabled);
+        assert_eq!(settings.idle_unload_minutes, 0);
+        assert_eq!(settings.sidecar_memory_cap_mb, 0);
+        assert!(!settings.sidecar_sandbox_enabled);
+        assert!(!settings.allow_path_sidecars);
+        assert!(!settings.offline_only);
+        assert!(settings.event_prefix.is_none());
+        assert!(!settings.noise_suppression_enabled);
+        assert!(!settings.punctuation_restoration);
+        assert_eq!(settings.max_insertion_chars, 0);
+        assert_eq!(
+            settings.long_transcript_behavior,
+            LongTranscriptBehavior::Split
+        );
+        assert_eq!(settings.scrubbing, ScrubbingSettings::default());
+        assert_eq!(settings.meter_attack_ms, 0);
+        assert_eq!(settings.meter_release_ms, 60);
+        assert_eq!(settings.meter_peak_hold_ms, 0);
+        assert!(!settings.performance_trace);
+        assert!(settings.auto_downgrade_profile);
+        assert!(!settings.spoken_punctuation);
+        assert!(settings.faster_whisper_cache_dir.is_none());
+        assert_eq!(settings.insertion_verification, InsertionVerification::Off);
+        assert!(!settings.retry_unverified_insertion);
+        assert_eq!(settings.panic_hotkey, "CtrlOrCmd+Shift+Escape");
+        assert!(!settings.auto_insert);
+        assert_eq!(settings.auto_insert_min_confidence_percent, 60);
+        assert!(settings.microphone_profiles.is_empty());
+        assert_eq!(settings.preprocessing, PreprocessingChains::default());
+        assert!(settings.shadow_engine.is_none());
+        assert!(settings.journal_directory.is_none());
+        assert!(settings.journal_profile.is_none());
+        assert!(settings.obs_caption_file.is_none());
+        assert!(!settings.instant_words);
+        assert_eq!(settings.profile_toggle_hotkey, "CtrlOrCmd+Shift+P");
+        assert!(settings.number_locale.is_none());
+        assert!(!settings.voice_activation);
+        assert_eq!(settings.voice_activation_silence_ms, 1_500);
+        assert!(!settings.utterance_audio);
+        assert!(settings.adaptive_cadence);
+        assert_eq!(settings.retention_mode, RetentionMode::KeepAll);
+        assert_eq!(settings.retention_days, 30);
+        assert_eq!(settings.retention_entries, 200);
+        assert!(!settings.screen_reader_announcements);
+        assert!(settings.gpu_device_index.is_none());
+        assert_eq!(settings.playback_guard, PlaybackGuard::Off);
+        assert_eq!(settings.frame_recv_timeout_ms, 60);
+        assert_eq!(settings.meter_refresh_hz, 30);
+        assert!(settings.spoken_phrase_overrides.is_empty());
+        assert_eq!(settings.utterance_routing, UtteranceRouting::default());
+        assert!(!settings.auto_quantize_models);
+        assert_eq!(settings.thread_priorities, ThreadPriorities::default());
+        assert!(settings.review_toasts);
+        assert!(!settings.screen_recording_transcripts);
+        assert_eq!(
+            settings.duplicate_suppression,
+            DuplicateSuppression::default()
+        );
+        assert_eq!(settings.hallucination_guard, HallucinationGuard::default());
+        assert_eq!(settings.transcript_viewer, TranscriptViewer::default());
+        assert!(settings.cold_start_fast_path);
     }
 
     #[test]
@@ -202,5 +823,78 @@ mod tests {
         assert_eq!(parsed.parakeet_compute_type, ParakeetComputeType::Auto);
         assert!(!parsed.vad_disabled);
         assert!(parsed.vad_rms_threshold_milli.is_none());
+        assert!(!parsed.chunk_normalization);
+        assert_eq!(parsed.max_recent_insertions, 3);
+        assert!(!parsed.focus_guard_enabled);
+        assert_eq!(parsed.focus_guard_timeout_ms, 1_500);
+        assert!(!parsed.privacy_mode);
+        assert!(parsed.drop_folder_path.is_none());
+        assert_eq!(parsed.drop_folder_poll_secs, 30);
+        assert!(parsed.app_rules.is_empty());
+        assert!(!parsed.meeting_transcript_enabled);
+        assert_eq!(parsed.meeting_paragraph_gap_secs, 8);
+        assert!(!parsed.captions_enabled);
+        assert_eq!(parsed.caption_line_chars, 42);
+        assert!(!parsed.predictive_preload_enabled);
+        assert_eq!(parsed.idle_unload_minutes, 0);
+        assert_eq!(parsed.sidecar_memory_cap_mb, 0);
+        assert!(!parsed.sidecar_sandbox_enabled);
+        assert!(!parsed.allow_path_sidecars);
+        assert!(!parsed.offline_only);
+        assert!(parsed.event_prefix.is_none());
+        assert!(!parsed.noise_suppression_enabled);
+        assert!(!parsed.punctuation_restoration);
+        assert_eq!(parsed.max_insertion_chars, 0);
+        assert_eq!(
+            parsed.long_transcript_behavior,
+            LongTranscriptBehavior::Split
+        );
+        assert_eq!(parsed.scrubbing, ScrubbingSettings::default());
+        assert_eq!(parsed.meter_attack_ms, 0);
+        assert_eq!(parsed.meter_release_ms, 60);
+        assert_eq!(parsed.meter_peak_hold_ms, 0);
+        assert!(!parsed.performance_trace);
+        assert!(parsed.auto_downgrade_profile);
+        assert!(!parsed.spoken_punctuation);
+        assert!(parsed.faster_whisper_cache_dir.is_none());
+        assert_eq!(parsed.insertion_verification, InsertionVerification::Off);
+        assert!(!parsed.retry_unverified_insertion);
+        assert_eq!(parsed.panic_hotkey, "CtrlOrCmd+Shift+Escape");
+        assert!(!parsed.auto_insert);
+        assert_eq!(parsed.auto_insert_min_confidence_percent, 60);
+        assert!(parsed.microphone_profiles.is_empty());
+        assert_eq!(parsed.preprocessing, PreprocessingChains::default());
+        assert!(parsed.shadow_engine.is_none());
+        assert!(parsed.journal_directory.is_none());
+        assert!(parsed.journal_profile.is_none());
+        assert!(parsed.obs_caption_file.is_none());
+        assert!(!parsed.instant_words);
+        assert_eq!(parsed.profile_toggle_hotkey, "CtrlOrCmd+Shift+P");
+        assert!(parsed.number_locale.is_none());
+        assert!(!parsed.voice_activation);
+        assert_eq!(parsed.voice_activation_silence_ms, 1_500);
+        assert!(!parsed.utterance_audio);
+        assert!(parsed.adaptive_cadence);
+        assert_eq!(parsed.retention_mode, RetentionMode::KeepAll);
+        assert_eq!(parsed.retention_days, 30);
+        assert_eq!(parsed.retention_entries, 200);
+        assert!(!parsed.screen_reader_announcements);
+        assert!(parsed.gpu_device_index.is_none());
+        assert_eq!(parsed.playback_guard, PlaybackGuard::Off);
+        assert_eq!(parsed.frame_recv_timeout_ms, 60);
+        assert_eq!(parsed.meter_refresh_hz, 30);
+        assert!(parsed.spoken_phrase_overrides.is_empty());
+        assert_eq!(parsed.utterance_routing, UtteranceRouting::default());
+        assert!(!parsed.auto_quantize_models);
+        assert_eq!(parsed.thread_priorities, ThreadPriorities::default());
+        assert!(parsed.review_toasts);
+        assert!(!parsed.screen_recording_transcripts);
+        assert_eq!(
+            parsed.duplicate_suppression,
+            DuplicateSuppression::default()
+        );
+        assert_eq!(parsed.hallucination_guard, HallucinationGuard::default());
+        assert_eq!(parsed.transcript_viewer, TranscriptViewer::default());
+        assert!(parsed.cold_start_fast_path);
     }
 }
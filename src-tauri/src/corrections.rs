@@ -0,0 +1,284 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const MAX_CORRECTION_PAIRS: usize = 500;
+const MAX_EDIT_WORDS: usize = 4;
+// A fix has to recur before it is trusted enough to rewrite future transcripts.
+pub const PROMOTE_AFTER_OCCURRENCES: u32 = 2;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CorrectionPair {
+    pub original: String,
+    pub corrected: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub record_id: Option<u64>,
+    pub submitted_unix_ms: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ReplacementRule {
+    pub from: String,
+    pub to: String,
+    pub occurrences: u32,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CorrectionStore {
+    #[serde(default)]
+    pub pairs: Vec<CorrectionPair>,
+    #[serde(default)]
+    pub rules: Vec<ReplacementRule>,
+    // Terms whose only fix was casing; fed to the biasing prompt and term corrections.
+    #[serde(default)]
+    pub vocabulary: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, PartialEq, Eq)]
+pub struct CorrectionOutcome {
+    pub edits: Vec<(String, String)>,
+    pub new_rules: Vec<ReplacementRule>,
+    pub new_vocabulary: Vec<String>,
+}
+
+impl CorrectionStore {
+    pub fn submit(&mut self, pair: CorrectionPair) -> CorrectionOutcome {
+        let edits = word_edits(&pair.original, &pair.corrected);
+        self.pairs.insert(0, pair);
+        self.pairs.truncate(MAX_CORRECTION_PAIRS);
+
+        let mut outcome = CorrectionOutcome {
+            edits: edits.clone(),
+            ..CorrectionOutcome::default()
+        };
+        for (from, to) in edits {
+            let occurrences = self
+                .pairs
+                .iter()
+                .filter(|pair| {
+                    word_edits(&pair.original, &pair.corrected)
+                        .iter()
+                        .any(|edit| edit_key(edit) == edit_key(&(from.clone(), to.clone())))
+                })
+                .count() as u32;
+            if occurrences < PROMOTE_AFTER_OCCURRENCES {
+                continue;
+            }
+
+            if from.to_lowercase() == to.to_lowercase() {
+                if !self.vocabulary.contains(&to) {
+                    self.vocabulary.push(to.clone());
+                    outcome.new_vocabulary.push(to);
+                }
+                continue;
+            }
+
+            let from_key = from.to_lowercase();
+            match self
+                .rules
+                .iter_mut()
+                .find(|rule| rule.from.to_lowercase() == from_key)
+            {
+                Some(rule) => {
+                    let changed = rule.to != to;
+                    rule.to = to;
+                    rule.occurrences = occurrences;
+                    if changed {
+                        outcome.new_rules.push(rule.clone());
+                    }
+                }
+                None => {
+                    let rule = ReplacementRule {
+                        from,
+                        to,
+                        occurrences,
+                    };
+                    self.rules.push(rule.clone());
+                    outcome.new_rules.push(rule);
+                }
+            }
+        }
+        outcome
+    }
+
+    pub fn remove_rule(&mut self, from: &str) -> Option<ReplacementRule> {
+        let index = self
+            .rules
+            .iter()
+            .position(|rule| rule.from.eq_ignore_ascii_case(from))?;
+        Some(self.rules.remove(index))
+    }
+}
+
+// Changed word spans between the two texts, found via the longest common
+// subsequence of words. Long rewrites are skipped since they are not reusable.
+pub fn word_edits(original: &str, corrected: &str) -> Vec<(String, String)> {
+    let before = original.split_whitespace().collect::<Vec<_>>();
+    let after = corrected.split_whitespace().collect::<Vec<_>>();
+
+    let mut lengths = vec![vec![0usize; after.len() + 1]; before.len() + 1];
+    for i in (0..before.len()).rev() {
+        for j in (0..after.len()).rev() {
+            lengths[i][j] = if before[i] == after[j] {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+
+    let mut edits = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    let (mut span_i, mut span_j) = (0, 0);
+    while i < before.len() || j < after.len() {
+        let same = i < before.len() && j < after.len() && before[i] == after[j];
+        if same {
+            push_edit(&mut edits, &before[span_i..i], &after[span_j..j]);
+            i += 1;
+            j += 1;
+            span_i = i;
+            span_j = j;
+        } else if j < after.len() && (i == before.len() || lengths[i][j + 1] >= lengths[i + 1][j]) {
+            j += 1;
+        } else {
+            i += 1;
+        }
+    }
+    push_edit(&mut edits, &before[span_i..], &after[span_j..]);
+    edits
+}
+
+fn push_edit(edits: &mut Vec<(String, String)>, from: &[&str], to: &[&str]) {
+    if from.is_empty() || to.is_empty() || from.len() > MAX_EDIT_WORDS || to.len() > MAX_EDIT_WORDS
+    {
+        return;
+    }
+    let from = strip_edge_punctuation(&from.join(" "));
+    let to = strip_edge_punctuation(&to.join(" "));
+    if !from.is_empty() && !to.is_empty() && from != to {
+        edits.push((from, to));
+    }
+}
+
+pub fn apply_rules(text: &str, rules: &[ReplacementRule]) -> String {
+    let mut output = text.to_string();
+    for rule in rules {
+        output = replace_phrase(&output, &rule.from, &rule.to);
+    }
+    output
+}
+
+fn replace_phrase(text: &str, from: &str, to: &str) -> String {
+    let haystack = text.to_lowercase();
+    let needle = from.to_lowercase();
+    // Lowercasing can change byte lengths outside ASCII; fall back to leaving the text alone.
+    if needle.is_empty() || haystack.len() != text.len() {
+        return text.to_string();
+    }
+    let mut output = String::with_capacity(text.len());
+    let mut cursor = 0;
+    while let Some(offset) = haystack[cursor..].find(&needle) {
+        let start = cursor + offset;
+        let end = start + needle.len();
+        output.push_str(&text[cursor..start]);
+        let before = text[..start].chars().next_back();
+        let after = text[end..].chars().next();
+        if before.is_some_and(char::is_alphanumeric) || after.is_some_and(char::is_alphanumeric) {
+            output.push_str(&text[start..end]);
+        } else {
+            output.push_str(to);
+        }
+        cursor = end;
+    }
+    output.push_str(&text[cursor..]);
+    output
+}
+
+fn edit_key(edit: &(String, String)) -> (String, String) {
+    (edit.0.to_lowercase(), edit.1.clone())
+}
+
+fn strip_edge_punctuation(text: &str) -> String {
+    text.trim_matches(|ch: char| ch.is_ascii_punctuation() && ch != '\'' && ch != '-')
+        .to_string()
+}
+
+pub fn default_corrections_path() -> PathBuf {
+    let base = dirs::config_dir().unwrap_or_else(|| PathBuf::from("."));
+    base.join("sonora-dictation").join("corrections.json")
+}
+
+pub fn load_or_default(path: &Path) -> CorrectionStore {
+    match fs::read_to_string(path) {
+        Ok(contents) => serde_json::from_str::<CorrectionStore>(&contents).unwrap_or_default(),
+        Err(_) => CorrectionStore::default(),
+    }
+}
+
+pub fn save(path: &Path, store: &CorrectionStore) -> Result<(), String> {
+    let parent = path
+        .parent()
+        .ok_or_else(|| "corrections path has no parent directory".to_string())?;
+    fs::create_dir_all(parent).map_err(io_to_string)?;
+    let payload = serde_json::to_string_pretty(store).map_err(|error| error.to_string())?;
+    fs::write(path, payload).map_err(io_to_string)
+}
+
+fn io_to_string(error: io::Error) -> String {
+    error.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pair(original: &str, corrected: &str) -> CorrectionPair {
+        CorrectionPair {
+            original: original.to_string(),
+            corrected: corrected.to_string(),
+            record_id: None,
+            submitted_unix_ms: 0,
+        }
+    }
+
+    #[test]
+    fn recurring_fixes_become_rules_and_vocabulary() {
+        let mut store = CorrectionStore::default();
+        let first = store.submit(pair(
+            "Deploy to cube nettys today.",
+            "Deploy to Kubernetes today.",
+        ));
+        assert_eq!(
+            first.edits,
+            vec![("cube nettys".to_string(), "Kubernetes".to_string())]
+        );
+        assert!(first.new_rules.is_empty());
+
+        let second = store.submit(pair("Is cube nettys up?", "Is Kubernetes up?"));
+        assert_eq!(second.new_rules.len(), 1);
+        assert_eq!(
+            apply_rules("restart Cube Nettys now", &store.rules),
+            "restart Kubernetes now"
+        );
+
+        store.submit(pair("ask sonora", "ask Sonora"));
+        let casing = store.submit(pair("sonora is open.", "Sonora is open."));
+        assert_eq!(casing.new_vocabulary, vec!["Sonora".to_string()]);
+        assert!(store.rules.iter().all(|rule| rule.to != "Sonora"));
+    }
+
+    #[test]
+    fn long_rewrites_are_stored_but_never_promoted() {
+        let mut store = CorrectionStore::default();
+        for _ in 0..3 {
+            store.submit(pair(
+                "please send it tomorrow",
+                "we will ship the whole package next week instead",
+            ));
+        }
+        assert_eq!(store.pairs.len(), 3);
+        assert!(store.rules.is_empty());
+        assert_eq!(apply_rules("subcube nettys", &[]), "subcube nettys");
+    }
+}
@@ -0,0 +1,65 @@
+use zbus::blocking::connection::Builder;
+use zbus::blocking::Connection;
+use zbus::interface;
+use zbus::object_server::SignalEmitter;
+
+pub const BUS_NAME: &str = "com.sonora.Dictation";
+pub const OBJECT_PATH: &str = "/com/sonora/Dictation";
+pub const INTERFACE_NAME: &str = "com.sonora.Dictation1";
+
+type ToggleHandler = Box<dyn Fn() + Send + Sync>;
+type StateHandler = Box<dyn Fn() -> String + Send + Sync>;
+
+pub struct DictationInterface {
+    toggle: ToggleHandler,
+    state: StateHandler,
+}
+
+#[interface(name = "com.sonora.Dictation1")]
+impl DictationInterface {
+    fn toggle_dictation(&self) {
+        (self.toggle)();
+    }
+
+    fn get_state(&self) -> String {
+        (self.state)()
+    }
+
+    #[zbus(signal)]
+    pub async fn last_transcript(emitter: &SignalEmitter<'_>, text: &str) -> zbus::Result<()>;
+}
+
+pub struct DbusService {
+    connection: Connection,
+}
+
+impl DbusService {
+    pub fn start(
+        toggle: impl Fn() + Send + Sync + 'static,
+        state: impl Fn() -> String + Send + Sync + 'static,
+    ) -> Result<Self, String> {
+        let interface = DictationInterface {
+            toggle: Box::new(toggle),
+            state: Box::new(state),
+        };
+        let connection = Builder::session()
+            .and_then(|builder| builder.name(BUS_NAME))
+            .and_then(|builder| builder.serve_at(OBJECT_PATH, interface))
+            .and_then(|builder| builder.build())
+            .map_err(|error| format!("failed to register D-Bus service {BUS_NAME}: {error}"))?;
+
+        Ok(Self { connection })
+    }
+
+    pub fn emit_last_transcript(&self, text: &str) -> Result<(), String> {
+        self.connection
+            .emit_signal(
+                None::<&str>,
+                OBJECT_PATH,
+                INTERFACE_NAME,
+                "LastTranscript",
+                &(text,),
+            )
+            .map_err(|error| error.to_string())
+    }
+}
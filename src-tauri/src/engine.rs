@@ -0,0 +1,289 @@
+// Tauri-free embedding facade over the pipeline; see "Embedding the engine" in the README.
+use std::path::{Path, PathBuf};
+
+use crate::audio;
+use crate::config::{self, AppSettings, SttEngine};
+use crate::language_pack;
+use crate::pipeline::{DictationPipeline, DictationState, PipelineStatus};
+use crate::postprocess::{normalize_transcript_with_terms, NormalizationProfile};
+use crate::profile;
+use crate::transcriber::{
+    build_runtime_engine, default_faster_whisper_model, default_parakeet_model, EngineSpec,
+    RuntimeEngineDiagnostics, RuntimeTranscriber, Transcriber,
+};
+use crate::vad::VadConfig;
+use crate::vocabulary;
+
+pub fn resolve_engine_model_path(settings: &AppSettings, resource_dir: Option<&Path>) -> PathBuf {
+    match settings.stt_engine {
+        SttEngine::WhisperCpp => profile::resolve_model_path(settings, resource_dir),
+        SttEngine::FasterWhisper => settings
+            .faster_whisper_model
+            .as_ref()
+            .map(|value| value.trim())
+            .filter(|value| !value.is_empty())
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from(default_faster_whisper_model(settings.model_profile))),
+        SttEngine::Parakeet => settings
+            .parakeet_model
+            .as_ref()
+            .map(|value| value.trim())
+            .filter(|value| !value.is_empty())
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from(default_parakeet_model(settings.model_profile))),
+        SttEngine::Demo => PathBuf::from("demo"),
+    }
+}
+
+pub fn engine_spec_for_settings(
+    settings: &AppSettings,
+    resource_dir: Option<PathBuf>,
+) -> EngineSpec {
+    EngineSpec {
+        engine: settings.stt_engine,
+        language: settings.language.clone(),
+        model_profile: settings.model_profile,
+        model_path: resolve_engine_model_path(settings, resource_dir.as_deref()),
+        whisper_backend_preference: settings.whisper_backend_preference,
+        faster_whisper_compute_type: settings.faster_whisper_compute_type,
+        faster_whisper_beam_size: settings.faster_whisper_beam_size,
+        faster_whisper_cache_dir: settings
+            .faster_whisper_cache_dir
+            .as_ref()
+            .map(PathBuf::from),
+        parakeet_compute_type: settings.parakeet_compute_type,
+        resource_dir,
+        sandbox_sidecars: settings.sidecar_sandbox_enabled,
+        allow_path_sidecars: settings.allow_path_sidecars,
+        offline_only: settings.offline_only,
+        punctuation_restoration: settings.punctuation_restoration,
+        gpu_device_index: settings.gpu_device_index,
+        translate: false,
+    }
+}
+
+pub fn vad_config_for_settings(settings: &AppSettings) -> VadConfig {
+    let mut config = VadConfig::default();
+    config.enabled = !settings.vad_disabled;
+
+    if let Some(threshold_milli) = settings.vad_rms_threshold_milli {
+        let clamped = threshold_milli.clamp(1, 80);
+        config.rms_threshold = clamped as f32 / 1000.0;
+    }
+
+    config
+}
+
+// chunk_normalization predates the chain and still switches its normalize stage on.
+pub fn preprocessing_for_settings(settings: &AppSettings) -> Vec<config::PreprocessStep> {
+    let mut steps = settings
+        .preprocessing
+        .for_profile(settings.model_profile)
+        .to_vec();
+    if settings.chunk_normalization {
+        match steps
+            .iter_mut()
+            .find(|step| step.stage == config::PreprocessStage::Normalize)
+        {
+            Some(step) => step.enabled = true,
+            None => steps.push(config::PreprocessStep {
+                stage: config::PreprocessStage::Normalize,
+                enabled: true,
+            }),
+        }
+    }
+    steps
+}
+
+pub fn pipeline_for_settings<T: Transcriber>(
+    settings: &AppSettings,
+    transcriber: T,
+) -> DictationPipeline<T> {
+    let mut pipeline = DictationPipeline::new(settings.mode, settings.model_profile, transcriber);
+    pipeline.set_tuning(profile::tuning_for_settings(settings));
+    pipeline.set_vad_config(vad_config_for_settings(settings));
+    pipeline.set_preprocessing(preprocessing_for_settings(settings));
+    pipeline
+}
+
+pub struct DictationEngine<T: Transcriber = RuntimeTranscriber> {
+    pipeline: DictationPipeline<T>,
+    diagnostics: Option<RuntimeEngineDiagnostics>,
+    normalization: NormalizationProfile,
+    terms: Vec<String>,
+    context: Option<String>,
+    // Audio fed since the last chunk, already at 16 kHz.
+    pending: Vec<f32>,
+}
+
+impl DictationEngine<RuntimeTranscriber> {
+    // Resolves the engine, model and sidecar the same way the desktop app does;
+    // check `diagnostics()` for why an engine is not ready.
+    pub fn from_settings(settings: &AppSettings, resource_dir: Option<&Path>) -> Self {
+        let runtime = build_runtime_engine(engine_spec_for_settings(
+            settings,
+            resource_dir.map(Path::to_path_buf),
+        ));
+        let mut engine = Self::with_transcriber(settings, runtime.transcriber);
+        engine.diagnostics = Some(runtime.diagnostics);
+        engine
+    }
+}
+
+impl<T: Transcriber> DictationEngine<T> {
+    pub fn with_transcriber(settings: &AppSettings, transcriber: T) -> Self {
+        Self {
+            pipeline: pipeline_for_settings(settings, transcriber),
+            diagnostics: None,
+            normalization: language_pack::normalization_for(Some(&settings.language)),
+            terms: Vec::new(),
+            context: None,
+            pending: Vec::new(),
+        }
+    }
+
+    pub fn diagnostics(&self) -> Option<&RuntimeEngineDiagnostics> {
+        self.diagnostics.as_ref()
+    }
+
+    pub fn status(&self) -> PipelineStatus {
+        self.pipeline.status()
+    }
+
+    // Loads the model ahead of the first chunk so it does not pay the warm-up.
+    pub fn prepare(&self) -> Result<(), String> {
+        self.pipeline.prepare_transcriber()
+    }
+
+    pub fn unload(&self) {
+        self.pipeline.unload_transcriber();
+    }
+
+    pub fn set_context(&mut self, context: Option<&str>) {
+        self.context = context.map(str::to_string);
+        self.refresh_prompt();
+    }
+
+    fn refresh_prompt(&mut self) {
+        let prompt = vocabulary::biasing_prompt(&self.terms, self.context.as_deref());
+        self.pipeline.set_stream_context(prompt.as_deref());
+    }
+
+    // Names and jargon to bias the engine toward, also restored to their exact
+    // casing in the output.
+    pub fn set_vocabulary(&mut self, terms: Vec<String>) {
+        self.terms = vocabulary::normalize_terms(terms);
+        self.refresh_prompt();
+    }
+
+    pub fn start(&mut self) {
+        self.pending.clear();
+        if self.pipeline.status().state == DictationState::Idle {
+            self.pipeline.on_hotkey_down();
+        }
+    }
+
+    // Accepts mono audio at any rate. Returns text once enough audio has
+    // collected for a chunk and it held speech.
+    pub fn feed(&mut self, samples: &[f32], sample_rate_hz: u32) -> Result<Option<String>, String> {
+        self.pending
+            .extend(audio::downsample_to_16k(samples, sample_rate_hz));
+        if self.pending.len() < self.pipeline.status().tuning.min_chunk_samples {
+            return Ok(None);
+        }
+        let chunk = std::mem::take(&mut self.pending);
+        self.transcribe(&chunk)
+    }
+
+    // Transcribes whatever is still buffered and returns to idle.
+    pub fn stop(&mut self) -> Result<Option<String>, String> {
+        let chunk = std::mem::take(&mut self.pending);
+        let transcript = if chunk.is_empty() {
+            Ok(None)
+        } else {
+            self.transcribe_remainder(&chunk)
+        };
+        self.pipeline.cancel();
+        transcript
+    }
+
+    // One-shot transcription of a complete recording.
+    pub fn transcribe_clip(
+        &mut self,
+        samples: &[f32],
+        sample_rate_hz: u32,
+    ) -> Result<Option<String>, String> {
+        self.start();
+        let clip = audio::downsample_to_16k(samples, sample_rate_hz);
+        let transcript = self.transcribe_remainder(&clip);
+        self.pipeline.cancel();
+        transcript
+    }
+
+    fn transcribe(&mut self, chunk: &[f32]) -> Result<Option<String>, String> {
+        let transcript = self.pipeline.process_audio_chunk(chunk)?;
+        Ok(transcript
+            .map(|text| normalize_transcript_with_terms(&text, self.normalization, &self.terms))
+            .filter(|text| !text.is_empty()))
+    }
+
+    // The pipeline skips chunks below the profile minimum, so a short tail is
+    // padded with silence rather than lost.
+    fn transcribe_remainder(&mut self, chunk: &[f32]) -> Result<Option<String>, String> {
+        let min_chunk_samples = self.pipeline.status().tuning.min_chunk_samples;
+        if chunk.len() >= min_chunk_samples {
+            return self.transcribe(chunk);
+        }
+        let mut padded = chunk.to_vec();
+        padded.resize(min_chunk_samples, 0.0);
+        self.transcribe(&padded)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transcriber::StubTranscriber;
+
+    fn speech(samples: usize) -> Vec<f32> {
+        (0..samples).map(|i| (i as f32 * 0.1).sin() * 0.2).collect()
+    }
+
+    #[test]
+    fn feeds_resampled_audio_and_flushes_the_tail_on_stop() {
+        let settings = AppSettings {
+            model_profile: config::ModelProfile::Fast,
+            ..AppSettings::default()
+        };
+        let mut engine = DictationEngine::with_transcriber(&settings, StubTranscriber);
+        engine.start();
+        assert_eq!(engine.status().state, DictationState::Listening);
+
+        // Half a second at 48 kHz is below the Fast profile's one-second chunk.
+        assert_eq!(engine.feed(&speech(24_000), 48_000), Ok(None));
+        assert_eq!(
+            engine.feed(&speech(24_000), 48_000),
+            Ok(Some("Phase-1 transcript.".to_string()))
+        );
+
+        engine.set_vocabulary(vec!["PHASE-1".to_string()]);
+        assert!(engine.feed(&speech(4_000), 16_000).expect("feed").is_none());
+        assert_eq!(engine.stop(), Ok(Some("PHASE-1 transcript.".to_string())));
+        assert_eq!(engine.status().state, DictationState::Idle);
+    }
+
+    #[test]
+    fn builds_an_unavailable_engine_without_a_model() {
+        let settings = AppSettings {
+            model_path: Some("/nonexistent/sonora-model.bin".to_string()),
+            ..AppSettings::default()
+        };
+        let mut engine = DictationEngine::from_settings(&settings, None);
+        let diagnostics = engine
+            .diagnostics()
+            .expect("runtime engine reports diagnostics");
+        assert!(!diagnostics.ready);
+        assert!(!diagnostics.model_exists);
+        assert!(engine.transcribe_clip(&speech(16_000), 16_000).is_err());
+    }
+}
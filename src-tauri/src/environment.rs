@@ -1,5 +1,8 @@
 use serde::Serialize;
 
+use crate::keyboard_layout::{self, KeyboardLayout};
+use crate::sandbox;
+
 #[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum SessionType {
@@ -21,6 +24,7 @@ pub struct EnvironmentHealth {
     pub os: String,
     pub session_type: SessionType,
     pub input_injection_permission: PermissionState,
+    pub keyboard_layout: Option<KeyboardLayout>,
     pub notes: Vec<String>,
 }
 
@@ -32,7 +36,9 @@ pub fn session_type_from_env(value: Option<&str>) -> SessionType {
     }
 }
 
-pub fn detect_environment_health() -> EnvironmentHealth {
+// `keyboard_layout` is the layout the focus watcher cached; it is probed here
+// only when there is none yet.
+pub fn detect_environment_health(keyboard_layout: Option<KeyboardLayout>) -> EnvironmentHealth {
     let os = std::env::consts::OS.to_string();
     let session_type = session_type_from_env(std::env::var("XDG_SESSION_TYPE").ok().as_deref());
 
@@ -45,14 +51,45 @@ pub fn detect_environment_health() -> EnvironmentHealth {
         );
     }
 
+    let keyboard_layout =
+        keyboard_layout.or_else(|| keyboard_layout::detect_keyboard_layout().ok());
+    notes.push(keyboard_layout_note(keyboard_layout.as_ref()));
+
+    match sandbox::detect_sandbox_tool() {
+        Some(tool) => notes.push(format!(
+            "Sidecar sandboxing is available via {}.",
+            tool.description()
+        )),
+        None => notes.push(
+            "Sidecar sandboxing is not available on this system; with it turned on, transcription workers will not start."
+                .to_string(),
+        ),
+    }
+
     EnvironmentHealth {
         os,
         session_type,
         input_injection_permission: permission,
+        keyboard_layout,
         notes,
     }
 }
 
+fn keyboard_layout_note(layout: Option<&KeyboardLayout>) -> String {
+    match layout {
+        Some(layout) if layout.us_qwerty => format!(
+            "Keyboard layout {} detected; ASCII text is typed with scancodes.",
+            layout.name
+        ),
+        Some(layout) => format!(
+            "Keyboard layout {} detected; direct insertion uses unicode injection.",
+            layout.name
+        ),
+        None => "Keyboard layout could not be detected; direct insertion uses unicode injection."
+            .to_string(),
+    }
+}
+
 fn permission_and_notes_for_os(
     os: &str,
     session_type: SessionType,
@@ -0,0 +1,173 @@
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+use std::fmt;
+
+use crate::mic_availability::{classify_stream_error, MicUnavailable, MicUnavailableReason};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SonoraError {
+    StateUnavailable(&'static str),
+    InvalidInput(String),
+    NotFound(String),
+    PermissionDenied(String),
+    BinaryMissing(String),
+    ModelMissing(String),
+    Io(String),
+    Audio(String),
+    MicUnavailable(MicUnavailable),
+    Transcription(String),
+    Insertion(String),
+    Unsupported(String),
+    Internal(String),
+}
+
+impl SonoraError {
+    pub fn state_unavailable(state: &'static str) -> Self {
+        Self::StateUnavailable(state)
+    }
+
+    pub fn io(message: impl Into<String>) -> Self {
+        Self::Io(message.into())
+    }
+
+    // Device and file errors only come back as text; a refusal by the OS is
+    // told apart so the UI can point at the privacy settings.
+    pub fn audio(message: impl Into<String>) -> Self {
+        let message = message.into();
+        match classify_stream_error(&message) {
+            MicUnavailableReason::PermissionDenied => Self::PermissionDenied(message),
+            _ => Self::Audio(message),
+        }
+    }
+
+    pub fn transcription(message: impl Into<String>) -> Self {
+        Self::Transcription(message.into())
+    }
+
+    pub fn internal(message: impl Into<String>) -> Self {
+        Self::Internal(message.into())
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::StateUnavailable(_) => "state_unavailable",
+            Self::InvalidInput(_) => "invalid_input",
+            Self::NotFound(_) => "not_found",
+            Self::PermissionDenied(_) => "permission_denied",
+            Self::BinaryMissing(_) => "binary_missing",
+            Self::ModelMissing(_) => "model_missing",
+            Self::Io(_) => "io",
+            Self::Audio(_) => "audio",
+            Self::MicUnavailable(_) => "mic_unavailable",
+            Self::Transcription(_) => "transcription",
+            Self::Insertion(_) => "insertion",
+            Self::Unsupported(_) => "unsupported",
+            Self::Internal(_) => "internal",
+        }
+    }
+}
+
+impl fmt::Display for SonoraError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::StateUnavailable(state) => write!(formatter, "failed to acquire {state} state"),
+            Self::MicUnavailable(report) => formatter.write_str(&report.summary()),
+            Self::InvalidInput(message)
+            | Self::NotFound(message)
+            | Self::PermissionDenied(message)
+            | Self::BinaryMissing(message)
+            | Self::ModelMissing(message)
+            | Self::Io(message)
+            | Self::Audio(message)
+            | Self::Transcription(message)
+            | Self::Insertion(message)
+            | Self::Unsupported(message)
+            | Self::Internal(message) => formatter.write_str(message),
+        }
+    }
+}
+
+impl std::error::Error for SonoraError {}
+
+impl Serialize for SonoraError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // The mic report rides along so the UI can tell a busy device from a missing one.
+        let mic = match self {
+            Self::MicUnavailable(report) => Some(report),
+            _ => None,
+        };
+        let mut state =
+            serializer.serialize_struct("SonoraError", 2 + usize::from(mic.is_some()))?;
+        state.serialize_field("code", self.code())?;
+        state.serialize_field("message", &self.to_string())?;
+        if let Some(report) = mic {
+            state.serialize_field("mic", report)?;
+        }
+        state.end()
+    }
+}
+
+impl From<String> for SonoraError {
+    fn from(message: String) -> Self {
+        Self::Internal(message)
+    }
+}
+
+impl From<SonoraError> for String {
+    fn from(error: SonoraError) -> Self {
+        error.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_legacy_display_strings() {
+        assert_eq!(
+            SonoraError::state_unavailable("settings").to_string(),
+            "failed to acquire settings state"
+        );
+        assert_eq!(
+            SonoraError::InvalidInput("cannot insert empty text".to_string()).to_string(),
+            "cannot insert empty text"
+        );
+    }
+
+    #[test]
+    fn serializes_code_and_message() {
+        let value = serde_json::to_value(SonoraError::BinaryMissing(
+            "whisper sidecar not found".to_string(),
+        ))
+        .expect("error should serialize");
+
+        assert_eq!(value["code"], "binary_missing");
+        assert_eq!(value["message"], "whisper sidecar not found");
+    }
+
+    #[test]
+    fn attaches_the_mic_report() {
+        let report = MicUnavailable::from_stream_error("Device or resource busy", None);
+        let value = serde_json::to_value(SonoraError::MicUnavailable(report))
+            .expect("error should serialize");
+
+        assert_eq!(value["code"], "mic_unavailable");
+        assert_eq!(value["mic"]["reason"], "exclusive_use");
+        assert_eq!(value["mic"]["waiting"], true);
+    }
+
+    #[test]
+    fn audio_errors_flag_os_refusals() {
+        let denied = SonoraError::audio("failed to get default input config: Access is denied.");
+        assert_eq!(denied.code(), "permission_denied");
+        assert_eq!(SonoraError::audio("wav is empty").code(), "audio");
+    }
+
+    #[test]
+    fn converts_from_plain_strings_as_internal() {
+        let error: SonoraError = "boom".to_string().into();
+        assert_eq!(error.code(), "internal");
+        assert_eq!(String::from(error), "boom");
+    }
+}
@@ -0,0 +1,407 @@
+use serde::Serialize;
+use serde_json::Value;
+use std::sync::Mutex;
+
+pub const EVENT_SCHEMA_VERSION: u32 = 1;
+
+pub const ACCESSIBILITY_ANNOUNCE: &str = "accessibility:announce";
+pub const APP_ACTIVATE: &str = "app:activate";
+pub const APP_DEGRADED: &str = "app:degraded";
+pub const APP_STARTUP_PROGRESS: &str = "app:startup-progress";
+pub const DICTATION_CAPTION: &str = "dictation:caption";
+pub const DICTATION_INSERTION: &str = "dictation:insertion";
+pub const DICTATION_INSERTION_LIMIT: &str = "dictation:insertion-limit";
+pub const DICTATION_INSTANT_EDIT: &str = "dictation:instant-edit";
+pub const DICTATION_LANGUAGE: &str = "dictation:language";
+pub const DICTATION_LIVE_MIC: &str = "dictation:live-mic";
+pub const DICTATION_MIC_LEVEL: &str = "dictation:mic-level";
+pub const DICTATION_MIC_RANKING: &str = "dictation:mic-ranking";
+pub const DICTATION_MIC_MONITOR: &str = "dictation:mic-monitor";
+pub const DICTATION_MIC_UNAVAILABLE: &str = "dictation:mic-unavailable";
+pub const DICTATION_NOISE_PROFILE: &str = "dictation:noise-profile";
+pub const DICTATION_PANIC: &str = "dictation:panic";
+pub const DICTATION_PLAYBACK_GUARD: &str = "dictation:playback-guard";
+pub const DICTATION_PROFILE_DOWNGRADE: &str = "dictation:profile-downgrade";
+pub const DICTATION_PROFILE_SWITCH: &str = "dictation:profile-switch";
+pub const DICTATION_REVIEW_HELD: &str = "dictation:review-held";
+pub const DICTATION_REVIEW_RESOLVED: &str = "dictation:review-resolved";
+pub const DICTATION_SCRUBBED: &str = "dictation:scrubbed";
+pub const DICTATION_TEST_CLIP: &str = "dictation:test-clip";
+pub const DICTATION_TEST_SCORE: &str = "dictation:test-score";
+pub const DICTATION_TRANSCRIPT: &str = "dictation:transcript";
+pub const DICTATION_VOICE_ACTIVATION: &str = "dictation:voice-activation";
+pub const MODEL_CONVERSION: &str = "model:conversion";
+pub const SYSTEM_FOCUS_CHANGED: &str = "system:focus-changed";
+pub const SYSTEM_RESUME: &str = "system:resume";
+pub const SYSTEM_SCREEN_RECORDING: &str = "system:screen-recording";
+pub const TRANSCRIPTION_FILE: &str = "transcription:file";
+pub const TRANSCRIPTION_JOB: &str = "transcription:job";
+
+const MAX_PREFIX_CHARS: usize = 32;
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+pub struct EventDescriptor {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub since_version: u32,
+}
+
+pub const EVENTS: &[EventDescriptor] = &[
+    EventDescriptor {
+        name: ACCESSIBILITY_ANNOUNCE,
+        description: "screen reader announcement of a dictation state change or insertion",
+        since_version: 1,
+    },
+    EventDescriptor {
+        name: APP_ACTIVATE,
+        description: "external activation request (relaunch, deep link, D-Bus)",
+        since_version: 1,
+    },
+    EventDescriptor {
+        name: APP_DEGRADED,
+        description: "shared state recovered after a worker panicked while holding it",
+        since_version: 1,
+    },
+    EventDescriptor {
+        name: APP_STARTUP_PROGRESS,
+        description: "background engine initialization phase started or finished",
+        since_version: 1,
+    },
+    EventDescriptor {
+        name: DICTATION_CAPTION,
+        description: "line-limited caption frame for the current utterance",
+        since_version: 1,
+    },
+    EventDescriptor {
+        name: DICTATION_INSERTION,
+        description: "insertion record after each insertion attempt",
+        since_version: 1,
+    },
+    EventDescriptor {
+        name: DICTATION_INSERTION_LIMIT,
+        description: "transcript exceeded the insertion length limit and was split or truncated",
+        since_version: 1,
+    },
+    EventDescriptor {
+        name: DICTATION_INSTANT_EDIT,
+        description: "backspace-and-retype edit for provisional instant words",
+        since_version: 1,
+    },
+    EventDescriptor {
+        name: DICTATION_LANGUAGE,
+        description: "active transcription language changed",
+        since_version: 1,
+    },
+    EventDescriptor {
+        name: DICTATION_LIVE_MIC,
+        description: "live capture started or stopped",
+        since_version: 1,
+    },
+    EventDescriptor {
+        name: DICTATION_MIC_LEVEL,
+        description: "microphone level meter update",
+        since_version: 1,
+    },
+    EventDescriptor {
+        name: DICTATION_MIC_RANKING,
+        description: "microphones ranked by probed signal-to-noise ratio",
+        since_version: 1,
+    },
+    EventDescriptor {
+        name: DICTATION_MIC_MONITOR,
+        description: "microphone monitor started or stopped",
+        since_version: 1,
+    },
+    EventDescriptor {
+        name: DICTATION_MIC_UNAVAILABLE,
+        description: "live capture could not open the microphone, with the reason and whether it is waiting for the device",
+        since_version: 1,
+    },
+    EventDescriptor {
+        name: DICTATION_NOISE_PROFILE,
+        description: "noise calibration finished for a microphone",
+        since_version: 1,
+    },
+    EventDescriptor {
+        name: DICTATION_PANIC,
+        description: "panic hotkey stopped capture and cancelled pending insertions",
+        since_version: 1,
+    },
+    EventDescriptor {
+        name: DICTATION_PLAYBACK_GUARD,
+        description: "live capture gated or released because other audio is playing",
+        since_version: 1,
+    },
+    EventDescriptor {
+        name: DICTATION_PROFILE_DOWNGRADE,
+        description: "model profile downgraded after inference fell behind real time",
+        since_version: 1,
+    },
+    EventDescriptor {
+        name: DICTATION_PROFILE_SWITCH,
+        description: "model profile toggled by hotkey; sent once the new engine is live",
+        since_version: 1,
+    },
+    EventDescriptor {
+        name: DICTATION_REVIEW_HELD,
+        description: "low-confidence transcript held in the review queue instead of auto-inserted",
+        since_version: 1,
+    },
+    EventDescriptor {
+        name: DICTATION_REVIEW_RESOLVED,
+        description: "held transcript inserted, copied or discarded from a notification action",
+        since_version: 1,
+    },
+    EventDescriptor {
+        name: DICTATION_SCRUBBED,
+        description: "transcript matched sensitive-data patterns and was masked or blocked",
+        since_version: 1,
+    },
+    EventDescriptor {
+        name: DICTATION_TEST_CLIP,
+        description: "mic test clip recorded with its measured stats",
+        since_version: 1,
+    },
+    EventDescriptor {
+        name: DICTATION_TEST_SCORE,
+        description: "onboarding test dictation scored against its reference sentence",
+        since_version: 1,
+    },
+    EventDescriptor {
+        name: DICTATION_TRANSCRIPT,
+        description: "fresh transcript text for a capture session",
+        since_version: 1,
+    },
+    EventDescriptor {
+        name: DICTATION_VOICE_ACTIVATION,
+        description: "dictation started or stopped by sustained speech in voice-activated mode",
+        since_version: 1,
+    },
+    EventDescriptor {
+        name: MODEL_CONVERSION,
+        description: "background model quantization progress, completion or failure",
+        since_version: 1,
+    },
+    EventDescriptor {
+        name: SYSTEM_FOCUS_CHANGED,
+        description: "foreground app or window changed, with the matching per-app rule",
+        since_version: 1,
+    },
+    EventDescriptor {
+        name: SYSTEM_RESUME,
+        description: "machine woke from sleep; capture and engine were rebuilt",
+        since_version: 1,
+    },
+    EventDescriptor {
+        name: SYSTEM_SCREEN_RECORDING,
+        description: "screen recorder started or stopped; loopback transcription followed it",
+        since_version: 1,
+    },
+    EventDescriptor {
+        name: TRANSCRIPTION_FILE,
+        description: "result of a one-off audio file transcription",
+        since_version: 1,
+    },
+    EventDescriptor {
+        name: TRANSCRIPTION_JOB,
+        description: "drop-folder transcription job finished",
+        since_version: 1,
+    },
+];
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct EventSchema {
+    pub version: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prefix: Option<String>,
+    pub events: Vec<EventDescriptor>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Versioned<T> {
+    pub schema_version: u32,
+    #[serde(flatten)]
+    pub payload: T,
+}
+
+impl<T> Versioned<T> {
+    pub fn new(payload: T) -> Self {
+        Self {
+            schema_version: EVENT_SCHEMA_VERSION,
+            payload,
+        }
+    }
+}
+
+pub fn event_schema(prefix: Option<&str>) -> EventSchema {
+    EventSchema {
+        version: EVENT_SCHEMA_VERSION,
+        prefix: prefix.map(str::to_string),
+        events: EVENTS.to_vec(),
+    }
+}
+
+pub fn normalize_event_prefix(raw: Option<&str>) -> Option<String> {
+    let prefix = raw?
+        .trim()
+        .chars()
+        .filter(|value| value.is_ascii_alphanumeric() || matches!(value, '-' | '_' | ':' | '/'))
+        .take(MAX_PREFIX_CHARS)
+        .collect::<String>();
+    (!prefix.is_empty()).then_some(prefix)
+}
+
+pub fn prefixed_event_name(prefix: &str, name: &str) -> String {
+    format!("{prefix}{name}")
+}
+
+// Destination for backend events. The desktop app implements it on the Tauri
+// handle; workers emit through it so they can run against a `MemorySink`.
+pub trait EventSink {
+    // A host prefix makes every event go out a second time under the prefixed name.
+    fn event_prefix(&self) -> Option<String> {
+        None
+    }
+
+    fn emit_value(&self, name: &str, payload: Value) -> Result<(), String>;
+
+    // Hosts that mirror each transcript elsewhere (the Linux D-Bus signal)
+    // hook in here.
+    fn transcript_published(&self, _text: &str) {}
+}
+
+pub fn emit<T: Serialize>(sink: &dyn EventSink, name: &str, payload: T) -> Result<(), String> {
+    let payload =
+        serde_json::to_value(Versioned::new(payload)).map_err(|error| error.to_string())?;
+    if let Some(prefix) = sink.event_prefix() {
+        let _ = sink.emit_value(&prefixed_event_name(&prefix, name), payload.clone());
+    }
+    sink.emit_value(name, payload)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EmittedEvent {
+    pub name: String,
+    pub payload: Value,
+}
+
+// Records events in order instead of delivering them.
+#[derive(Debug, Default)]
+pub struct MemorySink {
+    prefix: Option<String>,
+    events: Mutex<Vec<EmittedEvent>>,
+}
+
+impl MemorySink {
+    pub fn with_prefix(prefix: &str) -> Self {
+        Self {
+            prefix: Some(prefix.to_string()),
+            events: Mutex::default(),
+        }
+    }
+
+    pub fn take(&self) -> Vec<EmittedEvent> {
+        self.events
+            .lock()
+            .map(|mut events| std::mem::take(&mut *events))
+            .unwrap_or_default()
+    }
+
+    pub fn payloads(&self, name: &str) -> Vec<Value> {
+        self.events
+            .lock()
+            .map(|events| {
+                events
+                    .iter()
+                    .filter(|event| event.name == name)
+                    .map(|event| event.payload.clone())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+impl EventSink for MemorySink {
+    fn event_prefix(&self) -> Option<String> {
+        self.prefix.clone()
+    }
+
+    fn emit_value(&self, name: &str, payload: Value) -> Result<(), String> {
+        self.events
+            .lock()
+            .map_err(|_| "event sink is unavailable".to_string())?
+            .push(EmittedEvent {
+                name: name.to_string(),
+                payload,
+            });
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Serialize)]
+    struct LiveMic {
+        active: bool,
+    }
+
+    #[test]
+    fn adds_schema_version_to_flat_payloads() {
+        let value = serde_json::to_value(Versioned::new(LiveMic { active: true }))
+            .expect("payload should serialize");
+        assert_eq!(
+            value,
+            serde_json::json!({ "schema_version": EVENT_SCHEMA_VERSION, "active": true })
+        );
+    }
+
+    #[test]
+    fn normalizes_prefixes_to_valid_event_characters() {
+        assert_eq!(
+            normalize_event_prefix(Some("  acme/ ")),
+            Some("acme/".to_string())
+        );
+        assert_eq!(
+            normalize_event_prefix(Some("my app!")),
+            Some("myapp".to_string())
+        );
+        assert_eq!(normalize_event_prefix(Some("  ")), None);
+        assert_eq!(
+            prefixed_event_name("acme/", DICTATION_TRANSCRIPT),
+            "acme/dictation:transcript"
+        );
+    }
+
+    #[test]
+    fn emits_versioned_payloads_under_plain_and_prefixed_names() {
+        let sink = MemorySink::with_prefix("acme/");
+        emit(&sink, DICTATION_LIVE_MIC, LiveMic { active: false }).expect("emit");
+
+        let expected =
+            serde_json::json!({ "schema_version": EVENT_SCHEMA_VERSION, "active": false });
+        assert_eq!(sink.payloads(DICTATION_LIVE_MIC), vec![expected.clone()]);
+        let names = sink
+            .take()
+            .into_iter()
+            .map(|event| event.name)
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["acme/dictation:live-mic", "dictation:live-mic"]);
+        assert!(sink.take().is_empty());
+    }
+
+    #[test]
+    fn schema_lists_every_event_once() {
+        let schema = event_schema(None);
+        let mut names = schema
+            .events
+            .iter()
+            .map(|event| event.name)
+            .collect::<Vec<_>>();
+        names.sort_unstable();
+        names.dedup();
+        assert_eq!(names.len(), EVENTS.len());
+        assert_eq!(schema.version, EVENT_SCHEMA_VERSION);
+    }
+}
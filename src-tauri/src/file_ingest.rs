@@ -0,0 +1,508 @@
+use serde::Serialize;
+use std::fs::File;
+use std::io::Read;
+use std::ops::Range;
+use std::path::Path;
+use symphonia::core::audio::SampleBuffer;
+use symphonia::core::codecs::{DecoderOptions, CODEC_TYPE_NULL};
+use symphonia::core::errors::Error as SymphoniaError;
+use symphonia::core::formats::FormatOptions;
+use symphonia::core::io::MediaSourceStream;
+use symphonia::core::meta::MetadataOptions;
+use symphonia::core::probe::Hint;
+
+use crate::audio::{downsample_to_16k, SAMPLE_RATE_HZ};
+use crate::error::SonoraError;
+use crate::postprocess::{merge_transcript_segments, normalize_transcript};
+use crate::transcriber::Transcriber;
+use crate::vad::{detect_speech_segments, last_silence_boundary, VadConfig};
+
+pub const FILE_CHUNK_SAMPLES: usize = SAMPLE_RATE_HZ as usize * 25;
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AudioContainer {
+    Wav,
+    Mp3,
+    Mp4,
+    Ogg,
+    Flac,
+    Webm,
+    Unknown,
+}
+
+impl AudioContainer {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Wav => "WAV",
+            Self::Mp3 => "MP3",
+            Self::Mp4 => "M4A/MP4",
+            Self::Ogg => "Ogg/Opus",
+            Self::Flac => "FLAC",
+            Self::Webm => "WebM",
+            Self::Unknown => "unknown",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DecodedAudio {
+    pub container: AudioContainer,
+    pub source_sample_rate_hz: u32,
+    pub channels: u16,
+    pub samples: Vec<f32>,
+}
+
+impl DecodedAudio {
+    pub fn duration_ms(&self) -> u64 {
+        (self.samples.len() as u64 * 1_000) / u64::from(SAMPLE_RATE_HZ)
+    }
+}
+
+pub fn detect_container(header: &[u8], extension: Option<&str>) -> AudioContainer {
+    if header.len() >= 12 && &header[0..4] == b"RIFF" && &header[8..12] == b"WAVE" {
+        return AudioContainer::Wav;
+    }
+    if header.starts_with(b"OggS") {
+        return AudioContainer::Ogg;
+    }
+    if header.starts_with(b"fLaC") {
+        return AudioContainer::Flac;
+    }
+    if header.starts_with(&[0x1A, 0x45, 0xDF, 0xA3]) {
+        return AudioContainer::Webm;
+    }
+    if header.len() >= 8 && &header[4..8] == b"ftyp" {
+        return AudioContainer::Mp4;
+    }
+    if header.starts_with(b"ID3") || (header.len() >= 2 && header[0] == 0xFF && header[1] >= 0xE0) {
+        return AudioContainer::Mp3;
+    }
+
+    match extension.map(|value| value.to_ascii_lowercase()).as_deref() {
+        Some("wav") => AudioContainer::Wav,
+        Some("mp3") => AudioContainer::Mp3,
+        Some("m4a" | "mp4" | "aac") => AudioContainer::Mp4,
+        Some("ogg" | "opus" | "oga") => AudioContainer::Ogg,
+        Some("flac") => AudioContainer::Flac,
+        Some("webm") => AudioContainer::Webm,
+        _ => AudioContainer::Unknown,
+    }
+}
+
+pub fn decode_audio_file(path: &Path) -> Result<DecodedAudio, SonoraError> {
+    let mut header = [0u8; 16];
+    let read = File::open(path)
+        .and_then(|mut file| file.read(&mut header))
+        .map_err(|error| {
+            SonoraError::io(format!(
+                "failed to open audio file '{}': {error}",
+                path.to_string_lossy()
+            ))
+        })?;
+    let extension = path.extension().and_then(|value| value.to_str());
+
+    match detect_container(&header[..read], extension) {
+        AudioContainer::Wav => decode_wav(path),
+        AudioContainer::Unknown => Err(SonoraError::Unsupported(format!(
+            "'{}' is not a recognized audio file",
+            path.to_string_lossy()
+        ))),
+        container => decode_compressed(path, container),
+    }
+}
+
+// Opus (Ogg/Opus, most WebM) has no symphonia decoder and stays unsupported.
+fn unsupported_codec(path: &Path, container: AudioContainer, error: SymphoniaError) -> SonoraError {
+    match error {
+        SymphoniaError::Unsupported(detail) => SonoraError::Unsupported(format!(
+            "{} audio in '{}' cannot be decoded ({detail}); convert it to WAV first",
+            container.label(),
+            path.to_string_lossy()
+        )),
+        error => SonoraError::audio(format!(
+            "failed to decode '{}': {error}",
+            path.to_string_lossy()
+        )),
+    }
+}
+
+fn decode_compressed(path: &Path, container: AudioContainer) -> Result<DecodedAudio, SonoraError> {
+    let file = File::open(path).map_err(|error| {
+        SonoraError::io(format!(
+            "failed to open audio file '{}': {error}",
+            path.to_string_lossy()
+        ))
+    })?;
+    let stream = MediaSourceStream::new(Box::new(file), Default::default());
+    let mut hint = Hint::new();
+    if let Some(extension) = path.extension().and_then(|value| value.to_str()) {
+        hint.with_extension(extension);
+    }
+    let mut format = symphonia::default::get_probe()
+        .format(
+            &hint,
+            stream,
+            &FormatOptions::default(),
+            &MetadataOptions::default(),
+        )
+        .map_err(|error| unsupported_codec(path, container, error))?
+        .format;
+    let track = format
+        .tracks()
+        .iter()
+        .find(|track| track.codec_params.codec != CODEC_TYPE_NULL)
+        .ok_or_else(|| {
+            SonoraError::Unsupported(format!("'{}' has no audio track", path.to_string_lossy()))
+        })?;
+    let track_id = track.id;
+    let mut sample_rate = track.codec_params.sample_rate.unwrap_or(0);
+    let mut channels = track
+        .codec_params
+        .channels
+        .map(|layout| layout.count())
+        .unwrap_or(0);
+    let mut decoder = symphonia::default::get_codecs()
+        .make(&track.codec_params, &DecoderOptions::default())
+        .map_err(|error| unsupported_codec(path, container, error))?;
+
+    let mut mono = Vec::<f32>::new();
+    let mut buffer: Option<SampleBuffer<f32>> = None;
+    loop {
+        let packet = match format.next_packet() {
+            Ok(packet) => packet,
+            Err(SymphoniaError::IoError(error))
+                if error.kind() == std::io::ErrorKind::UnexpectedEof =>
+            {
+                break;
+            }
+            Err(SymphoniaError::ResetRequired) => break,
+            Err(error) => return Err(unsupported_codec(path, container, error)),
+        };
+        if packet.track_id() != track_id {
+            continue;
+        }
+        let decoded = match decoder.decode(&packet) {
+            Ok(decoded) => decoded,
+            // A corrupt frame is skipped, as players do.
+            Err(SymphoniaError::DecodeError(_)) => continue,
+            Err(error) => return Err(unsupported_codec(path, container, error)),
+        };
+        let spec = *decoded.spec();
+        sample_rate = spec.rate;
+        channels = spec.channels.count();
+        if channels == 0 {
+            continue;
+        }
+        let needed = decoded.capacity() * channels;
+        if buffer
+            .as_ref()
+            .is_none_or(|buffer| buffer.capacity() < needed)
+        {
+            buffer = Some(SampleBuffer::new(decoded.capacity() as u64, spec));
+        }
+        let Some(buffer) = buffer.as_mut() else {
+            continue;
+        };
+        buffer.copy_interleaved_ref(decoded);
+        mono.extend(
+            buffer
+                .samples()
+                .chunks_exact(channels)
+                .map(|frame| frame.iter().copied().sum::<f32>() / channels as f32),
+        );
+    }
+
+    if channels == 0 || mono.is_empty() {
+        return Err(SonoraError::audio(format!(
+            "'{}' contains no decodable audio",
+            path.to_string_lossy()
+        )));
+    }
+    to_decoded_audio(container, sample_rate, channels as u16, mono)
+}
+
+fn to_decoded_audio(
+    container: AudioContainer,
+    sample_rate: u32,
+    channels: u16,
+    mono: Vec<f32>,
+) -> Result<DecodedAudio, SonoraError> {
+    if sample_rate < SAMPLE_RATE_HZ {
+        return Err(SonoraError::Unsupported(format!(
+            "unsupported sample rate {sample_rate} Hz (minimum is {SAMPLE_RATE_HZ} Hz)"
+        )));
+    }
+
+    Ok(DecodedAudio {
+        container,
+        source_sample_rate_hz: sample_rate,
+        channels,
+        samples: downsample_to_16k(&mono, sample_rate),
+    })
+}
+
+fn decode_wav(path: &Path) -> Result<DecodedAudio, SonoraError> {
+    let mut reader = hound::WavReader::open(path).map_err(|error| {
+        SonoraError::audio(format!(
+            "failed to open wav '{}': {error}",
+            path.to_string_lossy()
+        ))
+    })?;
+    let spec = reader.spec();
+    if spec.channels == 0 {
+        return Err(SonoraError::audio("wav has invalid channel count 0"));
+    }
+
+    let mut interleaved = Vec::<f32>::new();
+    match spec.sample_format {
+        hound::SampleFormat::Int if spec.bits_per_sample <= 16 => {
+            for sample in reader.samples::<i16>() {
+                let value = sample.map_err(|error| {
+                    SonoraError::audio(format!("failed to read sample: {error}"))
+                })?;
+                interleaved.push(value as f32 / i16::MAX as f32);
+            }
+        }
+        hound::SampleFormat::Int => {
+            let scale = ((1_i64 << (spec.bits_per_sample - 1)) - 1) as f32;
+            for sample in reader.samples::<i32>() {
+                let value = sample.map_err(|error| {
+                    SonoraError::audio(format!("failed to read sample: {error}"))
+                })?;
+                interleaved.push((value as f32 / scale).clamp(-1.0, 1.0));
+            }
+        }
+        hound::SampleFormat::Float => {
+            for sample in reader.samples::<f32>() {
+                let value = sample.map_err(|error| {
+                    SonoraError::audio(format!("failed to read sample: {error}"))
+                })?;
+                interleaved.push(value.clamp(-1.0, 1.0));
+            }
+        }
+    }
+
+    let channels = usize::from(spec.channels);
+    let mono = if channels == 1 {
+        interleaved
+    } else {
+        interleaved
+            .chunks_exact(channels)
+            .map(|frame| frame.iter().copied().sum::<f32>() / channels as f32)
+            .collect()
+    };
+
+    to_decoded_audio(AudioContainer::Wav, spec.sample_rate, spec.channels, mono)
+}
+
+pub fn plan_file_chunks(
+    samples: &[f32],
+    vad_config: &VadConfig,
+    max_chunk_samples: usize,
+) -> Vec<Range<usize>> {
+    let max_chunk_samples = max_chunk_samples.max(1);
+    let mut chunks = Vec::new();
+    let mut start = 0usize;
+
+    while start < samples.len() {
+        let window_end = (start + max_chunk_samples).min(samples.len());
+        if window_end == samples.len() {
+            chunks.push(start..window_end);
+            break;
+        }
+
+        let segments = detect_speech_segments(&samples[start..window_end], vad_config);
+        let boundary = last_silence_boundary(&segments, max_chunk_samples / 2, max_chunk_samples)
+            .unwrap_or(max_chunk_samples);
+        chunks.push(start..start + boundary);
+        start += boundary;
+    }
+
+    chunks
+}
+
+pub fn transcribe_samples<T: Transcriber + ?Sized>(
+    transcriber: &T,
+    samples: &[f32],
+    vad_config: &VadConfig,
+    max_chunk_samples: usize,
+) -> Result<String, String> {
+    let mut transcript = String::new();
+    for range in plan_file_chunks(samples, vad_config, max_chunk_samples) {
+        let chunk = &samples[range];
+        if vad_config.enabled && detect_speech_segments(chunk, vad_config).is_empty() {
+            continue;
+        }
+
+        let text = normalize_transcript(&transcriber.transcribe(chunk)?);
+        if !text.is_empty() {
+            transcript = merge_transcript_segments(&transcript, &text);
+        }
+    }
+
+    Ok(transcript)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transcriber::StubTranscriber;
+    use std::path::PathBuf;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    fn temp_file(name: &str) -> PathBuf {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("clock should be set")
+            .as_nanos();
+        std::env::temp_dir().join(format!("sonora-{nanos}-{name}"))
+    }
+
+    fn tone(samples: usize) -> Vec<f32> {
+        (0..samples).map(|i| (i as f32 * 0.1).sin() * 0.2).collect()
+    }
+
+    #[test]
+    fn detects_containers_from_magic_bytes_before_extension() {
+        assert_eq!(
+            detect_container(b"RIFF\0\0\0\0WAVEfmt ", Some("mp3")),
+            AudioContainer::Wav
+        );
+        assert_eq!(detect_container(b"ID3\x04", None), AudioContainer::Mp3);
+        assert_eq!(
+            detect_container(b"\0\0\0\x20ftypM4A ", None),
+            AudioContainer::Mp4
+        );
+        assert_eq!(detect_container(b"OggS\0", None), AudioContainer::Ogg);
+        assert_eq!(detect_container(b"", Some("OPUS")), AudioContainer::Ogg);
+        assert_eq!(detect_container(b"hello", None), AudioContainer::Unknown);
+    }
+
+    #[test]
+    fn decodes_stereo_wav_to_16k_mono() {
+        let path = temp_file("ingest.wav");
+        let spec = hound::WavSpec {
+            channels: 2,
+            sample_rate: 32_000,
+            bits_per_sample: 16,
+            sample_format: hound::SampleFormat::Int,
+        };
+        let mut writer = hound::WavWriter::create(&path, spec).expect("wav should be writable");
+        for _ in 0..3_200 {
+            writer
+                .write_sample(i16::MAX / 2)
+                .expect("sample should write");
+            writer.write_sample(0i16).expect("sample should write");
+        }
+        writer.finalize().expect("wav should finalize");
+
+        let decoded = decode_audio_file(&path).expect("wav should decode");
+        let _ = std::fs::remove_file(&path);
+
+        assert_eq!(decoded.container, AudioContainer::Wav);
+        assert_eq!(decoded.source_sample_rate_hz, 32_000);
+        assert_eq!(decoded.channels, 2);
+        assert_eq!(decoded.samples.len(), 1_600);
+        assert_eq!(decoded.duration_ms(), 100);
+        assert!((decoded.samples[0] - 0.25).abs() < 0.01);
+    }
+
+    fn crc8(bytes: &[u8]) -> u8 {
+        bytes.iter().fold(0u8, |crc, byte| {
+            (0..8).fold(crc ^ byte, |crc, _| {
+                if crc & 0x80 != 0 {
+                    (crc << 1) ^ 0x07
+                } else {
+                    crc << 1
+                }
+            })
+        })
+    }
+
+    fn crc16(bytes: &[u8]) -> u16 {
+        bytes.iter().fold(0u16, |crc, byte| {
+            (0..8).fold(crc ^ (u16::from(*byte) << 8), |crc, _| {
+                if crc & 0x8000 != 0 {
+                    (crc << 1) ^ 0x8005
+                } else {
+                    crc << 1
+                }
+            })
+        })
+    }
+
+    // One 16 kHz mono frame of uncompressed (verbatim) 16-bit samples.
+    fn flac_file(samples: &[i16]) -> Vec<u8> {
+        let block = samples.len() as u16;
+        let mut bytes = b"fLaC\x80\0\0\x22".to_vec();
+        bytes.extend_from_slice(&block.to_be_bytes());
+        bytes.extend_from_slice(&block.to_be_bytes());
+        bytes.extend_from_slice(&[0; 6]);
+        let packed = (16_000u64 << 44) | (15u64 << 36) | samples.len() as u64;
+        bytes.extend_from_slice(&packed.to_be_bytes());
+        bytes.extend_from_slice(&[0; 16]);
+
+        let mut frame = vec![0xFF, 0xF8, 0x75, 0x08, 0x00];
+        frame.extend_from_slice(&(block - 1).to_be_bytes());
+        frame.push(crc8(&frame));
+        frame.push(0x02);
+        for sample in samples {
+            frame.extend_from_slice(&sample.to_be_bytes());
+        }
+        frame.extend_from_slice(&crc16(&frame).to_be_bytes());
+        bytes.extend_from_slice(&frame);
+        bytes
+    }
+
+    #[test]
+    fn decodes_compressed_containers_with_symphonia() {
+        let path = temp_file("memo.flac");
+        std::fs::write(&path, flac_file(&[8_192; 1_600])).expect("file should write");
+        let decoded = decode_audio_file(&path).expect("flac should decode");
+        let _ = std::fs::remove_file(&path);
+
+        assert_eq!(decoded.container, AudioContainer::Flac);
+        assert_eq!(decoded.source_sample_rate_hz, 16_000);
+        assert_eq!(decoded.duration_ms(), 100);
+        assert!((decoded.samples[0] - 0.25).abs() < 0.01);
+
+        // No WebM reader or Opus decoder is built in.
+        let path = temp_file("clip.webm");
+        std::fs::write(&path, [0x1A, 0x45, 0xDF, 0xA3, 0, 0, 0, 0]).expect("file should write");
+        let error = decode_audio_file(&path).expect_err("webm should be rejected");
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(error.code(), "unsupported");
+        assert!(error.to_string().contains("WebM"));
+    }
+
+    #[test]
+    fn splits_long_audio_at_silence() {
+        let mut samples = tone(6_000);
+        samples.extend(vec![0.0; 2_000]);
+        samples.extend(tone(6_000));
+
+        let chunks = plan_file_chunks(&samples, &VadConfig::default(), 10_000);
+        assert_eq!(chunks.len(), 2);
+        assert!(chunks[0].end > 6_000 && chunks[0].end <= 8_000);
+        assert_eq!(chunks[1].end, samples.len());
+    }
+
+    #[test]
+    fn skips_silent_chunks_when_transcribing() {
+        let silent = vec![0.0; 20_000];
+        let transcript =
+            transcribe_samples(&StubTranscriber, &silent, &VadConfig::default(), 10_000)
+                .expect("transcription should succeed");
+        assert!(transcript.is_empty());
+
+        let transcript = transcribe_samples(
+            &StubTranscriber,
+            &tone(8_000),
+            &VadConfig::default(),
+            10_000,
+        )
+        .expect("transcription should succeed");
+        assert_eq!(transcript, "Phase-1 transcript.");
+    }
+}
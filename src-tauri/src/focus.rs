@@ -0,0 +1,412 @@
+use serde::{Deserialize, Serialize};
+use std::process::Command;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+#[cfg(target_os = "windows")]
+use std::os::windows::process::CommandExt;
+
+#[cfg(target_os = "windows")]
+const CREATE_NO_WINDOW: u32 = 0x08000000;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FocusTarget {
+    pub app_name: String,
+    pub process_id: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FocusGuardOutcome {
+    Focused,
+    TimedOut { last_seen: Option<FocusTarget> },
+    Unavailable(String),
+}
+
+impl FocusTarget {
+    pub fn matches(&self, other: &FocusTarget) -> bool {
+        match (self.process_id, other.process_id) {
+            (Some(left), Some(right)) => left == right,
+            _ => self.app_name.eq_ignore_ascii_case(&other.app_name),
+        }
+    }
+
+    pub fn is_current_process(&self) -> bool {
+        self.process_id == Some(std::process::id())
+    }
+}
+
+pub fn wait_for_focus<P, S>(
+    target: &FocusTarget,
+    timeout: Duration,
+    poll_interval: Duration,
+    mut probe: P,
+    mut sleep: S,
+) -> FocusGuardOutcome
+where
+    P: FnMut() -> Result<FocusTarget, String>,
+    S: FnMut(Duration),
+{
+    let poll_interval = poll_interval.max(Duration::from_millis(1));
+    let mut waited = Duration::ZERO;
+    let mut last_seen = None;
+
+    loop {
+        match probe() {
+            Ok(current) if current.matches(target) => return FocusGuardOutcome::Focused,
+            Ok(current) => last_seen = Some(current),
+            Err(error) if last_seen.is_none() => return FocusGuardOutcome::Unavailable(error),
+            Err(_) => {}
+        }
+
+        if waited >= timeout {
+            return FocusGuardOutcome::TimedOut { last_seen };
+        }
+
+        sleep(poll_interval);
+        waited += poll_interval;
+    }
+}
+
+pub fn detect_frontmost_app() -> Result<FocusTarget, String> {
+    detect_frontmost_app_for_os(std::env::consts::OS)
+}
+
+// The probe spawns a process (PowerShell on Windows), so the focus watcher,
+// the focus guard and app rules share one answer while it is fresh.
+pub struct ProbeCache<T> {
+    last: Mutex<Option<(Instant, Result<T, String>)>>,
+}
+
+impl<T: Clone> ProbeCache<T> {
+    pub const fn new() -> Self {
+        Self {
+            last: Mutex::new(None),
+        }
+    }
+
+    pub fn get_or_probe<P>(&self, max_age: Duration, probe: P) -> Result<T, String>
+    where
+        P: FnOnce() -> Result<T, String>,
+    {
+        let Ok(mut last) = self.last.lock() else {
+            return probe();
+        };
+        if let Some((at, result)) = last.as_ref() {
+            if at.elapsed() < max_age {
+                return result.clone();
+            }
+        }
+        let result = probe();
+        *last = Some((Instant::now(), result.clone()));
+        result
+    }
+}
+
+impl<T: Clone> Default for ProbeCache<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static FOCUS_SNAPSHOT: ProbeCache<FocusSnapshot> = ProbeCache::new();
+
+pub fn focus_snapshot_within(max_age: Duration) -> Result<FocusSnapshot, String> {
+    FOCUS_SNAPSHOT.get_or_probe(max_age, detect_focus_snapshot)
+}
+
+pub fn frontmost_app_within(max_age: Duration) -> Result<FocusTarget, String> {
+    focus_snapshot_within(max_age).map(|snapshot| snapshot.target)
+}
+
+fn detect_frontmost_app_for_os(os: &str) -> Result<FocusTarget, String> {
+    match os {
+        "linux" => detect_linux_frontmost_app(),
+        "macos" => detect_macos_frontmost_app(),
+        "windows" => detect_windows_frontmost_app(),
+        _ => Err(format!("frontmost app detection is not supported on {os}")),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FocusSnapshot {
+    pub target: FocusTarget,
+    pub window_title: Option<String>,
+}
+
+// The app and its window title from one probe process, for the focus watcher.
+pub fn detect_focus_snapshot() -> Result<FocusSnapshot, String> {
+    match std::env::consts::OS {
+        "linux" => {
+            // xdotool chains commands on the window the first one found.
+            let output = run_probe(
+                "xdotool",
+                &["getactivewindow", "getwindowpid", "getwindowname"],
+            )?;
+            let (raw_pid, title) = output.split_once('\n').unwrap_or((output.as_str(), ""));
+            let process_id = raw_pid
+                .trim()
+                .parse::<u32>()
+                .map_err(|_| format!("unexpected xdotool output: {}", raw_pid.trim()))?;
+            let app_name = std::fs::read_to_string(format!("/proc/{process_id}/comm"))
+                .map(|value| value.trim().to_string())
+                .unwrap_or_else(|_| format!("pid {process_id}"));
+            Ok(FocusSnapshot {
+                target: FocusTarget {
+                    app_name,
+                    process_id: Some(process_id),
+                },
+                window_title: non_empty(title),
+            })
+        }
+        "macos" => parse_snapshot_lines(&run_probe(
+            "osascript",
+            &[
+                "-e",
+                "tell application \"System Events\"",
+                "-e",
+                "set p to first application process whose frontmost is true",
+                "-e",
+                "set t to \"\"",
+                "-e",
+                "try",
+                "-e",
+                "set t to name of front window of p",
+                "-e",
+                "end try",
+                "-e",
+                "return (name of p) & linefeed & (unix id of p) & linefeed & t",
+                "-e",
+                "end tell",
+            ],
+        )?),
+        "windows" => {
+            let script = "Add-Type -Name W -Namespace S -MemberDefinition '[DllImport(\"user32.dll\")] public static extern System.IntPtr GetForegroundWindow(); [DllImport(\"user32.dll\")] public static extern int GetWindowThreadProcessId(System.IntPtr h, out int p); [DllImport(\"user32.dll\", CharSet = CharSet.Unicode)] public static extern int GetWindowText(System.IntPtr h, System.Text.StringBuilder s, int n);'; $h = [S.W]::GetForegroundWindow(); $p = 0; [void][S.W]::GetWindowThreadProcessId($h, [ref]$p); $b = New-Object System.Text.StringBuilder 512; [void][S.W]::GetWindowText($h, $b, 512); $n = (Get-Process -Id $p).ProcessName; \"$n`n$p`n$($b.ToString())\"";
+            parse_snapshot_lines(&run_probe(
+                "powershell",
+                &["-NoProfile", "-NonInteractive", "-Command", script],
+            )?)
+        }
+        os => Err(format!("frontmost app detection is not supported on {os}")),
+    }
+}
+
+// Name, pid and window title, one per line; the title may be missing.
+fn parse_snapshot_lines(output: &str) -> Result<FocusSnapshot, String> {
+    let mut lines = output.lines();
+    let app_name = lines.next().unwrap_or_default().trim().to_string();
+    if app_name.is_empty() {
+        return Err("frontmost app name is empty".to_string());
+    }
+    let process_id = lines.next().and_then(|pid| pid.trim().parse::<u32>().ok());
+    Ok(FocusSnapshot {
+        target: FocusTarget {
+            app_name,
+            process_id,
+        },
+        window_title: non_empty(lines.next().unwrap_or_default()),
+    })
+}
+
+fn non_empty(value: &str) -> Option<String> {
+    let value = value.trim();
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+pub fn read_focused_field_value() -> Result<String, String> {
+    match std::env::consts::OS {
+        "macos" => run_probe(
+            "osascript",
+            &[
+                "-e",
+                "tell application \"System Events\" to get value of attribute \"AXValue\" of (value of attribute \"AXFocusedUIElement\" of (first application process whose frontmost is true))",
+            ],
+        ),
+        "windows" => {
+            let script = "Add-Type -AssemblyName UIAutomationClient; $e = [System.Windows.Automation.AutomationElement]::FocusedElement; $p = $null; if ($e.TryGetCurrentPattern([System.Windows.Automation.ValuePattern]::Pattern, [ref]$p)) { $p.Current.Value } else { exit 1 }";
+            run_probe(
+                "powershell",
+                &["-NoProfile", "-NonInteractive", "-Command", script],
+            )
+        }
+        os => Err(format!("focused field readback is not supported on {os}")),
+    }
+}
+
+fn detect_linux_frontmost_app() -> Result<FocusTarget, String> {
+    let raw_pid = run_probe("xdotool", &["getactivewindow", "getwindowpid"])?;
+    let process_id = raw_pid
+        .trim()
+        .parse::<u32>()
+        .map_err(|_| format!("unexpected xdotool output: {}", raw_pid.trim()))?;
+    let app_name = std::fs::read_to_string(format!("/proc/{process_id}/comm"))
+        .map(|value| value.trim().to_string())
+        .unwrap_or_else(|_| format!("pid {process_id}"));
+
+    Ok(FocusTarget {
+        app_name,
+        process_id: Some(process_id),
+    })
+}
+
+fn detect_macos_frontmost_app() -> Result<FocusTarget, String> {
+    let output = run_probe(
+        "osascript",
+        &[
+            "-e",
+            "tell application \"System Events\" to get {name, unix id} of first application process whose frontmost is true",
+        ],
+    )?;
+    parse_name_and_pid(&output)
+}
+
+fn detect_windows_frontmost_app() -> Result<FocusTarget, String> {
+    let script = "Add-Type -Name W -Namespace S -MemberDefinition '[DllImport(\"user32.dll\")] public static extern System.IntPtr GetForegroundWindow(); [DllImport(\"user32.dll\")] public static extern int GetWindowThreadProcessId(System.IntPtr h, out int p);'; $p = 0; [void][S.W]::GetWindowThreadProcessId([S.W]::GetForegroundWindow(), [ref]$p); $n = (Get-Process -Id $p).ProcessName; \"$n, $p\"";
+    let output = run_probe(
+        "powershell",
+        &["-NoProfile", "-NonInteractive", "-Command", script],
+    )?;
+    parse_name_and_pid(&output)
+}
+
+fn parse_name_and_pid(output: &str) -> Result<FocusTarget, String> {
+    let trimmed = output.trim();
+    let (name, pid) = trimmed
+        .rsplit_once(',')
+        .ok_or_else(|| format!("unexpected frontmost app output: {trimmed}"))?;
+    let app_name = name.trim().to_string();
+    if app_name.is_empty() {
+        return Err("frontmost app name is empty".to_string());
+    }
+
+    Ok(FocusTarget {
+        app_name,
+        process_id: pid.trim().parse::<u32>().ok(),
+    })
+}
+
+fn run_probe(program: &str, args: &[&str]) -> Result<String, String> {
+    let mut command = Command::new(program);
+    command.args(args);
+
+    #[cfg(target_os = "windows")]
+    {
+        command.creation_flags(CREATE_NO_WINDOW);
+    }
+
+    let output = command
+        .output()
+        .map_err(|error| format!("failed to run {program}: {error}"))?;
+    if !output.status.success() {
+        return Err(format!(
+            "{program} exited with status {}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn target(name: &str, pid: Option<u32>) -> FocusTarget {
+        FocusTarget {
+            app_name: name.to_string(),
+            process_id: pid,
+        }
+    }
+
+    #[test]
+    fn matches_by_pid_then_name() {
+        assert!(target("code", Some(10)).matches(&target("Code", Some(10))));
+        assert!(!target("code", Some(10)).matches(&target("code", Some(11))));
+        assert!(target("Slack", None).matches(&target("slack", Some(42))));
+    }
+
+    #[test]
+    fn parses_name_and_pid_probe_output() {
+        let parsed = parse_name_and_pid("Google Chrome, 4242\n").expect("output should parse");
+        assert_eq!(parsed, target("Google Chrome", Some(4242)));
+        assert!(parse_name_and_pid("garbage").is_err());
+    }
+
+    #[test]
+    fn parses_snapshot_lines_with_or_without_a_title() {
+        let parsed = parse_snapshot_lines("Code\n4242\nmain.rs - sonora\n").expect("snapshot");
+        assert_eq!(parsed.target, target("Code", Some(4242)));
+        assert_eq!(parsed.window_title.as_deref(), Some("main.rs - sonora"));
+        let untitled = parse_snapshot_lines("Finder\n7\n").expect("snapshot");
+        assert_eq!(untitled.window_title, None);
+        assert!(parse_snapshot_lines("\n").is_err());
+    }
+
+    #[test]
+    fn waits_until_target_regains_focus() {
+        let mut probes = vec![target("sonora", Some(1)), target("editor", Some(2))].into_iter();
+        let mut slept = Vec::new();
+        let outcome = wait_for_focus(
+            &target("editor", Some(2)),
+            Duration::from_millis(500),
+            Duration::from_millis(100),
+            || probes.next().ok_or_else(|| "exhausted".to_string()),
+            |duration| slept.push(duration),
+        );
+
+        assert_eq!(outcome, FocusGuardOutcome::Focused);
+        assert_eq!(slept, vec![Duration::from_millis(100)]);
+    }
+
+    #[test]
+    fn times_out_when_target_never_returns() {
+        let mut sleeps = 0;
+        let outcome = wait_for_focus(
+            &target("editor", Some(2)),
+            Duration::from_millis(300),
+            Duration::from_millis(100),
+            || Ok(target("sonora", Some(1))),
+            |_| sleeps += 1,
+        );
+
+        assert_eq!(
+            outcome,
+            FocusGuardOutcome::TimedOut {
+                last_seen: Some(target("sonora", Some(1)))
+            }
+        );
+        assert_eq!(sleeps, 3);
+    }
+
+    #[test]
+    fn reuses_a_fresh_probe_result() {
+        let cache = ProbeCache::new();
+        let mut probes = 0;
+        for _ in 0..3 {
+            let found = cache.get_or_probe(Duration::from_secs(60), || {
+                probes += 1;
+                Ok(target("editor", Some(2)))
+            });
+            assert_eq!(found, Ok(target("editor", Some(2))));
+        }
+        assert_eq!(probes, 1);
+
+        let stale = cache.get_or_probe(Duration::ZERO, || Ok(target("sonora", Some(1))));
+        assert_eq!(stale, Ok(target("sonora", Some(1))));
+    }
+
+    #[test]
+    fn reports_unavailable_when_probe_fails_immediately() {
+        let outcome = wait_for_focus(
+            &target("editor", None),
+            Duration::from_millis(300),
+            Duration::from_millis(100),
+            || Err("xdotool missing".to_string()),
+            |_| {},
+        );
+        assert_eq!(
+            outcome,
+            FocusGuardOutcome::Unavailable("xdotool missing".to_string())
+        );
+    }
+}
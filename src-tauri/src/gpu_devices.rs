@@ -0,0 +1,81 @@
+use serde::Serialize;
+use std::process::Command;
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct GpuDevice {
+    // nvidia-smi index, i.e. PCI bus order.
+    pub index: u32,
+    pub name: String,
+    pub pci_bus_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory_total_mb: Option<u64>,
+}
+
+pub fn list_nvidia_gpus() -> Vec<GpuDevice> {
+    let output = Command::new("nvidia-smi")
+        .args([
+            "--query-gpu=index,name,pci.bus_id,memory.total",
+            "--format=csv,noheader,nounits",
+        ])
+        .output();
+    match output {
+        Ok(output) if output.status.success() => {
+            parse_nvidia_smi_gpus(&String::from_utf8_lossy(&output.stdout))
+        }
+        _ => Vec::new(),
+    }
+}
+
+pub fn parse_nvidia_smi_gpus(output: &str) -> Vec<GpuDevice> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let fields = line.split(',').map(str::trim).collect::<Vec<_>>();
+            let [index, name, pci_bus_id, memory] = fields.as_slice() else {
+                return None;
+            };
+            Some(GpuDevice {
+                index: index.parse().ok()?,
+                name: (*name).to_string(),
+                pci_bus_id: (*pci_bus_id).to_string(),
+                memory_total_mb: memory.parse().ok(),
+            })
+        })
+        .collect()
+}
+
+// The physical GPU a CUDA run lands on. Without a selection the runtime picks
+// the fastest device, which is only known for certain on single-GPU machines.
+pub fn physical_gpu(devices: &[GpuDevice], selected: Option<u32>) -> Option<GpuDevice> {
+    match selected {
+        Some(index) => devices.iter().find(|device| device.index == index).cloned(),
+        None if devices.len() == 1 => devices.first().cloned(),
+        None => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_multi_gpu_listing_and_resolves_selection() {
+        let devices = parse_nvidia_smi_gpus(
+            "0, NVIDIA GeForce RTX 3060, 00000000:01:00.0, 12288\n\
+             1, NVIDIA RTX A4000, 00000000:02:00.0, [N/A]\n\
+             garbage line\n",
+        );
+        assert_eq!(devices.len(), 2);
+        assert_eq!(devices[0].memory_total_mb, Some(12_288));
+        assert_eq!(devices[1].name, "NVIDIA RTX A4000");
+        assert_eq!(devices[1].memory_total_mb, None);
+
+        assert_eq!(
+            physical_gpu(&devices, Some(1)).map(|device| device.pci_bus_id),
+            Some("00000000:02:00.0".to_string())
+        );
+        assert!(physical_gpu(&devices, Some(4)).is_none());
+        assert!(physical_gpu(&devices, None).is_none());
+        assert_eq!(physical_gpu(&devices[..1], None), Some(devices[0].clone()));
+    }
+}
@@ -1,17 +1,70 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
 
-#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum InsertionStatus {
     Success,
+    VerifiedSuccess,
+    Unverified,
     Fallback,
     Failure,
 }
 
-#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VerificationOutcome {
+    Verified,
+    Mismatch(String),
+    Unavailable(String),
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum InsertionStrategy {
+    #[default]
+    Auto,
+    Direct,
+    Clipboard,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct InsertionRecord {
+    #[serde(default)]
+    pub id: u64,
     pub text: String,
     pub status: InsertionStatus,
+    #[serde(default)]
+    pub strategy: InsertionStrategy,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry_of: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session_label: Option<String>,
+    // Dictated utterance this insertion delivered; None for typed or external text.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub utterance_id: Option<u64>,
+    // 0 for records written before retention existed; those have no known age.
+    #[serde(default)]
+    pub created_unix_ms: u64,
+}
+
+impl InsertionRecord {
+    pub fn new(id: u64, text: String, status: InsertionStatus) -> Self {
+        Self {
+            id,
+            text,
+            status,
+            strategy: InsertionStrategy::Auto,
+            retry_of: None,
+            language: None,
+            session_label: None,
+            utterance_id: None,
+            created_unix_ms: 0,
+        }
+    }
 }
 
 pub fn resolve_status(
@@ -28,14 +81,244 @@ pub fn resolve_status(
     InsertionStatus::Failure
 }
 
+pub fn resolve_status_for_strategy(
+    strategy: InsertionStrategy,
+    fallback_enabled: bool,
+    direct: impl FnOnce() -> Result<(), String>,
+    clipboard: impl FnOnce() -> Result<(), String>,
+) -> InsertionStatus {
+    match strategy {
+        InsertionStrategy::Auto => {
+            let direct_result = direct();
+            if direct_result.is_ok() {
+                return InsertionStatus::Success;
+            }
+            resolve_status(direct_result, fallback_enabled, clipboard())
+        }
+        InsertionStrategy::Direct => {
+            if direct().is_ok() {
+                InsertionStatus::Success
+            } else {
+                InsertionStatus::Failure
+            }
+        }
+        InsertionStrategy::Clipboard => {
+            if clipboard().is_ok() {
+                InsertionStatus::Fallback
+            } else {
+                InsertionStatus::Failure
+            }
+        }
+    }
+}
+
+// Only direct insertion can be verified; clipboard pastes are already reported as a fallback.
+pub fn apply_verification(
+    status: InsertionStatus,
+    outcome: &VerificationOutcome,
+) -> InsertionStatus {
+    match (status, outcome) {
+        (InsertionStatus::Success, VerificationOutcome::Verified) => {
+            InsertionStatus::VerifiedSuccess
+        }
+        (InsertionStatus::Success, _) => InsertionStatus::Unverified,
+        (status, _) => status,
+    }
+}
+
+pub fn readback_contains(field_value: &str, inserted: &str) -> bool {
+    let collapse = |value: &str| value.split_whitespace().collect::<Vec<_>>().join(" ");
+    let inserted = collapse(inserted);
+    !inserted.is_empty() && collapse(field_value).contains(&inserted)
+}
+
+pub fn next_record_id(records: &[InsertionRecord]) -> u64 {
+    records
+        .iter()
+        .map(|record| record.id)
+        .max()
+        .unwrap_or(0)
+        .saturating_add(1)
+}
+
+pub fn find_record(records: &[InsertionRecord], id: u64) -> Option<&InsertionRecord> {
+    records.iter().find(|record| record.id == id)
+}
+
+const MAX_SESSION_LABEL_CHARS: usize = 64;
+
+pub fn normalize_session_label(label: Option<&str>) -> Option<String> {
+    let label = label?.split_whitespace().collect::<Vec<_>>().join(" ");
+    (!label.is_empty()).then(|| label.chars().take(MAX_SESSION_LABEL_CHARS).collect())
+}
+
+// Labels match case-insensitively; the query matches any part of the text.
+pub fn search_records<'a>(
+    records: &'a [InsertionRecord],
+    query: Option<&str>,
+    session_label: Option<&str>,
+) -> Vec<&'a InsertionRecord> {
+    let query = query
+        .map(|value| value.trim().to_lowercase())
+        .filter(|value| !value.is_empty());
+    let session_label = normalize_session_label(session_label);
+    records
+        .iter()
+        .filter(|record| match &session_label {
+            Some(label) => record
+                .session_label
+                .as_deref()
+                .is_some_and(|value| value.eq_ignore_ascii_case(label)),
+            None => true,
+        })
+        .filter(|record| match &query {
+            Some(query) => record.text.to_lowercase().contains(query),
+            None => true,
+        })
+        .collect()
+}
+
+pub fn session_labels(records: &[InsertionRecord]) -> Vec<String> {
+    let mut labels = Vec::<String>::new();
+    for label in records
+        .iter()
+        .filter_map(|record| record.session_label.as_deref())
+    {
+        if !labels.iter().any(|known| known.eq_ignore_ascii_case(label)) {
+            labels.push(label.to_string());
+        }
+    }
+    labels
+}
+
+pub fn split_for_insertion(text: &str, max_chars: usize) -> Vec<String> {
+    if max_chars == 0 || text.chars().count() <= max_chars {
+        return vec![text.to_string()];
+    }
+
+    let mut parts = Vec::<String>::new();
+    let mut current = String::new();
+    for sentence in split_sentences(text) {
+        for piece in split_words_to_fit(sentence, max_chars) {
+            let joined_len = current.chars().count() + 1 + piece.chars().count();
+            if current.is_empty() {
+                current = piece;
+            } else if joined_len <= max_chars {
+                current.push(' ');
+                current.push_str(&piece);
+            } else {
+                parts.push(std::mem::replace(&mut current, piece));
+            }
+        }
+    }
+    if !current.is_empty() {
+        parts.push(current);
+    }
+    parts
+}
+
+pub fn truncate_for_insertion(text: &str, max_chars: usize) -> Option<String> {
+    if max_chars == 0 || text.chars().count() <= max_chars {
+        return None;
+    }
+    let clipped = text.chars().take(max_chars).collect::<String>();
+    let at_word_boundary = text.chars().nth(max_chars).is_some_and(char::is_whitespace);
+    let truncated = match clipped.rfind(char::is_whitespace) {
+        Some(index) if !at_word_boundary && index > 0 => clipped[..index].trim_end().to_string(),
+        _ => clipped.trim_end().to_string(),
+    };
+    Some(truncated)
+}
+
+fn split_sentences(text: &str) -> Vec<&str> {
+    let mut sentences = Vec::new();
+    let mut start = 0;
+    let mut chars = text.char_indices().peekable();
+    while let Some((index, value)) = chars.next() {
+        let ends_sentence = matches!(value, '.' | '?' | '!')
+            && chars.peek().is_none_or(|(_, next)| next.is_whitespace());
+        if ends_sentence {
+            let end = index + value.len_utf8();
+            sentences.push(text[start..end].trim());
+            start = end;
+        }
+    }
+    sentences.push(text[start..].trim());
+    sentences.retain(|sentence| !sentence.is_empty());
+    sentences
+}
+
+fn split_words_to_fit(sentence: &str, max_chars: usize) -> Vec<String> {
+    let mut pieces = Vec::<String>::new();
+    let mut current = String::new();
+    for word in sentence.split_whitespace() {
+        let word_chars = word.chars().collect::<Vec<_>>();
+        for chunk in word_chars.chunks(max_chars) {
+            let chunk = chunk.iter().collect::<String>();
+            if current.is_empty() {
+                current = chunk;
+            } else if current.chars().count() + 1 + chunk.chars().count() <= max_chars {
+                current.push(' ');
+                current.push_str(&chunk);
+            } else {
+                pieces.push(std::mem::replace(&mut current, chunk));
+            }
+        }
+    }
+    if !current.is_empty() {
+        pieces.push(current);
+    }
+    pieces
+}
+
 pub fn append_recent(records: &mut Vec<InsertionRecord>, record: InsertionRecord, max: usize) {
     records.insert(0, record);
     records.truncate(max);
 }
 
+pub fn default_history_path() -> PathBuf {
+    let base = dirs::config_dir().unwrap_or_else(|| PathBuf::from("."));
+    base.join("sonora-dictation").join("recent-insertions.json")
+}
+
+pub fn load_recent_or_default(path: &Path, max: usize) -> Vec<InsertionRecord> {
+    let mut records = match fs::read_to_string(path) {
+        Ok(contents) => serde_json::from_str::<Vec<InsertionRecord>>(&contents).unwrap_or_default(),
+        Err(_) => Vec::new(),
+    };
+    records.truncate(max);
+    records
+}
+
+pub fn save_recent(path: &Path, records: &[InsertionRecord]) -> Result<(), String> {
+    let parent = path
+        .parent()
+        .ok_or_else(|| "insertion history path has no parent directory".to_string())?;
+    fs::create_dir_all(parent).map_err(io_to_string)?;
+    let payload = serde_json::to_string_pretty(records).map_err(|error| error.to_string())?;
+    fs::write(path, payload).map_err(io_to_string)
+}
+
+fn io_to_string(error: io::Error) -> String {
+    error.to_string()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    fn temp_file(name: &str) -> PathBuf {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("clock should be set")
+            .as_nanos();
+        std::env::temp_dir().join(format!("sonora-insertions-{name}-{nanos}.json"))
+    }
+
+    fn record(text: &str, status: InsertionStatus) -> InsertionRecord {
+        InsertionRecord::new(0, text.to_string(), status)
+    }
 
     #[test]
     fn prefers_direct_success() {
@@ -59,34 +342,174 @@ mod tests {
         assert_eq!(status, InsertionStatus::Failure);
     }
 
+    #[test]
+    fn filters_history_by_session_label_and_text() {
+        let labeled = |text: &str, label: Option<&str>| InsertionRecord {
+            session_label: normalize_session_label(label),
+            ..record(text, InsertionStatus::Success)
+        };
+        let records = vec![
+            labeled("Invoice follow-up for March", Some("  Acme   Corp ")),
+            labeled("Draft the roadmap", Some("Globex")),
+            labeled("Invoice reminder", None),
+            labeled("Send the invoice", Some("acme corp")),
+        ];
+
+        let acme = search_records(&records, Some("INVOICE"), Some("Acme Corp"));
+        assert_eq!(acme.len(), 2);
+        assert_eq!(acme[0].session_label.as_deref(), Some("Acme Corp"));
+        assert_eq!(search_records(&records, Some("invoice"), None).len(), 3);
+        assert_eq!(search_records(&records, None, Some(" ")).len(), 4);
+        assert_eq!(session_labels(&records), vec!["Acme Corp", "Globex"]);
+    }
+
     #[test]
     fn truncates_history_to_max_length() {
         let mut records = vec![
-            InsertionRecord {
-                text: "one".to_string(),
-                status: InsertionStatus::Success,
-            },
-            InsertionRecord {
-                text: "two".to_string(),
-                status: InsertionStatus::Success,
-            },
-            InsertionRecord {
-                text: "three".to_string(),
-                status: InsertionStatus::Success,
-            },
+            record("one", InsertionStatus::Success),
+            record("two", InsertionStatus::Success),
+            record("three", InsertionStatus::Success),
         ];
         append_recent(
             &mut records,
             InsertionRecord {
-                text: "four".to_string(),
-                status: InsertionStatus::Fallback,
+                language: Some("de".to_string()),
+                ..record("four", InsertionStatus::Fallback)
             },
             3,
         );
 
         assert_eq!(records.len(), 3);
         assert_eq!(records[0].text, "four");
+        assert_eq!(records[0].language.as_deref(), Some("de"));
         assert_eq!(records[1].text, "one");
         assert_eq!(records[2].text, "two");
     }
+
+    #[test]
+    fn persists_and_restores_recent_records_within_capacity() {
+        let path = temp_file("persist");
+        let records = vec![
+            InsertionRecord {
+                language: Some("en".to_string()),
+                ..record("newest", InsertionStatus::Success)
+            },
+            record("older", InsertionStatus::Failure),
+        ];
+
+        save_recent(&path, &records).expect("history should save");
+        assert_eq!(load_recent_or_default(&path, 10), records);
+        assert_eq!(load_recent_or_default(&path, 1), records[..1].to_vec());
+
+        let _ = fs::remove_file(path);
+    }
+
+    #[test]
+    fn missing_history_file_restores_empty_list() {
+        let path = temp_file("missing");
+        assert!(load_recent_or_default(&path, 3).is_empty());
+    }
+
+    #[test]
+    fn verification_only_upgrades_direct_success() {
+        assert_eq!(
+            apply_verification(InsertionStatus::Success, &VerificationOutcome::Verified),
+            InsertionStatus::VerifiedSuccess
+        );
+        assert_eq!(
+            apply_verification(
+                InsertionStatus::Success,
+                &VerificationOutcome::Mismatch("focus moved to Slack".to_string())
+            ),
+            InsertionStatus::Unverified
+        );
+        assert_eq!(
+            apply_verification(InsertionStatus::Fallback, &VerificationOutcome::Verified),
+            InsertionStatus::Fallback
+        );
+        assert!(readback_contains(
+            "Dear team,\n  see you  soon.",
+            "see you soon."
+        ));
+        assert!(!readback_contains("Dear team,", "see you soon."));
+    }
+
+    #[test]
+    fn strategy_override_only_runs_selected_path() {
+        let clipboard_only = resolve_status_for_strategy(
+            InsertionStrategy::Clipboard,
+            false,
+            || panic!("direct path should not run"),
+            || Ok(()),
+        );
+        assert_eq!(clipboard_only, InsertionStatus::Fallback);
+
+        let direct_only = resolve_status_for_strategy(
+            InsertionStrategy::Direct,
+            true,
+            || Err("no focus".to_string()),
+            || panic!("clipboard path should not run"),
+        );
+        assert_eq!(direct_only, InsertionStatus::Failure);
+
+        let auto = resolve_status_for_strategy(
+            InsertionStrategy::Auto,
+            true,
+            || Err("no focus".to_string()),
+            || Ok(()),
+        );
+        assert_eq!(auto, InsertionStatus::Fallback);
+    }
+
+    #[test]
+    fn assigns_monotonic_record_ids_and_finds_by_id() {
+        let mut records = Vec::new();
+        assert_eq!(next_record_id(&records), 1);
+
+        append_recent(
+            &mut records,
+            InsertionRecord::new(7, "seven".to_string(), InsertionStatus::Failure),
+            3,
+        );
+        assert_eq!(next_record_id(&records), 8);
+        assert_eq!(
+            find_record(&records, 7).map(|record| record.text.as_str()),
+            Some("seven")
+        );
+        assert!(find_record(&records, 8).is_none());
+    }
+
+    #[test]
+    fn splits_long_text_on_sentence_boundaries() {
+        let text = "First sentence here. Second one is longer than that! Third?";
+        assert_eq!(
+            split_for_insertion(text, 40),
+            vec![
+                "First sentence here.",
+                "Second one is longer than that! Third?",
+            ]
+        );
+        assert_eq!(split_for_insertion(text, 0), vec![text]);
+        assert_eq!(
+            split_for_insertion("one two three four", 9),
+            vec!["one two", "three", "four"]
+        );
+        assert_eq!(
+            split_for_insertion("abcdefghij", 4),
+            vec!["abcd", "efgh", "ij"]
+        );
+    }
+
+    #[test]
+    fn truncates_on_word_boundaries() {
+        assert_eq!(truncate_for_insertion("short", 10), None);
+        assert_eq!(
+            truncate_for_insertion("hello wonderful world", 12),
+            Some("hello".to_string())
+        );
+        assert_eq!(
+            truncate_for_insertion("hello world again", 11),
+            Some("hello world".to_string())
+        );
+    }
 }
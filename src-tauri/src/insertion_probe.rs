@@ -0,0 +1,466 @@
+use serde::Serialize;
+use std::process::{Command, Stdio};
+
+#[cfg(target_os = "windows")]
+use std::os::windows::process::CommandExt;
+
+#[cfg(target_os = "windows")]
+const CREATE_NO_WINDOW: u32 = 0x08000000;
+
+// Tools looked up on PATH; nothing is ever run with text to type.
+const LINUX_PROGRAMS: [&str; 6] = ["xdotool", "wtype", "ydotool", "wl-copy", "xclip", "xsel"];
+// /proc comm names are cut to 15 bytes.
+const LINUX_PROCESSES: [&str; 6] = [
+    "ydotoold",
+    "at-spi-bus-laun",
+    "at-spi2-registr",
+    "ibus-daemon",
+    "fcitx5",
+    "fcitx",
+];
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ProbeStrategy {
+    DirectTyping,
+    ClipboardPaste,
+    // AT-SPI or an input method on Linux, the AX API on macOS, UI Automation
+    // on Windows.
+    Accessibility,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CapabilityLevel {
+    Ready,
+    // Works with a manual step, e.g. the text is copied but must be pasted by hand.
+    Partial,
+    Unavailable,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct StrategyCapability {
+    pub strategy: ProbeStrategy,
+    pub level: CapabilityLevel,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub backend: Option<String>,
+    pub detail: String,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct InsertionCapabilities {
+    pub os: String,
+    pub strategies: Vec<StrategyCapability>,
+    // The first ready strategy in the order auto insertion tries them.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recommended: Option<ProbeStrategy>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProbeEnvironment {
+    pub os: String,
+    pub wayland: bool,
+    pub x11: bool,
+    pub programs: Vec<String>,
+    pub processes: Vec<String>,
+    pub atspi_bus: bool,
+    // macOS accessibility permission; None when it could not be read.
+    pub accessibility_trusted: Option<bool>,
+}
+
+impl ProbeEnvironment {
+    pub fn detect() -> Self {
+        let os = std::env::consts::OS.to_string();
+        let mut environment = Self {
+            wayland: std::env::var_os("WAYLAND_DISPLAY").is_some(),
+            x11: std::env::var_os("DISPLAY").is_some(),
+            atspi_bus: std::env::var_os("AT_SPI_BUS_ADDRESS").is_some(),
+            ..Self::default()
+        };
+        match os.as_str() {
+            "linux" => {
+                environment.programs = present_programs(&LINUX_PROGRAMS);
+                environment.processes = running_linux_processes(&LINUX_PROCESSES);
+            }
+            "macos" => {
+                environment.programs = present_programs(&["pbcopy"]);
+                environment.accessibility_trusted = macos_accessibility_trusted();
+            }
+            "windows" => environment.programs = present_programs(&["powershell"]),
+            _ => {}
+        }
+        environment.os = os;
+        environment
+    }
+
+    fn has_program(&self, program: &str) -> bool {
+        self.programs.iter().any(|name| name == program)
+    }
+
+    fn has_process(&self, process: &str) -> bool {
+        self.processes.iter().any(|name| name == process)
+    }
+}
+
+pub fn probe() -> InsertionCapabilities {
+    evaluate(&ProbeEnvironment::detect())
+}
+
+pub fn evaluate(environment: &ProbeEnvironment) -> InsertionCapabilities {
+    let strategies = match environment.os.as_str() {
+        "linux" => linux_capabilities(environment),
+        "macos" => macos_capabilities(environment),
+        "windows" => windows_capabilities(environment),
+        os => [
+            ProbeStrategy::DirectTyping,
+            ProbeStrategy::ClipboardPaste,
+            ProbeStrategy::Accessibility,
+        ]
+        .into_iter()
+        .map(|strategy| {
+            capability(
+                strategy,
+                CapabilityLevel::Unavailable,
+                None,
+                format!("text insertion is not supported on {os}"),
+            )
+        })
+        .collect(),
+    };
+    let recommended = strategies
+        .iter()
+        .find(|capability| capability.level == CapabilityLevel::Ready)
+        .map(|capability| capability.strategy);
+    InsertionCapabilities {
+        os: environment.os.clone(),
+        strategies,
+        recommended,
+    }
+}
+
+fn capability(
+    strategy: ProbeStrategy,
+    level: CapabilityLevel,
+    backend: Option<&str>,
+    detail: String,
+) -> StrategyCapability {
+    StrategyCapability {
+        strategy,
+        level,
+        backend: backend.map(str::to_string),
+        detail,
+    }
+}
+
+// The keystroke tool usable in this session: wtype and ydotool on Wayland
+// (ydotool only with its daemon up), xdotool on X11.
+fn linux_keystroke_tool(environment: &ProbeEnvironment) -> Option<&'static str> {
+    if environment.wayland {
+        if environment.has_program("wtype") {
+            return Some("wtype");
+        }
+        if environment.has_program("ydotool") && environment.has_process("ydotoold") {
+            return Some("ydotool");
+        }
+        None
+    } else if environment.x11 && environment.has_program("xdotool") {
+        Some("xdotool")
+    } else {
+        None
+    }
+}
+
+fn linux_capabilities(environment: &ProbeEnvironment) -> Vec<StrategyCapability> {
+    let keystrokes = linux_keystroke_tool(environment);
+    let missing_keystrokes = if environment.wayland {
+        "no keystroke tool for Wayland (install wtype, or ydotool with ydotoold running)"
+    } else if environment.x11 {
+        "xdotool is not installed"
+    } else {
+        "no graphical session was found"
+    };
+    let direct = match keystrokes {
+        Some(tool) => capability(
+            ProbeStrategy::DirectTyping,
+            CapabilityLevel::Ready,
+            Some(tool),
+            format!("text is typed into the focused window with {tool}"),
+        ),
+        None => capability(
+            ProbeStrategy::DirectTyping,
+            CapabilityLevel::Unavailable,
+            None,
+            missing_keystrokes.to_string(),
+        ),
+    };
+
+    let clipboard_tool = ["wl-copy", "xclip", "xsel"]
+        .into_iter()
+        .filter(|tool| environment.wayland || *tool != "wl-copy")
+        .find(|tool| environment.has_program(tool));
+    let clipboard = match (clipboard_tool, keystrokes) {
+        (Some(copy), Some(paste)) => capability(
+            ProbeStrategy::ClipboardPaste,
+            CapabilityLevel::Ready,
+            Some(copy),
+            format!("text is copied with {copy} and pasted with {paste}"),
+        ),
+        (Some(copy), None) => capability(
+            ProbeStrategy::ClipboardPaste,
+            CapabilityLevel::Partial,
+            Some(copy),
+            format!("text is copied with {copy} but must be pasted by hand: {missing_keystrokes}"),
+        ),
+        (None, _) => capability(
+            ProbeStrategy::ClipboardPaste,
+            CapabilityLevel::Unavailable,
+            None,
+            if environment.wayland {
+                "no clipboard tool found (install wl-clipboard)".to_string()
+            } else {
+                "no clipboard tool found (install xclip or xsel)".to_string()
+            },
+        ),
+    };
+
+    let input_method = ["ibus-daemon", "fcitx5", "fcitx"]
+        .into_iter()
+        .find(|process| environment.has_process(process));
+    let atspi = environment.atspi_bus
+        || environment.has_process("at-spi-bus-laun")
+        || environment.has_process("at-spi2-registr");
+    let accessibility = match (atspi, input_method) {
+        (true, _) => capability(
+            ProbeStrategy::Accessibility,
+            CapabilityLevel::Ready,
+            Some("at-spi"),
+            "the AT-SPI bus is running; fields that expose editable text accept it directly"
+                .to_string(),
+        ),
+        (false, Some(daemon)) => capability(
+            ProbeStrategy::Accessibility,
+            CapabilityLevel::Ready,
+            Some(daemon),
+            format!("{daemon} is running and can commit text to the focused field"),
+        ),
+        (false, None) => capability(
+            ProbeStrategy::Accessibility,
+            CapabilityLevel::Unavailable,
+            None,
+            "neither the AT-SPI bus nor an input method (IBus, Fcitx) is running".to_string(),
+        ),
+    };
+    vec![direct, clipboard, accessibility]
+}
+
+fn macos_capabilities(environment: &ProbeEnvironment) -> Vec<StrategyCapability> {
+    let (level, permission) = match environment.accessibility_trusted {
+        Some(true) => (CapabilityLevel::Ready, "accessibility access is granted"),
+        Some(false) => (
+            CapabilityLevel::Unavailable,
+            "grant accessibility access in System Settings > Privacy & Security",
+        ),
+        None => (
+            CapabilityLevel::Unavailable,
+            "accessibility access could not be checked",
+        ),
+    };
+    let direct = capability(
+        ProbeStrategy::DirectTyping,
+        level,
+        Some("cg_event"),
+        format!("keystrokes are posted as CGEvents; {permission}"),
+    );
+    let clipboard = if !environment.has_program("pbcopy") {
+        capability(
+            ProbeStrategy::ClipboardPaste,
+            CapabilityLevel::Unavailable,
+            None,
+            "pbcopy was not found".to_string(),
+        )
+    } else if level == CapabilityLevel::Ready {
+        capability(
+            ProbeStrategy::ClipboardPaste,
+            CapabilityLevel::Ready,
+            Some("pbcopy"),
+            "text is copied with pbcopy and pasted with Cmd+V".to_string(),
+        )
+    } else {
+        capability(
+            ProbeStrategy::ClipboardPaste,
+            CapabilityLevel::Partial,
+            Some("pbcopy"),
+            format!("text is copied but must be pasted by hand; {permission}"),
+        )
+    };
+    let accessibility = capability(
+        ProbeStrategy::Accessibility,
+        level,
+        Some("ax_api"),
+        format!("the focused field's AXValue is set directly; {permission}"),
+    );
+    vec![direct, clipboard, accessibility]
+}
+
+fn windows_capabilities(environment: &ProbeEnvironment) -> Vec<StrategyCapability> {
+    let powershell = environment.has_program("powershell");
+    let direct = capability(
+        ProbeStrategy::DirectTyping,
+        CapabilityLevel::Ready,
+        Some("send_input"),
+        "keystrokes are sent with SendInput; windows running as administrator ignore them"
+            .to_string(),
+    );
+    let clipboard = if powershell {
+        capability(
+            ProbeStrategy::ClipboardPaste,
+            CapabilityLevel::Ready,
+            Some("powershell"),
+            "text is copied outside clipboard history and pasted with Ctrl+V".to_string(),
+        )
+    } else {
+        capability(
+            ProbeStrategy::ClipboardPaste,
+            CapabilityLevel::Unavailable,
+            None,
+            "powershell was not found".to_string(),
+        )
+    };
+    let accessibility = if powershell {
+        capability(
+            ProbeStrategy::Accessibility,
+            CapabilityLevel::Ready,
+            Some("ui_automation"),
+            "fields exposing the UI Automation value pattern are written directly".to_string(),
+        )
+    } else {
+        capability(
+            ProbeStrategy::Accessibility,
+            CapabilityLevel::Unavailable,
+            None,
+            "powershell was not found".to_string(),
+        )
+    };
+    vec![direct, clipboard, accessibility]
+}
+
+fn present_programs(candidates: &[&str]) -> Vec<String> {
+    let Some(paths) = std::env::var_os("PATH") else {
+        return Vec::new();
+    };
+    let dirs = std::env::split_paths(&paths).collect::<Vec<_>>();
+    let suffix = if cfg!(target_os = "windows") {
+        ".exe"
+    } else {
+        ""
+    };
+    candidates
+        .iter()
+        .filter(|program| {
+            dirs.iter()
+                .any(|dir| dir.join(format!("{program}{suffix}")).is_file())
+        })
+        .map(|program| program.to_string())
+        .collect()
+}
+
+fn running_linux_processes(wanted: &[&str]) -> Vec<String> {
+    let mut found = std::fs::read_dir("/proc")
+        .map(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .filter_map(|entry| std::fs::read_to_string(entry.path().join("comm")).ok())
+                .map(|name| name.trim().to_string())
+                .filter(|name| wanted.contains(&name.as_str()))
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    found.sort();
+    found.dedup();
+    found
+}
+
+// Reading `UI elements enabled` asks System Events for the permission state
+// without sending any keystroke.
+fn macos_accessibility_trusted() -> Option<bool> {
+    let mut command = Command::new("osascript");
+    command
+        .args([
+            "-e",
+            "tell application \"System Events\" to get UI elements enabled",
+        ])
+        .stdin(Stdio::null())
+        .stderr(Stdio::null());
+
+    #[cfg(target_os = "windows")]
+    {
+        command.creation_flags(CREATE_NO_WINDOW);
+    }
+
+    let output = command.output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    match String::from_utf8_lossy(&output.stdout).trim() {
+        "true" => Some(true),
+        "false" => Some(false),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn linux(wayland: bool, programs: &[&str], processes: &[&str]) -> ProbeEnvironment {
+        ProbeEnvironment {
+            os: "linux".to_string(),
+            wayland,
+            x11: true,
+            programs: programs.iter().map(|name| name.to_string()).collect(),
+            processes: processes.iter().map(|name| name.to_string()).collect(),
+            ..ProbeEnvironment::default()
+        }
+    }
+
+    #[test]
+    fn wayland_without_keystroke_tool_falls_back_to_manual_paste() {
+        let report = evaluate(&linux(true, &["xdotool", "wl-copy", "ydotool"], &[]));
+        assert_eq!(report.strategies[0].level, CapabilityLevel::Unavailable);
+        assert!(report.strategies[0].detail.contains("ydotoold"));
+        assert_eq!(report.strategies[1].level, CapabilityLevel::Partial);
+        assert_eq!(report.strategies[1].backend.as_deref(), Some("wl-copy"));
+        assert_eq!(report.strategies[2].level, CapabilityLevel::Unavailable);
+        assert_eq!(report.recommended, None);
+
+        let report = evaluate(&linux(
+            true,
+            &["wl-copy", "ydotool"],
+            &["ydotoold", "ibus-daemon"],
+        ));
+        assert_eq!(report.strategies[0].backend.as_deref(), Some("ydotool"));
+        assert_eq!(report.strategies[1].level, CapabilityLevel::Ready);
+        assert_eq!(report.strategies[2].backend.as_deref(), Some("ibus-daemon"));
+        assert_eq!(report.recommended, Some(ProbeStrategy::DirectTyping));
+    }
+
+    #[test]
+    fn macos_strategies_follow_the_accessibility_permission() {
+        let mut environment = ProbeEnvironment {
+            os: "macos".to_string(),
+            programs: vec!["pbcopy".to_string()],
+            accessibility_trusted: Some(false),
+            ..ProbeEnvironment::default()
+        };
+        let report = evaluate(&environment);
+        assert_eq!(report.strategies[0].level, CapabilityLevel::Unavailable);
+        assert_eq!(report.strategies[1].level, CapabilityLevel::Partial);
+        assert_eq!(report.recommended, None);
+
+        environment.accessibility_trusted = Some(true);
+        assert_eq!(
+            evaluate(&environment).recommended,
+            Some(ProbeStrategy::DirectTyping)
+        );
+    }
+}
@@ -0,0 +1,72 @@
+use serde::Serialize;
+
+// One edit to the text already typed for an utterance: delete `backspaces`
+// characters from the end, then type `text`.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct InstantEdit {
+    pub session_id: u64,
+    pub backspaces: usize,
+    pub text: String,
+    pub is_final: bool,
+}
+
+pub fn edit_between(typed: &str, target: &str) -> (usize, String) {
+    let common = typed
+        .chars()
+        .zip(target.chars())
+        .take_while(|(left, right)| left == right)
+        .count();
+    let backspaces = typed.chars().count() - common;
+    (backspaces, target.chars().skip(common).collect())
+}
+
+// Provisional edits are skipped when nothing changed; the final edit is always
+// sent so the receiver knows the utterance is settled.
+pub fn next_edit(
+    session_id: u64,
+    typed: &str,
+    target: &str,
+    is_final: bool,
+) -> Option<InstantEdit> {
+    let (backspaces, text) = edit_between(typed, target);
+    if backspaces == 0 && text.is_empty() && !is_final {
+        return None;
+    }
+    Some(InstantEdit {
+        session_id,
+        backspaces,
+        text,
+        is_final,
+    })
+}
+
+pub fn apply_edit(typed: &str, edit: &InstantEdit) -> String {
+    let keep = typed.chars().count().saturating_sub(edit.backspaces);
+    typed.chars().take(keep).chain(edit.text.chars()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn corrects_only_the_diverging_tail() {
+        let edit = next_edit(3, "send the report two", "send the report to Anna", false)
+            .expect("changed text should edit");
+        assert_eq!(edit.backspaces, 2);
+        assert_eq!(edit.text, "o Anna");
+        assert_eq!(
+            apply_edit("send the report two", &edit),
+            "send the report to Anna"
+        );
+        assert_eq!(edit_between("café", "cafe"), (1, "e".to_string()));
+    }
+
+    #[test]
+    fn final_edit_is_sent_even_without_changes() {
+        assert_eq!(next_edit(1, "hello", "hello", false), None);
+        let edit = next_edit(1, "hello", "hello", true).expect("final edit");
+        assert_eq!((edit.backspaces, edit.text.as_str()), (0, ""));
+        assert!(edit.is_final);
+    }
+}
@@ -0,0 +1,213 @@
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs::{self, File};
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::SystemTime;
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum IntegrityStatus {
+    Verified,
+    Modified { expected: String, actual: String },
+    Unrecorded,
+    PathResolved,
+    Unreadable { reason: String },
+}
+
+impl IntegrityStatus {
+    pub fn is_trusted(&self) -> bool {
+        matches!(self, Self::Verified | Self::Unrecorded)
+    }
+
+    pub fn warning(&self, binary_path: &Path) -> Option<String> {
+        let path = binary_path.to_string_lossy();
+        match self {
+            Self::Verified | Self::Unrecorded => None,
+            Self::Modified { expected, actual } => Some(format!(
+                "sidecar binary '{path}' does not match its recorded hash (expected {expected}, found {actual})"
+            )),
+            Self::PathResolved => Some(format!(
+                "sidecar binary '{path}' was resolved from PATH and is not a bundled binary"
+            )),
+            Self::Unreadable { reason } => Some(format!(
+                "sidecar binary '{path}' could not be hashed: {reason}"
+            )),
+        }
+    }
+}
+
+// A verdict holds while the binary keeps its size and mtime and the recorded
+// hash stays the same, so rebuilding a runtime does not rehash the sidecar.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct VerdictKey {
+    path: PathBuf,
+    modified: Option<SystemTime>,
+    len: u64,
+    expected: String,
+}
+
+static VERDICTS: Mutex<Vec<(VerdictKey, IntegrityStatus)>> = Mutex::new(Vec::new());
+
+#[derive(Debug, Deserialize)]
+struct SidecarHashMetadata {
+    sha256: Option<String>,
+}
+
+pub fn is_path_resolved(binary_path: &Path) -> bool {
+    binary_path.components().count() == 1
+}
+
+pub fn sha256_file(path: &Path) -> Result<String, String> {
+    let mut file = File::open(path).map_err(io_to_string)?;
+    let mut hasher = Sha256::new();
+    let mut buffer = [0u8; 64 * 1024];
+    loop {
+        let read = file.read(&mut buffer).map_err(io_to_string)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+
+    Ok(hasher
+        .finalize()
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect())
+}
+
+pub fn expected_sha256(binary_path: &Path, metadata_file_name: &str) -> Option<String> {
+    let metadata_path: PathBuf = binary_path.parent()?.join(metadata_file_name);
+    let raw = fs::read_to_string(metadata_path).ok()?;
+    let parsed = serde_json::from_str::<SidecarHashMetadata>(&raw).ok()?;
+    parsed
+        .sha256
+        .map(|value| value.trim().to_ascii_lowercase())
+        .filter(|value| !value.is_empty())
+}
+
+pub fn verify_sidecar_binary(binary_path: &Path, metadata_file_name: &str) -> IntegrityStatus {
+    if is_path_resolved(binary_path) {
+        return IntegrityStatus::PathResolved;
+    }
+    let Some(expected) = expected_sha256(binary_path, metadata_file_name) else {
+        return IntegrityStatus::Unrecorded;
+    };
+    let metadata = match fs::metadata(binary_path) {
+        Ok(metadata) => metadata,
+        Err(error) => {
+            return IntegrityStatus::Unreadable {
+                reason: error.to_string(),
+            }
+        }
+    };
+    let key = VerdictKey {
+        path: binary_path.to_path_buf(),
+        modified: metadata.modified().ok(),
+        len: metadata.len(),
+        expected: expected.clone(),
+    };
+    if let Some(status) = cached_verdict(&key) {
+        return status;
+    }
+
+    let status = match sha256_file(binary_path) {
+        Ok(actual) if actual == expected => IntegrityStatus::Verified,
+        Ok(actual) => IntegrityStatus::Modified { expected, actual },
+        Err(reason) => return IntegrityStatus::Unreadable { reason },
+    };
+    if let Ok(mut verdicts) = VERDICTS.lock() {
+        verdicts.retain(|(known, _)| known.path != key.path);
+        verdicts.push((key, status.clone()));
+    }
+    status
+}
+
+fn cached_verdict(key: &VerdictKey) -> Option<IntegrityStatus> {
+    let verdicts = VERDICTS.lock().ok()?;
+    verdicts
+        .iter()
+        .find(|(known, _)| known == key)
+        .map(|(_, status)| status.clone())
+}
+
+fn io_to_string(error: io::Error) -> String {
+    error.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("clock should be set")
+            .as_nanos();
+        std::env::temp_dir().join(format!("sonora-integrity-{name}-{nanos}"))
+    }
+
+    #[test]
+    fn hashes_files_as_lowercase_hex() {
+        let dir = temp_dir("hash");
+        fs::create_dir_all(&dir).expect("temp dir should be created");
+        let path = dir.join("worker");
+        fs::write(&path, b"abc").expect("binary should write");
+
+        let digest = sha256_file(&path).expect("file should hash");
+        let _ = fs::remove_dir_all(&dir);
+        assert_eq!(
+            digest,
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn verifies_binaries_against_recorded_hashes() {
+        let dir = temp_dir("verify");
+        fs::create_dir_all(&dir).expect("temp dir should be created");
+        let binary = dir.join("whisper-cli");
+        fs::write(&binary, b"abc").expect("binary should write");
+
+        assert_eq!(
+            verify_sidecar_binary(&binary, "whisper-sidecar.json"),
+            IntegrityStatus::Unrecorded
+        );
+
+        fs::write(
+            dir.join("whisper-sidecar.json"),
+            "{\"backend\":\"cpu\",\"sha256\":\"BA7816BF8F01CFEA414140DE5DAE2223B00361A396177A9CB410FF61F20015AD\"}",
+        )
+        .expect("metadata should write");
+        assert_eq!(
+            verify_sidecar_binary(&binary, "whisper-sidecar.json"),
+            IntegrityStatus::Verified
+        );
+
+        assert!(cached_verdict(&VerdictKey {
+            path: binary.clone(),
+            modified: fs::metadata(&binary).and_then(|meta| meta.modified()).ok(),
+            len: 3,
+            expected: "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+                .to_string(),
+        })
+        .is_some());
+
+        fs::write(&binary, b"tampered").expect("binary should write");
+        let status = verify_sidecar_binary(&binary, "whisper-sidecar.json");
+        let _ = fs::remove_dir_all(&dir);
+        assert!(matches!(status, IntegrityStatus::Modified { .. }));
+        assert!(!status.is_trusted());
+        assert!(status.warning(&binary).is_some());
+    }
+
+    #[test]
+    fn flags_binaries_resolved_from_path() {
+        let status = verify_sidecar_binary(Path::new("whisper-cli"), "whisper-sidecar.json");
+        assert_eq!(status, IntegrityStatus::PathResolved);
+        assert!(!status.is_trusted());
+    }
+}
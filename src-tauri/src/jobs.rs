@@ -0,0 +1,266 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+pub const MAX_JOB_HISTORY: usize = 200;
+
+const AUDIO_EXTENSIONS: &[&str] = &[
+    "wav", "mp3", "m4a", "mp4", "aac", "ogg", "opus", "oga", "flac", "webm",
+];
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum JobStatus {
+    Completed,
+    Failed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct JobRecord {
+    pub id: u64,
+    pub source_path: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transcript_path: Option<String>,
+    pub status: JobStatus,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(default)]
+    pub audio_duration_ms: u64,
+    pub started_unix_ms: u64,
+    pub finished_unix_ms: u64,
+}
+
+#[derive(Debug, Default)]
+pub struct DropFolderScanner {
+    observed_sizes: HashMap<PathBuf, u64>,
+    // Sources run this session, in case their transcript or marker could not
+    // be written.
+    handled: HashSet<PathBuf>,
+}
+
+impl DropFolderScanner {
+    // A source is done once its transcript or failure marker sits beside it,
+    // so clearing or trimming the job history never requeues it.
+    pub fn scan(&mut self, folder: &Path) -> io::Result<Vec<PathBuf>> {
+        let entries = list_audio_files(folder)?;
+        let handled = std::mem::take(&mut self.handled);
+        let ready =
+            self.collect_ready(entries, |path| handled.contains(path) || is_processed(path));
+        self.handled = handled;
+        Ok(ready)
+    }
+
+    pub fn mark_handled(&mut self, source: &Path) {
+        self.handled.insert(source.to_path_buf());
+    }
+
+    pub fn collect_ready(
+        &mut self,
+        entries: Vec<(PathBuf, u64)>,
+        already_handled: impl Fn(&Path) -> bool,
+    ) -> Vec<PathBuf> {
+        let mut ready = Vec::new();
+        let mut next_sizes = HashMap::new();
+
+        for (path, size) in entries {
+            if already_handled(&path) {
+                continue;
+            }
+            if size > 0 && self.observed_sizes.get(&path) == Some(&size) {
+                ready.push(path);
+            } else {
+                next_sizes.insert(path, size);
+            }
+        }
+
+        self.observed_sizes = next_sizes;
+        ready.sort();
+        ready
+    }
+}
+
+pub fn is_audio_candidate(path: &Path) -> bool {
+    path.extension()
+        .and_then(|value| value.to_str())
+        .map(|value| {
+            let lowered = value.to_ascii_lowercase();
+            AUDIO_EXTENSIONS.contains(&lowered.as_str())
+        })
+        .unwrap_or(false)
+}
+
+pub fn transcript_path_for(source: &Path) -> PathBuf {
+    let mut name = source.file_name().unwrap_or_default().to_os_string();
+    name.push(".txt");
+    source.with_file_name(name)
+}
+
+// Holds the error of a failed job; deleting it queues the source again.
+pub fn failed_marker_path_for(source: &Path) -> PathBuf {
+    let mut name = source.file_name().unwrap_or_default().to_os_string();
+    name.push(".failed");
+    source.with_file_name(name)
+}
+
+pub fn is_processed(source: &Path) -> bool {
+    transcript_path_for(source).exists() || failed_marker_path_for(source).exists()
+}
+
+pub fn mark_failed(source: &Path, error: &str) -> Result<(), String> {
+    fs::write(failed_marker_path_for(source), format!("{error}\n")).map_err(io_to_string)
+}
+
+pub fn next_job_id(history: &[JobRecord]) -> u64 {
+    history
+        .iter()
+        .map(|record| record.id)
+        .max()
+        .unwrap_or(0)
+        .saturating_add(1)
+}
+
+pub fn append_job(history: &mut Vec<JobRecord>, record: JobRecord) {
+    history.insert(0, record);
+    history.truncate(MAX_JOB_HISTORY);
+}
+
+pub fn default_jobs_path() -> PathBuf {
+    let base = dirs::config_dir().unwrap_or_else(|| PathBuf::from("."));
+    base.join("sonora-dictation")
+        .join("transcription-jobs.json")
+}
+
+pub fn load_jobs_or_default(path: &Path) -> Vec<JobRecord> {
+    let mut records = match fs::read_to_string(path) {
+        Ok(contents) => serde_json::from_str::<Vec<JobRecord>>(&contents).unwrap_or_default(),
+        Err(_) => Vec::new(),
+    };
+    records.truncate(MAX_JOB_HISTORY);
+    records
+}
+
+pub fn save_jobs(path: &Path, records: &[JobRecord]) -> Result<(), String> {
+    let parent = path
+        .parent()
+        .ok_or_else(|| "job history path has no parent directory".to_string())?;
+    fs::create_dir_all(parent).map_err(io_to_string)?;
+    let payload = serde_json::to_string_pretty(records).map_err(|error| error.to_string())?;
+    fs::write(path, payload).map_err(io_to_string)
+}
+
+fn list_audio_files(folder: &Path) -> io::Result<Vec<(PathBuf, u64)>> {
+    let mut files = Vec::new();
+    for entry in fs::read_dir(folder)? {
+        let entry = entry?;
+        let path = entry.path();
+        let metadata = entry.metadata()?;
+        if metadata.is_file() && is_audio_candidate(&path) {
+            files.push((path, metadata.len()));
+        }
+    }
+    Ok(files)
+}
+
+fn io_to_string(error: io::Error) -> String {
+    error.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    fn temp_path(name: &str) -> PathBuf {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("clock should be set")
+            .as_nanos();
+        std::env::temp_dir().join(format!("sonora-jobs-{name}-{nanos}"))
+    }
+
+    fn job(id: u64, source: &str) -> JobRecord {
+        JobRecord {
+            id,
+            source_path: source.to_string(),
+            transcript_path: None,
+            status: JobStatus::Completed,
+            error: None,
+            audio_duration_ms: 0,
+            started_unix_ms: 0,
+            finished_unix_ms: 0,
+        }
+    }
+
+    #[test]
+    fn waits_for_file_size_to_settle_before_queueing() {
+        let mut scanner = DropFolderScanner::default();
+        let memo = PathBuf::from("/drop/memo.m4a");
+
+        let first = scanner.collect_ready(vec![(memo.clone(), 100)], |_| false);
+        assert!(first.is_empty());
+        let growing = scanner.collect_ready(vec![(memo.clone(), 400)], |_| false);
+        assert!(growing.is_empty());
+        let settled = scanner.collect_ready(vec![(memo.clone(), 400)], |_| false);
+        assert_eq!(settled, vec![memo.clone()]);
+
+        let handled = scanner.collect_ready(vec![(memo.clone(), 400)], |_| true);
+        assert!(handled.is_empty());
+    }
+
+    #[test]
+    fn filters_audio_extensions_and_names_transcripts_beside_sources() {
+        assert!(is_audio_candidate(Path::new("/drop/interview.MP3")));
+        assert!(is_audio_candidate(Path::new("/drop/memo.opus")));
+        assert!(!is_audio_candidate(Path::new("/drop/notes.txt")));
+        assert!(!is_audio_candidate(Path::new("/drop/README")));
+        assert_eq!(
+            transcript_path_for(Path::new("/drop/interview.mp3")),
+            PathBuf::from("/drop/interview.mp3.txt")
+        );
+    }
+
+    #[test]
+    fn scans_folder_and_skips_transcribed_or_failed_sources() {
+        let folder = temp_path("drop");
+        fs::create_dir_all(&folder).expect("drop folder should be created");
+        fs::write(folder.join("a.wav"), b"RIFF").expect("file should write");
+        fs::write(folder.join("b.wav"), b"RIFF").expect("file should write");
+        fs::write(folder.join("b.wav.txt"), b"done").expect("file should write");
+        fs::write(folder.join("c.mp3"), b"ID3").expect("file should write");
+        fs::write(folder.join("d.ogg"), b"OggS").expect("file should write");
+        fs::write(folder.join("notes.txt"), b"ignore").expect("file should write");
+        mark_failed(&folder.join("c.mp3"), "unsupported codec").expect("marker should write");
+
+        let mut scanner = DropFolderScanner::default();
+        scanner.mark_handled(&folder.join("d.ogg"));
+        assert!(scanner.scan(&folder).expect("scan").is_empty());
+        let ready = scanner.scan(&folder).expect("scan");
+
+        fs::remove_file(failed_marker_path_for(&folder.join("c.mp3"))).expect("marker");
+        let _ = scanner.scan(&folder).expect("scan");
+        let retried = scanner.scan(&folder).expect("scan");
+        let _ = fs::remove_dir_all(&folder);
+
+        assert_eq!(ready, vec![folder.join("a.wav")]);
+        assert_eq!(retried, vec![folder.join("a.wav"), folder.join("c.mp3")]);
+    }
+
+    #[test]
+    fn persists_bounded_job_history() {
+        let path = temp_path("history.json");
+        let mut history = Vec::new();
+        for index in 0..(MAX_JOB_HISTORY + 5) {
+            let id = next_job_id(&history);
+            append_job(&mut history, job(id, &format!("/drop/{index}.wav")));
+        }
+        assert_eq!(history.len(), MAX_JOB_HISTORY);
+        assert_eq!(history[0].id, (MAX_JOB_HISTORY + 5) as u64);
+
+        save_jobs(&path, &history).expect("history should save");
+        let loaded = load_jobs_or_default(&path);
+        let _ = fs::remove_file(&path);
+        assert_eq!(loaded, history);
+    }
+}
@@ -0,0 +1,133 @@
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use crate::config::ModelProfile;
+use crate::meeting::format_wall_clock_utc;
+
+// Days since the epoch to a proleptic Gregorian (year, month, day).
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+pub fn format_date_utc(unix_ms: u64) -> String {
+    let (year, month, day) = civil_from_days((unix_ms / 86_400_000) as i64);
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+pub fn resolve_directory(configured: &str) -> PathBuf {
+    match configured.strip_prefix("~/").zip(dirs::home_dir()) {
+        Some((rest, home)) => home.join(rest),
+        None => PathBuf::from(configured),
+    }
+}
+
+pub fn journal_path(directory: &Path, unix_ms: u64) -> PathBuf {
+    directory.join(format!("{}.md", format_date_utc(unix_ms)))
+}
+
+// No profile filter journals every final transcript.
+pub fn accepts_profile(filter: Option<ModelProfile>, active: ModelProfile) -> bool {
+    filter.is_none_or(|profile| profile == active)
+}
+
+pub fn format_entry(text: &str, unix_ms: u64, session_label: Option<&str>) -> Option<String> {
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if text.is_empty() {
+        return None;
+    }
+    let label = session_label
+        .map(|label| format!(" _({label})_"))
+        .unwrap_or_default();
+    Some(format!(
+        "- **{}**{label} {text}\n",
+        format_wall_clock_utc(unix_ms)
+    ))
+}
+
+pub fn append_entry(
+    directory: &Path,
+    text: &str,
+    unix_ms: u64,
+    session_label: Option<&str>,
+) -> Result<Option<PathBuf>, String> {
+    let Some(entry) = format_entry(text, unix_ms, session_label) else {
+        return Ok(None);
+    };
+    fs::create_dir_all(directory).map_err(io_to_string)?;
+    let path = journal_path(directory, unix_ms);
+    let heading = if path.exists() {
+        String::new()
+    } else {
+        format!("# {}\n\n", format_date_utc(unix_ms))
+    };
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .map_err(io_to_string)?;
+    file.write_all(format!("{heading}{entry}").as_bytes())
+        .map_err(io_to_string)?;
+    Ok(Some(path))
+}
+
+fn io_to_string(error: io::Error) -> String {
+    error.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    #[test]
+    fn formats_utc_dates() {
+        assert_eq!(format_date_utc(0), "1970-01-01");
+        assert_eq!(format_date_utc(951_782_400_000), "2000-02-29");
+        assert_eq!(format_date_utc(1_700_000_000_000), "2023-11-14");
+        assert!(accepts_profile(None, ModelProfile::Fast));
+        assert!(!accepts_profile(
+            Some(ModelProfile::Balanced),
+            ModelProfile::Fast
+        ));
+    }
+
+    #[test]
+    fn appends_entries_to_a_dated_markdown_file() {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("clock should be set")
+            .as_nanos();
+        let directory = std::env::temp_dir().join(format!("sonora-journal-{nanos}"));
+        let morning = 1_700_000_000_000;
+
+        let path = append_entry(&directory, "Call the  supplier.", morning, None)
+            .expect("entry should append")
+            .expect("entry should be written");
+        append_entry(&directory, "  ", morning + 1_000, None).expect("blank entry is skipped");
+        append_entry(&directory, "Ship the fix.", morning + 60_000, Some("Acme"))
+            .expect("entry should append");
+
+        let contents = fs::read_to_string(&path).expect("journal should exist");
+        let _ = fs::remove_dir_all(&directory);
+        assert!(path.ends_with("2023-11-14.md"));
+        assert_eq!(
+            contents,
+            "# 2023-11-14\n\n- **22:13:20 UTC** Call the supplier.\n- **22:14:20 UTC** _(Acme)_ Ship the fix.\n"
+        );
+    }
+}
@@ -0,0 +1,246 @@
+use serde::Serialize;
+use std::process::Command;
+
+#[cfg(target_os = "windows")]
+use std::os::windows::process::CommandExt;
+
+#[cfg(target_os = "windows")]
+const CREATE_NO_WINDOW: u32 = 0x08000000;
+
+const US_LANGUAGE_ID: usize = 0x0409;
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct KeyboardLayout {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub variant: Option<String>,
+    pub us_qwerty: bool,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum InjectionMethod {
+    Scancode,
+    Unicode,
+}
+
+// Scancode tables assume a US QWERTY keymap; every other layout, and any
+// non-ASCII text, goes through layout-independent unicode injection.
+pub fn injection_method(layout: Option<&KeyboardLayout>, text: &str) -> InjectionMethod {
+    match layout {
+        Some(layout) if layout.us_qwerty && text.is_ascii() => InjectionMethod::Scancode,
+        _ => InjectionMethod::Unicode,
+    }
+}
+
+pub fn detect_keyboard_layout() -> Result<KeyboardLayout, String> {
+    match std::env::consts::OS {
+        "linux" => detect_linux_layout(),
+        "macos" => run_probe(
+            "defaults",
+            &[
+                "read",
+                "com.apple.HIToolbox",
+                "AppleCurrentKeyboardLayoutInputSourceID",
+            ],
+        )
+        .and_then(|output| parse_macos_input_source(&output)),
+        "windows" => foreground_layout_handle().map(windows_layout),
+        os => Err(format!(
+            "keyboard layout detection is not supported on {os}"
+        )),
+    }
+}
+
+fn detect_linux_layout() -> Result<KeyboardLayout, String> {
+    match run_probe("setxkbmap", &["-query"]).and_then(|output| parse_setxkbmap(&output)) {
+        Ok(layout) => Ok(layout),
+        // Wayland sessions often lack setxkbmap; the system locale still names the keymap.
+        Err(setxkbmap_error) => run_probe("localectl", &["status"])
+            .and_then(|output| parse_localectl(&output))
+            .map_err(|error| format!("{setxkbmap_error}; {error}")),
+    }
+}
+
+fn parse_setxkbmap(output: &str) -> Result<KeyboardLayout, String> {
+    let field = |key: &str| {
+        output.lines().find_map(|line| {
+            let (name, value) = line.split_once(':')?;
+            (name.trim() == key).then(|| value.trim().to_string())
+        })
+    };
+    let layouts = field("layout").ok_or("setxkbmap reported no layout")?;
+    let variants = field("variant").unwrap_or_default();
+    xkb_layout(
+        layouts.split(',').next().unwrap_or_default(),
+        variants.split(',').next(),
+    )
+}
+
+fn parse_localectl(output: &str) -> Result<KeyboardLayout, String> {
+    let field = |key: &str| {
+        output.lines().find_map(|line| {
+            let (name, value) = line.split_once(':')?;
+            (name.trim() == key).then(|| value.trim().to_string())
+        })
+    };
+    let layouts = field("X11 Layout").ok_or("localectl reported no X11 layout")?;
+    let variants = field("X11 Variant").unwrap_or_default();
+    xkb_layout(
+        layouts.split(',').next().unwrap_or_default(),
+        variants.split(',').next(),
+    )
+}
+
+fn xkb_layout(name: &str, variant: Option<&str>) -> Result<KeyboardLayout, String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("keyboard layout name is empty".to_string());
+    }
+    let variant = variant
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string);
+
+    Ok(KeyboardLayout {
+        us_qwerty: name == "us" && variant.is_none(),
+        name: name.to_string(),
+        variant,
+    })
+}
+
+fn parse_macos_input_source(output: &str) -> Result<KeyboardLayout, String> {
+    let source = output.trim();
+    let name = source
+        .strip_prefix("com.apple.keylayout.")
+        .ok_or_else(|| format!("unexpected input source: {source}"))?;
+
+    Ok(KeyboardLayout {
+        us_qwerty: matches!(name, "US" | "ABC"),
+        name: name.to_string(),
+        variant: None,
+    })
+}
+
+// The layout of the window being typed into, which can differ per window.
+#[cfg(target_os = "windows")]
+fn foreground_layout_handle() -> Result<usize, String> {
+    use std::ffi::c_void;
+
+    #[link(name = "user32")]
+    extern "system" {
+        fn GetForegroundWindow() -> *mut c_void;
+        fn GetWindowThreadProcessId(window: *mut c_void, process_id: *mut u32) -> u32;
+        fn GetKeyboardLayout(thread_id: u32) -> *mut c_void;
+    }
+
+    // SAFETY: plain queries; a null window yields thread 0, which reads the
+    // calling thread's layout instead.
+    let layout = unsafe {
+        GetKeyboardLayout(GetWindowThreadProcessId(
+            GetForegroundWindow(),
+            std::ptr::null_mut(),
+        ))
+    };
+    if layout.is_null() {
+        return Err("GetKeyboardLayout returned no layout".to_string());
+    }
+    Ok(layout as usize)
+}
+
+#[cfg(not(target_os = "windows"))]
+fn foreground_layout_handle() -> Result<usize, String> {
+    Err("GetKeyboardLayout is only available on Windows".to_string())
+}
+
+// An HKL carries the language in its low word and the physical layout in the
+// high word; the two match for a language's default layout, so US QWERTY is
+// 0x04090409 and US Dvorak has a different high word.
+fn windows_layout(handle: usize) -> KeyboardLayout {
+    let language = handle & 0xffff;
+    let device = (handle >> 16) & 0xffff;
+    let name = if device == language {
+        format!("0000{language:04X}")
+    } else {
+        format!("{device:04X}{language:04X}")
+    };
+    KeyboardLayout {
+        us_qwerty: language == US_LANGUAGE_ID && device == US_LANGUAGE_ID,
+        name,
+        variant: None,
+    }
+}
+
+fn run_probe(program: &str, args: &[&str]) -> Result<String, String> {
+    let mut command = Command::new(program);
+    command.args(args);
+
+    #[cfg(target_os = "windows")]
+    {
+        command.creation_flags(CREATE_NO_WINDOW);
+    }
+
+    let output = command
+        .output()
+        .map_err(|error| format!("failed to run {program}: {error}"))?;
+    if !output.status.success() {
+        return Err(format!(
+            "{program} exited with status {}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_platform_layout_probes() {
+        let german = parse_setxkbmap(
+            "rules:      evdev\nmodel:      pc105\nlayout:     de,us\nvariant:    nodeadkeys,\n",
+        )
+        .expect("setxkbmap output should parse");
+        assert_eq!(german.name, "de");
+        assert_eq!(german.variant.as_deref(), Some("nodeadkeys"));
+        assert!(!german.us_qwerty);
+
+        let us = parse_localectl("   System Locale: LANG=en_US.UTF-8\n       X11 Layout: us\n")
+            .expect("localectl output should parse");
+        assert!(us.us_qwerty);
+
+        assert!(
+            parse_macos_input_source("com.apple.keylayout.ABC\n")
+                .expect("input source should parse")
+                .us_qwerty
+        );
+        let us = windows_layout(0x0409_0409);
+        assert_eq!(us.name, "00000409");
+        assert!(us.us_qwerty);
+        let dvorak = windows_layout(0xf002_0409);
+        assert_eq!(dvorak.name, "F0020409");
+        assert!(!dvorak.us_qwerty);
+    }
+
+    #[test]
+    fn uses_scancodes_only_for_ascii_on_us_qwerty() {
+        let us = xkb_layout("us", None).expect("layout should build");
+        let azerty = xkb_layout("fr", None).expect("layout should build");
+        assert_eq!(
+            injection_method(Some(&us), "hello"),
+            InjectionMethod::Scancode
+        );
+        assert_eq!(
+            injection_method(Some(&us), "café"),
+            InjectionMethod::Unicode
+        );
+        assert_eq!(
+            injection_method(Some(&azerty), "hello"),
+            InjectionMethod::Unicode
+        );
+        assert_eq!(injection_method(None, "hello"), InjectionMethod::Unicode);
+    }
+}
@@ -0,0 +1,106 @@
+const LANGUAGE_NAMES: &[(&str, &str)] = &[
+    ("english", "en"),
+    ("german", "de"),
+    ("deutsch", "de"),
+    ("french", "fr"),
+    ("spanish", "es"),
+    ("italian", "it"),
+    ("portuguese", "pt"),
+    ("dutch", "nl"),
+    ("polish", "pl"),
+    ("russian", "ru"),
+    ("ukrainian", "uk"),
+    ("czech", "cs"),
+    ("swedish", "sv"),
+    ("turkish", "tr"),
+    ("japanese", "ja"),
+    ("chinese", "zh"),
+    ("korean", "ko"),
+];
+
+const SWITCH_PREFIXES: &[&str] = &[
+    "switch to",
+    "switch back to",
+    "switch language to",
+    "change language to",
+    "set language to",
+];
+
+pub fn normalize_language_code(value: &str) -> Option<String> {
+    let trimmed = value.trim().to_ascii_lowercase();
+    if trimmed.is_empty() {
+        return None;
+    }
+
+    if let Some((_, code)) = LANGUAGE_NAMES.iter().find(|(name, _)| *name == trimmed) {
+        return Some((*code).to_string());
+    }
+
+    let is_code =
+        (2..=3).contains(&trimmed.len()) && trimmed.chars().all(|ch| ch.is_ascii_lowercase());
+    if is_code {
+        Some(trimmed)
+    } else {
+        None
+    }
+}
+
+pub fn parse_language_switch_command(transcript: &str) -> Option<String> {
+    let normalized = transcript
+        .chars()
+        .map(|ch| {
+            if ch.is_alphanumeric() || ch.is_whitespace() {
+                ch.to_ascii_lowercase()
+            } else {
+                ' '
+            }
+        })
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    SWITCH_PREFIXES.iter().find_map(|prefix| {
+        let rest = normalized.strip_prefix(prefix)?.trim();
+        let name = rest.strip_suffix(" please").unwrap_or(rest);
+        LANGUAGE_NAMES
+            .iter()
+            .find(|(language, _)| *language == name)
+            .map(|(_, code)| (*code).to_string())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_language_names_and_codes() {
+        assert_eq!(normalize_language_code("German").as_deref(), Some("de"));
+        assert_eq!(normalize_language_code(" EN ").as_deref(), Some("en"));
+        assert!(normalize_language_code("").is_none());
+        assert!(normalize_language_code("klingon-ish").is_none());
+    }
+
+    #[test]
+    fn parses_spoken_language_switch_commands() {
+        assert_eq!(
+            parse_language_switch_command("Switch to German.").as_deref(),
+            Some("de")
+        );
+        assert_eq!(
+            parse_language_switch_command("switch back to English please").as_deref(),
+            Some("en")
+        );
+        assert_eq!(
+            parse_language_switch_command("Change language to French!").as_deref(),
+            Some("fr")
+        );
+    }
+
+    #[test]
+    fn ignores_regular_dictation() {
+        assert!(parse_language_switch_command("We should switch to German suppliers").is_none());
+        assert!(parse_language_switch_command("Hello world.").is_none());
+    }
+}
@@ -0,0 +1,413 @@
+use serde::Serialize;
+
+use crate::config::{AppSettings, ModelProfile, SpokenPhraseOverride};
+use crate::postprocess::NormalizationProfile;
+
+const MULTILINGUAL_WHISPER_FAST: &str = "tiny-q8";
+const MULTILINGUAL_WHISPER_BALANCED: &str = "base";
+const MULTILINGUAL_FASTER_WHISPER: &str = "small";
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+pub struct LanguagePack {
+    pub code: &'static str,
+    pub name: &'static str,
+    // None keeps the built-in English-only defaults for the active profile.
+    pub whisper_fast_model: Option<&'static str>,
+    pub whisper_balanced_model: Option<&'static str>,
+    pub faster_whisper_model: Option<&'static str>,
+    pub normalization: NormalizationProfile,
+    pub spoken_punctuation: &'static [(&'static str, &'static str)],
+}
+
+impl LanguagePack {
+    pub fn whisper_model(&self, profile: ModelProfile) -> Option<&'static str> {
+        match profile {
+            ModelProfile::Fast => self.whisper_fast_model,
+            ModelProfile::Balanced => self.whisper_balanced_model,
+        }
+    }
+}
+
+const fn multilingual(
+    code: &'static str,
+    name: &'static str,
+    normalization: NormalizationProfile,
+    spoken_punctuation: &'static [(&'static str, &'static str)],
+) -> LanguagePack {
+    LanguagePack {
+        code,
+        name,
+        whisper_fast_model: Some(MULTILINGUAL_WHISPER_FAST),
+        whisper_balanced_model: Some(MULTILINGUAL_WHISPER_BALANCED),
+        faster_whisper_model: Some(MULTILINGUAL_FASTER_WHISPER),
+        normalization,
+        spoken_punctuation,
+    }
+}
+
+pub const LANGUAGE_PACKS: &[LanguagePack] = &[
+    LanguagePack {
+        code: "en",
+        name: "English",
+        whisper_fast_model: None,
+        whisper_balanced_model: None,
+        faster_whisper_model: None,
+        normalization: NormalizationProfile::Latin,
+        spoken_punctuation: &[
+            ("question mark", "?"),
+            ("exclamation mark", "!"),
+            ("exclamation point", "!"),
+            ("full stop", "."),
+            ("period", "."),
+            ("comma", ","),
+            ("colon", ":"),
+            ("semicolon", ";"),
+        ],
+    },
+    multilingual(
+        "de",
+        "Deutsch",
+        NormalizationProfile::Latin,
+        &[
+            ("fragezeichen", "?"),
+            ("ausrufezeichen", "!"),
+            ("punkt", "."),
+            ("komma", ","),
+            ("doppelpunkt", ":"),
+            ("semikolon", ";"),
+        ],
+    ),
+    multilingual(
+        "fr",
+        "Français",
+        NormalizationProfile::Latin,
+        &[
+            ("point d'interrogation", "?"),
+            ("point d'exclamation", "!"),
+            ("point virgule", ";"),
+            ("deux points", ":"),
+            ("point", "."),
+            ("virgule", ","),
+        ],
+    ),
+    multilingual(
+        "es",
+        "Español",
+        NormalizationProfile::Latin,
+        &[
+            ("signo de interrogación", "?"),
+            ("signo de exclamación", "!"),
+            ("punto y coma", ";"),
+            ("dos puntos", ":"),
+            ("punto", "."),
+            ("coma", ","),
+        ],
+    ),
+    multilingual(
+        "it",
+        "Italiano",
+        NormalizationProfile::Latin,
+        &[
+            ("punto interrogativo", "?"),
+            ("punto esclamativo", "!"),
+            ("punto e virgola", ";"),
+            ("due punti", ":"),
+            ("punto", "."),
+            ("virgola", ","),
+        ],
+    ),
+    multilingual(
+        "ja",
+        "日本語",
+        NormalizationProfile::Cjk,
+        &[
+            ("句点", "。"),
+            ("読点", "、"),
+            ("疑問符", "？"),
+            ("感嘆符", "！"),
+        ],
+    ),
+    multilingual(
+        "zh",
+        "中文",
+        NormalizationProfile::Cjk,
+        &[
+            ("句号", "。"),
+            ("逗号", "，"),
+            ("问号", "？"),
+            ("感叹号", "！"),
+        ],
+    ),
+];
+
+pub fn find_pack(code: &str) -> Option<&'static LanguagePack> {
+    let code = code.trim();
+    LANGUAGE_PACKS
+        .iter()
+        .find(|pack| pack.code.eq_ignore_ascii_case(code))
+}
+
+pub fn normalization_for(language: Option<&str>) -> NormalizationProfile {
+    language
+        .and_then(find_pack)
+        .map(|pack| pack.normalization)
+        .unwrap_or_default()
+}
+
+pub fn apply_pack(settings: &AppSettings, pack: &LanguagePack) -> AppSettings {
+    let mut updated = settings.clone();
+    updated.language = pack.code.to_string();
+    updated.model_path = pack
+        .whisper_model(settings.model_profile)
+        .map(str::to_string);
+    updated.faster_whisper_model = pack.faster_whisper_model.map(str::to_string);
+    updated
+}
+
+pub fn apply_spoken_punctuation(text: &str, pack: &LanguagePack) -> String {
+    SpokenPhrases::for_pack(pack).apply(text)
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PhraseSource {
+    Pack,
+    User,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct SpokenPhrase {
+    pub phrase: String,
+    pub replacement: String,
+    pub source: PhraseSource,
+}
+
+// The phrase table in effect for one language: the pack's phrases with the
+// user's overrides layered on top.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct SpokenPhrases {
+    pub language: String,
+    pub normalization: NormalizationProfile,
+    pub phrases: Vec<SpokenPhrase>,
+}
+
+impl SpokenPhrases {
+    pub fn for_pack(pack: &LanguagePack) -> Self {
+        Self {
+            language: pack.code.to_string(),
+            normalization: pack.normalization,
+            phrases: pack
+                .spoken_punctuation
+                .iter()
+                .map(|(phrase, replacement)| SpokenPhrase {
+                    phrase: (*phrase).to_string(),
+                    replacement: (*replacement).to_string(),
+                    source: PhraseSource::Pack,
+                })
+                .collect(),
+        }
+    }
+
+    // None when the language has neither a pack nor overrides of its own.
+    pub fn resolve(language: &str, overrides: &[SpokenPhraseOverride]) -> Option<Self> {
+        let language = language.trim();
+        let overrides = overrides
+            .iter()
+            .filter(|entry| entry.language.eq_ignore_ascii_case(language))
+            .collect::<Vec<_>>();
+        let mut resolved = match find_pack(language) {
+            Some(pack) => Self::for_pack(pack),
+            None if overrides.is_empty() => return None,
+            None => Self {
+                language: language.to_lowercase(),
+                normalization: NormalizationProfile::default(),
+                phrases: Vec::new(),
+            },
+        };
+
+        for entry in overrides {
+            resolved
+                .phrases
+                .retain(|existing| !existing.phrase.eq_ignore_ascii_case(&entry.phrase));
+            if !entry.replacement.is_empty() {
+                resolved.phrases.push(SpokenPhrase {
+                    phrase: entry.phrase.clone(),
+                    replacement: entry.replacement.clone(),
+                    source: PhraseSource::User,
+                });
+            }
+        }
+        // Longer phrases first, so "point virgule" is tried before "point".
+        resolved
+            .phrases
+            .sort_by_key(|entry| std::cmp::Reverse(entry.phrase.chars().count()));
+        Some(resolved)
+    }
+
+    pub fn apply(&self, text: &str) -> String {
+        match self.normalization {
+            NormalizationProfile::Latin => replace_spoken_words(text, &self.phrases),
+            NormalizationProfile::Cjk => {
+                self.phrases.iter().fold(text.to_string(), |output, entry| {
+                    output.replace(&entry.phrase, &entry.replacement)
+                })
+            }
+        }
+    }
+}
+
+pub fn normalize_overrides(overrides: Vec<SpokenPhraseOverride>) -> Vec<SpokenPhraseOverride> {
+    let mut normalized: Vec<SpokenPhraseOverride> = Vec::with_capacity(overrides.len());
+    for entry in overrides {
+        let language = entry.language.trim().to_lowercase();
+        let phrase = entry
+            .phrase
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .to_lowercase();
+        if language.is_empty() || phrase.is_empty() {
+            continue;
+        }
+        normalized.retain(|existing| existing.language != language || existing.phrase != phrase);
+        normalized.push(SpokenPhraseOverride {
+            language,
+            phrase,
+            replacement: entry.replacement.trim().to_string(),
+        });
+    }
+    normalized
+}
+
+// Phrases are matched word by word so "comma" never fires inside "commander".
+fn replace_spoken_words(text: &str, table: &[SpokenPhrase]) -> String {
+    let words = text.split_whitespace().collect::<Vec<_>>();
+    let keys = words.iter().map(|word| match_key(word)).collect::<Vec<_>>();
+    let mut output = String::with_capacity(text.len());
+    let mut index = 0;
+
+    while index < words.len() {
+        let matched = table.iter().find_map(|entry| {
+            let phrase_words = entry.phrase.split_whitespace().collect::<Vec<_>>();
+            let end = index + phrase_words.len();
+            (end <= words.len()
+                && keys[index..end]
+                    .iter()
+                    .zip(&phrase_words)
+                    .all(|(key, word)| key == &word.to_lowercase()))
+            .then_some((entry.replacement.as_str(), end))
+        });
+
+        match matched {
+            Some((mark, end)) => {
+                let trimmed = output.trim_end_matches(' ').len();
+                output.truncate(trimmed);
+                output.push_str(mark);
+                index = end;
+            }
+            None => {
+                if !output.is_empty() {
+                    output.push(' ');
+                }
+                output.push_str(words[index]);
+                index += 1;
+            }
+        }
+    }
+    output
+}
+
+fn match_key(word: &str) -> String {
+    word.trim_matches(|ch: char| ch.is_ascii_punctuation() && ch != '\'')
+        .to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn switching_to_german_configures_models_together() {
+        let settings = AppSettings {
+            model_profile: ModelProfile::Balanced,
+            ..AppSettings::default()
+        };
+        let pack = find_pack("DE").expect("german pack should exist");
+        let updated = apply_pack(&settings, pack);
+        assert_eq!(updated.language, "de");
+        assert_eq!(
+            updated.model_path.as_deref(),
+            Some(MULTILINGUAL_WHISPER_BALANCED)
+        );
+        assert_eq!(updated.faster_whisper_model.as_deref(), Some("small"));
+
+        let english = apply_pack(
+            &updated,
+            find_pack("en").expect("english pack should exist"),
+        );
+        assert!(english.model_path.is_none());
+        assert!(english.faster_whisper_model.is_none());
+        assert_eq!(normalization_for(Some("ja")), NormalizationProfile::Cjk);
+        assert_eq!(normalization_for(Some("xx")), NormalizationProfile::Latin);
+    }
+
+    #[test]
+    fn replaces_spoken_punctuation_on_word_boundaries() {
+        let english = find_pack("en").expect("english pack should exist");
+        assert_eq!(
+            apply_spoken_punctuation("ask the commander comma then stop full stop", english),
+            "ask the commander, then stop."
+        );
+        assert_eq!(
+            apply_spoken_punctuation("is it done Question mark.", english),
+            "is it done?"
+        );
+
+        let german = find_pack("de").expect("german pack should exist");
+        assert_eq!(
+            apply_spoken_punctuation("wirklich Fragezeichen", german),
+            "wirklich?"
+        );
+
+        let japanese = find_pack("ja").expect("japanese pack should exist");
+        assert_eq!(
+            apply_spoken_punctuation("はい読点わかりました句点", japanese),
+            "はい、わかりました。"
+        );
+    }
+
+    #[test]
+    fn layers_user_overrides_over_the_language_pack() {
+        let overrides = normalize_overrides(vec![
+            SpokenPhraseOverride {
+                language: "FR".to_string(),
+                phrase: "Point".to_string(),
+                replacement: String::new(),
+            },
+            SpokenPhraseOverride {
+                language: "fr".to_string(),
+                phrase: "point final".to_string(),
+                replacement: ".".to_string(),
+            },
+            SpokenPhraseOverride {
+                language: "nl".to_string(),
+                phrase: "komma".to_string(),
+                replacement: ",".to_string(),
+            },
+        ]);
+
+        let french = SpokenPhrases::resolve("fr", &overrides).expect("french has a pack");
+        assert_eq!(
+            french.apply("oui virgule le point de vue point final"),
+            "oui, le point de vue."
+        );
+        assert!(french
+            .phrases
+            .iter()
+            .any(|entry| entry.phrase == "point final" && entry.source == PhraseSource::User));
+
+        let dutch = SpokenPhrases::resolve("nl", &overrides).expect("overrides alone resolve");
+        assert_eq!(dutch.apply("ja komma nee"), "ja, nee");
+        assert!(SpokenPhrases::resolve("pt", &overrides).is_none());
+    }
+}
@@ -0,0 +1,246 @@
+use serde::Serialize;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+use crate::config::{AppSettings, SttEngine};
+use crate::{language, language_pack, model_catalog, profile, transcriber};
+
+const GGML_MAGIC: u32 = 0x6767_6d6c;
+// Whisper's English-only checkpoints have one token fewer than the
+// multilingual ones (51864 against 51865+).
+const ENGLISH_ONLY_VOCAB: i32 = 51_864;
+const MULTILINGUAL_PARAKEET: &str = "nvidia/parakeet-tdt-0.6b-v3";
+// What parakeet-tdt-0.6b-v3 transcribes; every other Parakeet checkpoint is
+// English-only.
+const PARAKEET_V3_LANGUAGES: [&str; 25] = [
+    "bg", "cs", "da", "de", "el", "en", "es", "et", "fi", "fr", "hr", "hu", "it", "lt", "lv", "mt",
+    "nl", "pl", "pt", "ro", "ru", "sk", "sl", "sv", "uk",
+];
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct LanguageWarning {
+    pub language: String,
+    pub engine: SttEngine,
+    pub model: String,
+    pub message: String,
+    pub suggested_models: Vec<String>,
+}
+
+// Reads the vocabulary size from a ggml header. None when the file is missing
+// or is not a ggml whisper model.
+fn ggml_english_only(path: &Path) -> Option<bool> {
+    let mut header = [0u8; 8];
+    File::open(path).ok()?.read_exact(&mut header).ok()?;
+    let magic = u32::from_le_bytes([header[0], header[1], header[2], header[3]]);
+    if magic != GGML_MAGIC {
+        return None;
+    }
+    let n_vocab = i32::from_le_bytes([header[4], header[5], header[6], header[7]]);
+    Some(n_vocab == ENGLISH_ONLY_VOCAB)
+}
+
+// Whisper and faster-whisper name English-only checkpoints with a `.en`
+// suffix (ggml-base.en-q5_1.bin, small.en); distil-whisper is English-only.
+fn name_english_only(model: &str) -> bool {
+    let name = model
+        .trim()
+        .rsplit(['/', '\\'])
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    let stem = name.strip_suffix(".bin").unwrap_or(&name);
+    stem.ends_with(".en") || stem.contains(".en-") || stem.contains("distil")
+}
+
+fn whisper_model(settings: &AppSettings, resource_dir: Option<&Path>) -> (String, bool) {
+    if let Some(alias) = settings
+        .model_path
+        .as_deref()
+        .and_then(model_catalog::find_alias)
+    {
+        return (alias.alias.to_string(), alias.english_only);
+    }
+    // The same fallback order the transcriber uses, so a missing override is
+    // judged by the bundled model that actually loads.
+    let resolved = profile::resolve_model_candidates(settings, resource_dir)
+        .into_iter()
+        .find(|candidate| candidate.is_file());
+    let model = resolved
+        .as_ref()
+        .map(|path| path.display().to_string())
+        .or_else(|| settings.model_path.clone())
+        .unwrap_or_else(|| {
+            profile::default_model_relative_path(settings.model_profile).to_string()
+        });
+    let english_only = resolved
+        .as_deref()
+        .and_then(ggml_english_only)
+        .unwrap_or_else(|| name_english_only(&model));
+    (model, english_only)
+}
+
+fn whisper_suggestions(settings: &AppSettings, language: &str) -> Vec<String> {
+    let preferred = language_pack::find_pack(language)
+        .and_then(|pack| pack.whisper_model(settings.model_profile));
+    let mut suggestions = preferred
+        .into_iter()
+        .map(str::to_string)
+        .collect::<Vec<_>>();
+    for alias in model_catalog::MODEL_ALIASES {
+        if !alias.english_only && !suggestions.iter().any(|known| known == alias.alias) {
+            suggestions.push(alias.alias.to_string());
+        }
+    }
+    suggestions
+}
+
+fn faster_whisper_suggestions(model: &str, language: &str) -> Vec<String> {
+    let mut suggestions = Vec::new();
+    // small.en -> small keeps the size the user picked.
+    if let Some(multilingual) = model.trim().strip_suffix(".en") {
+        suggestions.push(multilingual.to_string());
+    }
+    let preferred = language_pack::find_pack(language)
+        .and_then(|pack| pack.faster_whisper_model)
+        .unwrap_or("small");
+    if !suggestions.iter().any(|known| known == preferred) {
+        suggestions.push(preferred.to_string());
+    }
+    suggestions
+}
+
+// Checks the configured model against the dictation language. English, an
+// unrecognised code and the demo engine never warn.
+pub fn check(settings: &AppSettings, resource_dir: Option<&Path>) -> Option<LanguageWarning> {
+    let language = language::normalize_language_code(&settings.language)?;
+    if language == "en" {
+        return None;
+    }
+    let (model, suggested_models) = match settings.stt_engine {
+        SttEngine::WhisperCpp => {
+            let (model, english_only) = whisper_model(settings, resource_dir);
+            if !english_only {
+                return None;
+            }
+            (model, whisper_suggestions(settings, &language))
+        }
+        SttEngine::FasterWhisper => {
+            let model = settings.faster_whisper_model.clone().unwrap_or_else(|| {
+                transcriber::default_faster_whisper_model(settings.model_profile).to_string()
+            });
+            if !name_english_only(&model) {
+                return None;
+            }
+            let suggestions = faster_whisper_suggestions(&model, &language);
+            (model, suggestions)
+        }
+        SttEngine::Parakeet => {
+            let model = settings.parakeet_model.clone().unwrap_or_else(|| {
+                transcriber::default_parakeet_model(settings.model_profile).to_string()
+            });
+            let multilingual = model.to_ascii_lowercase().contains("tdt-0.6b-v3");
+            let supported = PARAKEET_V3_LANGUAGES.contains(&language.as_str());
+            if multilingual && supported {
+                return None;
+            }
+            let suggestions = if supported {
+                vec![MULTILINGUAL_PARAKEET.to_string()]
+            } else {
+                Vec::new()
+            };
+            (model, suggestions)
+        }
+        SttEngine::Demo => return None,
+    };
+
+    let message = if suggested_models.is_empty() {
+        format!(
+            "{model} does not support language '{language}'; switch to whisper.cpp or faster-whisper for it"
+        )
+    } else {
+        format!(
+            "{model} is English-only and will garble '{language}' speech; try {}",
+            suggested_models.join(", ")
+        )
+    };
+    Some(LanguageWarning {
+        language,
+        engine: settings.stt_engine,
+        model,
+        message,
+        suggested_models,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    fn settings(language: &str, engine: SttEngine) -> AppSettings {
+        AppSettings {
+            language: language.to_string(),
+            stt_engine: engine,
+            ..AppSettings::default()
+        }
+    }
+
+    #[test]
+    fn warns_for_english_only_models_and_suggests_multilingual_ones() {
+        let mut whisper = settings("ja", SttEngine::WhisperCpp);
+        whisper.model_path = Some("base-en".to_string());
+        let warning = check(&whisper, None).expect("base-en is English-only");
+        assert_eq!(warning.model, "base-en");
+        assert_eq!(warning.suggested_models[0], "base");
+
+        whisper.model_path = Some("tiny-q8".to_string());
+        assert_eq!(check(&whisper, None), None);
+        whisper.language = "en".to_string();
+        whisper.model_path = Some("base-en".to_string());
+        assert_eq!(check(&whisper, None), None);
+
+        let mut faster = settings("de", SttEngine::FasterWhisper);
+        faster.faster_whisper_model = Some("medium.en".to_string());
+        let warning = check(&faster, None).expect("medium.en is English-only");
+        assert_eq!(warning.suggested_models, vec!["medium", "small"]);
+        faster.faster_whisper_model = Some("distil-large-v3".to_string());
+        assert!(check(&faster, None).is_some());
+        faster.faster_whisper_model = Some("large-v3".to_string());
+        assert_eq!(check(&faster, None), None);
+
+        let mut parakeet = settings("fr", SttEngine::Parakeet);
+        let warning = check(&parakeet, None).expect("parakeet-ctc is English-only");
+        assert_eq!(warning.suggested_models, vec![MULTILINGUAL_PARAKEET]);
+        parakeet.parakeet_model = Some(MULTILINGUAL_PARAKEET.to_string());
+        assert_eq!(check(&parakeet, None), None);
+        parakeet.language = "ja".to_string();
+        assert!(check(&parakeet, None)
+            .expect("v3 has no Japanese")
+            .suggested_models
+            .is_empty());
+    }
+
+    #[test]
+    fn reads_english_only_from_the_ggml_header() {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("clock should be set")
+            .as_nanos();
+        let path = std::env::temp_dir().join(format!("sonora-lang-{nanos}-custom.bin"));
+        let mut header = GGML_MAGIC.to_le_bytes().to_vec();
+        header.extend_from_slice(&ENGLISH_ONLY_VOCAB.to_le_bytes());
+        std::fs::write(&path, &header).expect("model header");
+        assert_eq!(ggml_english_only(&path), Some(true));
+
+        let mut whisper = settings("es", SttEngine::WhisperCpp);
+        whisper.model_path = Some(path.display().to_string());
+        assert!(check(&whisper, None).is_some());
+
+        header.truncate(4);
+        header.extend_from_slice(&51_866i32.to_le_bytes());
+        std::fs::write(&path, &header).expect("model header");
+        assert_eq!(check(&whisper, None), None);
+        let _ = std::fs::remove_file(path);
+    }
+}
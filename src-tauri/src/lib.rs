@@ -1,28 +1,127 @@
+pub mod activation;
+pub mod analytics;
+pub mod announce;
 pub mod audio;
+pub mod captions;
+pub mod chunk_cache;
+pub mod clipboard;
+pub mod command_metrics;
 pub mod config;
+pub mod corrections;
+#[cfg(all(feature = "desktop", target_os = "linux"))]
+pub mod dbus_service;
+pub mod engine;
 pub mod environment;
+pub mod error;
+pub mod events;
+pub mod file_ingest;
+pub mod focus;
+pub mod gpu_devices;
 pub mod insertion;
+pub mod insertion_probe;
+pub mod instant_words;
+pub mod integrity;
+pub mod jobs;
+pub mod journal;
+pub mod keyboard_layout;
+pub mod language;
+pub mod language_pack;
+pub mod language_support;
+pub mod local_time;
+pub mod meeting;
+pub mod mic_availability;
+pub mod mic_profile;
+pub mod mic_ranking;
+pub mod mic_test;
+pub mod microbench;
+pub mod model_cache;
+pub mod model_catalog;
+pub mod model_conversion;
+pub mod monitor;
+pub mod noise_profile;
+pub mod number_format;
+pub mod offline;
+pub mod onboarding;
+pub mod perf_trace;
 pub mod pipeline;
+pub mod pipeline_actor;
+pub mod playback;
 pub mod postprocess;
+pub mod power;
+pub mod process_memory;
 pub mod profile;
+pub mod readiness;
 pub mod recovery;
+pub mod resolution;
+pub mod retention;
+pub mod review_queue;
+pub mod review_toast;
 pub mod runtime_log;
+pub mod sandbox;
+pub mod screen_recording;
+pub mod scrubbing;
+pub mod segment_filter;
+pub mod session_timing;
 pub mod settings_store;
+pub mod shadow;
+pub mod sidecar_variants;
+pub mod speech_rate;
+pub mod state_recovery;
+pub mod support_snapshot;
+pub mod thread_priority;
 pub mod transcriber;
+pub mod transcriber_host;
+pub mod usage;
+pub mod utterance_history;
+pub mod utterance_routing;
 pub mod vad;
+pub mod viewer;
+pub mod vocabulary;
+pub mod voice_activation;
+pub mod work_dir;
+pub mod worker_supervisor;
 
+#[cfg(feature = "desktop")]
+use analytics::{SessionStats, UtteranceLatency};
+#[cfg(feature = "desktop")]
+use command_metrics::{CommandMetrics, CommandStats, StartupTimings};
 #[cfg(feature = "desktop")]
 use config::AppSettings;
 #[cfg(feature = "desktop")]
-use config::{DictationMode, ModelProfile, SttEngine};
+use config::{
+    DictationMode, DuplicateSuppression, InsertionVerification, ModelProfile, RetentionMode,
+};
+#[cfg(feature = "desktop")]
+use engine::{
+    engine_spec_for_settings, pipeline_for_settings, preprocessing_for_settings,
+    vad_config_for_settings,
+};
 #[cfg(feature = "desktop")]
 use environment::EnvironmentHealth;
 #[cfg(feature = "desktop")]
-use insertion::{append_recent, resolve_status, InsertionRecord};
+use error::SonoraError;
+#[cfg(feature = "desktop")]
+use events::EventSink;
+#[cfg(feature = "desktop")]
+use focus::{FocusGuardOutcome, FocusTarget};
+#[cfg(feature = "desktop")]
+use insertion::{
+    append_recent, resolve_status_for_strategy, InsertionRecord, InsertionStrategy,
+    VerificationOutcome,
+};
+#[cfg(feature = "desktop")]
+use jobs::JobRecord;
+#[cfg(feature = "desktop")]
+use meeting::MeetingTranscript;
+#[cfg(feature = "desktop")]
+use pipeline::PipelineStatus;
 #[cfg(feature = "desktop")]
-use pipeline::{DictationPipeline, PipelineStatus};
+use pipeline_actor::PipelineActor;
 #[cfg(feature = "desktop")]
-use postprocess::{is_duplicate_transcript, merge_transcript_segments, normalize_transcript};
+use postprocess::{
+    merge_transcript_segments, normalize_transcript_for_stage, normalize_transcript_with_terms,
+    NormalizationProfile, RecentFinals, RepeatGuard, TranscriptStage,
+};
 #[cfg(feature = "desktop")]
 use profile::{
     build_model_status, detect_hardware_tier, recommended_profile_for_tier, tuning_for_settings,
@@ -37,13 +136,19 @@ use serde::Serialize;
 #[cfg(feature = "desktop")]
 use settings_store::AppSettingsPatch;
 #[cfg(feature = "desktop")]
-use std::collections::VecDeque;
+use state_recovery::StateMutex;
+#[cfg(feature = "desktop")]
+use std::collections::{HashMap, VecDeque};
+#[cfg(feature = "desktop")]
+use std::net::{IpAddr, Ipv4Addr};
 #[cfg(feature = "desktop")]
 use std::path::{Path, PathBuf};
 #[cfg(feature = "desktop")]
-use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+#[cfg(feature = "desktop")]
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender, SyncSender};
 #[cfg(feature = "desktop")]
-use std::sync::{Arc, Mutex};
+use std::sync::Arc;
 #[cfg(feature = "desktop")]
 use std::thread;
 #[cfg(feature = "desktop")]
@@ -53,45 +158,123 @@ use tauri::Emitter;
 #[cfg(feature = "desktop")]
 use tauri::Manager;
 #[cfg(feature = "desktop")]
+use tauri_plugin_deep_link::DeepLinkExt;
+#[cfg(feature = "desktop")]
+use thread_priority::ThreadRole;
+#[cfg(feature = "desktop")]
 use transcriber::{
-    build_runtime_engine, default_faster_whisper_model, default_parakeet_model, EngineSpec,
-    RuntimeTranscriber,
+    build_runtime_engine, EngineSpec, RuntimeEngine, RuntimeTranscriber, Transcriber,
 };
 #[cfg(feature = "desktop")]
 use vad::VadConfig;
 
 #[cfg(feature = "desktop")]
 struct PipelineStore {
-    pipeline: Arc<Mutex<DictationPipeline<RuntimeTranscriber>>>,
-    last_transcript: Arc<Mutex<Option<String>>>,
-    live_capture: Mutex<Option<LiveCaptureSession>>,
+    pipeline: PipelineActor<RuntimeTranscriber>,
+    last_transcript: Arc<StateMutex<Option<String>>>,
+    repeat_guard: Arc<StateMutex<RepeatGuard>>,
+    recent_finals: StateMutex<RecentFinals>,
+    session_stats: Arc<StateMutex<SessionStats>>,
+    meeting_transcript: Arc<StateMutex<Option<MeetingTranscript>>>,
+    active_language: Arc<StateMutex<String>>,
+    session_vocabulary: Arc<StateMutex<Vec<String>>>,
+    panic_epoch: Arc<AtomicU64>,
+    language_override: StateMutex<Option<String>>,
+    live_capture: StateMutex<Option<LiveCaptureSession>>,
+    mic_monitor: StateMutex<Option<LiveCaptureSession>>,
 }
 
 #[cfg(feature = "desktop")]
 impl PipelineStore {
     fn new(settings: &AppSettings) -> Self {
-        let mut pipeline = DictationPipeline::new(
-            settings.mode,
-            settings.model_profile,
+        let pipeline = pipeline_for_settings(
+            settings,
             RuntimeTranscriber::Unavailable {
-                reason: "transcriber not initialized".to_string(),
+                reason: SonoraError::internal("transcriber not initialized"),
             },
         );
-        pipeline.set_tuning(tuning_for_settings(settings));
-        pipeline.set_vad_config(vad_config_for_settings(settings));
 
         Self {
-            pipeline: Arc::new(Mutex::new(pipeline)),
-            last_transcript: Arc::new(Mutex::new(None)),
-            live_capture: Mutex::new(None),
+            pipeline: PipelineActor::spawn(pipeline),
+            last_transcript: Arc::new(StateMutex::new(None)),
+            repeat_guard: Arc::default(),
+            recent_finals: StateMutex::default(),
+            session_stats: Arc::new(StateMutex::new(SessionStats::default())),
+            meeting_transcript: Arc::new(StateMutex::new(None)),
+            active_language: Arc::new(StateMutex::new(settings.language.clone())),
+            session_vocabulary: Arc::new(StateMutex::new(Vec::new())),
+            panic_epoch: Arc::new(AtomicU64::new(0)),
+            language_override: StateMutex::new(None),
+            // A session half-built when its worker panicked cannot be stopped
+            // cleanly, so recovery drops it instead.
+            live_capture: StateMutex::with_reset(None, || None),
+            mic_monitor: StateMutex::with_reset(None, || None),
+        }
+    }
+
+    fn capture_shared(&self) -> CaptureShared {
+        CaptureShared {
+            pipeline: self.pipeline.clone(),
+            session_stats: Arc::clone(&self.session_stats),
+            meeting_transcript: Arc::clone(&self.meeting_transcript),
+            active_language: Arc::clone(&self.active_language),
+            session_vocabulary: Arc::clone(&self.session_vocabulary),
+            panic_epoch: Arc::clone(&self.panic_epoch),
+            mode: CaptureMode::Dictation,
+        }
+    }
+}
+
+// Recording captures transcribe system audio into the recording's transcript
+// file only; they never insert, type or queue anything. Captions captures
+// show system audio as caption frames only, translated to English when asked.
+#[cfg(feature = "desktop")]
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum CaptureMode {
+    Dictation,
+    Recording,
+    Monitor,
+    Captions { translate: bool },
+}
+
+#[cfg(feature = "desktop")]
+static NEXT_CAPTURE_ID: AtomicU64 = AtomicU64::new(1);
+
+#[cfg(feature = "desktop")]
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct CaptureOwner {
+    id: u64,
+    mode: CaptureMode,
+}
+
+#[cfg(feature = "desktop")]
+impl CaptureOwner {
+    fn next(mode: CaptureMode) -> Self {
+        Self {
+            id: NEXT_CAPTURE_ID.fetch_add(1, Ordering::Relaxed),
+            mode,
         }
     }
 }
 
+#[cfg(feature = "desktop")]
+#[derive(Clone)]
+struct CaptureShared {
+    pipeline: PipelineActor<RuntimeTranscriber>,
+    session_stats: Arc<StateMutex<SessionStats>>,
+    meeting_transcript: Arc<StateMutex<Option<MeetingTranscript>>>,
+    active_language: Arc<StateMutex<String>>,
+    session_vocabulary: Arc<StateMutex<Vec<String>>>,
+    panic_epoch: Arc<AtomicU64>,
+    mode: CaptureMode,
+}
+
 #[cfg(feature = "desktop")]
 struct LiveCaptureSession {
     stop_tx: Sender<()>,
     worker: Option<thread::JoinHandle<()>>,
+    microphone_id: Option<String>,
+    owner: CaptureOwner,
 }
 
 #[cfg(feature = "desktop")]
@@ -124,11 +307,19 @@ struct TranscriberStatus {
     checked_binary_paths: Vec<String>,
     resolved_model_path: String,
     model_exists: bool,
+    binary_integrity: Option<integrity::IntegrityStatus>,
+    gpu_device: Option<gpu_devices::GpuDevice>,
+    available_gpus: Vec<gpu_devices::GpuDevice>,
+    sandbox: String,
+    // Why the engine cannot run: a missing model or binary, a refused PATH
+    // sidecar or an unsupported backend.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<SonoraError>,
 }
 
 #[cfg(feature = "desktop")]
 struct SettingsState {
-    settings: Mutex<AppSettings>,
+    settings: StateMutex<AppSettings>,
     settings_path: PathBuf,
 }
 
@@ -136,35 +327,86 @@ struct SettingsState {
 impl SettingsState {
     fn new(settings: AppSettings, settings_path: PathBuf) -> Self {
         Self {
-            settings: Mutex::new(settings),
+            settings: StateMutex::new(settings),
             settings_path,
         }
     }
 }
 
+// The LAN caption viewer, running while the setting is on.
+#[cfg(feature = "desktop")]
+#[derive(Default)]
+struct TranscriptViewerState {
+    server: StateMutex<Option<viewer::ViewerServer>>,
+}
+
+#[cfg(feature = "desktop")]
+impl TranscriptViewerState {
+    fn hub(&self) -> Option<viewer::ViewerHub> {
+        self.server
+            .lock()
+            .ok()
+            .and_then(|server| server.as_ref().map(|server| server.hub().clone()))
+    }
+}
+
+#[cfg(all(feature = "desktop", target_os = "linux"))]
+struct DbusState {
+    service: dbus_service::DbusService,
+}
+
 #[cfg(feature = "desktop")]
 #[derive(Default)]
+struct ActivationState {
+    pending: StateMutex<Option<activation::ActivationAction>>,
+    frontend_ready: AtomicBool,
+    review_token: String,
+}
+
+#[cfg(feature = "desktop")]
 struct InsertionState {
-    records: Mutex<Vec<InsertionRecord>>,
+    records: StateMutex<Vec<InsertionRecord>>,
+    path: PathBuf,
+    focus_target: StateMutex<Option<FocusTarget>>,
+    // Tag for the current or most recent capture session; kept after stop so
+    // the last chunk's insertion is still tagged.
+    session_label: StateMutex<Option<String>>,
+}
+
+#[cfg(feature = "desktop")]
+impl InsertionState {
+    fn new(path: PathBuf, records: Vec<InsertionRecord>) -> Self {
+        Self {
+            records: StateMutex::new(records),
+            path,
+            focus_target: StateMutex::new(None),
+            session_label: StateMutex::new(None),
+        }
+    }
 }
 
 #[cfg(feature = "desktop")]
 struct RuntimeLogState {
     path: PathBuf,
     perf_enabled: bool,
+    trace_path: PathBuf,
 }
 
 #[cfg(feature = "desktop")]
 impl RuntimeLogState {
-    fn new(path: PathBuf, perf_enabled: bool) -> Self {
-        Self { path, perf_enabled }
+    fn new(path: PathBuf, perf_enabled: bool, trace_path: PathBuf) -> Self {
+        Self {
+            path,
+            perf_enabled,
+            trace_path,
+        }
     }
 }
 
 #[cfg(feature = "desktop")]
 struct RecoveryState {
     path: PathBuf,
-    checkpoint: Mutex<RecoveryCheckpoint>,
+    checkpoint: StateMutex<RecoveryCheckpoint>,
 }
 
 #[cfg(feature = "desktop")]
@@ -172,11 +414,283 @@ impl RecoveryState {
     fn new(path: PathBuf, checkpoint: RecoveryCheckpoint) -> Self {
         Self {
             path,
-            checkpoint: Mutex::new(checkpoint),
+            checkpoint: StateMutex::new(checkpoint),
+        }
+    }
+}
+
+#[cfg(feature = "desktop")]
+struct JobState {
+    path: PathBuf,
+    records: StateMutex<Vec<JobRecord>>,
+}
+
+#[cfg(feature = "desktop")]
+impl JobState {
+    fn new(path: PathBuf, records: Vec<JobRecord>) -> Self {
+        Self {
+            path,
+            records: StateMutex::new(records),
+        }
+    }
+}
+
+#[cfg(feature = "desktop")]
+struct UtteranceState {
+    path: PathBuf,
+    entries: StateMutex<Vec<utterance_history::UtteranceEntry>>,
+}
+
+#[cfg(feature = "desktop")]
+impl UtteranceState {
+    fn new(path: PathBuf, entries: Vec<utterance_history::UtteranceEntry>) -> Self {
+        Self {
+            path,
+            entries: StateMutex::new(entries),
+        }
+    }
+}
+
+#[cfg(feature = "desktop")]
+struct CorrectionState {
+    path: PathBuf,
+    store: StateMutex<corrections::CorrectionStore>,
+}
+
+#[cfg(feature = "desktop")]
+impl CorrectionState {
+    fn new(path: PathBuf, store: corrections::CorrectionStore) -> Self {
+        Self {
+            path,
+            store: StateMutex::new(store),
+        }
+    }
+}
+
+#[cfg(feature = "desktop")]
+#[derive(Default)]
+struct FocusWatchState {
+    current: StateMutex<Option<FocusChangedPayload>>,
+    // Refreshed when focus moves, since Windows keeps a layout per window.
+    keyboard_layout: StateMutex<Option<keyboard_layout::KeyboardLayout>>,
+}
+
+#[cfg(feature = "desktop")]
+#[derive(Default)]
+struct ReviewQueueState {
+    items: StateMutex<Vec<review_queue::ReviewItem>>,
+}
+
+#[cfg(feature = "desktop")]
+#[derive(Default)]
+struct MicTestState {
+    clip: StateMutex<Option<Vec<f32>>>,
+}
+
+// The latest model conversion, kept after it ends so the UI can show the outcome.
+#[cfg(feature = "desktop")]
+#[derive(Default)]
+struct ModelConversionState {
+    current: StateMutex<Option<model_conversion::ConversionProgress>>,
+}
+
+#[cfg(feature = "desktop")]
+#[derive(Default)]
+struct MicRankingState {
+    snr_by_label: StateMutex<HashMap<String, Option<f32>>>,
+}
+
+#[cfg(feature = "desktop")]
+struct NoiseProfileState {
+    path: PathBuf,
+    profiles: StateMutex<noise_profile::NoiseProfiles>,
+}
+
+#[cfg(feature = "desktop")]
+impl NoiseProfileState {
+    fn new(path: PathBuf, profiles: noise_profile::NoiseProfiles) -> Self {
+        Self {
+            path,
+            profiles: StateMutex::new(profiles),
+        }
+    }
+}
+
+#[cfg(feature = "desktop")]
+struct UsageState {
+    path: PathBuf,
+    pattern: StateMutex<usage::UsagePattern>,
+    last_activity_unix_ms: AtomicU64,
+    model_warm: AtomicBool,
+    memory_cap_restarts: AtomicU64,
+}
+
+#[cfg(feature = "desktop")]
+impl UsageState {
+    fn new(path: PathBuf, pattern: usage::UsagePattern, now_unix_ms: u64) -> Self {
+        Self {
+            path,
+            pattern: StateMutex::new(pattern),
+            last_activity_unix_ms: AtomicU64::new(now_unix_ms),
+            model_warm: AtomicBool::new(false),
+            memory_cap_restarts: AtomicU64::new(0),
+        }
+    }
+
+    fn mark_activity(&self, now_unix_ms: u64) {
+        self.last_activity_unix_ms
+            .store(now_unix_ms, Ordering::Relaxed);
+        self.model_warm.store(true, Ordering::Relaxed);
+    }
+}
+
+#[cfg(feature = "desktop")]
+struct CommandMetricsState {
+    metrics: StateMutex<CommandMetrics>,
+    startup: StateMutex<StartupTimings>,
+    startup_origin: Instant,
+}
+
+#[cfg(feature = "desktop")]
+impl CommandMetricsState {
+    fn new(startup_origin: Instant, startup: StartupTimings) -> Self {
+        Self {
+            metrics: StateMutex::new(CommandMetrics::default()),
+            startup: StateMutex::new(startup),
+            startup_origin,
+        }
+    }
+}
+
+#[cfg(feature = "desktop")]
+fn record_startup_phase(app: &tauri::AppHandle, name: &'static str, started: Instant) {
+    let duration_ms = started.elapsed().as_millis() as u64;
+    if let Some(state) = app.try_state::<CommandMetricsState>() {
+        let started_ms = started.duration_since(state.startup_origin).as_millis() as u64;
+        if let Ok(mut startup) = state.startup.lock() {
+            startup.record(name, started_ms, duration_ms);
+        }
+    }
+    if let Some(logs) = app.try_state::<RuntimeLogState>() {
+        if logs.perf_enabled {
+            let _ = log_store::append(
+                &logs.path,
+                "info",
+                "perf.startup",
+                &format!("{name} took {duration_ms} ms"),
+            );
         }
     }
 }
 
+#[cfg(feature = "desktop")]
+fn instrument_command<T>(
+    app: &tauri::AppHandle,
+    name: &'static str,
+    body: impl FnOnce() -> Result<T, SonoraError>,
+) -> Result<T, SonoraError> {
+    let _ = command_metrics::take_note();
+    let started = Instant::now();
+    let result = body();
+    let duration_ms = started.elapsed().as_millis() as u64;
+    let error_code = result.as_ref().err().map(SonoraError::code);
+    let note = command_metrics::take_note();
+
+    if let Some(state) = app.try_state::<CommandMetricsState>() {
+        if let Ok(mut metrics) = state.metrics.lock() {
+            metrics.record(name, duration_ms, error_code);
+        }
+    }
+
+    let Some(logs) = app.try_state::<RuntimeLogState>() else {
+        return result;
+    };
+    if !command_metrics::should_log_invocation(
+        duration_ms,
+        result.is_err(),
+        note.is_some(),
+        logs.perf_enabled,
+    ) {
+        return result;
+    }
+
+    let privacy_mode = app
+        .try_state::<SettingsState>()
+        .and_then(|state| {
+            state
+                .settings
+                .lock()
+                .ok()
+                .map(|settings| settings.privacy_mode)
+        })
+        .unwrap_or(true);
+    let note_detail = note.as_ref().map(|note| note.detail.as_str());
+    let message = match &result {
+        Ok(_) => {
+            command_metrics::format_command_log(name, duration_ms, None, note_detail, privacy_mode)
+        }
+        Err(error) => {
+            let details = error.to_string();
+            command_metrics::format_command_log(
+                name,
+                duration_ms,
+                Some((error.code(), &details)),
+                None,
+                privacy_mode,
+            )
+        }
+    };
+    let (level, event) = match (&result, &note) {
+        (Err(_), _) => ("error", "command.error"),
+        (Ok(_), Some(note)) => (note.level, "command.invoke"),
+        (Ok(_), None) => ("info", "command.invoke"),
+    };
+    let _ = log_store::append(&logs.path, level, event, &message);
+
+    result
+}
+
+#[cfg(feature = "desktop")]
+#[derive(Clone, Serialize)]
+struct ResourceStatusPayload {
+    #[serde(flatten)]
+    status: PipelineStatus,
+    engine: &'static str,
+    engine_loaded: bool,
+    capture_active: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    worker_pid: Option<u32>,
+    worker_restarts: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    worker_resident_bytes: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    worker_threads: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    app_threads: Option<u32>,
+}
+
+#[cfg(feature = "desktop")]
+#[derive(Clone, Serialize)]
+struct SidecarMemoryPayload {
+    pid: Option<u32>,
+    resident_bytes: Option<u64>,
+    cap_mb: u32,
+    cap_restarts: u64,
+}
+
+#[cfg(feature = "desktop")]
+#[derive(Clone, Serialize)]
+struct FileTranscriptionPayload {
+    path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    container: Option<file_ingest::AudioContainer>,
+    duration_ms: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    transcript: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<SonoraError>,
+}
+
 #[cfg(feature = "desktop")]
 #[derive(Clone, Serialize)]
 struct TranscriptPayload {
@@ -187,6 +701,16 @@ struct TranscriptPayload {
     emitted_unix_ms: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     session_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    language: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    diff: Option<postprocess::TranscriptDiff>,
+    // Partials carry no sentence casing or closing period; the final for the
+    // same session replaces them with the fully normalized text.
+    is_final: bool,
+    // Only on finals: which engine, route and model size produced the text.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    engine: Option<utterance_routing::UtteranceEngine>,
 }
 
 #[cfg(feature = "desktop")]
@@ -196,12 +720,31 @@ struct TranscriptCorrelation {
     emitted_unix_ms: u64,
 }
 
+#[cfg(feature = "desktop")]
+#[derive(Clone, Copy)]
+struct PartialSession<'a> {
+    id: u64,
+    previous_text: Option<&'a str>,
+}
+
 #[cfg(feature = "desktop")]
 #[derive(Clone)]
 struct PendingUtterance {
     session_id: u64,
     text: String,
+    started_unix_ms: u64,
     last_speech_unix_ms: u64,
+    confidence: Option<f32>,
+    // Provisional text already typed through instant-word edits.
+    typed: Option<String>,
+    // Estimated capture time of the last voiced sample, for end-to-end latency.
+    speech_end_unix_ms: Option<u64>,
+    // 16 kHz chunks that produced text; routing measures and reruns them.
+    audio: Vec<f32>,
+    // Voiced samples within `audio`, for the hallucination guard.
+    speech_samples: usize,
+    // Some chunk came from the cold-start model, so the text is rougher.
+    cold_start: bool,
 }
 
 #[cfg(feature = "desktop")]
@@ -220,6 +763,7 @@ struct PerfChunkTrace {
     pipeline_ms: u64,
     vad_ms: u64,
     inference_ms: u64,
+    cache_hit: bool,
     emit_rust_ms: u64,
     total_worker_ms: u64,
     listening: bool,
@@ -245,475 +789,3632 @@ struct LiveMicPayload {
 }
 
 #[cfg(feature = "desktop")]
-#[tauri::command]
-fn get_default_settings() -> AppSettings {
-    AppSettings::default()
+#[derive(Clone, Serialize)]
+struct PanicPayload {
+    stopped_capture: bool,
+    triggered_unix_ms: u64,
 }
 
 #[cfg(feature = "desktop")]
-#[tauri::command]
-fn health_check() -> &'static str {
-    "ok"
+#[derive(Clone, Serialize)]
+struct ProfileSwitchPayload {
+    from: ModelProfile,
+    to: ModelProfile,
+    error: Option<String>,
 }
 
 #[cfg(feature = "desktop")]
-#[tauri::command]
-fn phase4_get_environment_health() -> EnvironmentHealth {
-    environment::detect_environment_health()
+#[derive(Clone, Serialize)]
+struct ProfileDowngradePayload {
+    from: ModelProfile,
+    to: ModelProfile,
+    slow: profile::SlowInference,
+    error: Option<String>,
 }
 
 #[cfg(feature = "desktop")]
-#[tauri::command]
-fn phase4_get_runtime_logs(
-    logs: tauri::State<'_, RuntimeLogState>,
-    limit: Option<usize>,
-) -> Result<Vec<String>, String> {
-    let normalized_limit = limit.unwrap_or(40).clamp(1, 200);
-    log_store::read_recent(&logs.path, normalized_limit)
+#[derive(Clone, Serialize)]
+struct StartupProgressPayload {
+    phase: &'static str,
+    finished: bool,
+    duration_ms: Option<u64>,
+    error: Option<String>,
 }
 
 #[cfg(feature = "desktop")]
-#[tauri::command]
-fn phase4_clear_runtime_logs(logs: tauri::State<'_, RuntimeLogState>) -> Result<(), String> {
-    log_store::clear(&logs.path)
+#[derive(Clone, Serialize, PartialEq)]
+struct FocusChangedPayload {
+    app_name: String,
+    process_id: Option<u32>,
+    window_title: Option<String>,
+    matched_rule: Option<config::AppRule>,
 }
 
 #[cfg(feature = "desktop")]
-#[tauri::command]
-fn phase4_get_transcriber_status(
-    app: tauri::AppHandle,
-    settings: tauri::State<'_, SettingsState>,
-) -> Result<TranscriberStatus, String> {
-    let current = settings
-        .settings
-        .lock()
-        .map_err(|_| "failed to acquire settings state".to_string())?
-        .clone();
-    Ok(build_transcriber_status(&app, &current))
+#[derive(Clone, Serialize)]
+struct ScrubPayload {
+    action: config::ScrubAction,
+    matched: Vec<String>,
+    blocked: bool,
 }
 
 #[cfg(feature = "desktop")]
-#[tauri::command]
-fn phase4_perf_mark_ui_transcript_received(
-    logs: tauri::State<'_, RuntimeLogState>,
-    chunk_id: u64,
-    emitted_unix_ms: u64,
-) -> Result<(), String> {
-    if !logs.perf_enabled {
-        return Ok(());
-    }
+#[derive(Clone, Serialize)]
+struct InsertionLimitPayload {
+    behavior: config::LongTranscriptBehavior,
+    max_chars: usize,
+    original_chars: usize,
+    inserted_chars: usize,
+    segments: usize,
+}
 
-    let received_unix_ms = current_unix_ms_u64();
-    append_perf_event(
-        &logs.path,
-        logs.perf_enabled,
-        "perf.ui_transcript",
-        &PerfUiTranscriptTrace {
-            chunk_id,
-            emitted_unix_ms,
-            received_unix_ms,
-            emit_to_ui_ms: received_unix_ms.saturating_sub(emitted_unix_ms),
-        },
-    );
-    Ok(())
+#[cfg(feature = "desktop")]
+#[derive(Clone, Serialize)]
+struct TestClipPayload {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stats: Option<mic_test::ClipStats>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    transcript: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<SonoraError>,
 }
 
 #[cfg(feature = "desktop")]
-#[tauri::command]
-fn phase4_get_recovery_checkpoint(
-    recovery: tauri::State<'_, RecoveryState>,
-) -> Result<RecoveryCheckpoint, String> {
-    let checkpoint = recovery
-        .checkpoint
-        .lock()
-        .map_err(|_| "failed to acquire recovery state".to_string())?;
-    Ok(checkpoint.clone())
+#[derive(Clone, Serialize)]
+struct TestScorePayload {
+    reference: onboarding::ReferenceSentence,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stats: Option<mic_test::ClipStats>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    score: Option<onboarding::DictationScore>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<SonoraError>,
 }
 
 #[cfg(feature = "desktop")]
-#[tauri::command]
-fn phase4_acknowledge_recovery_notice(
-    recovery: tauri::State<'_, RecoveryState>,
-) -> Result<RecoveryCheckpoint, String> {
-    let mut checkpoint = recovery
-        .checkpoint
-        .lock()
-        .map_err(|_| "failed to acquire recovery state".to_string())?;
-    let updated = recovery::acknowledge_recovery_notice(&checkpoint);
-    recovery::save(&recovery.path, &updated)?;
-    *checkpoint = updated.clone();
-    Ok(updated)
+#[derive(Clone, Serialize)]
+struct NoiseCalibrationPayload {
+    microphone_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    frame_count: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<SonoraError>,
 }
 
+// Saved settings plus anything worth telling the user about them. Flattened
+// so callers that only read the settings see the same shape as before.
 #[cfg(feature = "desktop")]
-#[tauri::command]
-fn phase4_mark_clean_shutdown(
-    recovery: tauri::State<'_, RecoveryState>,
-) -> Result<RecoveryCheckpoint, String> {
-    mark_clean_shutdown_state(&recovery)
+#[derive(Clone, Serialize)]
+struct SettingsUpdate {
+    #[serde(flatten)]
+    settings: AppSettings,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    language_warning: Option<language_support::LanguageWarning>,
 }
 
 #[cfg(feature = "desktop")]
-fn current_logical_cores() -> usize {
-    std::thread::available_parallelism()
-        .map(std::num::NonZeroUsize::get)
-        .unwrap_or(4)
+#[derive(Clone, Serialize)]
+struct LanguagePayload {
+    language: String,
+    overridden: bool,
 }
 
 #[cfg(feature = "desktop")]
-fn resolve_engine_model_path(settings: &AppSettings, resource_dir: Option<&Path>) -> PathBuf {
-    match settings.stt_engine {
-        SttEngine::WhisperCpp => profile::resolve_model_path(settings, resource_dir),
-        SttEngine::FasterWhisper => settings
-            .faster_whisper_model
-            .as_ref()
-            .map(|value| value.trim())
-            .filter(|value| !value.is_empty())
-            .map(PathBuf::from)
-            .unwrap_or_else(|| PathBuf::from(default_faster_whisper_model(settings.model_profile))),
-        SttEngine::Parakeet => settings
-            .parakeet_model
-            .as_ref()
-            .map(|value| value.trim())
-            .filter(|value| !value.is_empty())
-            .map(PathBuf::from)
-            .unwrap_or_else(|| PathBuf::from(default_parakeet_model(settings.model_profile))),
-    }
+#[tauri::command]
+fn get_default_settings(app: tauri::AppHandle) -> Result<AppSettings, SonoraError> {
+    instrument_command(&app, "get_default_settings", || Ok(AppSettings::default()))
 }
 
 #[cfg(feature = "desktop")]
-fn vad_config_for_settings(settings: &AppSettings) -> VadConfig {
-    let mut config = VadConfig::default();
-    config.enabled = !settings.vad_disabled;
+#[tauri::command]
+fn health_check(
+    app: tauri::AppHandle,
+    settings_state: tauri::State<'_, SettingsState>,
+) -> Result<readiness::ReadinessReport, SonoraError> {
+    use readiness::ReadinessCheck;
 
-    if let Some(threshold_milli) = settings.vad_rms_threshold_milli {
-        let clamped = threshold_milli.clamp(1, 80);
-        config.rms_threshold = clamped as f32 / 1000.0;
-    }
+    instrument_command(&app, "health_check", || {
+        let settings = settings_state
+            .settings
+            .lock()
+            .map(|settings| settings.clone());
+        let settings_loaded = match &settings {
+            Ok(_) if settings_state.settings_path.exists() => ReadinessCheck::ok(None),
+            Ok(_) => ReadinessCheck::ok(Some("no settings file yet; using defaults".to_string())),
+            Err(_) => ReadinessCheck::failed("settings state is unavailable"),
+        };
+        let settings = settings.unwrap_or_default();
 
-    config
-}
+        let transcriber = build_transcriber_status(&app, &settings);
+        let engine_ready = if transcriber.ready {
+            ReadinessCheck::ok(Some(transcriber.description))
+        } else {
+            ReadinessCheck::failed(transcriber.description)
+        };
 
-#[cfg(feature = "desktop")]
-fn build_transcriber_status(app: &tauri::AppHandle, settings: &AppSettings) -> TranscriberStatus {
-    let resource_dir = app.path().resource_dir().ok();
-    let model_path = resolve_engine_model_path(settings, resource_dir.as_deref());
-    let runtime = build_runtime_engine(EngineSpec {
-        engine: settings.stt_engine,
-        language: settings.language.clone(),
-        model_profile: settings.model_profile,
-        model_path,
-        whisper_backend_preference: settings.whisper_backend_preference,
-        faster_whisper_compute_type: settings.faster_whisper_compute_type,
-        faster_whisper_beam_size: settings.faster_whisper_beam_size,
-        parakeet_compute_type: settings.parakeet_compute_type,
-        resource_dir,
-    });
+        let mic_accessible = match audio::list_input_microphones() {
+            Ok(microphones) if !microphones.is_empty() => {
+                ReadinessCheck::ok(Some(format!("{} input device(s)", microphones.len())))
+            }
+            Ok(_) => ReadinessCheck::failed("no input devices found"),
+            Err(error) => ReadinessCheck::failed(error),
+        };
 
-    TranscriberStatus {
-        ready: runtime.diagnostics.ready,
-        active_engine: runtime.diagnostics.active_engine,
-        description: runtime.diagnostics.description,
-        compute_backend: runtime.diagnostics.compute_backend,
-        using_gpu: runtime.diagnostics.using_gpu,
-        resolved_binary_path: runtime.diagnostics.resolved_binary_path,
-        checked_binary_paths: runtime.diagnostics.checked_binary_paths,
-        resolved_model_path: runtime.diagnostics.resolved_model_path,
-        model_exists: runtime.diagnostics.model_exists,
-    }
-}
+        // Hotkey events arrive through phase1_hotkey_down/up; here we can only confirm
+        // the configured accelerator is one a shortcut handler can register.
+        let hotkey_registered = match readiness::validate_hotkey(&settings.hotkey) {
+            Ok(()) => ReadinessCheck::ok(Some(settings.hotkey.clone())),
+            Err(error) => ReadinessCheck::failed(error),
+        };
 
-#[cfg(feature = "desktop")]
-fn apply_runtime_transcriber_from_settings(
-    app: &tauri::AppHandle,
-    settings: &AppSettings,
-    pipeline_store: &tauri::State<'_, PipelineStore>,
-) -> Result<TranscriberStatus, String> {
-    let resource_dir = app.path().resource_dir().ok();
-    let model_path = resolve_engine_model_path(settings, resource_dir.as_deref());
-    let runtime = build_runtime_engine(EngineSpec {
-        engine: settings.stt_engine,
-        language: settings.language.clone(),
-        model_profile: settings.model_profile,
-        model_path,
-        whisper_backend_preference: settings.whisper_backend_preference,
-        faster_whisper_compute_type: settings.faster_whisper_compute_type,
-        faster_whisper_beam_size: settings.faster_whisper_beam_size,
-        parakeet_compute_type: settings.parakeet_compute_type,
-        resource_dir,
-    });
+        let permission = environment::detect_environment_health(cached_keyboard_layout(&app))
+            .input_injection_permission;
+        let insertion_capable = if permission == environment::PermissionState::Ready {
+            ReadinessCheck::ok(None)
+        } else if settings.clipboard_fallback {
+            ReadinessCheck::ok(Some(format!(
+                "input injection {permission:?}; clipboard fallback available"
+            )))
+        } else {
+            ReadinessCheck::failed(format!(
+                "input injection {permission:?} and clipboard fallback is disabled"
+            ))
+        };
 
-    let mut pipeline = pipeline_store
-        .pipeline
-        .lock()
-        .map_err(|_| "failed to acquire pipeline state".to_string())?;
-    pipeline.set_model_profile(settings.model_profile);
-    pipeline.set_tuning(tuning_for_settings(settings));
-    pipeline.set_vad_config(vad_config_for_settings(settings));
-    pipeline.set_transcriber(runtime.transcriber);
+        Ok(readiness::ReadinessReport::new(
+            settings_loaded,
+            engine_ready,
+            mic_accessible,
+            hotkey_registered,
+            insertion_capable,
+        ))
+    })
+}
 
-    Ok(build_transcriber_status(app, settings))
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase4_get_environment_health(app: tauri::AppHandle) -> Result<EnvironmentHealth, SonoraError> {
+    instrument_command(&app, "phase4_get_environment_health", || {
+        Ok(environment::detect_environment_health(
+            cached_keyboard_layout(&app),
+        ))
+    })
 }
 
 #[cfg(feature = "desktop")]
-fn mark_clean_shutdown_state(
-    recovery: &tauri::State<'_, RecoveryState>,
-) -> Result<RecoveryCheckpoint, String> {
-    let mut checkpoint = recovery
-        .checkpoint
+fn cached_keyboard_layout(app: &tauri::AppHandle) -> Option<keyboard_layout::KeyboardLayout> {
+    app.state::<FocusWatchState>()
+        .keyboard_layout
         .lock()
-        .map_err(|_| "failed to acquire recovery state".to_string())?;
-    let now = recovery::current_unix_ms()?;
-    let updated = recovery::mark_clean_shutdown(&checkpoint, now);
-    recovery::save(&recovery.path, &updated)?;
-    *checkpoint = updated.clone();
-    Ok(updated)
+        .ok()?
+        .clone()
 }
 
 #[cfg(feature = "desktop")]
 #[tauri::command]
-fn phase3_get_hardware_profile() -> HardwareProfileStatus {
-    let logical_cores = current_logical_cores();
-    let hardware_tier = detect_hardware_tier(logical_cores);
-    let recommended_profile = recommended_profile_for_tier(hardware_tier);
-
-    HardwareProfileStatus {
-        logical_cores,
-        hardware_tier,
-        recommended_profile,
-    }
+fn phase4_get_offline_status(
+    app: tauri::AppHandle,
+    settings_state: tauri::State<'_, SettingsState>,
+) -> Result<offline::OfflineReport, SonoraError> {
+    instrument_command(&app, "phase4_get_offline_status", || {
+        let settings = settings_state
+            .settings
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("settings"))?
+            .clone();
+        Ok(offline::offline_report(
+            settings.offline_only,
+            sandbox::detect_sandbox_tool(),
+            settings.transcript_viewer.enabled,
+        ))
+    })
 }
 
 #[cfg(feature = "desktop")]
 #[tauri::command]
-fn phase3_auto_select_profile(
+fn phase4_get_event_schema(
     app: tauri::AppHandle,
     settings_state: tauri::State<'_, SettingsState>,
-    pipeline_state: tauri::State<'_, PipelineStore>,
-    logs: tauri::State<'_, RuntimeLogState>,
-) -> Result<AppSettings, String> {
-    let hardware = phase3_get_hardware_profile();
-    let patch = AppSettingsPatch {
-        model_profile: Some(hardware.recommended_profile),
-        ..AppSettingsPatch::default()
-    };
-
-    let mut settings = settings_state
-        .settings
-        .lock()
-        .map_err(|_| "failed to acquire settings state".to_string())?;
-    let updated = settings_store::apply_patch(&settings, patch);
-    settings_store::save(&settings_state.settings_path, &updated)?;
-    *settings = updated.clone();
-
-    apply_runtime_transcriber_from_settings(&app, &updated, &pipeline_state)?;
-
-    let _ = log_store::append(
-        &logs.path,
-        "info",
-        "profile.auto_select",
-        &format!(
-            "auto-selected model profile {:?} for tier {:?}",
-            hardware.recommended_profile, hardware.hardware_tier
-        ),
-    );
-
-    Ok(updated)
+) -> Result<events::EventSchema, SonoraError> {
+    instrument_command(&app, "phase4_get_event_schema", || {
+        let settings = settings_state
+            .settings
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("settings"))?;
+        Ok(events::event_schema(settings.event_prefix.as_deref()))
+    })
 }
 
 #[cfg(feature = "desktop")]
 #[tauri::command]
-fn phase3_get_model_status(
+fn phase4_get_runtime_logs(
     app: tauri::AppHandle,
-    state: tauri::State<'_, SettingsState>,
-) -> Result<ModelStatus, String> {
-    let settings = state
-        .settings
-        .lock()
-        .map_err(|_| "failed to acquire settings state".to_string())?;
-    let resource_dir = app.path().resource_dir().ok();
-    Ok(build_model_status(
-        &settings,
-        current_logical_cores(),
-        resource_dir.as_deref(),
-    ))
+    logs: tauri::State<'_, RuntimeLogState>,
+    limit: Option<usize>,
+) -> Result<Vec<String>, SonoraError> {
+    instrument_command(&app, "phase4_get_runtime_logs", || {
+        let normalized_limit = limit.unwrap_or(40).clamp(1, 200);
+        log_store::read_recent(&logs.path, normalized_limit).map_err(SonoraError::io)
+    })
 }
 
 #[cfg(feature = "desktop")]
 #[tauri::command]
-fn phase3_set_model_path(
+fn phase4_clear_runtime_logs(
     app: tauri::AppHandle,
-    settings_state: tauri::State<'_, SettingsState>,
-    pipeline_state: tauri::State<'_, PipelineStore>,
     logs: tauri::State<'_, RuntimeLogState>,
-    path: Option<String>,
-) -> Result<AppSettings, String> {
-    let mut settings = settings_state
-        .settings
-        .lock()
-        .map_err(|_| "failed to acquire settings state".to_string())?;
-
-    let normalized = path
-        .map(|value| value.trim().to_string())
-        .and_then(|value| if value.is_empty() { None } else { Some(value) });
-
-    let patch = AppSettingsPatch {
-        model_path: Some(normalized),
-        ..AppSettingsPatch::default()
-    };
-    let updated = settings_store::apply_patch(&settings, patch);
-    settings_store::save(&settings_state.settings_path, &updated)?;
-    *settings = updated.clone();
-
-    apply_runtime_transcriber_from_settings(&app, &updated, &pipeline_state)?;
-
-    let _ = log_store::append(
-        &logs.path,
-        "info",
-        "model.path",
-        &format!("set model path to {:?}", updated.model_path),
-    );
-
-    Ok(updated)
+) -> Result<(), SonoraError> {
+    instrument_command(&app, "phase4_clear_runtime_logs", || {
+        log_store::clear(&logs.path).map_err(SonoraError::io)
+    })
 }
 
 #[cfg(feature = "desktop")]
 #[tauri::command]
-fn phase2_get_settings(state: tauri::State<'_, SettingsState>) -> Result<AppSettings, String> {
-    let settings = state
-        .settings
-        .lock()
-        .map_err(|_| "failed to acquire settings state".to_string())?;
-    Ok(settings.clone())
+fn phase4_get_startup_timings(
+    app: tauri::AppHandle,
+    metrics: tauri::State<'_, CommandMetricsState>,
+) -> Result<StartupTimings, SonoraError> {
+    instrument_command(&app, "phase4_get_startup_timings", || {
+        let startup = metrics
+            .startup
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("startup timings"))?;
+        Ok(startup.clone())
+    })
 }
 
 #[cfg(feature = "desktop")]
 #[tauri::command]
-fn phase2_update_settings(
+fn phase4_get_command_metrics(
     app: tauri::AppHandle,
-    settings_state: tauri::State<'_, SettingsState>,
-    pipeline_state: tauri::State<'_, PipelineStore>,
-    logs: tauri::State<'_, RuntimeLogState>,
-    patch: AppSettingsPatch,
-) -> Result<AppSettings, String> {
-    let mut settings = settings_state
-        .settings
-        .lock()
-        .map_err(|_| "failed to acquire settings state".to_string())?;
-    let updated = settings_store::apply_patch(&settings, patch);
-    settings_store::save(&settings_state.settings_path, &updated)?;
-    *settings = updated.clone();
-
-    {
-        let mut pipeline = pipeline_state
-            .pipeline
+    metrics: tauri::State<'_, CommandMetricsState>,
+) -> Result<Vec<CommandStats>, SonoraError> {
+    // The snapshot is taken before this call is recorded, so it never counts itself.
+    instrument_command(&app, "phase4_get_command_metrics", || {
+        let metrics = metrics
+            .metrics
             .lock()
-            .map_err(|_| "failed to acquire pipeline state".to_string())?;
-        pipeline.set_mode(updated.mode);
-    }
-    apply_runtime_transcriber_from_settings(&app, &updated, &pipeline_state)?;
-
-    let _ = log_store::append(
-        &logs.path,
-        "info",
-        "settings.update",
-        "updated runtime settings",
-    );
+            .map_err(|_| SonoraError::state_unavailable("command metrics"))?;
+        Ok(metrics.snapshot())
+    })
+}
 
-    Ok(updated)
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase4_get_transcriber_status(
+    app: tauri::AppHandle,
+    settings: tauri::State<'_, SettingsState>,
+) -> Result<TranscriberStatus, SonoraError> {
+    instrument_command(&app, "phase4_get_transcriber_status", || {
+        let current = settings
+            .settings
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("settings"))?
+            .clone();
+        Ok(build_transcriber_status(&app, &current))
+    })
 }
 
 #[cfg(feature = "desktop")]
 #[tauri::command]
-fn phase2_get_recent_insertions(
-    insertion_state: tauri::State<'_, InsertionState>,
-) -> Result<Vec<InsertionRecord>, String> {
-    let records = insertion_state
-        .records
-        .lock()
-        .map_err(|_| "failed to acquire insertion state".to_string())?;
-    Ok(records.clone())
+fn phase4_get_meeting_transcript_path(
+    app: tauri::AppHandle,
+    store: tauri::State<'_, PipelineStore>,
+) -> Result<Option<String>, SonoraError> {
+    instrument_command(&app, "phase4_get_meeting_transcript_path", || {
+        let meeting = store
+            .meeting_transcript
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("meeting transcript"))?;
+        Ok(meeting
+            .as_ref()
+            .map(|value| value.path().to_string_lossy().to_string()))
+    })
 }
 
 #[cfg(feature = "desktop")]
 #[tauri::command]
-fn phase2_insert_text(
+fn phase4_transcribe_file(
     app: tauri::AppHandle,
     settings_state: tauri::State<'_, SettingsState>,
-    insertion_state: tauri::State<'_, InsertionState>,
-    logs: tauri::State<'_, RuntimeLogState>,
-    text: String,
-) -> Result<InsertionRecord, String> {
-    if text.trim().is_empty() {
-        return Err("cannot insert empty text".to_string());
-    }
+    path: String,
+) -> Result<(), SonoraError> {
+    instrument_command(&app, "phase4_transcribe_file", || {
+        let trimmed = path.trim();
+        if trimmed.is_empty() {
+            return Err(SonoraError::InvalidInput(
+                "audio file path cannot be empty".to_string(),
+            ));
+        }
 
-    let fallback_enabled = settings_state
-        .settings
-        .lock()
-        .map_err(|_| "failed to acquire settings state".to_string())?
-        .clipboard_fallback;
+        let settings = settings_state
+            .settings
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("settings"))?
+            .clone();
+        let source = PathBuf::from(trimmed);
+        let app_for_worker = app.clone();
+        thread::spawn(move || {
+            let payload = transcribe_audio_file(&app_for_worker, &settings, &source);
+            let _ = emit_event(&app_for_worker, events::TRANSCRIPTION_FILE, payload);
+        });
+        Ok(())
+    })
+}
 
-    let status = resolve_status(
-        try_direct_insertion(&text),
-        fallback_enabled,
-        try_clipboard_fallback(&text),
-    );
-    let record = InsertionRecord { text, status };
+#[cfg(feature = "desktop")]
+fn transcribe_with_fresh_engine(
+    app: &tauri::AppHandle,
+    settings: &AppSettings,
+    samples: &[f32],
+) -> Result<String, SonoraError> {
+    let runtime = build_runtime_engine_for_settings(app, settings);
+    if let RuntimeTranscriber::Unavailable { reason } = runtime.transcriber {
+        return Err(reason);
+    }
+    runtime
+        .transcriber
+        .prepare()
+        .and_then(|_| {
+            file_ingest::transcribe_samples(
+                &runtime.transcriber,
+                samples,
+                &vad_config_for_settings(settings),
+                file_ingest::FILE_CHUNK_SAMPLES,
+            )
+        })
+        .map_err(SonoraError::transcription)
+}
 
-    let mut records = insertion_state
-        .records
-        .lock()
-        .map_err(|_| "failed to acquire insertion state".to_string())?;
-    append_recent(&mut records, record.clone(), 3);
+#[cfg(feature = "desktop")]
+fn transcribe_audio_file(
+    app: &tauri::AppHandle,
+    settings: &AppSettings,
+    source: &Path,
+) -> FileTranscriptionPayload {
+    let mut payload = FileTranscriptionPayload {
+        path: source.to_string_lossy().to_string(),
+        container: None,
+        duration_ms: 0,
+        transcript: None,
+        error: None,
+    };
 
-    app.emit("dictation:insertion", record.clone())
-        .map_err(|error| error.to_string())?;
+    let decoded = match file_ingest::decode_audio_file(source) {
+        Ok(decoded) => decoded,
+        Err(error) => {
+            payload.error = Some(error);
+            return payload;
+        }
+    };
+    payload.container = Some(decoded.container);
+    payload.duration_ms = decoded.duration_ms();
 
-    let _ = log_store::append(
-        &logs.path,
-        "info",
-        "insertion.attempt",
-        &format!("insertion status {:?}", record.status),
-    );
+    match transcribe_with_fresh_engine(app, settings, &decoded.samples) {
+        Ok(transcript) => payload.transcript = Some(transcript),
+        Err(error) => payload.error = Some(error),
+    }
 
-    Ok(record)
+    payload
 }
 
 #[cfg(feature = "desktop")]
-fn try_direct_insertion(_text: &str) -> Result<(), String> {
-    Err("direct insertion adapters are not wired yet".to_string())
+#[tauri::command]
+fn phase4_get_sidecar_memory(
+    app: tauri::AppHandle,
+    pipeline_store: tauri::State<'_, PipelineStore>,
+    settings_state: tauri::State<'_, SettingsState>,
+    usage_state: tauri::State<'_, UsageState>,
+) -> Result<SidecarMemoryPayload, SonoraError> {
+    instrument_command(&app, "phase4_get_sidecar_memory", || {
+        let cap_mb = settings_state
+            .settings
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("settings"))?
+            .sidecar_memory_cap_mb;
+        let pid = pipeline_store
+            .pipeline
+            .published()
+            .map_err(|_| SonoraError::state_unavailable("pipeline"))?
+            .worker_pid;
+
+        Ok(SidecarMemoryPayload {
+            pid,
+            resident_bytes: pid.and_then(process_memory::resident_memory_bytes),
+            cap_mb,
+            cap_restarts: usage_state.memory_cap_restarts.load(Ordering::Relaxed),
+        })
+    })
 }
 
 #[cfg(feature = "desktop")]
-fn try_clipboard_fallback(_text: &str) -> Result<(), String> {
-    Ok(())
+#[tauri::command]
+fn phase4_get_shadow_comparisons(
+    app: tauri::AppHandle,
+    limit: Option<usize>,
+) -> Result<Vec<shadow::ShadowComparison>, SonoraError> {
+    instrument_command(&app, "phase4_get_shadow_comparisons", || {
+        Ok(shadow::read_recent(
+            &shadow::default_shadow_log_path(),
+            limit.unwrap_or(50).clamp(1, 500),
+        ))
+    })
 }
 
 #[cfg(feature = "desktop")]
-fn emit_live_mic_state(app: &tauri::AppHandle, active: bool) {
-    let _ = app.emit("dictation:live-mic", LiveMicPayload { active });
+#[tauri::command]
+fn phase4_export_support_snapshot(
+    app: tauri::AppHandle,
+    settings_state: tauri::State<'_, SettingsState>,
+    path: Option<String>,
+) -> Result<String, SonoraError> {
+    instrument_command(&app, "phase4_export_support_snapshot", || {
+        let settings = settings_state
+            .settings
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("settings"))?
+            .clone();
+        let resource_dir = app.path().resource_dir().ok();
+        let created_unix_ms = current_unix_ms_u64();
+        let snapshot = support_snapshot::capture(
+            &settings,
+            resource_dir.as_deref(),
+            &app.package_info().version.to_string(),
+            created_unix_ms,
+        );
+        let target = path
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+            .map(PathBuf::from)
+            .unwrap_or_else(|| support_snapshot::default_snapshot_path(created_unix_ms));
+        support_snapshot::save(&target, &snapshot).map_err(SonoraError::io)?;
+        Ok(target.to_string_lossy().to_string())
+    })
 }
 
 #[cfg(feature = "desktop")]
-fn select_fresh_transcript(
-    last_transcript: &mut Option<String>,
-    raw_transcript: Option<String>,
-) -> Option<String> {
-    let normalized = raw_transcript.map(|value| normalize_transcript(&value));
-    normalized.and_then(|value| {
-        if value.is_empty() || is_duplicate_transcript(last_transcript.as_deref(), &value) {
-            None
-        } else {
-            *last_transcript = Some(value.clone());
-            Some(value)
-        }
+#[tauri::command]
+fn phase4_replay_support_snapshot(
+    app: tauri::AppHandle,
+    path: String,
+) -> Result<support_snapshot::ReplayReport, SonoraError> {
+    instrument_command(&app, "phase4_replay_support_snapshot", || {
+        let snapshot = support_snapshot::load(Path::new(path.trim())).map_err(SonoraError::io)?;
+        Ok(support_snapshot::replay(&snapshot))
     })
 }
 
 #[cfg(feature = "desktop")]
-fn upsert_pending_utterance(
-    pending: &mut Option<PendingUtterance>,
+#[tauri::command]
+fn phase4_get_transcription_jobs(
+    app: tauri::AppHandle,
+    jobs_state: tauri::State<'_, JobState>,
+) -> Result<Vec<JobRecord>, SonoraError> {
+    instrument_command(&app, "phase4_get_transcription_jobs", || {
+        let records = jobs_state
+            .records
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("transcription jobs"))?;
+        Ok(records.clone())
+    })
+}
+
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase4_clear_transcription_jobs(
+    app: tauri::AppHandle,
+    jobs_state: tauri::State<'_, JobState>,
+) -> Result<(), SonoraError> {
+    instrument_command(&app, "phase4_clear_transcription_jobs", || {
+        let mut records = jobs_state
+            .records
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("transcription jobs"))?;
+        records.clear();
+        jobs::save_jobs(&jobs_state.path, &records).map_err(SonoraError::io)
+    })
+}
+
+#[cfg(feature = "desktop")]
+const MODEL_SCHEDULER_INTERVAL_SECS: u64 = 60;
+#[cfg(feature = "desktop")]
+const LIVE_CAPTURE_OPEN_TIMEOUT_MS: u64 = 3_000;
+
+#[cfg(feature = "desktop")]
+const FOCUS_WATCH_INTERVAL_MS: u64 = 750;
+#[cfg(feature = "desktop")]
+const SCREEN_RECORDING_WATCH_INTERVAL_MS: u64 = 3_000;
+
+#[cfg(feature = "desktop")]
+fn record_usage_session(app: &tauri::AppHandle) {
+    let Some(usage_state) = app.try_state::<UsageState>() else {
+        return;
+    };
+    let now = current_unix_ms_u64();
+    usage_state.mark_activity(now);
+
+    let Ok(mut pattern) = usage_state.pattern.lock() else {
+        return;
+    };
+    pattern.record_session(now);
+    if let Err(error) = usage::save(&usage_state.path, &pattern) {
+        let logs = app.state::<RuntimeLogState>();
+        let _ = log_store::append(&logs.path, "warn", "usage.save", &error);
+    }
+}
+
+#[cfg(feature = "desktop")]
+fn enforce_sidecar_memory_cap(
+    pipeline_store: &PipelineStore,
+    usage_state: &UsageState,
+    logs_path: &Path,
+    cap_mb: u32,
+) -> bool {
+    if cap_mb == 0 {
+        return false;
+    }
+    let restarted = pipeline_store.pipeline.call(move |pipeline| {
+        let resident_bytes = pipeline
+            .transcriber_worker_pid()
+            .and_then(process_memory::resident_memory_bytes)
+            .filter(|bytes| process_memory::exceeds_cap(*bytes, cap_mb))?;
+        pipeline.unload_transcriber();
+        Some((resident_bytes, pipeline.prepare_transcriber()))
+    });
+    let Ok(Some((resident_bytes, restart))) = restarted else {
+        return false;
+    };
+    usage_state
+        .memory_cap_restarts
+        .fetch_add(1, Ordering::Relaxed);
+    usage_state
+        .model_warm
+        .store(restart.is_ok(), Ordering::Relaxed);
+
+    let resident_mb = resident_bytes / (1_024 * 1_024);
+    let message = match restart {
+        Ok(()) => format!(
+            "restarted transcription worker at {resident_mb} MB resident (cap {cap_mb} MB)"
+        ),
+        Err(error) => format!(
+            "stopped transcription worker at {resident_mb} MB resident (cap {cap_mb} MB); restart failed: {error}"
+        ),
+    };
+    let _ = log_store::append(logs_path, "warn", "sidecar.memory_cap", &message);
+    true
+}
+
+#[cfg(feature = "desktop")]
+fn spawn_model_scheduler(app: tauri::AppHandle) {
+    thread::spawn(move || loop {
+        thread::sleep(Duration::from_secs(MODEL_SCHEDULER_INTERVAL_SECS));
+
+        let (preload_enabled, idle_unload_minutes, memory_cap_mb) =
+            match app.state::<SettingsState>().settings.lock() {
+                Ok(settings) => (
+                    settings.predictive_preload_enabled,
+                    settings.idle_unload_minutes,
+                    settings.sidecar_memory_cap_mb,
+                ),
+                Err(_) => return,
+            };
+        let pipeline_store = app.state::<PipelineStore>();
+        let capture_active = pipeline_store
+            .live_capture
+            .lock()
+            .map(|capture| capture.is_some())
+            .unwrap_or(true);
+        if capture_active {
+            continue;
+        }
+
+        let usage_state = app.state::<UsageState>();
+        let logs = app.state::<RuntimeLogState>();
+        if enforce_sidecar_memory_cap(&pipeline_store, &usage_state, &logs.path, memory_cap_mb) {
+            continue;
+        }
+
+        let now = current_unix_ms_u64();
+        let warm = usage_state.model_warm.load(Ordering::Relaxed);
+        let idle_ms = u64::from(idle_unload_minutes) * 60_000;
+
+        if warm
+            && usage::is_idle_for(
+                usage_state.last_activity_unix_ms.load(Ordering::Relaxed),
+                now,
+                idle_ms,
+            )
+        {
+            let _ = pipeline_store
+                .pipeline
+                .call(|pipeline| pipeline.unload_transcriber());
+            usage_state.model_warm.store(false, Ordering::Relaxed);
+            let _ = log_store::append(
+                &logs.path,
+                "info",
+                "model.unload",
+                "unloaded transcription model after idle period",
+            );
+            continue;
+        }
+
+        let predicted = preload_enabled
+            && !warm
+            && usage_state
+                .pattern
+                .lock()
+                .map(|pattern| {
+                    pattern.predicts_usage_soon(
+                        now,
+                        usage::PRELOAD_LEAD_MS,
+                        usage::PRELOAD_MIN_SESSIONS,
+                    )
+                })
+                .unwrap_or(false);
+        if !predicted {
+            continue;
+        }
+
+        let result = match pipeline_store
+            .pipeline
+            .call(|pipeline| pipeline.prepare_transcriber())
+        {
+            Ok(result) => result,
+            Err(_) => return,
+        };
+        match result {
+            Ok(()) => {
+                usage_state.mark_activity(now);
+                let _ = log_store::append(
+                    &logs.path,
+                    "info",
+                    "model.preload",
+                    "preloaded transcription model ahead of predicted usage",
+                );
+            }
+            Err(error) => {
+                let _ = log_store::append(&logs.path, "warn", "model.preload", &error);
+            }
+        }
+    });
+}
+
+#[cfg(feature = "desktop")]
+fn live_capture_active(app: &tauri::AppHandle) -> bool {
+    app.state::<PipelineStore>()
+        .live_capture
+        .lock()
+        .map(|capture| capture.is_some())
+        .unwrap_or(true)
+}
+
+// Quantizes a full-precision whisper.cpp model once the app has been idle for
+// a while. A failed conversion is not retried until the next launch; one
+// interrupted by dictation starts over at the next idle window.
+#[cfg(feature = "desktop")]
+fn spawn_model_conversion_worker(app: tauri::AppHandle) {
+    thread::spawn(move || loop {
+        thread::sleep(Duration::from_secs(
+            model_conversion::IDLE_CHECK_INTERVAL_SECS,
+        ));
+
+        let enabled = match app.state::<SettingsState>().settings.lock() {
+            Ok(settings) => settings.auto_quantize_models,
+            Err(_) => return,
+        };
+        let usage_state = app.state::<UsageState>();
+        if !enabled
+            || live_capture_active(&app)
+            || !usage::is_idle_for(
+                usage_state.last_activity_unix_ms.load(Ordering::Relaxed),
+                current_unix_ms_u64(),
+                model_conversion::IDLE_BEFORE_CONVERSION_MS,
+            )
+        {
+            continue;
+        }
+        let Ok(plan) = model_conversion_plan(&app, None) else {
+            continue;
+        };
+        let skip = plan.target.exists()
+            || app
+                .state::<ModelConversionState>()
+                .current
+                .lock()
+                .map(|current| {
+                    current.as_ref().is_some_and(|progress| {
+                        progress.stage == model_conversion::ConversionStage::Running
+                            || (progress.stage == model_conversion::ConversionStage::Failed
+                                && Path::new(&progress.target) == plan.target)
+                    })
+                })
+                .unwrap_or(true);
+        if skip {
+            continue;
+        }
+        if let Err(error) = start_model_conversion(&app, plan) {
+            let logs = app.state::<RuntimeLogState>();
+            let _ = log_store::append(&logs.path, "warn", "model.convert", &error.to_string());
+        }
+    });
+}
+
+#[cfg(feature = "desktop")]
+fn model_conversion_plan(
+    app: &tauri::AppHandle,
+    level: Option<model_conversion::QuantizationLevel>,
+) -> Result<model_conversion::ConversionPlan, SonoraError> {
+    let settings = app
+        .state::<SettingsState>()
+        .settings
+        .lock()
+        .map_err(|_| SonoraError::state_unavailable("settings"))?
+        .clone();
+    if settings.stt_engine != config::SttEngine::WhisperCpp {
+        return Err(SonoraError::InvalidInput(
+            "model conversion only applies to whisper.cpp models".to_string(),
+        ));
+    }
+    let resource_dir = app.path().resource_dir().ok();
+    let model_path = engine::resolve_engine_model_path(&settings, resource_dir.as_deref());
+    if !model_path.is_file() {
+        return Err(SonoraError::NotFound(
+            model_path.to_string_lossy().to_string(),
+        ));
+    }
+    let level = level.unwrap_or_else(|| {
+        model_conversion::recommended_level(detect_hardware_tier(current_logical_cores()))
+    });
+    model_conversion::plan_conversion(&model_path, level).map_err(SonoraError::InvalidInput)
+}
+
+#[cfg(feature = "desktop")]
+fn start_model_conversion(
+    app: &tauri::AppHandle,
+    plan: model_conversion::ConversionPlan,
+) -> Result<model_conversion::ConversionProgress, SonoraError> {
+    let resource_dir = app.path().resource_dir().ok();
+    let binary = transcriber::resolve_quantize_binary_path(resource_dir.as_deref())
+        .ok_or_else(|| SonoraError::NotFound("whisper-quantize sidecar".to_string()))?;
+    let progress = model_conversion::ConversionProgress::started(&plan);
+    {
+        let state = app.state::<ModelConversionState>();
+        let mut current = state
+            .current
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("model conversion"))?;
+        if current
+            .as_ref()
+            .is_some_and(|current| current.stage == model_conversion::ConversionStage::Running)
+        {
+            return Err(SonoraError::InvalidInput(
+                "a model conversion is already running".to_string(),
+            ));
+        }
+        *current = Some(progress.clone());
+    }
+    let _ = emit_event(app, events::MODEL_CONVERSION, progress.clone());
+
+    let app = app.clone();
+    thread::spawn(move || {
+        let mut interrupted = false;
+        let result = model_conversion::run_quantize(&binary, &plan, |percent| {
+            update_model_conversion(&app, |progress| progress.percent = percent);
+            // Dictation takes priority over maintenance.
+            interrupted = live_capture_active(&app);
+            !interrupted
+        });
+        let logs = app.state::<RuntimeLogState>();
+        match &result {
+            Ok(()) => {
+                let _ = log_store::append(
+                    &logs.path,
+                    "info",
+                    "model.convert",
+                    &format!("wrote {}", plan.target.display()),
+                );
+            }
+            Err(error) => {
+                let _ = log_store::append(&logs.path, "warn", "model.convert", error);
+            }
+        }
+        update_model_conversion(&app, |progress| match result {
+            Ok(()) => {
+                progress.stage = model_conversion::ConversionStage::Completed;
+                progress.percent = 100;
+            }
+            Err(error) => {
+                progress.stage = if interrupted {
+                    model_conversion::ConversionStage::Interrupted
+                } else {
+                    model_conversion::ConversionStage::Failed
+                };
+                progress.error = Some(error);
+            }
+        });
+    });
+    Ok(progress)
+}
+
+#[cfg(feature = "desktop")]
+fn update_model_conversion(
+    app: &tauri::AppHandle,
+    update: impl FnOnce(&mut model_conversion::ConversionProgress),
+) {
+    let state = app.state::<ModelConversionState>();
+    let progress = {
+        let Ok(mut current) = state.current.lock() else {
+            return;
+        };
+        let Some(progress) = current.as_mut() else {
+            return;
+        };
+        update(progress);
+        progress.clone()
+    };
+    let _ = emit_event(app, events::MODEL_CONVERSION, progress);
+}
+
+#[cfg(feature = "desktop")]
+fn spawn_engine_initialization(app: tauri::AppHandle) {
+    thread::spawn(move || {
+        let build_started = Instant::now();
+        emit_startup_progress(&app, "engine.build", None, None);
+        let settings = match app.state::<SettingsState>().settings.lock() {
+            Ok(settings) => settings.clone(),
+            Err(_) => return,
+        };
+        let pipeline_state = app.state::<PipelineStore>();
+        let logs = app.state::<RuntimeLogState>();
+        let status = apply_runtime_transcriber_from_settings(&app, &settings, &pipeline_state);
+        record_startup_phase(&app, "engine.build", build_started);
+
+        let ready = match status {
+            Ok(status) => {
+                let _ = log_store::append(
+                    &logs.path,
+                    if status.ready { "info" } else { "warn" },
+                    "transcriber.setup",
+                    &status.description,
+                );
+                emit_startup_progress(
+                    &app,
+                    "engine.build",
+                    Some(build_started),
+                    (!status.ready).then_some(status.description),
+                );
+                status.ready
+            }
+            Err(error) => {
+                let _ =
+                    log_store::append(&logs.path, "error", "transcriber.setup", &error.to_string());
+                emit_startup_progress(
+                    &app,
+                    "engine.build",
+                    Some(build_started),
+                    Some(error.to_string()),
+                );
+                false
+            }
+        };
+        if !ready {
+            return;
+        }
+
+        let cold_start = start_cold_start_transcriber(&app, &settings);
+        let warmup_started = Instant::now();
+        emit_startup_progress(&app, "engine.warmup", None, None);
+        let warmup = if cold_start {
+            // Warm a handle off the pipeline thread so the tiny model keeps
+            // answering chunks meanwhile.
+            let warmup = pipeline_state
+                .pipeline
+                .call(|pipeline| pipeline.transcriber_handle())
+                .map_err(|_| "pipeline state is unavailable".to_string())
+                .and_then(|primary| primary.prepare());
+            if let Ok(true) = pipeline_state
+                .pipeline
+                .call(|pipeline| pipeline.end_cold_start())
+            {
+                let _ = log_store::append(
+                    &logs.path,
+                    "info",
+                    "transcriber.cold_start",
+                    "switched to the configured model",
+                );
+            }
+            warmup
+        } else {
+            pipeline_state
+                .pipeline
+                .call(|pipeline| pipeline.prepare_transcriber())
+                .map_err(|_| "pipeline state is unavailable".to_string())
+                .and_then(|result| result)
+        };
+        record_startup_phase(&app, "engine.warmup", warmup_started);
+        match &warmup {
+            Ok(()) => {
+                app.state::<UsageState>()
+                    .mark_activity(current_unix_ms_u64());
+                if let Ok(mut startup) = app.state::<CommandMetricsState>().startup.lock() {
+                    startup.engine_ready = true;
+                }
+            }
+            Err(error) => {
+                let _ = log_store::append(&logs.path, "warn", "transcriber.warmup", error);
+            }
+        }
+        emit_startup_progress(&app, "engine.warmup", Some(warmup_started), warmup.err());
+    });
+}
+
+// Installs the tiny model for the warm-up window. False when the fast path is
+// off or the tiny model is not available, e.g. missing from the resources.
+#[cfg(feature = "desktop")]
+fn start_cold_start_transcriber(app: &tauri::AppHandle, settings: &AppSettings) -> bool {
+    let Some(cold_settings) = utterance_routing::cold_start_settings(settings) else {
+        return false;
+    };
+    let logs = app.state::<RuntimeLogState>();
+    let runtime = build_runtime_engine_for_settings(app, &cold_settings);
+    if !runtime.diagnostics.ready {
+        let _ = log_store::append(
+            &logs.path,
+            "warn",
+            "transcriber.cold_start",
+            &runtime.transcriber.description(),
+        );
+        return false;
+    }
+    if let Err(error) = runtime.transcriber.prepare() {
+        let _ = log_store::append(&logs.path, "warn", "transcriber.cold_start", &error);
+        return false;
+    }
+    let transcriber = runtime.transcriber;
+    let started = app
+        .state::<PipelineStore>()
+        .pipeline
+        .call(move |pipeline| pipeline.start_cold_start(transcriber))
+        .is_ok();
+    if started {
+        let _ = log_store::append(
+            &logs.path,
+            "info",
+            "transcriber.cold_start",
+            &format!(
+                "serving {} until the configured model is ready",
+                cold_settings.model_path.unwrap_or_default()
+            ),
+        );
+    }
+    started
+}
+
+#[cfg(feature = "desktop")]
+fn emit_startup_progress(
+    app: &tauri::AppHandle,
+    phase: &'static str,
+    finished_from: Option<Instant>,
+    error: Option<String>,
+) {
+    let _ = emit_event(
+        app,
+        events::APP_STARTUP_PROGRESS,
+        StartupProgressPayload {
+            phase,
+            finished: finished_from.is_some(),
+            duration_ms: finished_from.map(|started| started.elapsed().as_millis() as u64),
+            error,
+        },
+    );
+}
+
+#[cfg(feature = "desktop")]
+#[derive(Clone, Serialize)]
+struct VoiceActivationPayload {
+    active: bool,
+    unix_ms: u64,
+}
+
+#[cfg(feature = "desktop")]
+#[derive(Clone, Serialize)]
+struct PlaybackGuardPayload {
+    engaged: bool,
+    guard: config::PlaybackGuard,
+    unix_ms: u64,
+}
+
+#[cfg(feature = "desktop")]
+#[derive(Clone, Serialize)]
+struct SystemResumePayload {
+    slept_ms: u64,
+    capture_restarted: bool,
+    engine_warmed: bool,
+    error: Option<String>,
+}
+
+#[cfg(feature = "desktop")]
+fn spawn_retention_worker(app: tauri::AppHandle) {
+    thread::spawn(move || loop {
+        let policy = match app.state::<SettingsState>().settings.lock() {
+            Ok(settings) => retention::RetentionPolicy::from_settings(&settings),
+            Err(_) => return,
+        };
+        if policy.mode != RetentionMode::KeepAll {
+            let report = apply_retention(&app, &policy);
+            let removed =
+                report.utterances + report.insertions + report.recordings + report.log_lines;
+            if removed > 0 && policy.mode != RetentionMode::KeepNothing {
+                let logs = app.state::<RuntimeLogState>();
+                let _ = log_store::append(
+                    &logs.path,
+                    "info",
+                    "retention.applied",
+                    &format!(
+                        "removed {} utterances, {} insertions, {} recordings, {} log lines",
+                        report.utterances, report.insertions, report.recordings, report.log_lines
+                    ),
+                );
+            }
+        }
+        thread::sleep(Duration::from_secs(retention::RETENTION_INTERVAL_SECS));
+    });
+}
+
+// Prunes every on-disk history the policy covers and persists the survivors.
+#[cfg(feature = "desktop")]
+fn apply_retention(
+    app: &tauri::AppHandle,
+    policy: &retention::RetentionPolicy,
+) -> retention::PurgeReport {
+    let now = current_unix_ms_u64();
+    let mut report = retention::PurgeReport::default();
+    let logs = app.state::<RuntimeLogState>();
+
+    let utterances = app.state::<UtteranceState>();
+    let audio_dir = utterance_history::audio_path_for(&utterances.path, 0)
+        .parent()
+        .map(Path::to_path_buf);
+    if let Ok(mut entries) = utterances.entries.lock() {
+        let removed = retention::retain_newest_first(&mut entries, policy, now, |entry| {
+            Some(entry.ended_unix_ms)
+        });
+        utterance_history::remove_audio(&removed);
+        report.utterances = removed.len();
+        if let Some(dir) = &audio_dir {
+            report.recordings += retention::purge_files(dir, policy, now);
+        }
+        for entry in entries.iter_mut() {
+            if entry
+                .audio_path
+                .as_deref()
+                .is_some_and(|path| !Path::new(path).exists())
+            {
+                entry.audio_path = None;
+            }
+        }
+        if let Err(error) = utterance_history::save(&utterances.path, &entries) {
+            let _ = log_store::append(&logs.path, "warn", "retention.utterances", &error);
+        }
+    }
+
+    let insertions = app.state::<InsertionState>();
+    if let Ok(mut records) = insertions.records.lock() {
+        report.insertions = retention::retain_newest_first(&mut records, policy, now, |record| {
+            (record.created_unix_ms > 0).then_some(record.created_unix_ms)
+        })
+        .len();
+        if report.insertions > 0 {
+            if let Err(error) = insertion::save_recent(&insertions.path, &records) {
+                let _ = log_store::append(&logs.path, "warn", "retention.insertions", &error);
+            }
+        }
+    }
+
+    if let Some(meetings_dir) = meeting::default_meeting_path(0).parent() {
+        report.recordings += retention::purge_files(meetings_dir, policy, now);
+    }
+    match retention::purge_log(&logs.path, policy, now) {
+        Ok(removed) => report.log_lines = removed,
+        Err(error) => {
+            let _ = log_store::append(&logs.path, "warn", "retention.logs", &error);
+        }
+    }
+    report
+}
+
+#[cfg(feature = "desktop")]
+fn spawn_resume_watcher(app: tauri::AppHandle) {
+    thread::spawn(move || {
+        let mut detector = power::ResumeDetector::new(
+            current_unix_ms_u64(),
+            power::RESUME_CHECK_INTERVAL_SECS * 1_000,
+        );
+        loop {
+            thread::sleep(Duration::from_secs(power::RESUME_CHECK_INTERVAL_SECS));
+            if let Some(slept_ms) = detector.observe(current_unix_ms_u64()) {
+                recover_after_resume(&app, slept_ms);
+            }
+        }
+    });
+}
+
+// Audio streams and sidecar workers rarely survive sleep, so both are rebuilt
+// rather than probed.
+#[cfg(feature = "desktop")]
+fn recover_after_resume(app: &tauri::AppHandle, slept_ms: u64) {
+    let store = app.state::<PipelineStore>();
+    let logs = app.state::<RuntimeLogState>();
+    let _ = log_store::append(
+        &logs.path,
+        "info",
+        "power.resume",
+        &format!("system resumed after about {} s asleep", slept_ms / 1_000),
+    );
+
+    if let Some(monitor) = store
+        .mic_monitor
+        .lock()
+        .ok()
+        .and_then(|mut monitor| monitor.take())
+    {
+        monitor.stop();
+        emit_mic_monitor_state(app, false);
+    }
+    let capture = store
+        .live_capture
+        .lock()
+        .ok()
+        .and_then(|mut capture| capture.take());
+    let restart_target = capture
+        .as_ref()
+        .map(|session| (session.microphone_id.clone(), session.owner));
+    if let Some(capture) = capture {
+        capture.stop();
+    }
+
+    let warmup = store
+        .pipeline
+        .call(|pipeline| {
+            pipeline.unload_transcriber();
+            pipeline.prepare_transcriber()
+        })
+        .and_then(|result| result);
+    app.state::<UsageState>()
+        .model_warm
+        .store(warmup.is_ok(), Ordering::Relaxed);
+
+    // The same owner, so the recording watcher still recognises its capture.
+    let restart = match restart_target {
+        Some((microphone_id, owner)) => app
+            .state::<SettingsState>()
+            .settings
+            .lock()
+            .map(|settings| settings.clone())
+            .map_err(|_| SonoraError::state_unavailable("settings"))
+            .and_then(|settings| {
+                spawn_live_capture_worker(app, &store, &settings, microphone_id, owner)
+            })
+            .map(|_| true),
+        None => Ok(false),
+    };
+    let capture_restarted = matches!(restart, Ok(true));
+    emit_live_mic_state(app, capture_restarted);
+
+    let engine_warmed = warmup.is_ok();
+    let errors = [warmup.err(), restart.err().map(|error| error.to_string())]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+    let payload = SystemResumePayload {
+        slept_ms,
+        capture_restarted,
+        engine_warmed,
+        error: (!errors.is_empty()).then(|| errors.join("; ")),
+    };
+    if let Some(error) = payload.error.as_deref() {
+        let _ = log_store::append(&logs.path, "warn", "power.resume", error);
+    }
+    let _ = emit_event(app, events::SYSTEM_RESUME, payload);
+}
+
+// Only the main window and a running session show the focused app, so
+// the watcher probes only while one of them is there to see it.
+#[cfg(feature = "desktop")]
+fn focus_watch_wanted(app: &tauri::AppHandle) -> bool {
+    let window_visible = app
+        .get_webview_window("main")
+        .and_then(|window| window.is_visible().ok())
+        .unwrap_or(false);
+    window_visible
+        || app
+            .state::<PipelineStore>()
+            .live_capture
+            .lock()
+            .map(|session| session.is_some())
+            .unwrap_or(false)
+}
+
+#[cfg(feature = "desktop")]
+fn spawn_focus_watcher(app: tauri::AppHandle) {
+    thread::spawn(move || loop {
+        thread::sleep(Duration::from_millis(FOCUS_WATCH_INTERVAL_MS));
+        if !focus_watch_wanted(&app) {
+            continue;
+        }
+
+        // Our own window gaining focus should not replace the app dictation will target.
+        let Some(snapshot) =
+            focus::focus_snapshot_within(Duration::from_millis(FOCUS_WATCH_INTERVAL_MS))
+                .ok()
+                .filter(|snapshot| !snapshot.target.is_current_process())
+        else {
+            continue;
+        };
+        let target = snapshot.target;
+        let matched_rule = match app.state::<SettingsState>().settings.lock() {
+            Ok(settings) => {
+                postprocess::rule_for_app(&settings.app_rules, Some(&target.app_name)).cloned()
+            }
+            Err(_) => return,
+        };
+        let payload = FocusChangedPayload {
+            app_name: target.app_name,
+            process_id: target.process_id,
+            window_title: snapshot.window_title,
+            matched_rule,
+        };
+
+        let watch_state = app.state::<FocusWatchState>();
+        let Ok(mut current) = watch_state.current.lock() else {
+            return;
+        };
+        if current.as_ref() == Some(&payload) {
+            continue;
+        }
+        *current = Some(payload.clone());
+        drop(current);
+        if let Ok(mut layout) = watch_state.keyboard_layout.lock() {
+            *layout = keyboard_layout::detect_keyboard_layout().ok();
+        }
+        let _ = emit_event(&app, events::SYSTEM_FOCUS_CHANGED, payload);
+    });
+}
+
+#[cfg(feature = "desktop")]
+#[derive(Debug, Clone, Serialize)]
+struct ScreenRecordingPayload {
+    state: screen_recording::RecordingState,
+    recorder: String,
+    started_unix_ms: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    transcript_path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+// Only follows recordings while no dictation session is running, and only
+// stops the capture it started itself.
+#[cfg(feature = "desktop")]
+fn spawn_screen_recording_watcher(app: tauri::AppHandle) {
+    thread::spawn(move || {
+        let mut watch = screen_recording::RecordingWatch::default();
+        let mut recording: Option<(u64, PathBuf)> = None;
+        loop {
+            thread::sleep(Duration::from_millis(SCREEN_RECORDING_WATCH_INTERVAL_MS));
+
+            let enabled = match app.state::<SettingsState>().settings.lock() {
+                Ok(settings) => settings.screen_recording_transcripts,
+                Err(_) => return,
+            };
+            let recorder = if enabled || recording.is_some() {
+                screen_recording::detect_recorder(&screen_recording::running_processes())
+            } else {
+                None
+            };
+            let Some(transition) = watch.observe(recorder, current_unix_ms_u64()) else {
+                continue;
+            };
+
+            let mut error = None;
+            match transition.state {
+                screen_recording::RecordingState::Started => {
+                    match start_recording_transcription(&app, transition.started_unix_ms) {
+                        Ok(started) => recording = Some(started),
+                        Err(start_error) => error = Some(start_error.to_string()),
+                    }
+                }
+                screen_recording::RecordingState::Stopped => {
+                    if let Some((capture_id, _)) = recording.as_ref() {
+                        let store = app.state::<PipelineStore>();
+                        if let Err(stop_error) = stop_live_capture_owned(&app, &store, *capture_id)
+                        {
+                            error = Some(stop_error.to_string());
+                        }
+                    }
+                }
+            }
+
+            let logs = app.state::<RuntimeLogState>();
+            let message = match (&error, transition.state) {
+                (Some(error), _) => format!("{}: {error}", transition.recorder),
+                (None, screen_recording::RecordingState::Started) => {
+                    format!(
+                        "{} started recording; transcribing loopback",
+                        transition.recorder
+                    )
+                }
+                (None, screen_recording::RecordingState::Stopped) => {
+                    format!("{} stopped recording", transition.recorder)
+                }
+            };
+            let level = if error.is_some() { "warn" } else { "info" };
+            let _ = log_store::append(&logs.path, level, "capture.screen_recording", &message);
+
+            let payload = ScreenRecordingPayload {
+                state: transition.state,
+                recorder: transition.recorder,
+                started_unix_ms: transition.started_unix_ms,
+                transcript_path: recording
+                    .as_ref()
+                    .map(|(_, path)| path.display().to_string()),
+                error,
+            };
+            if transition.state == screen_recording::RecordingState::Stopped {
+                recording = None;
+            }
+            let _ = emit_event(&app, events::SYSTEM_SCREEN_RECORDING, payload);
+        }
+    });
+}
+
+// Same session setup as `phase1_start_live_capture`, but on the loopback input
+// and with the transcript anchored to the recording's start. Returns the
+// capture id so the watcher only ever stops its own session.
+#[cfg(feature = "desktop")]
+fn start_recording_transcription(
+    app: &tauri::AppHandle,
+    started_unix_ms: u64,
+) -> Result<(u64, PathBuf), SonoraError> {
+    let store = app.state::<PipelineStore>();
+    reap_finished_live_capture(&store);
+    if store
+        .live_capture
+        .lock()
+        .map_err(|_| SonoraError::state_unavailable("live capture"))?
+        .is_some()
+    {
+        return Err(SonoraError::InvalidInput(
+            "live capture is already running".to_string(),
+        ));
+    }
+
+    let loopback = loopback_input_label()?;
+
+    let settings = app
+        .state::<SettingsState>()
+        .settings
+        .lock()
+        .map_err(|_| SonoraError::state_unavailable("settings"))?
+        .clone();
+    let shared = store.capture_shared();
+    *shared
+        .session_stats
+        .lock()
+        .map_err(|_| SonoraError::state_unavailable("session stats"))? =
+        SessionStats::started_at(current_unix_ms_u64());
+    let path = screen_recording::transcript_path(started_unix_ms);
+    *shared
+        .meeting_transcript
+        .lock()
+        .map_err(|_| SonoraError::state_unavailable("meeting transcript"))? =
+        Some(MeetingTranscript::anchored(
+            path.clone(),
+            u64::from(settings.meeting_paragraph_gap_secs) * 1_000,
+            started_unix_ms,
+        ));
+
+    let owner = CaptureOwner::next(CaptureMode::Recording);
+    let started_rx = spawn_live_capture_worker(app, &store, &settings, Some(loopback), owner)?;
+    if let Ok(Err(report)) =
+        started_rx.recv_timeout(Duration::from_millis(LIVE_CAPTURE_OPEN_TIMEOUT_MS))
+    {
+        if !report.waiting {
+            let _ = stop_live_capture_owned(app, &store, owner.id);
+        }
+        return Err(SonoraError::MicUnavailable(report));
+    }
+
+    record_usage_session(app);
+    emit_live_mic_state(app, true);
+    Ok((owner.id, path))
+}
+
+#[cfg(feature = "desktop")]
+fn loopback_input_label() -> Result<String, SonoraError> {
+    let labels = audio::list_input_microphones()
+        .map_err(SonoraError::audio)?
+        .into_iter()
+        .map(|microphone| microphone.label)
+        .collect::<Vec<_>>();
+    // Microphone ids are device indices.
+    screen_recording::loopback_input(&labels)
+        .map(|index| index.to_string())
+        .ok_or_else(|| {
+            SonoraError::NotFound("no loopback input device for system output".to_string())
+        })
+}
+
+// Captions what the machine is playing (a call, a video) from the loopback
+// input. With `translate` the engine runs Whisper's translate task, so the
+// captions come out in English whatever is spoken.
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase4_start_live_captions(
+    app: tauri::AppHandle,
+    store: tauri::State<'_, PipelineStore>,
+    settings_state: tauri::State<'_, SettingsState>,
+    translate: bool,
+) -> Result<bool, SonoraError> {
+    instrument_command(&app, "phase4_start_live_captions", || {
+        reap_finished_live_capture(&store);
+        if store
+            .live_capture
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("live capture"))?
+            .is_some()
+        {
+            return Err(SonoraError::InvalidInput(
+                "live capture is already running".to_string(),
+            ));
+        }
+        let loopback = loopback_input_label()?;
+        let settings = settings_state
+            .settings
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("settings"))?
+            .clone();
+        if translate {
+            if let RuntimeTranscriber::Unavailable { reason } =
+                translating_engine(&app, &settings).transcriber
+            {
+                return Err(reason);
+            }
+        }
+        let shared = store.capture_shared();
+        *shared
+            .meeting_transcript
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("meeting transcript"))? = None;
+
+        let owner = CaptureOwner::next(CaptureMode::Captions { translate });
+        let started_rx = spawn_live_capture_worker(&app, &store, &settings, Some(loopback), owner)?;
+        if let Ok(Err(report)) =
+            started_rx.recv_timeout(Duration::from_millis(LIVE_CAPTURE_OPEN_TIMEOUT_MS))
+        {
+            if !report.waiting {
+                let _ = stop_live_capture_matching(&app, &store, |session| session.id == owner.id);
+            }
+            return Err(SonoraError::MicUnavailable(report));
+        }
+        emit_live_mic_state(&app, true);
+        Ok(true)
+    })
+}
+
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase4_stop_live_captions(
+    app: tauri::AppHandle,
+    store: tauri::State<'_, PipelineStore>,
+) -> Result<bool, SonoraError> {
+    instrument_command(&app, "phase4_stop_live_captions", || {
+        stop_live_capture_matching(&app, &store, |owner| {
+            matches!(owner.mode, CaptureMode::Captions { .. })
+        })
+    })
+}
+
+// Puts back the engine the settings describe once a translating captions
+// session ends, so dictation transcribes again.
+#[cfg(feature = "desktop")]
+fn restore_configured_transcriber(app: &tauri::AppHandle, logs_path: &Path) {
+    let Ok(settings) = app
+        .state::<SettingsState>()
+        .settings
+        .lock()
+        .map(|settings| settings.clone())
+    else {
+        return;
+    };
+    if let Err(error) =
+        apply_runtime_transcriber_from_settings(app, &settings, &app.state::<PipelineStore>())
+    {
+        let _ = log_store::append(logs_path, "error", "captions.engine", &error.to_string());
+    }
+}
+
+#[cfg(feature = "desktop")]
+fn translating_engine(app: &tauri::AppHandle, settings: &AppSettings) -> RuntimeEngine {
+    let resource_dir = app.path().resource_dir().ok();
+    let mut spec = engine_spec_for_settings(settings, resource_dir);
+    spec.translate = true;
+    isolated_engine(spec)
+}
+
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase2_get_focused_app(
+    app: tauri::AppHandle,
+    watch_state: tauri::State<'_, FocusWatchState>,
+) -> Result<Option<FocusChangedPayload>, SonoraError> {
+    instrument_command(&app, "phase2_get_focused_app", || {
+        Ok(watch_state
+            .current
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("focus watcher"))?
+            .clone())
+    })
+}
+
+#[cfg(feature = "desktop")]
+fn spawn_drop_folder_worker(app: tauri::AppHandle) {
+    thread::spawn(move || {
+        // Jobs build their own engine on this thread, and on Linux the
+        // transcriber host and sidecars inherit its lowered priority.
+        if thread_priority_wanted(&app, ThreadRole::Inference) {
+            log_thread_priority(
+                &app.state::<RuntimeLogState>().path,
+                &thread_priority::apply_current_thread(ThreadRole::Inference),
+            );
+        }
+        let mut scanner = jobs::DropFolderScanner::default();
+        loop {
+            let settings = match app.state::<SettingsState>().settings.lock() {
+                Ok(settings) => settings.clone(),
+                Err(_) => return,
+            };
+            thread::sleep(Duration::from_secs(u64::from(
+                settings.drop_folder_poll_secs.max(1),
+            )));
+
+            let Some(folder) = settings
+                .drop_folder_path
+                .as_deref()
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+                .map(PathBuf::from)
+            else {
+                continue;
+            };
+
+            let pipeline = app.state::<PipelineStore>();
+            let capture_active = pipeline
+                .live_capture
+                .lock()
+                .map(|capture| capture.is_some())
+                .unwrap_or(true);
+            if capture_active {
+                continue;
+            }
+
+            let ready = match scanner.scan(&folder) {
+                Ok(ready) => ready,
+                Err(error) => {
+                    let logs = app.state::<RuntimeLogState>();
+                    let _ = log_store::append(
+                        &logs.path,
+                        "warn",
+                        "jobs.scan",
+                        &format!("failed to scan drop folder: {error}"),
+                    );
+                    continue;
+                }
+            };
+
+            for source in ready {
+                run_drop_folder_job(&app, &settings, &source);
+                scanner.mark_handled(&source);
+            }
+        }
+    });
+}
+
+#[cfg(feature = "desktop")]
+fn run_drop_folder_job(app: &tauri::AppHandle, settings: &AppSettings, source: &Path) {
+    let started_unix_ms = current_unix_ms_u64();
+    let payload = transcribe_audio_file(app, settings, source);
+    let transcript_path = jobs::transcript_path_for(source);
+
+    let outcome = match (&payload.transcript, &payload.error) {
+        (Some(transcript), None) => std::fs::write(&transcript_path, format!("{transcript}\n"))
+            .map_err(|error| format!("failed to write transcript: {error}")),
+        (_, Some(error)) => Err(error.to_string()),
+        (None, None) => Err("transcription produced no result".to_string()),
+    };
+    if let Err(error) = &outcome {
+        let _ = jobs::mark_failed(source, error);
+    }
+
+    let jobs_state = app.state::<JobState>();
+    let Ok(mut records) = jobs_state.records.lock() else {
+        return;
+    };
+    let record = JobRecord {
+        id: jobs::next_job_id(&records),
+        source_path: payload.path.clone(),
+        transcript_path: outcome
+            .is_ok()
+            .then(|| transcript_path.to_string_lossy().to_string()),
+        status: if outcome.is_ok() {
+            jobs::JobStatus::Completed
+        } else {
+            jobs::JobStatus::Failed
+        },
+        error: outcome.err(),
+        audio_duration_ms: payload.duration_ms,
+        started_unix_ms,
+        finished_unix_ms: current_unix_ms_u64(),
+    };
+    jobs::append_job(&mut records, record.clone());
+    let _ = jobs::save_jobs(&jobs_state.path, &records);
+    drop(records);
+
+    let logs = app.state::<RuntimeLogState>();
+    let _ = match &record.error {
+        Some(error) => log_store::append(&logs.path, "error", "jobs.run", error),
+        None => log_store::append(
+            &logs.path,
+            "info",
+            "jobs.run",
+            &format!("transcribed {} ms of audio", record.audio_duration_ms),
+        ),
+    };
+    let _ = emit_event(app, events::TRANSCRIPTION_JOB, record);
+}
+
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase4_get_session_stats(
+    app: tauri::AppHandle,
+    store: tauri::State<'_, PipelineStore>,
+) -> Result<SessionStats, SonoraError> {
+    instrument_command(&app, "phase4_get_session_stats", || {
+        let stats = store
+            .session_stats
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("session stats"))?;
+        Ok(stats.clone())
+    })
+}
+
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase4_get_transcript_viewer(
+    app: tauri::AppHandle,
+    viewer_state: tauri::State<'_, TranscriptViewerState>,
+) -> Result<Option<viewer::ViewerStatus>, SonoraError> {
+    instrument_command(&app, "phase4_get_transcript_viewer", || {
+        let server = viewer_state
+            .server
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("transcript viewer"))?;
+        Ok(server.as_ref().map(viewer::ViewerServer::status))
+    })
+}
+
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase4_perf_mark_ui_transcript_received(
+    app: tauri::AppHandle,
+    logs: tauri::State<'_, RuntimeLogState>,
+    chunk_id: u64,
+    emitted_unix_ms: u64,
+) -> Result<(), SonoraError> {
+    instrument_command(&app, "phase4_perf_mark_ui_transcript_received", || {
+        if !logs.perf_enabled {
+            return Ok(());
+        }
+
+        let received_unix_ms = current_unix_ms_u64();
+        append_perf_event(
+            &logs.path,
+            logs.perf_enabled,
+            "perf.ui_transcript",
+            &PerfUiTranscriptTrace {
+                chunk_id,
+                emitted_unix_ms,
+                received_unix_ms,
+                emit_to_ui_ms: received_unix_ms.saturating_sub(emitted_unix_ms),
+            },
+        );
+        Ok(())
+    })
+}
+
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase4_get_recovery_checkpoint(
+    app: tauri::AppHandle,
+    recovery: tauri::State<'_, RecoveryState>,
+) -> Result<RecoveryCheckpoint, SonoraError> {
+    instrument_command(&app, "phase4_get_recovery_checkpoint", || {
+        let checkpoint = recovery
+            .checkpoint
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("recovery"))?;
+        Ok(checkpoint.clone())
+    })
+}
+
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase4_acknowledge_recovery_notice(
+    app: tauri::AppHandle,
+    recovery: tauri::State<'_, RecoveryState>,
+) -> Result<RecoveryCheckpoint, SonoraError> {
+    instrument_command(&app, "phase4_acknowledge_recovery_notice", || {
+        let mut checkpoint = recovery
+            .checkpoint
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("recovery"))?;
+        let updated = recovery::acknowledge_recovery_notice(&checkpoint);
+        recovery::save(&recovery.path, &updated).map_err(SonoraError::io)?;
+        *checkpoint = updated.clone();
+        Ok(updated)
+    })
+}
+
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase4_mark_clean_shutdown(
+    app: tauri::AppHandle,
+    recovery: tauri::State<'_, RecoveryState>,
+) -> Result<RecoveryCheckpoint, SonoraError> {
+    instrument_command(&app, "phase4_mark_clean_shutdown", || {
+        mark_clean_shutdown_state(&recovery)
+    })
+}
+
+#[cfg(feature = "desktop")]
+fn current_logical_cores() -> usize {
+    std::thread::available_parallelism()
+        .map(std::num::NonZeroUsize::get)
+        .unwrap_or(4)
+}
+
+#[cfg(feature = "desktop")]
+fn build_transcriber_status(app: &tauri::AppHandle, settings: &AppSettings) -> TranscriberStatus {
+    let resource_dir = app.path().resource_dir().ok();
+    let runtime = build_runtime_engine(engine_spec_for_settings(settings, resource_dir));
+    let available_gpus = gpu_devices::list_nvidia_gpus();
+    let gpu_device = runtime
+        .diagnostics
+        .using_gpu
+        .then(|| gpu_devices::physical_gpu(&available_gpus, runtime.diagnostics.gpu_device_index))
+        .flatten();
+
+    TranscriberStatus {
+        ready: runtime.diagnostics.ready,
+        active_engine: runtime.diagnostics.active_engine,
+        description: runtime.diagnostics.description,
+        compute_backend: runtime.diagnostics.compute_backend,
+        using_gpu: runtime.diagnostics.using_gpu,
+        resolved_binary_path: runtime.diagnostics.resolved_binary_path,
+        checked_binary_paths: runtime.diagnostics.checked_binary_paths,
+        resolved_model_path: runtime.diagnostics.resolved_model_path,
+        model_exists: runtime.diagnostics.model_exists,
+        binary_integrity: runtime.diagnostics.binary_integrity,
+        gpu_device,
+        available_gpus,
+        sandbox: runtime.diagnostics.sandbox,
+        error: match runtime.transcriber {
+            RuntimeTranscriber::Unavailable { reason } => Some(reason),
+            _ => None,
+        },
+    }
+}
+
+#[cfg(feature = "desktop")]
+fn settings_with_language_override(settings: &AppSettings, language: Option<&str>) -> AppSettings {
+    let mut effective = settings.clone();
+    if let Some(language) = language {
+        effective.language = language.to_string();
+    }
+    effective
+}
+
+#[cfg(feature = "desktop")]
+fn build_runtime_engine_for_settings(
+    app: &tauri::AppHandle,
+    settings: &AppSettings,
+) -> RuntimeEngine {
+    let resource_dir = app.path().resource_dir().ok();
+    isolated_engine(engine_spec_for_settings(settings, resource_dir))
+}
+
+// Runs the engine in a transcriber host started from this executable; without
+// a known executable path it stays in-process.
+#[cfg(feature = "desktop")]
+fn isolated_engine(spec: EngineSpec) -> RuntimeEngine {
+    let runtime = build_runtime_engine(spec.clone());
+    match std::env::current_exe() {
+        Ok(program) => transcriber_host::hosted_engine(runtime, spec, program),
+        Err(_) => runtime,
+    }
+}
+
+#[cfg(feature = "desktop")]
+fn log_sidecar_integrity_warning(app: &tauri::AppHandle, runtime: &RuntimeEngine) {
+    let (Some(status), Some(binary_path)) = (
+        runtime.diagnostics.binary_integrity.as_ref(),
+        runtime.diagnostics.resolved_binary_path.as_deref(),
+    ) else {
+        return;
+    };
+    if let Some(warning) = status.warning(Path::new(binary_path)) {
+        let logs = app.state::<RuntimeLogState>();
+        let _ = log_store::append(&logs.path, "warn", "sidecar.integrity", &warning);
+    }
+}
+
+#[cfg(feature = "desktop")]
+fn apply_runtime_transcriber_from_settings(
+    app: &tauri::AppHandle,
+    settings: &AppSettings,
+    pipeline_store: &tauri::State<'_, PipelineStore>,
+) -> Result<TranscriberStatus, SonoraError> {
+    let language_override = pipeline_store
+        .language_override
+        .lock()
+        .map_err(|_| SonoraError::state_unavailable("language"))?
+        .clone();
+    let effective = settings_with_language_override(settings, language_override.as_deref());
+    let settings = &effective;
+
+    let runtime = build_runtime_engine_for_settings(app, settings);
+    log_sidecar_integrity_warning(app, &runtime);
+
+    let model_profile = settings.model_profile;
+    let tuning = tuning_for_settings(settings);
+    let vad_config = vad_config_for_settings(settings);
+    let preprocessing = preprocessing_for_settings(settings);
+    let noise_profile =
+        noise_profile_for_settings(app, settings, settings.microphone_id.as_deref());
+    let transcriber = runtime.transcriber;
+    pipeline_store
+        .pipeline
+        .call(move |pipeline| {
+            pipeline.set_model_profile(model_profile);
+            pipeline.set_tuning(tuning);
+            pipeline.set_vad_config(vad_config);
+            pipeline.set_preprocessing(preprocessing);
+            pipeline.set_noise_profile(noise_profile);
+            pipeline.set_transcriber(transcriber);
+        })
+        .map_err(|_| SonoraError::state_unavailable("pipeline"))?;
+
+    let mut active_language = pipeline_store
+        .active_language
+        .lock()
+        .map_err(|_| SonoraError::state_unavailable("language"))?;
+    *active_language = settings.language.clone();
+    drop(active_language);
+
+    Ok(build_transcriber_status(app, settings))
+}
+
+#[cfg(feature = "desktop")]
+fn noise_profile_for_settings(
+    app: &tauri::AppHandle,
+    settings: &AppSettings,
+    microphone_id: Option<&str>,
+) -> Option<noise_profile::NoiseProfile> {
+    if !settings.noise_suppression_enabled {
+        return None;
+    }
+    let legacy_key = noise_profile::microphone_key(microphone_id);
+    let key = noise_profile_key(microphone_id);
+    let state = app.try_state::<NoiseProfileState>()?;
+    let profiles = state.profiles.lock().ok()?;
+    profiles
+        .find(&key)
+        .or_else(|| profiles.find(&legacy_key))
+        .cloned()
+}
+
+// Profiles calibrated before device-name keys were stored under the enumeration index,
+// which the lookup above still falls back to.
+#[cfg(feature = "desktop")]
+fn noise_profile_key(microphone_id: Option<&str>) -> String {
+    audio::input_device_label(microphone_id)
+        .unwrap_or_else(|| noise_profile::microphone_key(microphone_id))
+}
+
+#[cfg(feature = "desktop")]
+fn switch_utterance_language(
+    app: &tauri::AppHandle,
+    language: Option<String>,
+) -> Result<TranscriberStatus, SonoraError> {
+    let settings_state = app.state::<SettingsState>();
+    let pipeline_state = app.state::<PipelineStore>();
+    let current = settings_state
+        .settings
+        .lock()
+        .map_err(|_| SonoraError::state_unavailable("settings"))?
+        .clone();
+    let override_language = language.filter(|value| *value != current.language);
+
+    {
+        let mut language_override = pipeline_state
+            .language_override
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("language"))?;
+        *language_override = override_language.clone();
+    }
+
+    let status = apply_runtime_transcriber_from_settings(app, &current, &pipeline_state)?;
+    let _ = emit_event(
+        app,
+        events::DICTATION_LANGUAGE,
+        LanguagePayload {
+            language: override_language
+                .clone()
+                .unwrap_or_else(|| current.language.clone()),
+            overridden: override_language.is_some(),
+        },
+    );
+
+    if let Some(logs) = app.try_state::<RuntimeLogState>() {
+        let _ = log_store::append(
+            &logs.path,
+            "info",
+            "language.switch",
+            &format!("utterance language override set to {:?}", override_language),
+        );
+    }
+
+    Ok(status)
+}
+
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase1_set_utterance_language(
+    app: tauri::AppHandle,
+    language: Option<String>,
+) -> Result<TranscriberStatus, SonoraError> {
+    instrument_command(&app, "phase1_set_utterance_language", || {
+        let normalized = match language.as_deref().map(str::trim) {
+            None | Some("") => None,
+            Some(raw) => Some(language::normalize_language_code(raw).ok_or_else(|| {
+                SonoraError::InvalidInput(format!("unsupported language: {raw}"))
+            })?),
+        };
+        switch_utterance_language(&app, normalized)
+    })
+}
+
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase1_get_active_language(
+    app: tauri::AppHandle,
+    store: tauri::State<'_, PipelineStore>,
+) -> Result<String, SonoraError> {
+    instrument_command(&app, "phase1_get_active_language", || {
+        let language = store
+            .active_language
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("language"))?;
+        Ok(language.clone())
+    })
+}
+
+#[cfg(feature = "desktop")]
+fn mark_clean_shutdown_state(
+    recovery: &tauri::State<'_, RecoveryState>,
+) -> Result<RecoveryCheckpoint, SonoraError> {
+    let mut checkpoint = recovery
+        .checkpoint
+        .lock()
+        .map_err(|_| SonoraError::state_unavailable("recovery"))?;
+    let now = recovery::current_unix_ms()?;
+    let updated = recovery::mark_clean_shutdown(&checkpoint, now);
+    recovery::save(&recovery.path, &updated).map_err(SonoraError::io)?;
+    *checkpoint = updated.clone();
+    Ok(updated)
+}
+
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase3_get_hardware_profile(
+    app: tauri::AppHandle,
+) -> Result<HardwareProfileStatus, SonoraError> {
+    instrument_command(&app, "phase3_get_hardware_profile", || {
+        Ok(hardware_profile_status())
+    })
+}
+
+#[cfg(feature = "desktop")]
+fn hardware_profile_status() -> HardwareProfileStatus {
+    let logical_cores = current_logical_cores();
+    let hardware_tier = detect_hardware_tier(logical_cores);
+    let recommended_profile = recommended_profile_for_tier(hardware_tier);
+
+    HardwareProfileStatus {
+        logical_cores,
+        hardware_tier,
+        recommended_profile,
+    }
+}
+
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase3_auto_select_profile(
+    app: tauri::AppHandle,
+    settings_state: tauri::State<'_, SettingsState>,
+    pipeline_state: tauri::State<'_, PipelineStore>,
+) -> Result<AppSettings, SonoraError> {
+    instrument_command(&app, "phase3_auto_select_profile", || {
+        let hardware = hardware_profile_status();
+        let patch = AppSettingsPatch {
+            model_profile: Some(hardware.recommended_profile),
+            ..AppSettingsPatch::default()
+        };
+
+        let mut settings = settings_state
+            .settings
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("settings"))?;
+        let updated = settings_store::apply_patch(&settings, patch);
+        scrubbing::Scrubber::new(&updated.scrubbing).map_err(SonoraError::InvalidInput)?;
+        validate_extra_hotkeys(&updated)?;
+        settings_store::save(&settings_state.settings_path, &updated).map_err(SonoraError::io)?;
+        *settings = updated.clone();
+
+        apply_runtime_transcriber_from_settings(&app, &updated, &pipeline_state)?;
+
+        command_metrics::note(
+            "info",
+            format!(
+                "auto-selected model profile {:?} for tier {:?}",
+                hardware.recommended_profile, hardware.hardware_tier
+            ),
+        );
+
+        Ok(updated)
+    })
+}
+
+// Returns the profile being switched to; the engine is rebuilt off-thread so a
+// running capture keeps going and picks it up on its next chunk.
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase3_toggle_model_profile(
+    app: tauri::AppHandle,
+    settings_state: tauri::State<'_, SettingsState>,
+) -> Result<ModelProfile, SonoraError> {
+    instrument_command(&app, "phase3_toggle_model_profile", || {
+        let from = settings_state
+            .settings
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("settings"))?
+            .model_profile;
+        let to = profile::toggled_profile(from);
+
+        let app = app.clone();
+        thread::spawn(move || {
+            let error = switch_model_profile(&app, from, to)
+                .err()
+                .map(|error| error.to_string());
+            // The switch finishes after the command returned, so it logs itself.
+            let _ = log_store::append(
+                &app.state::<RuntimeLogState>().path,
+                "info",
+                "profile.toggle",
+                &format!(
+                    "{from:?} -> {to:?}{}",
+                    error
+                        .as_deref()
+                        .map(|error| format!(" failed: {error}"))
+                        .unwrap_or_default()
+                ),
+            );
+            let _ = emit_event(
+                &app,
+                events::DICTATION_PROFILE_SWITCH,
+                ProfileSwitchPayload { from, to, error },
+            );
+        });
+        Ok(to)
+    })
+}
+
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase3_get_model_status(
+    app: tauri::AppHandle,
+    state: tauri::State<'_, SettingsState>,
+) -> Result<ModelStatus, SonoraError> {
+    instrument_command(&app, "phase3_get_model_status", || {
+        let settings = state
+            .settings
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("settings"))?;
+        let resource_dir = app.path().resource_dir().ok();
+        let mut status =
+            build_model_status(&settings, current_logical_cores(), resource_dir.as_deref());
+        status.faster_whisper_cache = Some(model_cache::cache_usage(
+            &faster_whisper_cache_dir_for(&settings, resource_dir.as_deref()),
+            &referenced_faster_whisper_models(&settings),
+        ));
+        Ok(status)
+    })
+}
+
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase3_prune_model_cache(
+    app: tauri::AppHandle,
+    state: tauri::State<'_, SettingsState>,
+) -> Result<model_cache::PruneReport, SonoraError> {
+    instrument_command(&app, "phase3_prune_model_cache", || {
+        let settings = state
+            .settings
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("settings"))?
+            .clone();
+        let resource_dir = app.path().resource_dir().ok();
+        let cache_dir = faster_whisper_cache_dir_for(&settings, resource_dir.as_deref());
+        let report = model_cache::prune_unreferenced(
+            &cache_dir,
+            &referenced_faster_whisper_models(&settings),
+        )
+        .map_err(SonoraError::io)?;
+
+        command_metrics::note(
+            "info",
+            format!(
+                "removed {} cached model(s), freed {} bytes",
+                report.removed.len(),
+                report.freed_bytes
+            ),
+        );
+        Ok(report)
+    })
+}
+
+#[cfg(feature = "desktop")]
+fn faster_whisper_cache_dir_for(settings: &AppSettings, resource_dir: Option<&Path>) -> PathBuf {
+    transcriber::resolve_faster_whisper_model_cache_dir(
+        settings.faster_whisper_cache_dir.as_deref().map(Path::new),
+        resource_dir,
+    )
+}
+
+// Both profile defaults stay cached so switching profiles never triggers a re-download.
+#[cfg(feature = "desktop")]
+fn referenced_faster_whisper_models(settings: &AppSettings) -> Vec<String> {
+    let mut models = [ModelProfile::Fast, ModelProfile::Balanced]
+        .into_iter()
+        .map(|profile| transcriber::default_faster_whisper_model(profile).to_string())
+        .collect::<Vec<_>>();
+    models.extend(settings.faster_whisper_model.clone());
+    models
+}
+
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase3_list_model_aliases(
+    app: tauri::AppHandle,
+) -> Result<Vec<model_catalog::ModelAlias>, SonoraError> {
+    instrument_command(&app, "phase3_list_model_aliases", || {
+        Ok(model_catalog::MODEL_ALIASES.to_vec())
+    })
+}
+
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase3_get_model_conversion(
+    app: tauri::AppHandle,
+    state: tauri::State<'_, ModelConversionState>,
+) -> Result<Option<model_conversion::ConversionProgress>, SonoraError> {
+    instrument_command(&app, "phase3_get_model_conversion", || {
+        state
+            .current
+            .lock()
+            .map(|current| current.clone())
+            .map_err(|_| SonoraError::state_unavailable("model conversion"))
+    })
+}
+
+// Starts quantizing the configured whisper.cpp model in the background; the
+// level defaults to the one recommended for this machine's hardware tier.
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase3_convert_model(
+    app: tauri::AppHandle,
+    level: Option<model_conversion::QuantizationLevel>,
+) -> Result<model_conversion::ConversionProgress, SonoraError> {
+    instrument_command(&app, "phase3_convert_model", || {
+        if live_capture_active(&app) {
+            return Err(SonoraError::InvalidInput(
+                "model conversion waits until dictation is idle".to_string(),
+            ));
+        }
+        let plan = model_conversion_plan(&app, level)?;
+        start_model_conversion(&app, plan)
+    })
+}
+
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase3_set_model_path(
+    app: tauri::AppHandle,
+    settings_state: tauri::State<'_, SettingsState>,
+    pipeline_state: tauri::State<'_, PipelineStore>,
+    path: Option<String>,
+) -> Result<AppSettings, SonoraError> {
+    instrument_command(&app, "phase3_set_model_path", || {
+        let mut settings = settings_state
+            .settings
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("settings"))?;
+
+        // Bundled catalog files are stored by alias so settings stay portable.
+        let normalized = path
+            .map(|value| value.trim().to_string())
+            .and_then(|value| if value.is_empty() { None } else { Some(value) })
+            .map(|value| match model_catalog::alias_for_path(&value) {
+                Some(entry) => entry.alias.to_string(),
+                None => value,
+            });
+
+        let patch = AppSettingsPatch {
+            model_path: Some(normalized),
+            ..AppSettingsPatch::default()
+        };
+        let updated = settings_store::apply_patch(&settings, patch);
+        scrubbing::Scrubber::new(&updated.scrubbing).map_err(SonoraError::InvalidInput)?;
+        validate_extra_hotkeys(&updated)?;
+        settings_store::save(&settings_state.settings_path, &updated).map_err(SonoraError::io)?;
+        *settings = updated.clone();
+
+        apply_runtime_transcriber_from_settings(&app, &updated, &pipeline_state)?;
+
+        command_metrics::note(
+            "info",
+            format!("set model path to {:?}", updated.model_path),
+        );
+
+        Ok(updated)
+    })
+}
+
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase2_get_settings(
+    app: tauri::AppHandle,
+    state: tauri::State<'_, SettingsState>,
+) -> Result<AppSettings, SonoraError> {
+    instrument_command(&app, "phase2_get_settings", || {
+        let settings = state
+            .settings
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("settings"))?;
+        Ok(settings.clone())
+    })
+}
+
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase2_update_settings(
+    app: tauri::AppHandle,
+    settings_state: tauri::State<'_, SettingsState>,
+    pipeline_state: tauri::State<'_, PipelineStore>,
+    patch: AppSettingsPatch,
+) -> Result<SettingsUpdate, SonoraError> {
+    instrument_command(&app, "phase2_update_settings", || {
+        let mut settings = settings_state
+            .settings
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("settings"))?;
+        let updated = settings_store::apply_patch(&settings, patch);
+        scrubbing::Scrubber::new(&updated.scrubbing).map_err(SonoraError::InvalidInput)?;
+        validate_extra_hotkeys(&updated)?;
+        settings_store::save(&settings_state.settings_path, &updated).map_err(SonoraError::io)?;
+        *settings = updated.clone();
+
+        let mode = updated.mode;
+        pipeline_state
+            .pipeline
+            .call(move |pipeline| pipeline.set_mode(mode))
+            .map_err(|_| SonoraError::state_unavailable("pipeline"))?;
+        apply_runtime_transcriber_from_settings(&app, &updated, &pipeline_state)?;
+        sync_transcript_viewer(&app, &updated);
+
+        let resource_dir = app.path().resource_dir().ok();
+        let language_warning = language_support::check(&updated, resource_dir.as_deref());
+        if let Some(warning) = &language_warning {
+            command_metrics::note("warn", warning.message.clone());
+        }
+        Ok(SettingsUpdate {
+            settings: updated,
+            language_warning,
+        })
+    })
+}
+
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase2_save_microphone_profile(
+    app: tauri::AppHandle,
+    settings_state: tauri::State<'_, SettingsState>,
+    microphone_id: Option<String>,
+    mic_sensitivity_percent: Option<u16>,
+    input_channel: Option<u16>,
+    playback_guard: Option<config::PlaybackGuard>,
+) -> Result<AppSettings, SonoraError> {
+    instrument_command(&app, "phase2_save_microphone_profile", || {
+        let device_label = audio::input_device_label(microphone_id.as_deref())
+            .ok_or_else(|| SonoraError::NotFound("microphone is not available".to_string()))?;
+        let mut settings = settings_state
+            .settings
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("settings"))?;
+
+        let mut profiles = settings.microphone_profiles.clone();
+        mic_profile::upsert(
+            &mut profiles,
+            config::MicrophoneProfile {
+                device_label,
+                mic_sensitivity_percent,
+                input_channel,
+                playback_guard,
+            },
+        );
+        let patch = AppSettingsPatch {
+            microphone_profiles: Some(profiles),
+            ..AppSettingsPatch::default()
+        };
+        let updated = settings_store::apply_patch(&settings, patch);
+        settings_store::save(&settings_state.settings_path, &updated).map_err(SonoraError::io)?;
+        *settings = updated.clone();
+        Ok(updated)
+    })
+}
+
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase2_list_language_packs(
+    app: tauri::AppHandle,
+) -> Result<Vec<language_pack::LanguagePack>, SonoraError> {
+    instrument_command(&app, "phase2_list_language_packs", || {
+        Ok(language_pack::LANGUAGE_PACKS.to_vec())
+    })
+}
+
+// Defaults to the language currently being transcribed.
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase2_get_spoken_phrases(
+    app: tauri::AppHandle,
+    settings_state: tauri::State<'_, SettingsState>,
+    pipeline_state: tauri::State<'_, PipelineStore>,
+    language: Option<String>,
+) -> Result<Option<language_pack::SpokenPhrases>, SonoraError> {
+    instrument_command(&app, "phase2_get_spoken_phrases", || {
+        let language = match language {
+            Some(language) => language,
+            None => pipeline_state
+                .active_language
+                .lock()
+                .map_err(|_| SonoraError::state_unavailable("language"))?
+                .clone(),
+        };
+        let settings = settings_state
+            .settings
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("settings"))?;
+        Ok(language_pack::SpokenPhrases::resolve(
+            &language,
+            &settings.spoken_phrase_overrides,
+        ))
+    })
+}
+
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase2_apply_language_pack(
+    app: tauri::AppHandle,
+    settings_state: tauri::State<'_, SettingsState>,
+    pipeline_state: tauri::State<'_, PipelineStore>,
+    code: String,
+) -> Result<AppSettings, SonoraError> {
+    instrument_command(&app, "phase2_apply_language_pack", || {
+        let pack = language_pack::find_pack(&code).ok_or_else(|| {
+            SonoraError::NotFound(format!("no language pack for: {}", code.trim()))
+        })?;
+        let updated = {
+            let mut settings = settings_state
+                .settings
+                .lock()
+                .map_err(|_| SonoraError::state_unavailable("settings"))?;
+            let updated = language_pack::apply_pack(&settings, pack);
+            settings_store::save(&settings_state.settings_path, &updated)
+                .map_err(SonoraError::io)?;
+            *settings = updated.clone();
+            updated
+        };
+
+        // The pack becomes the base language, so drop any per-utterance override.
+        *pipeline_state
+            .language_override
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("language"))? = None;
+        apply_runtime_transcriber_from_settings(&app, &updated, &pipeline_state)?;
+        let _ = emit_event(
+            &app,
+            events::DICTATION_LANGUAGE,
+            LanguagePayload {
+                language: updated.language.clone(),
+                overridden: false,
+            },
+        );
+
+        Ok(updated)
+    })
+}
+
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase2_get_recent_insertions(
+    app: tauri::AppHandle,
+    insertion_state: tauri::State<'_, InsertionState>,
+) -> Result<Vec<InsertionRecord>, SonoraError> {
+    instrument_command(&app, "phase2_get_recent_insertions", || {
+        let records = insertion_state
+            .records
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("insertion"))?;
+        Ok(records.clone())
+    })
+}
+
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase2_search_transcripts(
+    app: tauri::AppHandle,
+    insertion_state: tauri::State<'_, InsertionState>,
+    query: Option<String>,
+    session_label: Option<String>,
+    limit: Option<usize>,
+) -> Result<Vec<InsertionRecord>, SonoraError> {
+    instrument_command(&app, "phase2_search_transcripts", || {
+        let records = insertion_state
+            .records
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("insertion"))?;
+        Ok(
+            insertion::search_records(&records, query.as_deref(), session_label.as_deref())
+                .into_iter()
+                .take(limit.unwrap_or(usize::MAX))
+                .cloned()
+                .collect(),
+        )
+    })
+}
+
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase2_get_utterances(
+    app: tauri::AppHandle,
+    insertion_state: tauri::State<'_, InsertionState>,
+    utterance_state: tauri::State<'_, UtteranceState>,
+    query: Option<String>,
+    session_label: Option<String>,
+    limit: Option<usize>,
+) -> Result<Vec<utterance_history::UtteranceView>, SonoraError> {
+    instrument_command(&app, "phase2_get_utterances", || {
+        let entries = utterance_state
+            .entries
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("utterance history"))?;
+        let records = insertion_state
+            .records
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("insertion"))?;
+        Ok(
+            utterance_history::search(&entries, query.as_deref(), session_label.as_deref())
+                .into_iter()
+                .take(limit.unwrap_or(usize::MAX))
+                .map(|entry| utterance_history::view(entry, &records))
+                .collect(),
+        )
+    })
+}
+
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase2_get_utterance(
+    app: tauri::AppHandle,
+    insertion_state: tauri::State<'_, InsertionState>,
+    utterance_state: tauri::State<'_, UtteranceState>,
+    utterance_id: u64,
+) -> Result<utterance_history::UtteranceView, SonoraError> {
+    instrument_command(&app, "phase2_get_utterance", || {
+        let entries = utterance_state
+            .entries
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("utterance history"))?;
+        let entry = entries
+            .iter()
+            .find(|entry| entry.id == utterance_id)
+            .ok_or_else(|| SonoraError::NotFound(format!("utterance {utterance_id} not found")))?;
+        let records = insertion_state
+            .records
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("insertion"))?;
+        Ok(utterance_history::view(entry, &records))
+    })
+}
+
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase2_export_utterance_audio(
+    app: tauri::AppHandle,
+    utterance_state: tauri::State<'_, UtteranceState>,
+    utterance_id: u64,
+    path: Option<String>,
+) -> Result<String, SonoraError> {
+    instrument_command(&app, "phase2_export_utterance_audio", || {
+        let entry = utterance_state
+            .entries
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("utterance history"))?
+            .iter()
+            .find(|entry| entry.id == utterance_id)
+            .cloned()
+            .ok_or_else(|| SonoraError::NotFound(format!("utterance {utterance_id} not found")))?;
+        if entry.audio_path.is_none() {
+            return Err(SonoraError::InvalidInput(
+                if utterance_audio_enabled(&app) {
+                    format!("utterance {utterance_id} has no retained audio")
+                } else {
+                    "utterance audio retention is off".to_string()
+                },
+            ));
+        }
+        let created_unix_ms = current_unix_ms_u64();
+        let target = path
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+            .map(PathBuf::from)
+            .unwrap_or_else(|| {
+                utterance_history::default_export_path(utterance_id, created_unix_ms)
+            });
+        let exported = utterance_history::export_audio(&entry, &target, created_unix_ms)
+            .map_err(SonoraError::io)?;
+        Ok(exported.to_string_lossy().to_string())
+    })
+}
+
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase2_apply_retention(
+    app: tauri::AppHandle,
+    settings_state: tauri::State<'_, SettingsState>,
+) -> Result<retention::PurgeReport, SonoraError> {
+    instrument_command(&app, "phase2_apply_retention", || {
+        let policy = settings_state
+            .settings
+            .lock()
+            .map(|settings| retention::RetentionPolicy::from_settings(&settings))
+            .map_err(|_| SonoraError::state_unavailable("settings"))?;
+        Ok(apply_retention(&app, &policy))
+    })
+}
+
+// Deletes transcripts, insertion history, recordings and logs regardless of the
+// policy. Not instrumented, so the purge leaves no log line behind.
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase2_purge_history(
+    app: tauri::AppHandle,
+    review_state: tauri::State<'_, ReviewQueueState>,
+) -> Result<retention::PurgeReport, SonoraError> {
+    let report = apply_retention(&app, &retention::RetentionPolicy::NOTHING);
+    review_state
+        .items
+        .lock()
+        .map_err(|_| SonoraError::state_unavailable("review queue"))?
+        .clear();
+    Ok(report)
+}
+
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase2_get_session_labels(
+    app: tauri::AppHandle,
+    insertion_state: tauri::State<'_, InsertionState>,
+) -> Result<Vec<String>, SonoraError> {
+    instrument_command(&app, "phase2_get_session_labels", || {
+        let records = insertion_state
+            .records
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("insertion"))?;
+        Ok(insertion::session_labels(&records))
+    })
+}
+
+// Insertion waits for the target to regain focus and runs OS probes, so the
+// commands that insert run on the blocking pool instead of the main thread.
+#[cfg(feature = "desktop")]
+async fn blocking_command<T: Send + 'static>(
+    app: tauri::AppHandle,
+    name: &'static str,
+    body: impl FnOnce(&tauri::AppHandle) -> Result<T, SonoraError> + Send + 'static,
+) -> Result<T, SonoraError> {
+    tauri::async_runtime::spawn_blocking(move || instrument_command(&app, name, || body(&app)))
+        .await
+        .map_err(|error| SonoraError::internal(format!("{name} did not finish: {error}")))?
+}
+
+#[cfg(feature = "desktop")]
+#[tauri::command]
+async fn phase2_insert_text(
+    app: tauri::AppHandle,
+    text: String,
+    strategy: Option<InsertionStrategy>,
+) -> Result<InsertionRecord, SonoraError> {
+    blocking_command(app, "phase2_insert_text", move |app| {
+        let pipeline_state = app.state::<PipelineStore>();
+        if text.trim().is_empty() {
+            return Err(SonoraError::InvalidInput(
+                "cannot insert empty text".to_string(),
+            ));
+        }
+
+        let language = pipeline_state
+            .active_language
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("language"))?
+            .clone();
+        perform_insertion(
+            app,
+            text,
+            strategy.unwrap_or_default(),
+            None,
+            Some(language),
+            None,
+        )
+    })
+    .await
+}
+
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase2_list_review_queue(
+    app: tauri::AppHandle,
+    review: tauri::State<'_, ReviewQueueState>,
+) -> Result<Vec<review_queue::ReviewItem>, SonoraError> {
+    instrument_command(&app, "phase2_list_review_queue", || {
+        Ok(review
+            .items
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("review queue"))?
+            .clone())
+    })
+}
+
+#[cfg(feature = "desktop")]
+#[tauri::command]
+async fn phase2_approve_review_item(
+    app: tauri::AppHandle,
+    item_id: u64,
+    strategy: Option<InsertionStrategy>,
+) -> Result<InsertionRecord, SonoraError> {
+    blocking_command(app, "phase2_approve_review_item", move |app| {
+        let item = take_review_item(&app.state::<ReviewQueueState>(), item_id)?;
+        perform_insertion(
+            app,
+            item.text,
+            strategy.unwrap_or_default(),
+            None,
+            item.language,
+            item.utterance_id,
+        )
+    })
+    .await
+}
+
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase2_discard_review_item(
+    app: tauri::AppHandle,
+    review: tauri::State<'_, ReviewQueueState>,
+    item_id: u64,
+) -> Result<review_queue::ReviewItem, SonoraError> {
+    instrument_command(&app, "phase2_discard_review_item", || {
+        take_review_item(&review, item_id)
+    })
+}
+
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase2_submit_correction(
+    app: tauri::AppHandle,
+    insertion_state: tauri::State<'_, InsertionState>,
+    correction_state: tauri::State<'_, CorrectionState>,
+    corrected: String,
+    original: Option<String>,
+    record_id: Option<u64>,
+) -> Result<corrections::CorrectionOutcome, SonoraError> {
+    instrument_command(&app, "phase2_submit_correction", || {
+        let original = match (original, record_id) {
+            (Some(original), _) => original,
+            (None, Some(record_id)) => insertion_state
+                .records
+                .lock()
+                .map_err(|_| SonoraError::state_unavailable("insertion history"))?
+                .iter()
+                .find(|record| record.id == record_id)
+                .map(|record| record.text.clone())
+                .ok_or_else(|| {
+                    SonoraError::NotFound(format!("insertion record {record_id} not found"))
+                })?,
+            (None, None) => {
+                return Err(SonoraError::InvalidInput(
+                    "a correction needs the original text or a record id".to_string(),
+                ))
+            }
+        };
+        if original.trim().is_empty() || corrected.trim().is_empty() {
+            return Err(SonoraError::InvalidInput(
+                "original and corrected text must not be empty".to_string(),
+            ));
+        }
+
+        let mut store = correction_state
+            .store
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("corrections"))?;
+        let outcome = store.submit(corrections::CorrectionPair {
+            original,
+            corrected,
+            record_id,
+            submitted_unix_ms: current_unix_ms_u64(),
+        });
+        corrections::save(&correction_state.path, &store).map_err(SonoraError::io)?;
+        Ok(outcome)
+    })
+}
+
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase2_get_corrections(
+    app: tauri::AppHandle,
+    correction_state: tauri::State<'_, CorrectionState>,
+) -> Result<corrections::CorrectionStore, SonoraError> {
+    instrument_command(&app, "phase2_get_corrections", || {
+        Ok(correction_state
+            .store
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("corrections"))?
+            .clone())
+    })
+}
+
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase2_remove_correction_rule(
+    app: tauri::AppHandle,
+    correction_state: tauri::State<'_, CorrectionState>,
+    from: String,
+) -> Result<corrections::ReplacementRule, SonoraError> {
+    instrument_command(&app, "phase2_remove_correction_rule", || {
+        let mut store = correction_state
+            .store
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("corrections"))?;
+        let removed = store
+            .remove_rule(&from)
+            .ok_or_else(|| SonoraError::NotFound(format!("no correction rule for \"{from}\"")))?;
+        corrections::save(&correction_state.path, &store).map_err(SonoraError::io)?;
+        Ok(removed)
+    })
+}
+
+#[cfg(feature = "desktop")]
+fn take_review_item(
+    review: &ReviewQueueState,
+    item_id: u64,
+) -> Result<review_queue::ReviewItem, SonoraError> {
+    let mut items = review
+        .items
+        .lock()
+        .map_err(|_| SonoraError::state_unavailable("review queue"))?;
+    review_queue::take(&mut items, item_id)
+        .ok_or_else(|| SonoraError::NotFound(format!("review item {item_id} not found")))
+}
+
+#[cfg(feature = "desktop")]
+#[tauri::command]
+async fn phase2_retry_insertion(
+    app: tauri::AppHandle,
+    record_id: u64,
+    strategy: Option<InsertionStrategy>,
+) -> Result<InsertionRecord, SonoraError> {
+    blocking_command(app, "phase2_retry_insertion", move |app| {
+        let original = {
+            let insertion_state = app.state::<InsertionState>();
+            let records = insertion_state
+                .records
+                .lock()
+                .map_err(|_| SonoraError::state_unavailable("insertion"))?;
+            insertion::find_record(&records, record_id)
+                .cloned()
+                .ok_or_else(|| {
+                    SonoraError::NotFound(format!("insertion record {record_id} not found"))
+                })?
+        };
+
+        perform_insertion(
+            app,
+            original.text,
+            strategy.unwrap_or(original.strategy),
+            Some(original.id),
+            original.language,
+            original.utterance_id,
+        )
+    })
+    .await
+}
+
+#[cfg(feature = "desktop")]
+fn adapt_text_for_target(
+    insertion_state: &InsertionState,
+    app_rules: &[config::AppRule],
+    terms: &[String],
+    text: &str,
+) -> Result<String, SonoraError> {
+    if app_rules.is_empty() {
+        return Ok(text.to_string());
+    }
+
+    let remembered = insertion_state
+        .focus_target
+        .lock()
+        .map_err(|_| SonoraError::state_unavailable("insertion"))?
+        .clone();
+    let target = remembered.or_else(|| {
+        focus::frontmost_app_within(Duration::from_millis(FOCUS_WATCH_INTERVAL_MS)).ok()
+    });
+    let casing = postprocess::casing_for_app(
+        app_rules,
+        target.as_ref().map(|value| value.app_name.as_str()),
+    );
+    Ok(postprocess::apply_casing_with_terms(text, casing, terms))
+}
+
+#[cfg(feature = "desktop")]
+fn perform_insertion(
+    app: &tauri::AppHandle,
+    text: String,
+    strategy: InsertionStrategy,
+    retry_of: Option<u64>,
+    language: Option<String>,
+    utterance_id: Option<u64>,
+) -> Result<InsertionRecord, SonoraError> {
+    let settings_state = app.state::<SettingsState>();
+    let insertion_state = app.state::<InsertionState>();
+    let logs = app.state::<RuntimeLogState>();
+    let panic_epoch = Arc::clone(&app.state::<PipelineStore>().panic_epoch);
+    let started_epoch = panic_epoch.load(Ordering::SeqCst);
+
+    let (
+        fallback_enabled,
+        max_recent_insertions,
+        focus_guard_timeout,
+        app_rules,
+        scrubbing,
+        length_limit,
+        verification,
+    ) = {
+        let settings = settings_state
+            .settings
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("settings"))?;
+        (
+            settings.clipboard_fallback,
+            usize::from(settings.max_recent_insertions),
+            settings
+                .focus_guard_enabled
+                .then(|| Duration::from_millis(u64::from(settings.focus_guard_timeout_ms))),
+            settings.app_rules.clone(),
+            settings.scrubbing.clone(),
+            (
+                settings.max_insertion_chars as usize,
+                settings.long_transcript_behavior,
+            ),
+            (
+                settings.insertion_verification,
+                settings.retry_unverified_insertion && settings.clipboard_fallback,
+            ),
+        )
+    };
+    let text = adapt_text_for_target(&insertion_state, &app_rules, &vocabulary_terms(app), &text)?;
+    let text = scrub_before_insertion(app, &logs.path, &scrubbing, text)?;
+    let segments = limit_insertion_length(app, &logs.path, text, length_limit.0, length_limit.1);
+
+    let mut last_record = None;
+    for segment in segments {
+        let focus_ready = match focus_guard_timeout {
+            Some(timeout) => await_insertion_focus(&insertion_state, &logs.path, timeout)?,
+            None => true,
+        };
+        if panic_epoch.load(Ordering::SeqCst) != started_epoch {
+            let _ = log_store::append(
+                &logs.path,
+                "warn",
+                "insertion.cancelled",
+                "panic hotkey cancelled the remaining insertion",
+            );
+            return Err(SonoraError::Insertion(
+                "insertion cancelled by the panic hotkey".to_string(),
+            ));
+        }
+        let canary =
+            (verification.0 == InsertionVerification::Canary).then(focus::detect_frontmost_app);
+        let status = if focus_ready {
+            resolve_status_for_strategy(
+                strategy,
+                fallback_enabled,
+                || try_direct_insertion(&segment),
+                || try_clipboard_fallback(&segment),
+            )
+        } else {
+            insertion::InsertionStatus::Failure
+        };
+        let status = verify_insertion(&logs.path, &segment, status, verification, canary);
+
+        let session_label = insertion_state
+            .session_label
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("insertion"))?
+            .clone();
+        let mut records = insertion_state
+            .records
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("insertion"))?;
+        let record = InsertionRecord {
+            strategy,
+            retry_of,
+            language: language.clone(),
+            session_label,
+            utterance_id,
+            created_unix_ms: current_unix_ms_u64(),
+            ..InsertionRecord::new(insertion::next_record_id(&records), segment, status)
+        };
+        append_recent(&mut records, record.clone(), max_recent_insertions);
+        if let Err(error) = insertion::save_recent(&insertion_state.path, &records) {
+            let _ = log_store::append(&logs.path, "warn", "insertion.history", &error);
+        }
+        drop(records);
+        update_utterance(app, |entries| {
+            utterance_history::record_insertion(entries, &record)
+        });
+
+        emit_event(app, events::DICTATION_INSERTION, record.clone())
+            .map_err(|error| SonoraError::internal(error.to_string()))?;
+        announce_to_screen_reader(app, announce::insertion(&record));
+
+        let _ = log_store::append(
+            &logs.path,
+            "info",
+            "insertion.attempt",
+            &format!(
+                "insertion status {:?} strategy {:?} retry_of {:?}",
+                record.status, record.strategy, record.retry_of
+            ),
+        );
+
+        let failed = record.status == insertion::InsertionStatus::Failure;
+        last_record = Some(record);
+        if failed {
+            break;
+        }
+    }
+
+    last_record.ok_or_else(|| SonoraError::InvalidInput("nothing to insert".to_string()))
+}
+
+#[cfg(feature = "desktop")]
+fn verify_insertion(
+    logs_path: &Path,
+    segment: &str,
+    status: insertion::InsertionStatus,
+    (mode, retry_via_clipboard): (InsertionVerification, bool),
+    canary: Option<Result<FocusTarget, String>>,
+) -> insertion::InsertionStatus {
+    if status != insertion::InsertionStatus::Success {
+        return status;
+    }
+    let outcome = match (mode, canary) {
+        (InsertionVerification::Off, _) => return status,
+        (InsertionVerification::Canary, Some(Ok(before))) => match focus::detect_frontmost_app() {
+            Ok(after) if after.matches(&before) => VerificationOutcome::Verified,
+            Ok(after) => VerificationOutcome::Mismatch(format!(
+                "focus moved from {} to {} during insertion",
+                before.app_name, after.app_name
+            )),
+            Err(error) => VerificationOutcome::Unavailable(error),
+        },
+        (InsertionVerification::Canary, Some(Err(error))) => {
+            VerificationOutcome::Unavailable(error)
+        }
+        (InsertionVerification::Canary, None) => {
+            VerificationOutcome::Unavailable("no focus canary was taken".to_string())
+        }
+        (InsertionVerification::Readback, _) => match focus::read_focused_field_value() {
+            Ok(value) if insertion::readback_contains(&value, segment) => {
+                VerificationOutcome::Verified
+            }
+            Ok(_) => VerificationOutcome::Mismatch(
+                "inserted text was not found in the focused field".to_string(),
+            ),
+            Err(error) => VerificationOutcome::Unavailable(error),
+        },
+    };
+
+    let verified = insertion::apply_verification(status, &outcome);
+    let reason = match &outcome {
+        VerificationOutcome::Verified => return verified,
+        VerificationOutcome::Mismatch(reason) | VerificationOutcome::Unavailable(reason) => reason,
+    };
+    let _ = log_store::append(logs_path, "warn", "insertion.verify", reason);
+
+    // An unavailable check says nothing about the target, so retrying could paste twice.
+    if retry_via_clipboard
+        && matches!(outcome, VerificationOutcome::Mismatch(_))
+        && try_clipboard_fallback(segment).is_ok()
+    {
+        return insertion::InsertionStatus::Fallback;
+    }
+    verified
+}
+
+#[cfg(feature = "desktop")]
+fn scrub_before_insertion(
+    app: &tauri::AppHandle,
+    logs_path: &Path,
+    settings: &config::ScrubbingSettings,
+    text: String,
+) -> Result<String, SonoraError> {
+    let Some(scrubber) = scrubbing::Scrubber::new(settings).map_err(SonoraError::InvalidInput)?
+    else {
+        return Ok(text);
+    };
+    let outcome = scrubber.scrub(&text);
+    if outcome.matched.is_empty() {
+        return Ok(text);
+    }
+
+    let matched = outcome.matched.join(", ");
+    let _ = log_store::append(
+        logs_path,
+        "warn",
+        "insertion.scrub",
+        &format!(
+            "transcript matched sensitive-data patterns ({matched}); action {:?}",
+            settings.action
+        ),
+    );
+    let _ = emit_event(
+        app,
+        events::DICTATION_SCRUBBED,
+        ScrubPayload {
+            action: settings.action,
+            matched: outcome.matched,
+            blocked: outcome.blocked,
+        },
+    );
+    if outcome.blocked {
+        return Err(SonoraError::Insertion(format!(
+            "insertion blocked: transcript matched sensitive-data patterns ({matched})"
+        )));
+    }
+    Ok(outcome.text)
+}
+
+#[cfg(feature = "desktop")]
+fn limit_insertion_length(
+    app: &tauri::AppHandle,
+    logs_path: &Path,
+    text: String,
+    max_chars: usize,
+    behavior: config::LongTranscriptBehavior,
+) -> Vec<String> {
+    let original_chars = text.chars().count();
+    if max_chars == 0 || original_chars <= max_chars {
+        return vec![text];
+    }
+
+    let segments = match behavior {
+        config::LongTranscriptBehavior::Split => insertion::split_for_insertion(&text, max_chars),
+        config::LongTranscriptBehavior::Truncate => {
+            vec![insertion::truncate_for_insertion(&text, max_chars).unwrap_or(text)]
+        }
+    };
+    let payload = InsertionLimitPayload {
+        behavior,
+        max_chars,
+        original_chars,
+        inserted_chars: segments.iter().map(|segment| segment.chars().count()).sum(),
+        segments: segments.len(),
+    };
+    let _ = log_store::append(
+        logs_path,
+        "warn",
+        "insertion.limit",
+        &format!(
+            "transcript of {original_chars} chars exceeded the {max_chars}-char limit ({behavior:?})"
+        ),
+    );
+    let _ = emit_event(app, events::DICTATION_INSERTION_LIMIT, payload);
+    segments
+}
+
+#[cfg(feature = "desktop")]
+fn await_insertion_focus(
+    insertion_state: &InsertionState,
+    logs_path: &Path,
+    timeout: Duration,
+) -> Result<bool, SonoraError> {
+    let target = insertion_state
+        .focus_target
+        .lock()
+        .map_err(|_| SonoraError::state_unavailable("focus target"))?
+        .clone();
+    let Some(target) = target else {
+        return Ok(true);
+    };
+
+    match focus::wait_for_focus(
+        &target,
+        timeout,
+        Duration::from_millis(FOCUS_GUARD_POLL_MS),
+        || focus::frontmost_app_within(Duration::from_millis(FOCUS_GUARD_POLL_MS)),
+        thread::sleep,
+    ) {
+        FocusGuardOutcome::Focused => Ok(true),
+        FocusGuardOutcome::TimedOut { last_seen } => {
+            let _ = log_store::append(
+                logs_path,
+                "warn",
+                "insertion.focus_guard",
+                &format!(
+                    "target '{}' did not regain focus within {} ms (frontmost: {:?})",
+                    target.app_name,
+                    timeout.as_millis(),
+                    last_seen.map(|value| value.app_name)
+                ),
+            );
+            Ok(false)
+        }
+        FocusGuardOutcome::Unavailable(error) => {
+            let _ = log_store::append(logs_path, "warn", "insertion.focus_guard", &error);
+            Ok(true)
+        }
+    }
+}
+
+#[cfg(feature = "desktop")]
+fn remember_focus_target(
+    insertion_state: &InsertionState,
+) -> Result<Option<FocusTarget>, SonoraError> {
+    let detected = focus::detect_frontmost_app()
+        .ok()
+        .filter(|target| !target.is_current_process());
+    let mut focus_target = insertion_state
+        .focus_target
+        .lock()
+        .map_err(|_| SonoraError::state_unavailable("focus target"))?;
+    *focus_target = detected.clone();
+    Ok(detected)
+}
+
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase2_get_insertion_target(
+    app: tauri::AppHandle,
+    insertion_state: tauri::State<'_, InsertionState>,
+) -> Result<Option<FocusTarget>, SonoraError> {
+    instrument_command(&app, "phase2_get_insertion_target", || {
+        let focus_target = insertion_state
+            .focus_target
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("focus target"))?;
+        Ok(focus_target.clone())
+    })
+}
+
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase2_set_insertion_target(
+    app: tauri::AppHandle,
+    insertion_state: tauri::State<'_, InsertionState>,
+    target: Option<FocusTarget>,
+) -> Result<Option<FocusTarget>, SonoraError> {
+    instrument_command(&app, "phase2_set_insertion_target", || {
+        let mut focus_target = insertion_state
+            .focus_target
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("focus target"))?;
+        *focus_target = target;
+        Ok(focus_target.clone())
+    })
+}
+
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase2_copy_transcript_to_clipboard(
+    app: tauri::AppHandle,
+    insertion_state: tauri::State<'_, InsertionState>,
+    record_id: u64,
+) -> Result<InsertionRecord, SonoraError> {
+    instrument_command(&app, "phase2_copy_transcript_to_clipboard", || {
+        let record = {
+            let records = insertion_state
+                .records
+                .lock()
+                .map_err(|_| SonoraError::state_unavailable("insertion"))?;
+            insertion::find_record(&records, record_id)
+                .cloned()
+                .ok_or_else(|| {
+                    SonoraError::NotFound(format!("insertion record {record_id} not found"))
+                })?
+        };
+        clipboard::copy_text(&record.text).map_err(SonoraError::Insertion)?;
+        Ok(record)
+    })
+}
+
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase2_copy_last_transcript(
+    app: tauri::AppHandle,
+    insertion_state: tauri::State<'_, InsertionState>,
+) -> Result<InsertionRecord, SonoraError> {
+    instrument_command(&app, "phase2_copy_last_transcript", || {
+        let record = insertion_state
+            .records
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("insertion"))?
+            .first()
+            .cloned()
+            .ok_or_else(|| SonoraError::NotFound("no transcripts in history".to_string()))?;
+        clipboard::copy_text(&record.text).map_err(SonoraError::Insertion)?;
+        Ok(record)
+    })
+}
+
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase2_get_clipboard_managers(
+    app: tauri::AppHandle,
+) -> Result<Vec<clipboard::ClipboardManager>, SonoraError> {
+    instrument_command(&app, "phase2_get_clipboard_managers", || {
+        Ok(clipboard::detect_managers())
+    })
+}
+
+// Checks tools, sessions and permissions only; nothing is typed or copied.
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase2_probe_insertion_capabilities(
+    app: tauri::AppHandle,
+) -> Result<insertion_probe::InsertionCapabilities, SonoraError> {
+    instrument_command(&app, "phase2_probe_insertion_capabilities", || {
+        Ok(insertion_probe::probe())
+    })
+}
+
+// Once wired, adapters choose scancodes or unicode with
+// `keyboard_layout::injection_method` on the layout `FocusWatchState` caches,
+// instead of probing the layout on every insertion.
+#[cfg(feature = "desktop")]
+fn try_direct_insertion(_text: &str) -> Result<(), String> {
+    Err("direct insertion adapters are not wired yet".to_string())
+}
+
+#[cfg(feature = "desktop")]
+fn try_clipboard_fallback(text: &str) -> Result<(), String> {
+    clipboard::paste_transient(text)
+}
+
+#[cfg(feature = "desktop")]
+impl EventSink for tauri::AppHandle {
+    fn event_prefix(&self) -> Option<String> {
+        self.try_state::<SettingsState>()
+            .and_then(|state| state.settings.lock().ok()?.event_prefix.clone())
+    }
+
+    fn emit_value(&self, name: &str, payload: serde_json::Value) -> Result<(), String> {
+        self.emit(name, payload).map_err(|error| error.to_string())
+    }
+
+    fn transcript_published(&self, text: &str) {
+        publish_transcript_to_dbus(self, text);
+    }
+}
+
+// Settings and shared state the transcript emit path reads. The worker takes
+// it from the app for every chunk so settings edits apply mid-session; the
+// path itself then runs against any `EventSink`.
+#[cfg(feature = "desktop")]
+struct EmitContext {
+    logs_path: PathBuf,
+    last_transcript: Arc<StateMutex<Option<String>>>,
+    duplicate_policy: DuplicateSuppression,
+    vocabulary_terms: Vec<String>,
+    captions_enabled: bool,
+    caption_line_chars: usize,
+    instant_words: bool,
+    viewer: Option<viewer::ViewerHub>,
+    repeat_guard: Arc<StateMutex<RepeatGuard>>,
+}
+
+#[cfg(feature = "desktop")]
+impl EmitContext {
+    fn from_app(app: &tauri::AppHandle) -> Self {
+        let (duplicate_policy, captions_enabled, caption_line_chars, instant_words) = app
+            .state::<SettingsState>()
+            .settings
+            .lock()
+            .map(|settings| {
+                (
+                    settings.duplicate_suppression,
+                    settings.captions_enabled,
+                    usize::from(settings.caption_line_chars),
+                    settings.instant_words,
+                )
+            })
+            .unwrap_or_default();
+        let store = app.state::<PipelineStore>();
+        Self {
+            logs_path: app.state::<RuntimeLogState>().path.clone(),
+            last_transcript: Arc::clone(&store.last_transcript),
+            duplicate_policy,
+            vocabulary_terms: vocabulary_terms(app),
+            captions_enabled,
+            caption_line_chars,
+            instant_words,
+            viewer: app.state::<TranscriptViewerState>().hub(),
+            repeat_guard: Arc::clone(&store.repeat_guard),
+        }
+    }
+}
+
+#[cfg(feature = "desktop")]
+fn emit_event<T: Serialize>(sink: &dyn EventSink, name: &str, payload: T) -> Result<(), String> {
+    events::emit(sink, name, payload)
+}
+
+#[cfg(feature = "desktop")]
+fn emit_live_mic_state(sink: &dyn EventSink, active: bool) {
+    let _ = emit_event(sink, events::DICTATION_LIVE_MIC, LiveMicPayload { active });
+}
+
+#[cfg(feature = "desktop")]
+fn emit_mic_monitor_state(sink: &dyn EventSink, active: bool) {
+    let _ = emit_event(
+        sink,
+        events::DICTATION_MIC_MONITOR,
+        LiveMicPayload { active },
+    );
+}
+
+#[cfg(feature = "desktop")]
+fn handle_second_instance(app: &tauri::AppHandle, argv: Vec<String>) {
+    let command = activation::relaunch_command(&argv, &app.state::<ActivationState>().review_token);
+    let logs = app.state::<RuntimeLogState>();
+    let _ = log_store::append(
+        &logs.path,
+        "info",
+        "app.second_instance",
+        &format!("forwarded relaunch to running instance as {command:?}"),
+    );
+    dispatch_external_command(app, command);
+}
+
+// Commands keep working after a worker panics while holding shared state;
+// the recovery is logged and surfaced so the UI can flag degraded mode.
+#[cfg(feature = "desktop")]
+fn install_poison_reporter(app: tauri::AppHandle) {
+    state_recovery::set_poison_reporter(move |incident| {
+        let logs = app.state::<RuntimeLogState>();
+        let _ = log_store::append(
+            &logs.path,
+            "error",
+            "state.poisoned",
+            &format!(
+                "recovered {} after a panic (reset: {}, incident {})",
+                incident.state, incident.reset, incident.incidents
+            ),
+        );
+        let _ = emit_event(&app, events::APP_DEGRADED, incident);
+    });
+}
+
+#[cfg(feature = "desktop")]
+fn register_external_commands(app: &tauri::AppHandle) {
+    #[cfg(any(target_os = "linux", all(debug_assertions, target_os = "windows")))]
+    if let Err(error) = app.deep_link().register_all() {
+        let logs = app.state::<RuntimeLogState>();
+        let _ = log_store::append(&logs.path, "warn", "deep_link.register", &error.to_string());
+    }
+
+    let handle = app.clone();
+    app.deep_link().on_open_url(move |event| {
+        let review_token = &handle.state::<ActivationState>().review_token;
+        for url in event.urls() {
+            if let Some(command) = activation::parse_deep_link(url.as_str(), review_token) {
+                dispatch_external_command(&handle, command);
+            } else {
+                let logs = handle.state::<RuntimeLogState>();
+                let _ = log_store::append(
+                    &logs.path,
+                    "warn",
+                    "deep_link.rejected",
+                    &format!(
+                        "ignored deep link {}://{}",
+                        url.scheme(),
+                        url.host_str().unwrap_or_default()
+                    ),
+                );
+            }
+        }
+    });
+
+    let argv = std::env::args().collect::<Vec<_>>();
+    let review_token = &app.state::<ActivationState>().review_token;
+    if let Some(command) = activation::parse_cli_args(&argv, review_token) {
+        dispatch_external_command(app, command);
+    }
+}
+
+#[cfg(feature = "desktop")]
+fn dispatch_external_command(app: &tauri::AppHandle, command: activation::ExternalCommand) {
+    match command {
+        activation::ExternalCommand::Activate(action) => {
+            if action == activation::ActivationAction::Show {
+                if let Some(window) = app.get_webview_window("main") {
+                    let _ = window.unminimize();
+                    let _ = window.show();
+                    let _ = window.set_focus();
+                }
+            }
+            let activation_state = app.state::<ActivationState>();
+            if activation_state.frontend_ready.load(Ordering::Relaxed) {
+                let _ = emit_event(
+                    app,
+                    events::APP_ACTIVATE,
+                    activation::ActivationPayload { action },
+                );
+            } else if let Ok(mut pending) = activation_state.pending.lock() {
+                *pending = Some(action);
+            }
+        }
+        activation::ExternalCommand::Insert(text) => {
+            let app = app.clone();
+            thread::spawn(move || {
+                let language = app
+                    .state::<PipelineStore>()
+                    .active_language
+                    .lock()
+                    .ok()
+                    .map(|language| language.clone());
+                if let Err(error) = perform_insertion(
+                    &app,
+                    text,
+                    InsertionStrategy::default(),
+                    None,
+                    language,
+                    None,
+                ) {
+                    let logs = app.state::<RuntimeLogState>();
+                    let _ = log_store::append(
+                        &logs.path,
+                        "error",
+                        "external.insert",
+                        &error.to_string(),
+                    );
+                }
+            });
+        }
+        activation::ExternalCommand::Review { item_id, action } => {
+            let app = app.clone();
+            thread::spawn(move || {
+                if let Err(error) = resolve_review_action(&app, item_id, action) {
+                    let logs = app.state::<RuntimeLogState>();
+                    let _ = log_store::append(
+                        &logs.path,
+                        "error",
+                        "external.review",
+                        &error.to_string(),
+                    );
+                }
+            });
+        }
+    }
+}
+
+// Notification buttons act on the queue directly so the window never needs
+// focus; the frontend only learns the item is gone.
+#[cfg(feature = "desktop")]
+fn resolve_review_action(
+    app: &tauri::AppHandle,
+    item_id: u64,
+    action: activation::ReviewAction,
+) -> Result<(), SonoraError> {
+    let item = take_review_item(&app.state::<ReviewQueueState>(), item_id)?;
+    match action {
+        activation::ReviewAction::Insert => {
+            perform_insertion(
+                app,
+                item.text,
+                InsertionStrategy::default(),
+                None,
+                item.language,
+                item.utterance_id,
+            )?;
+        }
+        activation::ReviewAction::Copy => {
+            clipboard::copy_text(&item.text).map_err(SonoraError::Insertion)?;
+        }
+        activation::ReviewAction::Discard => {}
+    }
+    let _ = emit_event(
+        app,
+        events::DICTATION_REVIEW_RESOLVED,
+        activation::ReviewResolvedPayload { item_id, action },
+    );
+    Ok(())
+}
+
+#[cfg(feature = "desktop")]
+fn select_fresh_transcript(
+    last_transcript: &mut Option<String>,
+    repeats: &mut RepeatGuard,
+    duplicate_policy: DuplicateSuppression,
+    raw_transcript: Option<String>,
+    normalization: NormalizationProfile,
+    stage: TranscriptStage,
+    terms: &[String],
+) -> Option<String> {
+    let normalized = raw_transcript
+        .map(|value| normalize_transcript_for_stage(&value, normalization, stage, terms));
+    normalized.and_then(|value| {
+        if value.is_empty()
+            || repeats.suppress(
+                last_transcript.as_deref(),
+                &value,
+                current_unix_ms_u64(),
+                duplicate_policy,
+            )
+        {
+            None
+        } else {
+            *last_transcript = Some(value.clone());
+            Some(value)
+        }
+    })
+}
+
+#[cfg(feature = "desktop")]
+fn upsert_pending_utterance(
+    pending: &mut Option<PendingUtterance>,
     raw_transcript: Option<String>,
     observed_unix_ms: u64,
     next_session_id: &mut u64,
@@ -738,7 +4439,14 @@ fn upsert_pending_utterance(
             *pending = Some(PendingUtterance {
                 session_id: *next_session_id,
                 text: collapsed,
+                started_unix_ms: observed_unix_ms,
                 last_speech_unix_ms: observed_unix_ms,
+                confidence: None,
+                typed: None,
+                speech_end_unix_ms: None,
+                audio: Vec::new(),
+                speech_samples: 0,
+                cold_start: false,
             });
             true
         }
@@ -760,17 +4468,469 @@ fn should_flush_pending_utterance(
 }
 
 #[cfg(feature = "desktop")]
-fn take_pending_utterance(pending: &mut Option<PendingUtterance>) -> Option<String> {
-    pending
-        .take()
-        .map(|utterance| normalize_transcript(&utterance.text))
-        .filter(|value| !value.is_empty())
+fn take_pending_utterance(
+    pending: &mut Option<PendingUtterance>,
+    normalization: NormalizationProfile,
+    terms: &[String],
+) -> Option<String> {
+    pending
+        .take()
+        .map(|utterance| normalize_transcript_with_terms(&utterance.text, normalization, terms))
+        .filter(|value| !value.is_empty())
+}
+
+#[cfg(feature = "desktop")]
+fn finalize_pending_utterance(
+    app: &tauri::AppHandle,
+    pending: &mut Option<PendingUtterance>,
+    session_stats: &Arc<StateMutex<SessionStats>>,
+    meeting_transcript: &Arc<StateMutex<Option<MeetingTranscript>>>,
+    active_language: &Arc<StateMutex<String>>,
+    logs_path: &Path,
+    router: Option<&UtteranceRouter>,
+) -> Option<String> {
+    let (session_id, started_unix_ms, ended_unix_ms, mut confidence, typed, speech_end_unix_ms) =
+        pending.as_ref().map(|utterance| {
+            (
+                utterance.session_id,
+                utterance.started_unix_ms,
+                utterance.last_speech_unix_ms,
+                utterance.confidence,
+                utterance.typed.clone(),
+                utterance.speech_end_unix_ms,
+            )
+        })?;
+    let latency = speech_end_unix_ms
+        .map(|speech_end| UtteranceLatency::new(speech_end, ended_unix_ms, current_unix_ms_u64()));
+    let (audio, speech_samples, cold_start) = pending
+        .as_mut()
+        .map(|utterance| {
+            (
+                std::mem::take(&mut utterance.audio),
+                utterance.speech_samples,
+                utterance.cold_start,
+            )
+        })
+        .unwrap_or_default();
+    let speech_ratio_percent = (speech_samples.min(audio.len()) * 100)
+        .checked_div(audio.len())
+        .unwrap_or(100) as u8;
+    let audio_ms = utterance_routing::audio_duration_ms(audio.len());
+    let mut engine = live_utterance_engine(app, audio_ms, cold_start);
+    // Instant words already typed the live text, so only untyped utterances reroute.
+    if let Some(router) = router.filter(|router| typed.is_none() && router.engine.routes(audio_ms))
+    {
+        match router.engine.transcribe(&audio) {
+            Ok(Some((text, long_confidence))) => {
+                let (session_terms, learned_rules) =
+                    live_corrections(app, &router.session_vocabulary);
+                if let Some(utterance) = pending.as_mut() {
+                    utterance.text = postprocess_live_text(
+                        app,
+                        &text,
+                        &session_terms,
+                        &learned_rules,
+                        active_language,
+                    );
+                }
+                confidence = long_confidence.or(confidence);
+                engine = Some(router.engine.engine(audio_ms));
+            }
+            Ok(None) => {}
+            Err(error) => {
+                let _ = log_store::append(logs_path, "warn", "utterance.routing", &error);
+            }
+        }
+    }
+    let language = active_language.lock().ok().map(|language| language.clone());
+    let normalization = language_pack::normalization_for(language.as_deref());
+    let terms = vocabulary_terms(app);
+    let text = take_pending_utterance(pending, normalization, &terms);
+    if let Some(typed) = typed.as_deref() {
+        let final_text = text.as_deref().unwrap_or_default();
+        if let Some(edit) = instant_words::next_edit(session_id, typed, final_text, true) {
+            let _ = emit_event(app, events::DICTATION_INSTANT_EDIT, edit);
+        }
+    }
+    let text = text?;
+    if typed.is_none() && is_hallucinated_final(app, &text, speech_ratio_percent) {
+        let _ = log_store::append(
+            logs_path,
+            "info",
+            "transcript.hallucination",
+            &format!("suppressed repeated final at {speech_ratio_percent}% speech: {text}"),
+        );
+        if let Ok(mut stats) = session_stats.lock() {
+            stats.hallucinations_suppressed = stats.hallucinations_suppressed.saturating_add(1);
+        }
+        // Settles the live partial without replacing the last real transcript.
+        let _ = emit_event(
+            app,
+            events::DICTATION_TRANSCRIPT,
+            TranscriptPayload {
+                text: String::new(),
+                chunk_id: None,
+                emitted_unix_ms: Some(current_unix_ms_u64()),
+                session_id: Some(session_id),
+                language,
+                diff: None,
+                is_final: true,
+                engine: None,
+            },
+        );
+        return None;
+    }
+    emit_final_transcript(
+        app,
+        &EmitContext::from_app(app),
+        session_id,
+        &text,
+        language.clone(),
+        engine,
+    );
+    if let Ok(mut stats) = session_stats.lock() {
+        stats.record_utterance(&text);
+    }
+    if let Ok(mut meeting) = meeting_transcript.lock() {
+        if let Some(meeting) = meeting.as_mut() {
+            if let Err(error) = meeting.append_utterance(&text, started_unix_ms, ended_unix_ms) {
+                let _ = log_store::append(logs_path, "error", "meeting.write", &error);
+            }
+        }
+    }
+    append_journal_entry(app, logs_path, &text, ended_unix_ms);
+    write_obs_caption(app, logs_path, &text);
+    let utterance_id = record_utterance(
+        app,
+        logs_path,
+        utterance_history::UtteranceEntry {
+            id: 0,
+            text: text.clone(),
+            started_unix_ms,
+            ended_unix_ms,
+            language: language.clone(),
+            confidence,
+            session_label: None,
+            outcome: if typed.is_some() {
+                utterance_history::UtteranceOutcome::Typed
+            } else {
+                utterance_history::UtteranceOutcome::Transcribed
+            },
+            insertion_ids: Vec::new(),
+            insertion_status: None,
+            audio_path: None,
+            engine,
+        },
+        if utterance_audio_enabled(app) {
+            &audio
+        } else {
+            &[]
+        },
+    );
+    // Instant words already typed this utterance; the final edit above settled it.
+    if typed.is_none() {
+        auto_insert_utterance(
+            app,
+            logs_path,
+            &text,
+            confidence,
+            language,
+            latency,
+            utterance_id,
+        );
+    } else {
+        record_utterance_latency(app, latency);
+    }
+    Some(text)
+}
+
+// A recording's utterances go to its transcript file and nowhere else.
+#[cfg(feature = "desktop")]
+fn finalize_recording_utterance(
+    app: &tauri::AppHandle,
+    pending: &mut Option<PendingUtterance>,
+    meeting_transcript: &Arc<StateMutex<Option<MeetingTranscript>>>,
+    active_language: &Arc<StateMutex<String>>,
+    logs_path: &Path,
+) -> Option<String> {
+    let (started_unix_ms, ended_unix_ms) = pending
+        .as_ref()
+        .map(|utterance| (utterance.started_unix_ms, utterance.last_speech_unix_ms))?;
+    let language = active_language.lock().ok().map(|language| language.clone());
+    let normalization = language_pack::normalization_for(language.as_deref());
+    let text = take_pending_utterance(pending, normalization, &vocabulary_terms(app))?;
+    if let Ok(mut meeting) = meeting_transcript.lock() {
+        if let Some(meeting) = meeting.as_mut() {
+            if let Err(error) = meeting.append_utterance(&text, started_unix_ms, ended_unix_ms) {
+                let _ = log_store::append(logs_path, "error", "meeting.write", &error);
+            }
+        }
+    }
+    Some(text)
+}
+
+#[cfg(feature = "desktop")]
+fn utterance_audio_enabled(app: &tauri::AppHandle) -> bool {
+    app.state::<SettingsState>()
+        .settings
+        .lock()
+        .map(|settings| settings.utterance_audio)
+        .unwrap_or(false)
+}
+
+// Assigns the utterance its stable id; the audio clip is written beside the history.
+#[cfg(feature = "desktop")]
+fn record_utterance(
+    app: &tauri::AppHandle,
+    logs_path: &Path,
+    mut entry: utterance_history::UtteranceEntry,
+    audio: &[f32],
+) -> Option<u64> {
+    let state = app.state::<UtteranceState>();
+    let mut entries = state.entries.lock().ok()?;
+    entry.id = utterance_history::next_utterance_id(&entries);
+    entry.session_label = app
+        .state::<InsertionState>()
+        .session_label
+        .lock()
+        .ok()
+        .and_then(|label| label.clone());
+    if !audio.is_empty() {
+        let path = utterance_history::audio_path_for(&state.path, entry.id);
+        match utterance_history::write_audio(&path, audio) {
+            Ok(()) => entry.audio_path = Some(path.to_string_lossy().to_string()),
+            Err(error) => {
+                let _ = log_store::append(logs_path, "warn", "utterance.audio", &error);
+            }
+        }
+    }
+    let id = entry.id;
+    let evicted = utterance_history::append_entry(&mut entries, entry);
+    utterance_history::remove_audio(&evicted);
+    if let Err(error) = utterance_history::save(&state.path, &entries) {
+        let _ = log_store::append(logs_path, "warn", "utterance.history", &error);
+    }
+    Some(id)
+}
+
+#[cfg(feature = "desktop")]
+fn update_utterance(
+    app: &tauri::AppHandle,
+    update: impl FnOnce(&mut Vec<utterance_history::UtteranceEntry>) -> bool,
+) {
+    let state = app.state::<UtteranceState>();
+    let Ok(mut entries) = state.entries.lock() else {
+        return;
+    };
+    if update(&mut entries) {
+        if let Err(error) = utterance_history::save(&state.path, &entries) {
+            let logs = app.state::<RuntimeLogState>();
+            let _ = log_store::append(&logs.path, "warn", "utterance.history", &error);
+        }
+    }
+}
+
+#[cfg(feature = "desktop")]
+fn append_journal_entry(app: &tauri::AppHandle, logs_path: &Path, text: &str, unix_ms: u64) {
+    let directory = match app.state::<SettingsState>().settings.lock() {
+        Ok(settings) => settings
+            .journal_directory
+            .as_deref()
+            .filter(|_| journal::accepts_profile(settings.journal_profile, settings.model_profile))
+            .map(journal::resolve_directory),
+        Err(_) => None,
+    };
+    let Some(directory) = directory else {
+        return;
+    };
+    let session_label = app
+        .state::<InsertionState>()
+        .session_label
+        .lock()
+        .ok()
+        .and_then(|label| label.clone());
+    if let Err(error) = journal::append_entry(&directory, text, unix_ms, session_label.as_deref()) {
+        let _ = log_store::append(logs_path, "error", "journal.write", &error);
+    }
+}
+
+#[cfg(feature = "desktop")]
+fn write_obs_caption(app: &tauri::AppHandle, logs_path: &Path, text: &str) {
+    let (path, line_chars) = match app.state::<SettingsState>().settings.lock() {
+        Ok(settings) => match settings.obs_caption_file.as_deref() {
+            Some(path) => (
+                PathBuf::from(path),
+                usize::from(settings.caption_line_chars),
+            ),
+            None => return,
+        },
+        Err(_) => return,
+    };
+    let lines = captions::caption_frame(0, text, line_chars, None).lines;
+    if let Err(error) = captions::write_caption_file(&path, &lines) {
+        let _ = log_store::append(logs_path, "warn", "captions.obs", &error);
+    }
+}
+
+#[cfg(feature = "desktop")]
+fn clear_obs_caption(app: &tauri::AppHandle) {
+    let path = match app.state::<SettingsState>().settings.lock() {
+        Ok(settings) => settings.obs_caption_file.clone(),
+        Err(_) => None,
+    };
+    if let Some(path) = path {
+        let _ = captions::write_caption_file(Path::new(&path), &[]);
+    }
+}
+
+#[cfg(feature = "desktop")]
+fn show_review_toast(app: &tauri::AppHandle, logs_path: &Path, item: &review_queue::ReviewItem) {
+    let enabled = match app.state::<SettingsState>().settings.lock() {
+        Ok(settings) => settings.review_toasts,
+        Err(_) => false,
+    };
+    if !enabled || !cfg!(target_os = "windows") {
+        return;
+    }
+    let item = item.clone();
+    let logs_path = logs_path.to_path_buf();
+    let app_id = app.config().identifier.clone();
+    let token = app.state::<ActivationState>().review_token.clone();
+    thread::spawn(move || {
+        if let Err(error) = review_toast::show(&item, &app_id, &token) {
+            let _ = log_store::append(&logs_path, "warn", "insertion.review_toast", &error);
+        }
+    });
+}
+
+#[cfg(feature = "desktop")]
+fn auto_insert_utterance(
+    app: &tauri::AppHandle,
+    logs_path: &Path,
+    text: &str,
+    confidence: Option<f32>,
+    language: Option<String>,
+    latency: Option<UtteranceLatency>,
+    utterance_id: Option<u64>,
+) {
+    let (enabled, min_confidence_percent) = match app.state::<SettingsState>().settings.lock() {
+        Ok(settings) => (
+            settings.auto_insert,
+            settings.auto_insert_min_confidence_percent,
+        ),
+        Err(_) => return,
+    };
+    if !enabled {
+        record_utterance_latency(app, latency);
+        return;
+    }
+
+    if review_queue::should_hold(confidence, min_confidence_percent) {
+        let held = {
+            let review = app.state::<ReviewQueueState>();
+            let Ok(mut items) = review.items.lock() else {
+                return;
+            };
+            review_queue::hold(
+                &mut items,
+                review_queue::ReviewItem {
+                    id: 0,
+                    text: text.to_string(),
+                    confidence,
+                    language,
+                    utterance_id,
+                    held_unix_ms: current_unix_ms_u64(),
+                },
+            )
+        };
+        let _ = log_store::append(
+            logs_path,
+            "info",
+            "insertion.held",
+            &format!(
+                "held transcript {} for review at confidence {:.2}",
+                held.id,
+                confidence.unwrap_or_default()
+            ),
+        );
+        announce_to_screen_reader(app, announce::held_for_review(&held.text));
+        show_review_toast(app, logs_path, &held);
+        let _ = emit_event(app, events::DICTATION_REVIEW_HELD, held);
+        if let Some(id) = utterance_id {
+            update_utterance(app, |entries| {
+                utterance_history::set_outcome(
+                    entries,
+                    id,
+                    utterance_history::UtteranceOutcome::HeldForReview,
+                )
+            });
+        }
+        record_utterance_latency(app, latency);
+        return;
+    }
+
+    if let Some(id) = utterance_id {
+        update_utterance(app, |entries| {
+            utterance_history::set_outcome(
+                entries,
+                id,
+                utterance_history::UtteranceOutcome::Inserting,
+            )
+        });
+    }
+
+    // Insertion can block on the focus guard, which must not stall the capture worker.
+    let app = app.clone();
+    let text = text.to_string();
+    thread::spawn(move || {
+        match perform_insertion(
+            &app,
+            text,
+            InsertionStrategy::default(),
+            None,
+            language,
+            utterance_id,
+        ) {
+            Ok(record) if record.status != insertion::InsertionStatus::Failure => {
+                let inserted_unix_ms = current_unix_ms_u64();
+                record_utterance_latency(
+                    &app,
+                    latency.map(|latency| latency.inserted_at(inserted_unix_ms)),
+                );
+            }
+            Ok(_) => record_utterance_latency(&app, latency),
+            Err(error) => {
+                let logs = app.state::<RuntimeLogState>();
+                let _ =
+                    log_store::append(&logs.path, "error", "insertion.auto", &error.to_string());
+                record_utterance_latency(&app, latency);
+            }
+        }
+    });
+}
+
+#[cfg(feature = "desktop")]
+fn record_utterance_latency(app: &tauri::AppHandle, latency: Option<UtteranceLatency>) {
+    let Some(latency) = latency else {
+        return;
+    };
+    if let Ok(mut stats) = app.state::<PipelineStore>().session_stats.lock() {
+        stats.latency.record(latency);
+    }
+    let logs = app.state::<RuntimeLogState>();
+    append_perf_event(
+        &logs.path,
+        logs.perf_enabled,
+        "perf.utterance_latency",
+        &latency,
+    );
 }
 
 #[cfg(feature = "desktop")]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 struct LiveCaptureChunkPlan {
     next_chunk_size: usize,
+    min_chunk_samples: usize,
     max_chunk_samples: usize,
 }
 
@@ -779,17 +4939,19 @@ fn plan_live_capture_chunk(
     status: &PipelineStatus,
     pending_samples: usize,
     elapsed_since_last_feed: Duration,
+    adjustment: speech_rate::CadenceAdjustment,
 ) -> Option<LiveCaptureChunkPlan> {
     if status.state != pipeline::DictationState::Listening {
         return None;
     }
 
-    let min_chunk_samples = status.tuning.min_chunk_samples.max(8_000);
+    let tuning = adjustment.apply(&status.tuning);
+    let min_chunk_samples = tuning.min_chunk_samples.max(8_000);
     if pending_samples < min_chunk_samples {
         return None;
     }
 
-    let cadence = Duration::from_millis(status.tuning.partial_cadence_ms.max(300));
+    let cadence = Duration::from_millis(tuning.partial_cadence_ms.max(300));
     if elapsed_since_last_feed < cadence {
         return None;
     }
@@ -797,10 +4959,28 @@ fn plan_live_capture_chunk(
     let max_chunk_samples = min_chunk_samples.saturating_mul(3);
     Some(LiveCaptureChunkPlan {
         next_chunk_size: pending_samples.min(max_chunk_samples),
+        min_chunk_samples,
         max_chunk_samples,
     })
 }
 
+#[cfg(feature = "desktop")]
+fn align_chunk_plan_to_phrase_boundary(
+    plan: LiveCaptureChunkPlan,
+    speech_segments: &[vad::SpeechSegment],
+) -> LiveCaptureChunkPlan {
+    let boundary = vad::last_silence_boundary(
+        speech_segments,
+        plan.min_chunk_samples,
+        plan.next_chunk_size,
+    );
+
+    LiveCaptureChunkPlan {
+        next_chunk_size: boundary.unwrap_or(plan.next_chunk_size),
+        ..plan
+    }
+}
+
 #[cfg(feature = "desktop")]
 fn trim_pending_backlog(pending_samples: &mut VecDeque<f32>, max_chunk_samples: usize) {
     while pending_samples.len() > max_chunk_samples.saturating_mul(5) {
@@ -808,6 +4988,36 @@ fn trim_pending_backlog(pending_samples: &mut VecDeque<f32>, max_chunk_samples:
     }
 }
 
+#[cfg(feature = "desktop")]
+fn meter_ballistics_for_app(app: &tauri::AppHandle) -> audio::MeterBallistics {
+    let settings = app
+        .state::<SettingsState>()
+        .settings
+        .lock()
+        .map(|settings| {
+            (
+                settings.meter_attack_ms,
+                settings.meter_release_ms,
+                settings.meter_peak_hold_ms,
+            )
+        })
+        .unwrap_or((0, 60, 0));
+    audio::MeterBallistics::from_settings(settings.0, settings.1, settings.2)
+}
+
+// Resolved at every capture start so a newly selected or newly default device
+// picks up its own profile.
+#[cfg(feature = "desktop")]
+fn mic_settings_for(
+    settings: &AppSettings,
+    microphone_id: Option<&str>,
+) -> mic_profile::MicSettings {
+    mic_profile::resolve(
+        settings,
+        audio::input_device_label(microphone_id).as_deref(),
+    )
+}
+
 #[cfg(feature = "desktop")]
 fn mic_sensitivity_gain(mic_sensitivity_percent: u16) -> f32 {
     (mic_sensitivity_percent.clamp(50, 300) as f32 / 100.0).clamp(0.5, 3.0)
@@ -824,113 +5034,795 @@ fn apply_mic_gain(samples: &mut [f32], gain: f32) {
     }
 }
 
+// How often the test-clip playback checks whether its buffer has drained.
 #[cfg(feature = "desktop")]
-const FRAME_RECV_TIMEOUT_MS: u64 = 60;
+const CLIP_DRAIN_POLL_MS: u64 = 60;
 
 #[cfg(feature = "desktop")]
-const METER_EMIT_INTERVAL_MS: u64 = 33;
+const TRANSCRIPT_SESSION_GAP_MS: u64 = 2_000;
 
 #[cfg(feature = "desktop")]
-const TRANSCRIPT_SESSION_GAP_MS: u64 = 2_000;
+const FOCUS_GUARD_POLL_MS: u64 = 100;
+
+#[cfg(feature = "desktop")]
+fn live_session_timing(app: &tauri::AppHandle) -> session_timing::LiveSessionTiming {
+    app.state::<SettingsState>()
+        .settings
+        .lock()
+        .map(|settings| session_timing::LiveSessionTiming::from_settings(&settings))
+        .unwrap_or_default()
+}
+
+#[cfg(feature = "desktop")]
+fn duration_millis_u64(duration: Duration) -> u64 {
+    duration.as_millis().min(u128::from(u64::MAX)) as u64
+}
+
+#[cfg(feature = "desktop")]
+fn current_unix_ms_u64() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|value| value.as_millis().min(u128::from(u64::MAX)) as u64)
+        .unwrap_or(0)
+}
+
+#[cfg(feature = "desktop")]
+fn is_perf_enabled_from_env() -> bool {
+    std::env::var("SONORA_PERF")
+        .map(|value| {
+            matches!(
+                value.trim().to_ascii_lowercase().as_str(),
+                "1" | "true" | "on" | "yes"
+            )
+        })
+        .unwrap_or(false)
+}
+
+#[cfg(feature = "desktop")]
+fn request_profile_downgrade(app: &tauri::AppHandle, slow: profile::SlowInference) -> bool {
+    let (enabled, from) = match app.state::<SettingsState>().settings.lock() {
+        Ok(settings) => (settings.auto_downgrade_profile, settings.model_profile),
+        Err(_) => return false,
+    };
+    let Some(to) = profile::downgraded_profile(from).filter(|_| enabled) else {
+        return false;
+    };
+
+    // Rebuilding the engine can take seconds; keep the capture worker draining audio meanwhile.
+    let app = app.clone();
+    thread::spawn(move || {
+        let logs = app.state::<RuntimeLogState>();
+        let result = switch_model_profile(&app, from, to);
+        let error = result.err().map(|error| error.to_string());
+        let _ = log_store::append(
+            &logs.path,
+            "warn",
+            "profile.downgrade",
+            &format!(
+                "inference averaged {} ms for {} ms chunks over {} chunks; {from:?} -> {to:?}{}",
+                slow.avg_inference_ms,
+                slow.avg_audio_ms,
+                slow.chunks,
+                error
+                    .as_deref()
+                    .map(|error| format!(" failed: {error}"))
+                    .unwrap_or_default()
+            ),
+        );
+        let _ = emit_event(
+            &app,
+            events::DICTATION_PROFILE_DOWNGRADE,
+            ProfileDowngradePayload {
+                from,
+                to,
+                slow,
+                error,
+            },
+        );
+    });
+    true
+}
+
+#[cfg(feature = "desktop")]
+fn switch_model_profile(
+    app: &tauri::AppHandle,
+    from: ModelProfile,
+    to: ModelProfile,
+) -> Result<(), SonoraError> {
+    let settings_state = app.state::<SettingsState>();
+    let updated = {
+        let mut settings = settings_state
+            .settings
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("settings"))?;
+        if settings.model_profile != from {
+            return Ok(());
+        }
+        let updated = AppSettings {
+            model_profile: to,
+            ..settings.clone()
+        };
+        settings_store::save(&settings_state.settings_path, &updated).map_err(SonoraError::io)?;
+        *settings = updated.clone();
+        updated
+    };
+    apply_runtime_transcriber_from_settings(app, &updated, &app.state::<PipelineStore>())?;
+    Ok(())
+}
+
+#[cfg(feature = "desktop")]
+fn performance_trace_path(app: &tauri::AppHandle) -> Option<PathBuf> {
+    let enabled = app
+        .state::<SettingsState>()
+        .settings
+        .lock()
+        .map(|settings| settings.performance_trace)
+        .unwrap_or(false);
+    enabled.then(|| app.state::<RuntimeLogState>().trace_path.clone())
+}
+
+#[cfg(feature = "desktop")]
+fn append_perf_event<T: Serialize>(path: &Path, perf_enabled: bool, event: &str, payload: &T) {
+    if !perf_enabled {
+        return;
+    }
+
+    if let Ok(message) = serde_json::to_string(payload) {
+        let _ = log_store::append(path, "info", event, &message);
+    }
+}
+
+#[cfg(all(feature = "desktop", target_os = "linux"))]
+fn start_dbus_service(app: &tauri::AppHandle) {
+    let toggle_handle = app.clone();
+    let state_handle = app.clone();
+    let service = dbus_service::DbusService::start(
+        move || {
+            dispatch_external_command(
+                &toggle_handle,
+                activation::ExternalCommand::Activate(
+                    activation::ActivationAction::ToggleDictation,
+                ),
+            );
+        },
+        move || {
+            state_handle
+                .state::<PipelineStore>()
+                .pipeline
+                .status()
+                .ok()
+                .and_then(|status| serde_json::to_value(status.state).ok())
+                .and_then(|value| value.as_str().map(str::to_string))
+                .unwrap_or_else(|| "unknown".to_string())
+        },
+    );
+
+    let logs = app.state::<RuntimeLogState>();
+    match service {
+        Ok(service) => {
+            app.manage(DbusState { service });
+            let _ = log_store::append(
+                &logs.path,
+                "info",
+                "dbus.start",
+                &format!("registered {}", dbus_service::BUS_NAME),
+            );
+        }
+        Err(error) => {
+            let _ = log_store::append(&logs.path, "warn", "dbus.start", &error);
+        }
+    }
+}
+
+// Starts, stops or moves the viewer server to match the settings. Viewers
+// on a closed server reconnect on their own once it is back on the new port.
+// Offline-only keeps it stopped, since it listens on every interface.
+#[cfg(feature = "desktop")]
+fn sync_transcript_viewer(app: &tauri::AppHandle, settings: &AppSettings) {
+    let offline_only = settings.offline_only;
+    let settings = settings.transcript_viewer;
+    let viewer_state = app.state::<TranscriptViewerState>();
+    let Ok(mut server) = viewer_state.server.lock() else {
+        return;
+    };
+    let running_port = server.as_ref().map(viewer::ViewerServer::port);
+    let wanted_port = (settings.enabled && !offline_only).then_some(settings.port);
+    if running_port == wanted_port {
+        return;
+    }
+    server.take();
+
+    let logs = app.state::<RuntimeLogState>();
+    let Some(port) = wanted_port else {
+        let reason = if settings.enabled {
+            "transcript viewer off while offline-only mode is on"
+        } else {
+            "transcript viewer off"
+        };
+        let _ = log_store::append(&logs.path, "info", "viewer.stop", reason);
+        return;
+    };
+    match viewer::ViewerServer::start(IpAddr::V4(Ipv4Addr::UNSPECIFIED), port) {
+        Ok(started) => {
+            let _ = log_store::append(
+                &logs.path,
+                "info",
+                "viewer.start",
+                &format!("serving transcript viewer on port {port}"),
+            );
+            *server = Some(started);
+        }
+        Err(error) => {
+            let _ = log_store::append(&logs.path, "warn", "viewer.start", &error);
+        }
+    }
+}
+
+#[cfg(feature = "desktop")]
+fn publish_to_viewer(app: &tauri::AppHandle, update: viewer::ViewerUpdate) {
+    if let Some(hub) = app.state::<TranscriptViewerState>().hub() {
+        hub.publish(update);
+    }
+}
+
+#[cfg(feature = "desktop")]
+fn publish_transcript_to_dbus(app: &tauri::AppHandle, text: &str) {
+    #[cfg(target_os = "linux")]
+    if let Some(dbus) = app.try_state::<DbusState>() {
+        let _ = dbus.service.emit_last_transcript(text);
+    }
+    #[cfg(not(target_os = "linux"))]
+    let _ = (app, text);
+}
+
+#[cfg(feature = "desktop")]
+fn emit_transcript_if_fresh(
+    sink: &dyn EventSink,
+    context: &EmitContext,
+    raw_transcript: Option<String>,
+    correlation: Option<TranscriptCorrelation>,
+    session: Option<PartialSession<'_>>,
+    language: Option<String>,
+) -> Result<Option<String>, SonoraError> {
+    let mut last = context
+        .last_transcript
+        .lock()
+        .map_err(|_| SonoraError::state_unavailable("transcript"))?;
+    let mut repeats = context
+        .repeat_guard
+        .lock()
+        .map_err(|_| SonoraError::state_unavailable("transcript"))?;
+    let normalization = language_pack::normalization_for(language.as_deref());
+    // Live capture chunks belong to a session and are partials; a one-shot
+    // feed is complete as it stands.
+    let stage = if session.is_some() {
+        TranscriptStage::Partial
+    } else {
+        TranscriptStage::Final
+    };
+    let transcript = select_fresh_transcript(
+        &mut last,
+        &mut repeats,
+        context.duplicate_policy,
+        raw_transcript,
+        normalization,
+        stage,
+        &context.vocabulary_terms,
+    );
+
+    if let Some(text) = &transcript {
+        emit_event(
+            sink,
+            events::DICTATION_TRANSCRIPT,
+            TranscriptPayload {
+                text: text.clone(),
+                chunk_id: correlation.map(|value| value.chunk_id),
+                emitted_unix_ms: correlation.map(|value| value.emitted_unix_ms),
+                session_id: session.map(|value| value.id),
+                language,
+                diff: session
+                    .map(|value| postprocess::diff_partial_transcript(value.previous_text, text)),
+                is_final: stage == TranscriptStage::Final,
+                engine: None,
+            },
+        )
+        .map_err(|error| SonoraError::internal(error.to_string()))?;
+        sink.transcript_published(text);
+
+        let _ = log_store::append(
+            &context.logs_path,
+            "info",
+            "transcript.emit",
+            &format!("emitted transcript length={}", text.len()),
+        );
+    }
+
+    Ok(transcript)
+}
+
+// Settles a live session's partials with its final text. It skips the
+// duplicate check because the final often differs from the last partial
+// only in casing and punctuation.
+// Whisper tends to replay an earlier final when fed near-silence.
+#[cfg(feature = "desktop")]
+fn is_hallucinated_final(app: &tauri::AppHandle, text: &str, speech_ratio_percent: u8) -> bool {
+    let guard = match app.state::<SettingsState>().settings.lock() {
+        Ok(settings) => settings.hallucination_guard,
+        Err(_) => return false,
+    };
+    app.state::<PipelineStore>()
+        .recent_finals
+        .lock()
+        .map(|mut recent| recent.is_hallucinated(text, speech_ratio_percent, guard))
+        .unwrap_or(false)
+}
+
+#[cfg(feature = "desktop")]
+fn emit_final_transcript(
+    sink: &dyn EventSink,
+    context: &EmitContext,
+    session_id: u64,
+    text: &str,
+    language: Option<String>,
+    engine: Option<utterance_routing::UtteranceEngine>,
+) {
+    if let Ok(mut last) = context.last_transcript.lock() {
+        *last = Some(text.to_string());
+    }
+    if let Ok(mut repeats) = context.repeat_guard.lock() {
+        repeats.record(current_unix_ms_u64());
+    }
+    let _ = emit_event(
+        sink,
+        events::DICTATION_TRANSCRIPT,
+        TranscriptPayload {
+            text: text.to_string(),
+            chunk_id: None,
+            emitted_unix_ms: Some(current_unix_ms_u64()),
+            session_id: Some(session_id),
+            language,
+            diff: None,
+            is_final: true,
+            engine,
+        },
+    );
+    sink.transcript_published(text);
+    if let Some(viewer) = &context.viewer {
+        viewer.publish(viewer::ViewerUpdate::Final {
+            session_id,
+            text: text.to_string(),
+        });
+    }
+}
+
+// `always` is for a captions session, which shows frames whatever the
+// dictation captions setting says.
+#[cfg(feature = "desktop")]
+fn emit_caption_frame(
+    sink: &dyn EventSink,
+    context: &EmitContext,
+    utterance: &PendingUtterance,
+    language: String,
+    always: bool,
+) {
+    let captions_enabled = context.captions_enabled || always;
+    let viewer = context.viewer.as_ref();
+    if !captions_enabled && viewer.is_none() {
+        return;
+    }
+    let frame = captions::caption_frame(
+        utterance.session_id,
+        &utterance.text,
+        context.caption_line_chars,
+        Some(language),
+    );
+    if let Some(viewer) = viewer {
+        viewer.publish(viewer::ViewerUpdate::Caption {
+            session_id: frame.session_id,
+            lines: frame.lines.clone(),
+        });
+    }
+    if captions_enabled {
+        let _ = emit_event(sink, events::DICTATION_CAPTION, frame);
+    }
+}
+
+#[cfg(feature = "desktop")]
+fn emit_instant_words(
+    sink: &dyn EventSink,
+    context: &EmitContext,
+    utterance: &mut PendingUtterance,
+) {
+    if !context.instant_words {
+        return;
+    }
+    let typed = utterance.typed.get_or_insert_with(String::new);
+    if let Some(edit) =
+        instant_words::next_edit(utterance.session_id, typed, &utterance.text, false)
+    {
+        *typed = instant_words::apply_edit(typed, &edit);
+        let _ = emit_event(sink, events::DICTATION_INSTANT_EDIT, edit);
+    }
+}
+
+// The worker's emit step for a changed utterance: the partial transcript,
+// then its caption frame and instant-word edits. Recording and captions
+// sessions skip the dictation transcript; only captions keep the frames.
+#[cfg(feature = "desktop")]
+fn emit_utterance_update(
+    sink: &dyn EventSink,
+    context: &EmitContext,
+    utterance: &mut PendingUtterance,
+    last_emitted_partial: &mut Option<(u64, String)>,
+    correlation: TranscriptCorrelation,
+    language: String,
+    mode: CaptureMode,
+) -> Option<String> {
+    let captions = matches!(mode, CaptureMode::Captions { .. });
+    let detached = captions || mode == CaptureMode::Recording;
+    let emitted_text = if detached {
+        None
+    } else {
+        let previous_text = last_emitted_partial
+            .as_ref()
+            .filter(|(session_id, _)| *session_id == utterance.session_id)
+            .map(|(_, text)| text.as_str());
+        match emit_transcript_if_fresh(
+            sink,
+            context,
+            Some(utterance.text.clone()),
+            Some(correlation),
+            Some(PartialSession {
+                id: utterance.session_id,
+                previous_text,
+            }),
+            Some(language.clone()),
+        ) {
+            Ok(Some(text)) => {
+                *last_emitted_partial = Some((utterance.session_id, text.clone()));
+                Some(text)
+            }
+            Ok(None) => None,
+            Err(error) => {
+                let _ = log_store::append(
+                    &context.logs_path,
+                    "error",
+                    "mic.capture",
+                    &error.to_string(),
+                );
+                None
+            }
+        }
+    };
+    if !detached || captions {
+        // Whisper translates only into English.
+        let caption_language = if mode == (CaptureMode::Captions { translate: true }) {
+            "en".to_string()
+        } else {
+            language
+        };
+        emit_caption_frame(sink, context, utterance, caption_language, captions);
+        if !captions {
+            emit_instant_words(sink, context, utterance);
+        }
+    }
+    emitted_text
+}
+
+#[cfg(feature = "desktop")]
+fn active_number_locale(app: &tauri::AppHandle) -> Option<&'static number_format::NumberLocale> {
+    let settings = app.state::<SettingsState>();
+    let settings = settings.settings.lock().ok()?;
+    settings
+        .number_locale
+        .as_deref()
+        .and_then(number_format::find_locale)
+}
+
+#[cfg(feature = "desktop")]
+fn spoken_phrases_for(
+    app: &tauri::AppHandle,
+    active_language: &Arc<StateMutex<String>>,
+) -> Option<language_pack::SpokenPhrases> {
+    let overrides = {
+        let settings = app.state::<SettingsState>();
+        let settings = settings.settings.lock().ok()?;
+        if !settings.spoken_punctuation {
+            return None;
+        }
+        settings.spoken_phrase_overrides.clone()
+    };
+    let language = active_language.lock().ok()?.clone();
+    language_pack::SpokenPhrases::resolve(&language, &overrides)
+}
+
+// Session vocabulary plus learned corrections, re-read for every chunk so
+// edits made mid-session apply straight away.
+#[cfg(feature = "desktop")]
+fn live_corrections(
+    app: &tauri::AppHandle,
+    session_vocabulary: &Arc<StateMutex<Vec<String>>>,
+) -> (Vec<String>, Vec<corrections::ReplacementRule>) {
+    let (learned_terms, learned_rules) = app
+        .state::<CorrectionState>()
+        .store
+        .lock()
+        .map(|store| (store.vocabulary.clone(), store.rules.clone()))
+        .unwrap_or_default();
+    let mut session_terms = session_vocabulary
+        .lock()
+        .map(|terms| terms.clone())
+        .unwrap_or_default();
+    session_terms.extend(learned_terms);
+    (vocabulary::normalize_terms(session_terms), learned_rules)
+}
+
+#[cfg(feature = "desktop")]
+fn vocabulary_terms(app: &tauri::AppHandle) -> Vec<String> {
+    live_corrections(app, &app.state::<PipelineStore>().session_vocabulary).0
+}
+
+#[cfg(feature = "desktop")]
+fn postprocess_live_text(
+    app: &tauri::AppHandle,
+    text: &str,
+    session_terms: &[String],
+    learned_rules: &[corrections::ReplacementRule],
+    active_language: &Arc<StateMutex<String>>,
+) -> String {
+    let text = vocabulary::apply_term_corrections(text, session_terms);
+    let text = corrections::apply_rules(&text, learned_rules);
+    let text = match spoken_phrases_for(app, active_language) {
+        Some(phrases) => phrases.apply(&text),
+        None => text,
+    };
+    match active_number_locale(app) {
+        Some(locale) => number_format::format_numbers(&text, locale),
+        None => text,
+    }
+}
+
+// Holds the long-utterance engine for one capture session; None unless
+// routing is on and the long route differs from the live engine.
+#[cfg(feature = "desktop")]
+struct UtteranceRouter {
+    engine: utterance_routing::LongRouteEngine<RuntimeTranscriber>,
+    session_vocabulary: Arc<StateMutex<Vec<String>>>,
+}
+
+#[cfg(feature = "desktop")]
+fn start_utterance_router(
+    app: &tauri::AppHandle,
+    session_vocabulary: &Arc<StateMutex<Vec<String>>>,
+) -> Option<UtteranceRouter> {
+    let settings = app.state::<SettingsState>().settings.lock().ok()?.clone();
+    let long_settings = utterance_routing::long_route_settings(&settings)?;
+    let runtime = build_runtime_engine_for_settings(app, &long_settings);
+    Some(UtteranceRouter {
+        engine: utterance_routing::LongRouteEngine::new(&long_settings, runtime.transcriber),
+        session_vocabulary: Arc::clone(session_vocabulary),
+    })
+}
 
+// The engine behind an utterance's live text, before any long-route rerun.
 #[cfg(feature = "desktop")]
-fn should_emit_meter_update(elapsed: Duration) -> bool {
-    elapsed >= Duration::from_millis(METER_EMIT_INTERVAL_MS)
+fn live_utterance_engine(
+    app: &tauri::AppHandle,
+    audio_ms: u64,
+    cold_start: bool,
+) -> Option<utterance_routing::UtteranceEngine> {
+    if cold_start {
+        return Some(utterance_routing::cold_start_engine(audio_ms));
+    }
+    let settings = app.state::<SettingsState>();
+    let settings = settings.settings.lock().ok()?;
+    Some(utterance_routing::UtteranceEngine {
+        engine: settings.stt_engine,
+        model_profile: settings.model_profile,
+        route: match utterance_routing::route_for(&settings.utterance_routing, audio_ms) {
+            utterance_routing::UtteranceRoute::Short => utterance_routing::UtteranceRoute::Short,
+            _ => utterance_routing::UtteranceRoute::Live,
+        },
+        audio_ms,
+    })
 }
 
+// The shadow engine lives as long as the capture session holds the sender.
 #[cfg(feature = "desktop")]
-fn duration_millis_u64(duration: Duration) -> u64 {
-    duration.as_millis().min(u128::from(u64::MAX)) as u64
+fn start_shadow_engine(app: &tauri::AppHandle) -> Option<SyncSender<shadow::ShadowJob>> {
+    let settings = app.state::<SettingsState>().settings.lock().ok()?.clone();
+    let engine = settings.shadow_engine?;
+    let runtime = build_runtime_engine_for_settings(
+        app,
+        &AppSettings {
+            stt_engine: engine,
+            ..settings
+        },
+    );
+    let logs_path = app.state::<RuntimeLogState>().path.clone();
+    let (shadow_tx, shadow_rx) = mpsc::sync_channel(shadow::SHADOW_QUEUE_CHUNKS);
+    thread::spawn(move || {
+        let log_path = shadow::default_shadow_log_path();
+        if let Err(error) = shadow::run_shadow_worker(&runtime.transcriber, &log_path, shadow_rx) {
+            let _ = log_store::append(&logs_path, "warn", "shadow.engine", &error);
+        }
+    });
+    Some(shadow_tx)
 }
 
+// None while voice activation is off; otherwise the silence that ends a voice-started dictation.
 #[cfg(feature = "desktop")]
-fn current_unix_ms_u64() -> u64 {
-    SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .map(|value| value.as_millis().min(u128::from(u64::MAX)) as u64)
-        .unwrap_or(0)
+fn voice_activation_silence_ms(app: &tauri::AppHandle) -> Option<u64> {
+    let settings = app.state::<SettingsState>();
+    let settings = settings.settings.lock().ok()?;
+    settings
+        .voice_activation
+        .then_some(u64::from(settings.voice_activation_silence_ms))
 }
 
 #[cfg(feature = "desktop")]
-fn is_perf_enabled_from_env() -> bool {
-    std::env::var("SONORA_PERF")
-        .map(|value| {
-            matches!(
-                value.trim().to_ascii_lowercase().as_str(),
-                "1" | "true" | "on" | "yes"
-            )
-        })
-        .unwrap_or(false)
+fn adaptive_cadence_enabled(app: &tauri::AppHandle) -> bool {
+    let settings = app.state::<SettingsState>();
+    let enabled = settings
+        .settings
+        .lock()
+        .is_ok_and(|settings| settings.adaptive_cadence);
+    enabled
 }
 
 #[cfg(feature = "desktop")]
-fn append_perf_event<T: Serialize>(path: &Path, perf_enabled: bool, event: &str, payload: &T) {
-    if !perf_enabled {
+fn start_voice_activated_dictation(
+    app: &tauri::AppHandle,
+    pipeline: &PipelineActor<RuntimeTranscriber>,
+) {
+    let started = pipeline
+        .call(|locked| {
+            let was_idle = locked.status().state == pipeline::DictationState::Idle;
+            if was_idle {
+                locked.on_hotkey_down();
+            }
+            was_idle
+        })
+        .unwrap_or(false);
+    if !started {
         return;
     }
-
-    if let Ok(message) = serde_json::to_string(payload) {
-        let _ = log_store::append(path, "info", event, &message);
+    let focus_guard_enabled = app
+        .state::<SettingsState>()
+        .settings
+        .lock()
+        .map(|settings| settings.focus_guard_enabled)
+        .unwrap_or(false);
+    if focus_guard_enabled {
+        let _ = remember_focus_target(&app.state::<InsertionState>());
     }
+    emit_voice_activation(app, true);
 }
 
 #[cfg(feature = "desktop")]
-fn emit_transcript_if_fresh(
-    app: &tauri::AppHandle,
-    logs_path: &Path,
-    last_transcript: &Arc<Mutex<Option<String>>>,
-    raw_transcript: Option<String>,
-    correlation: Option<TranscriptCorrelation>,
-    session_id: Option<u64>,
-) -> Result<Option<String>, String> {
-    let mut last = last_transcript
+fn announce_to_screen_reader(app: &tauri::AppHandle, mut announcement: announce::Announcement) {
+    let enabled = app
+        .state::<SettingsState>()
+        .settings
         .lock()
-        .map_err(|_| "failed to acquire transcript state".to_string())?;
-    let transcript = select_fresh_transcript(&mut last, raw_transcript);
-
-    if let Some(text) = &transcript {
-        app.emit(
-            "dictation:transcript",
-            TranscriptPayload {
-                text: text.clone(),
-                chunk_id: correlation.map(|value| value.chunk_id),
-                emitted_unix_ms: correlation.map(|value| value.emitted_unix_ms),
-                session_id,
-            },
-        )
-        .map_err(|error| error.to_string())?;
-
-        let _ = log_store::append(
-            logs_path,
-            "info",
-            "transcript.emit",
-            &format!("emitted transcript length={}", text.len()),
-        );
+        .is_ok_and(|settings| settings.screen_reader_announcements);
+    if !enabled {
+        return;
     }
+    announcement.spoken_natively = announce::speak_natively(&announcement);
+    let _ = emit_event(app, events::ACCESSIBILITY_ANNOUNCE, announcement);
+}
 
-    Ok(transcript)
+#[cfg(feature = "desktop")]
+fn emit_voice_activation(sink: &dyn EventSink, active: bool) {
+    let _ = emit_event(
+        sink,
+        events::DICTATION_VOICE_ACTIVATION,
+        VoiceActivationPayload {
+            active,
+            unix_ms: current_unix_ms_u64(),
+        },
+    );
 }
 
 #[cfg(feature = "desktop")]
 fn run_transcription_worker(
     app: tauri::AppHandle,
-    pipeline: Arc<Mutex<DictationPipeline<RuntimeTranscriber>>>,
-    last_transcript: Arc<Mutex<Option<String>>>,
+    sink: &dyn EventSink,
+    shared: CaptureShared,
     logs_path: PathBuf,
     source_sample_rate_hz: u32,
-    perf_enabled: bool,
+    shadow_tx: Option<SyncSender<shadow::ShadowJob>>,
     frame_rx: Receiver<Vec<f32>>,
 ) {
+    let CaptureShared {
+        pipeline,
+        session_stats,
+        meeting_transcript,
+        active_language,
+        session_vocabulary,
+        panic_epoch,
+        mode,
+    } = shared;
+    let recording = mode == CaptureMode::Recording;
+    let captions = matches!(mode, CaptureMode::Captions { .. });
+    let translate = mode == CaptureMode::Captions { translate: true };
+    // Both leave dictation alone: no hotkey, no transcript, no insertion.
+    let detached = recording || captions;
+    let started_epoch = panic_epoch.load(Ordering::SeqCst);
+    if thread_priority_wanted(&app, ThreadRole::Inference) {
+        log_thread_priority(
+            &logs_path,
+            &thread_priority::apply_current_thread(ThreadRole::Inference),
+        );
+        // The pipeline actor runs the engine itself and outlives the session.
+        if let Ok(outcome) =
+            pipeline.call(|_| thread_priority::apply_current_thread(ThreadRole::Inference))
+        {
+            log_thread_priority(&logs_path, &outcome);
+        }
+    }
+    if let Ok(Err(error)) = pipeline.call(|locked| locked.begin_transcriber_session()) {
+        let _ = log_store::append(&logs_path, "warn", "capture.workdir", &error);
+    }
+    if detached {
+        let _ = pipeline.call(|locked| locked.hold_listening());
+    }
+    if translate {
+        if let Ok(settings) = app
+            .state::<SettingsState>()
+            .settings
+            .lock()
+            .map(|settings| settings.clone())
+        {
+            let transcriber = translating_engine(&app, &settings).transcriber;
+            let _ = pipeline.call(move |locked| locked.set_transcriber(transcriber));
+        }
+    }
+    let utterance_router = if detached {
+        None
+    } else {
+        start_utterance_router(&app, &session_vocabulary)
+    };
+    let finalize_utterance = |pending: &mut Option<PendingUtterance>| {
+        if detached {
+            finalize_recording_utterance(
+                &app,
+                pending,
+                &meeting_transcript,
+                &active_language,
+                &logs_path,
+            )
+        } else {
+            finalize_pending_utterance(
+                &app,
+                pending,
+                &session_stats,
+                &meeting_transcript,
+                &active_language,
+                &logs_path,
+                utterance_router.as_ref(),
+            )
+        }
+    };
     let mut pending_samples = VecDeque::<f32>::new();
     let mut pending_utterance: Option<PendingUtterance> = None;
     let mut next_transcript_session_id = 0u64;
+    let mut last_emitted_partial: Option<(u64, String)> = None;
     let mut last_feed_at = Instant::now() - Duration::from_secs(8);
     let mut pending_started_at: Option<Instant> = None;
     let mut pending_downsample_ms = 0u64;
     let mut chunk_id = 0u64;
+    let mut trace_path = performance_trace_path(&app);
+    let mut realtime = profile::RealtimeTracker::default();
+    let mut downgrade_requested = false;
+    let mut voice_activation = voice_activation::VoiceActivation::new(0);
+    let mut speech_rate = speech_rate::SpeechRateTracker::default();
+    let mut announced_state = None::<pipeline::DictationState>;
+    let timing = live_session_timing(&app);
 
     loop {
-        let frame = match frame_rx.recv_timeout(Duration::from_millis(FRAME_RECV_TIMEOUT_MS)) {
+        let frame = match frame_rx.recv_timeout(timing.frame_recv_timeout) {
             Ok(samples) => samples,
             Err(RecvTimeoutError::Timeout) => {
                 if should_flush_pending_utterance(
@@ -938,7 +5830,7 @@ fn run_transcription_worker(
                     current_unix_ms_u64(),
                     TRANSCRIPT_SESSION_GAP_MS,
                 ) {
-                    let _ = take_pending_utterance(&mut pending_utterance);
+                    let _ = finalize_utterance(&mut pending_utterance);
                 }
                 continue;
             }
@@ -955,32 +5847,94 @@ fn run_transcription_worker(
         if pending_samples.is_empty() {
             pending_started_at = Some(Instant::now());
         }
-        pending_samples.extend(downsampled);
+        pending_samples.extend(downsampled.iter().copied());
+
+        let (status, vad_config) =
+            match pipeline.call(|locked| (locked.status(), locked.vad_config().clone())) {
+                Ok(state) => state,
+                Err(_) => {
+                    let _ = log_store::append(
+                        &logs_path,
+                        "error",
+                        "mic.capture",
+                        "failed to acquire pipeline state",
+                    );
+                    break;
+                }
+            };
+        if let Some(previous) = announced_state.replace(status.state) {
+            if let Some(announcement) = announce::state_change(previous, status.state) {
+                announce_to_screen_reader(&app, announcement);
+            }
+        }
 
-        let status = match pipeline.lock() {
-            Ok(locked) => locked.status(),
-            Err(_) => {
-                let _ = log_store::append(
-                    &logs_path,
-                    "error",
-                    "mic.capture",
-                    "failed to acquire pipeline state",
-                );
-                break;
+        // A recording never starts or stops dictation on its own.
+        let silence_ms = voice_activation_silence_ms(&app).filter(|_| !detached);
+        let voice_activation_event = match silence_ms {
+            Some(silence_ms) => {
+                voice_activation.set_stop_after_silence_ms(silence_ms);
+                // The detector always gates on energy, even when chunk VAD is disabled.
+                let detector_config = VadConfig {
+                    enabled: true,
+                    ..vad_config.clone()
+                };
+                voice_activation.observe(&downsampled, &detector_config)
+            }
+            None => {
+                voice_activation.reset();
+                None
             }
         };
 
         if status.state != pipeline::DictationState::Listening {
-            let _ = take_pending_utterance(&mut pending_utterance);
-            pending_samples.clear();
+            if voice_activation_event == Some(voice_activation::VoiceActivationEvent::Start) {
+                start_voice_activated_dictation(&app, &pipeline);
+                continue;
+            }
+            if voice_activation.is_active() {
+                // Stopped by the hotkey or a cancel while voice-activated.
+                voice_activation.reset();
+            }
+            let _ = finalize_utterance(&mut pending_utterance);
+            let preroll = if silence_ms.is_some() {
+                voice_activation::preroll_samples()
+            } else {
+                0
+            };
+            let excess = pending_samples.len().saturating_sub(preroll);
+            pending_samples.drain(..excess);
             continue;
         }
 
-        let Some(chunk_plan) =
-            plan_live_capture_chunk(&status, pending_samples.len(), last_feed_at.elapsed())
-        else {
+        if voice_activation_event == Some(voice_activation::VoiceActivationEvent::Start) {
+            // Dictation was already running from the hotkey, so silence must not end it.
+            voice_activation.reset();
+        }
+        if voice_activation_event == Some(voice_activation::VoiceActivationEvent::Stop) {
+            let _ = pipeline.call(|locked| locked.cancel());
+            emit_voice_activation(sink, false);
+            continue;
+        }
+
+        let cadence_adjustment = if adaptive_cadence_enabled(&app) {
+            speech_rate.adjustment()
+        } else {
+            speech_rate::CadenceAdjustment::NEUTRAL
+        };
+        let Some(chunk_plan) = plan_live_capture_chunk(
+            &status,
+            pending_samples.len(),
+            last_feed_at.elapsed(),
+            cadence_adjustment,
+        ) else {
             continue;
         };
+        let window_segments = vad::detect_speech_segments(
+            &pending_samples.make_contiguous()[..chunk_plan.next_chunk_size],
+            &vad_config,
+        );
+        speech_rate.observe(&window_segments, chunk_plan.next_chunk_size);
+        let chunk_plan = align_chunk_plan_to_phrase_boundary(chunk_plan, &window_segments);
 
         chunk_id = chunk_id.saturating_add(1);
         let queue_samples_before_chunk = pending_samples.len();
@@ -998,25 +5952,28 @@ fn run_transcription_worker(
         if pending_samples.is_empty() {
             pending_started_at = None;
         }
+        // Samples still queued were captured after this chunk ended.
+        let chunk_end_unix_ms = current_unix_ms_u64()
+            .saturating_sub((pending_samples.len() as u64).saturating_mul(1_000) / 16_000);
 
         trim_pending_backlog(&mut pending_samples, chunk_plan.max_chunk_samples);
 
         last_feed_at = Instant::now();
 
+        let (session_terms, learned_rules) = live_corrections(&app, &session_vocabulary);
         let pipeline_started_at = Instant::now();
-        let metrics = match pipeline.lock() {
-            Ok(mut locked) => {
-                locked.set_stream_context(
-                    pending_utterance.as_ref().map(|value| value.text.as_str()),
-                );
-                match locked.process_audio_chunk_profiled(&chunk) {
-                    Ok(value) => value,
-                    Err(error) => {
-                        let _ = log_store::append(&logs_path, "error", "mic.capture", &error);
-                        continue;
-                    }
-                }
-            }
+        let prompt = vocabulary::biasing_prompt(
+            &session_terms,
+            pending_utterance.as_ref().map(|value| value.text.as_str()),
+        );
+        let cancel_epoch = pipeline.cancel_epoch();
+        // The chunk is handed to the pipeline thread and returned for tracing and the shadow engine.
+        let (chunk, processed) = match pipeline.call(move |locked| {
+            locked.set_stream_context(prompt.as_deref());
+            let processed = locked.process_audio_chunk_profiled(&chunk);
+            (chunk, processed)
+        }) {
+            Ok(result) => result,
             Err(_) => {
                 let _ = log_store::append(
                     &logs_path,
@@ -1027,39 +5984,109 @@ fn run_transcription_worker(
                 break;
             }
         };
+        let metrics = match processed {
+            Ok(value) => value,
+            Err(error) => {
+                let _ = log_store::append(&logs_path, "error", "mic.capture", &error);
+                continue;
+            }
+        };
+        // Cancelled while this chunk was being transcribed.
+        if pipeline.cancel_epoch() != cancel_epoch {
+            continue;
+        }
         let pipeline_ms = duration_millis_u64(pipeline_started_at.elapsed());
 
+        if let Some(shadow_tx) = shadow_tx.as_ref().filter(|_| metrics.had_speech) {
+            let _ = shadow_tx.try_send(shadow::ShadowJob {
+                chunk_id,
+                samples: chunk.clone(),
+                primary_engine: metrics.engine.clone(),
+                primary_model: metrics.model.clone(),
+                primary_ms: metrics.inference_ms,
+                primary_text: metrics.transcript.clone(),
+            });
+        }
+
+        if metrics.listening && metrics.enough_samples {
+            if let Ok(mut stats) = session_stats.lock() {
+                stats.record_audio(
+                    chunk.len(),
+                    vad::speech_sample_count(&metrics.speech_segments),
+                );
+            }
+        }
+
+        // A panic mid-inference discards the result instead of typing it.
+        if panic_epoch.load(Ordering::SeqCst) != started_epoch {
+            pending_utterance = None;
+            break;
+        }
+
+        let language_switch = metrics
+            .transcript
+            .as_deref()
+            .and_then(language::parse_language_switch_command);
+        let transcript = if let Some(language) = language_switch {
+            let _ = finalize_utterance(&mut pending_utterance);
+            if let Err(error) = switch_utterance_language(&app, Some(language)) {
+                let _ =
+                    log_store::append(&logs_path, "error", "language.switch", &error.to_string());
+            }
+            None
+        } else {
+            metrics.transcript.as_deref().map(|text| {
+                postprocess_live_text(&app, text, &session_terms, &learned_rules, &active_language)
+            })
+        };
+
         let emitted_unix_ms = current_unix_ms_u64();
+        let contributed_audio = transcript.is_some();
         let pending_changed = upsert_pending_utterance(
             &mut pending_utterance,
-            metrics.transcript.clone(),
+            transcript,
             emitted_unix_ms,
             &mut next_transcript_session_id,
         );
+        if pending_changed {
+            if let Some(utterance) = pending_utterance.as_mut() {
+                utterance.confidence =
+                    review_queue::lowest_confidence(utterance.confidence, metrics.confidence);
+                if let Some(segment) = metrics.speech_segments.last() {
+                    let trailing_samples = chunk.len().saturating_sub(segment.end_sample) as u64;
+                    utterance.speech_end_unix_ms =
+                        Some(chunk_end_unix_ms.saturating_sub(trailing_samples * 1_000 / 16_000));
+                }
+            }
+        }
+        // Kept for routing even when utterance audio is not saved.
+        if contributed_audio {
+            if let Some(utterance) = pending_utterance.as_mut() {
+                utterance.audio.extend_from_slice(&chunk);
+                utterance.speech_samples += vad::speech_sample_count(&metrics.speech_segments);
+                utterance.cold_start |= metrics.cold_start;
+            }
+        }
 
         let emit_started_at = Instant::now();
         let emitted_text = if pending_changed {
-            if let Some(utterance) = pending_utterance.as_ref() {
-                match emit_transcript_if_fresh(
-                    &app,
-                    &logs_path,
-                    &last_transcript,
-                    Some(utterance.text.clone()),
-                    Some(TranscriptCorrelation {
+            pending_utterance.as_mut().and_then(|utterance| {
+                emit_utterance_update(
+                    sink,
+                    &EmitContext::from_app(&app),
+                    utterance,
+                    &mut last_emitted_partial,
+                    TranscriptCorrelation {
                         chunk_id,
                         emitted_unix_ms,
-                    }),
-                    Some(utterance.session_id),
-                ) {
-                    Ok(value) => value,
-                    Err(error) => {
-                        let _ = log_store::append(&logs_path, "error", "mic.capture", &error);
-                        None
-                    }
-                }
-            } else {
-                None
-            }
+                    },
+                    active_language
+                        .lock()
+                        .map(|value| value.clone())
+                        .unwrap_or_default(),
+                    mode,
+                )
+            })
         } else {
             None
         };
@@ -1070,130 +6097,410 @@ fn run_transcription_worker(
             .map(str::len)
             .unwrap_or_else(|| metrics.transcript.as_deref().map(str::len).unwrap_or(0));
 
+        if metrics.had_speech && !metrics.cache_hit && !downgrade_requested {
+            let chunk_audio_ms = (chunk.len() as u64).saturating_mul(1_000) / 16_000;
+            if let Some(slow) = realtime.observe(metrics.inference_ms, chunk_audio_ms) {
+                downgrade_requested = request_profile_downgrade(&app, slow);
+            }
+        }
+
+        let chunk_trace = PerfChunkTrace {
+            chunk_id,
+            engine: metrics.engine,
+            model: metrics.model,
+            backend: metrics.backend,
+            source_sample_rate_hz,
+            chunk_samples: chunk.len(),
+            chunk_audio_ms: (chunk.len() as u64).saturating_mul(1_000) / 16_000,
+            queue_samples_before_chunk,
+            collect_ms,
+            downsample_ms: pending_downsample_ms,
+            pipeline_ms,
+            vad_ms: metrics.vad_ms,
+            inference_ms: metrics.inference_ms,
+            cache_hit: metrics.cache_hit,
+            emit_rust_ms,
+            total_worker_ms: duration_millis_u64(chunk_started_at.elapsed()),
+            listening: metrics.listening,
+            enough_samples: metrics.enough_samples,
+            had_speech: metrics.had_speech,
+            emitted_transcript,
+            transcript_len,
+        };
         append_perf_event(
             &logs_path,
-            perf_enabled,
+            app.state::<RuntimeLogState>().perf_enabled,
             "perf.chunk",
-            &PerfChunkTrace {
-                chunk_id,
-                engine: metrics.engine,
-                model: metrics.model,
-                backend: metrics.backend,
-                source_sample_rate_hz,
-                chunk_samples: chunk.len(),
-                chunk_audio_ms: (chunk.len() as u64).saturating_mul(1_000) / 16_000,
-                queue_samples_before_chunk,
-                collect_ms,
-                downsample_ms: pending_downsample_ms,
-                pipeline_ms,
-                vad_ms: metrics.vad_ms,
-                inference_ms: metrics.inference_ms,
-                emit_rust_ms,
-                total_worker_ms: duration_millis_u64(chunk_started_at.elapsed()),
-                listening: metrics.listening,
-                enough_samples: metrics.enough_samples,
-                had_speech: metrics.had_speech,
-                emitted_transcript,
-                transcript_len,
-            },
+            &chunk_trace,
+        );
+        if let Some(path) = trace_path.as_deref() {
+            if let Err(error) = perf_trace::append(path, &chunk_trace, perf_trace::MAX_TRACE_BYTES)
+            {
+                // Stop tracing for this session rather than logging the failure on every chunk.
+                let _ = log_store::append(&logs_path, "warn", "perf.trace", &error);
+                trace_path = None;
+            }
+        }
+
+        if !metrics.had_speech
+            && should_flush_pending_utterance(
+                &pending_utterance,
+                emitted_unix_ms,
+                TRANSCRIPT_SESSION_GAP_MS,
+            )
+        {
+            let _ = finalize_utterance(&mut pending_utterance);
+        }
+
+        pending_downsample_ms = 0;
+    }
+
+    let _ = finalize_utterance(&mut pending_utterance);
+    if let Some(router) = utterance_router {
+        router.engine.unload();
+    }
+    if detached {
+        let _ = pipeline.call(|locked| locked.release_listening());
+    }
+    if translate {
+        restore_configured_transcriber(&app, &logs_path);
+    }
+    let _ = pipeline.call(|locked| locked.end_transcriber_session());
+}
+
+#[cfg(feature = "desktop")]
+fn thread_priority_wanted(app: &tauri::AppHandle, role: ThreadRole) -> bool {
+    let priorities = app
+        .state::<SettingsState>()
+        .settings
+        .lock()
+        .map(|settings| settings.thread_priorities)
+        .unwrap_or_default();
+    match role {
+        ThreadRole::Capture => priorities.boost_capture,
+        ThreadRole::Inference => priorities.lower_inference,
+    }
+}
+
+// A boost skipped for lack of privileges is expected and logged as info; only
+// a mechanism that was tried and failed is worth a warning.
+#[cfg(feature = "desktop")]
+fn log_thread_priority(logs_path: &Path, outcome: &thread_priority::PriorityOutcome) {
+    let level = if outcome.error.is_some() {
+        "warn"
+    } else {
+        "info"
+    };
+    let _ = log_store::append(logs_path, level, "capture.priority", &outcome.summary());
+}
+
+#[cfg(feature = "desktop")]
+fn run_live_capture_session(
+    app: tauri::AppHandle,
+    sink: &dyn EventSink,
+    shared: CaptureShared,
+    microphone_id: Option<String>,
+    mic: mic_profile::MicSettings,
+    stop_rx: Receiver<()>,
+    started_tx: SyncSender<Result<(), mic_availability::MicUnavailable>>,
+) {
+    let logs_path = app.state::<RuntimeLogState>().path.clone();
+    if thread_priority_wanted(&app, ThreadRole::Capture) {
+        log_thread_priority(
+            &logs_path,
+            &thread_priority::apply_current_thread(ThreadRole::Capture),
+        );
+    }
+    let (capture_tx, capture_rx) = mpsc::sync_channel::<Vec<f32>>(48);
+    let Some(input_stream) = open_live_input_stream(
+        &app,
+        &logs_path,
+        microphone_id.as_deref(),
+        mic.input_channel,
+        capture_tx,
+        &stop_rx,
+        started_tx,
+    ) else {
+        return;
+    };
+
+    let (transcribe_tx, transcribe_rx) = mpsc::sync_channel::<Vec<f32>>(24);
+    let shadow_tx = start_shadow_engine(&app);
+    let app_for_transcription = app.clone();
+    let logs_for_transcription = logs_path.clone();
+    let source_sample_rate_hz = input_stream.sample_rate_hz;
+
+    let transcription_worker = thread::spawn(move || {
+        let sink = app_for_transcription.clone();
+        run_transcription_worker(
+            app_for_transcription,
+            &sink,
+            shared,
+            logs_for_transcription,
+            source_sample_rate_hz,
+            shadow_tx,
+            transcribe_rx,
+        );
+    });
+
+    let mic_gain = mic_sensitivity_gain(mic.mic_sensitivity_percent);
+    let meter_ballistics = meter_ballistics_for_app(&app);
+    let timing = live_session_timing(&app);
+    let mut last_meter_emit_at = Instant::now() - Duration::from_secs(1);
+    let mut meter_state = audio::MeterState::default();
+    let mut playback_gate = playback_gate_for_app(&app, mic.playback_guard);
+    let playback_playing = Arc::new(AtomicBool::new(false));
+    let playback_stop = Arc::new(AtomicBool::new(false));
+    if mic.playback_guard != config::PlaybackGuard::Off {
+        spawn_playback_watcher(playback_playing.clone(), playback_stop.clone());
+    }
+    let mut playback_engaged = false;
+
+    loop {
+        if stop_rx.try_recv().is_ok() {
+            break;
+        }
+
+        let mut frame = match capture_rx.recv_timeout(timing.frame_recv_timeout) {
+            Ok(samples) => samples,
+            Err(RecvTimeoutError::Timeout) => continue,
+            Err(RecvTimeoutError::Disconnected) => break,
+        };
+
+        apply_mic_gain(&mut frame, mic_gain);
+
+        let measured = audio::measure_mic_level(
+            &frame,
+            source_sample_rate_hz,
+            &mut meter_state,
+            &meter_ballistics,
         );
 
-        if !metrics.had_speech
-            && should_flush_pending_utterance(
-                &pending_utterance,
-                emitted_unix_ms,
-                TRANSCRIPT_SESSION_GAP_MS,
-            )
-        {
-            let _ = take_pending_utterance(&mut pending_utterance);
+        if timing.should_emit_meter(last_meter_emit_at.elapsed()) {
+            let _ = emit_event(sink, events::DICTATION_MIC_LEVEL, measured);
+            last_meter_emit_at = Instant::now();
+        }
+
+        let now = Instant::now();
+        playback_gate.observe_playback(playback_playing.load(Ordering::Relaxed), now);
+        if playback_gate.engaged(now) != playback_engaged {
+            playback_engaged = !playback_engaged;
+            emit_playback_guard(sink, playback_gate.guard(), playback_engaged);
+        }
+        playback_gate.filter(&mut frame, now);
+
+        let _ = transcribe_tx.try_send(frame);
+    }
+
+    playback_stop.store(true, Ordering::Relaxed);
+    if playback_engaged {
+        emit_playback_guard(sink, playback_gate.guard(), false);
+    }
+    drop(transcribe_tx);
+    let _ = transcription_worker.join();
+
+    let _ = emit_event(
+        sink,
+        events::DICTATION_MIC_LEVEL,
+        audio::MicLevel {
+            level: 0.0,
+            peak: 0.0,
+            active: false,
+        },
+    );
+}
+
+// Reports the first attempt back to the start command. When another app holds
+// the device exclusively the session keeps polling and starts by itself once
+// the device is released, unless it is stopped or the wait limit passes.
+#[cfg(feature = "desktop")]
+fn open_live_input_stream(
+    app: &tauri::AppHandle,
+    logs_path: &Path,
+    microphone_id: Option<&str>,
+    input_channel: Option<u16>,
+    capture_tx: SyncSender<Vec<f32>>,
+    stop_rx: &Receiver<()>,
+    started_tx: SyncSender<Result<(), mic_availability::MicUnavailable>>,
+) -> Option<audio::LiveInputStream> {
+    let device_label = audio::input_device_label(microphone_id);
+    let waiting_since = Instant::now();
+    let mut reported: Option<mic_availability::MicUnavailable> = None;
+    loop {
+        let error = match audio::build_live_input_stream(
+            microphone_id,
+            input_channel,
+            capture_tx.clone(),
+        ) {
+            Ok(stream) => {
+                let _ = started_tx.try_send(Ok(()));
+                if reported.is_some() {
+                    let _ = log_store::append(
+                        logs_path,
+                        "info",
+                        "mic.available",
+                        "microphone released; starting dictation",
+                    );
+                    record_usage_session(app);
+                    emit_live_mic_state(app, true);
+                }
+                return Some(stream);
+            }
+            Err(error) => error,
+        };
+
+        let mut report =
+            mic_availability::MicUnavailable::from_stream_error(&error, device_label.clone());
+        report.waiting &= waiting_since.elapsed() < mic_availability::AVAILABILITY_WAIT_LIMIT;
+        // Polling repeats the same failure; only a change is worth reporting.
+        if reported.as_ref() != Some(&report) {
+            let _ = log_store::append(logs_path, "error", "mic.capture", &error);
+            let _ = emit_event(app, events::DICTATION_MIC_UNAVAILABLE, report.clone());
+            let _ = started_tx.try_send(Err(report.clone()));
         }
+        if !report.waiting {
+            emit_live_mic_state(app, false);
+            return None;
+        }
+        reported = Some(report);
 
-        pending_downsample_ms = 0;
+        match stop_rx.recv_timeout(Duration::from_millis(
+            mic_availability::AVAILABILITY_POLL_INTERVAL_MS,
+        )) {
+            Err(RecvTimeoutError::Timeout) => continue,
+            _ => return None,
+        }
     }
 }
 
 #[cfg(feature = "desktop")]
-fn run_live_capture_session(
+fn playback_gate_for_app(
+    app: &tauri::AppHandle,
+    guard: config::PlaybackGuard,
+) -> playback::PlaybackGate {
+    let vad_rms_threshold = app
+        .state::<SettingsState>()
+        .settings
+        .lock()
+        .map(|settings| vad_config_for_settings(&settings).rms_threshold)
+        .unwrap_or(VadConfig::default().rms_threshold);
+    playback::PlaybackGate::new(guard, vad_rms_threshold)
+}
+
+#[cfg(feature = "desktop")]
+fn spawn_playback_watcher(playing: Arc<AtomicBool>, stop: Arc<AtomicBool>) {
+    thread::spawn(move || {
+        while !stop.load(Ordering::Relaxed) {
+            let active = playback::system_playback_active().unwrap_or(false);
+            playing.store(active, Ordering::Relaxed);
+            thread::sleep(Duration::from_millis(playback::PLAYBACK_POLL_INTERVAL_MS));
+        }
+    });
+}
+
+#[cfg(feature = "desktop")]
+fn emit_playback_guard(sink: &dyn EventSink, guard: config::PlaybackGuard, engaged: bool) {
+    let _ = emit_event(
+        sink,
+        events::DICTATION_PLAYBACK_GUARD,
+        PlaybackGuardPayload {
+            engaged,
+            guard,
+            unix_ms: current_unix_ms_u64(),
+        },
+    );
+}
+
+#[cfg(feature = "desktop")]
+fn run_mic_monitor_session(
     app: tauri::AppHandle,
-    pipeline: Arc<Mutex<DictationPipeline<RuntimeTranscriber>>>,
-    last_transcript: Arc<Mutex<Option<String>>>,
     logs_path: PathBuf,
-    perf_enabled: bool,
     microphone_id: Option<String>,
-    mic_sensitivity_percent: u16,
+    output_device_id: Option<String>,
+    mic: mic_profile::MicSettings,
     stop_rx: Receiver<()>,
 ) {
-    let (capture_tx, capture_rx) = mpsc::sync_channel::<Vec<f32>>(48);
-    let input_stream = match audio::build_live_input_stream(microphone_id.as_deref(), capture_tx) {
+    let (capture_tx, capture_rx) = mpsc::sync_channel::<Vec<f32>>(16);
+    let input_stream = match audio::build_live_input_stream(
+        microphone_id.as_deref(),
+        mic.input_channel,
+        capture_tx,
+    ) {
         Ok(stream) => stream,
         Err(error) => {
-            let _ = log_store::append(&logs_path, "error", "mic.capture", &error);
-            emit_live_mic_state(&app, false);
+            let _ = log_store::append(&logs_path, "error", "mic.monitor", &error);
+            emit_mic_monitor_state(&app, false);
             return;
         }
     };
 
-    let (transcribe_tx, transcribe_rx) = mpsc::sync_channel::<Vec<f32>>(24);
-    let app_for_transcription = app.clone();
-    let pipeline_for_transcription = Arc::clone(&pipeline);
-    let transcripts_for_transcription = Arc::clone(&last_transcript);
-    let logs_for_transcription = logs_path.clone();
-    let source_sample_rate_hz = input_stream.sample_rate_hz;
-
-    let transcription_worker = thread::spawn(move || {
-        run_transcription_worker(
-            app_for_transcription,
-            pipeline_for_transcription,
-            transcripts_for_transcription,
-            logs_for_transcription,
-            source_sample_rate_hz,
-            perf_enabled,
-            transcribe_rx,
+    let buffer = Arc::new(std::sync::Mutex::new(monitor::MonitorBuffer::new(
+        input_stream.sample_rate_hz,
+        monitor::MONITOR_MAX_LATENCY_MS,
+    )));
+    let output_stream = match audio::build_monitor_output_stream(
+        output_device_id.as_deref(),
+        buffer.clone(),
+        logs_path.clone(),
+    ) {
+        Ok(stream) => stream,
+        Err(error) => {
+            let _ = log_store::append(&logs_path, "error", "mic.monitor", &error);
+            emit_mic_monitor_state(&app, false);
+            return;
+        }
+    };
+    if let Ok(mut buffer) = buffer.lock() {
+        *buffer = monitor::MonitorBuffer::new(
+            output_stream.sample_rate_hz,
+            monitor::MONITOR_MAX_LATENCY_MS,
         );
-    });
+    }
 
-    let mic_gain = mic_sensitivity_gain(mic_sensitivity_percent);
-    let mut last_meter_emit_at = Instant::now() - Duration::from_secs(1);
-    let mut mic_level = 0f32;
-    let mut mic_peak = 0f32;
+    let _input_stream_guard = input_stream.stream;
+    let _output_stream_guard = output_stream.stream;
+    let mic_gain = mic_sensitivity_gain(mic.mic_sensitivity_percent);
+    let timing = live_session_timing(&app);
 
     loop {
         if stop_rx.try_recv().is_ok() {
             break;
         }
 
-        let mut frame = match capture_rx.recv_timeout(Duration::from_millis(FRAME_RECV_TIMEOUT_MS))
-        {
+        let mut frame = match capture_rx.recv_timeout(timing.frame_recv_timeout) {
             Ok(samples) => samples,
             Err(RecvTimeoutError::Timeout) => continue,
             Err(RecvTimeoutError::Disconnected) => break,
         };
 
         apply_mic_gain(&mut frame, mic_gain);
-
-        let measured = audio::measure_mic_level(&frame, mic_level, mic_peak);
-        mic_level = measured.level;
-        mic_peak = measured.peak;
-
-        if should_emit_meter_update(last_meter_emit_at.elapsed()) {
-            let _ = app.emit("dictation:mic-level", measured);
-            last_meter_emit_at = Instant::now();
+        let resampled = monitor::resample_linear(
+            &frame,
+            input_stream.sample_rate_hz,
+            output_stream.sample_rate_hz,
+        );
+        if let Ok(mut buffer) = buffer.lock() {
+            buffer.push(&resampled);
         }
-
-        let _ = transcribe_tx.try_send(frame);
     }
+}
 
-    drop(transcribe_tx);
-    let _ = transcription_worker.join();
+#[cfg(feature = "desktop")]
+fn stop_mic_monitor_internal(
+    app: &tauri::AppHandle,
+    store: &tauri::State<'_, PipelineStore>,
+) -> Result<bool, SonoraError> {
+    let session = {
+        let mut active_monitor = store
+            .mic_monitor
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("mic monitor"))?;
+        active_monitor.take()
+    };
 
-    let _ = app.emit(
-        "dictation:mic-level",
-        audio::MicLevel {
-            level: 0.0,
-            peak: 0.0,
-            active: false,
-        },
-    );
+    let stopped = session.map(LiveCaptureSession::stop).is_some();
+    emit_mic_monitor_state(app, false);
+    Ok(stopped)
 }
 
 #[cfg(feature = "desktop")]
@@ -1226,41 +6533,558 @@ fn reap_finished_live_capture(store: &tauri::State<'_, PipelineStore>) {
 fn stop_live_capture_internal(
     app: &tauri::AppHandle,
     store: &tauri::State<'_, PipelineStore>,
-) -> Result<bool, String> {
+) -> Result<bool, SonoraError> {
+    let session = {
+        let mut active_capture = store
+            .live_capture
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("live capture"))?;
+        active_capture.take()
+    };
+
+    if let Some(session) = session {
+        finish_live_capture(app, session);
+        Ok(true)
+    } else {
+        emit_live_mic_state(app, false);
+        Ok(false)
+    }
+}
+
+// Leaves any other session running, such as dictation started after the
+// capture with this id ended.
+#[cfg(feature = "desktop")]
+fn stop_live_capture_owned(
+    app: &tauri::AppHandle,
+    store: &tauri::State<'_, PipelineStore>,
+    capture_id: u64,
+) -> Result<bool, SonoraError> {
+    stop_live_capture_matching(app, store, |owner| owner.id == capture_id)
+}
+
+#[cfg(feature = "desktop")]
+fn stop_live_capture_matching(
+    app: &tauri::AppHandle,
+    store: &tauri::State<'_, PipelineStore>,
+    matches: impl Fn(&CaptureOwner) -> bool,
+) -> Result<bool, SonoraError> {
     let session = {
         let mut active_capture = store
             .live_capture
             .lock()
-            .map_err(|_| "failed to acquire live capture state".to_string())?;
-        active_capture.take()
-    };
+            .map_err(|_| SonoraError::state_unavailable("live capture"))?;
+        if active_capture
+            .as_ref()
+            .is_some_and(|session| matches(&session.owner))
+        {
+            active_capture.take()
+        } else {
+            None
+        }
+    };
+
+    match session {
+        Some(session) => {
+            finish_live_capture(app, session);
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
+
+#[cfg(feature = "desktop")]
+fn finish_live_capture(app: &tauri::AppHandle, session: LiveCaptureSession) {
+    session.stop();
+    emit_live_mic_state(app, false);
+    clear_obs_caption(app);
+    publish_to_viewer(app, viewer::ViewerUpdate::Clear);
+}
+
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase1_list_microphones(
+    app: tauri::AppHandle,
+    ranking: tauri::State<'_, MicRankingState>,
+) -> Result<Vec<audio::InputMicrophone>, SonoraError> {
+    instrument_command(&app, "phase1_list_microphones", || {
+        let microphones = audio::list_input_microphones().map_err(SonoraError::audio)?;
+        let snr_by_label = ranking
+            .snr_by_label
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("microphone ranking"))?;
+        Ok(rank_microphones(microphones, &snr_by_label))
+    })
+}
+
+#[cfg(feature = "desktop")]
+fn ensure_microphone_idle(store: &PipelineStore) -> Result<(), SonoraError> {
+    let capture_active = store
+        .live_capture
+        .lock()
+        .map_err(|_| SonoraError::state_unavailable("live capture"))?
+        .is_some()
+        || store
+            .mic_monitor
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("mic monitor"))?
+            .is_some();
+    if capture_active {
+        return Err(SonoraError::InvalidInput(
+            "stop live capture and the mic monitor before using the microphone".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(feature = "desktop")]
+fn rank_microphones(
+    mut microphones: Vec<audio::InputMicrophone>,
+    snr_by_label: &HashMap<String, Option<f32>>,
+) -> Vec<audio::InputMicrophone> {
+    if snr_by_label.is_empty() {
+        return microphones;
+    }
+    for microphone in &mut microphones {
+        microphone.snr_db = snr_by_label.get(&microphone.label).copied().flatten();
+    }
+    mic_ranking::rank_by_snr(&mut microphones, |microphone| microphone.snr_db);
+    if let Some(best) = microphones.first_mut() {
+        best.recommended = best.snr_db.is_some();
+    }
+    microphones
+}
+
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase1_probe_microphones(
+    app: tauri::AppHandle,
+    store: tauri::State<'_, PipelineStore>,
+) -> Result<(), SonoraError> {
+    instrument_command(&app, "phase1_probe_microphones", || {
+        ensure_microphone_idle(&store)?;
+
+        let microphones = audio::list_input_microphones().map_err(SonoraError::audio)?;
+        let app_for_worker = app.clone();
+        thread::spawn(move || {
+            let probe_duration = Duration::from_millis(mic_ranking::PROBE_DURATION_MS);
+            let snr_by_label = microphones
+                .iter()
+                .map(|microphone| {
+                    let snr_db =
+                        audio::capture_samples_16k(Some(&microphone.id), None, probe_duration)
+                            .ok()
+                            .and_then(|samples| mic_ranking::estimate_snr_db(&samples));
+                    (microphone.label.clone(), snr_db)
+                })
+                .collect::<HashMap<_, _>>();
+            let ranked = rank_microphones(microphones, &snr_by_label);
+
+            // The probe outlives the command, so the middleware cannot log it.
+            let logs = app_for_worker.state::<RuntimeLogState>();
+            let _ = log_store::append(
+                &logs.path,
+                "info",
+                "mic.ranking",
+                &format!(
+                    "probed {} microphones; best: {}",
+                    ranked.len(),
+                    ranked
+                        .iter()
+                        .find(|microphone| microphone.recommended)
+                        .map(|microphone| microphone.label.as_str())
+                        .unwrap_or("none")
+                ),
+            );
+            if let Ok(mut cached) = app_for_worker
+                .state::<MicRankingState>()
+                .snr_by_label
+                .lock()
+            {
+                *cached = snr_by_label;
+            }
+            let _ = emit_event(&app_for_worker, events::DICTATION_MIC_RANKING, ranked);
+        });
+        Ok(())
+    })
+}
+
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase1_calibrate_noise_profile(
+    app: tauri::AppHandle,
+    store: tauri::State<'_, PipelineStore>,
+    microphone_id: Option<String>,
+) -> Result<(), SonoraError> {
+    instrument_command(&app, "phase1_calibrate_noise_profile", || {
+        ensure_microphone_idle(&store)?;
+
+        let app_for_worker = app.clone();
+        thread::spawn(move || {
+            let payload = calibrate_noise_profile(&app_for_worker, microphone_id.as_deref());
+            let _ = emit_event(&app_for_worker, events::DICTATION_NOISE_PROFILE, payload);
+        });
+        Ok(())
+    })
+}
+
+#[cfg(feature = "desktop")]
+fn calibrate_noise_profile(
+    app: &tauri::AppHandle,
+    microphone_id: Option<&str>,
+) -> NoiseCalibrationPayload {
+    let key = noise_profile_key(microphone_id);
+    let input_channel = app
+        .state::<SettingsState>()
+        .settings
+        .lock()
+        .ok()
+        .and_then(|settings| mic_profile::resolve(&settings, Some(&key)).input_channel);
+    let outcome = audio::capture_samples_16k(
+        microphone_id,
+        input_channel,
+        Duration::from_millis(noise_profile::CALIBRATION_DURATION_MS),
+    )
+    .and_then(|samples| noise_profile::build_noise_profile(&key, &samples, current_unix_ms_u64()))
+    .map_err(SonoraError::audio)
+    .and_then(|profile| {
+        let frame_count = profile.frame_count;
+        let state = app.state::<NoiseProfileState>();
+        let mut profiles = state
+            .profiles
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("noise profiles"))?;
+        profiles.upsert(profile);
+        noise_profile::save(&state.path, &profiles).map_err(SonoraError::io)?;
+        Ok(frame_count)
+    });
+
+    let logs = app.state::<RuntimeLogState>();
+    let _ = match &outcome {
+        Ok(frame_count) => log_store::append(
+            &logs.path,
+            "info",
+            "noise.calibrate",
+            &format!("learned noise profile for '{key}' from {frame_count} frames"),
+        ),
+        Err(error) => log_store::append(&logs.path, "error", "noise.calibrate", &error.to_string()),
+    };
+
+    if outcome.is_ok() {
+        let settings_state = app.state::<SettingsState>();
+        let settings = settings_state.settings.lock().map(|value| value.clone());
+        if let Ok(settings) = settings {
+            let noise_profile =
+                noise_profile_for_settings(app, &settings, settings.microphone_id.as_deref());
+            let _ = app
+                .state::<PipelineStore>()
+                .pipeline
+                .call(move |pipeline| pipeline.set_noise_profile(noise_profile));
+        }
+    }
+
+    NoiseCalibrationPayload {
+        microphone_id: noise_profile::microphone_key(microphone_id),
+        frame_count: outcome.as_ref().ok().copied(),
+        error: outcome.err(),
+    }
+}
+
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase1_record_test_clip(
+    app: tauri::AppHandle,
+    store: tauri::State<'_, PipelineStore>,
+    settings_state: tauri::State<'_, SettingsState>,
+    seconds: u8,
+    microphone_id: Option<String>,
+    transcribe: Option<bool>,
+) -> Result<(), SonoraError> {
+    instrument_command(&app, "phase1_record_test_clip", || {
+        ensure_microphone_idle(&store)?;
+        let settings = settings_state
+            .settings
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("settings"))?
+            .clone();
+        let duration = Duration::from_secs(u64::from(mic_test::clamp_test_clip_seconds(seconds)));
+        let app_for_worker = app.clone();
+        thread::spawn(move || {
+            let payload = record_test_clip(
+                &app_for_worker,
+                &settings,
+                microphone_id.as_deref(),
+                duration,
+                transcribe.unwrap_or(false),
+            );
+            let _ = emit_event(&app_for_worker, events::DICTATION_TEST_CLIP, payload);
+        });
+        Ok(())
+    })
+}
+
+#[cfg(feature = "desktop")]
+fn record_test_clip(
+    app: &tauri::AppHandle,
+    settings: &AppSettings,
+    microphone_id: Option<&str>,
+    duration: Duration,
+    transcribe: bool,
+) -> TestClipPayload {
+    let mut payload = TestClipPayload {
+        stats: None,
+        transcript: None,
+        error: None,
+    };
+    let mic = mic_settings_for(settings, microphone_id);
+    let mut clip = match audio::capture_samples_16k(microphone_id, mic.input_channel, duration) {
+        Ok(samples) => samples,
+        Err(error) => {
+            payload.error = Some(SonoraError::audio(error));
+            return payload;
+        }
+    };
+    apply_mic_gain(&mut clip, mic_sensitivity_gain(mic.mic_sensitivity_percent));
+    payload.stats = Some(mic_test::measure_clip(&clip));
+
+    if transcribe {
+        match transcribe_with_fresh_engine(app, settings, &clip) {
+            Ok(transcript) => payload.transcript = Some(transcript),
+            Err(error) => payload.error = Some(error),
+        }
+    }
+    if let Ok(mut stored) = app.state::<MicTestState>().clip.lock() {
+        *stored = Some(clip);
+    }
+    payload
+}
+
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase1_get_reference_sentence(
+    app: tauri::AppHandle,
+    reference_id: Option<usize>,
+) -> Result<onboarding::ReferenceSentence, SonoraError> {
+    instrument_command(&app, "phase1_get_reference_sentence", || {
+        Ok(onboarding::reference_sentence(reference_id.unwrap_or(0)))
+    })
+}
+
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase1_score_test_dictation(
+    app: tauri::AppHandle,
+    store: tauri::State<'_, PipelineStore>,
+    settings_state: tauri::State<'_, SettingsState>,
+    reference_id: usize,
+    seconds: u8,
+    microphone_id: Option<String>,
+) -> Result<(), SonoraError> {
+    instrument_command(&app, "phase1_score_test_dictation", || {
+        ensure_microphone_idle(&store)?;
+        let settings = settings_state
+            .settings
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("settings"))?
+            .clone();
+        let reference = onboarding::reference_sentence(reference_id);
+        let duration = Duration::from_secs(u64::from(mic_test::clamp_test_clip_seconds(seconds)));
+        let app_for_worker = app.clone();
+        thread::spawn(move || {
+            let clip = record_test_clip(
+                &app_for_worker,
+                &settings,
+                microphone_id.as_deref(),
+                duration,
+                true,
+            );
+            let score = match (&clip.stats, &clip.transcript) {
+                (Some(stats), Some(transcript)) => Some(onboarding::score_dictation(
+                    reference.text,
+                    transcript,
+                    stats,
+                    settings.model_profile,
+                    settings.noise_suppression_enabled,
+                )),
+                _ => None,
+            };
+            let payload = TestScorePayload {
+                reference,
+                stats: clip.stats,
+                score,
+                error: clip.error,
+            };
+            let _ = emit_event(&app_for_worker, events::DICTATION_TEST_SCORE, payload);
+        });
+        Ok(())
+    })
+}
+
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase1_play_test_clip(
+    app: tauri::AppHandle,
+    mic_test_state: tauri::State<'_, MicTestState>,
+    logs: tauri::State<'_, RuntimeLogState>,
+    output_device_id: Option<String>,
+) -> Result<(), SonoraError> {
+    instrument_command(&app, "phase1_play_test_clip", || {
+        let clip = mic_test_state
+            .clip
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("mic test"))?
+            .clone()
+            .ok_or_else(|| SonoraError::NotFound("no test clip has been recorded".to_string()))?;
+        let logs_path = logs.path.clone();
+        // Playback outlives the command, so its failure is logged here.
+        thread::spawn(move || {
+            if let Err(error) = play_test_clip(output_device_id.as_deref(), &clip, &logs_path) {
+                let _ = log_store::append(&logs_path, "error", "mic.test_clip", &error);
+            }
+        });
+        Ok(())
+    })
+}
+
+#[cfg(feature = "desktop")]
+fn play_test_clip(
+    output_device_id: Option<&str>,
+    clip: &[f32],
+    logs_path: &Path,
+) -> Result<(), String> {
+    let clip_ms = (clip.len() as u64 * 1_000 / u64::from(audio::SAMPLE_RATE_HZ)) as u32;
+    let buffer = Arc::new(std::sync::Mutex::new(monitor::MonitorBuffer::new(
+        audio::SAMPLE_RATE_HZ,
+        clip_ms,
+    )));
+    let output_stream = audio::build_monitor_output_stream(
+        output_device_id,
+        buffer.clone(),
+        logs_path.to_path_buf(),
+    )?;
+    if let Ok(mut buffer) = buffer.lock() {
+        *buffer = monitor::MonitorBuffer::new(output_stream.sample_rate_hz, clip_ms + 500);
+        buffer.push(&monitor::resample_linear(
+            clip,
+            audio::SAMPLE_RATE_HZ,
+            output_stream.sample_rate_hz,
+        ));
+    }
+
+    let deadline = Instant::now() + Duration::from_millis(u64::from(clip_ms) + 2_000);
+    while Instant::now() < deadline {
+        let drained = buffer.lock().map(|value| value.is_empty()).unwrap_or(true);
+        if drained {
+            break;
+        }
+        thread::sleep(Duration::from_millis(CLIP_DRAIN_POLL_MS));
+    }
+    drop(output_stream.stream);
+    Ok(())
+}
+
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase1_list_output_devices(
+    app: tauri::AppHandle,
+) -> Result<Vec<audio::OutputDevice>, SonoraError> {
+    instrument_command(&app, "phase1_list_output_devices", || {
+        audio::list_output_devices().map_err(SonoraError::audio)
+    })
+}
+
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase1_start_mic_monitor(
+    app: tauri::AppHandle,
+    store: tauri::State<'_, PipelineStore>,
+    settings_state: tauri::State<'_, SettingsState>,
+    logs: tauri::State<'_, RuntimeLogState>,
+    microphone_id: Option<String>,
+    output_device_id: Option<String>,
+) -> Result<bool, SonoraError> {
+    instrument_command(&app, "phase1_start_mic_monitor", || {
+        let mut active_monitor = store
+            .mic_monitor
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("mic monitor"))?;
+        let is_running = active_monitor
+            .as_ref()
+            .and_then(|session| session.worker.as_ref())
+            .map(|worker| !worker.is_finished())
+            .unwrap_or(false);
+        if is_running {
+            emit_mic_monitor_state(&app, true);
+            return Ok(true);
+        }
+        if let Some(finished) = active_monitor.take() {
+            finished.stop();
+        }
+
+        let logs_path = logs.path.clone();
+        let app_for_worker = app.clone();
+        let settings = settings_state
+            .settings
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("settings"))?
+            .clone();
+        let selected_microphone = microphone_id
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty());
+        let mic = mic_settings_for(&settings, selected_microphone.as_deref());
+        let monitored_microphone = selected_microphone.clone();
+        let selected_output = output_device_id
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty());
+
+        let (stop_tx, stop_rx) = mpsc::channel::<()>();
+        let worker = thread::spawn(move || {
+            run_mic_monitor_session(
+                app_for_worker,
+                logs_path,
+                selected_microphone,
+                selected_output,
+                mic,
+                stop_rx,
+            );
+        });
+        *active_monitor = Some(LiveCaptureSession {
+            stop_tx,
+            worker: Some(worker),
+            microphone_id: monitored_microphone,
+            owner: CaptureOwner::next(CaptureMode::Monitor),
+        });
 
-    if let Some(session) = session {
-        session.stop();
-        emit_live_mic_state(app, false);
+        emit_mic_monitor_state(&app, true);
         Ok(true)
-    } else {
-        emit_live_mic_state(app, false);
-        Ok(false)
-    }
+    })
 }
 
 #[cfg(feature = "desktop")]
 #[tauri::command]
-fn phase1_list_microphones() -> Result<Vec<audio::InputMicrophone>, String> {
-    audio::list_input_microphones()
+fn phase1_stop_mic_monitor(
+    app: tauri::AppHandle,
+    store: tauri::State<'_, PipelineStore>,
+) -> Result<bool, SonoraError> {
+    instrument_command(&app, "phase1_stop_mic_monitor", || {
+        stop_mic_monitor_internal(&app, &store)
+    })
 }
 
 #[cfg(feature = "desktop")]
 #[tauri::command]
-fn phase1_get_live_capture_active(store: tauri::State<'_, PipelineStore>) -> Result<bool, String> {
-    reap_finished_live_capture(&store);
+fn phase1_get_live_capture_active(
+    app: tauri::AppHandle,
+    store: tauri::State<'_, PipelineStore>,
+) -> Result<bool, SonoraError> {
+    instrument_command(&app, "phase1_get_live_capture_active", || {
+        reap_finished_live_capture(&store);
 
-    let active_capture = store
-        .live_capture
-        .lock()
-        .map_err(|_| "failed to acquire live capture state".to_string())?;
-    Ok(active_capture.is_some())
+        let active_capture = store
+            .live_capture
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("live capture"))?;
+        Ok(active_capture.is_some())
+    })
 }
 
 #[cfg(feature = "desktop")]
@@ -1269,64 +7093,134 @@ fn phase1_start_live_capture(
     app: tauri::AppHandle,
     store: tauri::State<'_, PipelineStore>,
     settings_state: tauri::State<'_, SettingsState>,
-    logs: tauri::State<'_, RuntimeLogState>,
+    insertion_state: tauri::State<'_, InsertionState>,
     microphone_id: Option<String>,
-) -> Result<bool, String> {
-    reap_finished_live_capture(&store);
+    session_label: Option<String>,
+) -> Result<bool, SonoraError> {
+    instrument_command(&app, "phase1_start_live_capture", || {
+        reap_finished_live_capture(&store);
 
-    {
-        let active_capture = store
-            .live_capture
+        {
+            let active_capture = store
+                .live_capture
+                .lock()
+                .map_err(|_| SonoraError::state_unavailable("live capture"))?;
+            if active_capture.is_some() {
+                emit_live_mic_state(&app, true);
+                return Ok(true);
+            }
+        }
+
+        *insertion_state
+            .session_label
             .lock()
-            .map_err(|_| "failed to acquire live capture state".to_string())?;
-        if active_capture.is_some() {
-            emit_live_mic_state(&app, true);
-            return Ok(true);
+            .map_err(|_| SonoraError::state_unavailable("insertion"))? =
+            insertion::normalize_session_label(session_label.as_deref());
+
+        let shared = store.capture_shared();
+        {
+            let mut stats = shared
+                .session_stats
+                .lock()
+                .map_err(|_| SonoraError::state_unavailable("session stats"))?;
+            *stats = SessionStats::started_at(current_unix_ms_u64());
+        }
+        let settings = settings_state
+            .settings
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("settings"))?
+            .clone();
+        let meeting_gap_secs = settings
+            .meeting_transcript_enabled
+            .then_some(settings.meeting_paragraph_gap_secs);
+        {
+            let mut meeting = shared
+                .meeting_transcript
+                .lock()
+                .map_err(|_| SonoraError::state_unavailable("meeting transcript"))?;
+            *meeting = meeting_gap_secs.map(|gap_secs| {
+                MeetingTranscript::new(
+                    meeting::default_meeting_path(current_unix_ms_u64()),
+                    u64::from(gap_secs) * 1_000,
+                )
+            });
+        }
+        let selected_microphone = microphone_id
+            .map(|value| value.trim().to_string())
+            .and_then(|value| if value.is_empty() { None } else { Some(value) });
+        let started_rx = spawn_live_capture_worker(
+            &app,
+            &store,
+            &settings,
+            selected_microphone,
+            CaptureOwner::next(CaptureMode::Dictation),
+        )?;
+        // Opening a device normally answers within milliseconds; a backend that
+        // takes longer is treated as started and reports failures by event.
+        if let Ok(Err(report)) =
+            started_rx.recv_timeout(Duration::from_millis(LIVE_CAPTURE_OPEN_TIMEOUT_MS))
+        {
+            // A waiting session stays registered so a stop still cancels it.
+            if !report.waiting {
+                let _ = stop_live_capture_internal(&app, &store);
+            }
+            return Err(SonoraError::MicUnavailable(report));
         }
-    }
 
-    let pipeline = Arc::clone(&store.pipeline);
-    let last_transcript = Arc::clone(&store.last_transcript);
-    let logs_path = logs.path.clone();
-    let perf_enabled = logs.perf_enabled;
-    let app_for_worker = app.clone();
-    let mic_sensitivity_percent = settings_state
-        .settings
-        .lock()
-        .map_err(|_| "failed to acquire settings state".to_string())?
-        .mic_sensitivity_percent;
-    let selected_microphone = microphone_id
-        .map(|value| value.trim().to_string())
-        .and_then(|value| if value.is_empty() { None } else { Some(value) });
+        record_usage_session(&app);
+        emit_live_mic_state(&app, true);
+        Ok(true)
+    })
+}
+
+// Reuses the store's shared session state, so a restarted worker keeps the
+// running session stats and meeting transcript.
+#[cfg(feature = "desktop")]
+fn spawn_live_capture_worker(
+    app: &tauri::AppHandle,
+    store: &PipelineStore,
+    settings: &AppSettings,
+    microphone_id: Option<String>,
+    owner: CaptureOwner,
+) -> Result<Receiver<Result<(), mic_availability::MicUnavailable>>, SonoraError> {
+    let mic = mic_settings_for(settings, microphone_id.as_deref());
+    let noise_profile = noise_profile_for_settings(app, settings, microphone_id.as_deref());
+    let _ = store
+        .pipeline
+        .call(move |pipeline| pipeline.set_noise_profile(noise_profile));
 
+    let app_for_worker = app.clone();
+    let shared = CaptureShared {
+        mode: owner.mode,
+        ..store.capture_shared()
+    };
+    let selected_microphone = microphone_id.clone();
     let (stop_tx, stop_rx) = mpsc::channel::<()>();
+    let (started_tx, started_rx) = mpsc::sync_channel(1);
     let worker = thread::spawn(move || {
+        let sink = app_for_worker.clone();
         run_live_capture_session(
             app_for_worker,
-            pipeline,
-            last_transcript,
-            logs_path,
-            perf_enabled,
+            &sink,
+            shared,
             selected_microphone,
-            mic_sensitivity_percent,
+            mic,
             stop_rx,
+            started_tx,
         );
     });
 
-    {
-        let mut active_capture = store
-            .live_capture
-            .lock()
-            .map_err(|_| "failed to acquire live capture state".to_string())?;
-        *active_capture = Some(LiveCaptureSession {
-            stop_tx,
-            worker: Some(worker),
-        });
-    }
-
-    emit_live_mic_state(&app, true);
-    let _ = log_store::append(&logs.path, "info", "mic.capture", "live capture started");
-    Ok(true)
+    let mut active_capture = store
+        .live_capture
+        .lock()
+        .map_err(|_| SonoraError::state_unavailable("live capture"))?;
+    *active_capture = Some(LiveCaptureSession {
+        stop_tx,
+        worker: Some(worker),
+        microphone_id,
+        owner,
+    });
+    Ok(started_rx)
 }
 
 #[cfg(feature = "desktop")]
@@ -1334,84 +7228,275 @@ fn phase1_start_live_capture(
 fn phase1_stop_live_capture(
     app: tauri::AppHandle,
     store: tauri::State<'_, PipelineStore>,
-    logs: tauri::State<'_, RuntimeLogState>,
-) -> Result<bool, String> {
-    let stopped = stop_live_capture_internal(&app, &store)?;
-    if stopped {
-        let _ = log_store::append(&logs.path, "info", "mic.capture", "live capture stopped");
-    }
-    Ok(stopped)
+) -> Result<bool, SonoraError> {
+    instrument_command(&app, "phase1_stop_live_capture", || {
+        stop_live_capture_internal(&app, &store)
+    })
 }
 
 #[cfg(feature = "desktop")]
 #[tauri::command]
-fn phase1_get_status(store: tauri::State<'_, PipelineStore>) -> Result<PipelineStatus, String> {
-    let pipeline = store
-        .pipeline
-        .lock()
-        .map_err(|_| "failed to acquire pipeline state".to_string())?;
-    Ok(pipeline.status())
+fn phase1_get_status(
+    app: tauri::AppHandle,
+    store: tauri::State<'_, PipelineStore>,
+) -> Result<PipelineStatus, SonoraError> {
+    instrument_command(&app, "phase1_get_status", || {
+        store
+            .pipeline
+            .status()
+            .map_err(|_| SonoraError::state_unavailable("pipeline"))
+    })
+}
+
+// whisper.cpp spawns a process per utterance, so only a resident worker counts as loaded.
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase1_get_resource_status(
+    app: tauri::AppHandle,
+    store: tauri::State<'_, PipelineStore>,
+) -> Result<ResourceStatusPayload, SonoraError> {
+    instrument_command(&app, "phase1_get_resource_status", || {
+        reap_finished_live_capture(&store);
+        let capture_active = store
+            .live_capture
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("live capture"))?
+            .is_some();
+        let published = store
+            .pipeline
+            .published()
+            .map_err(|_| SonoraError::state_unavailable("pipeline"))?;
+        let worker_pid = published.worker_pid;
+
+        Ok(ResourceStatusPayload {
+            status: published.status,
+            engine: published.engine,
+            engine_loaded: worker_pid.is_some(),
+            capture_active,
+            worker_pid,
+            worker_restarts: published.worker_restarts,
+            worker_resident_bytes: worker_pid.and_then(process_memory::resident_memory_bytes),
+            worker_threads: worker_pid.and_then(process_memory::thread_count),
+            app_threads: process_memory::thread_count(std::process::id()),
+        })
+    })
 }
 
 #[cfg(feature = "desktop")]
 #[tauri::command]
 fn phase1_set_mode(
+    app: tauri::AppHandle,
     store: tauri::State<'_, PipelineStore>,
     mode: DictationMode,
-) -> Result<PipelineStatus, String> {
-    let mut pipeline = store
-        .pipeline
-        .lock()
-        .map_err(|_| "failed to acquire pipeline state".to_string())?;
-    pipeline.set_mode(mode);
+) -> Result<PipelineStatus, SonoraError> {
+    instrument_command(&app, "phase1_set_mode", || {
+        let status = store
+            .pipeline
+            .call(move |pipeline| {
+                pipeline.set_mode(mode);
+                pipeline.status()
+            })
+            .map_err(|_| SonoraError::state_unavailable("pipeline"))?;
+
+        let mut last_transcript = store
+            .last_transcript
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("transcript"))?;
+        *last_transcript = None;
 
-    let mut last_transcript = store
-        .last_transcript
-        .lock()
-        .map_err(|_| "failed to acquire transcript state".to_string())?;
-    *last_transcript = None;
+        Ok(status)
+    })
+}
 
-    Ok(pipeline.status())
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase1_set_session_vocabulary(
+    app: tauri::AppHandle,
+    terms: Vec<String>,
+    store: tauri::State<'_, PipelineStore>,
+) -> Result<Vec<String>, SonoraError> {
+    instrument_command(&app, "phase1_set_session_vocabulary", || {
+        let normalized = vocabulary::normalize_terms(terms);
+        *store
+            .session_vocabulary
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("vocabulary"))? = normalized.clone();
+        Ok(normalized)
+    })
 }
 
 #[cfg(feature = "desktop")]
 #[tauri::command]
-fn phase1_hotkey_down(store: tauri::State<'_, PipelineStore>) -> Result<PipelineStatus, String> {
-    let mut pipeline = store
-        .pipeline
-        .lock()
-        .map_err(|_| "failed to acquire pipeline state".to_string())?;
-    pipeline.on_hotkey_down();
-    Ok(pipeline.status())
+fn phase1_get_session_vocabulary(
+    app: tauri::AppHandle,
+    store: tauri::State<'_, PipelineStore>,
+) -> Result<Vec<String>, SonoraError> {
+    instrument_command(&app, "phase1_get_session_vocabulary", || {
+        Ok(store
+            .session_vocabulary
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("vocabulary"))?
+            .clone())
+    })
 }
 
 #[cfg(feature = "desktop")]
 #[tauri::command]
-fn phase1_hotkey_up(store: tauri::State<'_, PipelineStore>) -> Result<PipelineStatus, String> {
-    let mut pipeline = store
-        .pipeline
-        .lock()
-        .map_err(|_| "failed to acquire pipeline state".to_string())?;
-    pipeline.on_hotkey_up();
-    Ok(pipeline.status())
+fn phase1_take_pending_activation(
+    app: tauri::AppHandle,
+    activation_state: tauri::State<'_, ActivationState>,
+) -> Result<Option<activation::ActivationAction>, SonoraError> {
+    instrument_command(&app, "phase1_take_pending_activation", || {
+        activation_state
+            .frontend_ready
+            .store(true, Ordering::Relaxed);
+        Ok(activation_state
+            .pending
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("activation"))?
+            .take())
+    })
 }
 
 #[cfg(feature = "desktop")]
 #[tauri::command]
-fn phase1_cancel(store: tauri::State<'_, PipelineStore>) -> Result<PipelineStatus, String> {
-    let mut pipeline = store
-        .pipeline
-        .lock()
-        .map_err(|_| "failed to acquire pipeline state".to_string())?;
-    pipeline.cancel();
+fn phase1_hotkey_down(
+    app: tauri::AppHandle,
+    store: tauri::State<'_, PipelineStore>,
+    settings_state: tauri::State<'_, SettingsState>,
+    insertion_state: tauri::State<'_, InsertionState>,
+) -> Result<PipelineStatus, SonoraError> {
+    instrument_command(&app, "phase1_hotkey_down", || {
+        let (started_listening, status) = store
+            .pipeline
+            .call(|pipeline| {
+                let was_idle = pipeline.status().state == pipeline::DictationState::Idle;
+                pipeline.on_hotkey_down();
+                let status = pipeline.status();
+                (
+                    was_idle && status.state == pipeline::DictationState::Listening,
+                    status,
+                )
+            })
+            .map_err(|_| SonoraError::state_unavailable("pipeline"))?;
+
+        let focus_guard_enabled = settings_state
+            .settings
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("settings"))?
+            .focus_guard_enabled;
+        if started_listening && focus_guard_enabled {
+            remember_focus_target(&insertion_state)?;
+        }
 
-    let mut last_transcript = store
-        .last_transcript
-        .lock()
-        .map_err(|_| "failed to acquire transcript state".to_string())?;
-    *last_transcript = None;
+        Ok(status)
+    })
+}
+
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase1_hotkey_up(
+    app: tauri::AppHandle,
+    store: tauri::State<'_, PipelineStore>,
+) -> Result<PipelineStatus, SonoraError> {
+    instrument_command(&app, "phase1_hotkey_up", || {
+        store
+            .pipeline
+            .hotkey_up_now()
+            .map_err(|_| SonoraError::state_unavailable("pipeline"))
+    })
+}
+
+#[cfg(feature = "desktop")]
+fn validate_extra_hotkeys(settings: &AppSettings) -> Result<(), SonoraError> {
+    readiness::validate_hotkey(&settings.panic_hotkey)
+        .map_err(|error| SonoraError::InvalidInput(format!("panic {error}")))?;
+    if settings.panic_hotkey.eq_ignore_ascii_case(&settings.hotkey) {
+        return Err(SonoraError::InvalidInput(
+            "panic hotkey must differ from the dictation hotkey".to_string(),
+        ));
+    }
+    readiness::validate_hotkey(&settings.profile_toggle_hotkey)
+        .map_err(|error| SonoraError::InvalidInput(format!("profile toggle {error}")))?;
+    if [&settings.hotkey, &settings.panic_hotkey]
+        .iter()
+        .any(|other| settings.profile_toggle_hotkey.eq_ignore_ascii_case(other))
+    {
+        return Err(SonoraError::InvalidInput(
+            "profile toggle hotkey must differ from the dictation and panic hotkeys".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase1_panic(
+    app: tauri::AppHandle,
+    store: tauri::State<'_, PipelineStore>,
+    insertion_state: tauri::State<'_, InsertionState>,
+) -> Result<PanicPayload, SonoraError> {
+    instrument_command(&app, "phase1_panic", || {
+        store.panic_epoch.fetch_add(1, Ordering::SeqCst);
+        store
+            .pipeline
+            .cancel_now()
+            .map_err(|_| SonoraError::state_unavailable("pipeline"))?;
+        *store
+            .last_transcript
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("transcript"))? = None;
+        *insertion_state
+            .focus_target
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("focus target"))? = None;
+
+        // Joining the worker would wait for the current inference, so stop it off-thread.
+        let session = store
+            .live_capture
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("live capture"))?
+            .take();
+        let stopped_capture = session.is_some();
+        if let Some(session) = session {
+            thread::spawn(move || session.stop());
+        }
+        emit_live_mic_state(&app, false);
+
+        let payload = PanicPayload {
+            stopped_capture,
+            triggered_unix_ms: current_unix_ms_u64(),
+        };
+        command_metrics::note(
+            "warn",
+            format!("panic hotkey pressed; capture stopped: {stopped_capture}"),
+        );
+        let _ = emit_event(&app, events::DICTATION_PANIC, payload.clone());
+        announce_to_screen_reader(&app, announce::cancelled());
+        Ok(payload)
+    })
+}
 
-    Ok(pipeline.status())
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn phase1_cancel(
+    app: tauri::AppHandle,
+    store: tauri::State<'_, PipelineStore>,
+) -> Result<PipelineStatus, SonoraError> {
+    instrument_command(&app, "phase1_cancel", || {
+        let status = store
+            .pipeline
+            .cancel_now()
+            .map_err(|_| SonoraError::state_unavailable("pipeline"))?;
+
+        let mut last_transcript = store
+            .last_transcript
+            .lock()
+            .map_err(|_| SonoraError::state_unavailable("transcript"))?;
+        *last_transcript = None;
+
+        Ok(status)
+    })
 }
 
 #[cfg(feature = "desktop")]
@@ -1419,24 +7504,24 @@ fn phase1_cancel(store: tauri::State<'_, PipelineStore>) -> Result<PipelineStatu
 fn phase1_feed_audio(
     app: tauri::AppHandle,
     store: tauri::State<'_, PipelineStore>,
-    logs: tauri::State<'_, RuntimeLogState>,
     samples: Vec<f32>,
-) -> Result<Option<String>, String> {
-    let mut pipeline = store
-        .pipeline
-        .lock()
-        .map_err(|_| "failed to acquire pipeline state".to_string())?;
-    let raw_transcript = pipeline.process_audio_chunk(&samples)?;
-    drop(pipeline);
-
-    emit_transcript_if_fresh(
-        &app,
-        &logs.path,
-        &store.last_transcript,
-        raw_transcript,
-        None,
-        None,
-    )
+) -> Result<Option<String>, SonoraError> {
+    instrument_command(&app, "phase1_feed_audio", || {
+        let raw_transcript = store
+            .pipeline
+            .call(move |pipeline| pipeline.process_audio_chunk(&samples))
+            .map_err(|_| SonoraError::state_unavailable("pipeline"))?
+            .map_err(SonoraError::transcription)?;
+
+        emit_transcript_if_fresh(
+            &app,
+            &EmitContext::from_app(&app),
+            raw_transcript,
+            None,
+            None,
+            store.active_language.lock().ok().map(|value| value.clone()),
+        )
+    })
 }
 
 #[cfg(all(test, feature = "desktop"))]
@@ -1465,18 +7550,52 @@ mod tests {
     #[test]
     fn selects_fresh_transcript_once() {
         let mut last = None;
-
-        let first = select_fresh_transcript(&mut last, Some("  hello   world  ".to_string()));
+        let mut repeats = RepeatGuard::default();
+        let policy = DuplicateSuppression::default();
+
+        let first = select_fresh_transcript(
+            &mut last,
+            &mut repeats,
+            policy,
+            Some("  hello   world  ".to_string()),
+            NormalizationProfile::Latin,
+            TranscriptStage::Final,
+            &[],
+        );
         assert_eq!(first.as_deref(), Some("Hello world."));
         assert_eq!(last.as_deref(), Some("Hello world."));
 
-        let duplicate = select_fresh_transcript(&mut last, Some("hello world.".to_string()));
+        let duplicate = select_fresh_transcript(
+            &mut last,
+            &mut repeats,
+            policy,
+            Some("hello world.".to_string()),
+            NormalizationProfile::Latin,
+            TranscriptStage::Final,
+            &[],
+        );
         assert!(duplicate.is_none());
 
-        let empty = select_fresh_transcript(&mut last, Some("   ".to_string()));
+        let empty = select_fresh_transcript(
+            &mut last,
+            &mut repeats,
+            policy,
+            Some("   ".to_string()),
+            NormalizationProfile::Latin,
+            TranscriptStage::Final,
+            &[],
+        );
         assert!(empty.is_none());
 
-        let absent = select_fresh_transcript(&mut last, None);
+        let absent = select_fresh_transcript(
+            &mut last,
+            &mut repeats,
+            policy,
+            None,
+            NormalizationProfile::Latin,
+            TranscriptStage::Final,
+            &[],
+        );
         assert!(absent.is_none());
     }
 
@@ -1499,8 +7618,10 @@ mod tests {
 
         let session_id = pending.as_ref().map(|value| value.session_id);
         assert_eq!(session_id, Some(1));
+        let started_unix_ms = pending.as_ref().map(|value| value.started_unix_ms);
+        assert_eq!(started_unix_ms, Some(1_000));
 
-        let text = take_pending_utterance(&mut pending);
+        let text = take_pending_utterance(&mut pending, NormalizationProfile::Latin, &[]);
         assert_eq!(
             text.as_deref(),
             Some("At 7:45 a.m. I walked three blocks to Maple Street.")
@@ -1547,7 +7668,12 @@ mod tests {
     #[test]
     fn chunk_plan_requires_listening_state() {
         let status = pipeline_status(DictationState::Idle, 32_000, 1_400);
-        let plan = plan_live_capture_chunk(&status, 64_000, Duration::from_secs(3));
+        let plan = plan_live_capture_chunk(
+            &status,
+            64_000,
+            Duration::from_secs(3),
+            speech_rate::CadenceAdjustment::NEUTRAL,
+        );
         assert!(plan.is_none());
     }
 
@@ -1555,14 +7681,29 @@ mod tests {
     fn chunk_plan_respects_minimum_and_cadence() {
         let status = pipeline_status(DictationState::Listening, 32_000, 1_400);
 
-        let too_small = plan_live_capture_chunk(&status, 31_999, Duration::from_secs(3));
+        let too_small = plan_live_capture_chunk(
+            &status,
+            31_999,
+            Duration::from_secs(3),
+            speech_rate::CadenceAdjustment::NEUTRAL,
+        );
         assert!(too_small.is_none());
 
-        let too_soon = plan_live_capture_chunk(&status, 32_000, Duration::from_millis(1_000));
+        let too_soon = plan_live_capture_chunk(
+            &status,
+            32_000,
+            Duration::from_millis(1_000),
+            speech_rate::CadenceAdjustment::NEUTRAL,
+        );
         assert!(too_soon.is_none());
 
-        let ready = plan_live_capture_chunk(&status, 80_000, Duration::from_millis(1_600))
-            .expect("chunk should be planned");
+        let ready = plan_live_capture_chunk(
+            &status,
+            80_000,
+            Duration::from_millis(1_600),
+            speech_rate::CadenceAdjustment::NEUTRAL,
+        )
+        .expect("chunk should be planned");
         assert_eq!(ready.max_chunk_samples, 96_000);
         assert_eq!(ready.next_chunk_size, 80_000);
     }
@@ -1570,12 +7711,66 @@ mod tests {
     #[test]
     fn chunk_plan_caps_chunk_size_by_maximum() {
         let status = pipeline_status(DictationState::Listening, 32_000, 1_400);
-        let plan = plan_live_capture_chunk(&status, 150_000, Duration::from_secs(2))
-            .expect("chunk should be planned");
+        let plan = plan_live_capture_chunk(
+            &status,
+            150_000,
+            Duration::from_secs(2),
+            speech_rate::CadenceAdjustment::NEUTRAL,
+        )
+        .expect("chunk should be planned");
         assert_eq!(plan.max_chunk_samples, 96_000);
         assert_eq!(plan.next_chunk_size, 96_000);
     }
 
+    #[test]
+    fn chunk_plan_follows_speech_rate_adjustment() {
+        let status = pipeline_status(DictationState::Listening, 32_000, 1_400);
+
+        let continuous = speech_rate::CadenceAdjustment::CONTINUOUS;
+        let waiting =
+            plan_live_capture_chunk(&status, 40_000, Duration::from_millis(1_600), continuous);
+        assert!(waiting.is_none());
+        let longer = plan_live_capture_chunk(&status, 200_000, Duration::from_secs(2), continuous)
+            .expect("chunk should be planned");
+        assert_eq!(longer.min_chunk_samples, 48_000);
+        assert_eq!(longer.max_chunk_samples, 144_000);
+
+        let bursty = speech_rate::CadenceAdjustment::BURSTY;
+        let sooner = plan_live_capture_chunk(&status, 24_000, Duration::from_millis(1_000), bursty)
+            .expect("chunk should be planned");
+        assert_eq!(sooner.next_chunk_size, 24_000);
+    }
+
+    #[test]
+    fn chunk_plan_aligns_to_latest_silence_gap() {
+        let plan = LiveCaptureChunkPlan {
+            next_chunk_size: 64_000,
+            min_chunk_samples: 32_000,
+            max_chunk_samples: 96_000,
+        };
+        let segments = vec![
+            vad::SpeechSegment {
+                start_sample: 0,
+                end_sample: 40_000,
+            },
+            vad::SpeechSegment {
+                start_sample: 42_000,
+                end_sample: 64_000,
+            },
+        ];
+
+        let aligned = align_chunk_plan_to_phrase_boundary(plan, &segments);
+        assert_eq!(aligned.next_chunk_size, 41_000);
+        assert_eq!(aligned.max_chunk_samples, 96_000);
+
+        let continuous = vec![vad::SpeechSegment {
+            start_sample: 0,
+            end_sample: 64_000,
+        }];
+        let unchanged = align_chunk_plan_to_phrase_boundary(plan, &continuous);
+        assert_eq!(unchanged.next_chunk_size, 64_000);
+    }
+
     #[test]
     fn trims_pending_backlog_to_bounded_limit() {
         let mut pending = (0..80).map(|value| value as f32).collect::<VecDeque<_>>();
@@ -1604,16 +7799,100 @@ mod tests {
     }
 
     #[test]
-    fn meter_emit_interval_matches_smooth_ui_target() {
-        assert!(!should_emit_meter_update(Duration::from_millis(20)));
-        assert!(should_emit_meter_update(Duration::from_millis(33)));
-        assert!(should_emit_meter_update(Duration::from_millis(45)));
+    fn worker_state_events_reach_any_event_sink() {
+        let sink = events::MemorySink::default();
+        emit_live_mic_state(&sink, true);
+        emit_playback_guard(&sink, config::PlaybackGuard::Pause, true);
+
+        assert_eq!(
+            sink.payloads(events::DICTATION_LIVE_MIC)[0]["active"],
+            serde_json::json!(true)
+        );
+        let guard = &sink.payloads(events::DICTATION_PLAYBACK_GUARD)[0];
+        assert_eq!(guard["guard"], serde_json::json!("pause"));
+        assert_eq!(guard["engaged"], serde_json::json!(true));
+    }
+
+    #[test]
+    fn worker_emit_path_reaches_a_memory_sink() {
+        let sink = events::MemorySink::default();
+        let logs_path = std::env::temp_dir().join("sonora-worker-emit-test.log");
+        let last_transcript = Arc::new(StateMutex::new(None));
+        let context = EmitContext {
+            logs_path: logs_path.clone(),
+            last_transcript: Arc::clone(&last_transcript),
+            duplicate_policy: DuplicateSuppression::default(),
+            vocabulary_terms: Vec::new(),
+            captions_enabled: true,
+            caption_line_chars: 32,
+            instant_words: false,
+            viewer: None,
+            repeat_guard: Arc::default(),
+        };
+        let mut last_partial = None;
+        let mut next_session_id = 0;
+        let mut pending = None;
+        assert!(upsert_pending_utterance(
+            &mut pending,
+            Some("hello there".to_string()),
+            1_000,
+            &mut next_session_id,
+        ));
+        let utterance = pending.as_mut().expect("pending utterance");
+        let correlation = TranscriptCorrelation {
+            chunk_id: 1,
+            emitted_unix_ms: 1_000,
+        };
+
+        let emitted = emit_utterance_update(
+            &sink,
+            &context,
+            utterance,
+            &mut last_partial,
+            correlation,
+            "en".to_string(),
+            CaptureMode::Dictation,
+        );
+        assert!(emitted.is_some());
+        emit_final_transcript(
+            &sink,
+            &context,
+            utterance.session_id,
+            "Hello there.",
+            Some("en".to_string()),
+            None,
+        );
+
+        let transcripts = sink.payloads(events::DICTATION_TRANSCRIPT);
+        assert_eq!(transcripts.len(), 2);
+        assert_eq!(transcripts[0]["is_final"], serde_json::json!(false));
+        assert_eq!(transcripts[1]["text"], serde_json::json!("Hello there."));
+        assert_eq!(sink.payloads(events::DICTATION_CAPTION).len(), 1);
+        assert_eq!(
+            *last_transcript.lock().expect("last transcript"),
+            Some("Hello there.".to_string())
+        );
+
+        // A recording session keeps its partials out of the dictation events.
+        let recording = events::MemorySink::default();
+        emit_utterance_update(
+            &recording,
+            &context,
+            utterance,
+            &mut last_partial,
+            correlation,
+            "en".to_string(),
+            CaptureMode::Recording,
+        );
+        assert!(recording.take().is_empty());
+        let _ = std::fs::remove_file(logs_path);
     }
 }
 
 #[cfg(feature = "desktop")]
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
+    let startup_origin = Instant::now();
     let settings_path = settings_store::default_settings_path();
     let settings = settings_store::load_or_default(&settings_path);
     let logs_path = log_store::default_log_path();
@@ -1634,45 +7913,83 @@ pub fn run() {
         );
     }
 
+    let insertion_history_path = insertion::default_history_path();
+    let recent_insertions = insertion::load_recent_or_default(
+        &insertion_history_path,
+        usize::from(settings.max_recent_insertions),
+    );
+    let usage_path = usage::default_usage_path();
+    let usage_pattern = usage::load_or_default(&usage_path);
+    let jobs_path = jobs::default_jobs_path();
+    let job_history = jobs::load_jobs_or_default(&jobs_path);
+    let utterances_path = utterance_history::default_history_path();
+    let utterances = utterance_history::load_or_default(&utterances_path);
+    let corrections_path = corrections::default_corrections_path();
+    let correction_store = corrections::load_or_default(&corrections_path);
+    let noise_profiles_path = noise_profile::default_noise_profiles_path();
+    let noise_profiles = noise_profile::load_or_default(&noise_profiles_path);
     let pipeline_store = PipelineStore::new(&settings);
+    let mut startup_timings = StartupTimings::default();
+    startup_timings.record("state.load", 0, startup_origin.elapsed().as_millis() as u64);
 
     tauri::Builder::default()
         .manage(pipeline_store)
         .manage(SettingsState::new(settings, settings_path))
-        .manage(InsertionState::default())
-        .manage(RuntimeLogState::new(logs_path, perf_enabled))
+        .manage(InsertionState::new(
+            insertion_history_path,
+            recent_insertions,
+        ))
+        .manage(RuntimeLogState::new(
+            logs_path,
+            perf_enabled,
+            perf_trace::default_trace_path(),
+        ))
         .manage(RecoveryState::new(recovery_path, current_checkpoint))
+        .manage(JobState::new(jobs_path, job_history))
+        .manage(CorrectionState::new(corrections_path, correction_store))
+        .manage(UtteranceState::new(utterances_path, utterances))
+        .manage(ActivationState {
+            review_token: activation::new_review_token(),
+            ..ActivationState::default()
+        })
+        .manage(MicRankingState::default())
+        .manage(MicTestState::default())
+        .manage(TranscriptViewerState::default())
+        .manage(ModelConversionState::default())
+        .manage(FocusWatchState::default())
+        .manage(ReviewQueueState::default())
+        .manage(NoiseProfileState::new(noise_profiles_path, noise_profiles))
+        .manage(UsageState::new(
+            usage_path,
+            usage_pattern,
+            current_unix_ms_u64(),
+        ))
+        .manage(CommandMetricsState::new(startup_origin, startup_timings))
         .setup(|app| {
-            let settings_state = app.state::<SettingsState>();
-            let pipeline_state = app.state::<PipelineStore>();
-            if let Ok(current_settings) = settings_state.settings.lock().map(|value| value.clone())
-            {
-                let status = apply_runtime_transcriber_from_settings(
-                    &app.handle(),
-                    &current_settings,
-                    &pipeline_state,
-                );
-
-                let logs_state = app.state::<RuntimeLogState>();
-                match status {
-                    Ok(status) => {
-                        let _ = log_store::append(
-                            &logs_state.path,
-                            if status.ready { "info" } else { "warn" },
-                            "transcriber.setup",
-                            &status.description,
-                        );
-                    }
-                    Err(error) => {
-                        let _ = log_store::append(
-                            &logs_state.path,
-                            "error",
-                            "transcriber.setup",
-                            &error,
-                        );
-                    }
-                }
-            }
+            let setup_started = Instant::now();
+            install_poison_reporter(app.handle().clone());
+            // Engine construction and model warm-up can take seconds; keep them off the
+            // setup hook so the window shows immediately.
+            spawn_engine_initialization(app.handle().clone());
+
+            register_external_commands(app.handle());
+            #[cfg(target_os = "linux")]
+            start_dbus_service(app.handle());
+
+            spawn_drop_folder_worker(app.handle().clone());
+            spawn_model_scheduler(app.handle().clone());
+            spawn_model_conversion_worker(app.handle().clone());
+            spawn_focus_watcher(app.handle().clone());
+            spawn_resume_watcher(app.handle().clone());
+            spawn_screen_recording_watcher(app.handle().clone());
+            spawn_retention_worker(app.handle().clone());
+            let viewer_settings = app
+                .state::<SettingsState>()
+                .settings
+                .lock()
+                .map(|settings| settings.clone())
+                .unwrap_or_default();
+            sync_transcript_viewer(app.handle(), &viewer_settings);
 
             let logs_state = app.state::<RuntimeLogState>();
             if logs_state.perf_enabled {
@@ -1684,6 +8001,7 @@ pub fn run() {
                 );
             }
 
+            record_startup_phase(app.handle(), "setup", setup_started);
             Ok(())
         })
         .on_window_event(|window, event| {
@@ -1693,34 +8011,101 @@ pub fn run() {
 
                 let pipeline = window.app_handle().state::<PipelineStore>();
                 let _ = stop_live_capture_internal(&window.app_handle(), &pipeline);
+                let _ = stop_mic_monitor_internal(window.app_handle(), &pipeline);
             }
         })
+        .plugin(tauri_plugin_single_instance::init(|app, argv, _cwd| {
+            handle_second_instance(app, argv);
+        }))
+        .plugin(tauri_plugin_deep_link::init())
         .plugin(tauri_plugin_opener::init())
         .invoke_handler(tauri::generate_handler![
             get_default_settings,
             health_check,
             phase1_get_status,
+            phase1_get_resource_status,
             phase1_set_mode,
             phase1_hotkey_down,
+            phase1_take_pending_activation,
+            phase1_calibrate_noise_profile,
+            phase1_probe_microphones,
+            phase1_record_test_clip,
+            phase1_get_reference_sentence,
+            phase1_score_test_dictation,
+            phase1_play_test_clip,
+            phase1_set_session_vocabulary,
+            phase1_get_session_vocabulary,
             phase1_hotkey_up,
+            phase1_panic,
             phase1_cancel,
             phase1_list_microphones,
             phase1_get_live_capture_active,
             phase1_start_live_capture,
             phase1_stop_live_capture,
+            phase1_list_output_devices,
+            phase1_start_mic_monitor,
+            phase1_stop_mic_monitor,
+            phase1_set_utterance_language,
+            phase1_get_active_language,
             phase1_feed_audio,
             phase2_get_settings,
             phase2_update_settings,
+            phase2_save_microphone_profile,
+            phase2_list_language_packs,
+            phase2_get_spoken_phrases,
+            phase2_apply_language_pack,
             phase2_get_recent_insertions,
+            phase2_search_transcripts,
+            phase2_get_session_labels,
+            phase2_get_utterances,
+            phase2_get_utterance,
+            phase2_export_utterance_audio,
+            phase2_apply_retention,
+            phase2_purge_history,
             phase2_insert_text,
+            phase2_retry_insertion,
+            phase2_list_review_queue,
+            phase2_approve_review_item,
+            phase2_discard_review_item,
+            phase2_submit_correction,
+            phase2_get_corrections,
+            phase2_remove_correction_rule,
+            phase2_get_insertion_target,
+            phase2_set_insertion_target,
+            phase2_copy_transcript_to_clipboard,
+            phase2_copy_last_transcript,
+            phase2_get_clipboard_managers,
+            phase2_probe_insertion_capabilities,
+            phase2_get_focused_app,
             phase3_get_hardware_profile,
             phase3_auto_select_profile,
+            phase3_toggle_model_profile,
             phase3_get_model_status,
             phase3_set_model_path,
+            phase3_prune_model_cache,
+            phase3_list_model_aliases,
+            phase3_get_model_conversion,
+            phase3_convert_model,
             phase4_get_environment_health,
+            phase4_get_offline_status,
+            phase4_get_event_schema,
             phase4_get_runtime_logs,
             phase4_clear_runtime_logs,
+            phase4_get_command_metrics,
+            phase4_get_startup_timings,
             phase4_get_transcriber_status,
+            phase4_get_session_stats,
+            phase4_get_transcript_viewer,
+            phase4_start_live_captions,
+            phase4_stop_live_captions,
+            phase4_get_meeting_transcript_path,
+            phase4_transcribe_file,
+            phase4_get_sidecar_memory,
+            phase4_get_shadow_comparisons,
+            phase4_export_support_snapshot,
+            phase4_replay_support_snapshot,
+            phase4_get_transcription_jobs,
+            phase4_clear_transcription_jobs,
             phase4_perf_mark_ui_transcript_received,
             phase4_get_recovery_checkpoint,
             phase4_acknowledge_recovery_notice,
@@ -0,0 +1,171 @@
+// Offsets are in seconds east of UTC and come from the OS time zone rules, so
+// they follow daylight saving time for the instant asked about.
+pub fn utc_offset_secs(unix_ms: u64) -> i64 {
+    let unix_secs = i64::try_from(unix_ms / 1_000).unwrap_or(i64::MAX);
+    platform::utc_offset_secs(unix_secs).unwrap_or(0)
+}
+
+// The same instant on the local wall clock, for bucketing by local hour or day.
+pub fn local_unix_ms(unix_ms: u64) -> u64 {
+    shift(unix_ms, utc_offset_secs(unix_ms))
+}
+
+pub fn shift(unix_ms: u64, offset_secs: i64) -> u64 {
+    let offset_ms = offset_secs.saturating_mul(1_000);
+    if offset_ms >= 0 {
+        unix_ms.saturating_add(offset_ms.unsigned_abs())
+    } else {
+        unix_ms.saturating_sub(offset_ms.unsigned_abs())
+    }
+}
+
+// +02:00, -05:30 or +00:00.
+pub fn format_offset(offset_secs: i64) -> String {
+    let sign = if offset_secs < 0 { '-' } else { '+' };
+    let minutes = offset_secs.unsigned_abs() / 60;
+    format!("{sign}{:02}:{:02}", minutes / 60, minutes % 60)
+}
+
+#[cfg(unix)]
+mod platform {
+    use std::os::raw::{c_char, c_int, c_long};
+
+    #[repr(C)]
+    struct Tm {
+        tm_sec: c_int,
+        tm_min: c_int,
+        tm_hour: c_int,
+        tm_mday: c_int,
+        tm_mon: c_int,
+        tm_year: c_int,
+        tm_wday: c_int,
+        tm_yday: c_int,
+        tm_isdst: c_int,
+        tm_gmtoff: c_long,
+        tm_zone: *const c_char,
+    }
+
+    extern "C" {
+        fn tzset();
+        fn localtime_r(time: *const c_long, result: *mut Tm) -> *mut Tm;
+    }
+
+    pub fn utc_offset_secs(unix_secs: i64) -> Option<i64> {
+        let time = c_long::try_from(unix_secs).ok()?;
+        let mut tm = Tm {
+            tm_sec: 0,
+            tm_min: 0,
+            tm_hour: 0,
+            tm_mday: 0,
+            tm_mon: 0,
+            tm_year: 0,
+            tm_wday: 0,
+            tm_yday: 0,
+            tm_isdst: 0,
+            tm_gmtoff: 0,
+            tm_zone: std::ptr::null(),
+        };
+        // SAFETY: `time` and `tm` are valid for the call, and localtime_r only
+        // writes into `tm`. tzset picks up a changed TZ or zone file.
+        let converted = unsafe {
+            tzset();
+            localtime_r(&time, &mut tm)
+        };
+        (!converted.is_null()).then_some(tm.tm_gmtoff as i64)
+    }
+}
+
+#[cfg(windows)]
+mod platform {
+    use std::ffi::c_void;
+
+    // Seconds between 1601-01-01 (FILETIME) and 1970-01-01.
+    const FILETIME_UNIX_EPOCH_SECS: i64 = 11_644_473_600;
+    const FILETIME_TICKS_PER_SEC: i64 = 10_000_000;
+
+    #[repr(C)]
+    #[derive(Default)]
+    struct FileTime {
+        low: u32,
+        high: u32,
+    }
+
+    #[repr(C)]
+    #[derive(Default)]
+    struct SystemTime {
+        year: u16,
+        month: u16,
+        day_of_week: u16,
+        day: u16,
+        hour: u16,
+        minute: u16,
+        second: u16,
+        milliseconds: u16,
+    }
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn FileTimeToSystemTime(file_time: *const FileTime, system_time: *mut SystemTime) -> i32;
+        fn SystemTimeToTzSpecificLocalTime(
+            time_zone: *const c_void,
+            universal: *const SystemTime,
+            local: *mut SystemTime,
+        ) -> i32;
+        fn SystemTimeToFileTime(system_time: *const SystemTime, file_time: *mut FileTime) -> i32;
+    }
+
+    fn ticks(file_time: &FileTime) -> i64 {
+        ((i64::from(file_time.high)) << 32) | i64::from(file_time.low)
+    }
+
+    // Converts the instant with the active time zone and reads back the
+    // difference; a null zone means the current one, DST rules included.
+    pub fn utc_offset_secs(unix_secs: i64) -> Option<i64> {
+        let utc_ticks = unix_secs
+            .checked_add(FILETIME_UNIX_EPOCH_SECS)?
+            .checked_mul(FILETIME_TICKS_PER_SEC)?;
+        let utc_file_time = FileTime {
+            low: utc_ticks as u32,
+            high: (utc_ticks >> 32) as u32,
+        };
+        let mut utc = SystemTime::default();
+        let mut local = SystemTime::default();
+        let mut local_file_time = FileTime::default();
+        // SAFETY: every pointer refers to a live local of the expected layout.
+        let converted = unsafe {
+            FileTimeToSystemTime(&utc_file_time, &mut utc) != 0
+                && SystemTimeToTzSpecificLocalTime(std::ptr::null(), &utc, &mut local) != 0
+                && SystemTimeToFileTime(&local, &mut local_file_time) != 0
+        };
+        converted.then(|| (ticks(&local_file_time) - utc_ticks) / FILETIME_TICKS_PER_SEC)
+    }
+}
+
+#[cfg(not(any(unix, windows)))]
+mod platform {
+    pub fn utc_offset_secs(_unix_secs: i64) -> Option<i64> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_and_applies_offsets() {
+        assert_eq!(format_offset(0), "+00:00");
+        assert_eq!(format_offset(2 * 3_600), "+02:00");
+        assert_eq!(format_offset(-(5 * 3_600 + 30 * 60)), "-05:30");
+        assert_eq!(shift(10_000, -5), 5_000);
+        assert_eq!(shift(1_000, -5), 0);
+
+        // Real zones sit within UTC-12 and UTC+14.
+        let offset = utc_offset_secs(1_700_000_000_000);
+        assert!((-12 * 3_600..=14 * 3_600).contains(&offset), "{offset}");
+        assert_eq!(
+            local_unix_ms(1_700_000_000_000),
+            shift(1_700_000_000_000, offset)
+        );
+    }
+}
@@ -2,5 +2,10 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
 fn main() {
+    // The app re-executes itself with this flag to host the transcriber.
+    if std::env::args().nth(1).as_deref() == Some(sonora_dictation_lib::transcriber_host::HOST_ARG)
+    {
+        std::process::exit(sonora_dictation_lib::transcriber_host::run_from_stdio());
+    }
     sonora_dictation_lib::run()
 }
@@ -0,0 +1,231 @@
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use crate::local_time;
+
+#[derive(Debug)]
+pub struct MeetingTranscript {
+    path: PathBuf,
+    paragraph_gap_ms: u64,
+    last_utterance_end_ms: Option<u64>,
+    // Set for transcripts that accompany a recording: paragraphs are stamped
+    // with the offset from its start so they line up with the video.
+    origin_unix_ms: Option<u64>,
+}
+
+impl MeetingTranscript {
+    pub fn new(path: PathBuf, paragraph_gap_ms: u64) -> Self {
+        Self {
+            path,
+            paragraph_gap_ms,
+            last_utterance_end_ms: None,
+            origin_unix_ms: None,
+        }
+    }
+
+    pub fn anchored(path: PathBuf, paragraph_gap_ms: u64, origin_unix_ms: u64) -> Self {
+        Self {
+            origin_unix_ms: Some(origin_unix_ms),
+            ..Self::new(path, paragraph_gap_ms)
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn append_utterance(
+        &mut self,
+        text: &str,
+        started_unix_ms: u64,
+        ended_unix_ms: u64,
+    ) -> Result<(), String> {
+        let Some(chunk) = self.next_chunk(text, started_unix_ms, ended_unix_ms) else {
+            return Ok(());
+        };
+
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent).map_err(io_to_string)?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(io_to_string)?;
+        file.write_all(chunk.as_bytes()).map_err(io_to_string)?;
+        file.sync_data().map_err(io_to_string)
+    }
+
+    fn next_chunk(
+        &mut self,
+        text: &str,
+        started_unix_ms: u64,
+        ended_unix_ms: u64,
+    ) -> Option<String> {
+        let text = text.trim();
+        if text.is_empty() {
+            return None;
+        }
+
+        let previous_end = self.last_utterance_end_ms;
+        self.last_utterance_end_ms = Some(ended_unix_ms.max(started_unix_ms));
+
+        if !starts_new_paragraph(previous_end, started_unix_ms, self.paragraph_gap_ms) {
+            return Some(format!(" {text}"));
+        }
+
+        let separator = if previous_end.is_some() { "\n\n" } else { "" };
+        let stamp = match self.origin_unix_ms {
+            Some(origin) => format_offset(started_unix_ms.saturating_sub(origin)),
+            None => format_wall_clock_local(started_unix_ms),
+        };
+        Some(format!("{separator}[{stamp}] {text}"))
+    }
+}
+
+pub fn starts_new_paragraph(
+    previous_end_unix_ms: Option<u64>,
+    started_unix_ms: u64,
+    paragraph_gap_ms: u64,
+) -> bool {
+    match previous_end_unix_ms {
+        Some(previous_end) => started_unix_ms.saturating_sub(previous_end) > paragraph_gap_ms,
+        None => true,
+    }
+}
+
+// Meeting notes are read against the room's clock, so paragraphs carry local
+// time with its offset.
+pub fn format_wall_clock_local(unix_ms: u64) -> String {
+    format_wall_clock_at(unix_ms, local_time::utc_offset_secs(unix_ms))
+}
+
+pub fn format_wall_clock_at(unix_ms: u64, offset_secs: i64) -> String {
+    let seconds_of_day = (local_time::shift(unix_ms, offset_secs) / 1_000) % 86_400;
+    format!(
+        "{:02}:{:02}:{:02} {}",
+        seconds_of_day / 3_600,
+        (seconds_of_day / 60) % 60,
+        seconds_of_day % 60,
+        local_time::format_offset(offset_secs)
+    )
+}
+
+pub fn format_wall_clock_utc(unix_ms: u64) -> String {
+    let seconds_of_day = (unix_ms / 1_000) % 86_400;
+    format!(
+        "{:02}:{:02}:{:02} UTC",
+        seconds_of_day / 3_600,
+        (seconds_of_day / 60) % 60,
+        seconds_of_day % 60
+    )
+}
+
+pub fn format_offset(offset_ms: u64) -> String {
+    let seconds = offset_ms / 1_000;
+    format!(
+        "{:02}:{:02}:{:02}.{:03}",
+        seconds / 3_600,
+        (seconds / 60) % 60,
+        seconds % 60,
+        offset_ms % 1_000
+    )
+}
+
+pub fn default_meeting_path(started_unix_ms: u64) -> PathBuf {
+    let base = dirs::config_dir().unwrap_or_else(|| PathBuf::from("."));
+    base.join("sonora-dictation")
+        .join("meetings")
+        .join(format!("meeting-{started_unix_ms}.txt"))
+}
+
+fn io_to_string(error: io::Error) -> String {
+    error.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    fn temp_file(name: &str) -> PathBuf {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("clock should be set")
+            .as_nanos();
+        std::env::temp_dir().join(format!("sonora-meeting-{name}-{nanos}.txt"))
+    }
+
+    #[test]
+    fn formats_wall_clock_timestamps() {
+        assert_eq!(format_wall_clock_utc(0), "00:00:00 UTC");
+        assert_eq!(
+            format_wall_clock_utc(1_700_000_000_000 + 3_723_000),
+            "23:15:23 UTC"
+        );
+        assert_eq!(
+            format_wall_clock_at(1_700_000_000_000 + 3_723_000, 2 * 3_600),
+            "01:15:23 +02:00"
+        );
+        assert_eq!(
+            format_wall_clock_at(1_700_000_000_000 + 3_723_000, -(4 * 3_600)),
+            "19:15:23 -04:00"
+        );
+    }
+
+    #[test]
+    fn breaks_paragraphs_after_long_silence() {
+        assert!(starts_new_paragraph(None, 5_000, 8_000));
+        assert!(!starts_new_paragraph(Some(10_000), 17_000, 8_000));
+        assert!(starts_new_paragraph(Some(10_000), 18_001, 8_000));
+    }
+
+    #[test]
+    fn appends_utterances_incrementally_as_timestamped_paragraphs() {
+        let path = temp_file("notes");
+        let mut transcript = MeetingTranscript::new(path.clone(), 8_000);
+
+        transcript
+            .append_utterance("Welcome everyone.", 3_600_000, 3_602_000)
+            .expect("first utterance should append");
+        let partial = fs::read_to_string(&path).expect("transcript should exist");
+        let first = format_wall_clock_local(3_600_000);
+        assert_eq!(partial, format!("[{first}] Welcome everyone."));
+
+        transcript
+            .append_utterance("Let's start.", 3_604_000, 3_605_000)
+            .expect("second utterance should append");
+        transcript
+            .append_utterance("  ", 3_606_000, 3_606_000)
+            .expect("empty utterance should be ignored");
+        transcript
+            .append_utterance("Next topic.", 3_620_000, 3_622_000)
+            .expect("third utterance should append");
+
+        let contents = fs::read_to_string(&path).expect("transcript should exist");
+        let _ = fs::remove_file(&path);
+        assert_eq!(
+            contents,
+            format!(
+                "[{first}] Welcome everyone. Let's start.\n\n[{}] Next topic.",
+                format_wall_clock_local(3_620_000)
+            )
+        );
+    }
+
+    #[test]
+    fn anchored_transcripts_stamp_offsets_from_the_origin() {
+        let mut transcript = MeetingTranscript::anchored(temp_file("recording"), 8_000, 10_000);
+        assert_eq!(
+            transcript.next_chunk("Intro.", 12_345, 13_000).as_deref(),
+            Some("[00:00:02.345] Intro.")
+        );
+        assert_eq!(
+            transcript
+                .next_chunk("Later.", 3_735_000, 3_736_000)
+                .as_deref(),
+            Some("\n\n[01:02:05.000] Later.")
+        );
+    }
+}
@@ -0,0 +1,154 @@
+use serde::Serialize;
+use std::time::Duration;
+
+// How often a session waiting on a busy microphone tries to open it again.
+pub const AVAILABILITY_POLL_INTERVAL_MS: u64 = 2_000;
+// A call can hold the device for a long time; past this the session gives up.
+pub const AVAILABILITY_WAIT_LIMIT: Duration = Duration::from_secs(30 * 60);
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum MicUnavailableReason {
+    // Another application opened the device exclusively (WASAPI exclusive
+    // mode, an ALSA hw device, CoreAudio hog mode).
+    ExclusiveUse,
+    PermissionDenied,
+    Disconnected,
+    Other,
+}
+
+impl MicUnavailableReason {
+    // Only an exclusive hold is released on its own, so only it is worth polling.
+    pub fn frees_up(self) -> bool {
+        self == MicUnavailableReason::ExclusiveUse
+    }
+}
+
+// Backends only hand back error text, so matching is on the markers each one
+// is known to produce. Exclusive use is checked first because CoreAudio
+// reports hog mode as a permissions error.
+const PERMISSION_MARKERS: [&str; 4] = [
+    "permission denied",
+    "access is denied",
+    "e_accessdenied",
+    "0x80070005",
+];
+const EXCLUSIVE_MARKERS: [&str; 6] = [
+    "device or resource busy",
+    "ebusy",
+    "already in use",
+    "audclnt_e_device_in_use",
+    "0x8889000a",
+    "kaudiodevicepermissionserror",
+];
+const DISCONNECTED_MARKERS: [&str; 5] = [
+    "no longer available",
+    "microphone not found",
+    "no input microphone",
+    "no such device",
+    "0x88890004",
+];
+
+pub fn classify_stream_error(message: &str) -> MicUnavailableReason {
+    let message = message.to_ascii_lowercase();
+    let matches = |markers: &[&str]| markers.iter().any(|marker| message.contains(marker));
+    if matches(&EXCLUSIVE_MARKERS) {
+        MicUnavailableReason::ExclusiveUse
+    } else if matches(&PERMISSION_MARKERS) {
+        MicUnavailableReason::PermissionDenied
+    } else if matches(&DISCONNECTED_MARKERS) {
+        MicUnavailableReason::Disconnected
+    } else {
+        MicUnavailableReason::Other
+    }
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct MicUnavailable {
+    pub reason: MicUnavailableReason,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device_label: Option<String>,
+    // The backend's own text, kept for logs and support snapshots.
+    pub message: String,
+    // True while the session keeps polling and will start on its own.
+    pub waiting: bool,
+}
+
+impl MicUnavailable {
+    pub fn from_stream_error(message: &str, device_label: Option<String>) -> Self {
+        let reason = classify_stream_error(message);
+        Self {
+            reason,
+            device_label,
+            message: message.to_string(),
+            waiting: reason.frees_up(),
+        }
+    }
+
+    pub fn summary(&self) -> String {
+        let device = self.device_label.as_deref().unwrap_or("the microphone");
+        match self.reason {
+            MicUnavailableReason::ExclusiveUse if self.waiting => format!(
+                "{device} is in exclusive use by another application; dictation starts when it is released"
+            ),
+            MicUnavailableReason::ExclusiveUse => {
+                format!("{device} is in exclusive use by another application")
+            }
+            MicUnavailableReason::PermissionDenied => {
+                format!("access to {device} was denied by the system")
+            }
+            MicUnavailableReason::Disconnected => format!("{device} is not connected"),
+            MicUnavailableReason::Other => format!("{device} could not be opened: {}", self.message),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifies_backend_errors() {
+        assert_eq!(
+            classify_stream_error(
+                "failed to build i16 input stream: A backend-specific error has occurred: ALSA function 'snd_pcm_open' failed with error 'EBUSY: Device or resource busy'"
+            ),
+            MicUnavailableReason::ExclusiveUse
+        );
+        assert_eq!(
+            classify_stream_error("failed to build f32 input stream: A backend-specific error has occurred: 0x8889000A"),
+            MicUnavailableReason::ExclusiveUse
+        );
+        assert_eq!(
+            classify_stream_error(
+                "failed to get default input config: Access is denied. (0x80070005)"
+            ),
+            MicUnavailableReason::PermissionDenied
+        );
+        assert_eq!(
+            classify_stream_error("microphone not found for id 3"),
+            MicUnavailableReason::Disconnected
+        );
+        assert_eq!(
+            classify_stream_error("unsupported input sample format: I8"),
+            MicUnavailableReason::Other
+        );
+    }
+
+    #[test]
+    fn only_exclusive_use_waits_for_the_device() {
+        let busy = MicUnavailable::from_stream_error(
+            "Device or resource busy",
+            Some("USB Headset".to_string()),
+        );
+        assert!(busy.waiting);
+        assert!(busy
+            .summary()
+            .starts_with("USB Headset is in exclusive use"));
+
+        let gone = MicUnavailable::from_stream_error("no input microphone is available", None);
+        assert_eq!(gone.reason, MicUnavailableReason::Disconnected);
+        assert!(!gone.waiting);
+        assert_eq!(gone.summary(), "the microphone is not connected");
+    }
+}
@@ -0,0 +1,115 @@
+use crate::config::{AppSettings, MicrophoneProfile, PlaybackGuard};
+
+const MAX_INPUT_CHANNEL: u16 = 31;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MicSettings {
+    pub mic_sensitivity_percent: u16,
+    pub input_channel: Option<u16>,
+    pub playback_guard: PlaybackGuard,
+}
+
+pub fn find_profile<'a>(
+    profiles: &'a [MicrophoneProfile],
+    device_label: &str,
+) -> Option<&'a MicrophoneProfile> {
+    let device_label = device_label.trim();
+    profiles
+        .iter()
+        .find(|profile| profile.device_label.eq_ignore_ascii_case(device_label))
+}
+
+// Fields a profile leaves unset fall back to the global settings.
+pub fn resolve(settings: &AppSettings, device_label: Option<&str>) -> MicSettings {
+    let profile = device_label.and_then(|label| find_profile(&settings.microphone_profiles, label));
+    MicSettings {
+        mic_sensitivity_percent: profile
+            .and_then(|profile| profile.mic_sensitivity_percent)
+            .unwrap_or(settings.mic_sensitivity_percent),
+        input_channel: profile.and_then(|profile| profile.input_channel),
+        playback_guard: profile
+            .and_then(|profile| profile.playback_guard)
+            .unwrap_or(settings.playback_guard),
+    }
+}
+
+pub fn upsert(profiles: &mut Vec<MicrophoneProfile>, profile: MicrophoneProfile) {
+    profiles.retain(|existing| {
+        !existing
+            .device_label
+            .eq_ignore_ascii_case(profile.device_label.trim())
+    });
+    profiles.push(profile);
+}
+
+pub fn normalize_profiles(profiles: Vec<MicrophoneProfile>) -> Vec<MicrophoneProfile> {
+    let mut normalized = Vec::with_capacity(profiles.len());
+    for mut profile in profiles {
+        profile.device_label = profile.device_label.trim().to_string();
+        if profile.device_label.is_empty() {
+            continue;
+        }
+        profile.mic_sensitivity_percent = profile
+            .mic_sensitivity_percent
+            .map(|value| value.clamp(50, 300));
+        profile.input_channel = profile
+            .input_channel
+            .filter(|channel| *channel <= MAX_INPUT_CHANNEL);
+        upsert(&mut normalized, profile);
+    }
+    normalized
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn profile(label: &str, sensitivity: Option<u16>, channel: Option<u16>) -> MicrophoneProfile {
+        MicrophoneProfile {
+            device_label: label.to_string(),
+            mic_sensitivity_percent: sensitivity,
+            input_channel: channel,
+            playback_guard: None,
+        }
+    }
+
+    #[test]
+    fn resolves_device_overrides_over_global_settings() {
+        let settings = AppSettings {
+            mic_sensitivity_percent: 150,
+            playback_guard: PlaybackGuard::Pause,
+            microphone_profiles: vec![
+                MicrophoneProfile {
+                    playback_guard: Some(PlaybackGuard::Off),
+                    ..profile("Studio Interface", Some(220), Some(1))
+                },
+                profile("Laptop Mic", None, None),
+            ],
+            ..AppSettings::default()
+        };
+
+        let studio = resolve(&settings, Some("studio interface"));
+        assert_eq!(studio.mic_sensitivity_percent, 220);
+        assert_eq!(studio.input_channel, Some(1));
+        assert_eq!(studio.playback_guard, PlaybackGuard::Off);
+        assert_eq!(
+            resolve(&settings, Some("Laptop Mic")).playback_guard,
+            PlaybackGuard::Pause
+        );
+        assert_eq!(
+            resolve(&settings, Some("Laptop Mic")).mic_sensitivity_percent,
+            150
+        );
+        assert_eq!(resolve(&settings, None).input_channel, None);
+    }
+
+    #[test]
+    fn normalizes_and_deduplicates_profiles() {
+        let normalized = normalize_profiles(vec![
+            profile("  ", Some(100), None),
+            profile("USB Mic", Some(10), Some(64)),
+            profile(" usb mic ", Some(400), Some(0)),
+        ]);
+        assert_eq!(normalized, vec![profile("usb mic", Some(300), Some(0))]);
+    }
+}
@@ -0,0 +1,81 @@
+use std::cmp::Ordering;
+
+pub const PROBE_DURATION_MS: u64 = 600;
+
+const FRAME_SAMPLES: usize = 320;
+const MIN_FRAMES: usize = 4;
+const SILENT_RMS: f32 = 1e-5;
+
+pub fn estimate_snr_db(samples: &[f32]) -> Option<f32> {
+    let mut frame_rms = samples
+        .chunks_exact(FRAME_SAMPLES)
+        .map(|frame| {
+            (frame.iter().map(|value| value * value).sum::<f32>() / frame.len() as f32).sqrt()
+        })
+        .collect::<Vec<_>>();
+    if frame_rms.len() < MIN_FRAMES {
+        return None;
+    }
+    frame_rms.sort_by(f32::total_cmp);
+
+    let noise_floor = percentile(&frame_rms, 0.1).max(SILENT_RMS);
+    let signal = percentile(&frame_rms, 0.9);
+    // A muted or disconnected device reports digital silence; it should never win.
+    if signal <= SILENT_RMS {
+        return None;
+    }
+    Some(20.0 * (signal / noise_floor).log10())
+}
+
+pub fn rank_by_snr<T>(items: &mut [T], snr_db: impl Fn(&T) -> Option<f32>) {
+    items.sort_by(|left, right| match (snr_db(left), snr_db(right)) {
+        (Some(left), Some(right)) => right.total_cmp(&left),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    });
+}
+
+fn percentile(sorted: &[f32], fraction: f32) -> f32 {
+    let index = ((sorted.len() - 1) as f32 * fraction).round() as usize;
+    sorted[index.min(sorted.len() - 1)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bursty(noise: f32, burst: f32) -> Vec<f32> {
+        (0..16_000)
+            .map(|index| {
+                let level = if (index / 1_600) % 2 == 0 {
+                    burst
+                } else {
+                    noise
+                };
+                level * if index % 2 == 0 { 1.0 } else { -1.0 }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn scores_clean_devices_above_noisy_ones() {
+        let clean = estimate_snr_db(&bursty(0.001, 0.2)).expect("clean mic should score");
+        let noisy = estimate_snr_db(&bursty(0.05, 0.2)).expect("noisy mic should score");
+        assert!(clean > noisy);
+        assert_eq!(estimate_snr_db(&vec![0.0; 16_000]), None);
+        assert_eq!(estimate_snr_db(&[0.1; 100]), None);
+    }
+
+    #[test]
+    fn ranks_unscored_devices_last() {
+        let mut devices = vec![
+            ("webcam", Some(12.0)),
+            ("muted", None),
+            ("headset", Some(31.0)),
+        ];
+        rank_by_snr(&mut devices, |device| device.1);
+        let order = devices.iter().map(|device| device.0).collect::<Vec<_>>();
+        assert_eq!(order, vec!["headset", "webcam", "muted"]);
+    }
+}
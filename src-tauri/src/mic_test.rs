@@ -0,0 +1,94 @@
+use serde::Serialize;
+
+use crate::audio::SAMPLE_RATE_HZ;
+use crate::mic_ranking::estimate_snr_db;
+
+pub const MIN_TEST_CLIP_SECONDS: u8 = 1;
+pub const MAX_TEST_CLIP_SECONDS: u8 = 10;
+
+const CLIP_THRESHOLD: f32 = 0.99;
+const QUIET_PEAK: f32 = 0.05;
+const MAX_CLIPPED_RATIO: f32 = 0.001;
+const LOW_SNR_DB: f32 = 10.0;
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct ClipStats {
+    pub duration_ms: u64,
+    pub rms: f32,
+    pub peak: f32,
+    pub clipped_ratio: f32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snr_db: Option<f32>,
+    pub warnings: Vec<String>,
+}
+
+pub fn clamp_test_clip_seconds(seconds: u8) -> u8 {
+    seconds.clamp(MIN_TEST_CLIP_SECONDS, MAX_TEST_CLIP_SECONDS)
+}
+
+pub fn measure_clip(samples: &[f32]) -> ClipStats {
+    let duration_ms = samples.len() as u64 * 1_000 / u64::from(SAMPLE_RATE_HZ);
+    let rms = if samples.is_empty() {
+        0.0
+    } else {
+        (samples.iter().map(|value| value * value).sum::<f32>() / samples.len() as f32).sqrt()
+    };
+    let peak = samples
+        .iter()
+        .fold(0f32, |peak, value| peak.max(value.abs()));
+    let clipped = samples
+        .iter()
+        .filter(|value| value.abs() >= CLIP_THRESHOLD)
+        .count();
+    let clipped_ratio = if samples.is_empty() {
+        0.0
+    } else {
+        clipped as f32 / samples.len() as f32
+    };
+    let snr_db = estimate_snr_db(samples);
+
+    let mut warnings = Vec::new();
+    if peak < QUIET_PEAK {
+        warnings.push("Input is very quiet; raise mic sensitivity or move closer.".to_string());
+    }
+    if clipped_ratio > MAX_CLIPPED_RATIO {
+        warnings.push("Input is clipping; lower mic sensitivity.".to_string());
+    }
+    if snr_db.is_some_and(|value| value < LOW_SNR_DB) {
+        warnings.push("Background noise is close to speech level.".to_string());
+    }
+
+    ClipStats {
+        duration_ms,
+        rms,
+        peak,
+        clipped_ratio,
+        snr_db,
+        warnings,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flags_quiet_and_clipping_clips() {
+        let quiet = measure_clip(&[0.01; 16_000]);
+        assert_eq!(quiet.duration_ms, 1_000);
+        assert!(quiet
+            .warnings
+            .iter()
+            .any(|warning| warning.contains("quiet")));
+
+        let clipping = measure_clip(&[1.0; 16_000]);
+        assert_eq!(clipping.peak, 1.0);
+        assert!(clipping
+            .warnings
+            .iter()
+            .any(|warning| warning.contains("clipping")));
+
+        assert_eq!(clamp_test_clip_seconds(0), MIN_TEST_CLIP_SECONDS);
+        assert_eq!(clamp_test_clip_seconds(60), MAX_TEST_CLIP_SECONDS);
+    }
+}
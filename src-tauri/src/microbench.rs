@@ -0,0 +1,183 @@
+use serde::{Deserialize, Serialize};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+// Enough samples for a stable median without making slow engine benches
+// run for minutes.
+const MIN_SAMPLES: usize = 5;
+const MAX_SAMPLES: usize = 100;
+// Fast routines are batched so one sample is well above timer resolution.
+const MIN_SAMPLE_TIME: Duration = Duration::from_millis(2);
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Measurement {
+    pub name: String,
+    pub samples: usize,
+    pub iterations_per_sample: u64,
+    pub mean_ns: u64,
+    pub median_ns: u64,
+    pub p95_ns: u64,
+    pub min_ns: u64,
+    // Seconds of fixture audio processed per wall-clock second.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub realtime_factor: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct Regression {
+    pub name: String,
+    pub baseline_median_ns: u64,
+    pub current_median_ns: u64,
+    pub change_percent: f64,
+}
+
+// Warms up for a quarter of the budget, then times batches of calls until the
+// budget is spent. Reported times are per call.
+pub fn measure<R>(name: &str, budget: Duration, mut routine: impl FnMut() -> R) -> Measurement {
+    let warmup_until = Instant::now() + budget / 4;
+    let mut warmup_calls = 0u64;
+    let warmup_started = Instant::now();
+    loop {
+        black_box(routine());
+        warmup_calls += 1;
+        if Instant::now() >= warmup_until {
+            break;
+        }
+    }
+    let per_call = warmup_started.elapsed() / warmup_calls.max(1) as u32;
+    let iterations_per_sample = if per_call.is_zero() {
+        1_000
+    } else {
+        (MIN_SAMPLE_TIME.as_nanos() / per_call.as_nanos()).clamp(1, 1_000_000) as u64
+    };
+
+    let measure_until = Instant::now() + budget;
+    let mut per_call_ns = Vec::with_capacity(MAX_SAMPLES);
+    while per_call_ns.len() < MIN_SAMPLES
+        || (per_call_ns.len() < MAX_SAMPLES && Instant::now() < measure_until)
+    {
+        let started = Instant::now();
+        for _ in 0..iterations_per_sample {
+            black_box(routine());
+        }
+        per_call_ns.push(started.elapsed().as_nanos() as u64 / iterations_per_sample);
+    }
+    summarize(name, iterations_per_sample, per_call_ns)
+}
+
+fn summarize(name: &str, iterations_per_sample: u64, mut per_call_ns: Vec<u64>) -> Measurement {
+    per_call_ns.sort_unstable();
+    let samples = per_call_ns.len();
+    let at = |fraction: f64| per_call_ns[((samples - 1) as f64 * fraction).round() as usize];
+    Measurement {
+        name: name.to_string(),
+        samples,
+        iterations_per_sample,
+        mean_ns: per_call_ns.iter().sum::<u64>() / samples as u64,
+        median_ns: at(0.5),
+        p95_ns: at(0.95),
+        min_ns: per_call_ns[0],
+        realtime_factor: None,
+    }
+}
+
+impl Measurement {
+    pub fn with_audio_ms(mut self, audio_ms: u64) -> Self {
+        if self.median_ns > 0 {
+            self.realtime_factor = Some(audio_ms as f64 * 1_000_000.0 / self.median_ns as f64);
+        }
+        self
+    }
+}
+
+// Medians slower than the baseline by more than `tolerance_percent`. Benches
+// missing from either side are skipped so adding one does not fail the check.
+pub fn regressions(
+    baseline: &[Measurement],
+    current: &[Measurement],
+    tolerance_percent: f64,
+) -> Vec<Regression> {
+    current
+        .iter()
+        .filter_map(|measurement| {
+            let before = baseline
+                .iter()
+                .find(|candidate| candidate.name == measurement.name)?;
+            if before.median_ns == 0 {
+                return None;
+            }
+            let change_percent = (measurement.median_ns as f64 - before.median_ns as f64)
+                / before.median_ns as f64
+                * 100.0;
+            (change_percent > tolerance_percent).then(|| Regression {
+                name: measurement.name.clone(),
+                baseline_median_ns: before.median_ns,
+                current_median_ns: measurement.median_ns,
+                change_percent,
+            })
+        })
+        .collect()
+}
+
+// Deterministic speech-like fixture: voiced bursts with a moving pitch and
+// syllable-rate envelope, separated by low noise, so VAD has both to find.
+pub fn fixture_audio(sample_rate_hz: u32, seconds: u32) -> Vec<f32> {
+    let total = (sample_rate_hz * seconds) as usize;
+    let rate = sample_rate_hz as f32;
+    let mut noise_state = 0x2545_f491u32;
+    (0..total)
+        .map(|index| {
+            noise_state = noise_state
+                .wrapping_mul(1_664_525)
+                .wrapping_add(1_013_904_223);
+            let noise = (noise_state >> 8) as f32 / (1u32 << 24) as f32 - 0.5;
+            let t = index as f32 / rate;
+            let voiced = (t % 1.0) < 0.6;
+            if !voiced {
+                return noise * 0.004;
+            }
+            let pitch = 140.0 + 30.0 * (t * 1.3 * std::f32::consts::TAU).sin();
+            let envelope = 0.6 + 0.4 * (t * 4.0 * std::f32::consts::TAU).sin();
+            let phase = t * pitch * std::f32::consts::TAU;
+            let harmonics = phase.sin() + 0.5 * (2.0 * phase).sin() + 0.25 * (3.0 * phase).sin();
+            0.12 * envelope * harmonics + noise * 0.01
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vad::{detect_speech_segments, VadConfig};
+
+    #[test]
+    fn flags_only_medians_past_the_tolerance() {
+        let bench = |name: &str, median_ns: u64| Measurement {
+            median_ns,
+            ..summarize(name, 1, vec![median_ns])
+        };
+        let baseline = vec![bench("vad", 1_000), bench("resample", 2_000)];
+        let current = vec![
+            bench("vad", 1_050),
+            bench("resample", 2_600),
+            bench("new", 9),
+        ];
+        let flagged = regressions(&baseline, &current, 10.0);
+        assert_eq!(flagged.len(), 1);
+        assert_eq!(flagged[0].name, "resample");
+        assert!((flagged[0].change_percent - 30.0).abs() < 1e-9);
+
+        let measured = measure("sum", Duration::from_millis(4), || (0..64u32).sum::<u32>());
+        assert!(measured.samples >= MIN_SAMPLES);
+        assert!(measured.min_ns <= measured.median_ns && measured.median_ns <= measured.p95_ns);
+    }
+
+    #[test]
+    fn fixture_is_deterministic_and_has_speech_and_pauses() {
+        let audio = fixture_audio(16_000, 3);
+        assert_eq!(audio.len(), 48_000);
+        assert_eq!(audio, fixture_audio(16_000, 3));
+        let segments = detect_speech_segments(&audio, &VadConfig::default());
+        assert_eq!(segments.len(), 3);
+    }
+}
@@ -0,0 +1,179 @@
+use serde::Serialize;
+use std::collections::HashSet;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const HF_MODEL_DIR_PREFIX: &str = "models--";
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct CachedModel {
+    pub repo_id: String,
+    pub size_bytes: u64,
+    pub referenced: bool,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct CacheUsage {
+    pub path: String,
+    pub total_bytes: u64,
+    pub models: Vec<CachedModel>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, PartialEq, Eq)]
+pub struct PruneReport {
+    pub removed: Vec<String>,
+    pub freed_bytes: u64,
+}
+
+// Mirrors the short names faster-whisper maps to Hugging Face repositories.
+pub fn faster_whisper_repo_id(model: &str) -> Option<String> {
+    let model = model.trim();
+    if model.is_empty() || Path::new(model).is_absolute() || model.starts_with('.') {
+        return None;
+    }
+    if model.contains('/') {
+        return Some(model.to_string());
+    }
+    Some(match model {
+        "large-v3-turbo" | "turbo" => "mobiuslabsgmbh/faster-whisper-large-v3-turbo".to_string(),
+        _ => match model.strip_prefix("distil-") {
+            Some(rest) => format!("Systran/faster-distil-whisper-{rest}"),
+            None => format!("Systran/faster-whisper-{model}"),
+        },
+    })
+}
+
+pub fn cache_usage(cache_dir: &Path, referenced: &[String]) -> CacheUsage {
+    let referenced = referenced_repo_ids(referenced);
+    let models = cached_repo_dirs(cache_dir)
+        .into_iter()
+        .map(|(repo_id, path)| CachedModel {
+            referenced: referenced.contains(&repo_id.to_ascii_lowercase()),
+            size_bytes: directory_size_bytes(&path),
+            repo_id,
+        })
+        .collect::<Vec<_>>();
+
+    CacheUsage {
+        path: cache_dir.to_string_lossy().to_string(),
+        total_bytes: directory_size_bytes(cache_dir),
+        models,
+    }
+}
+
+pub fn prune_unreferenced(cache_dir: &Path, referenced: &[String]) -> Result<PruneReport, String> {
+    let referenced = referenced_repo_ids(referenced);
+    let mut report = PruneReport::default();
+    for (repo_id, path) in cached_repo_dirs(cache_dir) {
+        if referenced.contains(&repo_id.to_ascii_lowercase()) {
+            continue;
+        }
+        let size_bytes = directory_size_bytes(&path);
+        fs::remove_dir_all(&path).map_err(io_to_string)?;
+        report.freed_bytes += size_bytes;
+        report.removed.push(repo_id);
+    }
+    Ok(report)
+}
+
+fn referenced_repo_ids(models: &[String]) -> HashSet<String> {
+    models
+        .iter()
+        .filter_map(|model| faster_whisper_repo_id(model))
+        .map(|repo_id| repo_id.to_ascii_lowercase())
+        .collect()
+}
+
+fn cached_repo_dirs(cache_dir: &Path) -> Vec<(String, PathBuf)> {
+    let Ok(entries) = fs::read_dir(cache_dir) else {
+        return Vec::new();
+    };
+    let mut repos = entries
+        .flatten()
+        .filter(|entry| entry.file_type().is_ok_and(|kind| kind.is_dir()))
+        .filter_map(|entry| {
+            let name = entry.file_name().to_string_lossy().to_string();
+            let repo_id = name.strip_prefix(HF_MODEL_DIR_PREFIX)?.replace("--", "/");
+            Some((repo_id, entry.path()))
+        })
+        .collect::<Vec<_>>();
+    repos.sort();
+    repos
+}
+
+// Symlinks are not followed: HF snapshots link into blobs/, which is already counted.
+fn directory_size_bytes(path: &Path) -> u64 {
+    let Ok(entries) = fs::read_dir(path) else {
+        return 0;
+    };
+    entries
+        .flatten()
+        .map(|entry| match entry.file_type() {
+            Ok(kind) if kind.is_dir() => directory_size_bytes(&entry.path()),
+            Ok(kind) if kind.is_file() => entry.metadata().map(|meta| meta.len()).unwrap_or(0),
+            _ => 0,
+        })
+        .sum()
+}
+
+fn io_to_string(error: io::Error) -> String {
+    error.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    #[test]
+    fn maps_short_names_to_repositories() {
+        assert_eq!(
+            faster_whisper_repo_id("small.en").as_deref(),
+            Some("Systran/faster-whisper-small.en")
+        );
+        assert_eq!(
+            faster_whisper_repo_id("distil-large-v3").as_deref(),
+            Some("Systran/faster-distil-whisper-large-v3")
+        );
+        assert_eq!(
+            faster_whisper_repo_id("acme/whisper-ct2").as_deref(),
+            Some("acme/whisper-ct2")
+        );
+        assert_eq!(faster_whisper_repo_id("/opt/models/small"), None);
+    }
+
+    #[test]
+    fn prunes_only_unreferenced_models() {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("clock should be set")
+            .as_nanos();
+        let cache = std::env::temp_dir().join(format!("sonora-hf-cache-{nanos}"));
+        for (repo, bytes) in [
+            ("models--Systran--faster-whisper-tiny.en", 10),
+            ("models--Systran--faster-whisper-large-v3", 25),
+        ] {
+            let blobs = cache.join(repo).join("blobs");
+            fs::create_dir_all(&blobs).expect("cache dir should be created");
+            fs::write(blobs.join("model.bin"), vec![0u8; bytes]).expect("blob should be written");
+        }
+        fs::write(cache.join("version.txt"), "1").expect("marker should be written");
+
+        let referenced = vec!["tiny.en".to_string()];
+        let usage = cache_usage(&cache, &referenced);
+        assert_eq!(usage.total_bytes, 36);
+        assert_eq!(usage.models.len(), 2);
+        assert!(usage
+            .models
+            .iter()
+            .any(|model| model.repo_id == "Systran/faster-whisper-tiny.en" && model.referenced));
+
+        let report = prune_unreferenced(&cache, &referenced).expect("prune should succeed");
+        let remaining = cache_usage(&cache, &referenced);
+        let _ = fs::remove_dir_all(&cache);
+        assert_eq!(report.removed, vec!["Systran/faster-whisper-large-v3"]);
+        assert_eq!(report.freed_bytes, 25);
+        assert_eq!(remaining.models.len(), 1);
+    }
+}
@@ -0,0 +1,66 @@
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+pub struct ModelAlias {
+    pub alias: &'static str,
+    pub relative_path: &'static str,
+    pub english_only: bool,
+}
+
+pub const MODEL_ALIASES: &[ModelAlias] = &[
+    ModelAlias {
+        alias: "tiny-en-q8",
+        relative_path: "models/ggml-tiny.en-q8_0.bin",
+        english_only: true,
+    },
+    ModelAlias {
+        alias: "base-en",
+        relative_path: "models/ggml-base.en-q5_1.bin",
+        english_only: true,
+    },
+    ModelAlias {
+        alias: "tiny-q8",
+        relative_path: "models/ggml-tiny-q8_0.bin",
+        english_only: false,
+    },
+    ModelAlias {
+        alias: "base",
+        relative_path: "models/ggml-base-q5_1.bin",
+        english_only: false,
+    },
+];
+
+// Aliases never contain path separators or extensions, so a real path cannot collide.
+pub fn find_alias(value: &str) -> Option<&'static ModelAlias> {
+    let value = value.trim();
+    MODEL_ALIASES
+        .iter()
+        .find(|entry| entry.alias.eq_ignore_ascii_case(value))
+}
+
+pub fn alias_for_path(path: &str) -> Option<&'static ModelAlias> {
+    let normalized = path.trim().replace('\\', "/");
+    MODEL_ALIASES
+        .iter()
+        .find(|entry| normalized.ends_with(entry.relative_path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_aliases_and_recognizes_catalog_paths() {
+        assert_eq!(
+            find_alias(" Base-EN ").map(|entry| entry.relative_path),
+            Some("models/ggml-base.en-q5_1.bin")
+        );
+        assert_eq!(find_alias("models/ggml-base.en-q5_1.bin"), None);
+        assert_eq!(
+            alias_for_path("C:\\Sonora\\resources\\models\\ggml-tiny-q8_0.bin")
+                .map(|entry| entry.alias),
+            Some("tiny-q8")
+        );
+        assert_eq!(alias_for_path("/opt/models/custom.bin"), None);
+    }
+}
@@ -0,0 +1,218 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use crate::profile::HardwareTier;
+
+// How often the idle maintenance task looks for a model worth converting.
+pub const IDLE_CHECK_INTERVAL_SECS: u64 = 60;
+// Quiet time after the last dictation before the task takes the CPU.
+pub const IDLE_BEFORE_CONVERSION_MS: u64 = 5 * 60_000;
+
+const QUANTIZED_TAGS: [&str; 5] = ["-q4_0", "-q4_1", "-q5_0", "-q5_1", "-q8_0"];
+
+// ggml quantization types understood by whisper.cpp's quantize tool.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum QuantizationLevel {
+    Q5_0,
+    Q5_1,
+    Q8_0,
+}
+
+impl QuantizationLevel {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            QuantizationLevel::Q5_0 => "q5_0",
+            QuantizationLevel::Q5_1 => "q5_1",
+            QuantizationLevel::Q8_0 => "q8_0",
+        }
+    }
+}
+
+// Smaller levels trade accuracy for memory and speed on weaker machines.
+pub fn recommended_level(tier: HardwareTier) -> QuantizationLevel {
+    match tier {
+        HardwareTier::Low => QuantizationLevel::Q5_0,
+        HardwareTier::Mid => QuantizationLevel::Q5_1,
+        HardwareTier::High => QuantizationLevel::Q8_0,
+    }
+}
+
+// Downloaded whisper.cpp models carry their quantization in the file name,
+// e.g. ggml-small.en-q5_1.bin; a plain ggml-small.en.bin is f16.
+pub fn is_full_precision(model_path: &Path) -> bool {
+    let Some(name) = model_path.file_name().and_then(|name| name.to_str()) else {
+        return false;
+    };
+    let name = name.to_ascii_lowercase();
+    name.starts_with("ggml-")
+        && name.ends_with(".bin")
+        && !QUANTIZED_TAGS.iter().any(|tag| name.contains(tag))
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct ConversionPlan {
+    pub source: PathBuf,
+    pub target: PathBuf,
+    pub level: QuantizationLevel,
+}
+
+pub fn plan_conversion(
+    model_path: &Path,
+    level: QuantizationLevel,
+) -> Result<ConversionPlan, String> {
+    if !is_full_precision(model_path) {
+        return Err(format!(
+            "{} is not a full-precision ggml model",
+            model_path.display()
+        ));
+    }
+    let stem = model_path
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or_default();
+    Ok(ConversionPlan {
+        source: model_path.to_path_buf(),
+        target: model_path.with_file_name(format!("{stem}-{}.bin", level.as_str())),
+        level,
+    })
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ConversionStage {
+    Running,
+    Completed,
+    // Dictation started; the idle task tries again later.
+    Interrupted,
+    Failed,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct ConversionProgress {
+    pub source: String,
+    pub target: String,
+    pub level: QuantizationLevel,
+    pub stage: ConversionStage,
+    pub percent: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl ConversionProgress {
+    pub fn started(plan: &ConversionPlan) -> Self {
+        Self {
+            source: plan.source.to_string_lossy().to_string(),
+            target: plan.target.to_string_lossy().to_string(),
+            level: plan.level,
+            stage: ConversionStage::Running,
+            percent: 0,
+            error: None,
+        }
+    }
+}
+
+// The quantize tool logs one line per tensor ending in
+// `size =    0.281 MB ->     0.097 MB`; the input size tracks progress.
+pub fn parse_tensor_input_mb(line: &str) -> Option<f64> {
+    let (_, rest) = line.split_once("size =")?;
+    let (input, _) = rest.split_once("->")?;
+    input.trim().strip_suffix("MB")?.trim().parse().ok()
+}
+
+// Writes to a partial file and only renames it into place once the tool
+// succeeds. `on_progress` gets each new percentage and returns false to stop.
+pub fn run_quantize(
+    binary: &Path,
+    plan: &ConversionPlan,
+    mut on_progress: impl FnMut(u8) -> bool,
+) -> Result<(), String> {
+    let source_mb = fs::metadata(&plan.source)
+        .map_err(|error| format!("cannot read {}: {error}", plan.source.display()))?
+        .len() as f64
+        / (1024.0 * 1024.0);
+    let partial = plan.target.with_extension("bin.partial");
+    let mut child = Command::new(binary)
+        .arg(&plan.source)
+        .arg(&partial)
+        .arg(plan.level.as_str())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|error| format!("failed to start {}: {error}", binary.display()))?;
+
+    let mut processed_mb = 0.0;
+    let mut percent = 0u8;
+    let mut stopped = false;
+    if let Some(stdout) = child.stdout.take() {
+        for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+            let Some(tensor_mb) = parse_tensor_input_mb(&line) else {
+                continue;
+            };
+            processed_mb += tensor_mb;
+            // The file header is not a tensor, so 100 waits for the exit status.
+            let next = ((processed_mb / source_mb.max(f64::EPSILON)) * 100.0).min(99.0) as u8;
+            if next > percent {
+                percent = next;
+                if !on_progress(percent) {
+                    stopped = true;
+                    let _ = child.kill();
+                    break;
+                }
+            }
+        }
+    }
+    let status = child.wait().map_err(|error| error.to_string())?;
+    if stopped || !status.success() {
+        let _ = fs::remove_file(&partial);
+        return Err(if stopped {
+            "conversion interrupted".to_string()
+        } else {
+            format!("quantize tool exited with {status}")
+        });
+    }
+    fs::rename(&partial, &plan.target).map_err(|error| error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plans_conversion_only_for_full_precision_models() {
+        let plan = plan_conversion(
+            Path::new("/models/ggml-small.en.bin"),
+            recommended_level(HardwareTier::Mid),
+        )
+        .expect("f16 model converts");
+        assert_eq!(plan.target, PathBuf::from("/models/ggml-small.en-q5_1.bin"));
+        assert_eq!(
+            recommended_level(HardwareTier::Low),
+            QuantizationLevel::Q5_0
+        );
+
+        assert!(plan_conversion(
+            Path::new("/models/ggml-base.en-q5_1.bin"),
+            QuantizationLevel::Q8_0
+        )
+        .is_err());
+        assert!(!is_full_precision(Path::new("/models/custom.gguf")));
+    }
+
+    #[test]
+    fn reads_tensor_sizes_from_quantize_output() {
+        assert_eq!(
+            parse_tensor_input_mb(
+                "encoder.blocks.0.attn.query.weight - [  384,   384,     1], type =    f16 size =    0.281 MB ->     0.097 MB"
+            ),
+            Some(0.281)
+        );
+        assert_eq!(
+            parse_tensor_input_mb("whisper_model_quantize: loading model"),
+            None
+        );
+    }
+}
@@ -0,0 +1,107 @@
+use std::collections::VecDeque;
+
+pub const MONITOR_MAX_LATENCY_MS: u32 = 120;
+
+#[derive(Debug)]
+pub struct MonitorBuffer {
+    samples: VecDeque<f32>,
+    max_samples: usize,
+}
+
+impl MonitorBuffer {
+    pub fn new(sample_rate_hz: u32, max_latency_ms: u32) -> Self {
+        let max_samples =
+            ((u64::from(sample_rate_hz) * u64::from(max_latency_ms)) / 1_000).max(1) as usize;
+        Self {
+            samples: VecDeque::with_capacity(max_samples),
+            max_samples,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    pub fn push(&mut self, mono: &[f32]) {
+        self.samples.extend(mono.iter().copied());
+        let overflow = self.samples.len().saturating_sub(self.max_samples);
+        if overflow > 0 {
+            self.samples.drain(..overflow);
+        }
+    }
+
+    pub fn fill_interleaved(&mut self, output: &mut [f32], channels: usize) {
+        let channels = channels.max(1);
+        for frame in output.chunks_mut(channels) {
+            let sample = self.samples.pop_front().unwrap_or(0.0);
+            frame.fill(sample);
+        }
+    }
+}
+
+pub fn resample_linear(input: &[f32], source_rate_hz: u32, target_rate_hz: u32) -> Vec<f32> {
+    if input.is_empty() || source_rate_hz == 0 || target_rate_hz == 0 {
+        return Vec::new();
+    }
+    if source_rate_hz == target_rate_hz {
+        return input.to_vec();
+    }
+
+    let ratio = source_rate_hz as f64 / target_rate_hz as f64;
+    let output_length = ((input.len() as f64) / ratio).floor() as usize;
+    let last = input.len() - 1;
+    (0..output_length)
+        .map(|index| {
+            let position = index as f64 * ratio;
+            let left = (position.floor() as usize).min(last);
+            let right = (left + 1).min(last);
+            let fraction = (position - left as f64) as f32;
+            input[left] + (input[right] - input[left]) * fraction
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn caps_buffered_audio_to_latency_budget() {
+        let mut buffer = MonitorBuffer::new(1_000, 10);
+        buffer.push(&[0.1; 8]);
+        buffer.push(&[0.5; 6]);
+
+        assert_eq!(buffer.len(), 10);
+        let mut output = [0.0; 10];
+        buffer.fill_interleaved(&mut output, 1);
+        assert_eq!(output[0], 0.1);
+        assert_eq!(output[9], 0.5);
+    }
+
+    #[test]
+    fn duplicates_mono_into_every_channel_and_pads_underruns() {
+        let mut buffer = MonitorBuffer::new(48_000, 20);
+        buffer.push(&[0.25, -0.25]);
+
+        let mut output = [1.0; 6];
+        buffer.fill_interleaved(&mut output, 2);
+        assert_eq!(output, [0.25, 0.25, -0.25, -0.25, 0.0, 0.0]);
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn resamples_between_device_rates() {
+        let input = vec![0.0, 1.0, 0.0, -1.0];
+        assert_eq!(resample_linear(&input, 16_000, 16_000), input);
+
+        let upsampled = resample_linear(&input, 16_000, 32_000);
+        assert_eq!(upsampled.len(), 8);
+        assert!((upsampled[1] - 0.5).abs() < 1e-6);
+
+        assert_eq!(resample_linear(&input, 48_000, 24_000), vec![0.0, 0.0]);
+    }
+}
@@ -0,0 +1,253 @@
+use realfft::num_complex::Complex;
+use realfft::RealFftPlanner;
+use serde::{Deserialize, Serialize};
+use std::f32::consts::PI;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+pub const FRAME_SIZE: usize = 512;
+pub const CALIBRATION_DURATION_MS: u64 = 2_000;
+pub const DEFAULT_MICROPHONE_KEY: &str = "default";
+
+const HOP_SIZE: usize = FRAME_SIZE / 2;
+const BIN_COUNT: usize = FRAME_SIZE / 2 + 1;
+const OVER_SUBTRACTION: f32 = 1.5;
+const SPECTRAL_FLOOR: f32 = 0.05;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct NoiseProfile {
+    pub microphone_id: String,
+    pub magnitudes: Vec<f32>,
+    pub frame_count: usize,
+    pub captured_unix_ms: u64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct NoiseProfiles {
+    #[serde(default)]
+    pub profiles: Vec<NoiseProfile>,
+}
+
+impl NoiseProfiles {
+    pub fn find(&self, microphone_id: &str) -> Option<&NoiseProfile> {
+        self.profiles
+            .iter()
+            .find(|profile| profile.microphone_id == microphone_id)
+    }
+
+    pub fn upsert(&mut self, profile: NoiseProfile) {
+        self.profiles
+            .retain(|existing| existing.microphone_id != profile.microphone_id);
+        self.profiles.push(profile);
+    }
+}
+
+pub fn microphone_key(microphone_id: Option<&str>) -> String {
+    microphone_id
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .unwrap_or(DEFAULT_MICROPHONE_KEY)
+        .to_string()
+}
+
+pub fn build_noise_profile(
+    microphone_id: &str,
+    samples: &[f32],
+    captured_unix_ms: u64,
+) -> Result<NoiseProfile, String> {
+    if samples.len() < FRAME_SIZE {
+        return Err(format!(
+            "noise calibration needs at least {FRAME_SIZE} samples, got {}",
+            samples.len()
+        ));
+    }
+
+    let window = analysis_window();
+    let fft = RealFftPlanner::<f32>::new().plan_fft_forward(FRAME_SIZE);
+    let mut frame = fft.make_input_vec();
+    let mut spectrum = fft.make_output_vec();
+    let mut magnitudes = vec![0f32; BIN_COUNT];
+    let mut frame_count = 0usize;
+
+    for start in (0..=samples.len() - FRAME_SIZE).step_by(HOP_SIZE) {
+        for (index, value) in frame.iter_mut().enumerate() {
+            *value = samples[start + index] * window[index];
+        }
+        fft.process(&mut frame, &mut spectrum)
+            .map_err(|error| error.to_string())?;
+        for (total, bin) in magnitudes.iter_mut().zip(&spectrum) {
+            *total += bin.norm();
+        }
+        frame_count += 1;
+    }
+
+    for total in &mut magnitudes {
+        *total /= frame_count as f32;
+    }
+
+    Ok(NoiseProfile {
+        microphone_id: microphone_id.to_string(),
+        magnitudes,
+        frame_count,
+        captured_unix_ms,
+    })
+}
+
+pub fn spectral_subtract(samples: &[f32], profile: &NoiseProfile) -> Vec<f32> {
+    if profile.magnitudes.len() != BIN_COUNT || samples.len() < FRAME_SIZE {
+        return samples.to_vec();
+    }
+
+    let window = analysis_window();
+    let mut planner = RealFftPlanner::<f32>::new();
+    let forward = planner.plan_fft_forward(FRAME_SIZE);
+    let inverse = planner.plan_fft_inverse(FRAME_SIZE);
+    let mut frame = forward.make_input_vec();
+    let mut spectrum = forward.make_output_vec();
+
+    // Pad by a hop on each side so every sample is covered by two overlapping frames.
+    let mut padded = vec![0f32; HOP_SIZE];
+    padded.extend_from_slice(samples);
+    padded.resize(samples.len() + HOP_SIZE + FRAME_SIZE, 0.0);
+    let mut output = vec![0f32; padded.len()];
+
+    for start in (0..=padded.len() - FRAME_SIZE).step_by(HOP_SIZE) {
+        for (index, value) in frame.iter_mut().enumerate() {
+            *value = padded[start + index] * window[index];
+        }
+        if forward.process(&mut frame, &mut spectrum).is_err() {
+            return samples.to_vec();
+        }
+        for (bin, noise) in spectrum.iter_mut().zip(&profile.magnitudes) {
+            *bin = suppress_bin(*bin, *noise);
+        }
+        spectrum[0].im = 0.0;
+        spectrum[BIN_COUNT - 1].im = 0.0;
+        if inverse.process(&mut spectrum, &mut frame).is_err() {
+            return samples.to_vec();
+        }
+        for (index, value) in frame.iter().enumerate() {
+            output[start + index] += value * window[index] / FRAME_SIZE as f32;
+        }
+    }
+
+    output[HOP_SIZE..HOP_SIZE + samples.len()].to_vec()
+}
+
+fn suppress_bin(bin: Complex<f32>, noise: f32) -> Complex<f32> {
+    let magnitude = bin.norm();
+    if magnitude <= f32::EPSILON {
+        return bin;
+    }
+    let cleaned = (magnitude - OVER_SUBTRACTION * noise).max(SPECTRAL_FLOOR * magnitude);
+    bin * (cleaned / magnitude)
+}
+
+// Square-root Hann: applied on analysis and synthesis, it sums to unity at 50% overlap.
+fn analysis_window() -> Vec<f32> {
+    (0..FRAME_SIZE)
+        .map(|index| (0.5 - 0.5 * (2.0 * PI * index as f32 / FRAME_SIZE as f32).cos()).sqrt())
+        .collect()
+}
+
+pub fn default_noise_profiles_path() -> PathBuf {
+    let base = dirs::config_dir().unwrap_or_else(|| PathBuf::from("."));
+    base.join("sonora-dictation").join("noise-profiles.json")
+}
+
+pub fn load_or_default(path: &Path) -> NoiseProfiles {
+    match fs::read_to_string(path) {
+        Ok(contents) => serde_json::from_str::<NoiseProfiles>(&contents).unwrap_or_default(),
+        Err(_) => NoiseProfiles::default(),
+    }
+}
+
+pub fn save(path: &Path, profiles: &NoiseProfiles) -> Result<(), String> {
+    let parent = path
+        .parent()
+        .ok_or_else(|| "noise profile path has no parent directory".to_string())?;
+    fs::create_dir_all(parent).map_err(io_to_string)?;
+    let payload = serde_json::to_string_pretty(profiles).map_err(|error| error.to_string())?;
+    fs::write(path, payload).map_err(io_to_string)
+}
+
+fn io_to_string(error: io::Error) -> String {
+    error.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    fn hiss(len: usize) -> Vec<f32> {
+        let mut state = 0x2545_f491u32;
+        (0..len)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                (state as f32 / u32::MAX as f32 - 0.5) * 0.02
+            })
+            .collect()
+    }
+
+    fn energy(samples: &[f32]) -> f32 {
+        samples.iter().map(|value| value * value).sum()
+    }
+
+    #[test]
+    fn reduces_learned_noise_while_keeping_length() {
+        let profile =
+            build_noise_profile("usb-mic", &hiss(8_000), 1).expect("profile should build");
+        assert_eq!(profile.magnitudes.len(), BIN_COUNT);
+
+        let noisy = hiss(4_000);
+        let cleaned = spectral_subtract(&noisy, &profile);
+        assert_eq!(cleaned.len(), noisy.len());
+        assert!(energy(&cleaned) < energy(&noisy) * 0.25);
+    }
+
+    #[test]
+    fn preserves_tones_above_the_noise_floor() {
+        let profile =
+            build_noise_profile("usb-mic", &hiss(8_000), 1).expect("profile should build");
+        let tone = (0..4_000)
+            .map(|index| (index as f32 * 0.2).sin() * 0.3)
+            .collect::<Vec<_>>();
+        let cleaned = spectral_subtract(&tone, &profile);
+        assert!(energy(&cleaned) > energy(&tone) * 0.8);
+    }
+
+    #[test]
+    fn rejects_short_calibration_samples() {
+        assert!(build_noise_profile("usb-mic", &[0.0; 100], 1).is_err());
+        assert_eq!(microphone_key(Some("  ")), DEFAULT_MICROPHONE_KEY);
+    }
+
+    #[test]
+    fn persists_profiles_per_microphone() {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("clock should be set")
+            .as_nanos();
+        let path = std::env::temp_dir()
+            .join(format!("sonora-noise-{nanos}"))
+            .join("noise-profiles.json");
+
+        let mut profiles = NoiseProfiles::default();
+        profiles.upsert(build_noise_profile("a", &hiss(1_024), 1).expect("profile should build"));
+        profiles.upsert(build_noise_profile("a", &hiss(2_048), 2).expect("profile should build"));
+        profiles.upsert(build_noise_profile("b", &hiss(1_024), 3).expect("profile should build"));
+        save(&path, &profiles).expect("profiles should save");
+
+        let loaded = load_or_default(&path);
+        let _ = fs::remove_dir_all(path.parent().expect("path should have parent"));
+        assert_eq!(loaded.profiles.len(), 2);
+        assert_eq!(
+            loaded.find("a").map(|profile| profile.captured_unix_ms),
+            Some(2)
+        );
+    }
+}
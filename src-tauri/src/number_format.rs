@@ -0,0 +1,540 @@
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CurrencyPosition {
+    Before,
+    After,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+pub struct NumberLocale {
+    pub code: &'static str,
+    pub name: &'static str,
+    pub decimal_separator: char,
+    pub group_separator: char,
+    pub currency_position: CurrencyPosition,
+    // Most continental locales write "5 %" rather than "5%".
+    pub space_before_percent: bool,
+}
+
+pub const NUMBER_LOCALES: &[NumberLocale] = &[
+    NumberLocale {
+        code: "en-US",
+        name: "English (United States)",
+        decimal_separator: '.',
+        group_separator: ',',
+        currency_position: CurrencyPosition::Before,
+        space_before_percent: false,
+    },
+    NumberLocale {
+        code: "en-GB",
+        name: "English (United Kingdom)",
+        decimal_separator: '.',
+        group_separator: ',',
+        currency_position: CurrencyPosition::Before,
+        space_before_percent: false,
+    },
+    NumberLocale {
+        code: "de-DE",
+        name: "Deutsch (Deutschland)",
+        decimal_separator: ',',
+        group_separator: '.',
+        currency_position: CurrencyPosition::After,
+        space_before_percent: true,
+    },
+    NumberLocale {
+        code: "fr-FR",
+        name: "Français (France)",
+        decimal_separator: ',',
+        group_separator: ' ',
+        currency_position: CurrencyPosition::After,
+        space_before_percent: true,
+    },
+    NumberLocale {
+        code: "es-ES",
+        name: "Español (España)",
+        decimal_separator: ',',
+        group_separator: '.',
+        currency_position: CurrencyPosition::After,
+        space_before_percent: true,
+    },
+];
+
+const UNITS: &[(&str, &str)] = &[
+    ("kilometers", "km"),
+    ("kilometres", "km"),
+    ("kilometer", "km"),
+    ("kilometre", "km"),
+    ("meters", "m"),
+    ("metres", "m"),
+    ("meter", "m"),
+    ("metre", "m"),
+    ("centimeters", "cm"),
+    ("centimetres", "cm"),
+    ("centimeter", "cm"),
+    ("centimetre", "cm"),
+    ("millimeters", "mm"),
+    ("millimetres", "mm"),
+    ("millimeter", "mm"),
+    ("millimetre", "mm"),
+    ("kilograms", "kg"),
+    ("kilogram", "kg"),
+    ("grams", "g"),
+    ("gram", "g"),
+    ("liters", "l"),
+    ("litres", "l"),
+    ("liter", "l"),
+    ("litre", "l"),
+    ("milliliters", "ml"),
+    ("millilitres", "ml"),
+    ("milliliter", "ml"),
+    ("millilitre", "ml"),
+];
+
+const CURRENCIES: &[(&str, &str)] = &[
+    ("dollars", "$"),
+    ("dollar", "$"),
+    ("euros", "€"),
+    ("euro", "€"),
+    ("pounds", "£"),
+    ("pound", "£"),
+];
+
+const ONES: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+const TENS: [&str; 8] = [
+    "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+// Accepts "de-DE", "de_de" or a bare language such as "de" (first preset wins).
+pub fn find_locale(code: &str) -> Option<&'static NumberLocale> {
+    let wanted = code.trim().replace('_', "-").to_lowercase();
+    if wanted.is_empty() {
+        return None;
+    }
+    NUMBER_LOCALES
+        .iter()
+        .find(|locale| locale.code.to_lowercase() == wanted)
+        .or_else(|| {
+            NUMBER_LOCALES.iter().find(|locale| {
+                locale
+                    .code
+                    .split('-')
+                    .next()
+                    .is_some_and(|language| language.eq_ignore_ascii_case(&wanted))
+            })
+        })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ParsedNumber {
+    // Kept as text so leading zeros and long digit runs survive.
+    digits: String,
+    fraction: Option<String>,
+    // Leading symbol from recognizer output such as "$3.50".
+    currency: Option<&'static str>,
+    // Digit runs written without separators stay that way, so zip codes, order
+    // numbers and years are not regrouped; spoken values group from five digits.
+    grouped: bool,
+    spoken: bool,
+    end: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SpokenKind {
+    Ones(u64),
+    Tens,
+    Hundred,
+    Scale,
+}
+
+// Spoken numbers are only rewritten when they carry a decimal or a unit, so
+// everyday phrases such as "one of them" are left alone.
+// Only the number spans are rewritten; the text between them is copied as is.
+pub fn format_numbers(text: &str, locale: &NumberLocale) -> String {
+    let spans = word_spans(text);
+    let words = spans
+        .iter()
+        .map(|&(start, end)| &text[start..end])
+        .collect::<Vec<_>>();
+    let mut output = String::with_capacity(text.len());
+    let mut copied = 0;
+    let mut index = 0;
+
+    while index < words.len() {
+        let Some(number) = parse_number(&words, index) else {
+            index += 1;
+            continue;
+        };
+
+        let last = words[number.end - 1];
+        let mut trailing = trailing_punctuation(last);
+        let mut end = number.end;
+        let mut suffix = None;
+        if trailing.is_empty() && number.currency.is_none() {
+            if let Some(next) = words.get(end) {
+                let key = word_key(next);
+                if let Some((_, symbol)) = UNITS.iter().find(|(word, _)| *word == key) {
+                    suffix = Some(Suffix::Unit(symbol));
+                } else if let Some((_, symbol)) = CURRENCIES.iter().find(|(word, _)| *word == key) {
+                    suffix = Some(Suffix::Currency(symbol));
+                } else if key == "percent" {
+                    suffix = Some(Suffix::Percent);
+                }
+                if suffix.is_some() {
+                    trailing = trailing_punctuation(next);
+                    end += 1;
+                }
+            }
+        }
+
+        if number.spoken && number.fraction.is_none() && suffix.is_none() {
+            index += 1;
+            continue;
+        }
+
+        let amount = format_amount(&number, locale);
+        let formatted = match (number.currency, suffix) {
+            (Some(symbol), _) | (None, Some(Suffix::Currency(symbol))) => {
+                match locale.currency_position {
+                    CurrencyPosition::Before => format!("{symbol}{amount}"),
+                    CurrencyPosition::After => format!("{amount} {symbol}"),
+                }
+            }
+            (None, Some(Suffix::Unit(symbol))) => format!("{amount} {symbol}"),
+            (None, Some(Suffix::Percent)) if locale.space_before_percent => format!("{amount} %"),
+            (None, Some(Suffix::Percent)) => format!("{amount}%"),
+            (None, None) => amount,
+        };
+        output.push_str(&text[copied..spans[index].0]);
+        output.push_str(&formatted);
+        output.push_str(trailing);
+        copied = spans[end - 1].1;
+        index = end;
+    }
+
+    output.push_str(&text[copied..]);
+    output
+}
+
+// Byte ranges of the whitespace-separated words.
+fn word_spans(text: &str) -> Vec<(usize, usize)> {
+    let mut spans = Vec::new();
+    let mut start = None;
+    for (offset, ch) in text.char_indices() {
+        match (ch.is_whitespace(), start) {
+            (false, None) => start = Some(offset),
+            (true, Some(begin)) => {
+                spans.push((begin, offset));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(begin) = start {
+        spans.push((begin, text.len()));
+    }
+    spans
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Suffix {
+    Unit(&'static str),
+    Currency(&'static str),
+    Percent,
+}
+
+fn parse_number(words: &[&str], start: usize) -> Option<ParsedNumber> {
+    parse_digits(words[start], start).or_else(|| parse_spoken(words, start))
+}
+
+// Reformats numbers the recognizer already wrote as digits, e.g. "1,250.5".
+fn parse_digits(word: &str, index: usize) -> Option<ParsedNumber> {
+    let body = word.trim_end_matches(is_trailing_punctuation);
+    let (currency, body) = match CURRENCIES
+        .iter()
+        .find(|(_, symbol)| body.starts_with(symbol))
+    {
+        Some((_, symbol)) => (Some(*symbol), &body[symbol.len()..]),
+        None => (None, body),
+    };
+    let (integer, fraction) = match body.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (body, None),
+    };
+    let groups = integer.split(',').collect::<Vec<_>>();
+    let grouped_ok = groups.len() == 1
+        || (!groups[0].is_empty()
+            && groups[0].len() <= 3
+            && groups[1..].iter().all(|group| group.len() == 3));
+    let all_digits = |part: &str| !part.is_empty() && part.chars().all(|ch| ch.is_ascii_digit());
+    if !grouped_ok
+        || !groups.iter().all(|group| all_digits(group))
+        || fraction.is_some_and(|fraction| !all_digits(fraction))
+    {
+        return None;
+    }
+    Some(ParsedNumber {
+        digits: groups.concat(),
+        fraction: fraction.map(str::to_string),
+        currency,
+        grouped: groups.len() > 1,
+        spoken: false,
+        end: index + 1,
+    })
+}
+
+// Each group below a scale takes one "hundred", and scales must fall, so
+// "five hundred five hundred" stops before the second "hundred".
+fn parse_spoken(words: &[&str], start: usize) -> Option<ParsedNumber> {
+    let mut total = 0u64;
+    let mut current = 0u64;
+    let mut last: Option<SpokenKind> = None;
+    let mut had_hundred = false;
+    let mut last_scale = u64::MAX;
+    let mut index = start;
+
+    while index < words.len() {
+        let parts = word_key(words[index]);
+        let mut next = last;
+        let mut value = current;
+        let mut group_hundred = had_hundred;
+        let mut group_scale = last_scale;
+        let mut word_total = total;
+        let mut accepted = true;
+        for part in parts.split('-') {
+            let kind = spoken_kind(part);
+            let allowed = match (next, kind) {
+                (
+                    None | Some(SpokenKind::Scale | SpokenKind::Hundred),
+                    Some(SpokenKind::Ones(_)),
+                ) => true,
+                (Some(SpokenKind::Tens), Some(SpokenKind::Ones(n))) => (1..10).contains(&n),
+                (None | Some(SpokenKind::Scale | SpokenKind::Hundred), Some(SpokenKind::Tens)) => {
+                    true
+                }
+                (Some(SpokenKind::Ones(_)), Some(SpokenKind::Hundred)) => !group_hundred,
+                (
+                    Some(SpokenKind::Ones(_) | SpokenKind::Tens | SpokenKind::Hundred),
+                    Some(SpokenKind::Scale),
+                ) => scale_value(part) < group_scale,
+                _ => false,
+            };
+            if !allowed {
+                accepted = false;
+                break;
+            }
+            match kind {
+                Some(SpokenKind::Ones(n)) => value = value.checked_add(n)?,
+                Some(SpokenKind::Tens) => value = value.checked_add(tens_value(part))?,
+                Some(SpokenKind::Hundred) => {
+                    value = value.checked_mul(100)?;
+                    group_hundred = true;
+                }
+                Some(SpokenKind::Scale) => {
+                    let scale = scale_value(part);
+                    word_total = word_total.checked_add(value.checked_mul(scale)?)?;
+                    value = 0;
+                    group_hundred = false;
+                    group_scale = scale;
+                }
+                None => {}
+            }
+            next = kind;
+        }
+        if !accepted {
+            break;
+        }
+        current = value;
+        last = next;
+        had_hundred = group_hundred;
+        last_scale = group_scale;
+        total = word_total;
+        index += 1;
+        if !trailing_punctuation(words[index - 1]).is_empty() {
+            break;
+        }
+    }
+
+    last?;
+    let integer = total.checked_add(current)?;
+    let mut fraction = String::new();
+    let ends_clean = trailing_punctuation(words[index - 1]).is_empty();
+    if ends_clean
+        && words
+            .get(index)
+            .is_some_and(|word| word_key(word) == "point")
+    {
+        let mut cursor = index + 1;
+        while let Some(digit) = words.get(cursor).and_then(|word| spoken_digit(word)) {
+            fraction.push(digit);
+            cursor += 1;
+            if !trailing_punctuation(words[cursor - 1]).is_empty() {
+                break;
+            }
+        }
+        if !fraction.is_empty() {
+            index = cursor;
+        }
+    }
+
+    Some(ParsedNumber {
+        digits: integer.to_string(),
+        fraction: (!fraction.is_empty()).then_some(fraction),
+        currency: None,
+        grouped: false,
+        spoken: true,
+        end: index,
+    })
+}
+
+fn spoken_kind(word: &str) -> Option<SpokenKind> {
+    if let Some(value) = ONES.iter().position(|ones| *ones == word) {
+        return Some(SpokenKind::Ones(value as u64));
+    }
+    if TENS.contains(&word) {
+        return Some(SpokenKind::Tens);
+    }
+    match word {
+        "hundred" => Some(SpokenKind::Hundred),
+        "thousand" | "million" => Some(SpokenKind::Scale),
+        _ => None,
+    }
+}
+
+fn tens_value(word: &str) -> u64 {
+    TENS.iter()
+        .position(|tens| *tens == word)
+        .map(|position| (position as u64 + 2) * 10)
+        .unwrap_or(0)
+}
+
+fn scale_value(word: &str) -> u64 {
+    if word == "million" {
+        1_000_000
+    } else {
+        1_000
+    }
+}
+
+fn spoken_digit(word: &str) -> Option<char> {
+    let key = word_key(word);
+    ONES[..10]
+        .iter()
+        .position(|ones| *ones == key)
+        .and_then(|value| char::from_digit(value as u32, 10))
+}
+
+fn format_amount(number: &ParsedNumber, locale: &NumberLocale) -> String {
+    let digits = &number.digits;
+    let group = number.grouped || (number.spoken && digits.len() > 4);
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (position, ch) in digits.chars().enumerate() {
+        if group && position > 0 && (digits.len() - position).is_multiple_of(3) {
+            grouped.push(locale.group_separator);
+        }
+        grouped.push(ch);
+    }
+    match &number.fraction {
+        Some(fraction) => format!("{grouped}{}{fraction}", locale.decimal_separator),
+        None => grouped,
+    }
+}
+
+fn is_trailing_punctuation(ch: char) -> bool {
+    matches!(ch, '.' | ',' | '!' | '?' | ';' | ':')
+}
+
+fn trailing_punctuation(word: &str) -> &str {
+    let body = word.trim_end_matches(is_trailing_punctuation);
+    &word[body.len()..]
+}
+
+fn word_key(word: &str) -> String {
+    word.trim_matches(|ch: char| ch.is_ascii_punctuation() && ch != '-')
+        .to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spoken_decimals_with_units_follow_the_locale() {
+        let text = "It is three point five kilometers away.";
+        let us = find_locale("en-US").expect("en-US preset should exist");
+        let de = find_locale("de_de").expect("de-DE preset should exist");
+        assert_eq!(format_numbers(text, us), "It is 3.5 km away.");
+        assert_eq!(format_numbers(text, de), "It is 3,5 km away.");
+        assert_eq!(
+            format_numbers("That costs twenty-five euros.", de),
+            "That costs 25 €."
+        );
+        assert_eq!(
+            format_numbers("Growth was 12.5 percent, up from 10,500.", de),
+            "Growth was 12,5 %, up from 10.500."
+        );
+        assert_eq!(
+            format_numbers("It costs $3.50 today", us),
+            "It costs $3.50 today"
+        );
+    }
+
+    #[test]
+    fn plain_spoken_numbers_and_unknown_locales_are_left_alone() {
+        let us = find_locale("en").expect("bare language should match a preset");
+        assert_eq!(us.code, "en-US");
+        assert_eq!(
+            format_numbers("One of them said two things", us),
+            "One of them said two things"
+        );
+        assert!(find_locale("xx-YY").is_none());
+    }
+
+    #[test]
+    fn bare_digit_runs_and_spacing_are_kept() {
+        let us = find_locale("en-US").expect("en-US preset should exist");
+        let de = find_locale("de-DE").expect("de-DE preset should exist");
+        assert_eq!(
+            format_numbers("Ship order 123456 to 02134, not 90210.", de),
+            "Ship order 123456 to 02134, not 90210."
+        );
+        assert_eq!(
+            format_numbers("forty five thousand kilometers", us),
+            "45,000 km"
+        );
+        assert_eq!(
+            format_numbers("Notes:\n\n  ninety  point five percent\ttoo", us),
+            "Notes:\n\n  90.5%\ttoo"
+        );
+
+        let words = ["one", "hundred", "two", "hundred", "kilometers"];
+        let number = parse_spoken(&words, 0).expect("a number should parse");
+        assert_eq!((number.digits.as_str(), number.end), ("102", 3));
+        let words = ["two", "million", "three", "million"];
+        let number = parse_spoken(&words, 0).expect("a number should parse");
+        assert_eq!((number.digits.as_str(), number.end), ("2000003", 3));
+    }
+}
@@ -0,0 +1,102 @@
+use serde::Serialize;
+use std::process::Command;
+
+use crate::sandbox::SandboxTool;
+
+pub const SIDECAR_OFFLINE_ENV: &[(&str, &str)] = &[
+    ("HF_HUB_OFFLINE", "1"),
+    ("TRANSFORMERS_OFFLINE", "1"),
+    ("HF_DATASETS_OFFLINE", "1"),
+];
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct OfflineReport {
+    pub enabled: bool,
+    pub downloads_disabled: bool,
+    pub sidecar_network_blocked: bool,
+    // The LAN transcript viewer is configured but held off by offline-only.
+    pub viewer_blocked: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sandbox_tool: Option<String>,
+    pub notes: Vec<String>,
+}
+
+pub fn apply_offline_env(command: &mut Command) {
+    for (name, value) in SIDECAR_OFFLINE_ENV {
+        command.env(name, value);
+    }
+}
+
+pub fn offline_report(
+    enabled: bool,
+    sandbox_tool: Option<SandboxTool>,
+    viewer_requested: bool,
+) -> OfflineReport {
+    let mut notes = Vec::new();
+    if !enabled {
+        notes.push(
+            "Offline-only mode is off; sidecars may download models on first use.".to_string(),
+        );
+        return OfflineReport {
+            enabled,
+            downloads_disabled: false,
+            sidecar_network_blocked: false,
+            viewer_blocked: false,
+            sandbox_tool: sandbox_tool.map(|tool| tool.description().to_string()),
+            notes,
+        };
+    }
+
+    notes.push("Model hub downloads are disabled for sidecar workers.".to_string());
+    let sidecar_network_blocked = sandbox_tool.is_some();
+    match sandbox_tool {
+        Some(tool) => notes.push(format!(
+            "Sidecar network access is blocked via {}.",
+            tool.description()
+        )),
+        None => notes.push(
+            "No sandbox tool is available; sidecar network access relies on offline flags only."
+                .to_string(),
+        ),
+    }
+    if viewer_requested {
+        notes
+            .push("The LAN transcript viewer stays off while offline-only mode is on.".to_string());
+    }
+
+    OfflineReport {
+        enabled,
+        downloads_disabled: true,
+        sidecar_network_blocked,
+        viewer_blocked: viewer_requested,
+        sandbox_tool: sandbox_tool.map(|tool| tool.description().to_string()),
+        notes,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_network_blocking_only_with_a_sandbox_tool() {
+        let blocked = offline_report(true, Some(SandboxTool::Bubblewrap), true);
+        assert!(blocked.downloads_disabled);
+        assert!(blocked.sidecar_network_blocked);
+        assert!(blocked.viewer_blocked);
+
+        let flags_only = offline_report(true, None, false);
+        assert!(!flags_only.viewer_blocked);
+        assert!(flags_only.downloads_disabled);
+        assert!(!flags_only.sidecar_network_blocked);
+        assert!(flags_only
+            .notes
+            .iter()
+            .any(|note| note.contains("offline flags")));
+
+        let off = offline_report(false, Some(SandboxTool::Unshare), true);
+        assert!(!off.downloads_disabled);
+        assert!(!off.viewer_blocked);
+        assert!(!off.sidecar_network_blocked);
+    }
+}
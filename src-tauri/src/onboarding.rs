@@ -0,0 +1,232 @@
+use serde::Serialize;
+
+use crate::config::ModelProfile;
+use crate::mic_test::ClipStats;
+
+pub const REFERENCE_SENTENCES: &[&str] = &[
+    "The quick brown fox jumps over the lazy dog near the riverbank.",
+    "Please schedule the quarterly review for next Thursday at three thirty.",
+    "Our new heating system saves energy during the coldest winter months.",
+    "She sent seven detailed reports to the marketing team before lunch.",
+];
+
+const GOOD_WER: f32 = 0.1;
+const POOR_WER: f32 = 0.3;
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+pub struct ReferenceSentence {
+    pub id: usize,
+    pub text: &'static str,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq)]
+pub struct WordErrors {
+    pub reference_words: usize,
+    pub substitutions: usize,
+    pub deletions: usize,
+    pub insertions: usize,
+    pub wer: f32,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Recommendation {
+    RaiseGain,
+    LowerGain,
+    SwitchMicrophone,
+    EnableNoiseSuppression,
+    UseBalancedProfile,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct DictationScore {
+    pub reference: String,
+    pub transcript: String,
+    pub errors: WordErrors,
+    pub score: u8,
+    pub recommendations: Vec<Recommendation>,
+}
+
+pub fn reference_sentence(id: usize) -> ReferenceSentence {
+    let id = id % REFERENCE_SENTENCES.len();
+    ReferenceSentence {
+        id,
+        text: REFERENCE_SENTENCES[id],
+    }
+}
+
+pub fn word_errors(reference: &str, hypothesis: &str) -> WordErrors {
+    let reference = normalized_words(reference);
+    let hypothesis = normalized_words(hypothesis);
+
+    // Each cell tracks (cost, substitutions, deletions, insertions) for the best alignment.
+    let mut previous = (0..=hypothesis.len())
+        .map(|index| (index, 0, 0, index))
+        .collect::<Vec<_>>();
+    for (row, reference_word) in reference.iter().enumerate() {
+        let mut current = vec![(row + 1, 0, row + 1, 0)];
+        for (column, hypothesis_word) in hypothesis.iter().enumerate() {
+            let diagonal = previous[column];
+            let matched = if reference_word == hypothesis_word {
+                diagonal
+            } else {
+                (diagonal.0 + 1, diagonal.1 + 1, diagonal.2, diagonal.3)
+            };
+            let deleted = previous[column + 1];
+            let deleted = (deleted.0 + 1, deleted.1, deleted.2 + 1, deleted.3);
+            let inserted = current[column];
+            let inserted = (inserted.0 + 1, inserted.1, inserted.2, inserted.3 + 1);
+            current.push(
+                [matched, deleted, inserted]
+                    .into_iter()
+                    .min_by_key(|cell| cell.0)
+                    .unwrap_or(matched),
+            );
+        }
+        previous = current;
+    }
+
+    let (cost, substitutions, deletions, insertions) = previous[hypothesis.len()];
+    WordErrors {
+        reference_words: reference.len(),
+        substitutions,
+        deletions,
+        insertions,
+        wer: if reference.is_empty() {
+            if hypothesis.is_empty() {
+                0.0
+            } else {
+                1.0
+            }
+        } else {
+            cost as f32 / reference.len() as f32
+        },
+    }
+}
+
+pub fn score_dictation(
+    reference: &str,
+    transcript: &str,
+    stats: &ClipStats,
+    profile: ModelProfile,
+    noise_suppression_enabled: bool,
+) -> DictationScore {
+    let errors = word_errors(reference, transcript);
+    let score = ((1.0 - errors.wer.min(1.0)) * 100.0).round() as u8;
+    DictationScore {
+        reference: reference.to_string(),
+        transcript: transcript.to_string(),
+        errors,
+        score,
+        recommendations: recommend(errors.wer, stats, profile, noise_suppression_enabled),
+    }
+}
+
+// Capture problems are fixed first; a bigger model only helps once the input is clean.
+fn recommend(
+    wer: f32,
+    stats: &ClipStats,
+    profile: ModelProfile,
+    noise_suppression_enabled: bool,
+) -> Vec<Recommendation> {
+    if wer <= GOOD_WER {
+        return Vec::new();
+    }
+
+    let mut recommendations = Vec::new();
+    let warned = |needle: &str| {
+        stats
+            .warnings
+            .iter()
+            .any(|warning| warning.contains(needle))
+    };
+    if warned("quiet") {
+        recommendations.push(Recommendation::RaiseGain);
+    }
+    if warned("clipping") {
+        recommendations.push(Recommendation::LowerGain);
+    }
+    if warned("noise") {
+        if !noise_suppression_enabled {
+            recommendations.push(Recommendation::EnableNoiseSuppression);
+        }
+        recommendations.push(Recommendation::SwitchMicrophone);
+    }
+    if recommendations.is_empty() && wer > POOR_WER && profile == ModelProfile::Fast {
+        recommendations.push(Recommendation::UseBalancedProfile);
+    }
+    recommendations
+}
+
+fn normalized_words(text: &str) -> Vec<String> {
+    text.split_whitespace()
+        .map(|word| {
+            word.chars()
+                .filter(|ch| ch.is_alphanumeric() || *ch == '\'')
+                .flat_map(char::to_lowercase)
+                .collect::<String>()
+        })
+        .filter(|word| !word.is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn clean_stats() -> ClipStats {
+        ClipStats {
+            duration_ms: 4_000,
+            rms: 0.1,
+            peak: 0.6,
+            clipped_ratio: 0.0,
+            snr_db: Some(30.0),
+            warnings: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn counts_each_kind_of_word_error() {
+        let exact = word_errors("The quick brown fox.", "the quick, brown fox");
+        assert_eq!(exact.wer, 0.0);
+
+        let errors = word_errors("the quick brown fox", "the quack fox");
+        assert_eq!(errors.substitutions, 1);
+        assert_eq!(errors.deletions, 1);
+        assert_eq!(errors.wer, 0.5);
+        assert_eq!(word_errors("jumps over", "jumps right over").insertions, 1);
+        assert_eq!(word_errors("", "").wer, 0.0);
+        assert_eq!(word_errors("hello", "").deletions, 1);
+    }
+
+    #[test]
+    fn recommends_capture_fixes_before_a_bigger_model() {
+        let reference = reference_sentence(0).text;
+        let garbled = "the quick fox";
+
+        let quiet = ClipStats {
+            warnings: vec!["Input is very quiet; raise mic sensitivity.".to_string()],
+            ..clean_stats()
+        };
+        let scored = score_dictation(reference, garbled, &quiet, ModelProfile::Fast, false);
+        assert_eq!(scored.recommendations, vec![Recommendation::RaiseGain]);
+        assert!(scored.score < 50);
+
+        let clean = score_dictation(
+            reference,
+            garbled,
+            &clean_stats(),
+            ModelProfile::Fast,
+            false,
+        );
+        assert_eq!(
+            clean.recommendations,
+            vec![Recommendation::UseBalancedProfile]
+        );
+
+        let perfect = score_dictation(reference, reference, &quiet, ModelProfile::Fast, false);
+        assert_eq!(perfect.score, 100);
+        assert!(perfect.recommendations.is_empty());
+        assert_eq!(reference_sentence(REFERENCE_SENTENCES.len()).id, 0);
+    }
+}
@@ -0,0 +1,132 @@
+use serde::Serialize;
+use std::fs;
+use std::io;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub const MAX_TRACE_BYTES: u64 = 5 * 1024 * 1024;
+pub const MAX_ROTATED_FILES: usize = 3;
+
+#[derive(Serialize)]
+struct TraceLine<'a, T> {
+    timestamp_unix_ms: u128,
+    #[serde(flatten)]
+    entry: &'a T,
+}
+
+pub fn default_trace_path() -> PathBuf {
+    let base = dirs::config_dir().unwrap_or_else(|| PathBuf::from("."));
+    base.join("sonora-dictation").join("perf-trace.jsonl")
+}
+
+pub fn append<T: Serialize>(path: &Path, entry: &T, max_bytes: u64) -> Result<(), String> {
+    let parent = path
+        .parent()
+        .ok_or_else(|| "trace path has no parent directory".to_string())?;
+    fs::create_dir_all(parent).map_err(io_to_string)?;
+
+    if fs::metadata(path).is_ok_and(|metadata| metadata.len() >= max_bytes) {
+        rotate(path)?;
+    }
+
+    let timestamp_unix_ms = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|error| error.to_string())?
+        .as_millis();
+    let line = serde_json::to_string(&TraceLine {
+        timestamp_unix_ms,
+        entry,
+    })
+    .map_err(|error| error.to_string())?;
+
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(io_to_string)?;
+    file.write_all(line.as_bytes()).map_err(io_to_string)?;
+    file.write_all(b"\n").map_err(io_to_string)
+}
+
+pub fn rotated_path(path: &Path, index: usize) -> PathBuf {
+    let stem = path
+        .file_stem()
+        .map(|value| value.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let name = match path.extension() {
+        Some(extension) => format!("{stem}.{index}.{}", extension.to_string_lossy()),
+        None => format!("{stem}.{index}"),
+    };
+    path.with_file_name(name)
+}
+
+fn rotate(path: &Path) -> Result<(), String> {
+    let oldest = rotated_path(path, MAX_ROTATED_FILES);
+    if oldest.exists() {
+        fs::remove_file(&oldest).map_err(io_to_string)?;
+    }
+    for index in (1..MAX_ROTATED_FILES).rev() {
+        let from = rotated_path(path, index);
+        if from.exists() {
+            fs::rename(&from, rotated_path(path, index + 1)).map_err(io_to_string)?;
+        }
+    }
+    fs::rename(path, rotated_path(path, 1)).map_err(io_to_string)
+}
+
+fn io_to_string(error: io::Error) -> String {
+    error.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Chunk {
+        chunk_id: u64,
+        vad_ms: u64,
+    }
+
+    #[test]
+    fn appends_jsonl_and_rotates_past_the_size_limit() {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("clock should be set")
+            .as_nanos();
+        let dir = std::env::temp_dir().join(format!("sonora-trace-{nanos}"));
+        let path = dir.join("perf-trace.jsonl");
+
+        for chunk_id in 0..6 {
+            append(
+                &path,
+                &Chunk {
+                    chunk_id,
+                    vad_ms: 2,
+                },
+                1,
+            )
+            .expect("trace should append");
+        }
+
+        let current = fs::read_to_string(&path).expect("current trace should exist");
+        let newest_rotated =
+            fs::read_to_string(rotated_path(&path, 1)).expect("rotated trace should exist");
+        let rotated_count = (1..=MAX_ROTATED_FILES + 1)
+            .filter(|index| rotated_path(&path, *index).exists())
+            .count();
+        let _ = fs::remove_dir_all(&dir);
+
+        let line: serde_json::Value =
+            serde_json::from_str(current.trim()).expect("trace line should be json");
+        assert_eq!(line["chunk_id"], 5);
+        assert!(line["timestamp_unix_ms"].is_number());
+        assert!(newest_rotated.contains("\"chunk_id\":4"));
+        assert_eq!(rotated_count, MAX_ROTATED_FILES);
+        assert_eq!(
+            rotated_path(Path::new("/tmp/perf-trace.jsonl"), 2),
+            PathBuf::from("/tmp/perf-trace.2.jsonl")
+        );
+    }
+}
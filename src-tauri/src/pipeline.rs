@@ -1,10 +1,13 @@
 use serde::Serialize;
 use std::time::Instant;
 
-use crate::config::{DictationMode, ModelProfile};
+use crate::audio::PreprocessChain;
+use crate::chunk_cache::{chunk_key, CachedTranscript, ChunkCache};
+use crate::config::{default_preprocessing_chain, DictationMode, ModelProfile, PreprocessStep};
+use crate::noise_profile::NoiseProfile;
 use crate::profile::{tuning_for_profile, ProfileTuning};
 use crate::transcriber::Transcriber;
-use crate::vad::{has_speech, VadConfig};
+use crate::vad::{detect_speech_segments, SpeechSegment, VadConfig};
 
 #[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
@@ -28,7 +31,15 @@ pub struct DictationPipeline<T: Transcriber> {
     model_profile: ModelProfile,
     tuning: ProfileTuning,
     vad_config: VadConfig,
+    preprocessing: Vec<PreprocessStep>,
+    noise_profile: Option<NoiseProfile>,
     transcriber: T,
+    // A small model that answers while the configured one warms up.
+    cold_start: Option<T>,
+    // A recording capture listens regardless of the dictation hotkey.
+    listening_held: bool,
+    stream_context: Option<String>,
+    chunk_cache: ChunkCache,
 }
 
 #[derive(Debug, Clone)]
@@ -36,12 +47,18 @@ pub struct ChunkProcessMetrics {
     pub listening: bool,
     pub enough_samples: bool,
     pub had_speech: bool,
+    pub speech_segments: Vec<SpeechSegment>,
     pub vad_ms: u64,
     pub inference_ms: u64,
     pub engine: String,
     pub model: String,
     pub backend: String,
     pub transcript: Option<String>,
+    pub confidence: Option<f32>,
+    // The transcript came from the chunk cache instead of a fresh inference.
+    pub cache_hit: bool,
+    // Transcribed by the cold-start model rather than the configured one.
+    pub cold_start: bool,
 }
 
 impl<T: Transcriber> DictationPipeline<T> {
@@ -52,7 +69,13 @@ impl<T: Transcriber> DictationPipeline<T> {
             model_profile,
             tuning: tuning_for_profile(model_profile),
             vad_config: VadConfig::default(),
+            preprocessing: default_preprocessing_chain(),
+            noise_profile: None,
             transcriber,
+            cold_start: None,
+            listening_held: false,
+            stream_context: None,
+            chunk_cache: ChunkCache::default(),
         }
     }
 
@@ -67,6 +90,22 @@ impl<T: Transcriber> DictationPipeline<T> {
 
     pub fn set_mode(&mut self, mode: DictationMode) {
         self.mode = mode;
+        if !self.listening_held {
+            self.state = DictationState::Idle;
+        }
+    }
+
+    pub fn listening_held(&self) -> bool {
+        self.listening_held
+    }
+
+    pub fn hold_listening(&mut self) {
+        self.listening_held = true;
+        self.state = DictationState::Listening;
+    }
+
+    pub fn release_listening(&mut self) {
+        self.listening_held = false;
         self.state = DictationState::Idle;
     }
 
@@ -82,19 +121,92 @@ impl<T: Transcriber> DictationPipeline<T> {
         self.vad_config = vad_config;
     }
 
+    pub fn set_preprocessing(&mut self, preprocessing: Vec<PreprocessStep>) {
+        self.preprocessing = preprocessing;
+    }
+
+    pub fn set_noise_profile(&mut self, noise_profile: Option<NoiseProfile>) {
+        self.noise_profile = noise_profile;
+    }
+
+    pub fn vad_config(&self) -> &VadConfig {
+        &self.vad_config
+    }
+
     pub fn set_transcriber(&mut self, transcriber: T) {
         self.transcriber = transcriber;
+        self.end_cold_start();
+    }
+
+    pub fn start_cold_start(&mut self, transcriber: T) {
+        self.cold_start = Some(transcriber);
+    }
+
+    // False when no cold-start model was serving, e.g. because the
+    // transcriber was replaced while the old one warmed up.
+    pub fn end_cold_start(&mut self) -> bool {
+        match self.cold_start.take() {
+            Some(cold_start) => {
+                cold_start.end_session();
+                cold_start.unload();
+                true
+            }
+            None => false,
+        }
+    }
+
+    // Sidecar transcribers share their worker between clones, so a clone can
+    // warm up the model without holding the pipeline.
+    pub fn transcriber_handle(&self) -> T
+    where
+        T: Clone,
+    {
+        self.transcriber.clone()
     }
 
-    pub fn set_stream_context(&self, context: Option<&str>) {
+    pub fn set_stream_context(&mut self, context: Option<&str>) {
+        self.stream_context = context.map(str::to_string);
         self.transcriber.set_stream_context(context);
+        if let Some(cold_start) = &self.cold_start {
+            cold_start.set_stream_context(context);
+        }
     }
 
     pub fn prepare_transcriber(&self) -> Result<(), String> {
         self.transcriber.prepare()
     }
 
+    pub fn unload_transcriber(&self) {
+        self.transcriber.unload();
+    }
+
+    pub fn begin_transcriber_session(&self) -> Result<(), String> {
+        self.transcriber.begin_session()
+    }
+
+    pub fn end_transcriber_session(&self) {
+        self.transcriber.end_session();
+        if let Some(cold_start) = &self.cold_start {
+            cold_start.end_session();
+        }
+    }
+
+    pub fn transcriber_worker_pid(&self) -> Option<u32> {
+        self.transcriber.worker_pid()
+    }
+
+    pub fn transcriber_worker_restarts(&self) -> u32 {
+        self.transcriber.worker_restarts()
+    }
+
+    pub fn transcriber_engine_label(&self) -> &'static str {
+        self.transcriber.engine_label()
+    }
+
     pub fn on_hotkey_down(&mut self) {
+        if self.listening_held {
+            return;
+        }
         match self.state {
             DictationState::Idle => {
                 self.state = DictationState::Listening;
@@ -109,12 +221,18 @@ impl<T: Transcriber> DictationPipeline<T> {
     }
 
     pub fn on_hotkey_up(&mut self) {
+        if self.listening_held {
+            return;
+        }
         if self.mode == DictationMode::PushToTalk && self.state == DictationState::Listening {
             self.state = DictationState::Idle;
         }
     }
 
     pub fn cancel(&mut self) {
+        if self.listening_held {
+            return;
+        }
         self.state = DictationState::Idle;
     }
 
@@ -126,16 +244,21 @@ impl<T: Transcriber> DictationPipeline<T> {
         &mut self,
         samples: &[f32],
     ) -> Result<ChunkProcessMetrics, String> {
+        let transcriber = self.cold_start.as_ref().unwrap_or(&self.transcriber);
         let mut metrics = ChunkProcessMetrics {
             listening: self.state == DictationState::Listening,
             enough_samples: false,
             had_speech: false,
+            speech_segments: Vec::new(),
             vad_ms: 0,
             inference_ms: 0,
-            engine: self.transcriber.engine_label().to_string(),
-            model: self.transcriber.model_label(),
-            backend: self.transcriber.backend_label(),
+            engine: transcriber.engine_label().to_string(),
+            model: transcriber.model_label(),
+            backend: transcriber.backend_label(),
             transcript: None,
+            confidence: None,
+            cache_hit: false,
+            cold_start: self.cold_start.is_some(),
         };
 
         if !metrics.listening {
@@ -147,20 +270,47 @@ impl<T: Transcriber> DictationPipeline<T> {
         }
         metrics.enough_samples = true;
 
+        let chain = PreprocessChain::from_steps(&self.preprocessing, self.noise_profile.as_ref());
+        let (vad_samples, samples) = chain.process(samples);
+
         let vad_started_at = Instant::now();
-        let has_voice = has_speech(samples, &self.vad_config);
+        let speech_segments = detect_speech_segments(&vad_samples, &self.vad_config);
         metrics.vad_ms = vad_started_at.elapsed().as_millis() as u64;
+        let has_voice = !speech_segments.is_empty();
         metrics.had_speech = has_voice;
+        metrics.speech_segments = speech_segments;
 
         if !has_voice {
             return Ok(metrics);
         }
 
+        // Retries and replays feed the same audio again; reuse the earlier result.
+        let key = chunk_key(
+            &samples,
+            &metrics.engine,
+            &metrics.model,
+            self.stream_context.as_deref(),
+        );
+        if let Some(cached) = self.chunk_cache.get(key) {
+            metrics.transcript = Some(cached.text);
+            metrics.confidence = cached.confidence;
+            metrics.cache_hit = true;
+            return Ok(metrics);
+        }
+
         self.state = DictationState::Transcribing;
         let inference_started_at = Instant::now();
-        let transcript = self.transcriber.transcribe(samples)?;
+        let transcript = transcriber.transcribe(&samples)?;
         metrics.inference_ms = inference_started_at.elapsed().as_millis() as u64;
         self.state = DictationState::Listening;
+        metrics.confidence = transcriber.last_confidence();
+        self.chunk_cache.insert(
+            key,
+            CachedTranscript {
+                text: transcript.clone(),
+                confidence: metrics.confidence,
+            },
+        );
         metrics.transcript = Some(transcript);
         Ok(metrics)
     }
@@ -215,6 +365,21 @@ mod tests {
         assert_eq!(pipeline.status().state, DictationState::Idle);
     }
 
+    #[test]
+    fn held_listening_ignores_the_hotkey_until_released() {
+        let mut pipeline = DictationPipeline::new(
+            DictationMode::PushToToggle,
+            ModelProfile::Balanced,
+            StubTranscriber,
+        );
+        pipeline.hold_listening();
+        pipeline.on_hotkey_down();
+        pipeline.cancel();
+        assert_eq!(pipeline.status().state, DictationState::Listening);
+        pipeline.release_listening();
+        assert_eq!(pipeline.status().state, DictationState::Idle);
+    }
+
     #[test]
     fn silent_chunk_does_not_transcribe() {
         let mut pipeline = DictationPipeline::new(
@@ -247,6 +412,53 @@ mod tests {
         assert_eq!(pipeline.status().state, DictationState::Listening);
     }
 
+    #[test]
+    fn repeated_chunk_is_served_from_cache() {
+        let mut pipeline = DictationPipeline::new(
+            DictationMode::PushToToggle,
+            ModelProfile::Fast,
+            StubTranscriber,
+        );
+        pipeline.on_hotkey_down();
+
+        let first = pipeline
+            .process_audio_chunk_profiled(&speech_chunk())
+            .expect("speech chunk should be transcribed");
+        let replayed = pipeline
+            .process_audio_chunk_profiled(&speech_chunk())
+            .expect("replayed chunk should be transcribed");
+        assert!(!first.cache_hit);
+        assert!(replayed.cache_hit);
+        assert_eq!(replayed.transcript, first.transcript);
+
+        pipeline.set_stream_context(Some("new prompt"));
+        let reprompted = pipeline
+            .process_audio_chunk_profiled(&speech_chunk())
+            .expect("chunk should be transcribed");
+        assert!(!reprompted.cache_hit);
+    }
+
+    #[test]
+    fn cached_chunks_survive_an_engine_restart() {
+        let mut pipeline = DictationPipeline::new(
+            DictationMode::PushToToggle,
+            ModelProfile::Fast,
+            StubTranscriber,
+        );
+        pipeline.on_hotkey_down();
+
+        let first = pipeline
+            .process_audio_chunk_profiled(&speech_chunk())
+            .expect("speech chunk should be transcribed");
+        pipeline.set_transcriber(StubTranscriber);
+        let refed = pipeline
+            .process_audio_chunk_profiled(&speech_chunk())
+            .expect("re-fed chunk should be transcribed");
+        assert!(!first.cache_hit);
+        assert!(refed.cache_hit);
+        assert_eq!(refed.transcript, first.transcript);
+    }
+
     #[test]
     fn balanced_profile_ignores_short_chunks() {
         let mut pipeline = DictationPipeline::new(
@@ -332,6 +544,33 @@ mod tests {
         assert!(metrics.listening);
         assert!(metrics.enough_samples);
         assert!(metrics.had_speech);
+        assert!(!metrics.speech_segments.is_empty());
+        assert!(metrics.transcript.is_some());
+    }
+
+    #[test]
+    fn cold_start_model_serves_until_it_is_ended() {
+        let mut pipeline = DictationPipeline::new(
+            DictationMode::PushToToggle,
+            ModelProfile::Fast,
+            StubTranscriber,
+        );
+        pipeline.on_hotkey_down();
+        pipeline.start_cold_start(StubTranscriber);
+
+        let chunk = speech_chunk();
+        let metrics = pipeline
+            .process_audio_chunk_profiled(&chunk)
+            .expect("cold-start chunk should transcribe");
+        assert!(metrics.cold_start);
         assert!(metrics.transcript.is_some());
+
+        assert!(pipeline.end_cold_start());
+        assert!(!pipeline.end_cold_start());
+        let later = chunk.iter().map(|sample| sample * 0.9).collect::<Vec<_>>();
+        let metrics = pipeline
+            .process_audio_chunk_profiled(&later)
+            .expect("primary chunk should transcribe");
+        assert!(!metrics.cold_start);
     }
 }
@@ -0,0 +1,266 @@
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{self, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use crate::config::DictationMode;
+use crate::pipeline::{DictationPipeline, DictationState, PipelineStatus};
+use crate::transcriber::Transcriber;
+
+type PipelineJob<T> = Box<dyn FnOnce(&mut DictationPipeline<T>) + Send>;
+
+const STOPPED: &str = "pipeline actor has stopped";
+const PANICKED: &str = "pipeline job panicked";
+
+// What the actor republishes after every job, so status and resource reads
+// never wait behind a running inference.
+#[derive(Debug, Clone)]
+pub struct PublishedState {
+    pub status: PipelineStatus,
+    pub engine: &'static str,
+    pub worker_pid: Option<u32>,
+    pub worker_restarts: u32,
+    listening_held: bool,
+}
+
+impl PublishedState {
+    fn capture<T: Transcriber>(pipeline: &DictationPipeline<T>) -> Self {
+        Self {
+            status: pipeline.status(),
+            engine: pipeline.transcriber_engine_label(),
+            worker_pid: pipeline.transcriber_worker_pid(),
+            worker_restarts: pipeline.transcriber_worker_restarts(),
+            listening_held: pipeline.listening_held(),
+        }
+    }
+}
+
+// Owns the pipeline on a dedicated thread. Callers queue jobs instead of locking
+// it. Cancels and hotkey releases skip the wait: they flip the published state
+// at once and queue the real change behind whatever is already pending.
+pub struct PipelineActor<T: Transcriber> {
+    jobs: Sender<PipelineJob<T>>,
+    published: Arc<Mutex<PublishedState>>,
+    cancel_epoch: Arc<AtomicU64>,
+}
+
+impl<T: Transcriber> Clone for PipelineActor<T> {
+    fn clone(&self) -> Self {
+        Self {
+            jobs: self.jobs.clone(),
+            published: Arc::clone(&self.published),
+            cancel_epoch: Arc::clone(&self.cancel_epoch),
+        }
+    }
+}
+
+impl<T: Transcriber + 'static> PipelineActor<T> {
+    pub fn spawn(mut pipeline: DictationPipeline<T>) -> Self {
+        let (jobs, job_rx) = mpsc::channel::<PipelineJob<T>>();
+        let published = Arc::new(Mutex::new(PublishedState::capture(&pipeline)));
+        let cancel_epoch = Arc::new(AtomicU64::new(0));
+        let actor_published = Arc::clone(&published);
+        let actor_epoch = Arc::clone(&cancel_epoch);
+        thread::spawn(move || {
+            for job in job_rx {
+                let epoch = actor_epoch.load(Ordering::SeqCst);
+                // A panicking job fails only its own call; the actor keeps serving the rest.
+                let _ = panic::catch_unwind(AssertUnwindSafe(|| job(&mut pipeline)));
+                // An inference that was cancelled while it ran must not publish
+                // Listening again before the queued cancel reaches the pipeline.
+                if actor_epoch.load(Ordering::SeqCst) != epoch {
+                    pipeline.cancel();
+                }
+                if let Ok(mut published) = actor_published.lock() {
+                    *published = PublishedState::capture(&pipeline);
+                }
+            }
+        });
+        Self {
+            jobs,
+            published,
+            cancel_epoch,
+        }
+    }
+
+    // Queues a job without waiting for it.
+    pub fn send<F>(&self, job: F) -> Result<(), String>
+    where
+        F: FnOnce(&mut DictationPipeline<T>) + Send + 'static,
+    {
+        self.jobs
+            .send(Box::new(job))
+            .map_err(|_| STOPPED.to_string())
+    }
+
+    // Bumps the cancel epoch, so work that straddles it can drop its result.
+    pub fn cancel_now(&self) -> Result<PipelineStatus, String> {
+        self.cancel_epoch.fetch_add(1, Ordering::SeqCst);
+        self.send(|pipeline| pipeline.cancel())?;
+        self.flip_published(|status| status.state = DictationState::Idle)
+    }
+
+    pub fn hotkey_up_now(&self) -> Result<PipelineStatus, String> {
+        self.send(|pipeline| pipeline.on_hotkey_up())?;
+        self.flip_published(|status| {
+            if status.mode == DictationMode::PushToTalk && status.state == DictationState::Listening
+            {
+                status.state = DictationState::Idle;
+            }
+        })
+    }
+
+    pub fn cancel_epoch(&self) -> u64 {
+        self.cancel_epoch.load(Ordering::SeqCst)
+    }
+
+    fn flip_published(
+        &self,
+        change: impl FnOnce(&mut PipelineStatus),
+    ) -> Result<PipelineStatus, String> {
+        let mut published = self.published.lock().map_err(|_| STOPPED.to_string())?;
+        // A held recording ignores cancels and the hotkey, as the pipeline does.
+        if !published.listening_held {
+            change(&mut published.status);
+        }
+        Ok(published.status.clone())
+    }
+
+    // Jobs run in submission order; the actor stops once every handle is dropped.
+    pub fn call<R, F>(&self, job: F) -> Result<R, String>
+    where
+        R: Send + 'static,
+        F: FnOnce(&mut DictationPipeline<T>) -> R + Send + 'static,
+    {
+        let (reply_tx, reply_rx) = mpsc::sync_channel(1);
+        self.jobs
+            .send(Box::new(move |pipeline| {
+                let _ = reply_tx.send(job(pipeline));
+            }))
+            .map_err(|_| STOPPED.to_string())?;
+        reply_rx.recv().map_err(|_| PANICKED.to_string())
+    }
+
+    pub fn status(&self) -> Result<PipelineStatus, String> {
+        self.published().map(|published| published.status)
+    }
+
+    pub fn published(&self) -> Result<PublishedState, String> {
+        self.published
+            .lock()
+            .map(|published| published.clone())
+            .map_err(|_| STOPPED.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{DictationMode, ModelProfile};
+    use crate::pipeline::DictationState;
+    use crate::transcriber::StubTranscriber;
+    use std::sync::mpsc;
+    use std::time::Duration;
+
+    fn actor() -> PipelineActor<StubTranscriber> {
+        PipelineActor::spawn(DictationPipeline::new(
+            DictationMode::PushToToggle,
+            ModelProfile::Fast,
+            StubTranscriber,
+        ))
+    }
+
+    #[test]
+    fn publishes_status_after_each_job() {
+        let actor = actor();
+        assert_eq!(actor.status().expect("status").state, DictationState::Idle);
+
+        let state = actor
+            .call(|pipeline| {
+                pipeline.on_hotkey_down();
+                pipeline.status().state
+            })
+            .expect("job should run");
+        assert_eq!(state, DictationState::Listening);
+        assert_eq!(
+            actor.status().expect("status").state,
+            DictationState::Listening
+        );
+    }
+
+    #[test]
+    fn survives_a_panicking_job() {
+        let actor = actor();
+        let failed = actor.call::<(), _>(|_pipeline| panic!("engine binding fault"));
+        assert_eq!(failed, Err(PANICKED.to_string()));
+
+        let state = actor
+            .call(|pipeline| {
+                pipeline.on_hotkey_down();
+                pipeline.status().state
+            })
+            .expect("actor should keep running");
+        assert_eq!(state, DictationState::Listening);
+    }
+
+    #[test]
+    fn status_reads_do_not_wait_for_a_running_job() {
+        let actor = actor();
+        let (started_tx, started_rx) = mpsc::channel();
+        let (release_tx, release_rx) = mpsc::channel::<()>();
+        let busy = actor.clone();
+        let worker = std::thread::spawn(move || {
+            busy.call(move |pipeline| {
+                let _ = started_tx.send(());
+                let _ = release_rx.recv_timeout(Duration::from_secs(5));
+                pipeline.cancel();
+            })
+        });
+
+        started_rx
+            .recv_timeout(Duration::from_secs(5))
+            .expect("job should start");
+        assert_eq!(actor.status().expect("status").state, DictationState::Idle);
+        release_tx.send(()).expect("job should be waiting");
+        worker
+            .join()
+            .expect("worker should finish")
+            .expect("job should run");
+    }
+
+    #[test]
+    fn cancel_takes_effect_without_waiting_for_a_running_job() {
+        let actor = actor();
+        actor
+            .call(|pipeline| pipeline.on_hotkey_down())
+            .expect("job should run");
+        let (started_tx, started_rx) = mpsc::channel();
+        let (release_tx, release_rx) = mpsc::channel::<()>();
+        let busy = actor.clone();
+        let worker = std::thread::spawn(move || {
+            busy.call(move |pipeline| {
+                let _ = started_tx.send(());
+                let _ = release_rx.recv_timeout(Duration::from_secs(5));
+                pipeline.status().state
+            })
+        });
+        started_rx
+            .recv_timeout(Duration::from_secs(5))
+            .expect("job should start");
+
+        let epoch = actor.cancel_epoch();
+        let status = actor.cancel_now().expect("cancel should not wait");
+        assert_eq!(status.state, DictationState::Idle);
+        assert_eq!(actor.status().expect("status").state, DictationState::Idle);
+        assert_ne!(actor.cancel_epoch(), epoch);
+
+        release_tx.send(()).expect("job should be waiting");
+        let seen = worker.join().expect("worker should finish");
+        assert_eq!(seen, Ok(DictationState::Listening));
+        let state = actor
+            .call(|pipeline| pipeline.status().state)
+            .expect("job should run");
+        assert_eq!(state, DictationState::Idle);
+    }
+}
@@ -0,0 +1,144 @@
+use std::time::{Duration, Instant};
+
+use crate::config::PlaybackGuard;
+
+// Detection spawns a process, so it is polled off the capture thread.
+pub const PLAYBACK_POLL_INTERVAL_MS: u64 = 500;
+// Room echo and the poll interval both trail the real end of playback.
+const RELEASE_HOLD: Duration = Duration::from_millis(1_200);
+// While raised, a frame must be this much louder than the VAD threshold to pass.
+const RAISED_VAD_FACTOR: f32 = 3.0;
+// Once speech opens the raised gate it stays open briefly so word tails survive.
+const GATE_HOLD: Duration = Duration::from_millis(250);
+
+// A sink in RUNNING state is playing a stream; IDLE and SUSPENDED are quiet.
+pub fn parse_pactl_sinks(output: &str) -> bool {
+    output
+        .lines()
+        .filter_map(|line| line.split('\t').nth(4))
+        .any(|state| state.trim() == "RUNNING")
+}
+
+// None when playback cannot be observed on this platform, which leaves the
+// guard disengaged. PulseAudio and PipeWire both answer `pactl`.
+pub fn system_playback_active() -> Option<bool> {
+    #[cfg(target_os = "linux")]
+    {
+        let output = std::process::Command::new("pactl")
+            .args(["list", "short", "sinks"])
+            .output()
+            .ok()?;
+        output
+            .status
+            .success()
+            .then(|| parse_pactl_sinks(&String::from_utf8_lossy(&output.stdout)))
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        None
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PlaybackGate {
+    guard: PlaybackGuard,
+    raised_threshold: f32,
+    engaged_until: Option<Instant>,
+    open_until: Option<Instant>,
+}
+
+impl PlaybackGate {
+    pub fn new(guard: PlaybackGuard, vad_rms_threshold: f32) -> Self {
+        Self {
+            guard,
+            raised_threshold: vad_rms_threshold * RAISED_VAD_FACTOR,
+            engaged_until: None,
+            open_until: None,
+        }
+    }
+
+    pub fn guard(&self) -> PlaybackGuard {
+        self.guard
+    }
+
+    pub fn observe_playback(&mut self, playing: bool, now: Instant) {
+        if playing && self.guard != PlaybackGuard::Off {
+            self.engaged_until = Some(now + RELEASE_HOLD);
+        }
+    }
+
+    pub fn engaged(&self, now: Instant) -> bool {
+        self.engaged_until.is_some_and(|until| now < until)
+    }
+
+    // Gated audio is replaced with silence rather than dropped so chunk timing
+    // and voice-activation silence detection keep running on real time.
+    pub fn filter(&mut self, frame: &mut [f32], now: Instant) {
+        if !self.engaged(now) {
+            return;
+        }
+        match self.guard {
+            PlaybackGuard::Off => {}
+            PlaybackGuard::Pause => frame.fill(0.0),
+            PlaybackGuard::RaiseVad => {
+                if frame_rms(frame) >= self.raised_threshold {
+                    self.open_until = Some(now + GATE_HOLD);
+                }
+                if self.open_until.is_none_or(|until| now >= until) {
+                    frame.fill(0.0);
+                }
+            }
+        }
+    }
+}
+
+fn frame_rms(frame: &[f32]) -> f32 {
+    if frame.is_empty() {
+        return 0.0;
+    }
+    let energy = frame.iter().map(|sample| sample * sample).sum::<f32>();
+    (energy / frame.len() as f32).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_running_sinks() {
+        let output = "47\talsa_output.pci-0000_00_1f.3.analog-stereo\tPipeWire\ts32le 2ch 48000Hz\tSUSPENDED\n\
+                      52\tbluez_output.headset\tPipeWire\ts16le 2ch 48000Hz\tRUNNING\n";
+        assert!(parse_pactl_sinks(output));
+        assert!(!parse_pactl_sinks(
+            "47\talsa_output.analog-stereo\tPipeWire\ts32le 2ch 48000Hz\tIDLE\n"
+        ));
+        assert!(!parse_pactl_sinks(""));
+    }
+
+    #[test]
+    fn gates_leaked_audio_while_playback_lasts() {
+        let start = Instant::now();
+        let mut gate = PlaybackGate::new(PlaybackGuard::RaiseVad, 0.01);
+        gate.observe_playback(true, start);
+
+        let mut leak = vec![0.02; 160];
+        gate.filter(&mut leak, start);
+        assert!(leak.iter().all(|sample| *sample == 0.0));
+
+        let mut speech = vec![0.05; 160];
+        gate.filter(&mut speech, start);
+        assert!(speech.iter().all(|sample| *sample == 0.05));
+
+        let later = start + RELEASE_HOLD;
+        let mut after = vec![0.02; 160];
+        gate.filter(&mut after, later);
+        assert!(!gate.engaged(later));
+        assert!(after.iter().all(|sample| *sample == 0.02));
+
+        let mut paused = PlaybackGate::new(PlaybackGuard::Pause, 0.01);
+        paused.observe_playback(true, start);
+        let mut loud = vec![0.5; 160];
+        paused.filter(&mut loud, start);
+        assert!(loud.iter().all(|sample| *sample == 0.0));
+    }
+}
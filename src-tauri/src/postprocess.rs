@@ -1,28 +1,142 @@
+use serde::Serialize;
+
+use std::collections::VecDeque;
+
+use crate::config::{AppRule, CasingMode, DuplicateSuppression, HallucinationGuard};
+use crate::vocabulary;
+
+#[derive(Debug, Clone, Copy, Default, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum NormalizationProfile {
+    #[default]
+    Latin,
+    Cjk,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TranscriptStage {
+    Partial,
+    Final,
+}
+
 pub fn normalize_transcript(input: &str) -> String {
-    let collapsed = input
-        .split_whitespace()
-        .collect::<Vec<_>>()
-        .join(" ")
-        .trim()
-        .to_string();
+    normalize_transcript_for(input, NormalizationProfile::Latin)
+}
+
+pub fn normalize_transcript_for(input: &str, profile: NormalizationProfile) -> String {
+    let collapsed = collapse_whitespace(input);
 
     if collapsed.is_empty() {
         return String::new();
     }
 
-    let mut chars = collapsed.chars();
-    let first = chars
-        .next()
-        .map(|ch| ch.to_uppercase().to_string())
-        .unwrap_or_default();
-    let rest = chars.as_str();
-    let mut sentence = format!("{first}{rest}");
+    match profile {
+        NormalizationProfile::Latin => {
+            let mut chars = collapsed.chars();
+            let first = chars
+                .next()
+                .map(|ch| ch.to_uppercase().to_string())
+                .unwrap_or_default();
+            let rest = chars.as_str();
+            let mut sentence = format!("{first}{rest}");
 
-    if !sentence.ends_with('.') && !sentence.ends_with('!') && !sentence.ends_with('?') {
-        sentence.push('.');
+            if !sentence.ends_with('.') && !sentence.ends_with('!') && !sentence.ends_with('?') {
+                sentence.push('.');
+            }
+
+            sentence
+        }
+        // CJK scripts have no letter case and terminate sentences with full-width marks.
+        NormalizationProfile::Cjk => {
+            let mut sentence = collapsed;
+            if !sentence.ends_with(['。', '！', '？', '.', '!', '?']) {
+                sentence.push('。');
+            }
+            sentence
+        }
     }
+}
 
-    sentence
+// Sentence capitalization would turn a leading "macOS" into "MacOS", so
+// vocabulary casing is applied after it.
+pub fn normalize_transcript_with_terms(
+    input: &str,
+    profile: NormalizationProfile,
+    terms: &[String],
+) -> String {
+    vocabulary::apply_term_corrections(&normalize_transcript_for(input, profile), terms)
+}
+
+// Partials keep changing as audio arrives. A capital and closing period
+// added to each one would show up and then move, so partials only get
+// whitespace and vocabulary cleanup and the full pass waits for the final.
+pub fn normalize_transcript_for_stage(
+    input: &str,
+    profile: NormalizationProfile,
+    stage: TranscriptStage,
+    terms: &[String],
+) -> String {
+    match stage {
+        TranscriptStage::Partial => {
+            vocabulary::apply_term_corrections(&collapse_whitespace(input), terms)
+        }
+        TranscriptStage::Final => normalize_transcript_with_terms(input, profile, terms),
+    }
+}
+
+fn collapse_whitespace(input: &str) -> String {
+    input.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+// App casing rules lowercase words, but a vocabulary spelling still wins.
+pub fn apply_casing_with_terms(text: &str, mode: CasingMode, terms: &[String]) -> String {
+    vocabulary::apply_term_corrections(&apply_casing(text, mode), terms)
+}
+
+pub fn apply_casing(text: &str, mode: CasingMode) -> String {
+    match mode {
+        CasingMode::Sentence => text.to_string(),
+        CasingMode::Plain => {
+            let trimmed = strip_auto_period(text);
+            let first_word = trimmed.split_whitespace().next().unwrap_or_default();
+            let keep_case = first_word == "I"
+                || first_word.starts_with("I'")
+                || (first_word.chars().count() > 1
+                    && first_word.chars().all(|ch| !ch.is_lowercase()));
+            if keep_case {
+                return trimmed.to_string();
+            }
+
+            let mut chars = trimmed.chars();
+            match chars.next() {
+                Some(first) => format!("{}{}", first.to_lowercase(), chars.as_str()),
+                None => String::new(),
+            }
+        }
+        CasingMode::Lowercase => strip_auto_period(text).to_lowercase(),
+    }
+}
+
+pub fn casing_for_app(rules: &[AppRule], app_name: Option<&str>) -> CasingMode {
+    rule_for_app(rules, app_name)
+        .map(|rule| rule.casing)
+        .unwrap_or_default()
+}
+
+pub fn rule_for_app<'a>(rules: &'a [AppRule], app_name: Option<&str>) -> Option<&'a AppRule> {
+    let app_name = app_name.map(str::trim).filter(|value| !value.is_empty())?;
+    rules
+        .iter()
+        .find(|rule| rule.app_name.trim().eq_ignore_ascii_case(app_name))
+}
+
+fn strip_auto_period(text: &str) -> &str {
+    let trimmed = text.trim_end();
+    if trimmed.ends_with('.') && !trimmed.ends_with("..") {
+        &trimmed[..trimmed.len() - 1]
+    } else {
+        trimmed
+    }
 }
 
 fn normalize_overlap_token(token: &str) -> String {
@@ -81,6 +195,65 @@ pub fn merge_transcript_segments(current: &str, incoming: &str) -> String {
     format!("{normalized_current} {normalized_incoming}")
 }
 
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct TranscriptDiff {
+    pub stable_prefix: String,
+    pub suffix: String,
+}
+
+pub fn diff_partial_transcript(previous: Option<&str>, current: &str) -> TranscriptDiff {
+    let previous = previous.unwrap_or_default();
+    let common = previous
+        .char_indices()
+        .zip(current.chars())
+        .take_while(|((_, left), right)| left == right)
+        .last()
+        .map(|((index, value), _)| index + value.len_utf8())
+        .unwrap_or(0);
+
+    // Only words that are complete in both partials count as stable.
+    let word_complete = |text: &str| {
+        text[common..]
+            .chars()
+            .next()
+            .is_none_or(char::is_whitespace)
+    };
+    let stable_len = if word_complete(previous) && word_complete(current) {
+        common
+    } else {
+        current[..common]
+            .rfind(char::is_whitespace)
+            .map(|index| index + 1)
+            .unwrap_or(0)
+    };
+
+    TranscriptDiff {
+        stable_prefix: current[..stable_len].to_string(),
+        suffix: current[stable_len..].to_string(),
+    }
+}
+
+pub fn capitalize_sentences(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    let mut sentence_start = true;
+    for word in text.split_inclusive(char::is_whitespace) {
+        let token = word.trim_end();
+        let is_pronoun = token == "i" || token.starts_with("i'");
+        let mut chars = word.chars();
+        match chars.next() {
+            Some(first) if sentence_start || is_pronoun => {
+                output.extend(first.to_uppercase());
+                output.push_str(chars.as_str());
+            }
+            _ => output.push_str(word),
+        }
+        if !token.is_empty() {
+            sentence_start = token.ends_with(['.', '?', '!']);
+        }
+    }
+    output
+}
+
 pub fn is_duplicate_transcript(previous: Option<&str>, current: &str) -> bool {
     let normalized_current = current.trim().to_lowercase();
     if normalized_current.is_empty() {
@@ -92,10 +265,185 @@ pub fn is_duplicate_transcript(previous: Option<&str>, current: &str) -> bool {
         .unwrap_or(false)
 }
 
+// Tracks how recently and how often the previous transcript was seen, so
+// repeats are only dropped under the configured window and count.
+#[derive(Debug, Default)]
+pub struct RepeatGuard {
+    last_seen_unix_ms: Option<u64>,
+    suppressed: u8,
+}
+
+impl RepeatGuard {
+    // Marks text that went out without passing through `suppress`.
+    pub fn record(&mut self, now_unix_ms: u64) {
+        self.last_seen_unix_ms = Some(now_unix_ms);
+        self.suppressed = 0;
+    }
+
+    // The window runs from the last sighting, dropped repeats included, so a
+    // stream of identical chunks stays quiet while a repeat after a pause
+    // comes through.
+    pub fn suppress(
+        &mut self,
+        previous: Option<&str>,
+        current: &str,
+        now_unix_ms: u64,
+        policy: DuplicateSuppression,
+    ) -> bool {
+        if !is_duplicate_transcript(previous, current) {
+            self.record(now_unix_ms);
+            return false;
+        }
+        let within_window = policy.window_ms == 0
+            || self.last_seen_unix_ms.is_some_and(|seen| {
+                now_unix_ms.saturating_sub(seen) <= u64::from(policy.window_ms)
+            });
+        let under_limit = policy.max_suppressed == 0 || self.suppressed < policy.max_suppressed;
+        self.last_seen_unix_ms = Some(now_unix_ms);
+        if within_window && under_limit {
+            self.suppressed = self.suppressed.saturating_add(1);
+            true
+        } else {
+            self.suppressed = 0;
+            false
+        }
+    }
+}
+
+// The last few finals that went out, kept to spot an engine replaying one of
+// them over near-silent audio.
+#[derive(Debug, Default)]
+pub struct RecentFinals {
+    finals: VecDeque<String>,
+}
+
+impl RecentFinals {
+    // True when `text` should be dropped as a hallucinated repeat. Finals that
+    // pass are remembered; dropped ones are not, so they cannot push real
+    // finals out of the window.
+    pub fn is_hallucinated(
+        &mut self,
+        text: &str,
+        speech_ratio_percent: u8,
+        guard: HallucinationGuard,
+    ) -> bool {
+        let normalized = text.trim().to_lowercase();
+        let repeated = self.finals.iter().any(|recent| *recent == normalized);
+        if guard.enabled && repeated && speech_ratio_percent < guard.max_speech_ratio_percent {
+            return true;
+        }
+        self.finals.push_back(normalized);
+        while self.finals.len() > usize::from(guard.recent_finals) {
+            self.finals.pop_front();
+        }
+        false
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn partials_skip_sentence_casing_and_closing_punctuation() {
+        let terms = vec!["GitHub".to_string()];
+        let partial = normalize_transcript_for_stage(
+            "  open   github and",
+            NormalizationProfile::Latin,
+            TranscriptStage::Partial,
+            &terms,
+        );
+        assert_eq!(partial, "open GitHub and");
+        let next = normalize_transcript_for_stage(
+            "open github and push",
+            NormalizationProfile::Latin,
+            TranscriptStage::Partial,
+            &terms,
+        );
+        assert_eq!(
+            diff_partial_transcript(Some(&partial), &next).stable_prefix,
+            "open GitHub and"
+        );
+        assert_eq!(
+            normalize_transcript_for_stage(
+                "open github and push",
+                NormalizationProfile::Latin,
+                TranscriptStage::Final,
+                &terms,
+            ),
+            "Open GitHub and push."
+        );
+    }
+
+    #[test]
+    fn vocabulary_casing_survives_capitalization_and_app_casing() {
+        let terms = vec!["macOS".to_string(), "GitHub".to_string()];
+        assert_eq!(
+            normalize_transcript_with_terms(
+                "macos sync with github",
+                NormalizationProfile::Latin,
+                &terms
+            ),
+            "macOS sync with GitHub."
+        );
+        assert_eq!(
+            apply_casing_with_terms("MacOS sync with GitHub.", CasingMode::Lowercase, &terms),
+            "macOS sync with GitHub"
+        );
+        assert_eq!(
+            apply_casing_with_terms("Githubbers use MacOS.", CasingMode::Plain, &terms),
+            "githubbers use macOS"
+        );
+    }
+
+    #[test]
+    fn capitalizes_sentence_starts_and_pronoun() {
+        assert_eq!(
+            capitalize_sentences("well i think so. i'm sure? yes it is"),
+            "Well I think so. I'm sure? Yes it is"
+        );
+        assert_eq!(capitalize_sentences("inside it"), "Inside it");
+    }
+
+    #[test]
+    fn diffs_partials_on_word_boundaries() {
+        assert_eq!(
+            diff_partial_transcript(None, "hel"),
+            TranscriptDiff {
+                stable_prefix: String::new(),
+                suffix: "hel".to_string(),
+            }
+        );
+        assert_eq!(
+            diff_partial_transcript(Some("hel"), "hello wor"),
+            TranscriptDiff {
+                stable_prefix: String::new(),
+                suffix: "hello wor".to_string(),
+            }
+        );
+        assert_eq!(
+            diff_partial_transcript(Some("hello wor"), "hello world"),
+            TranscriptDiff {
+                stable_prefix: "hello ".to_string(),
+                suffix: "world".to_string(),
+            }
+        );
+        assert_eq!(
+            diff_partial_transcript(Some("hello world"), "hello world again"),
+            TranscriptDiff {
+                stable_prefix: "hello world".to_string(),
+                suffix: " again".to_string(),
+            }
+        );
+        assert_eq!(
+            diff_partial_transcript(Some("café au"), "café olé"),
+            TranscriptDiff {
+                stable_prefix: "café ".to_string(),
+                suffix: "olé".to_string(),
+            }
+        );
+    }
+
     #[test]
     fn normalizes_whitespace_and_punctuation() {
         let output = normalize_transcript("   hello   world   ");
@@ -107,6 +455,18 @@ mod tests {
         assert_eq!(normalize_transcript("what now?"), "What now?");
     }
 
+    #[test]
+    fn cjk_profile_skips_casing_and_uses_full_width_period() {
+        assert_eq!(
+            normalize_transcript_for(" 今日は 晴れ ", NormalizationProfile::Cjk),
+            "今日は 晴れ。"
+        );
+        assert_eq!(
+            normalize_transcript_for("本当？", NormalizationProfile::Cjk),
+            "本当？"
+        );
+    }
+
     #[test]
     fn duplicate_detection_ignores_case() {
         assert!(is_duplicate_transcript(
@@ -116,6 +476,54 @@ mod tests {
         assert!(!is_duplicate_transcript(Some("Hello world."), "different"));
     }
 
+    #[test]
+    fn hallucinated_repeats_need_a_recent_match_and_little_speech() {
+        let guard = HallucinationGuard {
+            enabled: true,
+            recent_finals: 2,
+            max_speech_ratio_percent: 35,
+        };
+        let mut recent = RecentFinals::default();
+        assert!(!recent.is_hallucinated("Thanks for watching.", 90, guard));
+        assert!(!recent.is_hallucinated("Next slide.", 90, guard));
+        assert!(recent.is_hallucinated("thanks for watching.", 10, guard));
+        // Said again with real speech behind it.
+        assert!(!recent.is_hallucinated("Next slide.", 80, guard));
+        assert!(!recent.is_hallucinated("Done.", 80, guard));
+        // Out of the window of two by now.
+        assert!(!recent.is_hallucinated("Thanks for watching.", 10, guard));
+
+        let disabled = HallucinationGuard {
+            enabled: false,
+            ..guard
+        };
+        assert!(!recent.is_hallucinated("Done.", 0, disabled));
+    }
+
+    #[test]
+    fn repeats_pass_after_the_window_or_the_count() {
+        let previous = Some("Yes.");
+        let windowed = DuplicateSuppression {
+            window_ms: 3_000,
+            max_suppressed: 0,
+        };
+        let mut guard = RepeatGuard::default();
+        guard.record(1_000);
+        assert!(guard.suppress(previous, "yes.", 3_500, windowed));
+        assert!(guard.suppress(previous, "yes.", 6_000, windowed));
+        assert!(!guard.suppress(previous, "yes.", 9_500, windowed));
+        assert!(!guard.suppress(previous, "No.", 9_600, windowed));
+
+        let counted = DuplicateSuppression {
+            window_ms: 0,
+            max_suppressed: 1,
+        };
+        let mut guard = RepeatGuard::default();
+        assert!(guard.suppress(previous, "yes.", 1_000, counted));
+        assert!(!guard.suppress(previous, "yes.", 1_100, counted));
+        assert!(guard.suppress(previous, "yes.", 1_200, counted));
+    }
+
     #[test]
     fn merge_segments_appends_continuous_speech() {
         let merged =
@@ -137,4 +545,40 @@ mod tests {
             "Our team discussed budget numbers including $14,250 for hardware."
         );
     }
+
+    #[test]
+    fn plain_casing_drops_auto_capitalization_and_period() {
+        assert_eq!(
+            apply_casing("Git status please.", CasingMode::Plain),
+            "git status please"
+        );
+        assert_eq!(apply_casing("I think so.", CasingMode::Plain), "I think so");
+        assert_eq!(
+            apply_casing("NASA launched it.", CasingMode::Plain),
+            "NASA launched it"
+        );
+        assert_eq!(
+            apply_casing("Hello there.", CasingMode::Lowercase),
+            "hello there"
+        );
+        assert_eq!(
+            apply_casing("Wait for it...", CasingMode::Sentence),
+            "Wait for it..."
+        );
+    }
+
+    #[test]
+    fn resolves_casing_from_app_rules() {
+        let rules = vec![AppRule {
+            app_name: "Kitty".to_string(),
+            casing: CasingMode::Plain,
+        }];
+        assert_eq!(casing_for_app(&rules, Some("kitty")), CasingMode::Plain);
+        assert_eq!(casing_for_app(&rules, Some("slack")), CasingMode::Sentence);
+        assert_eq!(casing_for_app(&rules, None), CasingMode::Sentence);
+        assert_eq!(
+            rule_for_app(&rules, Some(" KITTY ")).map(|rule| rule.app_name.as_str()),
+            Some("Kitty")
+        );
+    }
 }
@@ -0,0 +1,43 @@
+// Sleep is detected from the wall clock jumping past the watcher's tick. This
+// works the same on every platform without a power-event subscription; a
+// manual clock change looks alike and only costs an unneeded rebuild.
+pub const RESUME_CHECK_INTERVAL_SECS: u64 = 5;
+pub const RESUME_GAP_THRESHOLD_MS: u64 = 20_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResumeDetector {
+    last_tick_unix_ms: u64,
+    expected_tick_ms: u64,
+}
+
+impl ResumeDetector {
+    pub fn new(now_unix_ms: u64, expected_tick_ms: u64) -> Self {
+        Self {
+            last_tick_unix_ms: now_unix_ms,
+            expected_tick_ms,
+        }
+    }
+
+    // Returns roughly how long the machine was asleep when a resume is detected.
+    pub fn observe(&mut self, now_unix_ms: u64) -> Option<u64> {
+        let elapsed = now_unix_ms.saturating_sub(self.last_tick_unix_ms);
+        self.last_tick_unix_ms = now_unix_ms;
+        let slept_ms = elapsed.saturating_sub(self.expected_tick_ms);
+        (slept_ms >= RESUME_GAP_THRESHOLD_MS).then_some(slept_ms)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_resume_only_after_a_long_gap() {
+        let mut detector = ResumeDetector::new(1_000_000, 5_000);
+        assert_eq!(detector.observe(1_005_200), None);
+        assert_eq!(detector.observe(1_012_000), None);
+        assert_eq!(detector.observe(1_012_000 + 5_000 + 600_000), Some(600_000));
+        assert_eq!(detector.observe(1_622_000), None);
+        assert_eq!(detector.observe(1_600_000), None);
+    }
+}
@@ -0,0 +1,140 @@
+#[cfg(not(target_os = "linux"))]
+use std::process::Command;
+
+#[cfg(target_os = "windows")]
+use std::os::windows::process::CommandExt;
+
+#[cfg(target_os = "windows")]
+const CREATE_NO_WINDOW: u32 = 0x08000000;
+
+const BYTES_PER_KIB: u64 = 1_024;
+const BYTES_PER_MIB: u64 = 1_024 * 1_024;
+
+pub fn resident_memory_bytes(pid: u32) -> Option<u64> {
+    #[cfg(target_os = "linux")]
+    {
+        let status = std::fs::read_to_string(format!("/proc/{pid}/status")).ok()?;
+        parse_proc_status_rss_kib(&status).map(|kib| kib * BYTES_PER_KIB)
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        let filter = format!("PID eq {pid}");
+        let output = run_probe("tasklist", &["/FI", &filter, "/FO", "CSV", "/NH"])?;
+        parse_tasklist_rss_kib(&output).map(|kib| kib * BYTES_PER_KIB)
+    }
+
+    #[cfg(not(any(target_os = "linux", target_os = "windows")))]
+    {
+        let pid = pid.to_string();
+        let output = run_probe("ps", &["-o", "rss=", "-p", &pid])?;
+        output
+            .trim()
+            .parse::<u64>()
+            .ok()
+            .map(|kib| kib * BYTES_PER_KIB)
+    }
+}
+
+pub fn thread_count(pid: u32) -> Option<u32> {
+    #[cfg(target_os = "linux")]
+    {
+        let status = std::fs::read_to_string(format!("/proc/{pid}/status")).ok()?;
+        parse_proc_status_threads(&status)
+    }
+
+    // tasklist does not report threads; callers show the count as unknown.
+    #[cfg(target_os = "windows")]
+    {
+        let _ = pid;
+        None
+    }
+
+    #[cfg(not(any(target_os = "linux", target_os = "windows")))]
+    {
+        let pid = pid.to_string();
+        let output = run_probe("ps", &["-M", "-p", &pid])?;
+        let threads = output
+            .lines()
+            .skip(1)
+            .filter(|line| !line.trim().is_empty());
+        u32::try_from(threads.count())
+            .ok()
+            .filter(|count| *count > 0)
+    }
+}
+
+pub fn exceeds_cap(resident_bytes: u64, cap_mb: u32) -> bool {
+    cap_mb > 0 && resident_bytes > u64::from(cap_mb) * BYTES_PER_MIB
+}
+
+pub fn parse_proc_status_rss_kib(status: &str) -> Option<u64> {
+    status
+        .lines()
+        .find_map(|line| line.strip_prefix("VmRSS:"))
+        .and_then(|value| value.split_whitespace().next())
+        .and_then(|value| value.parse::<u64>().ok())
+}
+
+pub fn parse_proc_status_threads(status: &str) -> Option<u32> {
+    status
+        .lines()
+        .find_map(|line| line.strip_prefix("Threads:"))
+        .and_then(|value| value.trim().parse::<u32>().ok())
+}
+
+pub fn parse_tasklist_rss_kib(output: &str) -> Option<u64> {
+    let line = output.lines().find(|line| line.starts_with('"'))?;
+    let memory = line.rsplit("\",\"").next()?;
+    let digits = memory
+        .chars()
+        .filter(|value| value.is_ascii_digit())
+        .collect::<String>();
+    digits.parse::<u64>().ok()
+}
+
+#[cfg(not(target_os = "linux"))]
+fn run_probe(program: &str, args: &[&str]) -> Option<String> {
+    let mut command = Command::new(program);
+    command.args(args);
+
+    #[cfg(target_os = "windows")]
+    {
+        command.creation_flags(CREATE_NO_WINDOW);
+    }
+
+    let output = command.output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_resident_memory_from_platform_probes() {
+        let status = "Name:\tpython3\nVmPeak:\t  912000 kB\nVmRSS:\t  734512 kB\nThreads:\t12\n";
+        assert_eq!(parse_proc_status_rss_kib(status), Some(734_512));
+        assert_eq!(parse_proc_status_rss_kib("Name:\tzombie\n"), None);
+        assert_eq!(parse_proc_status_threads(status), Some(12));
+
+        let tasklist = "\"python.exe\",\"4242\",\"Console\",\"1\",\"1,048,576 K\"\r\n";
+        assert_eq!(parse_tasklist_rss_kib(tasklist), Some(1_048_576));
+        assert_eq!(
+            parse_tasklist_rss_kib(
+                "INFO: No tasks are running which match the specified criteria."
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn caps_only_apply_when_configured() {
+        assert!(exceeds_cap(2_049 * BYTES_PER_MIB, 2_048));
+        assert!(!exceeds_cap(2_048 * BYTES_PER_MIB, 2_048));
+        assert!(!exceeds_cap(u64::MAX, 0));
+    }
+}
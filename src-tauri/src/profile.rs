@@ -1,4 +1,7 @@
 use crate::config::{AppSettings, ModelProfile};
+use crate::model_cache::CacheUsage;
+use crate::model_catalog;
+use crate::resolution::{LiveProbe, ResolutionProbe};
 use serde::Serialize;
 use std::collections::HashSet;
 use std::path::{Path, PathBuf};
@@ -22,6 +25,7 @@ pub const CHUNK_DURATION_MS_MAX: u16 = 4_000;
 pub const PARTIAL_CADENCE_MS_MIN: u16 = 300;
 pub const PARTIAL_CADENCE_MS_MAX: u16 = 2_500;
 const SAMPLE_RATE_HZ: usize = 16_000;
+pub const SLOW_INFERENCE_STREAK: u32 = 5;
 
 #[derive(Debug, Clone, Serialize, PartialEq, Eq)]
 pub struct ModelStatus {
@@ -31,6 +35,8 @@ pub struct ModelStatus {
     pub model_exists: bool,
     pub checked_paths: Vec<String>,
     pub tuning: ProfileTuning,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub faster_whisper_cache: Option<CacheUsage>,
 }
 
 pub fn detect_hardware_tier(logical_cores: usize) -> HardwareTier {
@@ -104,6 +110,63 @@ pub fn tuning_for_settings(settings: &AppSettings) -> ProfileTuning {
     }
 }
 
+pub fn downgraded_profile(profile: ModelProfile) -> Option<ModelProfile> {
+    match profile {
+        ModelProfile::Balanced => Some(ModelProfile::Fast),
+        ModelProfile::Fast => None,
+    }
+}
+
+pub fn toggled_profile(profile: ModelProfile) -> ModelProfile {
+    match profile {
+        ModelProfile::Fast => ModelProfile::Balanced,
+        ModelProfile::Balanced => ModelProfile::Fast,
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+pub struct SlowInference {
+    pub chunks: u32,
+    pub avg_inference_ms: u64,
+    pub avg_audio_ms: u64,
+}
+
+#[derive(Debug, Default)]
+pub struct RealtimeTracker {
+    slow_streak: u32,
+    inference_ms: u64,
+    audio_ms: u64,
+}
+
+impl RealtimeTracker {
+    // Returns a report once inference has run slower than real time for
+    // `SLOW_INFERENCE_STREAK` consecutive chunks; any real-time chunk resets the streak.
+    pub fn observe(&mut self, inference_ms: u64, audio_ms: u64) -> Option<SlowInference> {
+        if audio_ms == 0 {
+            return None;
+        }
+        if inference_ms <= audio_ms {
+            *self = Self::default();
+            return None;
+        }
+
+        self.slow_streak += 1;
+        self.inference_ms = self.inference_ms.saturating_add(inference_ms);
+        self.audio_ms = self.audio_ms.saturating_add(audio_ms);
+        if self.slow_streak < SLOW_INFERENCE_STREAK {
+            return None;
+        }
+
+        let report = SlowInference {
+            chunks: self.slow_streak,
+            avg_inference_ms: self.inference_ms / u64::from(self.slow_streak),
+            avg_audio_ms: self.audio_ms / u64::from(self.slow_streak),
+        };
+        *self = Self::default();
+        Some(report)
+    }
+}
+
 pub fn default_model_relative_path(profile: ModelProfile) -> &'static str {
     match profile {
         ModelProfile::Fast => "models/ggml-tiny.en-q8_0.bin",
@@ -115,39 +178,57 @@ pub fn resolve_model_candidates(
     settings: &AppSettings,
     resource_dir: Option<&Path>,
 ) -> Vec<PathBuf> {
-    let default_relative = default_model_relative_path(settings.model_profile);
-    let default_file_name = Path::new(default_relative)
-        .file_name()
-        .map(|value| value.to_os_string())
-        .unwrap_or_default();
-
     let mut candidates = Vec::<PathBuf>::new();
 
     if let Some(path) = &settings.model_path {
-        let override_path = PathBuf::from(path);
-        candidates.push(override_path.clone());
-
-        if override_path.is_relative() {
-            candidates.push(PathBuf::from("src-tauri/resources").join(&override_path));
-            if let Some(resources) = resource_dir {
-                candidates.push(resources.join(&override_path));
-                candidates.push(resources.join("resources").join(&override_path));
+        match model_catalog::find_alias(path) {
+            Some(entry) => {
+                push_bundled_candidates(&mut candidates, entry.relative_path, resource_dir)
+            }
+            None => {
+                let override_path = PathBuf::from(path);
+                candidates.push(override_path.clone());
+
+                if override_path.is_relative() {
+                    candidates.push(PathBuf::from("src-tauri/resources").join(&override_path));
+                    if let Some(resources) = resource_dir {
+                        candidates.push(resources.join(&override_path));
+                        candidates.push(resources.join("resources").join(&override_path));
+                    }
+                }
             }
         }
     }
 
-    candidates.push(PathBuf::from(default_relative));
+    push_bundled_candidates(
+        &mut candidates,
+        default_model_relative_path(settings.model_profile),
+        resource_dir,
+    );
 
-    candidates.push(PathBuf::from("src-tauri/resources").join(default_relative));
+    dedupe_paths(candidates)
+}
+
+fn push_bundled_candidates(
+    candidates: &mut Vec<PathBuf>,
+    relative: &str,
+    resource_dir: Option<&Path>,
+) {
+    let file_name = Path::new(relative)
+        .file_name()
+        .map(|value| value.to_os_string())
+        .unwrap_or_default();
+
+    candidates.push(PathBuf::from(relative));
+
+    candidates.push(PathBuf::from("src-tauri/resources").join(relative));
 
     if let Some(resources) = resource_dir {
-        candidates.push(resources.join(default_relative));
-        candidates.push(resources.join("resources").join(default_relative));
-        candidates.push(resources.join("models").join(&default_file_name));
-        candidates.push(resources.join(&default_file_name));
+        candidates.push(resources.join(relative));
+        candidates.push(resources.join("resources").join(relative));
+        candidates.push(resources.join("models").join(&file_name));
+        candidates.push(resources.join(&file_name));
     }
-
-    dedupe_paths(candidates)
 }
 
 fn dedupe_paths(paths: Vec<PathBuf>) -> Vec<PathBuf> {
@@ -162,9 +243,17 @@ fn dedupe_paths(paths: Vec<PathBuf>) -> Vec<PathBuf> {
 }
 
 pub fn resolve_model_path(settings: &AppSettings, resource_dir: Option<&Path>) -> PathBuf {
+    resolve_model_path_with(settings, resource_dir, &LiveProbe)
+}
+
+pub fn resolve_model_path_with(
+    settings: &AppSettings,
+    resource_dir: Option<&Path>,
+    probe: &dyn ResolutionProbe,
+) -> PathBuf {
     let candidates = resolve_model_candidates(settings, resource_dir);
     for candidate in &candidates {
-        if candidate.exists() {
+        if probe.exists(candidate) {
             return candidate.clone();
         }
     }
@@ -194,6 +283,7 @@ pub fn build_model_status(
         model_exists: model_path.exists(),
         checked_paths,
         tuning: tuning_for_profile(settings.model_profile),
+        faster_whisper_cache: None,
     }
 }
 
@@ -256,6 +346,22 @@ mod tests {
         assert!(candidates.len() > 1);
     }
 
+    #[test]
+    fn resolves_model_aliases_through_the_catalog() {
+        let settings = AppSettings {
+            model_profile: ModelProfile::Fast,
+            model_path: Some("base-en".to_string()),
+            ..AppSettings::default()
+        };
+
+        let candidates = resolve_model_candidates(&settings, Some(Path::new("/app/resources")));
+        assert_eq!(candidates[0], PathBuf::from("models/ggml-base.en-q5_1.bin"));
+        assert!(!candidates.contains(&PathBuf::from("base-en")));
+        assert!(candidates
+            .iter()
+            .any(|path| path == &PathBuf::from("/app/resources/models/ggml-base.en-q5_1.bin")));
+    }
+
     #[test]
     fn includes_resource_candidate_when_provided() {
         let settings = AppSettings {
@@ -306,4 +412,31 @@ mod tests {
         assert_eq!(tuning.min_chunk_samples, 8_000);
         assert_eq!(tuning.partial_cadence_ms, 2_500);
     }
+
+    #[test]
+    fn reports_only_sustained_slow_inference() {
+        let mut tracker = RealtimeTracker::default();
+        for _ in 0..SLOW_INFERENCE_STREAK - 1 {
+            assert_eq!(tracker.observe(1_500, 1_000), None);
+        }
+        assert_eq!(tracker.observe(400, 1_000), None);
+        for _ in 0..SLOW_INFERENCE_STREAK - 1 {
+            assert_eq!(tracker.observe(1_500, 1_000), None);
+        }
+        assert_eq!(
+            tracker.observe(2_000, 1_000),
+            Some(SlowInference {
+                chunks: SLOW_INFERENCE_STREAK,
+                avg_inference_ms: 1_600,
+                avg_audio_ms: 1_000,
+            })
+        );
+        assert_eq!(
+            downgraded_profile(ModelProfile::Balanced),
+            Some(ModelProfile::Fast)
+        );
+        assert_eq!(downgraded_profile(ModelProfile::Fast), None);
+        assert_eq!(toggled_profile(ModelProfile::Fast), ModelProfile::Balanced);
+        assert_eq!(toggled_profile(ModelProfile::Balanced), ModelProfile::Fast);
+    }
 }
@@ -0,0 +1,164 @@
+use serde::Serialize;
+
+const MODIFIERS: &[&str] = &[
+    "ctrl",
+    "control",
+    "shift",
+    "alt",
+    "option",
+    "cmd",
+    "command",
+    "super",
+    "meta",
+    "ctrlorcmd",
+    "cmdorctrl",
+    "commandorcontrol",
+    "commandorctrl",
+];
+
+const NAMED_KEYS: &[&str] = &[
+    "space",
+    "enter",
+    "return",
+    "tab",
+    "escape",
+    "esc",
+    "backspace",
+    "delete",
+    "insert",
+    "home",
+    "end",
+    "pageup",
+    "pagedown",
+    "up",
+    "down",
+    "left",
+    "right",
+];
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct ReadinessCheck {
+    pub ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+}
+
+impl ReadinessCheck {
+    pub fn ok(detail: Option<String>) -> Self {
+        Self { ok: true, detail }
+    }
+
+    pub fn failed(detail: impl Into<String>) -> Self {
+        Self {
+            ok: false,
+            detail: Some(detail.into()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct ReadinessReport {
+    pub ready: bool,
+    pub settings_loaded: ReadinessCheck,
+    pub engine_ready: ReadinessCheck,
+    pub mic_accessible: ReadinessCheck,
+    pub hotkey_registered: ReadinessCheck,
+    pub insertion_capable: ReadinessCheck,
+}
+
+impl ReadinessReport {
+    pub fn new(
+        settings_loaded: ReadinessCheck,
+        engine_ready: ReadinessCheck,
+        mic_accessible: ReadinessCheck,
+        hotkey_registered: ReadinessCheck,
+        insertion_capable: ReadinessCheck,
+    ) -> Self {
+        let ready = [
+            &settings_loaded,
+            &engine_ready,
+            &mic_accessible,
+            &hotkey_registered,
+            &insertion_capable,
+        ]
+        .iter()
+        .all(|check| check.ok);
+        Self {
+            ready,
+            settings_loaded,
+            engine_ready,
+            mic_accessible,
+            hotkey_registered,
+            insertion_capable,
+        }
+    }
+}
+
+pub fn validate_hotkey(hotkey: &str) -> Result<(), String> {
+    let tokens = hotkey.split('+').map(str::trim).collect::<Vec<_>>();
+    let Some((key, modifiers)) = tokens.split_last() else {
+        return Err("hotkey is empty".to_string());
+    };
+    if let Some(token) = modifiers
+        .iter()
+        .find(|token| !MODIFIERS.contains(&token.to_ascii_lowercase().as_str()))
+    {
+        return Err(format!("hotkey modifier {token:?} is not recognized"));
+    }
+    if !is_key(key) {
+        return Err(format!("hotkey key {key:?} is not recognized"));
+    }
+    Ok(())
+}
+
+fn is_key(token: &str) -> bool {
+    let lower = token.to_ascii_lowercase();
+    let mut chars = token.chars();
+    match (chars.next(), chars.next()) {
+        (Some(value), None) => value.is_ascii_graphic(),
+        _ => {
+            NAMED_KEYS.contains(&lower.as_str())
+                || lower
+                    .strip_prefix('f')
+                    .and_then(|number| number.parse::<u8>().ok())
+                    .is_some_and(|number| (1..=24).contains(&number))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validates_accelerator_strings() {
+        assert!(validate_hotkey("CtrlOrCmd+Shift+U").is_ok());
+        assert!(validate_hotkey("Alt+Space").is_ok());
+        assert!(validate_hotkey("F13").is_ok());
+        assert!(validate_hotkey("").is_err());
+        assert!(validate_hotkey("Ctrl+Shift+").is_err());
+        assert!(validate_hotkey("Hyper+U").is_err());
+        assert!(validate_hotkey("Ctrl+F25").is_err());
+    }
+
+    #[test]
+    fn overall_readiness_requires_every_check() {
+        let ready = ReadinessReport::new(
+            ReadinessCheck::ok(None),
+            ReadinessCheck::ok(None),
+            ReadinessCheck::ok(None),
+            ReadinessCheck::ok(None),
+            ReadinessCheck::ok(None),
+        );
+        assert!(ready.ready);
+
+        let missing_mic = ReadinessReport::new(
+            ReadinessCheck::ok(None),
+            ReadinessCheck::ok(None),
+            ReadinessCheck::failed("no input devices"),
+            ReadinessCheck::ok(None),
+            ReadinessCheck::ok(None),
+        );
+        assert!(!missing_mic.ready);
+    }
+}
@@ -0,0 +1,81 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+// Everything path resolution reads from the machine goes through a probe, so a
+// support snapshot can replay the same decisions on another machine.
+pub trait ResolutionProbe {
+    fn env_var(&self, name: &str) -> Option<String>;
+    fn exists(&self, path: &Path) -> bool;
+}
+
+pub struct LiveProbe;
+
+impl ResolutionProbe for LiveProbe {
+    fn env_var(&self, name: &str) -> Option<String> {
+        std::env::var(name).ok()
+    }
+
+    fn exists(&self, path: &Path) -> bool {
+        path.exists()
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ReplayProbe {
+    #[serde(default)]
+    pub env: BTreeMap<String, String>,
+    #[serde(default)]
+    pub existing_paths: BTreeSet<String>,
+}
+
+impl ReplayProbe {
+    pub fn record_env(&mut self, probe: &dyn ResolutionProbe, name: &str) {
+        if let Some(value) = probe.env_var(name) {
+            self.env.insert(name.to_string(), value);
+        }
+    }
+
+    pub fn record_path(&mut self, probe: &dyn ResolutionProbe, path: &Path) {
+        if probe.exists(path) {
+            self.existing_paths
+                .insert(path.to_string_lossy().to_string());
+        }
+    }
+}
+
+impl ResolutionProbe for ReplayProbe {
+    fn env_var(&self, name: &str) -> Option<String> {
+        self.env.get(name).cloned()
+    }
+
+    fn exists(&self, path: &Path) -> bool {
+        self.existing_paths
+            .contains(path.to_string_lossy().as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn replay_answers_only_what_was_recorded() {
+        let dir = std::env::temp_dir();
+        let mut replay = ReplayProbe::default();
+        replay.record_path(&LiveProbe, &dir);
+        replay.record_path(&LiveProbe, &dir.join("sonora-missing-resolution-probe"));
+        replay.env.insert(
+            "SONORA_WHISPER_BIN".to_string(),
+            "/opt/whisper-cli".to_string(),
+        );
+
+        assert!(replay.exists(&dir));
+        assert!(!replay.exists(&dir.join("sonora-missing-resolution-probe")));
+        assert_eq!(
+            replay.env_var("SONORA_WHISPER_BIN").as_deref(),
+            Some("/opt/whisper-cli")
+        );
+        assert_eq!(replay.env_var("SONORA_PARAKEET_BIN"), None);
+    }
+}
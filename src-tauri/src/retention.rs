@@ -0,0 +1,209 @@
+use serde::Serialize;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::UNIX_EPOCH;
+
+use crate::config::{AppSettings, RetentionMode};
+use crate::runtime_log::RuntimeLogEntry;
+
+const DAY_MS: u64 = 86_400_000;
+// Retention runs at startup and then once a day while the app stays open.
+pub const RETENTION_INTERVAL_SECS: u64 = 86_400;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetentionPolicy {
+    pub mode: RetentionMode,
+    pub days: u16,
+    pub entries: u16,
+}
+
+impl RetentionPolicy {
+    pub const NOTHING: Self = Self {
+        mode: RetentionMode::KeepNothing,
+        days: 0,
+        entries: 0,
+    };
+
+    pub fn from_settings(settings: &AppSettings) -> Self {
+        Self {
+            mode: settings.retention_mode,
+            days: settings.retention_days,
+            entries: settings.retention_entries,
+        }
+    }
+
+    // Whether an item at `index` (0 = newest) may stay. Items with no known
+    // timestamp survive an age limit since their age cannot be proven.
+    fn keeps(&self, index: usize, timestamp_unix_ms: Option<u64>, now_unix_ms: u64) -> bool {
+        match self.mode {
+            RetentionMode::KeepAll => true,
+            RetentionMode::KeepNothing => false,
+            RetentionMode::KeepEntries => index < usize::from(self.entries),
+            RetentionMode::KeepDays => timestamp_unix_ms.is_none_or(|timestamp| {
+                now_unix_ms.saturating_sub(timestamp) < u64::from(self.days) * DAY_MS
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, PartialEq, Eq)]
+pub struct PurgeReport {
+    pub utterances: usize,
+    pub insertions: usize,
+    pub recordings: usize,
+    pub log_lines: usize,
+}
+
+// Drops items the policy no longer keeps from a newest-first list and returns them.
+pub fn retain_newest_first<T>(
+    items: &mut Vec<T>,
+    policy: &RetentionPolicy,
+    now_unix_ms: u64,
+    timestamp: impl Fn(&T) -> Option<u64>,
+) -> Vec<T> {
+    let mut removed = Vec::new();
+    let mut kept = Vec::with_capacity(items.len());
+    for (index, item) in items.drain(..).enumerate() {
+        if policy.keeps(index, timestamp(&item), now_unix_ms) {
+            kept.push(item);
+        } else {
+            removed.push(item);
+        }
+    }
+    *items = kept;
+    removed
+}
+
+// Removes recording files in `dir` by modification time; returns how many went.
+pub fn purge_files(dir: &Path, policy: &RetentionPolicy, now_unix_ms: u64) -> usize {
+    let Ok(read_dir) = fs::read_dir(dir) else {
+        return 0;
+    };
+    let mut files = read_dir
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_ok_and(|kind| kind.is_file()))
+        .map(|entry| {
+            let modified = entry
+                .metadata()
+                .and_then(|metadata| metadata.modified())
+                .ok()
+                .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+                .map(|age| age.as_millis() as u64);
+            (entry.path(), modified)
+        })
+        .collect::<Vec<_>>();
+    files.sort_by_key(|file| std::cmp::Reverse(file.1));
+
+    retain_newest_first(&mut files, policy, now_unix_ms, |file| file.1)
+        .into_iter()
+        .filter(|(path, _)| fs::remove_file(path).is_ok())
+        .count()
+}
+
+// Rewrites the JSON-lines runtime log keeping only what the policy allows.
+pub fn purge_log(path: &Path, policy: &RetentionPolicy, now_unix_ms: u64) -> Result<usize, String> {
+    if policy.mode == RetentionMode::KeepAll {
+        return Ok(0);
+    }
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(0),
+        Err(error) => return Err(error.to_string()),
+    };
+    let mut lines = contents
+        .lines()
+        .rev()
+        .filter(|line| !line.trim().is_empty())
+        .collect::<Vec<_>>();
+    let removed = retain_newest_first(&mut lines, policy, now_unix_ms, |line| {
+        serde_json::from_str::<RuntimeLogEntry>(line)
+            .ok()
+            .map(|entry| entry.timestamp_unix_ms as u64)
+    })
+    .len();
+    if removed == 0 {
+        return Ok(0);
+    }
+
+    let mut payload = lines.into_iter().rev().collect::<Vec<_>>().join("\n");
+    if !payload.is_empty() {
+        payload.push('\n');
+    }
+    fs::write(path, payload).map_err(|error| error.to_string())?;
+    Ok(removed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy(mode: RetentionMode) -> RetentionPolicy {
+        RetentionPolicy {
+            mode,
+            days: 7,
+            entries: 2,
+        }
+    }
+
+    #[test]
+    fn applies_each_retention_mode() {
+        let now = 100 * DAY_MS;
+        let stamps = [
+            Some(now - DAY_MS),
+            Some(now - 8 * DAY_MS),
+            None,
+            Some(now - 9 * DAY_MS),
+        ];
+
+        let mut items = stamps.to_vec();
+        assert!(
+            retain_newest_first(&mut items, &policy(RetentionMode::KeepAll), now, |t| *t)
+                .is_empty()
+        );
+
+        let mut items = stamps.to_vec();
+        let removed =
+            retain_newest_first(&mut items, &policy(RetentionMode::KeepDays), now, |t| *t);
+        assert_eq!(items, vec![Some(now - DAY_MS), None]);
+        assert_eq!(removed.len(), 2);
+
+        let mut items = stamps.to_vec();
+        retain_newest_first(&mut items, &policy(RetentionMode::KeepEntries), now, |t| *t);
+        assert_eq!(items, stamps[..2].to_vec());
+
+        let mut items = stamps.to_vec();
+        retain_newest_first(&mut items, &RetentionPolicy::NOTHING, now, |t| *t);
+        assert!(items.is_empty());
+    }
+
+    #[test]
+    fn trims_runtime_log_to_newest_lines() {
+        let path = std::env::temp_dir().join(format!(
+            "sonora-retention-{}.log",
+            std::time::SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .expect("clock should be set")
+                .as_nanos()
+        ));
+        let line = |timestamp: u64, message: &str| {
+            format!(
+                "{{\"timestamp_unix_ms\":{timestamp},\"level\":\"info\",\"event\":\"test\",\"message\":\"{message}\"}}"
+            )
+        };
+        fs::write(
+            &path,
+            [line(1, "old"), line(2, "middle"), line(3, "new")].join("\n") + "\n",
+        )
+        .expect("log should be written");
+
+        let removed = purge_log(&path, &policy(RetentionMode::KeepEntries), 10).expect("purge");
+        assert_eq!(removed, 1);
+        let kept = fs::read_to_string(&path).expect("log should remain");
+        assert!(!kept.contains("old") && kept.contains("middle") && kept.ends_with("new\"}\n"));
+
+        assert_eq!(purge_log(&path, &RetentionPolicy::NOTHING, 10), Ok(2));
+        assert_eq!(fs::read_to_string(&path).expect("log should remain"), "");
+        let _ = fs::remove_file(path);
+    }
+}
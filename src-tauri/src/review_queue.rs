@@ -0,0 +1,88 @@
+use serde::Serialize;
+
+pub const MAX_REVIEW_ITEMS: usize = 20;
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct ReviewItem {
+    pub id: u64,
+    pub text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub utterance_id: Option<u64>,
+    pub held_unix_ms: u64,
+}
+
+// Engines that report no confidence are never held; there is nothing to gate on.
+pub fn should_hold(confidence: Option<f32>, min_confidence_percent: u8) -> bool {
+    confidence.is_some_and(|value| value * 100.0 < f32::from(min_confidence_percent))
+}
+
+pub fn lowest_confidence(current: Option<f32>, observed: Option<f32>) -> Option<f32> {
+    match (current, observed) {
+        (Some(current), Some(observed)) => Some(current.min(observed)),
+        (current, observed) => current.or(observed),
+    }
+}
+
+pub fn hold(items: &mut Vec<ReviewItem>, mut item: ReviewItem) -> ReviewItem {
+    item.id = items
+        .iter()
+        .map(|existing| existing.id)
+        .max()
+        .unwrap_or(0)
+        .saturating_add(1);
+    items.insert(0, item.clone());
+    items.truncate(MAX_REVIEW_ITEMS);
+    item
+}
+
+pub fn take(items: &mut Vec<ReviewItem>, id: u64) -> Option<ReviewItem> {
+    let index = items.iter().position(|item| item.id == id)?;
+    Some(items.remove(index))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(text: &str) -> ReviewItem {
+        ReviewItem {
+            id: 0,
+            text: text.to_string(),
+            confidence: Some(0.3),
+            language: None,
+            utterance_id: None,
+            held_unix_ms: 1,
+        }
+    }
+
+    #[test]
+    fn holds_only_reported_low_confidence() {
+        assert!(should_hold(Some(0.45), 60));
+        assert!(!should_hold(Some(0.6), 60));
+        assert!(!should_hold(None, 60));
+        assert!(!should_hold(Some(0.0), 0));
+        assert_eq!(lowest_confidence(Some(0.8), Some(0.4)), Some(0.4));
+        assert_eq!(lowest_confidence(None, Some(0.7)), Some(0.7));
+        assert_eq!(lowest_confidence(Some(0.7), None), Some(0.7));
+    }
+
+    #[test]
+    fn queues_newest_first_and_takes_by_id() {
+        let mut items = Vec::new();
+        let first = hold(&mut items, item("first"));
+        let second = hold(&mut items, item("second"));
+        assert_eq!((first.id, second.id), (1, 2));
+        assert_eq!(items[0].text, "second");
+
+        assert_eq!(
+            take(&mut items, 1).map(|item| item.text),
+            Some("first".to_string())
+        );
+        assert_eq!(take(&mut items, 1), None);
+        assert_eq!(items.len(), 1);
+    }
+}
@@ -0,0 +1,131 @@
+use crate::activation::{review_action_url, ReviewAction};
+use crate::review_queue::ReviewItem;
+
+#[cfg(target_os = "windows")]
+use std::os::windows::process::CommandExt;
+
+#[cfg(target_os = "windows")]
+const CREATE_NO_WINDOW: u32 = 0x08000000;
+
+// Toasts wrap after a few lines; the full text stays in the review panel.
+const PREVIEW_CHARS: usize = 180;
+
+// The XML travels through the environment so no quoting survives into the
+// PowerShell command line.
+#[cfg(target_os = "windows")]
+const SHOW_TOAST_SCRIPT: &str = "[Windows.UI.Notifications.ToastNotificationManager, Windows.UI.Notifications, ContentType = WindowsRuntime] > $null; [Windows.Data.Xml.Dom.XmlDocument, Windows.Data.Xml.Dom.XmlDocument, ContentType = WindowsRuntime] > $null; $xml = New-Object Windows.Data.Xml.Dom.XmlDocument; $xml.LoadXml($env:SONORA_TOAST_XML); [Windows.UI.Notifications.ToastNotificationManager]::CreateToastNotifier($env:SONORA_TOAST_APP_ID).Show([Windows.UI.Notifications.ToastNotification]::new($xml))";
+
+fn action_label(action: ReviewAction) -> &'static str {
+    match action {
+        ReviewAction::Insert => "Insert",
+        ReviewAction::Copy => "Copy",
+        ReviewAction::Discard => "Discard",
+    }
+}
+
+fn escape_xml(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for character in value.chars() {
+        match character {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            other => escaped.push(other),
+        }
+    }
+    escaped
+}
+
+fn preview(text: &str) -> String {
+    let trimmed = text.trim();
+    if trimmed.chars().count() <= PREVIEW_CHARS {
+        return trimmed.to_string();
+    }
+    let mut preview = trimmed.chars().take(PREVIEW_CHARS).collect::<String>();
+    preview.push('…');
+    preview
+}
+
+// Buttons use protocol activation, so a click reaches the running instance
+// as a sonora:// deep link even when the window is hidden.
+pub fn toast_xml(item: &ReviewItem, token: &str) -> String {
+    let title = match item.confidence {
+        Some(confidence) => format!(
+            "Transcript held for review ({:.0}% confidence)",
+            confidence * 100.0
+        ),
+        None => "Transcript held for review".to_string(),
+    };
+    let actions = ReviewAction::ALL
+        .into_iter()
+        .map(|action| {
+            format!(
+                "<action content=\"{}\" activationType=\"protocol\" arguments=\"{}\"/>",
+                action_label(action),
+                escape_xml(&review_action_url(item.id, action, token))
+            )
+        })
+        .collect::<String>();
+    format!(
+        "<toast tag=\"review-{}\" group=\"review\"><visual><binding template=\"ToastGeneric\"><text>{}</text><text>{}</text></binding></visual><actions>{actions}</actions></toast>",
+        item.id,
+        escape_xml(&title),
+        escape_xml(&preview(&item.text))
+    )
+}
+
+#[cfg(target_os = "windows")]
+pub fn show(item: &ReviewItem, app_id: &str, token: &str) -> Result<(), String> {
+    let output = std::process::Command::new("powershell")
+        .args([
+            "-NoProfile",
+            "-NonInteractive",
+            "-Command",
+            SHOW_TOAST_SCRIPT,
+        ])
+        .env("SONORA_TOAST_XML", toast_xml(item, token))
+        .env("SONORA_TOAST_APP_ID", app_id)
+        .creation_flags(CREATE_NO_WINDOW)
+        .output()
+        .map_err(|error| format!("failed to run powershell: {error}"))?;
+    if !output.status.success() {
+        return Err(format!(
+            "review toast failed with status {}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(not(target_os = "windows"))]
+pub fn show(_item: &ReviewItem, _app_id: &str, _token: &str) -> Result<(), String> {
+    Err("review toasts are only available on Windows".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn toast_escapes_text_and_links_each_action() {
+        let item = ReviewItem {
+            id: 4,
+            text: "fish & <chips>".to_string(),
+            confidence: Some(0.42),
+            language: None,
+            utterance_id: None,
+            held_unix_ms: 1,
+        };
+        let xml = toast_xml(&item, "f00d");
+        assert!(xml.contains("<text>fish &amp; &lt;chips&gt;</text>"));
+        assert!(xml.contains("(42% confidence)"));
+        assert!(xml.contains("arguments=\"sonora://review/insert?id=4&amp;token=f00d\""));
+        assert!(xml.contains("arguments=\"sonora://review/copy?id=4&amp;token=f00d\""));
+        assert!(xml.contains("arguments=\"sonora://review/discard?id=4&amp;token=f00d\""));
+
+        assert_eq!(preview(&"a".repeat(200)).chars().count(), PREVIEW_CHARS + 1);
+    }
+}
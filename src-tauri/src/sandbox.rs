@@ -0,0 +1,332 @@
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+const SYSTEM_DIRS: [&str; 6] = ["/usr", "/bin", "/sbin", "/lib", "/lib64", "/etc"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SandboxTool {
+    Bubblewrap,
+    Unshare,
+    SandboxExec,
+}
+
+impl SandboxTool {
+    // Fixed system paths, never looked up on PATH: a planted `bwrap` earlier in
+    // PATH would otherwise run every sidecar as the "sandbox".
+    pub fn program(self) -> &'static str {
+        match self {
+            Self::Bubblewrap => "/usr/bin/bwrap",
+            Self::Unshare => "/usr/bin/unshare",
+            Self::SandboxExec => "/usr/bin/sandbox-exec",
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            Self::Bubblewrap => "bubblewrap (no network, only system and sidecar paths visible)",
+            Self::Unshare => "unshare (no network)",
+            Self::SandboxExec => "sandbox-exec (no network, restricted writes)",
+        }
+    }
+}
+
+pub fn detect_sandbox_tool() -> Option<SandboxTool> {
+    let candidates: &[SandboxTool] = match std::env::consts::OS {
+        "linux" => &[SandboxTool::Bubblewrap, SandboxTool::Unshare],
+        "macos" => &[SandboxTool::SandboxExec],
+        _ => &[],
+    };
+
+    candidates
+        .iter()
+        .copied()
+        .find(|tool| Path::new(tool.program()).is_file())
+}
+
+// An explicit request fails closed; offline-only mode sandboxes when it can
+// and otherwise relies on the offline flags alone.
+pub fn sidecars_sandboxed(requested: bool, offline_only: bool) -> bool {
+    requested || (offline_only && detect_sandbox_tool().is_some())
+}
+
+// What the diagnostics show for a sidecar launch. Windows has no containment
+// yet, so a sandboxed sidecar there refuses to start.
+pub fn status_label(sandboxed: bool) -> String {
+    if !sandboxed {
+        return "off".to_string();
+    }
+    match detect_sandbox_tool() {
+        Some(tool) => tool.description().to_string(),
+        None => format!(
+            "unavailable (no sandbox tool on {}; sidecars will not start)",
+            std::env::consts::OS
+        ),
+    }
+}
+
+pub fn sandbox_args(
+    tool: SandboxTool,
+    program: &Path,
+    readonly_paths: &[PathBuf],
+    writable_paths: &[PathBuf],
+) -> Vec<OsString> {
+    let mut args = Vec::<OsString>::new();
+    match tool {
+        SandboxTool::Bubblewrap => {
+            // Only what a dynamically linked worker needs to start, plus its
+            // own directory, the model and resource paths and the writable
+            // cache and temp paths; home directories stay out of view.
+            for dir in SYSTEM_DIRS {
+                for value in ["--ro-bind-try", dir, dir] {
+                    args.push(value.into());
+                }
+            }
+            for value in [
+                "--dev",
+                "/dev",
+                "--proc",
+                "/proc",
+                "--unshare-net",
+                "--die-with-parent",
+            ] {
+                args.push(value.into());
+            }
+            for path in program
+                .parent()
+                .into_iter()
+                .chain(readonly_paths.iter().map(PathBuf::as_path))
+            {
+                args.push("--ro-bind".into());
+                args.push(path.into());
+                args.push(path.into());
+            }
+            for path in writable_paths {
+                args.push("--bind".into());
+                args.push(path.into());
+                args.push(path.into());
+            }
+            args.push("--".into());
+        }
+        SandboxTool::Unshare => {
+            for value in ["--user", "--map-root-user", "--net", "--"] {
+                args.push(value.into());
+            }
+        }
+        SandboxTool::SandboxExec => {
+            args.push("-p".into());
+            args.push(sandbox_exec_profile(writable_paths).into());
+        }
+    }
+    args.push(program.into());
+    args
+}
+
+// `readonly_paths` are models and extra library directories the worker reads;
+// bubblewrap hides everything else outside the system directories.
+pub fn sidecar_command(
+    program: &Path,
+    sandboxed: bool,
+    readonly_paths: &[PathBuf],
+    writable_paths: &[PathBuf],
+) -> Result<Command, String> {
+    if !sandboxed {
+        return Ok(Command::new(program));
+    }
+
+    match detect_sandbox_tool() {
+        Some(tool) => {
+            let existing = |paths: &[PathBuf]| {
+                paths
+                    .iter()
+                    .filter(|path| path.exists())
+                    .cloned()
+                    .collect::<Vec<_>>()
+            };
+            let mut command = Command::new(tool.program());
+            command.args(sandbox_args(
+                tool,
+                program,
+                &existing(readonly_paths),
+                &existing(writable_paths),
+            ));
+            Ok(command)
+        }
+        None => Err(format!(
+            "sidecar sandboxing is on but no sandbox tool is installed on {}; refusing to start '{}' unrestricted",
+            std::env::consts::OS,
+            program.to_string_lossy()
+        )),
+    }
+}
+
+// bwrap forks the worker and stays behind as its parent, so the process we
+// spawned is bwrap itself and the worker is its child. unshare and
+// sandbox-exec exec the worker in place, so their pid is already the worker's.
+pub fn worker_pid(spawned: u32) -> u32 {
+    let comm = std::fs::read_to_string(format!("/proc/{spawned}/comm")).unwrap_or_default();
+    let bwrap = Path::new(SandboxTool::Bubblewrap.program()).file_name();
+    if bwrap != Some(comm.trim().as_ref()) {
+        return spawned;
+    }
+    child_pids(spawned).first().copied().unwrap_or(spawned)
+}
+
+fn child_pids(parent: u32) -> Vec<u32> {
+    // Each thread lists the children it forked.
+    let listed = std::fs::read_dir(format!("/proc/{parent}/task"))
+        .map(|tasks| {
+            tasks
+                .filter_map(Result::ok)
+                .filter_map(|task| std::fs::read_to_string(task.path().join("children")).ok())
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    if !listed.is_empty() {
+        return listed
+            .iter()
+            .flat_map(|children| children.split_whitespace())
+            .filter_map(|pid| pid.parse().ok())
+            .collect();
+    }
+    // Kernels without CONFIG_PROC_CHILDREN: walk every process's parent.
+    std::fs::read_dir("/proc")
+        .map(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .filter_map(|entry| entry.file_name().to_str()?.parse::<u32>().ok())
+                .filter(|pid| {
+                    std::fs::read_to_string(format!("/proc/{pid}/stat"))
+                        .ok()
+                        .and_then(|stat| parent_pid(&stat))
+                        == Some(parent)
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+// The command name in /proc/<pid>/stat is parenthesised and may itself
+// contain spaces or parentheses, so fields are counted from the last ')'.
+fn parent_pid(stat: &str) -> Option<u32> {
+    let (_, rest) = stat.rsplit_once(')')?;
+    rest.split_whitespace().nth(1)?.parse().ok()
+}
+
+fn sandbox_exec_profile(writable_paths: &[PathBuf]) -> String {
+    let mut profile = String::from("(version 1)(allow default)(deny network*)");
+    profile.push_str("(deny file-write* (require-not (require-any (literal \"/dev/null\")");
+    for path in writable_paths {
+        let escaped = path
+            .to_string_lossy()
+            .replace('\\', "\\\\")
+            .replace('"', "\\\"");
+        profile.push_str(&format!(" (subpath \"{escaped}\")"));
+    }
+    profile.push_str(")))");
+    profile
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(args: Vec<OsString>) -> Vec<String> {
+        args.into_iter()
+            .map(|value| value.to_string_lossy().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn bubblewrap_denies_network_and_binds_only_writable_paths() {
+        let args = strings(sandbox_args(
+            SandboxTool::Bubblewrap,
+            Path::new("/opt/sonora/worker"),
+            &[PathBuf::from("/models/base.bin")],
+            &[PathBuf::from("/tmp")],
+        ));
+
+        assert_eq!(&args[..3], ["--ro-bind-try", "/usr", "/usr"]);
+        assert!(!args.windows(3).any(|bind| bind[1] == "/" && bind[2] == "/"));
+        assert!(args.contains(&"--unshare-net".to_string()));
+        let readonly = args
+            .windows(3)
+            .filter(|bind| bind[0] == "--ro-bind")
+            .map(|bind| bind[1].as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(readonly, ["/opt/sonora", "/models/base.bin"]);
+        let bind = args
+            .iter()
+            .position(|value| value == "--bind")
+            .expect("bind");
+        assert_eq!(&args[bind + 1..bind + 3], ["/tmp", "/tmp"]);
+        assert_eq!(&args[args.len() - 2..], ["--", "/opt/sonora/worker"]);
+    }
+
+    #[test]
+    fn sandbox_exec_profile_denies_network_and_unlisted_writes() {
+        let args = strings(sandbox_args(
+            SandboxTool::SandboxExec,
+            Path::new("/Applications/Sonora.app/worker"),
+            &[],
+            &[PathBuf::from("/Users/me/Library/Caches/sonora")],
+        ));
+
+        assert_eq!(args[0], "-p");
+        assert!(args[1].contains("(deny network*)"));
+        assert!(args[1].contains("(subpath \"/Users/me/Library/Caches/sonora\")"));
+        assert_eq!(args[2], "/Applications/Sonora.app/worker");
+
+        let unshare = strings(sandbox_args(
+            SandboxTool::Unshare,
+            Path::new("/opt/worker"),
+            &[],
+            &[],
+        ));
+        assert_eq!(
+            unshare,
+            ["--user", "--map-root-user", "--net", "--", "/opt/worker"]
+        );
+    }
+
+    #[test]
+    fn finds_the_worker_behind_its_parent() {
+        assert_eq!(
+            parent_pid("4242 (python3 (worker)) S 4200 4242 4200 0 -1"),
+            Some(4200)
+        );
+        assert_eq!(parent_pid("garbage"), None);
+
+        if cfg!(target_os = "linux") {
+            let mut child = Command::new("sleep").arg("5").spawn().expect("sleep");
+            assert!(child_pids(std::process::id()).contains(&child.id()));
+            // Not bwrap, so the spawned pid is already the worker.
+            assert_eq!(worker_pid(child.id()), child.id());
+            let _ = child.kill();
+            let _ = child.wait();
+        }
+    }
+
+    #[test]
+    fn uses_fixed_tool_paths_and_refuses_to_run_unsandboxed() {
+        for tool in [
+            SandboxTool::Bubblewrap,
+            SandboxTool::Unshare,
+            SandboxTool::SandboxExec,
+        ] {
+            assert!(Path::new(tool.program()).is_absolute());
+        }
+        let worker = Path::new("/opt/worker");
+        match detect_sandbox_tool() {
+            Some(tool) => {
+                let command = sidecar_command(worker, true, &[], &[]).expect("sandboxed command");
+                assert_eq!(command.get_program(), tool.program());
+            }
+            None => assert!(sidecar_command(worker, true, &[], &[]).is_err()),
+        }
+        let plain = sidecar_command(worker, false, &[], &[]).expect("plain command");
+        assert_eq!(plain.get_program(), "/opt/worker");
+        assert!(!sidecars_sandboxed(false, false));
+        assert!(sidecars_sandboxed(true, false));
+    }
+}
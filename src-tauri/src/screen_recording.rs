@@ -0,0 +1,217 @@
+use serde::Serialize;
+use std::path::PathBuf;
+use std::process::Command;
+
+#[cfg(target_os = "windows")]
+use std::os::windows::process::CommandExt;
+
+#[cfg(target_os = "windows")]
+const CREATE_NO_WINDOW: u32 = 0x08000000;
+
+// No desktop OS exposes "a recording is in progress" to other apps, so a
+// running recorder process is the signal. Only recorders whose process lives
+// exactly as long as the recording are listed; OBS and Game Bar's capture
+// host sit idle for hours and would look like a recording.
+#[cfg(target_os = "linux")]
+const RECORDER_PROCESSES: [(&str, &str); 7] = [
+    // /proc comm names are cut to 15 bytes.
+    ("simplescreenrec", "SimpleScreenRecorder"),
+    ("gpu-screen-reco", "GPU Screen Recorder"),
+    ("wf-recorder", "wf-recorder"),
+    ("kazam", "Kazam"),
+    ("peek", "Peek"),
+    ("kooha", "Kooha"),
+    ("vokoscreenNG", "vokoscreenNG"),
+];
+
+#[cfg(target_os = "macos")]
+const RECORDER_PROCESSES: [(&str, &str); 2] = [
+    // Only alive while a Screenshot toolbar recording runs.
+    ("screencaptureui", "macOS Screenshot"),
+    ("ScreenFlow", "ScreenFlow"),
+];
+
+#[cfg(target_os = "windows")]
+const RECORDER_PROCESSES: [(&str, &str); 2] = [
+    ("ScreenClippingHost.exe", "Snipping Tool"),
+    ("CamtasiaRecorder.exe", "Camtasia"),
+];
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+const RECORDER_PROCESSES: [(&str, &str); 0] = [];
+
+// Input devices that carry what the system plays rather than a microphone:
+// PulseAudio/PipeWire monitors, Windows Stereo Mix and macOS virtual drivers.
+const LOOPBACK_MARKERS: [&str; 7] = [
+    "monitor of",
+    ".monitor",
+    "stereo mix",
+    "what u hear",
+    "loopback",
+    "blackhole",
+    "soundflower",
+];
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordingState {
+    Started,
+    Stopped,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct RecordingTransition {
+    pub state: RecordingState,
+    pub recorder: String,
+    pub started_unix_ms: u64,
+}
+
+// Debounces process scans into start/stop transitions. The start time is the
+// first scan that saw the recorder, so it trails the real start by at most one
+// poll interval.
+#[derive(Debug, Default)]
+pub struct RecordingWatch {
+    active: Option<(String, u64)>,
+}
+
+impl RecordingWatch {
+    pub fn observe(
+        &mut self,
+        recorder: Option<&str>,
+        now_unix_ms: u64,
+    ) -> Option<RecordingTransition> {
+        match (self.active.take(), recorder) {
+            (None, Some(recorder)) => {
+                self.active = Some((recorder.to_string(), now_unix_ms));
+                Some(RecordingTransition {
+                    state: RecordingState::Started,
+                    recorder: recorder.to_string(),
+                    started_unix_ms: now_unix_ms,
+                })
+            }
+            (Some((recorder, started_unix_ms)), None) => Some(RecordingTransition {
+                state: RecordingState::Stopped,
+                recorder,
+                started_unix_ms,
+            }),
+            (active, _) => {
+                self.active = active;
+                None
+            }
+        }
+    }
+}
+
+pub fn detect_recorder(processes: &[String]) -> Option<&'static str> {
+    RECORDER_PROCESSES
+        .iter()
+        .find(|(process, _)| {
+            processes
+                .iter()
+                .any(|running| running.eq_ignore_ascii_case(process))
+        })
+        .map(|(_, label)| *label)
+}
+
+pub fn loopback_input(labels: &[String]) -> Option<usize> {
+    labels.iter().position(|label| {
+        let label = label.to_lowercase();
+        LOOPBACK_MARKERS.iter().any(|marker| label.contains(marker))
+    })
+}
+
+pub fn transcript_path(started_unix_ms: u64) -> PathBuf {
+    crate::meeting::default_meeting_path(started_unix_ms)
+        .with_file_name(format!("recording-{started_unix_ms}.txt"))
+}
+
+pub fn running_processes() -> Vec<String> {
+    if cfg!(target_os = "linux") {
+        return std::fs::read_dir("/proc")
+            .map(|entries| {
+                entries
+                    .filter_map(Result::ok)
+                    .filter_map(|entry| std::fs::read_to_string(entry.path().join("comm")).ok())
+                    .map(|name| name.trim().to_string())
+                    .collect()
+            })
+            .unwrap_or_default();
+    }
+    if cfg!(target_os = "macos") {
+        return command_lines("ps", &["-axco", "comm="]);
+    }
+    if cfg!(target_os = "windows") {
+        return command_lines("tasklist", &["/fo", "csv", "/nh"])
+            .into_iter()
+            .filter_map(|line| {
+                line.split(',')
+                    .next()
+                    .map(|name| name.trim_matches('"').to_string())
+            })
+            .collect();
+    }
+    Vec::new()
+}
+
+fn command_lines(program: &str, args: &[&str]) -> Vec<String> {
+    let mut command = Command::new(program);
+    command.args(args);
+
+    #[cfg(target_os = "windows")]
+    {
+        command.creation_flags(CREATE_NO_WINDOW);
+    }
+
+    command
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| {
+            String::from_utf8_lossy(&output.stdout)
+                .lines()
+                .map(|line| line.trim().to_string())
+                .filter(|line| !line.is_empty())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn watch_reports_each_recording_once() {
+        let mut watch = RecordingWatch::default();
+        assert_eq!(watch.observe(None, 1_000), None);
+
+        let started = watch.observe(Some("Kazam"), 2_000).expect("start");
+        assert_eq!(started.state, RecordingState::Started);
+        assert_eq!(started.started_unix_ms, 2_000);
+        assert_eq!(watch.observe(Some("Kazam"), 3_000), None);
+
+        let stopped = watch.observe(None, 4_000).expect("stop");
+        assert_eq!(stopped.state, RecordingState::Stopped);
+        assert_eq!(stopped.recorder, "Kazam");
+        assert_eq!(stopped.started_unix_ms, 2_000);
+        assert_eq!(watch.observe(None, 5_000), None);
+    }
+
+    #[test]
+    fn picks_loopback_inputs_over_microphones() {
+        let labels = [
+            "Built-in Microphone".to_string(),
+            "Monitor of Built-in Audio Analog Stereo".to_string(),
+        ];
+        assert_eq!(loopback_input(&labels), Some(1));
+        assert_eq!(loopback_input(&labels[..1]), None);
+        assert_eq!(detect_recorder(&[]), None);
+        // Idle-capable apps are never taken as a recording.
+        let idle = [
+            "obs".to_string(),
+            "obs64.exe".to_string(),
+            "bcastdvr.exe".to_string(),
+        ];
+        assert_eq!(detect_recorder(&idle), None);
+    }
+}
@@ -0,0 +1,164 @@
+use regex::{Captures, Regex};
+use serde::Serialize;
+
+use crate::config::{ScrubAction, ScrubPreset, ScrubbingSettings};
+
+pub const MASK: &str = "[redacted]";
+
+const CREDIT_CARD_PATTERN: &str = r"\b(?:\d[ -]?){12,18}\d\b";
+const SSN_PATTERN: &str = r"\b\d{3}[- ]\d{2}[- ]\d{4}\b";
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ScrubOutcome {
+    pub text: String,
+    pub matched: Vec<String>,
+    pub blocked: bool,
+}
+
+struct Rule {
+    label: String,
+    pattern: Regex,
+    luhn: bool,
+}
+
+pub struct Scrubber {
+    rules: Vec<Rule>,
+    action: ScrubAction,
+}
+
+impl Scrubber {
+    pub fn new(settings: &ScrubbingSettings) -> Result<Option<Self>, String> {
+        let mut rules = Vec::new();
+        for preset in &settings.presets {
+            let (label, source, luhn) = match preset {
+                ScrubPreset::CreditCard => ("credit_card", CREDIT_CARD_PATTERN, true),
+                ScrubPreset::Ssn => ("ssn", SSN_PATTERN, false),
+            };
+            rules.push(Rule {
+                label: label.to_string(),
+                pattern: Regex::new(source).map_err(|error| error.to_string())?,
+                luhn,
+            });
+        }
+        for (index, source) in settings.custom_patterns.iter().enumerate() {
+            let pattern = Regex::new(source)
+                .map_err(|error| format!("invalid scrub pattern {source:?}: {error}"))?;
+            rules.push(Rule {
+                label: format!("custom_{}", index + 1),
+                pattern,
+                luhn: false,
+            });
+        }
+
+        Ok((!rules.is_empty()).then_some(Self {
+            rules,
+            action: settings.action,
+        }))
+    }
+
+    pub fn scrub(&self, text: &str) -> ScrubOutcome {
+        let mut scrubbed = text.to_string();
+        let mut matched = Vec::new();
+        for rule in &self.rules {
+            let mut hit = false;
+            let replaced = rule.pattern.replace_all(&scrubbed, |captures: &Captures| {
+                let found = &captures[0];
+                if rule.luhn && !passes_luhn(found) {
+                    return found.to_string();
+                }
+                hit = true;
+                MASK.to_string()
+            });
+            let replaced = replaced.into_owned();
+            if hit {
+                matched.push(rule.label.clone());
+                scrubbed = replaced;
+            }
+        }
+
+        let blocked = self.action == ScrubAction::Block && !matched.is_empty();
+        ScrubOutcome {
+            text: if self.action == ScrubAction::Mask {
+                scrubbed
+            } else {
+                text.to_string()
+            },
+            matched,
+            blocked,
+        }
+    }
+}
+
+// Card-length digit runs are common in dictation (order numbers, phone numbers);
+// the Luhn checksum keeps those from being masked.
+fn passes_luhn(candidate: &str) -> bool {
+    let digits = candidate
+        .chars()
+        .filter_map(|value| value.to_digit(10))
+        .collect::<Vec<_>>();
+    if !(13..=19).contains(&digits.len()) {
+        return false;
+    }
+    let sum = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(index, digit)| {
+            if index % 2 == 1 {
+                let doubled = digit * 2;
+                if doubled > 9 {
+                    doubled - 9
+                } else {
+                    doubled
+                }
+            } else {
+                *digit
+            }
+        })
+        .sum::<u32>();
+    sum % 10 == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings(action: ScrubAction, custom: &[&str]) -> ScrubbingSettings {
+        ScrubbingSettings {
+            presets: vec![ScrubPreset::CreditCard, ScrubPreset::Ssn],
+            custom_patterns: custom.iter().map(|value| value.to_string()).collect(),
+            action,
+        }
+    }
+
+    #[test]
+    fn masks_valid_cards_and_ssns_only() {
+        let scrubber = Scrubber::new(&settings(ScrubAction::Mask, &[]))
+            .expect("patterns should compile")
+            .expect("scrubber should be enabled");
+        let outcome =
+            scrubber.scrub("card 4111 1111 1111 1111, order 1234 5678 9012 3456, ssn 123-45-6789");
+        assert_eq!(
+            outcome.text,
+            "card [redacted], order 1234 5678 9012 3456, ssn [redacted]"
+        );
+        assert_eq!(outcome.matched, vec!["credit_card", "ssn"]);
+        assert!(!outcome.blocked);
+    }
+
+    #[test]
+    fn blocks_on_custom_pattern_without_rewriting() {
+        let scrubber = Scrubber::new(&settings(ScrubAction::Block, &[r"(?i)\bproject falcon\b"]))
+            .expect("patterns should compile")
+            .expect("scrubber should be enabled");
+        let outcome = scrubber.scrub("status on Project Falcon");
+        assert!(outcome.blocked);
+        assert_eq!(outcome.matched, vec!["custom_1"]);
+        assert_eq!(outcome.text, "status on Project Falcon");
+
+        assert!(Scrubber::new(&ScrubbingSettings::default())
+            .expect("empty settings should compile")
+            .is_none());
+        assert!(Scrubber::new(&settings(ScrubAction::Mask, &["("])).is_err());
+    }
+}
@@ -0,0 +1,147 @@
+use serde::Deserialize;
+
+// Whisper's own defaults for treating a segment as silence.
+pub const NO_SPEECH_THRESHOLD: f32 = 0.6;
+pub const LOGPROB_THRESHOLD: f32 = -1.0;
+// Without a no-speech probability only a much lower log-probability is trusted,
+// so quiet but real speech is not dropped.
+const LOGPROB_ONLY_THRESHOLD: f32 = -1.5;
+
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct TranscriptSegment {
+    pub text: String,
+    #[serde(default)]
+    pub no_speech_prob: Option<f32>,
+    #[serde(default)]
+    pub avg_logprob: Option<f32>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FilteredTranscript {
+    pub text: String,
+    pub confidence: Option<f32>,
+    pub dropped_segments: usize,
+}
+
+#[derive(Debug, Deserialize)]
+struct WhisperCppOutput {
+    #[serde(default)]
+    transcription: Vec<WhisperCppSegment>,
+}
+
+#[derive(Debug, Deserialize)]
+struct WhisperCppSegment {
+    text: String,
+    #[serde(default)]
+    tokens: Vec<WhisperCppToken>,
+}
+
+#[derive(Debug, Deserialize)]
+struct WhisperCppToken {
+    text: String,
+    p: f32,
+}
+
+pub fn is_low_quality(segment: &TranscriptSegment) -> bool {
+    match (segment.no_speech_prob, segment.avg_logprob) {
+        (Some(no_speech), Some(logprob)) => {
+            no_speech > NO_SPEECH_THRESHOLD && logprob < LOGPROB_THRESHOLD
+        }
+        (None, Some(logprob)) => logprob < LOGPROB_ONLY_THRESHOLD,
+        (Some(_), None) | (None, None) => false,
+    }
+}
+
+pub fn filter_segments(segments: &[TranscriptSegment]) -> FilteredTranscript {
+    let mut pieces = Vec::new();
+    let mut log_probs = Vec::new();
+    let mut dropped_segments = 0;
+    for segment in segments {
+        let text = segment.text.trim();
+        if text.is_empty() {
+            continue;
+        }
+        if is_low_quality(segment) {
+            dropped_segments += 1;
+            continue;
+        }
+        pieces.push(text);
+        log_probs.extend(segment.avg_logprob);
+    }
+
+    FilteredTranscript {
+        text: pieces.join(" "),
+        confidence: (!log_probs.is_empty())
+            .then(|| (log_probs.iter().sum::<f32>() / log_probs.len() as f32).exp()),
+        dropped_segments,
+    }
+}
+
+// whisper.cpp's full JSON output (-ojf) has token probabilities but no
+// no-speech probability; special tokens such as [_BEG_] are left out.
+pub fn parse_whisper_cpp_json(raw: &str) -> Result<Vec<TranscriptSegment>, String> {
+    let output = serde_json::from_str::<WhisperCppOutput>(raw)
+        .map_err(|error| format!("failed to parse whisper JSON output: {error}"))?;
+    Ok(output
+        .transcription
+        .into_iter()
+        .map(|segment| {
+            let log_probs = segment
+                .tokens
+                .iter()
+                .filter(|token| !token.text.starts_with("[_"))
+                .map(|token| token.p.max(f32::MIN_POSITIVE).ln())
+                .collect::<Vec<_>>();
+            TranscriptSegment {
+                text: segment.text,
+                no_speech_prob: None,
+                avg_logprob: (!log_probs.is_empty())
+                    .then(|| log_probs.iter().sum::<f32>() / log_probs.len() as f32),
+            }
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(text: &str, no_speech: Option<f32>, logprob: Option<f32>) -> TranscriptSegment {
+        TranscriptSegment {
+            text: text.to_string(),
+            no_speech_prob: no_speech,
+            avg_logprob: logprob,
+        }
+    }
+
+    #[test]
+    fn drops_only_low_quality_segments() {
+        let filtered = filter_segments(&[
+            segment(" Send the report today.", Some(0.05), Some(-0.2)),
+            segment(" Thanks for watching!", Some(0.82), Some(-1.3)),
+            segment(" Quietly spoken.", Some(0.7), Some(-0.6)),
+            segment(" Subtitles by", None, Some(-2.1)),
+            segment("   ", None, None),
+        ]);
+        assert_eq!(filtered.text, "Send the report today. Quietly spoken.");
+        assert_eq!(filtered.dropped_segments, 2);
+        assert!((filtered.confidence.expect("confidence") - (-0.4f32).exp()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn parses_whisper_cpp_token_probabilities() {
+        let raw = r#"{"transcription":[
+            {"text":" Hello there.","tokens":[
+                {"text":"[_BEG_]","p":0.01},
+                {"text":" Hello","p":0.9},
+                {"text":" there.","p":0.8}]},
+            {"text":" you","tokens":[{"text":" you","p":0.1}]}
+        ]}"#;
+        let segments = parse_whisper_cpp_json(raw).expect("whisper JSON should parse");
+        assert_eq!(segments.len(), 2);
+        let expected = (0.9f32.ln() + 0.8f32.ln()) / 2.0;
+        assert!((segments[0].avg_logprob.expect("logprob") - expected).abs() < 1e-6);
+        assert_eq!(filter_segments(&segments).text, "Hello there.");
+        assert!(parse_whisper_cpp_json("not json").is_err());
+    }
+}
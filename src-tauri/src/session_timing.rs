@@ -0,0 +1,72 @@
+use std::time::Duration;
+
+use crate::config::AppSettings;
+
+pub const FRAME_RECV_TIMEOUT_MS_MIN: u16 = 10;
+pub const FRAME_RECV_TIMEOUT_MS_MAX: u16 = 500;
+pub const METER_REFRESH_HZ_MIN: u16 = 1;
+pub const METER_REFRESH_HZ_MAX: u16 = 120;
+
+// How a live capture session paces itself, resolved once when the session starts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LiveSessionTiming {
+    // How long a worker waits for the next audio frame before doing idle work
+    // such as flushing a finished utterance or checking for a stop request.
+    pub frame_recv_timeout: Duration,
+    pub meter_emit_interval: Duration,
+}
+
+impl Default for LiveSessionTiming {
+    fn default() -> Self {
+        Self::new(60, 30)
+    }
+}
+
+impl LiveSessionTiming {
+    pub fn new(frame_recv_timeout_ms: u16, meter_refresh_hz: u16) -> Self {
+        let frame_recv_timeout_ms =
+            frame_recv_timeout_ms.clamp(FRAME_RECV_TIMEOUT_MS_MIN, FRAME_RECV_TIMEOUT_MS_MAX);
+        let meter_refresh_hz = meter_refresh_hz.clamp(METER_REFRESH_HZ_MIN, METER_REFRESH_HZ_MAX);
+        Self {
+            frame_recv_timeout: Duration::from_millis(u64::from(frame_recv_timeout_ms)),
+            meter_emit_interval: Duration::from_millis(1_000 / u64::from(meter_refresh_hz)),
+        }
+    }
+
+    pub fn from_settings(settings: &AppSettings) -> Self {
+        Self::new(settings.frame_recv_timeout_ms, settings.meter_refresh_hz)
+    }
+
+    pub fn should_emit_meter(&self, elapsed: Duration) -> bool {
+        elapsed >= self.meter_emit_interval
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_meter_targets_a_smooth_thirty_hertz() {
+        let timing = LiveSessionTiming::default();
+        assert_eq!(timing.frame_recv_timeout, Duration::from_millis(60));
+        assert!(!timing.should_emit_meter(Duration::from_millis(20)));
+        assert!(timing.should_emit_meter(Duration::from_millis(33)));
+    }
+
+    #[test]
+    fn derives_from_settings_within_bounds() {
+        let settings = AppSettings {
+            frame_recv_timeout_ms: 5_000,
+            meter_refresh_hz: 60,
+            ..AppSettings::default()
+        };
+        let timing = LiveSessionTiming::from_settings(&settings);
+        assert_eq!(timing.frame_recv_timeout, Duration::from_millis(500));
+        assert_eq!(timing.meter_emit_interval, Duration::from_millis(16));
+
+        let low_power = LiveSessionTiming::new(0, 10);
+        assert_eq!(low_power.frame_recv_timeout, Duration::from_millis(10));
+        assert_eq!(low_power.meter_emit_interval, Duration::from_millis(100));
+    }
+}
@@ -1,8 +1,17 @@
 use crate::config::{
-    AppSettings, DictationMode, FasterWhisperComputeType, ModelProfile, ParakeetComputeType,
-    SttEngine, WhisperBackendPreference,
+    AppRule, AppSettings, DictationMode, DuplicateSuppression, FasterWhisperComputeType,
+    HallucinationGuard, InsertionVerification, LongTranscriptBehavior, MicrophoneProfile,
+    ModelProfile, ParakeetComputeType, PlaybackGuard, PreprocessingChains, RetentionMode,
+    ScrubbingSettings, SpokenPhraseOverride, SttEngine, ThreadPriorities, TranscriptViewer,
+    UtteranceRouting, WhisperBackendPreference,
 };
+use crate::number_format;
 use crate::profile::{clamp_chunk_duration_ms, clamp_partial_cadence_ms};
+use crate::session_timing::{
+    FRAME_RECV_TIMEOUT_MS_MAX, FRAME_RECV_TIMEOUT_MS_MIN, METER_REFRESH_HZ_MAX,
+    METER_REFRESH_HZ_MIN,
+};
+use crate::utterance_routing;
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::io;
@@ -27,6 +36,73 @@ pub struct AppSettingsPatch {
     pub parakeet_compute_type: Option<ParakeetComputeType>,
     pub vad_disabled: Option<bool>,
     pub vad_rms_threshold_milli: Option<u16>,
+    pub chunk_normalization: Option<bool>,
+    pub max_recent_insertions: Option<u16>,
+    pub focus_guard_enabled: Option<bool>,
+    pub focus_guard_timeout_ms: Option<u16>,
+    pub privacy_mode: Option<bool>,
+    pub drop_folder_path: Option<Option<String>>,
+    pub drop_folder_poll_secs: Option<u16>,
+    pub app_rules: Option<Vec<AppRule>>,
+    pub meeting_transcript_enabled: Option<bool>,
+    pub meeting_paragraph_gap_secs: Option<u16>,
+    pub captions_enabled: Option<bool>,
+    pub caption_line_chars: Option<u16>,
+    pub predictive_preload_enabled: Option<bool>,
+    pub idle_unload_minutes: Option<u16>,
+    pub sidecar_memory_cap_mb: Option<u32>,
+    pub sidecar_sandbox_enabled: Option<bool>,
+    pub allow_path_sidecars: Option<bool>,
+    pub offline_only: Option<bool>,
+    pub event_prefix: Option<Option<String>>,
+    pub noise_suppression_enabled: Option<bool>,
+    pub punctuation_restoration: Option<bool>,
+    pub max_insertion_chars: Option<u32>,
+    pub long_transcript_behavior: Option<LongTranscriptBehavior>,
+    pub scrubbing: Option<ScrubbingSettings>,
+    pub meter_attack_ms: Option<u16>,
+    pub meter_release_ms: Option<u16>,
+    pub meter_peak_hold_ms: Option<u16>,
+    pub performance_trace: Option<bool>,
+    pub auto_downgrade_profile: Option<bool>,
+    pub spoken_punctuation: Option<bool>,
+    pub faster_whisper_cache_dir: Option<Option<String>>,
+    pub insertion_verification: Option<InsertionVerification>,
+    pub retry_unverified_insertion: Option<bool>,
+    pub panic_hotkey: Option<String>,
+    pub auto_insert: Option<bool>,
+    pub auto_insert_min_confidence_percent: Option<u8>,
+    pub microphone_profiles: Option<Vec<MicrophoneProfile>>,
+    pub preprocessing: Option<PreprocessingChains>,
+    pub shadow_engine: Option<Option<SttEngine>>,
+    pub journal_directory: Option<Option<String>>,
+    pub journal_profile: Option<Option<ModelProfile>>,
+    pub obs_caption_file: Option<Option<String>>,
+    pub instant_words: Option<bool>,
+    pub profile_toggle_hotkey: Option<String>,
+    pub number_locale: Option<Option<String>>,
+    pub voice_activation: Option<bool>,
+    pub voice_activation_silence_ms: Option<u16>,
+    pub utterance_audio: Option<bool>,
+    pub adaptive_cadence: Option<bool>,
+    pub retention_mode: Option<RetentionMode>,
+    pub retention_days: Option<u16>,
+    pub retention_entries: Option<u16>,
+    pub screen_reader_announcements: Option<bool>,
+    pub gpu_device_index: Option<Option<u32>>,
+    pub playback_guard: Option<PlaybackGuard>,
+    pub frame_recv_timeout_ms: Option<u16>,
+    pub meter_refresh_hz: Option<u16>,
+    pub spoken_phrase_overrides: Option<Vec<SpokenPhraseOverride>>,
+    pub utterance_routing: Option<UtteranceRouting>,
+    pub auto_quantize_models: Option<bool>,
+    pub thread_priorities: Option<ThreadPriorities>,
+    pub review_toasts: Option<bool>,
+    pub screen_recording_transcripts: Option<bool>,
+    pub duplicate_suppression: Option<DuplicateSuppression>,
+    pub hallucination_guard: Option<HallucinationGuard>,
+    pub transcript_viewer: Option<TranscriptViewer>,
+    pub cold_start_fast_path: Option<bool>,
     pub clipboard_fallback: Option<bool>,
     pub launch_at_startup: Option<bool>,
 }
@@ -99,6 +175,175 @@ pub fn apply_patch(settings: &AppSettings, patch: AppSettingsPatch) -> AppSettin
         vad_rms_threshold_milli: patch
             .vad_rms_threshold_milli
             .or(settings.vad_rms_threshold_milli),
+        chunk_normalization: patch
+            .chunk_normalization
+            .unwrap_or(settings.chunk_normalization),
+        max_recent_insertions: patch
+            .max_recent_insertions
+            .unwrap_or(settings.max_recent_insertions),
+        focus_guard_enabled: patch
+            .focus_guard_enabled
+            .unwrap_or(settings.focus_guard_enabled),
+        focus_guard_timeout_ms: patch
+            .focus_guard_timeout_ms
+            .unwrap_or(settings.focus_guard_timeout_ms),
+        privacy_mode: patch.privacy_mode.unwrap_or(settings.privacy_mode),
+        drop_folder_path: patch
+            .drop_folder_path
+            .unwrap_or_else(|| settings.drop_folder_path.clone()),
+        drop_folder_poll_secs: patch
+            .drop_folder_poll_secs
+            .unwrap_or(settings.drop_folder_poll_secs),
+        app_rules: patch
+            .app_rules
+            .unwrap_or_else(|| settings.app_rules.clone()),
+        meeting_transcript_enabled: patch
+            .meeting_transcript_enabled
+            .unwrap_or(settings.meeting_transcript_enabled),
+        meeting_paragraph_gap_secs: patch
+            .meeting_paragraph_gap_secs
+            .unwrap_or(settings.meeting_paragraph_gap_secs),
+        captions_enabled: patch.captions_enabled.unwrap_or(settings.captions_enabled),
+        caption_line_chars: patch
+            .caption_line_chars
+            .unwrap_or(settings.caption_line_chars),
+        predictive_preload_enabled: patch
+            .predictive_preload_enabled
+            .unwrap_or(settings.predictive_preload_enabled),
+        idle_unload_minutes: patch
+            .idle_unload_minutes
+            .unwrap_or(settings.idle_unload_minutes),
+        sidecar_memory_cap_mb: patch
+            .sidecar_memory_cap_mb
+            .unwrap_or(settings.sidecar_memory_cap_mb),
+        sidecar_sandbox_enabled: patch
+            .sidecar_sandbox_enabled
+            .unwrap_or(settings.sidecar_sandbox_enabled),
+        allow_path_sidecars: patch
+            .allow_path_sidecars
+            .unwrap_or(settings.allow_path_sidecars),
+        offline_only: patch.offline_only.unwrap_or(settings.offline_only),
+        event_prefix: patch
+            .event_prefix
+            .unwrap_or_else(|| settings.event_prefix.clone()),
+        noise_suppression_enabled: patch
+            .noise_suppression_enabled
+            .unwrap_or(settings.noise_suppression_enabled),
+        punctuation_restoration: patch
+            .punctuation_restoration
+            .unwrap_or(settings.punctuation_restoration),
+        max_insertion_chars: patch
+            .max_insertion_chars
+            .unwrap_or(settings.max_insertion_chars),
+        long_transcript_behavior: patch
+            .long_transcript_behavior
+            .unwrap_or(settings.long_transcript_behavior),
+        scrubbing: patch
+            .scrubbing
+            .unwrap_or_else(|| settings.scrubbing.clone()),
+        meter_attack_ms: patch.meter_attack_ms.unwrap_or(settings.meter_attack_ms),
+        meter_release_ms: patch.meter_release_ms.unwrap_or(settings.meter_release_ms),
+        meter_peak_hold_ms: patch
+            .meter_peak_hold_ms
+            .unwrap_or(settings.meter_peak_hold_ms),
+        performance_trace: patch
+            .performance_trace
+            .unwrap_or(settings.performance_trace),
+        auto_downgrade_profile: patch
+            .auto_downgrade_profile
+            .unwrap_or(settings.auto_downgrade_profile),
+        spoken_punctuation: patch
+            .spoken_punctuation
+            .unwrap_or(settings.spoken_punctuation),
+        faster_whisper_cache_dir: patch
+            .faster_whisper_cache_dir
+            .unwrap_or_else(|| settings.faster_whisper_cache_dir.clone()),
+        insertion_verification: patch
+            .insertion_verification
+            .unwrap_or(settings.insertion_verification),
+        retry_unverified_insertion: patch
+            .retry_unverified_insertion
+            .unwrap_or(settings.retry_unverified_insertion),
+        panic_hotkey: patch
+            .panic_hotkey
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+            .unwrap_or_else(|| settings.panic_hotkey.clone()),
+        auto_insert: patch.auto_insert.unwrap_or(settings.auto_insert),
+        auto_insert_min_confidence_percent: patch
+            .auto_insert_min_confidence_percent
+            .unwrap_or(settings.auto_insert_min_confidence_percent),
+        microphone_profiles: patch
+            .microphone_profiles
+            .unwrap_or_else(|| settings.microphone_profiles.clone()),
+        preprocessing: patch
+            .preprocessing
+            .unwrap_or_else(|| settings.preprocessing.clone()),
+        shadow_engine: patch.shadow_engine.unwrap_or(settings.shadow_engine),
+        journal_directory: patch
+            .journal_directory
+            .unwrap_or_else(|| settings.journal_directory.clone()),
+        journal_profile: patch.journal_profile.unwrap_or(settings.journal_profile),
+        obs_caption_file: patch
+            .obs_caption_file
+            .unwrap_or_else(|| settings.obs_caption_file.clone()),
+        instant_words: patch.instant_words.unwrap_or(settings.instant_words),
+        profile_toggle_hotkey: patch
+            .profile_toggle_hotkey
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+            .unwrap_or_else(|| settings.profile_toggle_hotkey.clone()),
+        number_locale: patch
+            .number_locale
+            .unwrap_or_else(|| settings.number_locale.clone()),
+        voice_activation: patch.voice_activation.unwrap_or(settings.voice_activation),
+        voice_activation_silence_ms: patch
+            .voice_activation_silence_ms
+            .unwrap_or(settings.voice_activation_silence_ms),
+        utterance_audio: patch.utterance_audio.unwrap_or(settings.utterance_audio),
+        adaptive_cadence: patch.adaptive_cadence.unwrap_or(settings.adaptive_cadence),
+        retention_mode: patch.retention_mode.unwrap_or(settings.retention_mode),
+        retention_days: patch.retention_days.unwrap_or(settings.retention_days),
+        retention_entries: patch
+            .retention_entries
+            .unwrap_or(settings.retention_entries),
+        screen_reader_announcements: patch
+            .screen_reader_announcements
+            .unwrap_or(settings.screen_reader_announcements),
+        gpu_device_index: patch.gpu_device_index.unwrap_or(settings.gpu_device_index),
+        playback_guard: patch.playback_guard.unwrap_or(settings.playback_guard),
+        frame_recv_timeout_ms: patch
+            .frame_recv_timeout_ms
+            .unwrap_or(settings.frame_recv_timeout_ms),
+        meter_refresh_hz: patch.meter_refresh_hz.unwrap_or(settings.meter_refresh_hz),
+        spoken_phrase_overrides: patch
+            .spoken_phrase_overrides
+            .unwrap_or_else(|| settings.spoken_phrase_overrides.clone()),
+        utterance_routing: patch
+            .utterance_routing
+            .unwrap_or(settings.utterance_routing),
+        auto_quantize_models: patch
+            .auto_quantize_models
+            .unwrap_or(settings.auto_quantize_models),
+        thread_priorities: patch
+            .thread_priorities
+            .unwrap_or(settings.thread_priorities),
+        review_toasts: patch.review_toasts.unwrap_or(settings.review_toasts),
+        screen_recording_transcripts: patch
+            .screen_recording_transcripts
+            .unwrap_or(settings.screen_recording_transcripts),
+        duplicate_suppression: patch
+            .duplicate_suppression
+            .unwrap_or(settings.duplicate_suppression),
+        hallucination_guard: patch
+            .hallucination_guard
+            .unwrap_or(settings.hallucination_guard),
+        transcript_viewer: patch
+            .transcript_viewer
+            .unwrap_or(settings.transcript_viewer),
+        cold_start_fast_path: patch
+            .cold_start_fast_path
+            .unwrap_or(settings.cold_start_fast_path),
         clipboard_fallback: patch
             .clipboard_fallback
             .unwrap_or(settings.clipboard_fallback),
@@ -126,6 +371,89 @@ fn normalize_settings(mut settings: AppSettings) -> AppSettings {
     settings.vad_rms_threshold_milli = settings
         .vad_rms_threshold_milli
         .map(|value| value.clamp(1, 80));
+    settings.max_recent_insertions = settings.max_recent_insertions.clamp(1, 50);
+    settings.focus_guard_timeout_ms = settings.focus_guard_timeout_ms.clamp(100, 10_000);
+    settings.drop_folder_poll_secs = settings.drop_folder_poll_secs.clamp(5, 3_600);
+    settings
+        .app_rules
+        .retain(|rule| !rule.app_name.trim().is_empty());
+    settings.meeting_paragraph_gap_secs = settings.meeting_paragraph_gap_secs.clamp(2, 300);
+    settings.caption_line_chars = settings.caption_line_chars.clamp(16, 120);
+    settings.idle_unload_minutes = settings.idle_unload_minutes.min(1_440);
+    if settings.sidecar_memory_cap_mb > 0 {
+        settings.sidecar_memory_cap_mb = settings.sidecar_memory_cap_mb.clamp(512, 65_536);
+    }
+    settings.event_prefix = crate::events::normalize_event_prefix(settings.event_prefix.as_deref());
+    if settings.max_insertion_chars > 0 {
+        settings.max_insertion_chars = settings.max_insertion_chars.clamp(20, 100_000);
+    }
+    let presets = std::mem::take(&mut settings.scrubbing.presets);
+    for preset in presets {
+        if !settings.scrubbing.presets.contains(&preset) {
+            settings.scrubbing.presets.push(preset);
+        }
+    }
+    settings
+        .scrubbing
+        .custom_patterns
+        .retain(|pattern| !pattern.trim().is_empty());
+    settings.meter_attack_ms = settings.meter_attack_ms.min(1_000);
+    settings.meter_release_ms = settings.meter_release_ms.min(2_000);
+    settings.meter_peak_hold_ms = settings.meter_peak_hold_ms.min(3_000);
+    settings.faster_whisper_cache_dir = settings
+        .faster_whisper_cache_dir
+        .as_ref()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty());
+    settings.auto_insert_min_confidence_percent =
+        settings.auto_insert_min_confidence_percent.min(100);
+    settings.microphone_profiles =
+        crate::mic_profile::normalize_profiles(std::mem::take(&mut settings.microphone_profiles));
+    for chain in [
+        &mut settings.preprocessing.fast,
+        &mut settings.preprocessing.balanced,
+    ] {
+        let mut seen = Vec::new();
+        chain.retain(|step| {
+            let first = !seen.contains(&step.stage);
+            seen.push(step.stage);
+            first
+        });
+    }
+    if settings.shadow_engine == Some(settings.stt_engine) {
+        settings.shadow_engine = None;
+    }
+    settings.journal_directory = settings
+        .journal_directory
+        .as_ref()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty());
+    settings.obs_caption_file = settings
+        .obs_caption_file
+        .as_ref()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty());
+    settings.number_locale = settings
+        .number_locale
+        .as_deref()
+        .and_then(number_format::find_locale)
+        .map(|locale| locale.code.to_string());
+    settings.voice_activation_silence_ms = settings.voice_activation_silence_ms.clamp(500, 10_000);
+    settings.retention_days = settings.retention_days.clamp(1, 3_650);
+    settings.retention_entries = settings.retention_entries.max(1);
+    settings.frame_recv_timeout_ms = settings
+        .frame_recv_timeout_ms
+        .clamp(FRAME_RECV_TIMEOUT_MS_MIN, FRAME_RECV_TIMEOUT_MS_MAX);
+    settings.meter_refresh_hz = settings
+        .meter_refresh_hz
+        .clamp(METER_REFRESH_HZ_MIN, METER_REFRESH_HZ_MAX);
+    settings.spoken_phrase_overrides = crate::language_pack::normalize_overrides(std::mem::take(
+        &mut settings.spoken_phrase_overrides,
+    ));
+    settings.utterance_routing.short_max_ms = settings.utterance_routing.short_max_ms.clamp(
+        utterance_routing::SHORT_MAX_MS_MIN,
+        utterance_routing::SHORT_MAX_MS_MAX,
+    );
     settings
 }
 
@@ -136,6 +464,7 @@ fn io_to_string(error: io::Error) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::{CasingMode, PreprocessStage, PreprocessStep, ScrubAction, ScrubPreset};
     use std::time::{SystemTime, UNIX_EPOCH};
 
     fn temp_file(name: &str) -> PathBuf {
@@ -169,6 +498,125 @@ mod tests {
                 parakeet_compute_type: Some(ParakeetComputeType::Float16),
                 vad_disabled: Some(true),
                 vad_rms_threshold_milli: Some(6),
+                chunk_normalization: Some(true),
+                max_recent_insertions: Some(12),
+                focus_guard_enabled: Some(true),
+                focus_guard_timeout_ms: Some(2_000),
+                privacy_mode: Some(true),
+                drop_folder_path: Some(Some("/tmp/drop".to_string())),
+                drop_folder_poll_secs: Some(60),
+                app_rules: Some(vec![AppRule {
+                    app_name: "kitty".to_string(),
+                    casing: CasingMode::Plain,
+                }]),
+                meeting_transcript_enabled: Some(true),
+                meeting_paragraph_gap_secs: Some(15),
+                captions_enabled: Some(true),
+                caption_line_chars: Some(32),
+                predictive_preload_enabled: Some(true),
+                idle_unload_minutes: Some(45),
+                sidecar_memory_cap_mb: Some(3_072),
+                sidecar_sandbox_enabled: Some(true),
+                allow_path_sidecars: Some(true),
+                offline_only: Some(true),
+                event_prefix: Some(Some(" acme/ ".to_string())),
+                noise_suppression_enabled: Some(true),
+                punctuation_restoration: Some(true),
+                max_insertion_chars: Some(5),
+                long_transcript_behavior: Some(LongTranscriptBehavior::Truncate),
+                scrubbing: Some(ScrubbingSettings {
+                    presets: vec![ScrubPreset::Ssn, ScrubPreset::Ssn],
+                    custom_patterns: vec!["  ".to_string(), r"\bACME-\d+\b".to_string()],
+                    action: ScrubAction::Block,
+                }),
+                meter_attack_ms: Some(5_000),
+                meter_release_ms: Some(120),
+                meter_peak_hold_ms: Some(800),
+                performance_trace: Some(true),
+                auto_downgrade_profile: Some(false),
+                spoken_punctuation: Some(true),
+                faster_whisper_cache_dir: Some(Some("/data/hf-cache".to_string())),
+                insertion_verification: Some(InsertionVerification::Canary),
+                retry_unverified_insertion: Some(true),
+                panic_hotkey: Some("Alt+F12".to_string()),
+                auto_insert: Some(true),
+                auto_insert_min_confidence_percent: Some(75),
+                microphone_profiles: Some(vec![MicrophoneProfile {
+                    device_label: " USB Mic ".to_string(),
+                    mic_sensitivity_percent: Some(999),
+                    input_channel: Some(1),
+                    playback_guard: Some(PlaybackGuard::Pause),
+                }]),
+                preprocessing: Some(PreprocessingChains {
+                    fast: vec![
+                        PreprocessStep {
+                            stage: PreprocessStage::Agc,
+                            enabled: true,
+                        },
+                        PreprocessStep {
+                            stage: PreprocessStage::Agc,
+                            enabled: false,
+                        },
+                    ],
+                    ..PreprocessingChains::default()
+                }),
+                shadow_engine: Some(Some(SttEngine::FasterWhisper)),
+                journal_directory: Some(Some(" ~/notes/journal ".to_string())),
+                journal_profile: Some(Some(ModelProfile::Balanced)),
+                obs_caption_file: Some(Some("/tmp/obs-captions.txt".to_string())),
+                instant_words: Some(true),
+                profile_toggle_hotkey: Some(" Alt+F11 ".to_string()),
+                number_locale: Some(Some("de_de".to_string())),
+                voice_activation: Some(true),
+                voice_activation_silence_ms: Some(50),
+                utterance_audio: Some(true),
+                adaptive_cadence: Some(false),
+                retention_mode: Some(RetentionMode::KeepDays),
+                retention_days: Some(0),
+                retention_entries: Some(0),
+                screen_reader_announcements: Some(true),
+                gpu_device_index: Some(Some(1)),
+                playback_guard: Some(PlaybackGuard::Pause),
+                frame_recv_timeout_ms: Some(5),
+                meter_refresh_hz: Some(240),
+                spoken_phrase_overrides: Some(vec![
+                    SpokenPhraseOverride {
+                        language: " FR ".to_string(),
+                        phrase: " Nouveau  Point ".to_string(),
+                        replacement: ".".to_string(),
+                    },
+                    SpokenPhraseOverride {
+                        language: "fr".to_string(),
+                        phrase: " ".to_string(),
+                        replacement: "!".to_string(),
+                    },
+                ]),
+                utterance_routing: Some(UtteranceRouting {
+                    enabled: true,
+                    short_max_ms: 100,
+                    long_engine: Some(SttEngine::FasterWhisper),
+                    long_model_profile: ModelProfile::Balanced,
+                }),
+                auto_quantize_models: Some(true),
+                thread_priorities: Some(ThreadPriorities {
+                    boost_capture: false,
+                    lower_inference: true,
+                }),
+                review_toasts: Some(false),
+                screen_recording_transcripts: Some(true),
+                duplicate_suppression: Some(DuplicateSuppression {
+                    window_ms: 0,
+                    max_suppressed: 2,
+                }),
+                hallucination_guard: Some(HallucinationGuard {
+                    enabled: false,
+                    ..HallucinationGuard::default()
+                }),
+                transcript_viewer: Some(TranscriptViewer {
+                    enabled: true,
+                    ..TranscriptViewer::default()
+                }),
+                cold_start_fast_path: Some(false),
                 clipboard_fallback: Some(false),
                 launch_at_startup: Some(true),
             },
@@ -201,6 +649,110 @@ mod tests {
         assert_eq!(updated.parakeet_compute_type, ParakeetComputeType::Float16);
         assert!(updated.vad_disabled);
         assert_eq!(updated.vad_rms_threshold_milli, Some(6));
+        assert!(updated.chunk_normalization);
+        assert_eq!(updated.max_recent_insertions, 12);
+        assert!(updated.focus_guard_enabled);
+        assert_eq!(updated.focus_guard_timeout_ms, 2_000);
+        assert!(updated.privacy_mode);
+        assert_eq!(updated.drop_folder_path.as_deref(), Some("/tmp/drop"));
+        assert_eq!(updated.drop_folder_poll_secs, 60);
+        assert_eq!(updated.app_rules[0].casing, CasingMode::Plain);
+        assert!(updated.meeting_transcript_enabled);
+        assert_eq!(updated.meeting_paragraph_gap_secs, 15);
+        assert!(updated.captions_enabled);
+        assert_eq!(updated.caption_line_chars, 32);
+        assert!(updated.predictive_preload_enabled);
+        assert_eq!(updated.idle_unload_minutes, 45);
+        assert_eq!(updated.sidecar_memory_cap_mb, 3_072);
+        assert!(updated.sidecar_sandbox_enabled);
+        assert!(updated.allow_path_sidecars);
+        assert!(updated.offline_only);
+        assert_eq!(updated.event_prefix.as_deref(), Some("acme/"));
+        assert!(updated.noise_suppression_enabled);
+        assert!(updated.punctuation_restoration);
+        assert_eq!(updated.max_insertion_chars, 20);
+        assert_eq!(
+            updated.long_transcript_behavior,
+            LongTranscriptBehavior::Truncate
+        );
+        assert_eq!(updated.scrubbing.presets, vec![ScrubPreset::Ssn]);
+        assert_eq!(updated.scrubbing.custom_patterns, vec![r"\bACME-\d+\b"]);
+        assert_eq!(updated.meter_attack_ms, 1_000);
+        assert_eq!(updated.meter_release_ms, 120);
+        assert_eq!(updated.meter_peak_hold_ms, 800);
+        assert!(updated.performance_trace);
+        assert!(!updated.auto_downgrade_profile);
+        assert!(updated.spoken_punctuation);
+        assert_eq!(
+            updated.faster_whisper_cache_dir.as_deref(),
+            Some("/data/hf-cache")
+        );
+        assert_eq!(
+            updated.insertion_verification,
+            InsertionVerification::Canary
+        );
+        assert!(updated.retry_unverified_insertion);
+        assert_eq!(updated.panic_hotkey, "Alt+F12");
+        assert!(updated.auto_insert);
+        assert_eq!(updated.auto_insert_min_confidence_percent, 75);
+        assert_eq!(updated.microphone_profiles[0].device_label, "USB Mic");
+        assert_eq!(
+            updated.microphone_profiles[0].mic_sensitivity_percent,
+            Some(300)
+        );
+        assert_eq!(
+            updated.preprocessing.fast,
+            vec![PreprocessStep {
+                stage: PreprocessStage::Agc,
+                enabled: true,
+            }]
+        );
+        assert_eq!(updated.shadow_engine, Some(SttEngine::FasterWhisper));
+        assert_eq!(
+            updated.journal_directory.as_deref(),
+            Some("~/notes/journal")
+        );
+        assert_eq!(updated.journal_profile, Some(ModelProfile::Balanced));
+        assert_eq!(
+            updated.obs_caption_file.as_deref(),
+            Some("/tmp/obs-captions.txt")
+        );
+        assert!(updated.instant_words);
+        assert_eq!(updated.profile_toggle_hotkey, "Alt+F11");
+        assert_eq!(updated.number_locale.as_deref(), Some("de-DE"));
+        assert!(updated.voice_activation);
+        assert_eq!(updated.voice_activation_silence_ms, 500);
+        assert!(updated.utterance_audio);
+        assert!(!updated.adaptive_cadence);
+        assert_eq!(updated.retention_mode, RetentionMode::KeepDays);
+        assert_eq!(updated.retention_days, 1);
+        assert_eq!(updated.retention_entries, 1);
+        assert!(updated.screen_reader_announcements);
+        assert_eq!(updated.gpu_device_index, Some(1));
+        assert_eq!(updated.playback_guard, PlaybackGuard::Pause);
+        assert_eq!(updated.frame_recv_timeout_ms, 10);
+        assert_eq!(updated.meter_refresh_hz, 120);
+        assert_eq!(
+            updated.spoken_phrase_overrides,
+            vec![SpokenPhraseOverride {
+                language: "fr".to_string(),
+                phrase: "nouveau point".to_string(),
+                replacement: ".".to_string(),
+            }]
+        );
+        assert!(updated.utterance_routing.enabled);
+        assert_eq!(
+            updated.utterance_routing.short_max_ms,
+            utterance_routing::SHORT_MAX_MS_MIN
+        );
+        assert!(updated.auto_quantize_models);
+        assert!(!updated.thread_priorities.boost_capture);
+        assert!(!updated.review_toasts);
+        assert!(updated.screen_recording_transcripts);
+        assert_eq!(updated.duplicate_suppression.max_suppressed, 2);
+        assert!(!updated.hallucination_guard.enabled);
+        assert!(updated.transcript_viewer.enabled);
+        assert!(!updated.cold_start_fast_path);
         assert!(!updated.clipboard_fallback);
         assert!(updated.launch_at_startup);
     }
@@ -274,6 +826,76 @@ mod tests {
             parakeet_compute_type: ParakeetComputeType::Auto,
             vad_disabled: false,
             vad_rms_threshold_milli: Some(9),
+            chunk_normalization: true,
+            max_recent_insertions: 5,
+            focus_guard_enabled: true,
+            focus_guard_timeout_ms: 800,
+            privacy_mode: false,
+            drop_folder_path: Some("/tmp/drop".to_string()),
+            drop_folder_poll_secs: 45,
+            app_rules: vec![AppRule {
+                app_name: "weechat".to_string(),
+                casing: CasingMode::Lowercase,
+            }],
+            meeting_transcript_enabled: true,
+            meeting_paragraph_gap_secs: 12,
+            captions_enabled: true,
+            caption_line_chars: 37,
+            predictive_preload_enabled: true,
+            idle_unload_minutes: 20,
+            sidecar_memory_cap_mb: 4_096,
+            sidecar_sandbox_enabled: true,
+            allow_path_sidecars: true,
+            offline_only: true,
+            event_prefix: Some("acme/".to_string()),
+            noise_suppression_enabled: true,
+            punctuation_restoration: true,
+            max_insertion_chars: 2_000,
+            long_transcript_behavior: LongTranscriptBehavior::Split,
+            scrubbing: ScrubbingSettings::default(),
+            meter_attack_ms: 0,
+            meter_release_ms: 60,
+            meter_peak_hold_ms: 0,
+            performance_trace: true,
+            auto_downgrade_profile: true,
+            spoken_punctuation: false,
+            faster_whisper_cache_dir: None,
+            insertion_verification: InsertionVerification::Off,
+            retry_unverified_insertion: false,
+            panic_hotkey: "CtrlOrCmd+Shift+Escape".to_string(),
+            auto_insert: false,
+            auto_insert_min_confidence_percent: 60,
+            microphone_profiles: Vec::new(),
+            preprocessing: PreprocessingChains::default(),
+            shadow_engine: None,
+            journal_directory: None,
+            journal_profile: None,
+            obs_caption_file: None,
+            instant_words: false,
+            profile_toggle_hotkey: "CtrlOrCmd+Shift+P".to_string(),
+            number_locale: None,
+            voice_activation: false,
+            voice_activation_silence_ms: 1_500,
+            utterance_audio: false,
+            adaptive_cadence: true,
+            retention_mode: RetentionMode::KeepAll,
+            retention_days: 30,
+            retention_entries: 200,
+            screen_reader_announcements: false,
+            gpu_device_index: Some(0),
+            playback_guard: PlaybackGuard::RaiseVad,
+            frame_recv_timeout_ms: 120,
+            meter_refresh_hz: 60,
+            spoken_phrase_overrides: Vec::new(),
+            utterance_routing: UtteranceRouting::default(),
+            auto_quantize_models: false,
+            thread_priorities: ThreadPriorities::default(),
+            review_toasts: true,
+            screen_recording_transcripts: false,
+            duplicate_suppression: DuplicateSuppression::default(),
+            hallucination_guard: HallucinationGuard::default(),
+            transcript_viewer: TranscriptViewer::default(),
+            cold_start_fast_path: true,
             clipboard_fallback: true,
             launch_at_startup: false,
         };
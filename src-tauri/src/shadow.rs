@@ -0,0 +1,152 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::Receiver;
+use std::time::Instant;
+
+use crate::onboarding::word_errors;
+use crate::perf_trace;
+use crate::transcriber::Transcriber;
+
+// Small on purpose: when the shadow engine falls behind, chunks are skipped
+// rather than queued so the primary path never waits on it.
+pub const SHADOW_QUEUE_CHUNKS: usize = 2;
+pub const MAX_SHADOW_LOG_BYTES: u64 = 2 * 1024 * 1024;
+
+pub struct ShadowJob {
+    pub chunk_id: u64,
+    pub samples: Vec<f32>,
+    pub primary_engine: String,
+    pub primary_model: String,
+    pub primary_ms: u64,
+    pub primary_text: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ShadowComparison {
+    pub chunk_id: u64,
+    pub primary_engine: String,
+    pub primary_model: String,
+    pub primary_ms: u64,
+    #[serde(default)]
+    pub primary_text: Option<String>,
+    pub shadow_engine: String,
+    pub shadow_model: String,
+    pub shadow_ms: u64,
+    #[serde(default)]
+    pub shadow_text: Option<String>,
+    #[serde(default)]
+    pub shadow_error: Option<String>,
+    // Word error rate of the shadow transcript measured against the primary one.
+    #[serde(default)]
+    pub divergence: Option<f32>,
+}
+
+pub fn default_shadow_log_path() -> PathBuf {
+    let base = dirs::config_dir().unwrap_or_else(|| PathBuf::from("."));
+    base.join("sonora-dictation").join("shadow-compare.jsonl")
+}
+
+pub fn compare<T: Transcriber>(transcriber: &T, job: ShadowJob) -> ShadowComparison {
+    let started_at = Instant::now();
+    let outcome = transcriber.transcribe(&job.samples);
+    let shadow_ms = started_at.elapsed().as_millis() as u64;
+    let (shadow_text, shadow_error) = match outcome {
+        Ok(text) => (Some(text.trim().to_string()), None),
+        Err(error) => (None, Some(error)),
+    };
+    let divergence = match (&job.primary_text, &shadow_text) {
+        (Some(primary), Some(shadow)) => Some(word_errors(primary, shadow).wer),
+        _ => None,
+    };
+
+    ShadowComparison {
+        chunk_id: job.chunk_id,
+        primary_engine: job.primary_engine,
+        primary_model: job.primary_model,
+        primary_ms: job.primary_ms,
+        primary_text: job.primary_text,
+        shadow_engine: transcriber.engine_label().to_string(),
+        shadow_model: transcriber.model_label(),
+        shadow_ms,
+        shadow_text,
+        shadow_error,
+        divergence,
+    }
+}
+
+// Runs until every sender is dropped, then unloads the shadow engine.
+pub fn run_shadow_worker<T: Transcriber>(
+    transcriber: &T,
+    log_path: &Path,
+    jobs: Receiver<ShadowJob>,
+) -> Result<(), String> {
+    let result = transcriber.prepare().and_then(|_| {
+        jobs.into_iter().try_for_each(|job| {
+            perf_trace::append(log_path, &compare(transcriber, job), MAX_SHADOW_LOG_BYTES)
+        })
+    });
+    transcriber.unload();
+    result
+}
+
+pub fn read_recent(log_path: &Path, limit: usize) -> Vec<ShadowComparison> {
+    let Ok(raw) = fs::read_to_string(log_path) else {
+        return Vec::new();
+    };
+    let mut comparisons = raw
+        .lines()
+        .rev()
+        .filter_map(|line| serde_json::from_str::<ShadowComparison>(line).ok())
+        .take(limit)
+        .collect::<Vec<_>>();
+    comparisons.reverse();
+    comparisons
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transcriber::StubTranscriber;
+    use std::sync::mpsc;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    fn job(chunk_id: u64, primary_text: &str) -> ShadowJob {
+        ShadowJob {
+            chunk_id,
+            samples: vec![0.1; 1_600],
+            primary_engine: "whisper_cpp".to_string(),
+            primary_model: "ggml-base.en".to_string(),
+            primary_ms: 120,
+            primary_text: Some(primary_text.to_string()),
+        }
+    }
+
+    #[test]
+    fn logs_both_results_until_capture_stops() {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("clock should be set")
+            .as_nanos();
+        let log_path = std::env::temp_dir()
+            .join(format!("sonora-shadow-{nanos}"))
+            .join("shadow-compare.jsonl");
+
+        let (tx, rx) = mpsc::sync_channel(SHADOW_QUEUE_CHUNKS);
+        tx.send(job(1, "phase-1 transcript"))
+            .expect("job should queue");
+        tx.send(job(2, "Phase-1 transcription."))
+            .expect("job should queue");
+        drop(tx);
+        run_shadow_worker(&StubTranscriber, &log_path, rx).expect("worker should finish");
+
+        let comparisons = read_recent(&log_path, 10);
+        let _ = fs::remove_dir_all(log_path.parent().expect("log dir"));
+        assert_eq!(comparisons.len(), 2);
+        assert_eq!(comparisons[0].shadow_engine, "stub");
+        assert_eq!(comparisons[0].primary_engine, "whisper_cpp");
+        assert_eq!(comparisons[0].divergence, Some(0.0));
+        assert_eq!(comparisons[1].divergence, Some(0.5));
+        assert_eq!(read_recent(&log_path, 10), Vec::new());
+    }
+}
@@ -0,0 +1,320 @@
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum SimdLevel {
+    #[default]
+    Baseline,
+    Neon,
+    #[serde(alias = "sse4.1")]
+    Sse41,
+    Avx,
+    Avx2,
+    Avx512,
+}
+
+impl SimdLevel {
+    fn is_x86(self) -> bool {
+        matches!(self, Self::Sse41 | Self::Avx | Self::Avx2 | Self::Avx512)
+    }
+
+    pub fn supports(self, required: SimdLevel) -> bool {
+        match required {
+            Self::Baseline => true,
+            Self::Neon => self == Self::Neon,
+            _ => self.is_x86() && self >= required,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum VariantBackend {
+    Cpu,
+    Cuda,
+}
+
+// One bundled build of a sidecar. `binary` is relative to the metadata file.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SidecarVariant {
+    pub binary: String,
+    #[serde(default = "default_backends")]
+    pub backends: Vec<VariantBackend>,
+    #[serde(default)]
+    pub simd: SimdLevel,
+    #[serde(default)]
+    pub min_driver_version: Option<String>,
+}
+
+fn default_backends() -> Vec<VariantBackend> {
+    vec![VariantBackend::Cpu]
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SidecarMetadata {
+    // Legacy single-binary hint, still honoured when no variants are declared.
+    #[serde(default)]
+    pub backend: Option<String>,
+    #[serde(default)]
+    pub backends: Vec<VariantBackend>,
+    #[serde(default)]
+    pub simd: Option<SimdLevel>,
+    #[serde(default)]
+    pub min_driver_version: Option<String>,
+    #[serde(default)]
+    pub variants: Vec<SidecarVariant>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, PartialEq, Eq)]
+pub struct HostCapabilities {
+    pub simd: SimdLevel,
+    // None when no NVIDIA driver answered.
+    pub nvidia_driver_version: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct VariantChoice {
+    pub binary_path: PathBuf,
+    pub backend: VariantBackend,
+    pub simd: SimdLevel,
+}
+
+pub fn load_metadata(path: &Path) -> Option<SidecarMetadata> {
+    let raw = fs::read_to_string(path).ok()?;
+    serde_json::from_str::<SidecarMetadata>(&raw).ok()
+}
+
+pub fn detect_host() -> HostCapabilities {
+    HostCapabilities {
+        simd: detect_simd(),
+        nvidia_driver_version: detect_nvidia_driver_version(),
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+fn detect_simd() -> SimdLevel {
+    if std::arch::is_x86_feature_detected!("avx512f") {
+        SimdLevel::Avx512
+    } else if std::arch::is_x86_feature_detected!("avx2") {
+        SimdLevel::Avx2
+    } else if std::arch::is_x86_feature_detected!("avx") {
+        SimdLevel::Avx
+    } else if std::arch::is_x86_feature_detected!("sse4.1") {
+        SimdLevel::Sse41
+    } else {
+        SimdLevel::Baseline
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+fn detect_simd() -> SimdLevel {
+    SimdLevel::Neon
+}
+
+#[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+fn detect_simd() -> SimdLevel {
+    SimdLevel::Baseline
+}
+
+fn detect_nvidia_driver_version() -> Option<String> {
+    let output = Command::new("nvidia-smi")
+        .args(["--query-gpu=driver_version", "--format=csv,noheader"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .map(str::to_string)
+}
+
+pub fn compare_versions(left: &str, right: &str) -> Ordering {
+    let parse = |value: &str| {
+        value
+            .trim()
+            .split('.')
+            .map(|part| part.trim().parse::<u32>().unwrap_or(0))
+            .collect::<Vec<_>>()
+    };
+    let (left, right) = (parse(left), parse(right));
+    let len = left.len().max(right.len());
+    (0..len)
+        .map(|index| {
+            let a = left.get(index).copied().unwrap_or(0);
+            let b = right.get(index).copied().unwrap_or(0);
+            a.cmp(&b)
+        })
+        .find(|ordering| ordering.is_ne())
+        .unwrap_or(Ordering::Equal)
+}
+
+fn cuda_usable(variant: &SidecarVariant, host: &HostCapabilities) -> bool {
+    let Some(driver) = host.nvidia_driver_version.as_deref() else {
+        return false;
+    };
+    variant.backends.contains(&VariantBackend::Cuda)
+        && variant
+            .min_driver_version
+            .as_deref()
+            .is_none_or(|minimum| compare_versions(driver, minimum).is_ge())
+}
+
+// Picks the best variant the host can run: CUDA first unless `allow_cuda` is
+// off, then the highest SIMD level. `require_cuda` drops CPU-only matches.
+pub fn pick_variant<'a>(
+    variants: &'a [SidecarVariant],
+    host: &HostCapabilities,
+    allow_cuda: bool,
+    require_cuda: bool,
+) -> Option<(&'a SidecarVariant, VariantBackend)> {
+    variants
+        .iter()
+        .filter(|variant| host.simd.supports(variant.simd))
+        .filter_map(|variant| {
+            if allow_cuda && cuda_usable(variant, host) {
+                Some((variant, VariantBackend::Cuda))
+            } else if !require_cuda && variant.backends.contains(&VariantBackend::Cpu) {
+                Some((variant, VariantBackend::Cpu))
+            } else {
+                None
+            }
+        })
+        .max_by_key(|(variant, backend)| (*backend == VariantBackend::Cuda, variant.simd))
+}
+
+// Resolves a variant next to `metadata_path`, skipping entries whose binary is missing.
+pub fn choose_variant(
+    metadata_path: &Path,
+    metadata: &SidecarMetadata,
+    host: &HostCapabilities,
+    allow_cuda: bool,
+    require_cuda: bool,
+) -> Option<VariantChoice> {
+    let base = metadata_path.parent()?;
+    let present = metadata
+        .variants
+        .iter()
+        .filter(|variant| base.join(&variant.binary).exists())
+        .cloned()
+        .collect::<Vec<_>>();
+    let (variant, backend) = pick_variant(&present, host, allow_cuda, require_cuda)?;
+    Some(VariantChoice {
+        binary_path: base.join(&variant.binary),
+        backend,
+        simd: variant.simd,
+    })
+}
+
+// Whether the single binary described at the top level can run here at all.
+pub fn top_level_backend(
+    metadata: &SidecarMetadata,
+    host: &HostCapabilities,
+) -> Option<VariantBackend> {
+    if metadata.backends.is_empty() {
+        return None;
+    }
+    let variant = SidecarVariant {
+        binary: String::new(),
+        backends: metadata.backends.clone(),
+        simd: metadata.simd.unwrap_or_default(),
+        min_driver_version: metadata.min_driver_version.clone(),
+    };
+    pick_variant(std::slice::from_ref(&variant), host, true, false).map(|(_, backend)| backend)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn variant(
+        binary: &str,
+        backends: &[VariantBackend],
+        simd: SimdLevel,
+        driver: Option<&str>,
+    ) -> SidecarVariant {
+        SidecarVariant {
+            binary: binary.to_string(),
+            backends: backends.to_vec(),
+            simd,
+            min_driver_version: driver.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn prefers_cuda_then_highest_supported_simd() {
+        let variants = vec![
+            variant(
+                "cpu/whisper-cli",
+                &[VariantBackend::Cpu],
+                SimdLevel::Sse41,
+                None,
+            ),
+            variant(
+                "avx2/whisper-cli",
+                &[VariantBackend::Cpu],
+                SimdLevel::Avx2,
+                None,
+            ),
+            variant(
+                "avx512/whisper-cli",
+                &[VariantBackend::Cpu],
+                SimdLevel::Avx512,
+                None,
+            ),
+            variant(
+                "cuda/whisper-cli",
+                &[VariantBackend::Cuda, VariantBackend::Cpu],
+                SimdLevel::Avx2,
+                Some("535.54"),
+            ),
+        ];
+        let avx2_gpu = HostCapabilities {
+            simd: SimdLevel::Avx2,
+            nvidia_driver_version: Some("550.1".to_string()),
+        };
+        let (picked, backend) =
+            pick_variant(&variants, &avx2_gpu, true, false).expect("a variant should match");
+        assert_eq!(picked.binary, "cuda/whisper-cli");
+        assert_eq!(backend, VariantBackend::Cuda);
+
+        let old_driver = HostCapabilities {
+            nvidia_driver_version: Some("470.182".to_string()),
+            ..avx2_gpu.clone()
+        };
+        let (picked, backend) =
+            pick_variant(&variants, &old_driver, true, false).expect("a variant should match");
+        assert_eq!(backend, VariantBackend::Cpu);
+        assert_eq!(picked.simd, SimdLevel::Avx2);
+        assert!(pick_variant(&variants, &old_driver, true, true).is_none());
+
+        let (picked, _) =
+            pick_variant(&variants, &avx2_gpu, false, false).expect("a variant should match");
+        assert_ne!(picked.binary, "avx512/whisper-cli");
+    }
+
+    #[test]
+    fn parses_extended_schema_and_keeps_legacy_hint() {
+        let raw = r#"{
+            "backend": "cuda",
+            "sha256": "abc",
+            "variants": [
+                {"binary": "neon/whisper-cli", "simd": "neon"},
+                {"binary": "avx/whisper-cli", "backends": ["cpu"], "simd": "sse4.1"}
+            ]
+        }"#;
+        let metadata = serde_json::from_str::<SidecarMetadata>(raw).expect("metadata should parse");
+        assert_eq!(metadata.backend.as_deref(), Some("cuda"));
+        assert_eq!(metadata.variants[0].backends, vec![VariantBackend::Cpu]);
+        assert_eq!(metadata.variants[1].simd, SimdLevel::Sse41);
+        assert!(!SimdLevel::Avx2.supports(SimdLevel::Neon));
+        assert!(SimdLevel::Neon.supports(SimdLevel::Baseline));
+        assert_eq!(compare_versions("535.54.03", "535.54"), Ordering::Greater);
+    }
+}
@@ -0,0 +1,178 @@
+use crate::profile::{
+    ProfileTuning, CHUNK_DURATION_MS_MAX, CHUNK_DURATION_MS_MIN, PARTIAL_CADENCE_MS_MAX,
+    PARTIAL_CADENCE_MS_MIN,
+};
+use crate::vad::SpeechSegment;
+use std::collections::VecDeque;
+
+const SAMPLE_RATE_HZ: u64 = 16_000;
+// Roughly the last 10-15 seconds of dictation at the default chunk sizes.
+const RECENT_WINDOWS: usize = 6;
+const CONTINUOUS_SPEECH_RATIO_PCT: u64 = 75;
+const CONTINUOUS_SEGMENT_MS: u64 = 1_200;
+const BURSTY_SPEECH_RATIO_PCT: u64 = 45;
+const BURSTY_SEGMENT_MS: u64 = 600;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CadenceAdjustment {
+    pub chunk_pct: u64,
+    pub cadence_pct: u64,
+}
+
+impl CadenceAdjustment {
+    pub const NEUTRAL: Self = Self {
+        chunk_pct: 100,
+        cadence_pct: 100,
+    };
+    // Long chunks give whisper whole phrases instead of cutting mid-word.
+    pub const CONTINUOUS: Self = Self {
+        chunk_pct: 150,
+        cadence_pct: 130,
+    };
+    // Short replies finish sooner when the next chunk is cut early.
+    pub const BURSTY: Self = Self {
+        chunk_pct: 70,
+        cadence_pct: 70,
+    };
+
+    // Scales the tuning, staying inside the same bounds the settings are clamped to.
+    pub fn apply(self, tuning: &ProfileTuning) -> ProfileTuning {
+        let min_chunk_samples = SAMPLE_RATE_HZ * u64::from(CHUNK_DURATION_MS_MIN) / 1_000;
+        let max_chunk_samples = SAMPLE_RATE_HZ * u64::from(CHUNK_DURATION_MS_MAX) / 1_000;
+        let chunk_samples = (tuning.min_chunk_samples as u64 * self.chunk_pct / 100)
+            .clamp(min_chunk_samples, max_chunk_samples);
+        let cadence_ms = (tuning.partial_cadence_ms * self.cadence_pct / 100).clamp(
+            u64::from(PARTIAL_CADENCE_MS_MIN),
+            u64::from(PARTIAL_CADENCE_MS_MAX),
+        );
+        ProfileTuning {
+            min_chunk_samples: chunk_samples as usize,
+            partial_cadence_ms: cadence_ms,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct WindowStats {
+    samples: u64,
+    speech_samples: u64,
+    segments: u64,
+}
+
+// Rolling VAD statistics over the most recent chunk windows.
+#[derive(Debug, Clone, Default)]
+pub struct SpeechRateTracker {
+    windows: VecDeque<WindowStats>,
+}
+
+impl SpeechRateTracker {
+    pub fn observe(&mut self, segments: &[SpeechSegment], window_samples: usize) {
+        if window_samples == 0 {
+            return;
+        }
+        self.windows.push_back(WindowStats {
+            samples: window_samples as u64,
+            speech_samples: segments.iter().map(|segment| segment.len() as u64).sum(),
+            segments: segments.len() as u64,
+        });
+        while self.windows.len() > RECENT_WINDOWS {
+            let _ = self.windows.pop_front();
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.windows.clear();
+    }
+
+    pub fn adjustment(&self) -> CadenceAdjustment {
+        let samples = self
+            .windows
+            .iter()
+            .map(|window| window.samples)
+            .sum::<u64>();
+        let speech_samples = self
+            .windows
+            .iter()
+            .map(|window| window.speech_samples)
+            .sum::<u64>();
+        let segments = self
+            .windows
+            .iter()
+            .map(|window| window.segments)
+            .sum::<u64>();
+        // A couple of windows are not enough to tell a talker from a pause.
+        if self.windows.len() < 2 || samples == 0 {
+            return CadenceAdjustment::NEUTRAL;
+        }
+        if segments == 0 {
+            return CadenceAdjustment::BURSTY;
+        }
+
+        let speech_ratio_pct = speech_samples * 100 / samples;
+        let mean_segment_ms = speech_samples * 1_000 / SAMPLE_RATE_HZ / segments;
+        if speech_ratio_pct >= CONTINUOUS_SPEECH_RATIO_PCT
+            && mean_segment_ms >= CONTINUOUS_SEGMENT_MS
+        {
+            CadenceAdjustment::CONTINUOUS
+        } else if speech_ratio_pct <= BURSTY_SPEECH_RATIO_PCT
+            || mean_segment_ms <= BURSTY_SEGMENT_MS
+        {
+            CadenceAdjustment::BURSTY
+        } else {
+            CadenceAdjustment::NEUTRAL
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(start_ms: u64, end_ms: u64) -> SpeechSegment {
+        SpeechSegment {
+            start_sample: (start_ms * SAMPLE_RATE_HZ / 1_000) as usize,
+            end_sample: (end_ms * SAMPLE_RATE_HZ / 1_000) as usize,
+        }
+    }
+
+    #[test]
+    fn classifies_continuous_and_bursty_speech() {
+        let mut tracker = SpeechRateTracker::default();
+        tracker.observe(&[segment(0, 2_000)], 32_000);
+        assert_eq!(tracker.adjustment(), CadenceAdjustment::NEUTRAL);
+        tracker.observe(&[segment(0, 1_900)], 32_000);
+        assert_eq!(tracker.adjustment(), CadenceAdjustment::CONTINUOUS);
+
+        tracker.reset();
+        for _ in 0..RECENT_WINDOWS {
+            tracker.observe(&[segment(100, 400), segment(900, 1_200)], 32_000);
+        }
+        assert_eq!(tracker.adjustment(), CadenceAdjustment::BURSTY);
+
+        // Old windows roll off once the speaker starts talking steadily.
+        for _ in 0..RECENT_WINDOWS {
+            tracker.observe(&[segment(0, 2_000)], 32_000);
+        }
+        assert_eq!(tracker.adjustment(), CadenceAdjustment::CONTINUOUS);
+    }
+
+    #[test]
+    fn adjusted_tuning_stays_within_setting_bounds() {
+        let long = ProfileTuning {
+            min_chunk_samples: 56_000,
+            partial_cadence_ms: 2_200,
+        };
+        let stretched = CadenceAdjustment::CONTINUOUS.apply(&long);
+        assert_eq!(stretched.min_chunk_samples, 64_000);
+        assert_eq!(stretched.partial_cadence_ms, 2_500);
+
+        let short = ProfileTuning {
+            min_chunk_samples: 8_000,
+            partial_cadence_ms: 400,
+        };
+        let shortened = CadenceAdjustment::BURSTY.apply(&short);
+        assert_eq!(shortened.min_chunk_samples, 8_000);
+        assert_eq!(shortened.partial_cadence_ms, 300);
+        assert_eq!(CadenceAdjustment::NEUTRAL.apply(&long), long);
+    }
+}
@@ -0,0 +1,111 @@
+use serde::Serialize;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{LockResult, Mutex, MutexGuard, OnceLock};
+
+static INCIDENTS: AtomicU64 = AtomicU64::new(0);
+static REPORTER: OnceLock<Box<dyn Fn(PoisonIncident) + Send + Sync>> = OnceLock::new();
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct PoisonIncident {
+    // Type of the guarded value; state names are not known at the lock site.
+    pub state: &'static str,
+    // True when the value was rebuilt rather than kept as the panic left it.
+    pub reset: bool,
+    // Recoveries since launch, this one included.
+    pub incidents: u64,
+}
+
+// Installed once at startup; recoveries before that only bump the counter.
+pub fn set_poison_reporter(reporter: impl Fn(PoisonIncident) + Send + Sync + 'static) {
+    let _ = REPORTER.set(Box::new(reporter));
+}
+
+pub fn incident_count() -> u64 {
+    INCIDENTS.load(Ordering::Relaxed)
+}
+
+// A Mutex for app state that a panicking worker cannot take down for good.
+// `lock` keeps the std signature so callers read the same, but never returns
+// the poison error: the flag is cleared, the incident reported and the guard
+// handed back.
+pub struct StateMutex<T> {
+    inner: Mutex<T>,
+    // Rebuilds values a half-finished update would leave inconsistent.
+    reset: Option<fn() -> T>,
+}
+
+impl<T> StateMutex<T> {
+    pub const fn new(value: T) -> Self {
+        Self {
+            inner: Mutex::new(value),
+            reset: None,
+        }
+    }
+
+    pub const fn with_reset(value: T, reset: fn() -> T) -> Self {
+        Self {
+            inner: Mutex::new(value),
+            reset: Some(reset),
+        }
+    }
+
+    pub fn lock(&self) -> LockResult<MutexGuard<'_, T>> {
+        let poisoned = match self.inner.lock() {
+            Ok(guard) => return Ok(guard),
+            Err(poisoned) => poisoned,
+        };
+        let mut guard = poisoned.into_inner();
+        if let Some(reset) = self.reset {
+            *guard = reset();
+        }
+        // Reported with the lock released so the reporter may take it too.
+        drop(guard);
+        self.inner.clear_poison();
+        let incident = PoisonIncident {
+            state: std::any::type_name::<T>(),
+            reset: self.reset.is_some(),
+            incidents: INCIDENTS.fetch_add(1, Ordering::Relaxed) + 1,
+        };
+        if let Some(reporter) = REPORTER.get() {
+            reporter(incident);
+        }
+        Ok(self
+            .inner
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()))
+    }
+}
+
+impl<T: Default> Default for StateMutex<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::panic::{self, AssertUnwindSafe};
+
+    fn poison<T>(mutex: &StateMutex<T>) {
+        let _ = panic::catch_unwind(AssertUnwindSafe(|| {
+            let _guard = mutex.inner.lock();
+            panic!("worker panicked while holding state");
+        }));
+        assert!(mutex.inner.is_poisoned());
+    }
+
+    #[test]
+    fn poisoned_state_stays_usable() {
+        let kept = StateMutex::new(vec![1, 2]);
+        poison(&kept);
+        let before = incident_count();
+        assert_eq!(*kept.lock().expect("recovered"), vec![1, 2]);
+        assert!(!kept.inner.is_poisoned());
+        assert!(incident_count() > before);
+
+        let rebuilt = StateMutex::with_reset(Some("session".to_string()), || None);
+        poison(&rebuilt);
+        assert_eq!(*rebuilt.lock().expect("recovered"), None);
+    }
+}
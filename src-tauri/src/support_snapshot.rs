@@ -0,0 +1,251 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::config::AppSettings;
+use crate::profile::{resolve_model_candidates, resolve_model_path_with};
+use crate::resolution::{LiveProbe, ReplayProbe, ResolutionProbe};
+use crate::transcriber::{
+    pick_sidecar_binary, sidecar_binary_candidates, SidecarBinary, RESOLUTION_ENV_NAMES,
+};
+
+pub const SUPPORT_SNAPSHOT_SCHEMA_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BinaryDecision {
+    pub env_name: String,
+    pub candidates: Vec<String>,
+    pub resolved: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ResolutionDecisions {
+    pub model_candidates: Vec<String>,
+    pub model_path: String,
+    pub binaries: Vec<BinaryDecision>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SupportSnapshot {
+    pub schema_version: u32,
+    pub created_unix_ms: u64,
+    pub os: String,
+    pub arch: String,
+    pub app_version: String,
+    pub resource_dir: Option<String>,
+    pub settings: AppSettings,
+    pub probe: ReplayProbe,
+    pub decisions: ResolutionDecisions,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct ReplayReport {
+    pub decisions: ResolutionDecisions,
+    pub mismatches: Vec<String>,
+}
+
+pub fn default_snapshot_path(created_unix_ms: u64) -> PathBuf {
+    let base = dirs::config_dir().unwrap_or_else(|| PathBuf::from("."));
+    base.join("sonora-dictation")
+        .join(format!("support-snapshot-{created_unix_ms}.json"))
+}
+
+fn path_strings(paths: &[PathBuf]) -> Vec<String> {
+    paths
+        .iter()
+        .map(|path| path.to_string_lossy().to_string())
+        .collect()
+}
+
+pub fn resolve_decisions(
+    settings: &AppSettings,
+    resource_dir: Option<&Path>,
+    probe: &dyn ResolutionProbe,
+) -> ResolutionDecisions {
+    let binaries = SidecarBinary::ALL
+        .iter()
+        .map(|binary| {
+            let candidates = sidecar_binary_candidates(*binary, resource_dir, probe);
+            BinaryDecision {
+                env_name: binary.env_name().to_string(),
+                resolved: pick_sidecar_binary(&candidates, probe)
+                    .map(|path| path.to_string_lossy().to_string()),
+                candidates: path_strings(&candidates),
+            }
+        })
+        .collect();
+
+    ResolutionDecisions {
+        model_candidates: path_strings(&resolve_model_candidates(settings, resource_dir)),
+        model_path: resolve_model_path_with(settings, resource_dir, probe)
+            .to_string_lossy()
+            .to_string(),
+        binaries,
+    }
+}
+
+// Records every env override and candidate path the resolvers look at, so
+// replaying against the recorded probe reaches the same decisions.
+pub fn capture_with(
+    settings: &AppSettings,
+    resource_dir: Option<&Path>,
+    app_version: &str,
+    created_unix_ms: u64,
+    probe: &dyn ResolutionProbe,
+) -> SupportSnapshot {
+    let mut recorded = ReplayProbe::default();
+    for name in RESOLUTION_ENV_NAMES {
+        recorded.record_env(probe, name);
+    }
+
+    let decisions = resolve_decisions(settings, resource_dir, probe);
+    let checked_paths = decisions.model_candidates.iter().chain(
+        decisions
+            .binaries
+            .iter()
+            .flat_map(|binary| binary.candidates.iter()),
+    );
+    for path in checked_paths {
+        recorded.record_path(probe, Path::new(path));
+    }
+
+    SupportSnapshot {
+        schema_version: SUPPORT_SNAPSHOT_SCHEMA_VERSION,
+        created_unix_ms,
+        os: std::env::consts::OS.to_string(),
+        arch: std::env::consts::ARCH.to_string(),
+        app_version: app_version.to_string(),
+        resource_dir: resource_dir.map(|path| path.to_string_lossy().to_string()),
+        settings: settings.clone(),
+        probe: recorded,
+        decisions,
+    }
+}
+
+pub fn capture(
+    settings: &AppSettings,
+    resource_dir: Option<&Path>,
+    app_version: &str,
+    created_unix_ms: u64,
+) -> SupportSnapshot {
+    capture_with(
+        settings,
+        resource_dir,
+        app_version,
+        created_unix_ms,
+        &LiveProbe,
+    )
+}
+
+pub fn replay(snapshot: &SupportSnapshot) -> ReplayReport {
+    let resource_dir = snapshot.resource_dir.as_deref().map(Path::new);
+    let decisions = resolve_decisions(&snapshot.settings, resource_dir, &snapshot.probe);
+
+    let mut mismatches = Vec::new();
+    if decisions.model_candidates != snapshot.decisions.model_candidates {
+        mismatches.push("model candidate list differs".to_string());
+    }
+    if decisions.model_path != snapshot.decisions.model_path {
+        mismatches.push(format!(
+            "model path: recorded {}, replayed {}",
+            snapshot.decisions.model_path, decisions.model_path
+        ));
+    }
+    for (replayed, recorded) in decisions.binaries.iter().zip(&snapshot.decisions.binaries) {
+        if replayed.candidates != recorded.candidates {
+            mismatches.push(format!("{} candidate list differs", recorded.env_name));
+        }
+        if replayed.resolved != recorded.resolved {
+            mismatches.push(format!(
+                "{}: recorded {:?}, replayed {:?}",
+                recorded.env_name, recorded.resolved, replayed.resolved
+            ));
+        }
+    }
+    if decisions.binaries.len() != snapshot.decisions.binaries.len() {
+        mismatches.push("sidecar binary list differs".to_string());
+    }
+
+    ReplayReport {
+        decisions,
+        mismatches,
+    }
+}
+
+pub fn save(path: &Path, snapshot: &SupportSnapshot) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|error| format!("failed to create snapshot directory: {error}"))?;
+    }
+    let raw = serde_json::to_string_pretty(snapshot)
+        .map_err(|error| format!("failed to serialize support snapshot: {error}"))?;
+    fs::write(path, raw).map_err(|error| format!("failed to write support snapshot: {error}"))
+}
+
+pub fn load(path: &Path) -> Result<SupportSnapshot, String> {
+    let raw = fs::read_to_string(path)
+        .map_err(|error| format!("failed to read support snapshot: {error}"))?;
+    let snapshot = serde_json::from_str::<SupportSnapshot>(&raw)
+        .map_err(|error| format!("failed to parse support snapshot: {error}"))?;
+    if snapshot.schema_version > SUPPORT_SNAPSHOT_SCHEMA_VERSION {
+        return Err(format!(
+            "support snapshot schema {} is newer than supported {}",
+            snapshot.schema_version, SUPPORT_SNAPSHOT_SCHEMA_VERSION
+        ));
+    }
+    Ok(snapshot)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn support_machine() -> ReplayProbe {
+        let mut probe = ReplayProbe::default();
+        probe.env.insert(
+            "SONORA_PARAKEET_BIN".to_string(),
+            "/opt/parakeet/run".to_string(),
+        );
+        probe.existing_paths.insert("/opt/parakeet/run".to_string());
+        probe
+    }
+
+    #[test]
+    fn replay_reproduces_recorded_decisions() {
+        let settings = AppSettings::default();
+        let resource_dir = Path::new("/Applications/Sonora.app/Contents/Resources");
+        let snapshot = capture_with(
+            &settings,
+            Some(resource_dir),
+            "0.1.0",
+            1,
+            &support_machine(),
+        );
+
+        let parakeet = snapshot
+            .decisions
+            .binaries
+            .iter()
+            .find(|binary| binary.env_name == "SONORA_PARAKEET_BIN")
+            .expect("parakeet decision");
+        assert_eq!(parakeet.resolved.as_deref(), Some("/opt/parakeet/run"));
+
+        let raw = serde_json::to_string(&snapshot).expect("snapshot should serialize");
+        let restored =
+            serde_json::from_str::<SupportSnapshot>(&raw).expect("snapshot should parse");
+        let report = replay(&restored);
+        assert!(report.mismatches.is_empty(), "{:?}", report.mismatches);
+        assert_eq!(report.decisions, snapshot.decisions);
+    }
+
+    #[test]
+    fn replay_reports_decisions_that_no_longer_match() {
+        let settings = AppSettings::default();
+        let mut snapshot = capture_with(&settings, None, "0.1.0", 1, &support_machine());
+        snapshot.probe.existing_paths.clear();
+
+        let report = replay(&snapshot);
+        assert_eq!(report.mismatches.len(), 1);
+        assert!(report.mismatches[0].starts_with("SONORA_PARAKEET_BIN"));
+    }
+}
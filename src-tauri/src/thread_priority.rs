@@ -0,0 +1,307 @@
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ThreadRole {
+    // Drains frames from the device callback; when it stalls the bounded
+    // frame channel fills and audio is dropped.
+    Capture,
+    // Runs or feeds inference, which should yield to capture and the UI.
+    Inference,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct PriorityOutcome {
+    pub role: ThreadRole,
+    // sched_rr, rtkit, nice, qos_class, mmcss or thread_priority; "none" when
+    // no boost was available and the thread was left alone.
+    pub mechanism: &'static str,
+    pub applied: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl PriorityOutcome {
+    pub fn summary(&self) -> String {
+        let role = match self.role {
+            ThreadRole::Capture => "capture thread boosted",
+            ThreadRole::Inference => "inference thread lowered",
+        };
+        match &self.error {
+            None if !self.applied => format!("{role} skipped: no priority mechanism is available"),
+            None => format!("{role} via {}", self.mechanism),
+            Some(error) => format!("{role} via {} failed: {error}", self.mechanism),
+        }
+    }
+}
+
+const SKIPPED: &str = "none";
+
+// Changes only the calling thread. Threads this is applied to live for one
+// capture session, except the pipeline actor, which keeps its lowered priority.
+pub fn apply_current_thread(role: ThreadRole) -> PriorityOutcome {
+    let (mechanism, result) = platform::apply(role);
+    PriorityOutcome {
+        role,
+        mechanism,
+        applied: result.is_ok() && mechanism != SKIPPED,
+        error: result.err(),
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod platform {
+    use super::ThreadRole;
+    use std::io;
+    use std::os::raw::{c_int, c_uint, c_ulong};
+
+    const PRIO_PROCESS: c_int = 0;
+    const RLIMIT_RTPRIO: c_int = 14;
+    const SCHED_RR: c_int = 2;
+    // Threads the capture thread spawns drop back to normal scheduling.
+    const SCHED_RESET_ON_FORK: c_int = 0x4000_0000;
+    // Well below PipeWire's and JACK's own data threads.
+    const CAPTURE_RT_PRIORITY: c_int = 10;
+    const CAPTURE_NICE: c_int = -10;
+    const INFERENCE_NICE: c_int = 10;
+
+    #[repr(C)]
+    struct Rlimit {
+        rlim_cur: c_ulong,
+        rlim_max: c_ulong,
+    }
+
+    #[repr(C)]
+    struct SchedParam {
+        sched_priority: c_int,
+    }
+
+    extern "C" {
+        fn gettid() -> c_int;
+        fn setpriority(which: c_int, who: c_uint, prio: c_int) -> c_int;
+        fn getrlimit(resource: c_int, rlim: *mut Rlimit) -> c_int;
+        fn sched_setscheduler(pid: c_int, policy: c_int, param: *const SchedParam) -> c_int;
+    }
+
+    // Linux keeps a nice value per thread when given a thread id, and sidecars
+    // spawned from a lowered thread inherit it.
+    pub fn apply(role: ThreadRole) -> (&'static str, Result<(), String>) {
+        match role {
+            ThreadRole::Capture => boost_capture(),
+            ThreadRole::Inference => ("nice", set_nice(INFERENCE_NICE)),
+        }
+    }
+
+    // A negative nice value needs CAP_SYS_NICE, which desktop users rarely
+    // have, so the unprivileged routes go first: SCHED_RR within the user's
+    // RLIMIT_RTPRIO, then rtkit, which most desktops run for PipeWire. When
+    // none of them is allowed the thread keeps its normal priority.
+    fn boost_capture() -> (&'static str, Result<(), String>) {
+        if set_round_robin().is_ok() {
+            return ("sched_rr", Ok(()));
+        }
+        #[cfg(feature = "desktop")]
+        if rtkit_high_priority().is_ok() {
+            return ("rtkit", Ok(()));
+        }
+        if set_nice(CAPTURE_NICE).is_ok() {
+            return ("nice", Ok(()));
+        }
+        (super::SKIPPED, Ok(()))
+    }
+
+    fn set_round_robin() -> Result<(), String> {
+        let mut limit = Rlimit {
+            rlim_cur: 0,
+            rlim_max: 0,
+        };
+        // SAFETY: `limit` is a valid, writable rlimit for the duration of the call.
+        if unsafe { getrlimit(RLIMIT_RTPRIO, &mut limit) } != 0 {
+            return Err(io::Error::last_os_error().to_string());
+        }
+        let allowed = c_int::try_from(limit.rlim_cur).unwrap_or(c_int::MAX);
+        if allowed < 1 {
+            return Err("RLIMIT_RTPRIO allows no real-time priority".to_string());
+        }
+        let param = SchedParam {
+            sched_priority: CAPTURE_RT_PRIORITY.min(allowed),
+        };
+        // SAFETY: `param` outlives the call, and the thread id is the caller's own.
+        let status =
+            unsafe { sched_setscheduler(gettid(), SCHED_RR | SCHED_RESET_ON_FORK, &param) };
+        if status == 0 {
+            Ok(())
+        } else {
+            Err(io::Error::last_os_error().to_string())
+        }
+    }
+
+    #[cfg(feature = "desktop")]
+    fn rtkit_high_priority() -> Result<(), String> {
+        // SAFETY: takes no arguments and only reads the calling thread's id.
+        let thread = unsafe { gettid() } as u64;
+        let connection = zbus::blocking::Connection::system().map_err(|error| error.to_string())?;
+        connection
+            .call_method(
+                Some("org.freedesktop.RealtimeKit1"),
+                "/org/freedesktop/RealtimeKit1",
+                Some("org.freedesktop.RealtimeKit1"),
+                "MakeThreadHighPriority",
+                &(thread, CAPTURE_NICE),
+            )
+            .map(|_| ())
+            .map_err(|error| error.to_string())
+    }
+
+    fn set_nice(nice: c_int) -> Result<(), String> {
+        // SAFETY: both calls take plain integers and only touch the calling thread.
+        let status = unsafe { setpriority(PRIO_PROCESS, gettid() as c_uint, nice) };
+        if status == 0 {
+            Ok(())
+        } else {
+            Err(io::Error::last_os_error().to_string())
+        }
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod platform {
+    use super::ThreadRole;
+    use std::io;
+    use std::os::raw::{c_int, c_uint};
+
+    const QOS_CLASS_USER_INTERACTIVE: c_uint = 0x21;
+    const QOS_CLASS_UTILITY: c_uint = 0x11;
+
+    extern "C" {
+        fn pthread_set_qos_class_self_np(qos_class: c_uint, relative_priority: c_int) -> c_int;
+    }
+
+    // CoreAudio's own IO thread is already real-time; this covers the thread
+    // that drains it and the one that feeds inference.
+    pub fn apply(role: ThreadRole) -> (&'static str, Result<(), String>) {
+        let class = match role {
+            ThreadRole::Capture => QOS_CLASS_USER_INTERACTIVE,
+            ThreadRole::Inference => QOS_CLASS_UTILITY,
+        };
+        // SAFETY: takes plain integers and only touches the calling thread.
+        let status = unsafe { pthread_set_qos_class_self_np(class, 0) };
+        let result = if status == 0 {
+            Ok(())
+        } else {
+            Err(io::Error::from_raw_os_error(status).to_string())
+        };
+        ("qos_class", result)
+    }
+}
+
+#[cfg(target_os = "windows")]
+mod platform {
+    use super::ThreadRole;
+    use std::ffi::c_void;
+    use std::io;
+
+    type Handle = *mut c_void;
+
+    const THREAD_PRIORITY_BELOW_NORMAL: i32 = -1;
+    const THREAD_PRIORITY_HIGHEST: i32 = 2;
+
+    #[link(name = "avrt")]
+    extern "system" {
+        fn AvSetMmThreadCharacteristicsW(task_name: *const u16, task_index: *mut u32) -> Handle;
+    }
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn GetCurrentThread() -> Handle;
+        fn SetThreadPriority(thread: Handle, priority: i32) -> i32;
+    }
+
+    // MMCSS "Pro Audio" is what audio engines register with; the registration
+    // ends with the thread, so there is nothing to revert.
+    pub fn apply(role: ThreadRole) -> (&'static str, Result<(), String>) {
+        match role {
+            ThreadRole::Capture => {
+                let task = "Pro Audio"
+                    .encode_utf16()
+                    .chain(Some(0))
+                    .collect::<Vec<u16>>();
+                let mut task_index = 0u32;
+                // SAFETY: `task` is NUL-terminated and outlives the call.
+                let handle =
+                    unsafe { AvSetMmThreadCharacteristicsW(task.as_ptr(), &mut task_index) };
+                if !handle.is_null() {
+                    return ("mmcss", Ok(()));
+                }
+                // The MMCSS service can be disabled; a plain boost still helps.
+                set_thread_priority(THREAD_PRIORITY_HIGHEST)
+            }
+            ThreadRole::Inference => set_thread_priority(THREAD_PRIORITY_BELOW_NORMAL),
+        }
+    }
+
+    fn set_thread_priority(priority: i32) -> (&'static str, Result<(), String>) {
+        // SAFETY: GetCurrentThread returns a pseudo handle that needs no closing.
+        let status = unsafe { SetThreadPriority(GetCurrentThread(), priority) };
+        let result = if status != 0 {
+            Ok(())
+        } else {
+            Err(io::Error::last_os_error().to_string())
+        };
+        ("thread_priority", result)
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+mod platform {
+    use super::ThreadRole;
+
+    pub fn apply(_role: ThreadRole) -> (&'static str, Result<(), String>) {
+        (
+            super::SKIPPED,
+            Err("thread priorities are not supported on this platform".to_string()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn lowering_inference_needs_no_privileges() {
+        let outcome = thread::spawn(|| apply_current_thread(ThreadRole::Inference))
+            .join()
+            .expect("thread should finish");
+        if cfg!(any(
+            target_os = "linux",
+            target_os = "macos",
+            target_os = "windows"
+        )) {
+            assert!(outcome.applied, "{}", outcome.summary());
+            assert!(outcome
+                .summary()
+                .starts_with("inference thread lowered via"));
+        }
+
+        let capture = thread::spawn(|| apply_current_thread(ThreadRole::Capture))
+            .join()
+            .expect("thread should finish");
+        if cfg!(target_os = "linux") {
+            // Without any allowance the boost is skipped, not reported as an error.
+            assert_eq!(capture.error, None, "{}", capture.summary());
+        }
+
+        let refused = PriorityOutcome {
+            role: ThreadRole::Capture,
+            mechanism: "nice",
+            applied: false,
+            error: Some("Permission denied (os error 13)".to_string()),
+        };
+        assert_eq!(
+            refused.summary(),
+            "capture thread boosted via nice failed: Permission denied (os error 13)"
+        );
+    }
+}
@@ -4,12 +4,23 @@ use std::io::{BufRead, BufReader, Write};
 use std::path::{Path, PathBuf};
 use std::process::{Child, ChildStdin, ChildStdout, Command, Stdio};
 use std::sync::{Arc, Mutex};
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::Instant;
 
 use crate::config::{
     FasterWhisperComputeType, ModelProfile, ParakeetComputeType, SttEngine,
     WhisperBackendPreference,
 };
+use crate::error::SonoraError;
+use crate::integrity::{self, IntegrityStatus};
+use crate::offline;
+use crate::postprocess::capitalize_sentences;
+use crate::resolution::{LiveProbe, ResolutionProbe};
+use crate::sandbox;
+use crate::segment_filter::{self, TranscriptSegment};
+use crate::sidecar_variants::{self, VariantBackend, VariantChoice};
+use crate::transcriber_host::HostedTranscriber;
+use crate::work_dir::SessionWorkspace;
+use crate::worker_supervisor::{self, WorkerSupervisor};
 use serde::{Deserialize, Serialize};
 
 #[cfg(target_os = "windows")]
@@ -25,10 +36,34 @@ pub trait Transcriber: Send + Sync {
 
     fn set_stream_context(&self, _context: Option<&str>) {}
 
+    // Confidence of the most recent transcript in 0..=1, for engines that report one.
+    fn last_confidence(&self) -> Option<f32> {
+        None
+    }
+
     fn prepare(&self) -> Result<(), String> {
         Ok(())
     }
 
+    fn unload(&self) {}
+
+    // Brackets a capture session so per-chunk files share one working
+    // directory that is removed when the session ends or is cancelled.
+    fn begin_session(&self) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn end_session(&self) {}
+
+    fn worker_pid(&self) -> Option<u32> {
+        None
+    }
+
+    // Times the sidecar was restarted after crashing mid-request.
+    fn worker_restarts(&self) -> u32 {
+        0
+    }
+
     fn engine_label(&self) -> &'static str {
         "unknown"
     }
@@ -66,6 +101,10 @@ pub struct WhisperSidecarConfig {
     pub language: String,
     pub threads: usize,
     pub compute_backend: WhisperComputeBackend,
+    pub sandboxed: bool,
+    pub gpu_device: Option<u32>,
+    // Whisper's translate task, which only ever targets English.
+    pub translate: bool,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -83,23 +122,33 @@ impl WhisperComputeBackend {
     }
 }
 
-#[derive(Debug, Deserialize)]
-struct WhisperSidecarMetadata {
-    backend: Option<String>,
-}
-
 const SIDECAR_METADATA_FILE_NAME: &str = "whisper-sidecar.json";
+const FASTER_WHISPER_METADATA_FILE_NAME: &str = "faster-whisper-sidecar.json";
+const PARAKEET_METADATA_FILE_NAME: &str = "parakeet-sidecar.json";
 const BACKEND_ENV_NAME: &str = "SONORA_WHISPER_BACKEND";
+const WHISPER_BIN_ENV_NAME: &str = "SONORA_WHISPER_BIN";
 const FASTER_WHISPER_BIN_ENV_NAME: &str = "SONORA_FASTER_WHISPER_BIN";
 const PARAKEET_BIN_ENV_NAME: &str = "SONORA_PARAKEET_BIN";
+const WHISPER_QUANTIZE_BIN_ENV_NAME: &str = "SONORA_WHISPER_QUANTIZE_BIN";
 const WHISPER_EXTRA_PATH_ENV_NAME: &str = "SONORA_WHISPER_EXTRA_PATH";
 const FASTER_WHISPER_EXTRA_PATH_ENV_NAME: &str = "SONORA_FASTER_WHISPER_EXTRA_PATH";
+// Environment overrides that change which binary or backend gets picked.
+pub const RESOLUTION_ENV_NAMES: [&str; 7] = [
+    BACKEND_ENV_NAME,
+    WHISPER_BIN_ENV_NAME,
+    FASTER_WHISPER_BIN_ENV_NAME,
+    PARAKEET_BIN_ENV_NAME,
+    WHISPER_QUANTIZE_BIN_ENV_NAME,
+    WHISPER_EXTRA_PATH_ENV_NAME,
+    FASTER_WHISPER_EXTRA_PATH_ENV_NAME,
+];
 const FASTER_WHISPER_DEFAULT_MODEL_FAST: &str = "tiny.en";
 const FASTER_WHISPER_DEFAULT_MODEL_BALANCED: &str = "small.en";
 const PARAKEET_DEFAULT_MODEL_FAST: &str = "nvidia/parakeet-ctc-0.6b";
 const PARAKEET_DEFAULT_MODEL_BALANCED: &str = "nvidia/parakeet-ctc-1.1b";
+pub const DEFAULT_PUNCTUATION_MODEL: &str = "oliverguhr/fullstop-punctuation-multilang-large";
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EngineSpec {
     pub engine: SttEngine,
     pub language: String,
@@ -108,8 +157,17 @@ pub struct EngineSpec {
     pub whisper_backend_preference: WhisperBackendPreference,
     pub faster_whisper_compute_type: FasterWhisperComputeType,
     pub faster_whisper_beam_size: u8,
+    pub faster_whisper_cache_dir: Option<PathBuf>,
     pub parakeet_compute_type: ParakeetComputeType,
     pub resource_dir: Option<PathBuf>,
+    pub sandbox_sidecars: bool,
+    pub allow_path_sidecars: bool,
+    pub offline_only: bool,
+    pub punctuation_restoration: bool,
+    // nvidia-smi index of the GPU whisper.cpp should use; None leaves it to CUDA.
+    pub gpu_device_index: Option<u32>,
+    // Translate speech to English instead of transcribing it (live captions).
+    pub translate: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -123,6 +181,9 @@ pub struct RuntimeEngineDiagnostics {
     pub checked_binary_paths: Vec<String>,
     pub resolved_model_path: String,
     pub model_exists: bool,
+    pub binary_integrity: Option<IntegrityStatus>,
+    pub gpu_device_index: Option<u32>,
+    pub sandbox: String,
 }
 
 #[derive(Debug, Clone)]
@@ -145,6 +206,7 @@ impl WhisperSidecarConfig {
             "-np".to_string(),
             "--no-timestamps".to_string(),
             "-otxt".to_string(),
+            "-ojf".to_string(),
             "-of".to_string(),
             output_prefix.to_string_lossy().to_string(),
         ];
@@ -152,14 +214,31 @@ impl WhisperSidecarConfig {
         if self.compute_backend == WhisperComputeBackend::Cpu {
             args.push("-ng".to_string());
         }
+        if self.translate {
+            args.push("-tr".to_string());
+        }
 
         args
     }
+
+    // Pinning by PCI order keeps the index consistent with what nvidia-smi lists;
+    // CUDA's default order puts the fastest card first instead.
+    pub fn command_env(&self) -> Vec<(&'static str, String)> {
+        match (self.compute_backend, self.gpu_device) {
+            (WhisperComputeBackend::Cuda, Some(index)) => vec![
+                ("CUDA_DEVICE_ORDER", "PCI_BUS_ID".to_string()),
+                ("CUDA_VISIBLE_DEVICES", index.to_string()),
+            ],
+            _ => Vec::new(),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct WhisperSidecarTranscriber {
     pub config: WhisperSidecarConfig,
+    pub supervisor: Arc<Mutex<WorkerSupervisor>>,
+    pub workspace: SessionWorkspace,
 }
 
 impl WhisperSidecarTranscriber {
@@ -168,39 +247,56 @@ impl WhisperSidecarTranscriber {
             return Err("cannot transcribe empty audio chunk".to_string());
         }
 
-        let token = temporary_token();
-        let temp_dir = std::env::temp_dir();
-        let wav_path = temp_dir.join(format!("sonora-{token}.wav"));
-        let output_prefix = temp_dir.join(format!("sonora-{token}-out"));
+        let session = self.workspace.session()?;
+        let chunk = session.next_chunk();
+        let wav_path = chunk.file(".wav");
+        let output_prefix = chunk.file("-out");
         let txt_path = output_prefix.with_extension("txt");
+        let json_path = output_prefix.with_extension("json");
 
         write_wav_file(&wav_path, samples)?;
 
         let args = self.config.command_args(&wav_path, &output_prefix);
-        let mut command = Command::new(&self.config.binary_path);
+        let extra_paths = if self.config.compute_backend == WhisperComputeBackend::Cuda {
+            extra_path_entries_from_env(WHISPER_EXTRA_PATH_ENV_NAME)
+        } else {
+            Vec::new()
+        };
+        let mut readonly_paths = vec![self.config.model_path.clone()];
+        readonly_paths.extend(extra_paths.iter().cloned());
+        let mut command = sandbox::sidecar_command(
+            &self.config.binary_path,
+            self.config.sandboxed,
+            &readonly_paths,
+            &[session.path().to_path_buf()],
+        )?;
         command.args(args);
-
-        if self.config.compute_backend == WhisperComputeBackend::Cuda {
-            let extra_paths = extra_path_entries_from_env(WHISPER_EXTRA_PATH_ENV_NAME);
-            prepend_process_path(&mut command, &extra_paths);
-        }
+        command.envs(self.config.command_env());
+        prepend_process_path(&mut command, &extra_paths);
 
         #[cfg(target_os = "windows")]
         {
             command.creation_flags(CREATE_NO_WINDOW | BELOW_NORMAL_PRIORITY_CLASS);
         }
 
-        let output = command.output().map_err(|error| {
-            format!(
-                "failed to execute whisper sidecar at '{}': {}",
-                self.config.binary_path.to_string_lossy(),
-                error
-            )
-        })?;
+        let mut run = || {
+            command.output().map_err(|error| {
+                format!(
+                    "failed to execute whisper sidecar at '{}': {}",
+                    self.config.binary_path.to_string_lossy(),
+                    error
+                )
+            })
+        };
+        let mut output = run()?;
+        // The chunk is replayed once so a driver fault costs latency rather than words.
+        if worker_supervisor::crashed(&output.status) && allow_restart(&self.supervisor) {
+            output = run()?;
+        }
 
         if !output.status.success() {
             let stderr = String::from_utf8_lossy(&output.stderr);
-            cleanup_temp_files(&[&wav_path, &txt_path]);
+            cleanup_temp_files(&[&wav_path, &txt_path, &json_path]);
             return Err(format!(
                 "whisper sidecar exited with status {}: {}",
                 output.status,
@@ -208,14 +304,25 @@ impl WhisperSidecarTranscriber {
             ));
         }
 
-        let transcript = if txt_path.exists() {
+        // Older sidecar builds without -ojf only write the plain text file.
+        let segments = fs::read_to_string(&json_path)
+            .ok()
+            .and_then(|raw| segment_filter::parse_whisper_cpp_json(&raw).ok());
+        let transcript = if let Some(segments) = segments {
+            let filtered = segment_filter::filter_segments(&segments);
+            if filtered.text.is_empty() && filtered.dropped_segments > 0 {
+                cleanup_temp_files(&[&wav_path, &txt_path, &json_path]);
+                return Ok(String::new());
+            }
+            filtered.text
+        } else if txt_path.exists() {
             fs::read_to_string(&txt_path)
                 .map_err(|error| format!("failed to read transcription output: {}", error))?
         } else {
             String::from_utf8_lossy(&output.stdout).to_string()
         };
 
-        cleanup_temp_files(&[&wav_path, &txt_path]);
+        cleanup_temp_files(&[&wav_path, &txt_path, &json_path]);
 
         let normalized = transcript.trim().to_string();
         if normalized.is_empty() {
@@ -231,6 +338,18 @@ impl Transcriber for WhisperSidecarTranscriber {
         self.transcribe_impl(samples)
     }
 
+    fn begin_session(&self) -> Result<(), String> {
+        self.workspace.begin()
+    }
+
+    fn end_session(&self) {
+        self.workspace.end();
+    }
+
+    fn worker_restarts(&self) -> u32 {
+        supervisor_restarts(&self.supervisor)
+    }
+
     fn engine_label(&self) -> &'static str {
         "whisper_cpp"
     }
@@ -254,11 +373,14 @@ pub struct FasterWhisperSidecarConfig {
     pub compute_type: String,
     pub beam_size: u8,
     pub condition_on_previous_text: bool,
+    pub sandboxed: bool,
+    pub offline: bool,
+    pub translate: bool,
 }
 
 #[derive(Debug)]
 struct FasterWhisperWorker {
-    _child: Child,
+    child: Child,
     stdin: ChildStdin,
     stdout: BufReader<ChildStdout>,
 }
@@ -269,6 +391,9 @@ pub struct FasterWhisperSidecarTranscriber {
     worker: Arc<Mutex<Option<FasterWhisperWorker>>>,
     preloaded: Arc<Mutex<bool>>,
     context_prompt: Arc<Mutex<Option<String>>>,
+    last_confidence: Arc<Mutex<Option<f32>>>,
+    supervisor: Arc<Mutex<WorkerSupervisor>>,
+    workspace: SessionWorkspace,
 }
 
 #[derive(Debug, Clone)]
@@ -279,11 +404,14 @@ pub struct ParakeetSidecarConfig {
     pub language: String,
     pub device: String,
     pub compute_type: String,
+    pub sandboxed: bool,
+    pub offline: bool,
+    pub punctuation_model: Option<String>,
 }
 
 #[derive(Debug)]
 struct ParakeetWorker {
-    _child: Child,
+    child: Child,
     stdin: ChildStdin,
     stdout: BufReader<ChildStdout>,
 }
@@ -293,6 +421,8 @@ pub struct ParakeetSidecarTranscriber {
     pub config: ParakeetSidecarConfig,
     worker: Arc<Mutex<Option<ParakeetWorker>>>,
     preloaded: Arc<Mutex<bool>>,
+    supervisor: Arc<Mutex<WorkerSupervisor>>,
+    workspace: SessionWorkspace,
 }
 
 impl FasterWhisperSidecarTranscriber {
@@ -302,6 +432,9 @@ impl FasterWhisperSidecarTranscriber {
             worker: Arc::new(Mutex::new(None)),
             preloaded: Arc::new(Mutex::new(false)),
             context_prompt: Arc::new(Mutex::new(None)),
+            last_confidence: Arc::new(Mutex::new(None)),
+            supervisor: Arc::new(Mutex::new(WorkerSupervisor::default())),
+            workspace: SessionWorkspace::new("faster-whisper"),
         }
     }
 
@@ -312,9 +445,9 @@ impl FasterWhisperSidecarTranscriber {
 
         self.prepare_impl()?;
 
-        let token = temporary_token();
-        let temp_dir = std::env::temp_dir();
-        let wav_path = temp_dir.join(format!("sonora-faster-{token}.wav"));
+        let session = self.workspace.session()?;
+        let chunk = session.next_chunk();
+        let wav_path = chunk.file(".wav");
         let initial_prompt = self
             .context_prompt
             .lock()
@@ -324,7 +457,7 @@ impl FasterWhisperSidecarTranscriber {
         write_wav_file(&wav_path, samples)?;
         let request = FasterWhisperRequest {
             op: "transcribe".to_string(),
-            id: token,
+            id: chunk.token,
             audio_path: path_to_sidecar_string(&wav_path),
             language: self.config.language.clone(),
             model: self.config.model.clone(),
@@ -333,13 +466,36 @@ impl FasterWhisperSidecarTranscriber {
             beam_size: self.config.beam_size,
             condition_on_previous_text: self.config.condition_on_previous_text,
             initial_prompt,
+            task: if self.config.translate {
+                "translate"
+            } else {
+                "transcribe"
+            },
         };
 
-        let result = self.send_request(request);
+        let mut result = self.send_request(request.clone());
+        if result.is_err() && self.restart_crashed_worker() {
+            result = self.send_request(request);
+        }
         cleanup_temp_files(&[&wav_path]);
         result
     }
 
+    // Respawns and preloads a worker that died mid-request so the chunk can be replayed.
+    fn restart_crashed_worker(&self) -> bool {
+        let crashed = self
+            .worker
+            .lock()
+            .is_ok_and(|mut guard| take_exited(&mut guard));
+        if !crashed || !allow_restart(&self.supervisor) {
+            return false;
+        }
+        if let Ok(mut preloaded) = self.preloaded.lock() {
+            *preloaded = false;
+        }
+        self.prepare_impl().is_ok()
+    }
+
     fn send_request(&self, request: FasterWhisperRequest) -> Result<String, String> {
         let request_id = request.id.clone();
         let mut guard = self
@@ -421,8 +577,17 @@ impl FasterWhisperSidecarTranscriber {
                 .unwrap_or_else(|| "unknown faster-whisper worker error".to_string()));
         }
 
-        let normalized = response.text.unwrap_or_default().trim().to_string();
-        Ok(normalized)
+        let (text, confidence) = match response.segments.as_deref() {
+            Some(segments) => {
+                let filtered = segment_filter::filter_segments(segments);
+                (filtered.text, filtered.confidence)
+            }
+            None => (response.text.unwrap_or_default(), response.confidence),
+        };
+        if let Ok(mut last_confidence) = self.last_confidence.lock() {
+            *last_confidence = confidence.map(|value| value.clamp(0.0, 1.0));
+        }
+        Ok(text.trim().to_string())
     }
 
     fn prepare_impl(&self) -> Result<(), String> {
@@ -525,10 +690,46 @@ impl Transcriber for FasterWhisperSidecarTranscriber {
         }
     }
 
+    fn last_confidence(&self) -> Option<f32> {
+        self.last_confidence.lock().ok().and_then(|guard| *guard)
+    }
+
     fn prepare(&self) -> Result<(), String> {
         self.prepare_impl()
     }
 
+    fn begin_session(&self) -> Result<(), String> {
+        self.workspace.begin()
+    }
+
+    fn end_session(&self) {
+        self.workspace.end();
+    }
+
+    fn unload(&self) {
+        if let Ok(mut guard) = self.worker.lock() {
+            if let Some(mut worker) = guard.take() {
+                let _ = worker.child.kill();
+                let _ = worker.child.wait();
+            }
+        }
+        if let Ok(mut preloaded) = self.preloaded.lock() {
+            *preloaded = false;
+        }
+    }
+
+    fn worker_pid(&self) -> Option<u32> {
+        self.worker.lock().ok().and_then(|guard| {
+            guard
+                .as_ref()
+                .map(|worker| sandbox::worker_pid(worker.child.id()))
+        })
+    }
+
+    fn worker_restarts(&self) -> u32 {
+        supervisor_restarts(&self.supervisor)
+    }
+
     fn engine_label(&self) -> &'static str {
         "faster_whisper"
     }
@@ -548,6 +749,8 @@ impl ParakeetSidecarTranscriber {
             config,
             worker: Arc::new(Mutex::new(None)),
             preloaded: Arc::new(Mutex::new(false)),
+            supervisor: Arc::new(Mutex::new(WorkerSupervisor::default())),
+            workspace: SessionWorkspace::new("parakeet"),
         }
     }
 
@@ -558,14 +761,14 @@ impl ParakeetSidecarTranscriber {
 
         self.prepare_impl()?;
 
-        let token = temporary_token();
-        let temp_dir = std::env::temp_dir();
-        let wav_path = temp_dir.join(format!("sonora-parakeet-{token}.wav"));
+        let session = self.workspace.session()?;
+        let chunk = session.next_chunk();
+        let wav_path = chunk.file(".wav");
 
         write_wav_file(&wav_path, samples)?;
         let request = ParakeetRequest {
             op: "transcribe".to_string(),
-            id: token,
+            id: chunk.token,
             audio_path: path_to_sidecar_string(&wav_path),
             language: self.config.language.clone(),
             model: self.config.model.clone(),
@@ -573,13 +776,50 @@ impl ParakeetSidecarTranscriber {
             compute_type: self.config.compute_type.clone(),
         };
 
-        let result = self.send_request(request);
+        let mut result = self.send_request(&request.id, &request);
+        if result.is_err() && self.restart_crashed_worker() {
+            result = self.send_request(&request.id, &request);
+        }
         cleanup_temp_files(&[&wav_path]);
-        result
+        let text = result?;
+        match self.config.punctuation_model.as_deref() {
+            Some(model) if !text.is_empty() => {
+                Ok(self.restore_punctuation(text, model, &session.next_chunk().token))
+            }
+            _ => Ok(text),
+        }
     }
 
-    fn send_request(&self, request: ParakeetRequest) -> Result<String, String> {
-        let request_id = request.id.clone();
+    fn restart_crashed_worker(&self) -> bool {
+        let crashed = self
+            .worker
+            .lock()
+            .is_ok_and(|mut guard| take_exited(&mut guard));
+        if !crashed || !allow_restart(&self.supervisor) {
+            return false;
+        }
+        if let Ok(mut preloaded) = self.preloaded.lock() {
+            *preloaded = false;
+        }
+        self.prepare_impl().is_ok()
+    }
+
+    fn restore_punctuation(&self, text: String, model: &str, token: &str) -> String {
+        let request = ParakeetPunctuateRequest {
+            op: "punctuate".to_string(),
+            id: format!("punctuate-{token}"),
+            text: text.clone(),
+            model: model.to_string(),
+            device: self.config.device.clone(),
+        };
+        // Unpunctuated text is still usable, so a failed restoration pass keeps the raw words.
+        match self.send_request(&request.id, &request) {
+            Ok(punctuated) if !punctuated.is_empty() => capitalize_sentences(&punctuated),
+            _ => text,
+        }
+    }
+
+    fn send_request<R: Serialize>(&self, request_id: &str, request: &R) -> Result<String, String> {
         let mut guard = self
             .worker
             .lock()
@@ -629,7 +869,7 @@ impl ParakeetSidecarTranscriber {
 
             match serde_json::from_str::<ParakeetResponse>(trimmed) {
                 Ok(parsed) => {
-                    if parsed.id.as_deref() == Some(request_id.as_str()) {
+                    if parsed.id.as_deref() == Some(request_id) {
                         response = Some(parsed);
                         break;
                     }
@@ -760,6 +1000,38 @@ impl Transcriber for ParakeetSidecarTranscriber {
         self.prepare_impl()
     }
 
+    fn begin_session(&self) -> Result<(), String> {
+        self.workspace.begin()
+    }
+
+    fn end_session(&self) {
+        self.workspace.end();
+    }
+
+    fn unload(&self) {
+        if let Ok(mut guard) = self.worker.lock() {
+            if let Some(mut worker) = guard.take() {
+                let _ = worker.child.kill();
+                let _ = worker.child.wait();
+            }
+        }
+        if let Ok(mut preloaded) = self.preloaded.lock() {
+            *preloaded = false;
+        }
+    }
+
+    fn worker_pid(&self) -> Option<u32> {
+        self.worker.lock().ok().and_then(|guard| {
+            guard
+                .as_ref()
+                .map(|worker| sandbox::worker_pid(worker.child.id()))
+        })
+    }
+
+    fn worker_restarts(&self) -> u32 {
+        supervisor_restarts(&self.supervisor)
+    }
+
     fn engine_label(&self) -> &'static str {
         "parakeet"
     }
@@ -773,7 +1045,7 @@ impl Transcriber for ParakeetSidecarTranscriber {
     }
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 struct FasterWhisperRequest {
     op: String,
     id: String,
@@ -786,6 +1058,7 @@ struct FasterWhisperRequest {
     condition_on_previous_text: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     initial_prompt: Option<String>,
+    task: &'static str,
 }
 
 #[derive(Debug, Serialize)]
@@ -805,9 +1078,12 @@ struct FasterWhisperResponse {
     ok: bool,
     text: Option<String>,
     error: Option<String>,
+    confidence: Option<f32>,
+    #[serde(default)]
+    segments: Option<Vec<TranscriptSegment>>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 struct ParakeetRequest {
     op: String,
     id: String,
@@ -818,6 +1094,15 @@ struct ParakeetRequest {
     compute_type: String,
 }
 
+#[derive(Debug, Serialize)]
+struct ParakeetPunctuateRequest {
+    op: String,
+    id: String,
+    text: String,
+    model: String,
+    device: String,
+}
+
 #[derive(Debug, Serialize)]
 struct ParakeetPreloadRequest {
     op: String,
@@ -840,11 +1125,20 @@ fn ensure_faster_whisper_worker(
     worker: &mut Option<FasterWhisperWorker>,
     config: &FasterWhisperSidecarConfig,
 ) -> Result<(), String> {
-    if worker.is_some() {
+    if worker
+        .as_mut()
+        .is_some_and(|running| !worker_supervisor::child_exited(&mut running.child))
+    {
         return Ok(());
     }
 
-    let mut command = Command::new(&config.binary_path);
+    let extra_paths = extra_path_entries_from_env(FASTER_WHISPER_EXTRA_PATH_ENV_NAME);
+    let mut command = sandbox::sidecar_command(
+        &config.binary_path,
+        config.sandboxed,
+        &extra_paths,
+        &[config.model_cache_dir.clone(), std::env::temp_dir()],
+    )?;
     command
         .arg("--stdio")
         .env(
@@ -854,8 +1148,10 @@ fn ensure_faster_whisper_worker(
         .stdin(Stdio::piped())
         .stdout(Stdio::piped())
         .stderr(Stdio::null());
+    if config.offline {
+        offline::apply_offline_env(&mut command);
+    }
 
-    let extra_paths = extra_path_entries_from_env(FASTER_WHISPER_EXTRA_PATH_ENV_NAME);
     prepend_process_path(&mut command, &extra_paths);
 
     #[cfg(target_os = "windows")]
@@ -881,7 +1177,7 @@ fn ensure_faster_whisper_worker(
         .ok_or_else(|| "faster-whisper worker stdout not available".to_string())?;
 
     *worker = Some(FasterWhisperWorker {
-        _child: child,
+        child,
         stdin,
         stdout: BufReader::new(stdout),
     });
@@ -893,11 +1189,19 @@ fn ensure_parakeet_worker(
     worker: &mut Option<ParakeetWorker>,
     config: &ParakeetSidecarConfig,
 ) -> Result<(), String> {
-    if worker.is_some() {
+    if worker
+        .as_mut()
+        .is_some_and(|running| !worker_supervisor::child_exited(&mut running.child))
+    {
         return Ok(());
     }
 
-    let mut command = Command::new(&config.binary_path);
+    let mut command = sandbox::sidecar_command(
+        &config.binary_path,
+        config.sandboxed,
+        &[],
+        &[config.model_cache_dir.clone(), std::env::temp_dir()],
+    )?;
     command
         .arg("--stdio")
         .env(
@@ -907,6 +1211,9 @@ fn ensure_parakeet_worker(
         .stdin(Stdio::piped())
         .stdout(Stdio::piped())
         .stderr(Stdio::null());
+    if config.offline {
+        offline::apply_offline_env(&mut command);
+    }
 
     #[cfg(target_os = "windows")]
     {
@@ -931,7 +1238,7 @@ fn ensure_parakeet_worker(
         .ok_or_else(|| "parakeet worker stdout not available".to_string())?;
 
     *worker = Some(ParakeetWorker {
-        _child: child,
+        child,
         stdin,
         stdout: BufReader::new(stdout),
     });
@@ -939,19 +1246,60 @@ fn ensure_parakeet_worker(
     Ok(())
 }
 
+trait SupervisedWorker {
+    fn child(&mut self) -> &mut Child;
+}
+
+impl SupervisedWorker for FasterWhisperWorker {
+    fn child(&mut self) -> &mut Child {
+        &mut self.child
+    }
+}
+
+impl SupervisedWorker for ParakeetWorker {
+    fn child(&mut self) -> &mut Child {
+        &mut self.child
+    }
+}
+
+// A worker dropped on EOF, or one whose process has exited, counts as crashed.
+fn take_exited<W: SupervisedWorker>(worker: &mut Option<W>) -> bool {
+    let exited = worker
+        .as_mut()
+        .is_none_or(|running| worker_supervisor::child_exited(running.child()));
+    if exited {
+        *worker = None;
+    }
+    exited
+}
+
+fn allow_restart(supervisor: &Mutex<WorkerSupervisor>) -> bool {
+    supervisor
+        .lock()
+        .is_ok_and(|mut supervisor| supervisor.allow_restart(Instant::now()))
+}
+
+fn supervisor_restarts(supervisor: &Mutex<WorkerSupervisor>) -> u32 {
+    supervisor
+        .lock()
+        .map(|supervisor| supervisor.restarts())
+        .unwrap_or(0)
+}
+
 #[derive(Debug, Clone)]
 pub enum RuntimeTranscriber {
     Stub(StubTranscriber),
-    Unavailable { reason: String },
+    Unavailable { reason: SonoraError },
     Whisper(WhisperSidecarTranscriber),
     FasterWhisper(FasterWhisperSidecarTranscriber),
     Parakeet(ParakeetSidecarTranscriber),
+    Hosted(HostedTranscriber),
 }
 
 impl RuntimeTranscriber {
     pub fn description(&self) -> String {
         match self {
-            RuntimeTranscriber::Stub(_) => "stub".to_string(),
+            RuntimeTranscriber::Stub(_) => "demo transcriber (no model or sidecar)".to_string(),
             RuntimeTranscriber::Unavailable { reason } => {
                 format!("unavailable: {reason}")
             }
@@ -976,6 +1324,12 @@ impl RuntimeTranscriber {
                     config.config.device
                 )
             }
+            RuntimeTranscriber::Hosted(hosted) => {
+                format!(
+                    "{} in a transcriber host process",
+                    hosted.local.description()
+                )
+            }
         }
     }
 
@@ -988,6 +1342,7 @@ impl RuntimeTranscriber {
             }
             RuntimeTranscriber::FasterWhisper(runtime) => runtime.config.device.clone(),
             RuntimeTranscriber::Parakeet(runtime) => runtime.config.device.clone(),
+            RuntimeTranscriber::Hosted(hosted) => hosted.local.compute_backend_label(),
         }
     }
 
@@ -998,6 +1353,7 @@ impl RuntimeTranscriber {
             }
             RuntimeTranscriber::FasterWhisper(runtime) => runtime.config.device == "cuda",
             RuntimeTranscriber::Parakeet(runtime) => runtime.config.device == "cuda",
+            RuntimeTranscriber::Hosted(hosted) => hosted.local.uses_gpu(),
             _ => false,
         }
     }
@@ -1007,7 +1363,9 @@ impl RuntimeTranscriber {
             RuntimeTranscriber::Whisper(_) => "whisper_cpp",
             RuntimeTranscriber::FasterWhisper(_) => "faster_whisper",
             RuntimeTranscriber::Parakeet(_) => "parakeet",
-            RuntimeTranscriber::Stub(_) | RuntimeTranscriber::Unavailable { .. } => "unknown",
+            RuntimeTranscriber::Hosted(hosted) => hosted.local.active_engine_label(),
+            RuntimeTranscriber::Stub(_) => "demo",
+            RuntimeTranscriber::Unavailable { .. } => "unknown",
         }
     }
 
@@ -1018,6 +1376,7 @@ impl RuntimeTranscriber {
             }
             RuntimeTranscriber::FasterWhisper(runtime) => runtime.config.model.clone(),
             RuntimeTranscriber::Parakeet(runtime) => runtime.config.model.clone(),
+            RuntimeTranscriber::Hosted(hosted) => hosted.local.model_label(),
             RuntimeTranscriber::Stub(_) => "stub".to_string(),
             RuntimeTranscriber::Unavailable { .. } => "unknown".to_string(),
         }
@@ -1028,10 +1387,11 @@ impl Transcriber for RuntimeTranscriber {
     fn transcribe(&self, samples: &[f32]) -> Result<String, String> {
         match self {
             RuntimeTranscriber::Stub(stub) => stub.transcribe(samples),
-            RuntimeTranscriber::Unavailable { reason } => Err(reason.clone()),
+            RuntimeTranscriber::Unavailable { reason } => Err(reason.to_string()),
             RuntimeTranscriber::Whisper(runtime) => runtime.transcribe(samples),
             RuntimeTranscriber::FasterWhisper(runtime) => runtime.transcribe(samples),
             RuntimeTranscriber::Parakeet(runtime) => runtime.transcribe(samples),
+            RuntimeTranscriber::Hosted(hosted) => hosted.transcribe(samples),
         }
     }
 
@@ -1040,18 +1400,78 @@ impl Transcriber for RuntimeTranscriber {
             RuntimeTranscriber::Whisper(runtime) => runtime.set_stream_context(context),
             RuntimeTranscriber::FasterWhisper(runtime) => runtime.set_stream_context(context),
             RuntimeTranscriber::Parakeet(runtime) => runtime.set_stream_context(context),
+            RuntimeTranscriber::Hosted(hosted) => hosted.set_stream_context(context),
             RuntimeTranscriber::Stub(stub) => stub.set_stream_context(context),
             RuntimeTranscriber::Unavailable { .. } => {}
         }
     }
 
+    fn last_confidence(&self) -> Option<f32> {
+        match self {
+            RuntimeTranscriber::FasterWhisper(runtime) => runtime.last_confidence(),
+            RuntimeTranscriber::Hosted(hosted) => hosted.last_confidence(),
+            _ => None,
+        }
+    }
+
     fn prepare(&self) -> Result<(), String> {
         match self {
             RuntimeTranscriber::Stub(stub) => stub.prepare(),
-            RuntimeTranscriber::Unavailable { reason } => Err(reason.clone()),
+            RuntimeTranscriber::Unavailable { reason } => Err(reason.to_string()),
             RuntimeTranscriber::Whisper(runtime) => runtime.prepare(),
             RuntimeTranscriber::FasterWhisper(runtime) => runtime.prepare(),
             RuntimeTranscriber::Parakeet(runtime) => runtime.prepare(),
+            RuntimeTranscriber::Hosted(hosted) => hosted.prepare(),
+        }
+    }
+
+    fn begin_session(&self) -> Result<(), String> {
+        match self {
+            RuntimeTranscriber::Whisper(runtime) => runtime.begin_session(),
+            RuntimeTranscriber::FasterWhisper(runtime) => runtime.begin_session(),
+            RuntimeTranscriber::Parakeet(runtime) => runtime.begin_session(),
+            RuntimeTranscriber::Hosted(hosted) => hosted.begin_session(),
+            RuntimeTranscriber::Stub(_) | RuntimeTranscriber::Unavailable { .. } => Ok(()),
+        }
+    }
+
+    fn end_session(&self) {
+        match self {
+            RuntimeTranscriber::Whisper(runtime) => runtime.end_session(),
+            RuntimeTranscriber::FasterWhisper(runtime) => runtime.end_session(),
+            RuntimeTranscriber::Parakeet(runtime) => runtime.end_session(),
+            RuntimeTranscriber::Hosted(hosted) => hosted.end_session(),
+            RuntimeTranscriber::Stub(_) | RuntimeTranscriber::Unavailable { .. } => {}
+        }
+    }
+
+    fn unload(&self) {
+        match self {
+            RuntimeTranscriber::Whisper(runtime) => runtime.unload(),
+            RuntimeTranscriber::FasterWhisper(runtime) => runtime.unload(),
+            RuntimeTranscriber::Parakeet(runtime) => runtime.unload(),
+            RuntimeTranscriber::Hosted(hosted) => hosted.unload(),
+            RuntimeTranscriber::Stub(stub) => stub.unload(),
+            RuntimeTranscriber::Unavailable { .. } => {}
+        }
+    }
+
+    fn worker_pid(&self) -> Option<u32> {
+        match self {
+            RuntimeTranscriber::FasterWhisper(runtime) => runtime.worker_pid(),
+            RuntimeTranscriber::Parakeet(runtime) => runtime.worker_pid(),
+            RuntimeTranscriber::Hosted(hosted) => hosted.worker_pid(),
+            _ => None,
+        }
+    }
+
+    fn worker_restarts(&self) -> u32 {
+        match self {
+            RuntimeTranscriber::Whisper(runtime) => runtime.worker_restarts(),
+            RuntimeTranscriber::FasterWhisper(runtime) => runtime.worker_restarts(),
+            RuntimeTranscriber::Parakeet(runtime) => runtime.worker_restarts(),
+            RuntimeTranscriber::Hosted(hosted) => hosted.worker_restarts(),
+            _ => 0,
         }
     }
 
@@ -1060,6 +1480,7 @@ impl Transcriber for RuntimeTranscriber {
             RuntimeTranscriber::Whisper(runtime) => runtime.engine_label(),
             RuntimeTranscriber::FasterWhisper(runtime) => runtime.engine_label(),
             RuntimeTranscriber::Parakeet(runtime) => runtime.engine_label(),
+            RuntimeTranscriber::Hosted(hosted) => hosted.engine_label(),
             RuntimeTranscriber::Stub(stub) => stub.engine_label(),
             RuntimeTranscriber::Unavailable { .. } => "unavailable",
         }
@@ -1070,6 +1491,7 @@ impl Transcriber for RuntimeTranscriber {
             RuntimeTranscriber::Whisper(runtime) => runtime.model_label(),
             RuntimeTranscriber::FasterWhisper(runtime) => runtime.model_label(),
             RuntimeTranscriber::Parakeet(runtime) => runtime.model_label(),
+            RuntimeTranscriber::Hosted(hosted) => hosted.model_label(),
             RuntimeTranscriber::Stub(stub) => stub.model_label(),
             RuntimeTranscriber::Unavailable { .. } => "unknown".to_string(),
         }
@@ -1080,6 +1502,7 @@ impl Transcriber for RuntimeTranscriber {
             RuntimeTranscriber::Whisper(runtime) => runtime.backend_label(),
             RuntimeTranscriber::FasterWhisper(runtime) => runtime.backend_label(),
             RuntimeTranscriber::Parakeet(runtime) => runtime.backend_label(),
+            RuntimeTranscriber::Hosted(hosted) => hosted.backend_label(),
             RuntimeTranscriber::Stub(stub) => stub.backend_label(),
             RuntimeTranscriber::Unavailable { .. } => "unavailable".to_string(),
         }
@@ -1091,6 +1514,7 @@ pub fn build_runtime_engine(spec: EngineSpec) -> RuntimeEngine {
         SttEngine::WhisperCpp => build_whisper_runtime(spec),
         SttEngine::FasterWhisper => build_faster_whisper_runtime(spec),
         SttEngine::Parakeet => build_parakeet_runtime(spec),
+        SttEngine::Demo => build_demo_runtime(spec),
     }
 }
 
@@ -1109,27 +1533,55 @@ pub fn build_runtime_transcriber(
         whisper_backend_preference: backend_preference,
         faster_whisper_compute_type: FasterWhisperComputeType::Auto,
         faster_whisper_beam_size: 1,
+        faster_whisper_cache_dir: None,
         parakeet_compute_type: ParakeetComputeType::Auto,
         resource_dir: resource_dir.map(Path::to_path_buf),
+        sandbox_sidecars: false,
+        allow_path_sidecars: false,
+        offline_only: false,
+        punctuation_restoration: false,
+        gpu_device_index: None,
+        translate: false,
     })
     .transcriber
 }
 
 fn build_whisper_runtime(spec: EngineSpec) -> RuntimeEngine {
+    let sandboxed = sandbox::sidecars_sandboxed(spec.sandbox_sidecars, spec.offline_only);
+    let sandbox = sandbox::status_label(sandboxed);
     let model_exists = spec.model_path.exists();
     let resolved_model_path = spec.model_path.to_string_lossy().to_string();
     let checked_binary_paths = resolve_binary_candidates(spec.resource_dir.as_deref())
         .into_iter()
         .map(|value| value.to_string_lossy().to_string())
         .collect::<Vec<_>>();
-    let binary_path = resolve_binary_path(spec.resource_dir.as_deref());
+    let bundled_binary_path = resolve_binary_path(spec.resource_dir.as_deref());
+    let variant = bundled_binary_path
+        .as_deref()
+        .and_then(|path| resolve_whisper_variant(path, spec.whisper_backend_preference));
+    let binary_path = variant
+        .as_ref()
+        .map(|choice| choice.binary_path.clone())
+        .or(bundled_binary_path);
+    let binary_integrity = binary_path
+        .as_deref()
+        .map(|path| integrity::verify_sidecar_binary(path, SIDECAR_METADATA_FILE_NAME));
 
     let transcriber = if !model_exists {
         RuntimeTranscriber::Unavailable {
-            reason: format!("model file not found: {resolved_model_path}"),
+            reason: SonoraError::ModelMissing(format!(
+                "model file not found: {resolved_model_path}"
+            )),
+        }
+    } else if blocks_path_sidecar(binary_path.as_deref(), spec.allow_path_sidecars) {
+        RuntimeTranscriber::Unavailable {
+            reason: path_sidecar_reason("whisper sidecar"),
         }
     } else if let Some(binary_path) = &binary_path {
-        let compute_backend = resolve_compute_backend(binary_path, spec.whisper_backend_preference);
+        let compute_backend = match &variant {
+            Some(choice) => map_variant_backend(choice.backend),
+            None => resolve_compute_backend(binary_path, spec.whisper_backend_preference),
+        };
         RuntimeTranscriber::Whisper(WhisperSidecarTranscriber {
             config: WhisperSidecarConfig {
                 binary_path: binary_path.clone(),
@@ -1137,11 +1589,16 @@ fn build_whisper_runtime(spec: EngineSpec) -> RuntimeEngine {
                 language: spec.language,
                 compute_backend,
                 threads: recommended_threads(spec.model_profile),
+                sandboxed,
+                gpu_device: spec.gpu_device_index,
+                translate: spec.translate,
             },
+            supervisor: Arc::new(Mutex::new(WorkerSupervisor::default())),
+            workspace: SessionWorkspace::new("whisper"),
         })
     } else {
         RuntimeTranscriber::Unavailable {
-            reason: "whisper sidecar binary not found".to_string(),
+            reason: SonoraError::BinaryMissing("whisper sidecar binary not found".to_string()),
         }
     };
 
@@ -1156,12 +1613,24 @@ fn build_whisper_runtime(spec: EngineSpec) -> RuntimeEngine {
             checked_binary_paths,
             resolved_model_path,
             model_exists,
+            binary_integrity,
+            gpu_device_index: match &transcriber {
+                RuntimeTranscriber::Whisper(runtime)
+                    if runtime.config.compute_backend == WhisperComputeBackend::Cuda =>
+                {
+                    runtime.config.gpu_device
+                }
+                _ => None,
+            },
+            sandbox,
         },
         transcriber,
     }
 }
 
 fn build_faster_whisper_runtime(spec: EngineSpec) -> RuntimeEngine {
+    let sandboxed = sandbox::sidecars_sandboxed(spec.sandbox_sidecars, spec.offline_only);
+    let sandbox = sandbox::status_label(sandboxed);
     let resolved_model_path = spec.model_path.to_string_lossy().to_string();
     let checked_binary_paths =
         resolve_faster_whisper_binary_candidates(spec.resource_dir.as_deref())
@@ -1169,6 +1638,9 @@ fn build_faster_whisper_runtime(spec: EngineSpec) -> RuntimeEngine {
             .map(|value| value.to_string_lossy().to_string())
             .collect::<Vec<_>>();
     let binary_path = resolve_faster_whisper_binary_path(spec.resource_dir.as_deref());
+    let binary_integrity = binary_path
+        .as_deref()
+        .map(|path| integrity::verify_sidecar_binary(path, FASTER_WHISPER_METADATA_FILE_NAME));
     let model_exists = is_resolvable_faster_whisper_model(&resolved_model_path);
     let resolved_model_reference = normalize_path_for_sidecar(&resolved_model_path);
     let cuda_runtime_ready = faster_whisper_cuda_runtime_ready();
@@ -1177,17 +1649,26 @@ fn build_faster_whisper_runtime(spec: EngineSpec) -> RuntimeEngine {
     let compute_type =
         resolve_faster_whisper_compute_type(device.as_str(), spec.faster_whisper_compute_type)
             .to_string();
-    let model_cache_dir = resolve_faster_whisper_model_cache_dir(spec.resource_dir.as_deref());
+    let model_cache_dir = resolve_faster_whisper_model_cache_dir(
+        spec.faster_whisper_cache_dir.as_deref(),
+        spec.resource_dir.as_deref(),
+    );
 
     let transcriber = if !model_exists {
         RuntimeTranscriber::Unavailable {
-            reason: format!("faster-whisper model target not found: {resolved_model_path}"),
+            reason: SonoraError::ModelMissing(format!(
+                "faster-whisper model target not found: {resolved_model_path}"
+            )),
         }
     } else if spec.whisper_backend_preference == WhisperBackendPreference::Cuda
         && !cuda_runtime_ready
     {
         RuntimeTranscriber::Unavailable {
-            reason: "CUDA backend requested for faster-whisper, but CUDA runtime libraries were not found (missing cublas64_12.dll). Install CUDA runtime or switch backend to auto/cpu.".to_string(),
+            reason: SonoraError::Unsupported("CUDA backend requested for faster-whisper, but CUDA runtime libraries were not found (missing cublas64_12.dll). Install CUDA runtime or switch backend to auto/cpu.".to_string()),
+        }
+    } else if blocks_path_sidecar(binary_path.as_deref(), spec.allow_path_sidecars) {
+        RuntimeTranscriber::Unavailable {
+            reason: path_sidecar_reason("faster-whisper worker"),
         }
     } else if let Some(binary_path) = &binary_path {
         RuntimeTranscriber::FasterWhisper(FasterWhisperSidecarTranscriber::new(
@@ -1200,13 +1681,17 @@ fn build_faster_whisper_runtime(spec: EngineSpec) -> RuntimeEngine {
                 compute_type,
                 beam_size: spec.faster_whisper_beam_size.clamp(1, 8),
                 condition_on_previous_text: true,
+                sandboxed,
+                offline: spec.offline_only,
+                translate: spec.translate,
             },
         ))
     } else {
         RuntimeTranscriber::Unavailable {
-            reason:
+            reason: SonoraError::BinaryMissing(
                 "faster-whisper worker binary not found (run pnpm sidecar:setup:faster-whisper)"
                     .to_string(),
+            ),
         }
     };
 
@@ -1221,18 +1706,26 @@ fn build_faster_whisper_runtime(spec: EngineSpec) -> RuntimeEngine {
             checked_binary_paths,
             resolved_model_path,
             model_exists,
+            binary_integrity,
+            gpu_device_index: None,
+            sandbox,
         },
         transcriber,
     }
 }
 
 fn build_parakeet_runtime(spec: EngineSpec) -> RuntimeEngine {
+    let sandboxed = sandbox::sidecars_sandboxed(spec.sandbox_sidecars, spec.offline_only);
+    let sandbox = sandbox::status_label(sandboxed);
     let resolved_model_path = spec.model_path.to_string_lossy().to_string();
     let checked_binary_paths = resolve_parakeet_binary_candidates(spec.resource_dir.as_deref())
         .into_iter()
         .map(|value| value.to_string_lossy().to_string())
         .collect::<Vec<_>>();
     let binary_path = resolve_parakeet_binary_path(spec.resource_dir.as_deref());
+    let binary_integrity = binary_path
+        .as_deref()
+        .map(|path| integrity::verify_sidecar_binary(path, PARAKEET_METADATA_FILE_NAME));
     let model_exists = is_resolvable_parakeet_model(&resolved_model_path);
     let resolved_model_reference = normalize_path_for_sidecar(&resolved_model_path);
     let device = resolve_parakeet_device(spec.whisper_backend_preference).to_string();
@@ -1242,16 +1735,29 @@ fn build_parakeet_runtime(spec: EngineSpec) -> RuntimeEngine {
 
     let transcriber = if !model_exists {
         RuntimeTranscriber::Unavailable {
-            reason: format!("parakeet model target not found: {resolved_model_path}"),
+            reason: SonoraError::ModelMissing(format!(
+                "parakeet model target not found: {resolved_model_path}"
+            )),
+        }
+    } else if spec.translate {
+        RuntimeTranscriber::Unavailable {
+            reason: SonoraError::Unsupported(
+                "parakeet cannot translate; use whisper.cpp or faster-whisper for translated captions"
+                    .to_string(),
+            ),
         }
     } else if !is_transformers_parakeet_model_supported(&resolved_model_reference) {
         RuntimeTranscriber::Unavailable {
-            reason: "parakeet model is not supported by the current Transformers sidecar (TDT/RNNT requires a NeMo-based worker). Use nvidia/parakeet-ctc-* models for now.".to_string(),
+            reason: SonoraError::Unsupported("parakeet model is not supported by the current Transformers sidecar (TDT/RNNT requires a NeMo-based worker). Use nvidia/parakeet-ctc-* models for now.".to_string()),
         }
     } else if spec.whisper_backend_preference == WhisperBackendPreference::Cuda && device != "cuda"
     {
         RuntimeTranscriber::Unavailable {
-            reason: "CUDA backend requested for parakeet, but no NVIDIA GPU was detected. Switch backend to auto/cpu or verify your GPU setup.".to_string(),
+            reason: SonoraError::Unsupported("CUDA backend requested for parakeet, but no NVIDIA GPU was detected. Switch backend to auto/cpu or verify your GPU setup.".to_string()),
+        }
+    } else if blocks_path_sidecar(binary_path.as_deref(), spec.allow_path_sidecars) {
+        RuntimeTranscriber::Unavailable {
+            reason: path_sidecar_reason("parakeet worker"),
         }
     } else if let Some(binary_path) = &binary_path {
         RuntimeTranscriber::Parakeet(ParakeetSidecarTranscriber::new(ParakeetSidecarConfig {
@@ -1261,11 +1767,17 @@ fn build_parakeet_runtime(spec: EngineSpec) -> RuntimeEngine {
             language: spec.language,
             device: device.clone(),
             compute_type,
+            sandboxed,
+            offline: spec.offline_only,
+            punctuation_model: spec
+                .punctuation_restoration
+                .then(|| DEFAULT_PUNCTUATION_MODEL.to_string()),
         }))
     } else {
         RuntimeTranscriber::Unavailable {
-            reason: "parakeet worker binary not found (run pnpm sidecar:setup:parakeet)"
-                .to_string(),
+            reason: SonoraError::BinaryMissing(
+                "parakeet worker binary not found (run pnpm sidecar:setup:parakeet)".to_string(),
+            ),
         }
     };
 
@@ -1280,6 +1792,30 @@ fn build_parakeet_runtime(spec: EngineSpec) -> RuntimeEngine {
             checked_binary_paths,
             resolved_model_path,
             model_exists,
+            binary_integrity,
+            gpu_device_index: None,
+            sandbox,
+        },
+        transcriber,
+    }
+}
+
+fn build_demo_runtime(spec: EngineSpec) -> RuntimeEngine {
+    let transcriber = RuntimeTranscriber::Stub(StubTranscriber);
+    RuntimeEngine {
+        diagnostics: RuntimeEngineDiagnostics {
+            ready: true,
+            active_engine: "demo".to_string(),
+            description: transcriber.description(),
+            compute_backend: transcriber.compute_backend_label(),
+            using_gpu: false,
+            resolved_binary_path: None,
+            checked_binary_paths: Vec::new(),
+            resolved_model_path: spec.model_path.to_string_lossy().to_string(),
+            model_exists: true,
+            binary_integrity: None,
+            gpu_device_index: None,
+            sandbox: sandbox::status_label(false),
         },
         transcriber,
     }
@@ -1407,7 +1943,14 @@ fn resolve_parakeet_compute_type(device: &str, preference: ParakeetComputeType)
     }
 }
 
-fn resolve_faster_whisper_model_cache_dir(resource_dir: Option<&Path>) -> PathBuf {
+pub fn resolve_faster_whisper_model_cache_dir(
+    override_dir: Option<&Path>,
+    resource_dir: Option<&Path>,
+) -> PathBuf {
+    if let Some(path) = override_dir {
+        return path.to_path_buf();
+    }
+
     let mut candidates = Vec::<PathBuf>::new();
 
     if let Some(resources) = resource_dir {
@@ -1563,13 +2106,59 @@ fn recommended_threads(profile: ModelProfile) -> usize {
     }
 }
 
+fn blocks_path_sidecar(binary_path: Option<&Path>, allow_path_sidecars: bool) -> bool {
+    !allow_path_sidecars && binary_path.is_some_and(integrity::is_path_resolved)
+}
+
+fn path_sidecar_reason(label: &str) -> SonoraError {
+    SonoraError::PermissionDenied(format!("{label} was only found on PATH; enable PATH sidecars in settings to run a non-bundled binary"))
+}
+
+fn effective_backend_preference(
+    backend_preference: WhisperBackendPreference,
+) -> WhisperBackendPreference {
+    parse_backend_preference(std::env::var(BACKEND_ENV_NAME).ok().as_deref())
+        .unwrap_or(backend_preference)
+}
+
+// Picks among bundled builds declared under `variants` in the metadata next to
+// the resolved binary. An explicit CUDA preference only accepts CUDA-capable
+// variants and otherwise falls back to the bundled binary itself.
+fn resolve_whisper_variant(
+    binary_path: &Path,
+    backend_preference: WhisperBackendPreference,
+) -> Option<VariantChoice> {
+    let metadata_path = metadata_path_for_binary(binary_path)?;
+    let metadata = sidecar_variants::load_metadata(&metadata_path)?;
+    if metadata.variants.is_empty() {
+        return None;
+    }
+    let (allow_cuda, require_cuda) = match effective_backend_preference(backend_preference) {
+        WhisperBackendPreference::Cpu => (false, false),
+        WhisperBackendPreference::Cuda => (true, true),
+        WhisperBackendPreference::Auto => (true, false),
+    };
+    sidecar_variants::choose_variant(
+        &metadata_path,
+        &metadata,
+        &sidecar_variants::detect_host(),
+        allow_cuda,
+        require_cuda,
+    )
+}
+
+fn map_variant_backend(backend: VariantBackend) -> WhisperComputeBackend {
+    match backend {
+        VariantBackend::Cpu => WhisperComputeBackend::Cpu,
+        VariantBackend::Cuda => WhisperComputeBackend::Cuda,
+    }
+}
+
 fn resolve_compute_backend(
     binary_path: &Path,
     backend_preference: WhisperBackendPreference,
 ) -> WhisperComputeBackend {
-    match parse_backend_preference(std::env::var(BACKEND_ENV_NAME).ok().as_deref())
-        .unwrap_or(backend_preference)
-    {
+    match effective_backend_preference(backend_preference) {
         WhisperBackendPreference::Cpu => WhisperComputeBackend::Cpu,
         WhisperBackendPreference::Cuda => WhisperComputeBackend::Cuda,
         WhisperBackendPreference::Auto => {
@@ -1602,10 +2191,15 @@ fn metadata_path_for_binary(binary_path: &Path) -> Option<PathBuf> {
         .map(|parent| parent.join(SIDECAR_METADATA_FILE_NAME))
 }
 
+// Declared `backends` are checked against the host; the older single `backend`
+// hint is trusted as written.
 fn read_metadata_backend(binary_path: &Path) -> Option<WhisperComputeBackend> {
     let metadata_path = metadata_path_for_binary(binary_path)?;
-    let raw = fs::read_to_string(metadata_path).ok()?;
-    let parsed = serde_json::from_str::<WhisperSidecarMetadata>(&raw).ok()?;
+    let parsed = sidecar_variants::load_metadata(&metadata_path)?;
+    if !parsed.backends.is_empty() {
+        return sidecar_variants::top_level_backend(&parsed, &sidecar_variants::detect_host())
+            .map(map_variant_backend);
+    }
     parse_backend_preference(parsed.backend.as_deref()).map(map_preference_to_compute_backend)
 }
 
@@ -1627,35 +2221,51 @@ fn has_nvidia_gpu() -> bool {
         .unwrap_or(false)
 }
 
-fn resolve_faster_whisper_binary_candidates(resource_dir: Option<&Path>) -> Vec<PathBuf> {
-    let binary_name = default_faster_whisper_binary_name();
-    let mut candidates = Vec::<PathBuf>::new();
-
-    if let Ok(override_path) = std::env::var(FASTER_WHISPER_BIN_ENV_NAME) {
-        let normalized = override_path.trim();
-        if !normalized.is_empty() {
-            candidates.push(PathBuf::from(normalized));
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SidecarBinary {
+    Whisper,
+    FasterWhisper,
+    Parakeet,
+    // whisper.cpp's model quantizer, only run by the model conversion task.
+    WhisperQuantize,
+}
+
+impl SidecarBinary {
+    pub const ALL: [SidecarBinary; 4] = [
+        SidecarBinary::Whisper,
+        SidecarBinary::FasterWhisper,
+        SidecarBinary::Parakeet,
+        SidecarBinary::WhisperQuantize,
+    ];
+
+    pub fn env_name(self) -> &'static str {
+        match self {
+            SidecarBinary::Whisper => WHISPER_BIN_ENV_NAME,
+            SidecarBinary::FasterWhisper => FASTER_WHISPER_BIN_ENV_NAME,
+            SidecarBinary::Parakeet => PARAKEET_BIN_ENV_NAME,
+            SidecarBinary::WhisperQuantize => WHISPER_QUANTIZE_BIN_ENV_NAME,
         }
     }
 
-    candidates.push(PathBuf::from("src-tauri/resources/bin").join(binary_name));
-    candidates.push(PathBuf::from("resources/bin").join(binary_name));
-
-    if let Some(resources) = resource_dir {
-        candidates.push(resources.join("bin").join(binary_name));
-        candidates.push(resources.join("resources").join("bin").join(binary_name));
-        candidates.push(resources.join(binary_name));
+    fn binary_name(self) -> &'static str {
+        match self {
+            SidecarBinary::Whisper => default_binary_name(),
+            SidecarBinary::FasterWhisper => default_faster_whisper_binary_name(),
+            SidecarBinary::Parakeet => default_parakeet_binary_name(),
+            SidecarBinary::WhisperQuantize => default_quantize_binary_name(),
+        }
     }
-
-    candidates.push(PathBuf::from(binary_name));
-    dedupe_paths(candidates)
 }
 
-fn resolve_parakeet_binary_candidates(resource_dir: Option<&Path>) -> Vec<PathBuf> {
-    let binary_name = default_parakeet_binary_name();
+pub fn sidecar_binary_candidates(
+    binary: SidecarBinary,
+    resource_dir: Option<&Path>,
+    probe: &dyn ResolutionProbe,
+) -> Vec<PathBuf> {
+    let binary_name = binary.binary_name();
     let mut candidates = Vec::<PathBuf>::new();
 
-    if let Ok(override_path) = std::env::var(PARAKEET_BIN_ENV_NAME) {
+    if let Some(override_path) = probe.env_var(binary.env_name()) {
         let normalized = override_path.trim();
         if !normalized.is_empty() {
             candidates.push(PathBuf::from(normalized));
@@ -1675,71 +2285,49 @@ fn resolve_parakeet_binary_candidates(resource_dir: Option<&Path>) -> Vec<PathBu
     dedupe_paths(candidates)
 }
 
-fn resolve_faster_whisper_binary_path(resource_dir: Option<&Path>) -> Option<PathBuf> {
-    let candidates = resolve_faster_whisper_binary_candidates(resource_dir);
-    for candidate in &candidates {
-        if candidate.components().count() == 1 {
-            return Some(candidate.clone());
-        }
-        if candidate.exists() {
-            return Some(candidate.clone());
-        }
-    }
-
-    None
+// A bare binary name is left for PATH lookup at spawn time.
+pub fn pick_sidecar_binary(candidates: &[PathBuf], probe: &dyn ResolutionProbe) -> Option<PathBuf> {
+    candidates
+        .iter()
+        .find(|candidate| candidate.components().count() == 1 || probe.exists(candidate))
+        .cloned()
 }
 
-fn resolve_parakeet_binary_path(resource_dir: Option<&Path>) -> Option<PathBuf> {
-    let candidates = resolve_parakeet_binary_candidates(resource_dir);
-    for candidate in &candidates {
-        if candidate.components().count() == 1 {
-            return Some(candidate.clone());
-        }
-        if candidate.exists() {
-            return Some(candidate.clone());
-        }
-    }
-
-    None
+fn resolve_faster_whisper_binary_candidates(resource_dir: Option<&Path>) -> Vec<PathBuf> {
+    sidecar_binary_candidates(SidecarBinary::FasterWhisper, resource_dir, &LiveProbe)
 }
 
-pub fn resolve_binary_candidates(resource_dir: Option<&Path>) -> Vec<PathBuf> {
-    let binary_name = default_binary_name();
-    let mut candidates = Vec::<PathBuf>::new();
-
-    if let Ok(override_path) = std::env::var("SONORA_WHISPER_BIN") {
-        let normalized = override_path.trim();
-        if !normalized.is_empty() {
-            candidates.push(PathBuf::from(normalized));
-        }
-    }
+fn resolve_parakeet_binary_candidates(resource_dir: Option<&Path>) -> Vec<PathBuf> {
+    sidecar_binary_candidates(SidecarBinary::Parakeet, resource_dir, &LiveProbe)
+}
 
-    candidates.push(PathBuf::from("src-tauri/resources/bin").join(binary_name));
-    candidates.push(PathBuf::from("resources/bin").join(binary_name));
+fn resolve_faster_whisper_binary_path(resource_dir: Option<&Path>) -> Option<PathBuf> {
+    pick_sidecar_binary(
+        &resolve_faster_whisper_binary_candidates(resource_dir),
+        &LiveProbe,
+    )
+}
 
-    if let Some(resources) = resource_dir {
-        candidates.push(resources.join("bin").join(binary_name));
-        candidates.push(resources.join("resources").join("bin").join(binary_name));
-        candidates.push(resources.join(binary_name));
-    }
+fn resolve_parakeet_binary_path(resource_dir: Option<&Path>) -> Option<PathBuf> {
+    pick_sidecar_binary(
+        &resolve_parakeet_binary_candidates(resource_dir),
+        &LiveProbe,
+    )
+}
 
-    candidates.push(PathBuf::from(binary_name));
-    dedupe_paths(candidates)
+pub fn resolve_binary_candidates(resource_dir: Option<&Path>) -> Vec<PathBuf> {
+    sidecar_binary_candidates(SidecarBinary::Whisper, resource_dir, &LiveProbe)
 }
 
 pub fn resolve_binary_path(resource_dir: Option<&Path>) -> Option<PathBuf> {
-    let candidates = resolve_binary_candidates(resource_dir);
-
-    for candidate in &candidates {
-        if candidate.components().count() == 1 {
-            return Some(candidate.clone());
-        }
-        if candidate.exists() {
-            return Some(candidate.clone());
-        }
-    }
+    pick_sidecar_binary(&resolve_binary_candidates(resource_dir), &LiveProbe)
+}
 
-    None
+pub fn resolve_quantize_binary_path(resource_dir: Option<&Path>) -> Option<PathBuf> {
+    pick_sidecar_binary(
+        &sidecar_binary_candidates(SidecarBinary::WhisperQuantize, resource_dir, &LiveProbe),
+        &LiveProbe,
+    )
 }
 
 fn default_binary_name() -> &'static str {
@@ -1766,6 +2354,14 @@ fn default_parakeet_binary_name() -> &'static str {
     }
 }
 
+fn default_quantize_binary_name() -> &'static str {
+    if cfg!(target_os = "windows") {
+        "whisper-quantize.exe"
+    } else {
+        "whisper-quantize"
+    }
+}
+
 fn extra_path_entries_from_env(var_name: &str) -> Vec<PathBuf> {
     let separator = if cfg!(target_os = "windows") {
         ';'
@@ -1853,14 +2449,6 @@ fn trim_context_prompt(context: Option<&str>) -> Option<String> {
     }
 }
 
-fn temporary_token() -> String {
-    let stamp = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .map(|duration| duration.as_millis())
-        .unwrap_or(0);
-    format!("{}-{stamp}", std::process::id())
-}
-
 fn write_wav_file(path: &Path, samples: &[f32]) -> Result<(), String> {
     let spec = hound::WavSpec {
         channels: 1,
@@ -1911,6 +2499,9 @@ mod tests {
             language: "en".to_string(),
             threads: 2,
             compute_backend: WhisperComputeBackend::Cpu,
+            sandboxed: false,
+            gpu_device: Some(1),
+            translate: false,
         };
         let args = config.command_args(Path::new("./tmp/chunk.wav"), Path::new("./tmp/out"));
 
@@ -1920,9 +2511,13 @@ mod tests {
         assert!(args.iter().any(|arg| arg == "-t"));
         assert!(args.iter().any(|arg| arg == "-np"));
         assert!(args.iter().any(|arg| arg == "-otxt"));
+        assert!(args.iter().any(|arg| arg == "-ojf"));
         assert!(args.iter().any(|arg| arg == "-of"));
         assert!(args.iter().any(|arg| arg == "en"));
         assert!(args.iter().any(|arg| arg == "-ng"));
+        assert!(!args.iter().any(|arg| arg == "-tr"));
+        // A CPU run ignores the GPU selection.
+        assert!(config.command_env().is_empty());
     }
 
     #[test]
@@ -1933,10 +2528,17 @@ mod tests {
             language: "en".to_string(),
             threads: 6,
             compute_backend: WhisperComputeBackend::Cuda,
+            sandboxed: false,
+            gpu_device: Some(1),
+            translate: true,
         };
 
         let args = config.command_args(Path::new("./tmp/chunk.wav"), Path::new("./tmp/out"));
         assert!(!args.iter().any(|arg| arg == "-ng"));
+        assert!(args.iter().any(|arg| arg == "-tr"));
+        assert!(config
+            .command_env()
+            .contains(&("CUDA_VISIBLE_DEVICES", "1".to_string())));
     }
 
     #[test]
@@ -1963,9 +2565,9 @@ mod tests {
 
     #[test]
     fn reads_sidecar_metadata_backend_hint() {
-        let token = temporary_token();
-        let dir = std::env::temp_dir().join(format!("sonora-sidecar-meta-{token}"));
-        fs::create_dir_all(&dir).expect("temp metadata directory should be created");
+        let session = crate::work_dir::SessionDir::create(&std::env::temp_dir(), "sidecar-meta")
+            .expect("temp metadata directory should be created");
+        let dir = session.path().to_path_buf();
 
         let binary = dir.join("whisper-cli");
         fs::write(&binary, "").expect("binary placeholder should be created");
@@ -1974,10 +2576,6 @@ mod tests {
 
         let backend = read_metadata_backend(&binary);
         assert_eq!(backend, Some(WhisperComputeBackend::Cuda));
-
-        let _ = fs::remove_file(metadata);
-        let _ = fs::remove_file(binary);
-        let _ = fs::remove_dir_all(dir);
     }
 
     #[test]
@@ -2001,7 +2599,8 @@ mod tests {
 
         match transcriber {
             RuntimeTranscriber::Unavailable { reason } => {
-                assert!(reason.contains("model file not found"));
+                assert_eq!(reason.code(), "model_missing");
+                assert!(reason.to_string().contains("model file not found"));
             }
             _ => panic!("expected unavailable transcriber"),
         }
@@ -2031,8 +2630,15 @@ mod tests {
             whisper_backend_preference: WhisperBackendPreference::Auto,
             faster_whisper_compute_type: FasterWhisperComputeType::Auto,
             faster_whisper_beam_size: 1,
+            faster_whisper_cache_dir: None,
             parakeet_compute_type: ParakeetComputeType::Auto,
             resource_dir: None,
+            sandbox_sidecars: false,
+            allow_path_sidecars: false,
+            offline_only: false,
+            punctuation_restoration: false,
+            gpu_device_index: None,
+            translate: false,
         });
 
         assert!(!runtime.diagnostics.ready);
@@ -2043,6 +2649,36 @@ mod tests {
             .contains("faster-whisper model target not found"));
     }
 
+    #[test]
+    fn demo_runtime_is_ready_without_model_or_sidecar() {
+        let runtime = build_runtime_engine(EngineSpec {
+            engine: SttEngine::Demo,
+            language: "en".to_string(),
+            model_profile: ModelProfile::Fast,
+            model_path: PathBuf::from("demo"),
+            whisper_backend_preference: WhisperBackendPreference::Auto,
+            faster_whisper_compute_type: FasterWhisperComputeType::Auto,
+            faster_whisper_beam_size: 1,
+            faster_whisper_cache_dir: None,
+            parakeet_compute_type: ParakeetComputeType::Auto,
+            resource_dir: None,
+            sandbox_sidecars: true,
+            allow_path_sidecars: false,
+            offline_only: true,
+            punctuation_restoration: false,
+            gpu_device_index: None,
+            translate: false,
+        });
+
+        assert!(runtime.diagnostics.ready);
+        assert_eq!(runtime.diagnostics.active_engine, "demo");
+        assert_eq!(runtime.transcriber.active_engine_label(), "demo");
+        assert_eq!(
+            runtime.transcriber.transcribe(&[0.1; 1_600]),
+            Ok("phase-1 transcript".to_string())
+        );
+    }
+
     #[test]
     fn faster_whisper_defaults_are_profile_aware() {
         assert_eq!(default_faster_whisper_model(ModelProfile::Fast), "tiny.en");
@@ -2076,8 +2712,15 @@ mod tests {
             whisper_backend_preference: WhisperBackendPreference::Auto,
             faster_whisper_compute_type: FasterWhisperComputeType::Auto,
             faster_whisper_beam_size: 1,
+            faster_whisper_cache_dir: None,
             parakeet_compute_type: ParakeetComputeType::Auto,
             resource_dir: None,
+            sandbox_sidecars: false,
+            allow_path_sidecars: false,
+            offline_only: false,
+            punctuation_restoration: false,
+            gpu_device_index: None,
+            translate: false,
         });
 
         assert!(!runtime.diagnostics.ready);
@@ -2098,8 +2741,15 @@ mod tests {
             whisper_backend_preference: WhisperBackendPreference::Auto,
             faster_whisper_compute_type: FasterWhisperComputeType::Auto,
             faster_whisper_beam_size: 1,
+            faster_whisper_cache_dir: None,
             parakeet_compute_type: ParakeetComputeType::Auto,
             resource_dir: None,
+            sandbox_sidecars: false,
+            allow_path_sidecars: false,
+            offline_only: false,
+            punctuation_restoration: false,
+            gpu_device_index: None,
+            translate: false,
         });
 
         assert!(!runtime.diagnostics.ready);
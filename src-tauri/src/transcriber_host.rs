@@ -0,0 +1,480 @@
+use serde::{Deserialize, Serialize};
+use std::io::{BufRead, BufReader, Write};
+use std::path::PathBuf;
+use std::process::{Child, ChildStdin, ChildStdout, Command, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+use crate::transcriber::{
+    build_runtime_engine, EngineSpec, RuntimeEngine, RuntimeTranscriber, Transcriber,
+};
+use crate::worker_supervisor::WorkerSupervisor;
+
+#[cfg(target_os = "windows")]
+use std::os::windows::process::CommandExt;
+
+#[cfg(target_os = "windows")]
+const CREATE_NO_WINDOW: u32 = 0x08000000;
+
+// The app binary started with this flag runs the transcriber host: it builds
+// the engine from the spec on its first stdin line and answers one JSON line
+// per request. A native crash in an engine or GPU driver then takes down the
+// host, not the app.
+pub const HOST_ARG: &str = "--transcriber-host";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+enum HostRequest {
+    Transcribe { samples: Vec<f32> },
+    SetContext { context: Option<String> },
+    Prepare,
+    Unload,
+    BeginSession,
+    EndSession,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+struct HostResponse {
+    text: Option<String>,
+    error: Option<String>,
+    confidence: Option<f32>,
+    worker_pid: Option<u32>,
+    worker_restarts: u32,
+}
+
+pub fn run_host(input: impl BufRead, mut output: impl Write) -> Result<(), String> {
+    let mut lines = input.lines();
+    let spec_line = lines
+        .next()
+        .ok_or_else(|| "transcriber host received no engine spec".to_string())?
+        .map_err(|error| format!("failed to read engine spec: {error}"))?;
+    let spec = serde_json::from_str::<EngineSpec>(&spec_line)
+        .map_err(|error| format!("invalid engine spec: {error}"))?;
+    let transcriber = build_runtime_engine(spec).transcriber;
+
+    for line in lines {
+        let line = line.map_err(|error| format!("failed to read host request: {error}"))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let response = match serde_json::from_str::<HostRequest>(&line) {
+            Ok(request) => handle_request(&transcriber, request),
+            Err(error) => HostResponse {
+                error: Some(format!("invalid transcriber host request: {error}")),
+                ..HostResponse::default()
+            },
+        };
+        let payload = serde_json::to_string(&response)
+            .map_err(|error| format!("failed to serialize host response: {error}"))?;
+        writeln!(output, "{payload}")
+            .and_then(|_| output.flush())
+            .map_err(|error| format!("failed to write host response: {error}"))?;
+    }
+
+    transcriber.unload();
+    Ok(())
+}
+
+pub fn run_from_stdio() -> i32 {
+    match run_host(std::io::stdin().lock(), std::io::stdout().lock()) {
+        Ok(()) => 0,
+        Err(error) => {
+            eprintln!("{error}");
+            1
+        }
+    }
+}
+
+fn handle_request(transcriber: &RuntimeTranscriber, request: HostRequest) -> HostResponse {
+    let result = match request {
+        HostRequest::Transcribe { samples } => transcriber.transcribe(&samples).map(Some),
+        HostRequest::SetContext { context } => {
+            transcriber.set_stream_context(context.as_deref());
+            Ok(None)
+        }
+        HostRequest::Prepare => transcriber.prepare().map(|_| None),
+        HostRequest::Unload => {
+            transcriber.unload();
+            Ok(None)
+        }
+        HostRequest::BeginSession => transcriber.begin_session().map(|_| None),
+        HostRequest::EndSession => {
+            transcriber.end_session();
+            Ok(None)
+        }
+    };
+    let (text, error) = match result {
+        Ok(text) => (text, None),
+        Err(error) => (None, Some(error)),
+    };
+    HostResponse {
+        text,
+        error,
+        confidence: transcriber.last_confidence(),
+        worker_pid: transcriber.worker_pid(),
+        worker_restarts: transcriber.worker_restarts(),
+    }
+}
+
+#[derive(Debug)]
+struct HostProcess {
+    child: Child,
+    stdin: ChildStdin,
+    stdout: BufReader<ChildStdout>,
+}
+
+impl HostProcess {
+    fn write_line<T: Serialize>(&mut self, value: &T) -> Result<(), String> {
+        let payload = serde_json::to_string(value)
+            .map_err(|error| format!("failed to serialize host request: {error}"))?;
+        self.stdin
+            .write_all(payload.as_bytes())
+            .and_then(|_| self.stdin.write_all(b"\n"))
+            .and_then(|_| self.stdin.flush())
+            .map_err(|error| format!("failed to write to transcriber host: {error}"))
+    }
+
+    // An Err means the host is gone; errors the engine reports come back in
+    // the response.
+    fn send(&mut self, request: &HostRequest) -> Result<HostResponse, String> {
+        self.write_line(request)?;
+        let mut line = String::new();
+        let bytes_read = self
+            .stdout
+            .read_line(&mut line)
+            .map_err(|error| format!("failed to read from transcriber host: {error}"))?;
+        if bytes_read == 0 {
+            return Err("transcriber host exited unexpectedly".to_string());
+        }
+        serde_json::from_str(line.trim())
+            .map_err(|error| format!("invalid transcriber host response: {error}"))
+    }
+}
+
+impl Drop for HostProcess {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+// Runs an engine inside a transcriber host process. `local` is the same engine
+// built in the app and only answers labels; inference always goes to the host.
+#[derive(Debug, Clone)]
+pub struct HostedTranscriber {
+    spec: EngineSpec,
+    program: PathBuf,
+    pub local: Box<RuntimeTranscriber>,
+    host: Arc<Mutex<Option<HostProcess>>>,
+    supervisor: Arc<Mutex<WorkerSupervisor>>,
+    context: Arc<Mutex<Option<String>>>,
+    prepared: Arc<AtomicBool>,
+    in_session: Arc<AtomicBool>,
+    last_response: Arc<Mutex<HostResponse>>,
+}
+
+impl HostedTranscriber {
+    pub fn new(spec: EngineSpec, program: PathBuf, local: RuntimeTranscriber) -> Self {
+        Self {
+            spec,
+            program,
+            local: Box::new(local),
+            host: Arc::new(Mutex::new(None)),
+            supervisor: Arc::new(Mutex::new(WorkerSupervisor::default())),
+            context: Arc::new(Mutex::new(None)),
+            prepared: Arc::new(AtomicBool::new(false)),
+            in_session: Arc::new(AtomicBool::new(false)),
+            last_response: Arc::new(Mutex::new(HostResponse::default())),
+        }
+    }
+
+    fn spawn_host(&self) -> Result<HostProcess, String> {
+        let mut command = Command::new(&self.program);
+        command
+            .arg(HOST_ARG)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null());
+
+        #[cfg(target_os = "windows")]
+        {
+            command.creation_flags(CREATE_NO_WINDOW);
+        }
+
+        let mut child = command.spawn().map_err(|error| {
+            format!(
+                "failed to launch transcriber host '{}': {error}",
+                self.program.to_string_lossy()
+            )
+        })?;
+        let stdin = child
+            .stdin
+            .take()
+            .ok_or_else(|| "transcriber host stdin not available".to_string())?;
+        let stdout = child
+            .stdout
+            .take()
+            .ok_or_else(|| "transcriber host stdout not available".to_string())?;
+        let mut host = HostProcess {
+            child,
+            stdin,
+            stdout: BufReader::new(stdout),
+        };
+        host.write_line(&self.spec)?;
+
+        // A restarted host picks the session up where the crashed one left it.
+        let context = self.context.lock().ok().and_then(|context| context.clone());
+        if context.is_some() {
+            host.send(&HostRequest::SetContext { context })?;
+        }
+        if self.in_session.load(Ordering::Relaxed) {
+            host.send(&HostRequest::BeginSession)?;
+        }
+        if self.prepared.load(Ordering::Relaxed) {
+            host.send(&HostRequest::Prepare)?;
+        }
+        Ok(host)
+    }
+
+    // Sends a request, restarting a host that died on it and replaying the
+    // request once.
+    fn request(&self, request: &HostRequest) -> Result<HostResponse, String> {
+        let mut guard = self
+            .host
+            .lock()
+            .map_err(|_| "failed to acquire transcriber host lock".to_string())?;
+        let mut host = match guard.take() {
+            Some(host) => host,
+            None => self.spawn_host()?,
+        };
+        let response = match host.send(request) {
+            Ok(response) => response,
+            Err(error) => {
+                drop(host);
+                let restart = self
+                    .supervisor
+                    .lock()
+                    .is_ok_and(|mut supervisor| supervisor.allow_restart(Instant::now()));
+                if !restart {
+                    return Err(format!(
+                        "transcriber host keeps crashing and was not restarted: {error}"
+                    ));
+                }
+                host = self.spawn_host()?;
+                host.send(request)?
+            }
+        };
+        *guard = Some(host);
+
+        if let Ok(mut last) = self.last_response.lock() {
+            *last = response.clone();
+        }
+        match response.error {
+            Some(error) => Err(error),
+            None => Ok(response),
+        }
+    }
+
+    fn host_pid(&self) -> Option<u32> {
+        self.host
+            .lock()
+            .ok()
+            .and_then(|guard| guard.as_ref().map(|host| host.child.id()))
+    }
+}
+
+impl Transcriber for HostedTranscriber {
+    fn transcribe(&self, samples: &[f32]) -> Result<String, String> {
+        self.request(&HostRequest::Transcribe {
+            samples: samples.to_vec(),
+        })
+        .map(|response| response.text.unwrap_or_default())
+    }
+
+    fn set_stream_context(&self, context: Option<&str>) {
+        let context = context.map(str::to_string);
+        if let Ok(mut guard) = self.context.lock() {
+            guard.clone_from(&context);
+        }
+        let _ = self.request(&HostRequest::SetContext { context });
+    }
+
+    fn last_confidence(&self) -> Option<f32> {
+        self.last_response
+            .lock()
+            .ok()
+            .and_then(|last| last.confidence)
+    }
+
+    fn prepare(&self) -> Result<(), String> {
+        self.prepared.store(true, Ordering::Relaxed);
+        self.request(&HostRequest::Prepare).map(|_| ())
+    }
+
+    // Stopping the host frees everything the engine loaded; the next request
+    // starts a fresh one.
+    fn unload(&self) {
+        self.prepared.store(false, Ordering::Relaxed);
+        if let Ok(mut guard) = self.host.lock() {
+            if let Some(mut host) = guard.take() {
+                let _ = host.send(&HostRequest::Unload);
+            }
+        }
+    }
+
+    fn begin_session(&self) -> Result<(), String> {
+        self.in_session.store(true, Ordering::Relaxed);
+        self.request(&HostRequest::BeginSession).map(|_| ())
+    }
+
+    fn end_session(&self) {
+        self.in_session.store(false, Ordering::Relaxed);
+        let _ = self.request(&HostRequest::EndSession);
+    }
+
+    // The resident sidecar when the engine has one, otherwise the host itself.
+    fn worker_pid(&self) -> Option<u32> {
+        let worker = self
+            .last_response
+            .lock()
+            .ok()
+            .and_then(|last| last.worker_pid);
+        worker.or_else(|| self.host_pid())
+    }
+
+    fn worker_restarts(&self) -> u32 {
+        let host_restarts = self
+            .supervisor
+            .lock()
+            .map(|supervisor| supervisor.restarts())
+            .unwrap_or(0);
+        let sidecar_restarts = self
+            .last_response
+            .lock()
+            .map(|last| last.worker_restarts)
+            .unwrap_or(0);
+        host_restarts.saturating_add(sidecar_restarts)
+    }
+
+    fn engine_label(&self) -> &'static str {
+        self.local.engine_label()
+    }
+
+    fn model_label(&self) -> String {
+        Transcriber::model_label(self.local.as_ref())
+    }
+
+    fn backend_label(&self) -> String {
+        self.local.backend_label()
+    }
+}
+
+// Moves sidecar engines into a host started from `program`; the demo engine
+// and an unavailable one have nothing to isolate.
+pub fn hosted_engine(runtime: RuntimeEngine, spec: EngineSpec, program: PathBuf) -> RuntimeEngine {
+    match runtime.transcriber {
+        RuntimeTranscriber::Whisper(_)
+        | RuntimeTranscriber::FasterWhisper(_)
+        | RuntimeTranscriber::Parakeet(_) => {
+            let transcriber = RuntimeTranscriber::Hosted(HostedTranscriber::new(
+                spec,
+                program,
+                runtime.transcriber,
+            ));
+            let mut diagnostics = runtime.diagnostics;
+            diagnostics.description = transcriber.description();
+            RuntimeEngine {
+                transcriber,
+                diagnostics,
+            }
+        }
+        _ => runtime,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{
+        FasterWhisperComputeType, ModelProfile, ParakeetComputeType, SttEngine,
+        WhisperBackendPreference,
+    };
+    use crate::transcriber::StubTranscriber;
+
+    fn demo_spec() -> EngineSpec {
+        EngineSpec {
+            engine: SttEngine::Demo,
+            language: "en".to_string(),
+            model_profile: ModelProfile::Fast,
+            model_path: PathBuf::from("demo.bin"),
+            whisper_backend_preference: WhisperBackendPreference::Auto,
+            faster_whisper_compute_type: FasterWhisperComputeType::Auto,
+            faster_whisper_beam_size: 1,
+            faster_whisper_cache_dir: None,
+            parakeet_compute_type: ParakeetComputeType::Auto,
+            resource_dir: None,
+            sandbox_sidecars: false,
+            allow_path_sidecars: false,
+            offline_only: false,
+            punctuation_restoration: false,
+            gpu_device_index: None,
+            translate: false,
+        }
+    }
+
+    #[test]
+    fn host_answers_one_line_per_request() {
+        let mut input = serde_json::to_string(&demo_spec()).expect("spec should serialize");
+        input.push_str("\n{\"op\":\"prepare\"}\n{\"op\":\"transcribe\",\"samples\":[0.1,0.2]}\n");
+        input.push_str("not json\n");
+        let mut output = Vec::new();
+        run_host(input.as_bytes(), &mut output).expect("host should run");
+
+        let responses = String::from_utf8(output)
+            .expect("responses should be utf-8")
+            .lines()
+            .map(|line| serde_json::from_str::<HostResponse>(line).expect("response json"))
+            .collect::<Vec<_>>();
+        assert_eq!(responses.len(), 3);
+        assert_eq!(responses[0].error, None);
+        assert_eq!(responses[1].text.as_deref(), Some("phase-1 transcript"));
+        assert!(responses[2].error.is_some());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn restarts_a_crashed_host_and_replays_the_request() {
+        use std::os::unix::fs::PermissionsExt;
+        use std::time::{SystemTime, UNIX_EPOCH};
+
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("clock should be set")
+            .as_nanos();
+        let dir = std::env::temp_dir().join(format!("sonora-host-{nanos}"));
+        std::fs::create_dir_all(&dir).expect("temp dir should be created");
+        let marker = dir.join("crashed");
+        let program = dir.join("host.sh");
+        // The first host dies after reading the spec; the second one answers.
+        let script = format!(
+            "#!/bin/sh\nread spec\nif [ ! -e '{marker}' ]; then touch '{marker}'; exit 1; fi\nread request\necho '{{\"text\":\"recovered\",\"worker_restarts\":0}}'\nread rest\n",
+            marker = marker.display()
+        );
+        std::fs::write(&program, script).expect("script should write");
+        std::fs::set_permissions(&program, std::fs::Permissions::from_mode(0o755))
+            .expect("script should be executable");
+
+        let hosted = HostedTranscriber::new(
+            demo_spec(),
+            program,
+            RuntimeTranscriber::Stub(StubTranscriber),
+        );
+        let result = hosted.transcribe(&[0.1, 0.2]);
+        drop(hosted.host.lock().expect("host lock").take());
+        let _ = std::fs::remove_dir_all(&dir);
+        assert_eq!(result.as_deref(), Ok("recovered"));
+        assert_eq!(hosted.worker_restarts(), 1);
+    }
+}
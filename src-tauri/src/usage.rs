@@ -0,0 +1,151 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::local_time;
+
+const HOURS_PER_DAY: usize = 24;
+const MS_PER_HOUR: u64 = 3_600_000;
+pub const PRELOAD_LEAD_MS: u64 = 10 * 60 * 1_000;
+pub const PRELOAD_MIN_SESSIONS: u32 = 3;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct UsagePattern {
+    pub hourly_sessions: Vec<u32>,
+    pub total_sessions: u64,
+    #[serde(default)]
+    pub last_session_unix_ms: Option<u64>,
+}
+
+impl Default for UsagePattern {
+    fn default() -> Self {
+        Self {
+            hourly_sessions: vec![0; HOURS_PER_DAY],
+            total_sessions: 0,
+            last_session_unix_ms: None,
+        }
+    }
+}
+
+impl UsagePattern {
+    pub fn record_session(&mut self, unix_ms: u64) {
+        self.hourly_sessions.resize(HOURS_PER_DAY, 0);
+        let bucket = hour_of_day(unix_ms);
+        self.hourly_sessions[bucket] = self.hourly_sessions[bucket].saturating_add(1);
+        self.total_sessions = self.total_sessions.saturating_add(1);
+        self.last_session_unix_ms = Some(unix_ms);
+    }
+
+    pub fn sessions_in_hour_of(&self, unix_ms: u64) -> u32 {
+        self.hourly_sessions
+            .get(hour_of_day(unix_ms))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    pub fn predicts_usage_soon(&self, now_unix_ms: u64, lead_ms: u64, min_sessions: u32) -> bool {
+        let upcoming = now_unix_ms.saturating_add(lead_ms);
+        self.sessions_in_hour_of(upcoming) >= min_sessions.max(1)
+    }
+}
+
+pub fn is_idle_for(last_activity_unix_ms: u64, now_unix_ms: u64, idle_ms: u64) -> bool {
+    idle_ms > 0 && now_unix_ms.saturating_sub(last_activity_unix_ms) >= idle_ms
+}
+
+// Habits follow the local clock, so the buckets stay put across DST changes
+// and travel.
+fn hour_of_day(unix_ms: u64) -> usize {
+    hour_of_day_at(unix_ms, local_time::utc_offset_secs(unix_ms))
+}
+
+fn hour_of_day_at(unix_ms: u64, offset_secs: i64) -> usize {
+    ((local_time::shift(unix_ms, offset_secs) / MS_PER_HOUR) % HOURS_PER_DAY as u64) as usize
+}
+
+pub fn default_usage_path() -> PathBuf {
+    let base = dirs::config_dir().unwrap_or_else(|| PathBuf::from("."));
+    base.join("sonora-dictation").join("usage-pattern.json")
+}
+
+pub fn load_or_default(path: &Path) -> UsagePattern {
+    match fs::read_to_string(path) {
+        Ok(contents) => serde_json::from_str::<UsagePattern>(&contents).unwrap_or_default(),
+        Err(_) => UsagePattern::default(),
+    }
+}
+
+pub fn save(path: &Path, pattern: &UsagePattern) -> Result<(), String> {
+    let parent = path
+        .parent()
+        .ok_or_else(|| "usage pattern path has no parent directory".to_string())?;
+    fs::create_dir_all(parent).map_err(io_to_string)?;
+    let payload = serde_json::to_string_pretty(pattern).map_err(|error| error.to_string())?;
+    fs::write(path, payload).map_err(io_to_string)
+}
+
+fn io_to_string(error: io::Error) -> String {
+    error.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    const NINE_AM: u64 = 9 * MS_PER_HOUR;
+    const DAY_MS: u64 = 24 * MS_PER_HOUR;
+
+    fn temp_file(name: &str) -> PathBuf {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("clock should be set")
+            .as_nanos();
+        std::env::temp_dir().join(format!("sonora-usage-{name}-{nanos}.json"))
+    }
+
+    #[test]
+    fn predicts_habitual_usage_shortly_before_the_hour() {
+        let mut pattern = UsagePattern::default();
+        for day in 0..3 {
+            pattern.record_session(day * DAY_MS + NINE_AM + 5 * 60 * 1_000);
+        }
+
+        let before_nine = 4 * DAY_MS + NINE_AM - 5 * 60 * 1_000;
+        assert!(pattern.predicts_usage_soon(before_nine, PRELOAD_LEAD_MS, PRELOAD_MIN_SESSIONS));
+        assert!(!pattern.predicts_usage_soon(
+            4 * DAY_MS + 14 * MS_PER_HOUR,
+            PRELOAD_LEAD_MS,
+            PRELOAD_MIN_SESSIONS
+        ));
+        assert!(!pattern.predicts_usage_soon(before_nine, PRELOAD_LEAD_MS, 4));
+    }
+
+    #[test]
+    fn buckets_sessions_by_local_hour() {
+        assert_eq!(hour_of_day_at(NINE_AM, 0), 9);
+        assert_eq!(hour_of_day_at(DAY_MS + NINE_AM, 2 * 3_600), 11);
+        assert_eq!(hour_of_day_at(DAY_MS + NINE_AM, -10 * 3_600), 23);
+    }
+
+    #[test]
+    fn detects_idle_periods_unless_disabled() {
+        assert!(is_idle_for(1_000, 61_000, 60_000));
+        assert!(!is_idle_for(1_000, 30_000, 60_000));
+        assert!(!is_idle_for(0, u64::MAX, 0));
+    }
+
+    #[test]
+    fn persists_usage_pattern() {
+        let path = temp_file("pattern");
+        let mut pattern = UsagePattern::default();
+        pattern.record_session(NINE_AM);
+        save(&path, &pattern).expect("pattern should save");
+
+        let loaded = load_or_default(&path);
+        let _ = fs::remove_file(&path);
+        assert_eq!(loaded, pattern);
+        assert_eq!(loaded.sessions_in_hour_of(NINE_AM + 1_000), 1);
+    }
+}
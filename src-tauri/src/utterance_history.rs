@@ -0,0 +1,336 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::insertion::{normalize_session_label, InsertionRecord, InsertionStatus};
+use crate::utterance_routing::UtteranceEngine;
+
+pub const MAX_UTTERANCES: usize = 200;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum UtteranceOutcome {
+    // Transcribed only; auto-insert was off.
+    Transcribed,
+    // Typed live through instant-word edits.
+    Typed,
+    HeldForReview,
+    Inserting,
+    Inserted,
+    InsertionFailed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct UtteranceEntry {
+    pub id: u64,
+    pub text: String,
+    pub started_unix_ms: u64,
+    pub ended_unix_ms: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session_label: Option<String>,
+    pub outcome: UtteranceOutcome,
+    #[serde(default)]
+    pub insertion_ids: Vec<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub insertion_status: Option<InsertionStatus>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub audio_path: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub engine: Option<UtteranceEngine>,
+}
+
+// An utterance joined with whichever of its insertion records are still in
+// the (shorter) insertion history.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct UtteranceView {
+    #[serde(flatten)]
+    pub entry: UtteranceEntry,
+    pub insertions: Vec<InsertionRecord>,
+}
+
+pub fn next_utterance_id(entries: &[UtteranceEntry]) -> u64 {
+    entries
+        .iter()
+        .map(|entry| entry.id)
+        .max()
+        .unwrap_or(0)
+        .saturating_add(1)
+}
+
+// Returns the entries pushed out of the history so their audio can be removed.
+pub fn append_entry(
+    entries: &mut Vec<UtteranceEntry>,
+    entry: UtteranceEntry,
+) -> Vec<UtteranceEntry> {
+    entries.insert(0, entry);
+    if entries.len() > MAX_UTTERANCES {
+        entries.split_off(MAX_UTTERANCES)
+    } else {
+        Vec::new()
+    }
+}
+
+pub fn set_outcome(entries: &mut [UtteranceEntry], id: u64, outcome: UtteranceOutcome) -> bool {
+    match entries.iter_mut().find(|entry| entry.id == id) {
+        Some(entry) => {
+            entry.outcome = outcome;
+            true
+        }
+        None => false,
+    }
+}
+
+// The latest attempt decides the outcome, so a successful retry clears an earlier failure.
+pub fn record_insertion(entries: &mut [UtteranceEntry], record: &InsertionRecord) -> bool {
+    let Some(entry) = record
+        .utterance_id
+        .and_then(|id| entries.iter_mut().find(|entry| entry.id == id))
+    else {
+        return false;
+    };
+    if !entry.insertion_ids.contains(&record.id) {
+        entry.insertion_ids.push(record.id);
+    }
+    entry.insertion_status = Some(record.status);
+    entry.outcome = if record.status == InsertionStatus::Failure {
+        UtteranceOutcome::InsertionFailed
+    } else {
+        UtteranceOutcome::Inserted
+    };
+    true
+}
+
+pub fn view(entry: &UtteranceEntry, records: &[InsertionRecord]) -> UtteranceView {
+    UtteranceView {
+        entry: entry.clone(),
+        insertions: records
+            .iter()
+            .filter(|record| entry.insertion_ids.contains(&record.id))
+            .cloned()
+            .collect(),
+    }
+}
+
+pub fn search<'a>(
+    entries: &'a [UtteranceEntry],
+    query: Option<&str>,
+    session_label: Option<&str>,
+) -> Vec<&'a UtteranceEntry> {
+    let query = query
+        .map(|value| value.trim().to_lowercase())
+        .filter(|value| !value.is_empty());
+    let session_label = normalize_session_label(session_label);
+    entries
+        .iter()
+        .filter(|entry| match &session_label {
+            Some(label) => entry
+                .session_label
+                .as_deref()
+                .is_some_and(|value| value.eq_ignore_ascii_case(label)),
+            None => true,
+        })
+        .filter(|entry| match &query {
+            Some(query) => entry.text.to_lowercase().contains(query),
+            None => true,
+        })
+        .collect()
+}
+
+pub fn default_history_path() -> PathBuf {
+    let base = dirs::config_dir().unwrap_or_else(|| PathBuf::from("."));
+    base.join("sonora-dictation").join("utterances.json")
+}
+
+pub fn audio_path_for(history_path: &Path, id: u64) -> PathBuf {
+    history_path
+        .with_file_name("utterance-audio")
+        .join(format!("utterance-{id}.wav"))
+}
+
+pub fn write_audio(path: &Path, samples: &[f32]) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(io_to_string)?;
+    }
+    let spec = hound::WavSpec {
+        channels: 1,
+        sample_rate: 16_000,
+        bits_per_sample: 16,
+        sample_format: hound::SampleFormat::Int,
+    };
+    let mut writer = hound::WavWriter::create(path, spec).map_err(|error| error.to_string())?;
+    for sample in samples {
+        let value = (sample.clamp(-1.0, 1.0) * f32::from(i16::MAX)) as i16;
+        writer
+            .write_sample(value)
+            .map_err(|error| error.to_string())?;
+    }
+    writer.finalize().map_err(|error| error.to_string())
+}
+
+pub fn default_export_path(id: u64, created_unix_ms: u64) -> PathBuf {
+    let base = dirs::config_dir().unwrap_or_else(|| PathBuf::from("."));
+    base.join("sonora-dictation")
+        .join("exports")
+        .join(format!("utterance-{id}-{created_unix_ms}.wav"))
+}
+
+// Copies an utterance's retained clip out of the history directory. A target
+// that is an existing directory gets the clip under its default file name.
+pub fn export_audio(
+    entry: &UtteranceEntry,
+    target: &Path,
+    created_unix_ms: u64,
+) -> Result<PathBuf, String> {
+    let source = entry
+        .audio_path
+        .as_deref()
+        .map(Path::new)
+        .filter(|path| path.exists())
+        .ok_or_else(|| format!("utterance {} has no retained audio", entry.id))?;
+    let target = if target.is_dir() {
+        let name = default_export_path(entry.id, created_unix_ms);
+        target.join(name.file_name().unwrap_or_default())
+    } else if target.extension().is_none() {
+        target.with_extension("wav")
+    } else {
+        target.to_path_buf()
+    };
+    if let Some(parent) = target.parent() {
+        fs::create_dir_all(parent).map_err(io_to_string)?;
+    }
+    fs::copy(source, &target).map_err(io_to_string)?;
+    Ok(target)
+}
+
+pub fn remove_audio(entries: &[UtteranceEntry]) {
+    for path in entries
+        .iter()
+        .filter_map(|entry| entry.audio_path.as_deref())
+    {
+        let _ = fs::remove_file(path);
+    }
+}
+
+pub fn load_or_default(path: &Path) -> Vec<UtteranceEntry> {
+    let mut entries = match fs::read_to_string(path) {
+        Ok(contents) => serde_json::from_str::<Vec<UtteranceEntry>>(&contents).unwrap_or_default(),
+        Err(_) => Vec::new(),
+    };
+    entries.truncate(MAX_UTTERANCES);
+    entries
+}
+
+pub fn save(path: &Path, entries: &[UtteranceEntry]) -> Result<(), String> {
+    let parent = path
+        .parent()
+        .ok_or_else(|| "utterance history path has no parent directory".to_string())?;
+    fs::create_dir_all(parent).map_err(io_to_string)?;
+    let payload = serde_json::to_string_pretty(entries).map_err(|error| error.to_string())?;
+    fs::write(path, payload).map_err(io_to_string)
+}
+
+fn io_to_string(error: io::Error) -> String {
+    error.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    fn temp_path(name: &str) -> PathBuf {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("clock should be set")
+            .as_nanos();
+        std::env::temp_dir().join(format!("sonora-utterances-{name}-{nanos}"))
+    }
+
+    fn entry(id: u64, text: &str) -> UtteranceEntry {
+        UtteranceEntry {
+            id,
+            text: text.to_string(),
+            started_unix_ms: 0,
+            ended_unix_ms: 0,
+            language: None,
+            confidence: None,
+            session_label: None,
+            outcome: UtteranceOutcome::Inserting,
+            insertion_ids: Vec::new(),
+            insertion_status: None,
+            audio_path: None,
+            engine: None,
+        }
+    }
+
+    #[test]
+    fn insertions_and_retries_resolve_onto_their_utterance() {
+        let mut entries = vec![entry(2, "send the invoice"), entry(1, "hello there")];
+        let failed = InsertionRecord {
+            utterance_id: Some(2),
+            ..InsertionRecord::new(7, "send the invoice".to_string(), InsertionStatus::Failure)
+        };
+        assert!(record_insertion(&mut entries, &failed));
+        assert_eq!(entries[0].outcome, UtteranceOutcome::InsertionFailed);
+
+        let retried = InsertionRecord {
+            retry_of: Some(7),
+            utterance_id: Some(2),
+            ..InsertionRecord::new(8, "send the invoice".to_string(), InsertionStatus::Success)
+        };
+        assert!(record_insertion(&mut entries, &retried));
+        assert_eq!(entries[0].insertion_ids, vec![7, 8]);
+        assert_eq!(entries[0].outcome, UtteranceOutcome::Inserted);
+
+        let typed = InsertionRecord::new(9, "typed".to_string(), InsertionStatus::Success);
+        assert!(!record_insertion(&mut entries, &typed));
+
+        let joined = view(&entries[0], &[retried.clone(), typed]);
+        assert_eq!(joined.insertions, vec![retried]);
+        assert_eq!(search(&entries, Some("INVOICE"), None).len(), 1);
+    }
+
+    #[test]
+    fn caps_history_and_returns_evicted_entries() {
+        let mut entries = Vec::new();
+        for id in 1..=MAX_UTTERANCES as u64 {
+            assert!(append_entry(&mut entries, entry(id, "words")).is_empty());
+        }
+        let next = next_utterance_id(&entries);
+        let evicted = append_entry(&mut entries, entry(next, "newest"));
+        assert_eq!(entries.len(), MAX_UTTERANCES);
+        assert_eq!(entries[0].id, MAX_UTTERANCES as u64 + 1);
+        assert_eq!(evicted.len(), 1);
+        assert_eq!(evicted[0].id, 1);
+        assert_eq!(
+            audio_path_for(Path::new("/cfg/sonora-dictation/utterances.json"), 3),
+            PathBuf::from("/cfg/sonora-dictation/utterance-audio/utterance-3.wav")
+        );
+    }
+
+    #[test]
+    fn exports_retained_audio_as_a_wav_clip() {
+        let dir = temp_path("export");
+        let mut clip = entry(4, "misheard words");
+        assert!(export_audio(&clip, &dir.join("clip.wav"), 10).is_err());
+
+        let stored = dir.join("utterance-audio").join("utterance-4.wav");
+        write_audio(&stored, &[0.25; 1_600]).expect("clip should be written");
+        clip.audio_path = Some(stored.to_string_lossy().to_string());
+
+        let named = export_audio(&clip, &dir.join("out").join("report"), 10).expect("export");
+        assert_eq!(named, dir.join("out").join("report.wav"));
+        let reader = hound::WavReader::open(&named).expect("exported clip is a wav");
+        assert_eq!(reader.spec().sample_rate, 16_000);
+        assert_eq!(reader.len(), 1_600);
+
+        let into_dir = export_audio(&clip, &dir.join("out"), 10).expect("export into dir");
+        assert_eq!(into_dir, dir.join("out").join("utterance-4-10.wav"));
+        let _ = fs::remove_dir_all(dir);
+    }
+}
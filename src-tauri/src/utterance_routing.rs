@@ -0,0 +1,231 @@
+use serde::{Deserialize, Serialize};
+
+use crate::config::{AppSettings, ModelProfile, SttEngine, UtteranceRouting};
+use crate::model_catalog;
+use crate::transcriber::Transcriber;
+
+pub const SHORT_MAX_MS_MIN: u32 = 500;
+pub const SHORT_MAX_MS_MAX: u32 = 30_000;
+const COLD_START_ENGLISH_MODEL: &str = "tiny-en-q8";
+const COLD_START_MULTILINGUAL_MODEL: &str = "tiny-q8";
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum UtteranceRoute {
+    // Routing was off; the live chunks are the utterance.
+    Live,
+    // Short enough to keep the live engine's text.
+    Short,
+    // Transcribed again as a whole by the accurate engine.
+    Long,
+    // Answered by the resident tiny model while the configured one warmed up.
+    ColdStart,
+}
+
+// Which engine produced an utterance's final text, kept in its history entry.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct UtteranceEngine {
+    pub engine: SttEngine,
+    pub model_profile: ModelProfile,
+    pub route: UtteranceRoute,
+    pub audio_ms: u64,
+}
+
+// Buffered utterance audio is always 16 kHz.
+pub fn audio_duration_ms(samples: usize) -> u64 {
+    samples as u64 * 1_000 / 16_000
+}
+
+pub fn route_for(routing: &UtteranceRouting, audio_ms: u64) -> UtteranceRoute {
+    if !routing.enabled {
+        UtteranceRoute::Live
+    } else if audio_ms <= u64::from(routing.short_max_ms) {
+        UtteranceRoute::Short
+    } else {
+        UtteranceRoute::Long
+    }
+}
+
+// Settings for the long-utterance engine, or None when routing is off or the
+// long route would run exactly what the live session already runs.
+pub fn long_route_settings(settings: &AppSettings) -> Option<AppSettings> {
+    let routing = settings.utterance_routing;
+    let engine = routing.long_engine.unwrap_or(settings.stt_engine);
+    if !routing.enabled
+        || (engine == settings.stt_engine && routing.long_model_profile == settings.model_profile)
+    {
+        return None;
+    }
+    Some(AppSettings {
+        stt_engine: engine,
+        model_profile: routing.long_model_profile,
+        ..settings.clone()
+    })
+}
+
+// Settings for the bundled tiny whisper.cpp model that answers while the
+// configured model warms up, or None when the fast path is off or the
+// configured model is already a tiny whisper.cpp one.
+pub fn cold_start_settings(settings: &AppSettings) -> Option<AppSettings> {
+    if !settings.cold_start_fast_path || settings.stt_engine == SttEngine::Demo {
+        return None;
+    }
+    let configured_alias = match settings.model_path.as_deref() {
+        Some(path) => model_catalog::find_alias(path).map(|entry| entry.alias),
+        // The Fast profile's default is the tiny English model.
+        None => (settings.model_profile == ModelProfile::Fast).then_some(COLD_START_ENGLISH_MODEL),
+    };
+    if settings.stt_engine == SttEngine::WhisperCpp
+        && configured_alias.is_some_and(|alias| alias.starts_with("tiny"))
+    {
+        return None;
+    }
+    let model = if settings.language.trim().eq_ignore_ascii_case("en") {
+        COLD_START_ENGLISH_MODEL
+    } else {
+        COLD_START_MULTILINGUAL_MODEL
+    };
+    Some(AppSettings {
+        stt_engine: SttEngine::WhisperCpp,
+        model_profile: ModelProfile::Fast,
+        model_path: Some(model.to_string()),
+        ..settings.clone()
+    })
+}
+
+pub fn cold_start_engine(audio_ms: u64) -> UtteranceEngine {
+    UtteranceEngine {
+        engine: SttEngine::WhisperCpp,
+        model_profile: ModelProfile::Fast,
+        route: UtteranceRoute::ColdStart,
+        audio_ms,
+    }
+}
+
+// Holds the accurate engine for a capture session so each long utterance does
+// not pay a model load.
+pub struct LongRouteEngine<T: Transcriber> {
+    transcriber: T,
+    engine: SttEngine,
+    model_profile: ModelProfile,
+    short_max_ms: u32,
+}
+
+impl<T: Transcriber> LongRouteEngine<T> {
+    pub fn new(long_settings: &AppSettings, transcriber: T) -> Self {
+        Self {
+            transcriber,
+            engine: long_settings.stt_engine,
+            model_profile: long_settings.model_profile,
+            short_max_ms: long_settings.utterance_routing.short_max_ms,
+        }
+    }
+
+    pub fn routes(&self, audio_ms: u64) -> bool {
+        audio_ms > u64::from(self.short_max_ms)
+    }
+
+    pub fn engine(&self, audio_ms: u64) -> UtteranceEngine {
+        UtteranceEngine {
+            engine: self.engine,
+            model_profile: self.model_profile,
+            route: UtteranceRoute::Long,
+            audio_ms,
+        }
+    }
+
+    // Raw engine text with its confidence; None when the engine heard nothing.
+    pub fn transcribe(&self, audio: &[f32]) -> Result<Option<(String, Option<f32>)>, String> {
+        let text = self.transcriber.transcribe(audio)?;
+        let text = text.trim();
+        Ok((!text.is_empty()).then(|| (text.to_string(), self.transcriber.last_confidence())))
+    }
+
+    pub fn unload(&self) {
+        self.transcriber.unload();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transcriber::StubTranscriber;
+
+    #[test]
+    fn routes_by_buffered_audio_length() {
+        let mut routing = UtteranceRouting::default();
+        assert_eq!(route_for(&routing, 10_000), UtteranceRoute::Live);
+
+        routing.enabled = true;
+        assert_eq!(routing.short_max_ms, 3_000);
+        assert_eq!(
+            route_for(&routing, audio_duration_ms(2 * 16_000)),
+            UtteranceRoute::Short
+        );
+        assert_eq!(
+            route_for(&routing, audio_duration_ms(48_000)),
+            UtteranceRoute::Short
+        );
+        assert_eq!(
+            route_for(&routing, audio_duration_ms(48_016)),
+            UtteranceRoute::Long
+        );
+    }
+
+    #[test]
+    fn long_route_swaps_engine_and_profile_only_when_it_differs() {
+        let mut settings = AppSettings {
+            stt_engine: SttEngine::WhisperCpp,
+            model_profile: ModelProfile::Fast,
+            ..AppSettings::default()
+        };
+        assert!(long_route_settings(&settings).is_none());
+
+        settings.utterance_routing.enabled = true;
+        let long = long_route_settings(&settings).expect("balanced long route");
+        assert_eq!(long.stt_engine, SttEngine::WhisperCpp);
+        assert_eq!(long.model_profile, ModelProfile::Balanced);
+
+        settings.model_profile = ModelProfile::Balanced;
+        assert!(long_route_settings(&settings).is_none());
+        settings.utterance_routing.long_engine = Some(SttEngine::FasterWhisper);
+        let long = long_route_settings(&settings).expect("gpu long route");
+        assert_eq!(long.stt_engine, SttEngine::FasterWhisper);
+
+        let engine = LongRouteEngine::new(&long, StubTranscriber);
+        assert!(!engine.routes(3_000));
+        assert!(engine.routes(3_001));
+        assert_eq!(engine.engine(4_000).route, UtteranceRoute::Long);
+        let (text, _) = engine
+            .transcribe(&[0.1; 16_000])
+            .expect("stub transcribes")
+            .expect("stub returns text");
+        assert_eq!(text, "phase-1 transcript");
+    }
+
+    #[test]
+    fn cold_start_uses_a_tiny_model_matching_the_language() {
+        let mut settings = AppSettings {
+            stt_engine: SttEngine::FasterWhisper,
+            model_profile: ModelProfile::Balanced,
+            ..AppSettings::default()
+        };
+        let cold = cold_start_settings(&settings).expect("faster-whisper warms up slowly");
+        assert_eq!(cold.stt_engine, SttEngine::WhisperCpp);
+        assert_eq!(cold.model_path.as_deref(), Some("tiny-en-q8"));
+
+        settings.language = "de".to_string();
+        let cold = cold_start_settings(&settings).expect("multilingual fallback");
+        assert_eq!(cold.model_path.as_deref(), Some("tiny-q8"));
+
+        settings.stt_engine = SttEngine::WhisperCpp;
+        settings.model_path = Some("tiny-q8".to_string());
+        assert!(cold_start_settings(&settings).is_none());
+        settings.model_path = None;
+        settings.model_profile = ModelProfile::Fast;
+        assert!(cold_start_settings(&settings).is_none());
+        settings.model_profile = ModelProfile::Balanced;
+        settings.cold_start_fast_path = false;
+        assert!(cold_start_settings(&settings).is_none());
+    }
+}
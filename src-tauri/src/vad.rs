@@ -35,6 +35,108 @@ pub fn has_speech(samples: &[f32], config: &VadConfig) -> bool {
         .any(|chunk| chunk_rms(chunk) >= config.rms_threshold)
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SpeechSegment {
+    pub start_sample: usize,
+    pub end_sample: usize,
+}
+
+impl SpeechSegment {
+    pub fn len(&self) -> usize {
+        self.end_sample.saturating_sub(self.start_sample)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+pub fn detect_speech_segments(samples: &[f32], config: &VadConfig) -> Vec<SpeechSegment> {
+    if samples.is_empty() {
+        return Vec::new();
+    }
+
+    if !config.enabled {
+        return vec![SpeechSegment {
+            start_sample: 0,
+            end_sample: samples.len(),
+        }];
+    }
+
+    if samples.len() < config.min_samples {
+        return Vec::new();
+    }
+
+    let window = config
+        .window_samples
+        .max(config.min_samples)
+        .min(samples.len());
+    let mut segments = Vec::<SpeechSegment>::new();
+    for (index, chunk) in samples.chunks(window).enumerate() {
+        if chunk_rms(chunk) < config.rms_threshold {
+            continue;
+        }
+
+        let start_sample = index * window;
+        let end_sample = start_sample + chunk.len();
+        match segments.last_mut() {
+            Some(previous) if previous.end_sample == start_sample => {
+                previous.end_sample = end_sample;
+            }
+            _ => segments.push(SpeechSegment {
+                start_sample,
+                end_sample,
+            }),
+        }
+    }
+
+    segments
+}
+
+pub fn speech_sample_count(segments: &[SpeechSegment]) -> usize {
+    segments.iter().map(SpeechSegment::len).sum()
+}
+
+pub fn last_silence_boundary(
+    segments: &[SpeechSegment],
+    min_sample: usize,
+    max_sample: usize,
+) -> Option<usize> {
+    if min_sample > max_sample {
+        return None;
+    }
+
+    let mut gap_end = usize::MAX;
+    for segment in segments.iter().rev() {
+        if let Some(boundary) = boundary_in_gap(segment.end_sample, gap_end, min_sample, max_sample)
+        {
+            return Some(boundary);
+        }
+        gap_end = segment.start_sample;
+    }
+
+    boundary_in_gap(0, gap_end, min_sample, max_sample)
+}
+
+fn boundary_in_gap(
+    gap_start: usize,
+    gap_end: usize,
+    min_sample: usize,
+    max_sample: usize,
+) -> Option<usize> {
+    let low = gap_start.max(min_sample);
+    let high = gap_end.min(max_sample);
+    if low >= high {
+        return None;
+    }
+
+    if gap_end > max_sample {
+        Some(max_sample)
+    } else {
+        Some(low + (high - low) / 2)
+    }
+}
+
 fn chunk_rms(samples: &[f32]) -> f32 {
     let energy_sum = samples.iter().map(|value| value * value).sum::<f32>();
     (energy_sum / samples.len() as f32).sqrt()
@@ -99,6 +201,85 @@ mod tests {
         assert!(!has_speech(&noise, &config));
     }
 
+    #[test]
+    fn merges_adjacent_voiced_windows_into_segments() {
+        let config = VadConfig::default();
+        let mut chunk = vec![0.0_f32; 8_192];
+        for sample in chunk.iter_mut().skip(1_024).take(1_024) {
+            *sample = 0.05;
+        }
+        for sample in chunk.iter_mut().skip(5_120).take(512) {
+            *sample = 0.05;
+        }
+
+        let segments = detect_speech_segments(&chunk, &config);
+        assert_eq!(
+            segments,
+            vec![
+                SpeechSegment {
+                    start_sample: 1_024,
+                    end_sample: 2_048,
+                },
+                SpeechSegment {
+                    start_sample: 5_120,
+                    end_sample: 5_632,
+                },
+            ]
+        );
+        assert_eq!(speech_sample_count(&segments), 1_536);
+    }
+
+    #[test]
+    fn picks_latest_silence_gap_inside_window() {
+        let segments = vec![
+            SpeechSegment {
+                start_sample: 0,
+                end_sample: 10_000,
+            },
+            SpeechSegment {
+                start_sample: 12_000,
+                end_sample: 20_000,
+            },
+            SpeechSegment {
+                start_sample: 21_000,
+                end_sample: 30_000,
+            },
+        ];
+
+        assert_eq!(
+            last_silence_boundary(&segments, 8_000, 30_000),
+            Some(20_500)
+        );
+        assert_eq!(
+            last_silence_boundary(&segments, 8_000, 19_000),
+            Some(11_000)
+        );
+        assert_eq!(
+            last_silence_boundary(&segments, 8_000, 40_000),
+            Some(40_000)
+        );
+    }
+
+    #[test]
+    fn reports_no_boundary_for_continuous_speech() {
+        let segments = vec![SpeechSegment {
+            start_sample: 0,
+            end_sample: 32_000,
+        }];
+        assert!(last_silence_boundary(&segments, 8_000, 32_000).is_none());
+    }
+
+    #[test]
+    fn disabled_vad_reports_single_full_segment() {
+        let config = VadConfig {
+            enabled: false,
+            ..VadConfig::default()
+        };
+        let segments = detect_speech_segments(&vec![0.0_f32; 2_000], &config);
+        assert_eq!(segments.len(), 1);
+        assert_eq!(segments[0].len(), 2_000);
+    }
+
     #[test]
     fn allows_all_chunks_when_disabled() {
         let mut config = VadConfig::default();
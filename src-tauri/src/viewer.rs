@@ -0,0 +1,519 @@
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr, TcpListener, TcpStream, UdpSocket};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+// Late joiners get this much history before the live stream.
+const RECENT_FINALS: usize = 50;
+// A meeting room has a handful of screens; more than this is likely a mistake.
+const MAX_VIEWERS: usize = 16;
+// Every open stream holds a thread, so page and transcript loads only get a
+// little headroom above the viewers.
+const MAX_CONNECTIONS: usize = MAX_VIEWERS + 8;
+// Keeps idle streams alive through proxies and notices closed tabs.
+const KEEPALIVE: Duration = Duration::from_secs(15);
+const ACCEPT_POLL: Duration = Duration::from_millis(200);
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+const MAX_REQUEST_BYTES: usize = 8 * 1024;
+
+const VIEWER_PAGE: &str = r#"<!doctype html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<meta name="viewport" content="width=device-width, initial-scale=1">
+<title>Sonora captions</title>
+<style>
+  html, body { margin: 0; height: 100%; background: #0b0d10; color: #f4f6f8; font-family: system-ui, sans-serif; }
+  main { box-sizing: border-box; height: 100%; display: flex; flex-direction: column; justify-content: flex-end; padding: 4vh 5vw; }
+  #history p { margin: 0.3em 0; color: #8b96a3; font-size: 3.2vh; }
+  #live { min-height: 2.6em; font-size: 5.5vh; line-height: 1.3; }
+  #status { position: fixed; top: 1vh; right: 2vw; font-size: 1.6vh; color: #5c6670; }
+</style>
+</head>
+<body>
+<div id="status">connecting…</div>
+<main><div id="history"></div><div id="live"></div></main>
+<script>
+  const history = document.getElementById("history");
+  const live = document.getElementById("live");
+  const status = document.getElementById("status");
+  const keep = 6;
+  function addFinal(text) {
+    const line = document.createElement("p");
+    line.textContent = text;
+    history.appendChild(line);
+    while (history.children.length > keep) history.removeChild(history.firstChild);
+  }
+  const source = new EventSource("/events" + location.search);
+  source.onopen = () => { status.textContent = "live"; };
+  source.onerror = () => { status.textContent = "reconnecting…"; };
+  source.onmessage = (event) => {
+    const update = JSON.parse(event.data);
+    if (update.type === "snapshot") {
+      history.replaceChildren();
+      update.finals.slice(-keep).forEach(addFinal);
+      live.textContent = update.live.join("\n");
+    } else if (update.type === "caption") {
+      live.textContent = update.lines.join("\n");
+    } else if (update.type === "final") {
+      addFinal(update.text);
+      live.textContent = "";
+    } else if (update.type === "clear") {
+      live.textContent = "";
+    }
+  };
+</script>
+</body>
+</html>
+"#;
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ViewerUpdate {
+    Snapshot {
+        finals: Vec<String>,
+        live: Vec<String>,
+    },
+    Caption {
+        session_id: u64,
+        lines: Vec<String>,
+    },
+    Final {
+        session_id: u64,
+        text: String,
+    },
+    Clear,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct ViewerStatus {
+    pub url: String,
+    pub port: u16,
+    pub viewers: usize,
+}
+
+#[derive(Debug, Default)]
+struct HubState {
+    finals: VecDeque<String>,
+    live: Vec<String>,
+    viewers: Vec<Sender<String>>,
+}
+
+impl HubState {
+    fn snapshot(&self) -> ViewerUpdate {
+        ViewerUpdate::Snapshot {
+            finals: self.finals.iter().cloned().collect(),
+            live: self.live.clone(),
+        }
+    }
+}
+
+// Fans updates out to connected viewers. Nothing flows back, so a viewer can
+// watch but never drive dictation.
+#[derive(Debug, Clone, Default)]
+pub struct ViewerHub {
+    state: Arc<Mutex<HubState>>,
+}
+
+impl ViewerHub {
+    pub fn publish(&self, update: ViewerUpdate) {
+        let Ok(mut state) = self.state.lock() else {
+            return;
+        };
+        match &update {
+            ViewerUpdate::Caption { lines, .. } => state.live = lines.clone(),
+            ViewerUpdate::Final { text, .. } => {
+                state.live.clear();
+                state.finals.push_back(text.clone());
+                while state.finals.len() > RECENT_FINALS {
+                    state.finals.pop_front();
+                }
+            }
+            ViewerUpdate::Clear => state.live.clear(),
+            ViewerUpdate::Snapshot { .. } => return,
+        }
+        let Ok(message) = serde_json::to_string(&update) else {
+            return;
+        };
+        state
+            .viewers
+            .retain(|viewer| viewer.send(message.clone()).is_ok());
+    }
+
+    pub fn viewers(&self) -> usize {
+        self.state
+            .lock()
+            .map(|state| state.viewers.len())
+            .unwrap_or(0)
+    }
+
+    fn snapshot_json(&self) -> String {
+        self.state
+            .lock()
+            .ok()
+            .and_then(|state| serde_json::to_string(&state.snapshot()).ok())
+            .unwrap_or_else(|| "{}".to_string())
+    }
+
+    // The snapshot and the subscription are taken under one lock so no update
+    // falls between them.
+    fn subscribe(&self) -> Option<(String, Receiver<String>)> {
+        let mut state = self.state.lock().ok()?;
+        if state.viewers.len() >= MAX_VIEWERS {
+            return None;
+        }
+        let snapshot = serde_json::to_string(&state.snapshot()).ok()?;
+        let (sender, receiver) = mpsc::channel();
+        state.viewers.push(sender);
+        Some((snapshot, receiver))
+    }
+}
+
+pub struct ViewerServer {
+    port: u16,
+    token: String,
+    hub: ViewerHub,
+    shutdown: Arc<AtomicBool>,
+}
+
+impl ViewerServer {
+    pub fn start(bind: IpAddr, port: u16) -> Result<Self, String> {
+        let listener = TcpListener::bind((bind, port))
+            .map_err(|error| format!("failed to listen on {bind}:{port}: {error}"))?;
+        listener
+            .set_nonblocking(true)
+            .map_err(|error| error.to_string())?;
+        let port = listener
+            .local_addr()
+            .map_err(|error| error.to_string())?
+            .port();
+        let token = access_token()?;
+        let hub = ViewerHub::default();
+        let shutdown = Arc::new(AtomicBool::new(false));
+
+        let accept_token = token.clone();
+        let accept_hub = hub.clone();
+        let accept_shutdown = Arc::clone(&shutdown);
+        let connections = Arc::new(AtomicUsize::new(0));
+        thread::spawn(move || {
+            while !accept_shutdown.load(Ordering::Relaxed) {
+                match listener.accept() {
+                    Ok((stream, _)) => {
+                        // Over the cap the connection is dropped unanswered.
+                        let Some(slot) = ConnectionSlot::claim(&connections) else {
+                            continue;
+                        };
+                        let token = accept_token.clone();
+                        let hub = accept_hub.clone();
+                        let shutdown = Arc::clone(&accept_shutdown);
+                        thread::spawn(move || {
+                            serve(stream, &token, &hub, &shutdown);
+                            drop(slot);
+                        });
+                    }
+                    // Nonblocking, so this is usually WouldBlock between clients.
+                    Err(_) => thread::sleep(ACCEPT_POLL),
+                }
+            }
+        });
+
+        Ok(Self {
+            port,
+            token,
+            hub,
+            shutdown,
+        })
+    }
+
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+
+    pub fn hub(&self) -> &ViewerHub {
+        &self.hub
+    }
+
+    pub fn status(&self) -> ViewerStatus {
+        ViewerStatus {
+            url: format!(
+                "http://{}:{}/?token={}",
+                lan_address(),
+                self.port,
+                self.token
+            ),
+            port: self.port,
+            viewers: self.hub.viewers(),
+        }
+    }
+}
+
+impl Drop for ViewerServer {
+    fn drop(&mut self) {
+        self.shutdown.store(true, Ordering::Relaxed);
+        // Dropping the senders ends the open event streams.
+        if let Ok(mut state) = self.hub.state.lock() {
+            state.viewers.clear();
+        }
+    }
+}
+
+struct ConnectionSlot(Arc<AtomicUsize>);
+
+impl ConnectionSlot {
+    fn claim(connections: &Arc<AtomicUsize>) -> Option<Self> {
+        connections
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |open| {
+                (open < MAX_CONNECTIONS).then_some(open + 1)
+            })
+            .ok()
+            .map(|_| Self(Arc::clone(connections)))
+    }
+}
+
+impl Drop for ConnectionSlot {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+// Changes every time the server starts, so a URL shared for one meeting stops
+// working once sharing is turned off. Anyone on the LAN can reach the port, so
+// the token comes from the OS random source and the server refuses to start
+// without one.
+fn access_token() -> Result<String, String> {
+    let mut bytes = [0u8; 16];
+    getrandom::fill(&mut bytes)
+        .map_err(|error| format!("no randomness for the viewer token: {error}"))?;
+    Ok(bytes.iter().map(|byte| format!("{byte:02x}")).collect())
+}
+
+// The address other machines reach this one on. Connecting a UDP socket sends
+// nothing; it only picks the outgoing interface.
+fn lan_address() -> IpAddr {
+    UdpSocket::bind((Ipv4Addr::UNSPECIFIED, 0))
+        .and_then(|socket| {
+            socket.connect(SocketAddr::from(([192, 0, 2, 1], 9)))?;
+            socket.local_addr()
+        })
+        .map(|address| address.ip())
+        .unwrap_or(IpAddr::V4(Ipv4Addr::LOCALHOST))
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Request {
+    method: String,
+    path: String,
+    token: Option<String>,
+}
+
+fn parse_request_line(line: &str) -> Option<Request> {
+    let mut parts = line.split_whitespace();
+    let method = parts.next()?.to_string();
+    let target = parts.next()?;
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    let token = query
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(key, _)| *key == "token")
+        .map(|(_, value)| value.to_string());
+    Some(Request {
+        method,
+        path: path.to_string(),
+        token,
+    })
+}
+
+// The whole head must fit in MAX_REQUEST_BYTES; a line that runs past it is
+// cut off by `take` and the request is refused.
+fn read_request(stream: impl Read) -> Option<Request> {
+    let mut reader = BufReader::new(stream.take(MAX_REQUEST_BYTES as u64));
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line).ok()?;
+    if !request_line.ends_with('\n') {
+        return None;
+    }
+    // Headers are not needed, but the client expects them to be consumed.
+    loop {
+        let mut header = String::new();
+        reader.read_line(&mut header).ok()?;
+        if !header.ends_with('\n') {
+            return None;
+        }
+        if header.trim().is_empty() {
+            break;
+        }
+    }
+    parse_request_line(&request_line)
+}
+
+// Compares every byte so the time taken does not reveal how much of a guessed
+// token was right.
+fn tokens_match(given: Option<&str>, expected: &str) -> bool {
+    let Some(given) = given else {
+        return false;
+    };
+    given.len() == expected.len()
+        && given
+            .bytes()
+            .zip(expected.bytes())
+            .fold(0u8, |difference, (a, b)| difference | (a ^ b))
+            == 0
+}
+
+fn respond(stream: &mut TcpStream, status: &str, content_type: &str, body: &str) {
+    let _ = write!(
+        stream,
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nCache-Control: no-store\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+}
+
+fn serve(mut stream: TcpStream, token: &str, hub: &ViewerHub, shutdown: &AtomicBool) {
+    let _ = stream.set_nonblocking(false);
+    if stream.set_read_timeout(Some(REQUEST_TIMEOUT)).is_err() {
+        return;
+    }
+    let Some(request) = read_request(&stream) else {
+        respond(&mut stream, "400 Bad Request", "text/plain", "bad request");
+        return;
+    };
+    if request.method != "GET" {
+        let _ = write!(
+            stream,
+            "HTTP/1.1 405 Method Not Allowed\r\nAllow: GET\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+        );
+        return;
+    }
+    if !tokens_match(request.token.as_deref(), token) {
+        respond(&mut stream, "403 Forbidden", "text/plain", "invalid token");
+        return;
+    }
+    match request.path.as_str() {
+        "/" => respond(
+            &mut stream,
+            "200 OK",
+            "text/html; charset=utf-8",
+            VIEWER_PAGE,
+        ),
+        "/transcript" => respond(
+            &mut stream,
+            "200 OK",
+            "application/json",
+            &hub.snapshot_json(),
+        ),
+        "/events" => stream_events(stream, hub, shutdown),
+        _ => respond(&mut stream, "404 Not Found", "text/plain", "not found"),
+    }
+}
+
+fn stream_events(mut stream: TcpStream, hub: &ViewerHub, shutdown: &AtomicBool) {
+    let Some((snapshot, updates)) = hub.subscribe() else {
+        respond(
+            &mut stream,
+            "503 Service Unavailable",
+            "text/plain",
+            "too many viewers",
+        );
+        return;
+    };
+    let head = "HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\nCache-Control: no-store\r\nConnection: keep-alive\r\n\r\n";
+    if write!(stream, "{head}data: {snapshot}\n\n").is_err() {
+        return;
+    }
+    while !shutdown.load(Ordering::Relaxed) {
+        let written = match updates.recv_timeout(KEEPALIVE) {
+            Ok(message) => write!(stream, "data: {message}\n\n"),
+            Err(RecvTimeoutError::Timeout) => write!(stream, ": keepalive\n\n"),
+            Err(RecvTimeoutError::Disconnected) => return,
+        };
+        if written.and_then(|_| stream.flush()).is_err() {
+            return;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+
+    fn get(port: u16, request_line: &str) -> String {
+        let mut stream = TcpStream::connect((Ipv4Addr::LOCALHOST, port)).expect("connect");
+        write!(stream, "{request_line}\r\nHost: localhost\r\n\r\n").expect("request");
+        let mut response = String::new();
+        stream.read_to_string(&mut response).expect("response");
+        response
+    }
+
+    #[test]
+    fn serves_the_transcript_read_only_behind_the_token() {
+        let server = ViewerServer::start(IpAddr::V4(Ipv4Addr::LOCALHOST), 0).expect("server");
+        server.hub().publish(ViewerUpdate::Final {
+            session_id: 1,
+            text: "Welcome, everyone.".to_string(),
+        });
+        server.hub().publish(ViewerUpdate::Caption {
+            session_id: 2,
+            lines: vec!["Today we".to_string()],
+        });
+        let port = server.port();
+        let token = server.token.clone();
+        assert_eq!(token.len(), 32);
+
+        let snapshot = get(port, &format!("GET /transcript?token={token} HTTP/1.1"));
+        assert!(snapshot.starts_with("HTTP/1.1 200 OK"));
+        assert!(snapshot.contains(r#""finals":["Welcome, everyone."]"#));
+        assert!(snapshot.contains(r#""live":["Today we"]"#));
+
+        assert!(get(port, "GET /transcript HTTP/1.1").starts_with("HTTP/1.1 403"));
+        assert!(
+            get(port, &format!("POST /transcript?token={token} HTTP/1.1"))
+                .starts_with("HTTP/1.1 405")
+        );
+        assert!(server.status().url.ends_with(&format!("/?token={token}")));
+    }
+
+    #[test]
+    fn parses_the_token_out_of_the_query() {
+        assert_eq!(
+            parse_request_line("GET /events?x=1&token=abc HTTP/1.1"),
+            Some(Request {
+                method: "GET".to_string(),
+                path: "/events".to_string(),
+                token: Some("abc".to_string()),
+            })
+        );
+        assert_eq!(parse_request_line(""), None);
+    }
+
+    #[test]
+    fn refuses_endless_request_heads_and_mismatched_tokens() {
+        assert_eq!(read_request(std::io::repeat(b'A')), None);
+        let endless_header = "GET /?token=abc HTTP/1.1\r\nX-Pad: "
+            .as_bytes()
+            .chain(std::io::repeat(b'A'));
+        assert_eq!(read_request(endless_header), None);
+        assert_eq!(
+            read_request("GET /?token=abc HTTP/1.1\r\nHost: x\r\n\r\n".as_bytes()),
+            parse_request_line("GET /?token=abc HTTP/1.1")
+        );
+
+        assert!(tokens_match(Some("abc"), "abc"));
+        assert!(!tokens_match(Some("abd"), "abc"));
+        assert!(!tokens_match(Some("ab"), "abc"));
+        assert!(!tokens_match(None, "abc"));
+
+        let connections = Arc::new(AtomicUsize::new(0));
+        let slots = (0..MAX_CONNECTIONS)
+            .map(|_| ConnectionSlot::claim(&connections).expect("slot"))
+            .collect::<Vec<_>>();
+        assert!(ConnectionSlot::claim(&connections).is_none());
+        drop(slots);
+        assert_eq!(connections.load(Ordering::Acquire), 0);
+    }
+}
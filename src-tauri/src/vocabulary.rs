@@ -0,0 +1,143 @@
+pub const MAX_SESSION_TERMS: usize = 64;
+const MAX_TERM_CHARS: usize = 64;
+
+pub fn normalize_terms(terms: Vec<String>) -> Vec<String> {
+    let mut normalized = Vec::<String>::new();
+    for term in terms {
+        let collapsed = term
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .chars()
+            .take(MAX_TERM_CHARS)
+            .collect::<String>();
+        if collapsed.is_empty()
+            || normalized
+                .iter()
+                .any(|existing| existing.eq_ignore_ascii_case(&collapsed))
+        {
+            continue;
+        }
+        normalized.push(collapsed);
+        if normalized.len() == MAX_SESSION_TERMS {
+            break;
+        }
+    }
+    normalized
+}
+
+pub fn biasing_prompt(terms: &[String], context: Option<&str>) -> Option<String> {
+    let context = context.map(str::trim).filter(|value| !value.is_empty());
+    if terms.is_empty() {
+        return context.map(str::to_string);
+    }
+
+    // Prompts are trimmed from the front, so terms go last to survive truncation.
+    let glossary = format!("{}.", terms.join(", "));
+    Some(match context {
+        Some(context) => format!("{context} {glossary}"),
+        None => glossary,
+    })
+}
+
+// Vocabulary entries carry their exact casing ("GitHub", "macOS"), which is
+// restored on every whole-word match whatever casing the engine or a later
+// capitalization pass produced.
+pub fn apply_term_corrections(text: &str, terms: &[String]) -> String {
+    let mut corrected = text.to_string();
+    for term in terms {
+        corrected = replace_term(&corrected, term);
+    }
+    corrected
+}
+
+fn replace_term(text: &str, term: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    let mut cursor = 0;
+    let mut start = 0;
+    while start < text.len() {
+        let matched = caseless_prefix_len(&text[start..], term)
+            .filter(|len| is_word_boundary(text, start, start + len));
+        match matched {
+            Some(len) => {
+                output.push_str(&text[cursor..start]);
+                output.push_str(term);
+                start += len;
+                cursor = start;
+            }
+            None => start += text[start..].chars().next().map_or(1, char::len_utf8),
+        }
+    }
+    output.push_str(&text[cursor..]);
+    output
+}
+
+// Byte length of the start of `text` that spells `term` in any casing,
+// compared per character so non-ASCII names match too.
+fn caseless_prefix_len(text: &str, term: &str) -> Option<usize> {
+    if term.is_empty() {
+        return None;
+    }
+    let mut chars = text.char_indices();
+    for expected in term.chars() {
+        let (_, actual) = chars.next()?;
+        if !actual.to_lowercase().eq(expected.to_lowercase()) {
+            return None;
+        }
+    }
+    Some(chars.next().map_or(text.len(), |(index, _)| index))
+}
+
+fn is_word_boundary(text: &str, start: usize, end: usize) -> bool {
+    let before = text[..start].chars().next_back();
+    let after = text[end..].chars().next();
+    !before.is_some_and(char::is_alphanumeric) && !after.is_some_and(char::is_alphanumeric)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_and_deduplicates_terms() {
+        let terms = normalize_terms(vec![
+            "  Priya   Raman ".to_string(),
+            "priya raman".to_string(),
+            String::new(),
+            "Kubernetes".to_string(),
+        ]);
+        assert_eq!(terms, vec!["Priya Raman", "Kubernetes"]);
+    }
+
+    #[test]
+    fn places_terms_after_stream_context() {
+        let terms = vec!["Priya".to_string(), "Okonkwo".to_string()];
+        assert_eq!(
+            biasing_prompt(&terms, Some("thanks everyone")),
+            Some("thanks everyone Priya, Okonkwo.".to_string())
+        );
+        assert_eq!(biasing_prompt(&[], Some("  ")), None);
+        assert_eq!(
+            biasing_prompt(&terms, None),
+            Some("Priya, Okonkwo.".to_string())
+        );
+    }
+
+    #[test]
+    fn corrects_casing_of_whole_word_matches_only() {
+        let terms = vec!["Priya".to_string(), "gRPC".to_string()];
+        assert_eq!(
+            apply_term_corrections("ask priya about grpc and priyanka", &terms),
+            "ask Priya about gRPC and priyanka"
+        );
+    }
+
+    #[test]
+    fn restores_exact_casing_for_non_ascii_terms() {
+        let terms = vec!["Zoë".to_string(), "ÉCOLE".to_string()];
+        assert_eq!(
+            apply_term_corrections("zoë went to école. zoëlle did not", &terms),
+            "Zoë went to ÉCOLE. zoëlle did not"
+        );
+    }
+}
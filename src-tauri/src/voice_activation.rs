@@ -0,0 +1,135 @@
+use crate::vad::{self, VadConfig};
+
+const SAMPLE_RATE_HZ: u64 = 16_000;
+
+// Speech has to hold this long before dictation starts, so coughs and door slams do not.
+pub const START_AFTER_SPEECH_MS: u64 = 300;
+// Short gaps between syllables do not reset the sustained-speech run.
+pub const SPEECH_GAP_TOLERANCE_MS: u64 = 150;
+// Audio kept while idle so the first word is not clipped when dictation starts.
+pub const PREROLL_MS: u64 = 500;
+
+pub fn preroll_samples() -> usize {
+    (PREROLL_MS * SAMPLE_RATE_HZ / 1_000) as usize
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VoiceActivationEvent {
+    Start,
+    Stop,
+}
+
+#[derive(Debug, Clone)]
+pub struct VoiceActivation {
+    stop_after_silence_ms: u64,
+    window: Vec<f32>,
+    speech_ms: u64,
+    silence_ms: u64,
+    active: bool,
+}
+
+impl VoiceActivation {
+    pub fn new(stop_after_silence_ms: u64) -> Self {
+        Self {
+            stop_after_silence_ms,
+            window: Vec::new(),
+            speech_ms: 0,
+            silence_ms: 0,
+            active: false,
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    pub fn set_stop_after_silence_ms(&mut self, stop_after_silence_ms: u64) {
+        self.stop_after_silence_ms = stop_after_silence_ms;
+    }
+
+    // Forgets the current run, e.g. when the user took over with the hotkey.
+    pub fn reset(&mut self) {
+        self.window.clear();
+        self.speech_ms = 0;
+        self.silence_ms = 0;
+        self.active = false;
+    }
+
+    // Feeds 16 kHz samples through the energy VAD one window at a time.
+    pub fn observe(&mut self, samples: &[f32], config: &VadConfig) -> Option<VoiceActivationEvent> {
+        let window_samples = config.window_samples.max(config.min_samples).max(1);
+        let mut event = None;
+        self.window.extend_from_slice(samples);
+        while self.window.len() >= window_samples {
+            let window = self.window.drain(..window_samples).collect::<Vec<_>>();
+            let voiced = vad::has_speech(&window, config);
+            let window_ms = window_samples as u64 * 1_000 / SAMPLE_RATE_HZ;
+            event = self.step(voiced, window_ms).or(event);
+        }
+        event
+    }
+
+    fn step(&mut self, voiced: bool, window_ms: u64) -> Option<VoiceActivationEvent> {
+        if voiced {
+            self.speech_ms = self.speech_ms.saturating_add(window_ms);
+            self.silence_ms = 0;
+        } else {
+            self.silence_ms = self.silence_ms.saturating_add(window_ms);
+            if self.silence_ms >= SPEECH_GAP_TOLERANCE_MS {
+                self.speech_ms = 0;
+            }
+        }
+
+        if !self.active && self.speech_ms >= START_AFTER_SPEECH_MS {
+            self.active = true;
+            self.silence_ms = 0;
+            return Some(VoiceActivationEvent::Start);
+        }
+        if self.active && self.silence_ms >= self.stop_after_silence_ms {
+            self.active = false;
+            self.speech_ms = 0;
+            return Some(VoiceActivationEvent::Stop);
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame(amplitude: f32, ms: u64) -> Vec<f32> {
+        vec![amplitude; (ms * SAMPLE_RATE_HZ / 1_000) as usize]
+    }
+
+    #[test]
+    fn sustained_speech_starts_and_silence_stops() {
+        let config = VadConfig::default();
+        let mut activation = VoiceActivation::new(1_000);
+
+        assert_eq!(activation.observe(&frame(0.2, 100), &config), None);
+        assert_eq!(activation.observe(&frame(0.0, 400), &config), None);
+        assert_eq!(
+            activation.observe(&frame(0.2, 400), &config),
+            Some(VoiceActivationEvent::Start)
+        );
+        assert!(activation.is_active());
+        assert_eq!(activation.observe(&frame(0.0, 600), &config), None);
+        assert_eq!(
+            activation.observe(&frame(0.0, 600), &config),
+            Some(VoiceActivationEvent::Stop)
+        );
+        assert!(!activation.is_active());
+    }
+
+    #[test]
+    fn brief_noise_never_starts_dictation() {
+        let config = VadConfig::default();
+        let mut activation = VoiceActivation::new(1_000);
+        for _ in 0..10 {
+            assert_eq!(activation.observe(&frame(0.2, 160), &config), None);
+            assert_eq!(activation.observe(&frame(0.0, 300), &config), None);
+        }
+        assert!(!activation.is_active());
+    }
+}
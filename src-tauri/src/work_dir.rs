@@ -0,0 +1,152 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+static NEXT_SESSION: AtomicU64 = AtomicU64::new(1);
+
+// Chunk files for one transcription session. Names come from counters rather
+// than the clock, so chunks produced within the same millisecond cannot
+// collide, and removing the directory clears whatever a cancelled chunk left.
+#[derive(Debug)]
+pub struct SessionDir {
+    path: PathBuf,
+    name: String,
+    next_chunk: AtomicU64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChunkFiles {
+    dir: PathBuf,
+    // Unique across sessions too; sidecar request ids reuse it.
+    pub token: String,
+    index: u64,
+}
+
+impl ChunkFiles {
+    pub fn file(&self, suffix: &str) -> PathBuf {
+        self.dir.join(format!("chunk-{:06}{suffix}", self.index))
+    }
+}
+
+impl SessionDir {
+    pub fn create(root: &Path, label: &str) -> Result<Self, String> {
+        let name = format!(
+            "{label}-{}-{}",
+            std::process::id(),
+            NEXT_SESSION.fetch_add(1, Ordering::Relaxed)
+        );
+        let path = root.join(&name);
+        fs::create_dir_all(&path).map_err(|error| {
+            format!(
+                "failed to create working directory '{}': {error}",
+                path.display()
+            )
+        })?;
+        Ok(Self {
+            path,
+            name,
+            next_chunk: AtomicU64::new(1),
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn next_chunk(&self) -> ChunkFiles {
+        let index = self.next_chunk.fetch_add(1, Ordering::Relaxed);
+        ChunkFiles {
+            dir: self.path.clone(),
+            token: format!("{}-{index:06}", self.name),
+            index,
+        }
+    }
+}
+
+impl Drop for SessionDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
+
+pub fn default_root() -> PathBuf {
+    std::env::temp_dir().join("sonora-work")
+}
+
+// The current session's directory for a transcriber. Chunks hold an `Arc` to
+// it, so ending a session while one is in flight removes the directory only
+// after that chunk finishes.
+#[derive(Debug, Clone)]
+pub struct SessionWorkspace {
+    label: &'static str,
+    current: Arc<Mutex<Option<Arc<SessionDir>>>>,
+}
+
+impl SessionWorkspace {
+    pub fn new(label: &'static str) -> Self {
+        Self {
+            label,
+            current: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    pub fn begin(&self) -> Result<(), String> {
+        let dir = SessionDir::create(&default_root(), self.label)?;
+        let mut current = self
+            .current
+            .lock()
+            .map_err(|_| "failed to acquire working directory lock".to_string())?;
+        *current = Some(Arc::new(dir));
+        Ok(())
+    }
+
+    pub fn end(&self) {
+        if let Ok(mut current) = self.current.lock() {
+            current.take();
+        }
+    }
+
+    // One-shot transcriptions outside a capture session get a directory on
+    // first use; it lasts until the next `end`.
+    pub fn session(&self) -> Result<Arc<SessionDir>, String> {
+        let mut current = self
+            .current
+            .lock()
+            .map_err(|_| "failed to acquire working directory lock".to_string())?;
+        if let Some(dir) = current.as_ref() {
+            return Ok(Arc::clone(dir));
+        }
+        let dir = Arc::new(SessionDir::create(&default_root(), self.label)?);
+        *current = Some(Arc::clone(&dir));
+        Ok(dir)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chunks_get_distinct_names_and_the_session_cleans_up_as_a_unit() {
+        let workspace = SessionWorkspace::new("test");
+        let session = workspace.session().expect("session directory");
+        let first = session.next_chunk();
+        let second = session.next_chunk();
+        assert_ne!(first.token, second.token);
+        assert_ne!(first.file(".wav"), second.file(".wav"));
+        fs::write(first.file(".wav"), b"chunk").expect("chunk file");
+
+        let path = session.path().to_path_buf();
+        workspace.end();
+        // An in-flight chunk keeps the directory until it is done.
+        assert!(path.is_dir());
+        drop(session);
+        assert!(!path.exists());
+
+        workspace.begin().expect("next session");
+        let next = workspace.session().expect("session directory");
+        assert_ne!(next.path(), path);
+        workspace.end();
+    }
+}
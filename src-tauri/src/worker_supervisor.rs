@@ -0,0 +1,90 @@
+use std::collections::VecDeque;
+use std::process::Child;
+use std::time::{Duration, Instant};
+
+// A sidecar that keeps dying (bad driver, corrupt model) is left down instead
+// of being respawned in a tight loop.
+pub const MAX_CRASH_RESTARTS: usize = 3;
+pub const CRASH_WINDOW: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Default)]
+pub struct WorkerSupervisor {
+    recent_crashes: VecDeque<Instant>,
+    restarts: u32,
+}
+
+impl WorkerSupervisor {
+    // Records a crash and decides whether the worker may be started again.
+    pub fn allow_restart(&mut self, now: Instant) -> bool {
+        while self
+            .recent_crashes
+            .front()
+            .is_some_and(|crashed_at| now.duration_since(*crashed_at) >= CRASH_WINDOW)
+        {
+            let _ = self.recent_crashes.pop_front();
+        }
+        self.recent_crashes.push_back(now);
+        if self.recent_crashes.len() > MAX_CRASH_RESTARTS {
+            return false;
+        }
+        self.restarts = self.restarts.saturating_add(1);
+        true
+    }
+
+    pub fn restarts(&self) -> u32 {
+        self.restarts
+    }
+}
+
+pub fn child_exited(child: &mut Child) -> bool {
+    !matches!(child.try_wait(), Ok(None))
+}
+
+// whisper.cpp runs once per chunk; a signal (or a Windows exception code)
+// means the process crashed rather than rejecting its input.
+pub fn crashed(status: &std::process::ExitStatus) -> bool {
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::ExitStatusExt;
+        status.signal().is_some()
+    }
+    #[cfg(windows)]
+    {
+        status
+            .code()
+            .is_some_and(|code| (code as u32) >= 0xC000_0000)
+    }
+    #[cfg(not(any(unix, windows)))]
+    {
+        let _ = status;
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gives_up_after_repeated_crashes_within_window() {
+        let mut supervisor = WorkerSupervisor::default();
+        let start = Instant::now();
+        for offset in 0..MAX_CRASH_RESTARTS as u64 {
+            assert!(supervisor.allow_restart(start + Duration::from_secs(offset)));
+        }
+        assert!(!supervisor.allow_restart(start + Duration::from_secs(10)));
+        assert_eq!(supervisor.restarts(), MAX_CRASH_RESTARTS as u32);
+
+        // Crashes older than the window no longer count against the worker.
+        assert!(supervisor.allow_restart(start + CRASH_WINDOW + Duration::from_secs(11)));
+        assert_eq!(supervisor.restarts(), MAX_CRASH_RESTARTS as u32 + 1);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn detects_signal_terminated_processes() {
+        use std::os::unix::process::ExitStatusExt;
+        assert!(crashed(&std::process::ExitStatus::from_raw(11)));
+        assert!(!crashed(&std::process::ExitStatus::from_raw(1 << 8)));
+    }
+}
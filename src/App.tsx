@@ -6,6 +6,7 @@ import { Phase4EnvironmentPanel } from "./components/Phase4EnvironmentPanel";
 import { RecentTranscriptsPanel } from "./components/RecentTranscriptsPanel";
 import { Phase4RecoveryPanel } from "./components/Phase4RecoveryPanel";
 import { ErrorBanner } from "./components/ErrorBanner";
+import { ScreenReaderAnnouncer } from "./components/ScreenReaderAnnouncer";
 import {
   AppControllerProvider,
   useAppControllerContext,
@@ -26,6 +27,7 @@ function AppShell() {
       <RecentTranscriptsPanel />
       <Phase4RecoveryPanel />
       <ErrorBanner />
+      <ScreenReaderAnnouncer />
     </main>
   );
 }
@@ -8,7 +8,12 @@ import { useAppControllerContext } from "../context/AppControllerContext";
 import {
   getPhase1LiveCaptureActive,
   startPhase1LiveCapture,
+  startPhase1MicMonitor,
   stopPhase1LiveCapture,
+  stopPhase1MicMonitor,
+  takePendingActivation,
+  type ActivationAction,
+  type ActivationPayload,
   type MicLevelPayload,
 } from "../services/phase1";
 
@@ -25,6 +30,8 @@ function Phase1ControlsComponent() {
   const indicatorRef = useRef<MicLevelPeakIndicatorHandle>(null);
   const reportErrorRef = useRef(reportError);
   const [liveMicActive, setLiveMicActive] = useState(false);
+  const [monitorActive, setMonitorActive] = useState(false);
+  const activationRef = useRef<(action: ActivationAction) => Promise<void>>(async () => {});
 
   useEffect(() => {
     reportErrorRef.current = reportError;
@@ -40,6 +47,8 @@ function Phase1ControlsComponent() {
     let cancelled = false;
     let disposeMicLevel: (() => void) | null = null;
     let disposeLiveMic: (() => void) | null = null;
+    let disposeMonitor: (() => void) | null = null;
+    let disposeActivate: (() => void) | null = null;
 
     void (async () => {
       try {
@@ -54,6 +63,14 @@ function Phase1ControlsComponent() {
           }
         });
 
+        disposeMonitor = await listen<{ active: boolean }>("dictation:mic-monitor", (event) => {
+          setMonitorActive(event.payload.active);
+        });
+
+        disposeActivate = await listen<ActivationPayload>("app:activate", (event) => {
+          void activationRef.current(event.payload.action);
+        });
+
         const active = await getPhase1LiveCaptureActive();
         if (!cancelled) {
           setLiveMicActive(active);
@@ -61,6 +78,11 @@ function Phase1ControlsComponent() {
             indicatorRef.current?.reset();
           }
         }
+
+        const pending = await takePendingActivation();
+        if (!cancelled && pending) {
+          void activationRef.current(pending);
+        }
       } catch (cause) {
         if (!cancelled) {
           reportErrorRef.current(cause);
@@ -72,6 +94,8 @@ function Phase1ControlsComponent() {
       cancelled = true;
       disposeMicLevel?.();
       disposeLiveMic?.();
+      disposeMonitor?.();
+      disposeActivate?.();
       indicatorRef.current?.reset();
     };
   }, [available]);
@@ -104,6 +128,29 @@ function Phase1ControlsComponent() {
     }
   }
 
+  activationRef.current = async (action: ActivationAction) => {
+    const wantsActive =
+      action === "toggle_dictation" ? !liveMicActive : action === "start_dictation";
+    if (action === "show" || wantsActive === liveMicActive) {
+      return;
+    }
+    await (wantsActive ? startLiveMic() : stopLiveMic());
+  };
+
+  async function toggleMonitor() {
+    try {
+      if (monitorActive) {
+        await stopPhase1MicMonitor();
+        setMonitorActive(false);
+      } else {
+        await startPhase1MicMonitor(selectedMicrophoneId.trim() ? selectedMicrophoneId : null, null);
+        setMonitorActive(true);
+      }
+    } catch (cause) {
+      reportError(cause);
+    }
+  }
+
   return (
     <section className="panel">
       <h2>Phase 1 Controls</h2>
@@ -116,6 +163,9 @@ function Phase1ControlsComponent() {
         <button disabled={!available || !liveMicActive} onClick={stopLiveMic}>
           Stop Live Mic
         </button>
+        <button disabled={!available} onClick={toggleMonitor}>
+          {monitorActive ? "Stop Monitor" : "Monitor Mic"}
+        </button>
       </div>
     </section>
   );
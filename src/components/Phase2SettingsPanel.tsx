@@ -1,5 +1,6 @@
 import { memo } from "react";
 import { useAppControllerContext } from "../context/AppControllerContext";
+import type { SttEngine } from "../domain/settings";
 
 const WHISPER_CPP_MODELS = [
   {
@@ -101,12 +102,13 @@ function Phase2SettingsPanelComponent() {
           disabled={!available}
           value={sttEngine}
           onChange={(event) =>
-            setSttEngine(event.currentTarget.value as "whisper_cpp" | "faster_whisper" | "parakeet")
+            setSttEngine(event.currentTarget.value as SttEngine)
           }
         >
           <option value="whisper_cpp">whisper.cpp sidecar</option>
           <option value="faster_whisper">faster-whisper</option>
           <option value="parakeet">parakeet (transformers)</option>
+          <option value="demo">demo (no model needed)</option>
         </select>
       </label>
       <p className="muted">
@@ -19,6 +19,12 @@ function Phase4EnvironmentPanelComponent() {
           <li>OS: {environmentHealth.os}</li>
           <li>Session: {environmentHealth.session_type}</li>
           <li>Input permission state: {environmentHealth.input_injection_permission}</li>
+          <li>
+            Keyboard layout:{" "}
+            {environmentHealth.keyboard_layout
+              ? environmentHealth.keyboard_layout.name
+              : "unknown"}
+          </li>
           {environmentHealth.notes.map((note, index) => (
             <li key={`note-${index}`}>{note}</li>
           ))}
@@ -0,0 +1,54 @@
+import { listen } from "@tauri-apps/api/event";
+import { memo, useEffect, useState } from "react";
+import { useAppControllerContext } from "../context/AppControllerContext";
+import type { AccessibilityAnnouncement } from "../services/phase1";
+
+// Visually hidden live regions; WebView2 turns updates into the UIA
+// notifications NVDA and JAWS read. Skipped when a native channel already spoke.
+function ScreenReaderAnnouncerComponent() {
+  const { available } = useAppControllerContext();
+  const [polite, setPolite] = useState("");
+  const [assertive, setAssertive] = useState("");
+
+  useEffect(() => {
+    if (!available) {
+      return;
+    }
+
+    let dispose: (() => void) | null = null;
+    let cancelled = false;
+    void listen<AccessibilityAnnouncement>("accessibility:announce", (event) => {
+      if (event.payload.spoken_natively) {
+        return;
+      }
+      const setText = event.payload.politeness === "assertive" ? setAssertive : setPolite;
+      // Clearing first makes a repeated message count as a change.
+      setText("");
+      window.setTimeout(() => setText(event.payload.text), 50);
+    }).then((unlisten) => {
+      if (cancelled) {
+        unlisten();
+      } else {
+        dispose = unlisten;
+      }
+    });
+
+    return () => {
+      cancelled = true;
+      dispose?.();
+    };
+  }, [available]);
+
+  return (
+    <>
+      <div className="sr-only" role="status" aria-live="polite">
+        {polite}
+      </div>
+      <div className="sr-only" role="alert" aria-live="assertive">
+        {assertive}
+      </div>
+    </>
+  );
+}
+
+export const ScreenReaderAnnouncer = memo(ScreenReaderAnnouncerComponent);
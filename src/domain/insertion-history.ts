@@ -1,4 +1,9 @@
-export type InsertionStatus = "success" | "fallback" | "failure";
+export type InsertionStatus =
+  | "success"
+  | "verified_success"
+  | "unverified"
+  | "fallback"
+  | "failure";
 
 export interface InsertionRecord {
   text: string;
@@ -1,7 +1,7 @@
 import type { DictationMode } from "./dictation-machine";
 
 export type ModelProfile = "balanced" | "fast";
-export type SttEngine = "whisper_cpp" | "faster_whisper" | "parakeet";
+export type SttEngine = "whisper_cpp" | "faster_whisper" | "parakeet" | "demo";
 export type WhisperBackendPreference = "auto" | "cpu" | "cuda";
 export type FasterWhisperComputeType = "auto" | "int8" | "float16" | "float32";
 export type ParakeetComputeType = "auto" | "float16" | "float32";
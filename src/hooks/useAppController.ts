@@ -9,6 +9,7 @@ import {
   effectiveChunkDurationMs,
   effectivePartialCadenceMs,
 } from "../domain/settings";
+import { describeError } from "../services/errors";
 import {
   cancelPhase1,
   listPhase1Microphones,
@@ -132,7 +133,8 @@ export function useAppController() {
       const microphones = await listPhase1Microphones();
       setAvailableMicrophones(microphones);
       if (microphones.length > 0 && !selectedMicrophoneId) {
-        setSelectedMicrophoneId(microphones[0].id);
+        const preferred = microphones.find((microphone) => microphone.recommended) ?? microphones[0];
+        setSelectedMicrophoneId(preferred.id);
       }
     } catch {
       setAvailableMicrophones([]);
@@ -150,7 +152,7 @@ export function useAppController() {
       applyPhaseStatus(status);
       return status;
     } catch (cause) {
-      setError(String(cause));
+      setError(describeError(cause));
       return null;
     }
   }
@@ -217,7 +219,7 @@ export function useAppController() {
         },
       )
       .catch((cause) => {
-        setError(String(cause));
+        setError(describeError(cause));
       });
 
     refreshMicrophones();
@@ -273,7 +275,7 @@ export function useAppController() {
       setError(null);
       return status;
     } catch (cause) {
-      setError(String(cause));
+      setError(describeError(cause));
       return null;
     }
   }
@@ -285,7 +287,7 @@ export function useAppController() {
       setError(null);
       return status;
     } catch (cause) {
-      setError(String(cause));
+      setError(describeError(cause));
       return null;
     }
   }
@@ -357,9 +359,9 @@ export function useAppController() {
       setModelStatus(status);
       setTranscriberStatus(runtimeTranscriber);
       setSettingsSavedAt(new Date().toLocaleTimeString());
-      setError(null);
+      setError(updated.language_warning?.message ?? null);
     } catch (cause) {
-      setError(String(cause));
+      setError(describeError(cause));
     }
   }
 
@@ -397,7 +399,7 @@ export function useAppController() {
       setSettingsSavedAt(new Date().toLocaleTimeString());
       setError(null);
     } catch (cause) {
-      setError(String(cause));
+      setError(describeError(cause));
     }
   }
 
@@ -415,7 +417,7 @@ export function useAppController() {
       setTranscriberStatus(runtimeTranscriber);
       setError(null);
     } catch (cause) {
-      setError(String(cause));
+      setError(describeError(cause));
     }
   }
 
@@ -425,7 +427,7 @@ export function useAppController() {
       setRuntimeLogs([]);
       setError(null);
     } catch (cause) {
-      setError(String(cause));
+      setError(describeError(cause));
     }
   }
 
@@ -435,7 +437,7 @@ export function useAppController() {
       setRecoveryCheckpoint(checkpoint);
       setError(null);
     } catch (cause) {
-      setError(String(cause));
+      setError(describeError(cause));
     }
   }
 
@@ -444,7 +446,7 @@ export function useAppController() {
   }
 
   function reportError(cause: unknown) {
-    setError(String(cause));
+    setError(describeError(cause));
   }
 
   function applyHighAccuracyPreset() {
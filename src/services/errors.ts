@@ -0,0 +1,46 @@
+export type SonoraErrorCode =
+  | "state_unavailable"
+  | "invalid_input"
+  | "not_found"
+  | "permission_denied"
+  | "binary_missing"
+  | "model_missing"
+  | "io"
+  | "audio"
+  | "mic_unavailable"
+  | "transcription"
+  | "insertion"
+  | "unsupported"
+  | "internal";
+
+export type MicUnavailableReason =
+  | "exclusive_use"
+  | "permission_denied"
+  | "disconnected"
+  | "other";
+
+export interface MicUnavailable {
+  reason: MicUnavailableReason;
+  device_label?: string;
+  message: string;
+  waiting: boolean;
+}
+
+export interface SonoraError {
+  code: SonoraErrorCode;
+  message: string;
+  mic?: MicUnavailable;
+}
+
+export function isSonoraError(value: unknown): value is SonoraError {
+  return (
+    typeof value === "object" &&
+    value !== null &&
+    typeof (value as SonoraError).code === "string" &&
+    typeof (value as SonoraError).message === "string"
+  );
+}
+
+export function describeError(cause: unknown): string {
+  return isSonoraError(cause) ? cause.message : String(cause);
+}
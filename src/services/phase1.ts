@@ -1,8 +1,22 @@
 import { invoke } from "@tauri-apps/api/core";
+import type { SonoraError } from "./errors";
+import type { UtteranceEngine } from "./phase2";
+import type { TranscriberStatus } from "./phase4";
 
 export type DictationMode = "push_to_toggle" | "push_to_talk";
 export type DictationState = "idle" | "listening" | "transcribing";
 
+export type ActivationAction =
+  | "toggle_dictation"
+  | "start_dictation"
+  | "stop_dictation"
+  | "show";
+
+export interface ActivationPayload {
+  action: ActivationAction;
+  schema_version?: number;
+}
+
 export interface ProfileTuning {
   min_chunk_samples: number;
   partial_cadence_ms: number;
@@ -15,29 +29,160 @@ export interface PipelineStatus {
   tuning: ProfileTuning;
 }
 
+export interface TranscriptDiff {
+  stable_prefix: string;
+  suffix: string;
+}
+
 export interface TranscriptPayload {
   text: string;
   chunk_id?: number;
   emitted_unix_ms?: number;
   session_id?: number;
+  language?: string;
+  diff?: TranscriptDiff;
+  is_final: boolean;
+  engine?: UtteranceEngine;
+  schema_version?: number;
+}
+
+export interface LanguagePayload {
+  language: string;
+  overridden: boolean;
 }
 
 export interface InputMicrophone {
   id: string;
   label: string;
   is_default: boolean;
+  snr_db?: number;
+  recommended: boolean;
+}
+
+export interface OutputDevice {
+  id: string;
+  label: string;
+  is_default: boolean;
+}
+
+export interface CaptionFrame {
+  session_id: number;
+  lines: string[];
+  language?: string;
+}
+
+export interface InstantEdit {
+  session_id: number;
+  backspaces: number;
+  text: string;
+  is_final: boolean;
+}
+
+export interface AccessibilityAnnouncement {
+  text: string;
+  politeness: "polite" | "assertive";
+  spoken_natively: boolean;
+  schema_version?: number;
+}
+
+export interface VoiceActivationPayload {
+  active: boolean;
+  unix_ms: number;
+  schema_version?: number;
+}
+
+export interface PlaybackGuardPayload {
+  engaged: boolean;
+  guard: "off" | "raise_vad" | "pause";
+  unix_ms: number;
+  schema_version?: number;
 }
 
 export interface MicLevelPayload {
   level: number;
   peak: number;
   active: boolean;
+  schema_version?: number;
+}
+
+export interface ClipStats {
+  duration_ms: number;
+  rms: number;
+  peak: number;
+  clipped_ratio: number;
+  snr_db?: number;
+  warnings: string[];
+}
+
+export interface TestClipPayload {
+  stats?: ClipStats;
+  transcript?: string;
+  error?: SonoraError;
+  schema_version?: number;
+}
+
+export interface ReferenceSentence {
+  id: number;
+  text: string;
+}
+
+export interface WordErrors {
+  reference_words: number;
+  substitutions: number;
+  deletions: number;
+  insertions: number;
+  wer: number;
+}
+
+export type DictationRecommendation =
+  | "raise_gain"
+  | "lower_gain"
+  | "switch_microphone"
+  | "enable_noise_suppression"
+  | "use_balanced_profile";
+
+export interface DictationScore {
+  reference: string;
+  transcript: string;
+  errors: WordErrors;
+  score: number;
+  recommendations: DictationRecommendation[];
+}
+
+export interface TestScorePayload {
+  reference: ReferenceSentence;
+  stats?: ClipStats;
+  score?: DictationScore;
+  error?: SonoraError;
+  schema_version?: number;
+}
+
+export interface NoiseCalibrationPayload {
+  microphone_id: string;
+  frame_count?: number;
+  error?: SonoraError;
+  schema_version?: number;
 }
 
 export async function getPhase1Status(): Promise<PipelineStatus> {
   return invoke<PipelineStatus>("phase1_get_status");
 }
 
+export interface ResourceStatus extends PipelineStatus {
+  engine: string;
+  engine_loaded: boolean;
+  capture_active: boolean;
+  worker_pid?: number;
+  worker_restarts: number;
+  worker_resident_bytes?: number;
+  worker_threads?: number;
+  app_threads?: number;
+}
+
+export async function getPhase1ResourceStatus(): Promise<ResourceStatus> {
+  return invoke<ResourceStatus>("phase1_get_resource_status");
+}
+
 export async function setPhase1Mode(mode: DictationMode): Promise<PipelineStatus> {
   return invoke<PipelineStatus>("phase1_set_mode", { mode });
 }
@@ -50,6 +195,16 @@ export async function sendPhase1HotkeyUp(): Promise<PipelineStatus> {
   return invoke<PipelineStatus>("phase1_hotkey_up");
 }
 
+export interface PanicPayload {
+  schema_version?: number;
+  stopped_capture: boolean;
+  triggered_unix_ms: number;
+}
+
+export async function sendPhase1Panic(): Promise<PanicPayload> {
+  return invoke<PanicPayload>("phase1_panic");
+}
+
 export async function cancelPhase1(): Promise<PipelineStatus> {
   return invoke<PipelineStatus>("phase1_cancel");
 }
@@ -62,18 +217,92 @@ export async function listPhase1Microphones(): Promise<InputMicrophone[]> {
   return invoke<InputMicrophone[]>("phase1_list_microphones");
 }
 
+export async function calibrateNoiseProfile(microphoneId?: string | null): Promise<void> {
+  return invoke<void>("phase1_calibrate_noise_profile", { microphoneId });
+}
+
+export async function probePhase1Microphones(): Promise<void> {
+  return invoke<void>("phase1_probe_microphones");
+}
+
+export async function recordTestClip(
+  seconds: number,
+  microphoneId?: string | null,
+  transcribe?: boolean,
+): Promise<void> {
+  return invoke<void>("phase1_record_test_clip", { seconds, microphoneId, transcribe });
+}
+
+export async function getReferenceSentence(referenceId?: number): Promise<ReferenceSentence> {
+  return invoke<ReferenceSentence>("phase1_get_reference_sentence", { referenceId });
+}
+
+export async function scoreTestDictation(
+  referenceId: number,
+  seconds: number,
+  microphoneId?: string | null,
+): Promise<void> {
+  return invoke<void>("phase1_score_test_dictation", { referenceId, seconds, microphoneId });
+}
+
+export async function playTestClip(outputDeviceId?: string | null): Promise<void> {
+  return invoke<void>("phase1_play_test_clip", { outputDeviceId });
+}
+
 export async function getPhase1LiveCaptureActive(): Promise<boolean> {
   return invoke<boolean>("phase1_get_live_capture_active");
 }
 
 export async function startPhase1LiveCapture(
   microphoneId: string | null,
+  sessionLabel?: string,
 ): Promise<boolean> {
   return invoke<boolean>("phase1_start_live_capture", {
     microphone_id: microphoneId,
+    sessionLabel,
   });
 }
 
 export async function stopPhase1LiveCapture(): Promise<boolean> {
   return invoke<boolean>("phase1_stop_live_capture");
 }
+
+export async function listPhase1OutputDevices(): Promise<OutputDevice[]> {
+  return invoke<OutputDevice[]>("phase1_list_output_devices");
+}
+
+export async function startPhase1MicMonitor(
+  microphoneId: string | null,
+  outputDeviceId: string | null,
+): Promise<boolean> {
+  return invoke<boolean>("phase1_start_mic_monitor", {
+    microphone_id: microphoneId,
+    output_device_id: outputDeviceId,
+  });
+}
+
+export async function stopPhase1MicMonitor(): Promise<boolean> {
+  return invoke<boolean>("phase1_stop_mic_monitor");
+}
+
+export async function setPhase1UtteranceLanguage(
+  language: string | null,
+): Promise<TranscriberStatus> {
+  return invoke<TranscriberStatus>("phase1_set_utterance_language", { language });
+}
+
+export async function takePendingActivation(): Promise<ActivationAction | null> {
+  return invoke<ActivationAction | null>("phase1_take_pending_activation");
+}
+
+export async function setSessionVocabulary(terms: string[]): Promise<string[]> {
+  return invoke<string[]>("phase1_set_session_vocabulary", { terms });
+}
+
+export async function getSessionVocabulary(): Promise<string[]> {
+  return invoke<string[]>("phase1_get_session_vocabulary");
+}
+
+export async function getPhase1ActiveLanguage(): Promise<string> {
+  return invoke<string>("phase1_get_active_language");
+}
@@ -2,10 +2,90 @@ import { invoke } from "@tauri-apps/api/core";
 import type { DictationMode } from "./phase1";
 
 export type WhisperBackendPreference = "auto" | "cpu" | "cuda";
-export type SttEngine = "whisper_cpp" | "faster_whisper" | "parakeet";
+export type SttEngine = "whisper_cpp" | "faster_whisper" | "parakeet" | "demo";
 export type FasterWhisperComputeType = "auto" | "int8" | "float16" | "float32";
 export type ParakeetComputeType = "auto" | "float16" | "float32";
 
+export type CasingMode = "sentence" | "plain" | "lowercase";
+
+export type LongTranscriptBehavior = "split" | "truncate";
+
+export type ScrubPreset = "credit_card" | "ssn";
+
+export type ScrubAction = "mask" | "block";
+
+export interface ScrubbingSettings {
+  presets: ScrubPreset[];
+  custom_patterns: string[];
+  action: ScrubAction;
+}
+
+export interface AppRule {
+  app_name: string;
+  casing: CasingMode;
+}
+
+export type PreprocessStage = "high_pass" | "denoise" | "agc" | "normalize";
+
+export interface PreprocessStep {
+  stage: PreprocessStage;
+  enabled: boolean;
+}
+
+export interface PreprocessingChains {
+  fast: PreprocessStep[];
+  balanced: PreprocessStep[];
+}
+
+export interface MicrophoneProfile {
+  device_label: string;
+  mic_sensitivity_percent?: number | null;
+  input_channel?: number | null;
+  playback_guard?: "off" | "raise_vad" | "pause" | null;
+}
+
+export interface SpokenPhraseOverride {
+  language: string;
+  phrase: string;
+  replacement: string;
+}
+
+export interface UtteranceRouting {
+  enabled: boolean;
+  short_max_ms: number;
+  long_engine: SttEngine | null;
+  long_model_profile: "fast" | "balanced";
+}
+
+export interface DuplicateSuppression {
+  window_ms: number;
+  max_suppressed: number;
+}
+
+export interface HallucinationGuard {
+  enabled: boolean;
+  recent_finals: number;
+  max_speech_ratio_percent: number;
+}
+
+export interface LanguageWarning {
+  language: string;
+  engine: SttEngine;
+  model: string;
+  message: string;
+  suggested_models: string[];
+}
+
+export interface TranscriptViewer {
+  enabled: boolean;
+  port: number;
+}
+
+export interface ThreadPriorities {
+  boost_capture: boolean;
+  lower_inference: boolean;
+}
+
 export interface AppSettings {
   hotkey: string;
   mode: DictationMode;
@@ -25,10 +105,81 @@ export interface AppSettings {
   parakeet_compute_type: ParakeetComputeType;
   vad_disabled: boolean;
   vad_rms_threshold_milli: number | null;
+  chunk_normalization: boolean;
+  max_recent_insertions: number;
+  focus_guard_enabled: boolean;
+  focus_guard_timeout_ms: number;
+  privacy_mode: boolean;
+  drop_folder_path: string | null;
+  drop_folder_poll_secs: number;
+  app_rules: AppRule[];
+  meeting_transcript_enabled: boolean;
+  meeting_paragraph_gap_secs: number;
+  captions_enabled: boolean;
+  caption_line_chars: number;
+  predictive_preload_enabled: boolean;
+  idle_unload_minutes: number;
+  sidecar_memory_cap_mb: number;
+  sidecar_sandbox_enabled: boolean;
+  allow_path_sidecars: boolean;
+  offline_only: boolean;
+  event_prefix: string | null;
+  noise_suppression_enabled: boolean;
+  punctuation_restoration: boolean;
+  max_insertion_chars: number;
+  long_transcript_behavior: LongTranscriptBehavior;
+  scrubbing: ScrubbingSettings;
+  meter_attack_ms: number;
+  meter_release_ms: number;
+  meter_peak_hold_ms: number;
+  performance_trace: boolean;
+  auto_downgrade_profile: boolean;
+  spoken_punctuation: boolean;
+  faster_whisper_cache_dir: string | null;
+  insertion_verification: "off" | "canary" | "readback";
+  retry_unverified_insertion: boolean;
+  panic_hotkey: string;
+  auto_insert: boolean;
+  auto_insert_min_confidence_percent: number;
+  microphone_profiles: MicrophoneProfile[];
+  preprocessing: PreprocessingChains;
+  shadow_engine: SttEngine | null;
+  journal_directory: string | null;
+  journal_profile: "fast" | "balanced" | null;
+  obs_caption_file: string | null;
+  instant_words: boolean;
+  profile_toggle_hotkey: string;
+  number_locale: string | null;
+  voice_activation: boolean;
+  voice_activation_silence_ms: number;
+  utterance_audio: boolean;
+  adaptive_cadence: boolean;
+  retention_mode: "keep_all" | "keep_days" | "keep_entries" | "keep_nothing";
+  retention_days: number;
+  retention_entries: number;
+  screen_reader_announcements: boolean;
+  gpu_device_index: number | null;
+  playback_guard: "off" | "raise_vad" | "pause";
+  frame_recv_timeout_ms: number;
+  meter_refresh_hz: number;
+  spoken_phrase_overrides: SpokenPhraseOverride[];
+  utterance_routing: UtteranceRouting;
+  auto_quantize_models: boolean;
+  thread_priorities: ThreadPriorities;
+  review_toasts: boolean;
+  screen_recording_transcripts: boolean;
+  duplicate_suppression: DuplicateSuppression;
+  hallucination_guard: HallucinationGuard;
+  transcript_viewer: TranscriptViewer;
+  cold_start_fast_path: boolean;
   clipboard_fallback: boolean;
   launch_at_startup: boolean;
 }
 
+export interface SettingsUpdate extends AppSettings {
+  language_warning?: LanguageWarning;
+}
+
 export interface AppSettingsPatch {
   hotkey?: string;
   mode?: DictationMode;
@@ -47,15 +198,194 @@ export interface AppSettingsPatch {
   parakeet_compute_type?: ParakeetComputeType;
   vad_disabled?: boolean;
   vad_rms_threshold_milli?: number;
+  chunk_normalization?: boolean;
+  max_recent_insertions?: number;
+  focus_guard_enabled?: boolean;
+  focus_guard_timeout_ms?: number;
+  privacy_mode?: boolean;
+  drop_folder_path?: string | null;
+  drop_folder_poll_secs?: number;
+  app_rules?: AppRule[];
+  meeting_transcript_enabled?: boolean;
+  meeting_paragraph_gap_secs?: number;
+  captions_enabled?: boolean;
+  caption_line_chars?: number;
+  predictive_preload_enabled?: boolean;
+  idle_unload_minutes?: number;
+  sidecar_memory_cap_mb?: number;
+  sidecar_sandbox_enabled?: boolean;
+  allow_path_sidecars?: boolean;
+  offline_only?: boolean;
+  event_prefix?: string | null;
+  noise_suppression_enabled?: boolean;
+  punctuation_restoration?: boolean;
+  max_insertion_chars?: number;
+  long_transcript_behavior?: LongTranscriptBehavior;
+  scrubbing?: ScrubbingSettings;
+  meter_attack_ms?: number;
+  meter_release_ms?: number;
+  meter_peak_hold_ms?: number;
+  performance_trace?: boolean;
+  auto_downgrade_profile?: boolean;
+  spoken_punctuation?: boolean;
+  faster_whisper_cache_dir?: string | null;
+  insertion_verification?: "off" | "canary" | "readback";
+  retry_unverified_insertion?: boolean;
+  panic_hotkey?: string;
+  auto_insert?: boolean;
+  auto_insert_min_confidence_percent?: number;
+  microphone_profiles?: MicrophoneProfile[];
+  preprocessing?: PreprocessingChains;
+  shadow_engine?: SttEngine | null;
+  journal_directory?: string | null;
+  journal_profile?: "fast" | "balanced" | null;
+  obs_caption_file?: string | null;
+  instant_words?: boolean;
+  profile_toggle_hotkey?: string;
+  number_locale?: string | null;
+  voice_activation?: boolean;
+  voice_activation_silence_ms?: number;
+  utterance_audio?: boolean;
+  adaptive_cadence?: boolean;
+  retention_mode?: "keep_all" | "keep_days" | "keep_entries" | "keep_nothing";
+  retention_days?: number;
+  retention_entries?: number;
+  screen_reader_announcements?: boolean;
+  gpu_device_index?: number | null;
+  playback_guard?: "off" | "raise_vad" | "pause";
+  frame_recv_timeout_ms?: number;
+  meter_refresh_hz?: number;
+  spoken_phrase_overrides?: SpokenPhraseOverride[];
+  utterance_routing?: UtteranceRouting;
+  auto_quantize_models?: boolean;
+  thread_priorities?: ThreadPriorities;
+  review_toasts?: boolean;
+  screen_recording_transcripts?: boolean;
+  duplicate_suppression?: DuplicateSuppression;
+  hallucination_guard?: HallucinationGuard;
+  transcript_viewer?: TranscriptViewer;
+  cold_start_fast_path?: boolean;
   clipboard_fallback?: boolean;
   launch_at_startup?: boolean;
 }
 
-export type InsertionStatus = "success" | "fallback" | "failure";
+export type InsertionStatus =
+  | "success"
+  | "verified_success"
+  | "unverified"
+  | "fallback"
+  | "failure";
+
+export type InsertionStrategy = "auto" | "direct" | "clipboard";
 
 export interface InsertionRecord {
+  id: number;
   text: string;
   status: InsertionStatus;
+  strategy: InsertionStrategy;
+  retry_of?: number;
+  language?: string;
+  session_label?: string;
+  utterance_id?: number;
+  created_unix_ms: number;
+}
+
+export type UtteranceOutcome =
+  | "transcribed"
+  | "typed"
+  | "held_for_review"
+  | "inserting"
+  | "inserted"
+  | "insertion_failed";
+
+export interface UtteranceEngine {
+  engine: SttEngine;
+  model_profile: "fast" | "balanced";
+  route: "live" | "short" | "long" | "cold_start";
+  audio_ms: number;
+}
+
+export interface Utterance {
+  id: number;
+  text: string;
+  started_unix_ms: number;
+  ended_unix_ms: number;
+  language?: string;
+  confidence?: number;
+  session_label?: string;
+  outcome: UtteranceOutcome;
+  insertion_ids: number[];
+  insertion_status?: InsertionStatus;
+  audio_path?: string;
+  engine?: UtteranceEngine;
+  insertions: InsertionRecord[];
+}
+
+export interface InsertionLimitPayload {
+  schema_version?: number;
+  behavior: LongTranscriptBehavior;
+  max_chars: number;
+  original_chars: number;
+  inserted_chars: number;
+  segments: number;
+}
+
+export interface ScrubPayload {
+  schema_version?: number;
+  action: ScrubAction;
+  matched: string[];
+  blocked: boolean;
+}
+
+export interface SlowInference {
+  chunks: number;
+  avg_inference_ms: number;
+  avg_audio_ms: number;
+}
+
+export interface ProfileDowngradePayload {
+  schema_version?: number;
+  from: "fast" | "balanced";
+  to: "fast" | "balanced";
+  slow: SlowInference;
+  error: string | null;
+}
+
+export interface ProfileSwitchPayload {
+  schema_version?: number;
+  from: "fast" | "balanced";
+  to: "fast" | "balanced";
+  error: string | null;
+}
+
+export interface FocusTarget {
+  app_name: string;
+  process_id: number | null;
+}
+
+export interface FocusChangedPayload {
+  schema_version?: number;
+  app_name: string;
+  process_id: number | null;
+  window_title: string | null;
+  matched_rule: AppRule | null;
+}
+
+export interface ScreenRecordingPayload {
+  schema_version?: number;
+  state: "started" | "stopped";
+  recorder: string;
+  started_unix_ms: number;
+  transcript_path?: string;
+  error?: string;
+}
+
+export interface SystemResumePayload {
+  schema_version?: number;
+  slept_ms: number;
+  capture_restarted: boolean;
+  engine_warmed: boolean;
+  error: string | null;
 }
 
 export async function getPhase2Settings(): Promise<AppSettings> {
@@ -64,14 +394,239 @@ export async function getPhase2Settings(): Promise<AppSettings> {
 
 export async function updatePhase2Settings(
   patch: AppSettingsPatch,
+): Promise<SettingsUpdate> {
+  return invoke<SettingsUpdate>("phase2_update_settings", { patch });
+}
+
+export async function saveMicrophoneProfile(
+  microphoneId: string | null,
+  micSensitivityPercent?: number | null,
+  inputChannel?: number | null,
+  playbackGuard?: MicrophoneProfile["playback_guard"],
 ): Promise<AppSettings> {
-  return invoke<AppSettings>("phase2_update_settings", { patch });
+  return invoke<AppSettings>("phase2_save_microphone_profile", {
+    microphoneId,
+    micSensitivityPercent,
+    inputChannel,
+    playbackGuard,
+  });
 }
 
 export async function getPhase2RecentInsertions(): Promise<InsertionRecord[]> {
   return invoke<InsertionRecord[]>("phase2_get_recent_insertions");
 }
 
-export async function insertPhase2Text(text: string): Promise<InsertionRecord> {
-  return invoke<InsertionRecord>("phase2_insert_text", { text });
+export async function searchTranscripts(
+  query?: string,
+  sessionLabel?: string,
+  limit?: number,
+): Promise<InsertionRecord[]> {
+  return invoke<InsertionRecord[]>("phase2_search_transcripts", { query, sessionLabel, limit });
+}
+
+export async function getUtterances(
+  query?: string,
+  sessionLabel?: string,
+  limit?: number,
+): Promise<Utterance[]> {
+  return invoke<Utterance[]>("phase2_get_utterances", { query, sessionLabel, limit });
+}
+
+export async function getUtterance(utteranceId: number): Promise<Utterance> {
+  return invoke<Utterance>("phase2_get_utterance", { utteranceId });
+}
+
+export async function exportUtteranceAudio(utteranceId: number, path?: string): Promise<string> {
+  return invoke<string>("phase2_export_utterance_audio", { utteranceId, path });
+}
+
+export interface PurgeReport {
+  utterances: number;
+  insertions: number;
+  recordings: number;
+  log_lines: number;
+}
+
+export async function applyRetention(): Promise<PurgeReport> {
+  return invoke<PurgeReport>("phase2_apply_retention");
+}
+
+export async function purgeHistory(): Promise<PurgeReport> {
+  return invoke<PurgeReport>("phase2_purge_history");
+}
+
+export async function getSessionLabels(): Promise<string[]> {
+  return invoke<string[]>("phase2_get_session_labels");
+}
+
+export async function insertPhase2Text(
+  text: string,
+  strategy?: InsertionStrategy,
+): Promise<InsertionRecord> {
+  return invoke<InsertionRecord>("phase2_insert_text", { text, strategy });
+}
+
+export async function retryPhase2Insertion(
+  recordId: number,
+  strategy?: InsertionStrategy,
+): Promise<InsertionRecord> {
+  return invoke<InsertionRecord>("phase2_retry_insertion", {
+    record_id: recordId,
+    strategy,
+  });
+}
+
+export async function getPhase2InsertionTarget(): Promise<FocusTarget | null> {
+  return invoke<FocusTarget | null>("phase2_get_insertion_target");
+}
+
+export async function setPhase2InsertionTarget(
+  target: FocusTarget | null,
+): Promise<FocusTarget | null> {
+  return invoke<FocusTarget | null>("phase2_set_insertion_target", { target });
+}
+
+export async function copyTranscriptToClipboard(
+  recordId: number,
+): Promise<InsertionRecord> {
+  return invoke<InsertionRecord>("phase2_copy_transcript_to_clipboard", {
+    recordId,
+  });
+}
+
+export async function getFocusedApp(): Promise<FocusChangedPayload | null> {
+  return invoke<FocusChangedPayload | null>("phase2_get_focused_app");
+}
+
+export async function copyLastTranscript(): Promise<InsertionRecord> {
+  return invoke<InsertionRecord>("phase2_copy_last_transcript");
+}
+
+export type ClipboardManager = "copyq" | "ditto";
+
+export async function getClipboardManagers(): Promise<ClipboardManager[]> {
+  return invoke<ClipboardManager[]>("phase2_get_clipboard_managers");
+}
+
+export type InsertionProbeStrategy = "direct_typing" | "clipboard_paste" | "accessibility";
+
+export interface StrategyCapability {
+  strategy: InsertionProbeStrategy;
+  level: "ready" | "partial" | "unavailable";
+  backend?: string;
+  detail: string;
+}
+
+export interface InsertionCapabilities {
+  os: string;
+  strategies: StrategyCapability[];
+  recommended?: InsertionProbeStrategy;
+}
+
+export async function probeInsertionCapabilities(): Promise<InsertionCapabilities> {
+  return invoke<InsertionCapabilities>("phase2_probe_insertion_capabilities");
+}
+
+export interface LanguagePack {
+  code: string;
+  name: string;
+  whisper_fast_model: string | null;
+  whisper_balanced_model: string | null;
+  faster_whisper_model: string | null;
+  normalization: "latin" | "cjk";
+  spoken_punctuation: [string, string][];
+}
+
+export async function listLanguagePacks(): Promise<LanguagePack[]> {
+  return invoke<LanguagePack[]>("phase2_list_language_packs");
+}
+
+export interface SpokenPhrases {
+  language: string;
+  normalization: "latin" | "cjk";
+  phrases: { phrase: string; replacement: string; source: "pack" | "user" }[];
+}
+
+export async function getSpokenPhrases(language?: string): Promise<SpokenPhrases | null> {
+  return invoke<SpokenPhrases | null>("phase2_get_spoken_phrases", { language });
+}
+
+export async function applyLanguagePack(code: string): Promise<AppSettings> {
+  return invoke<AppSettings>("phase2_apply_language_pack", { code });
+}
+
+export interface ReviewItem {
+  id: number;
+  text: string;
+  confidence?: number;
+  language?: string;
+  utterance_id?: number;
+  held_unix_ms: number;
+}
+
+export type ReviewAction = "insert" | "copy" | "discard";
+
+export interface ReviewResolvedPayload {
+  item_id: number;
+  action: ReviewAction;
+}
+
+export async function listReviewQueue(): Promise<ReviewItem[]> {
+  return invoke<ReviewItem[]>("phase2_list_review_queue");
+}
+
+export async function approveReviewItem(
+  itemId: number,
+  strategy?: InsertionStrategy,
+): Promise<InsertionRecord> {
+  return invoke<InsertionRecord>("phase2_approve_review_item", { itemId, strategy });
+}
+
+export async function discardReviewItem(itemId: number): Promise<ReviewItem> {
+  return invoke<ReviewItem>("phase2_discard_review_item", { itemId });
+}
+
+export interface CorrectionPair {
+  original: string;
+  corrected: string;
+  record_id?: number;
+  submitted_unix_ms: number;
+}
+
+export interface ReplacementRule {
+  from: string;
+  to: string;
+  occurrences: number;
+}
+
+export interface CorrectionStore {
+  pairs: CorrectionPair[];
+  rules: ReplacementRule[];
+  vocabulary: string[];
+}
+
+export interface CorrectionOutcome {
+  edits: [string, string][];
+  new_rules: ReplacementRule[];
+  new_vocabulary: string[];
+}
+
+export async function submitCorrection(
+  corrected: string,
+  original?: string,
+  recordId?: number,
+): Promise<CorrectionOutcome> {
+  return invoke<CorrectionOutcome>("phase2_submit_correction", {
+    corrected,
+    original,
+    recordId,
+  });
+}
+
+export async function getCorrections(): Promise<CorrectionStore> {
+  return invoke<CorrectionStore>("phase2_get_corrections");
+}
+
+export async function removeCorrectionRule(from: string): Promise<ReplacementRule> {
+  return invoke<ReplacementRule>("phase2_remove_correction_rule", { from });
 }
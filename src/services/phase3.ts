@@ -15,6 +15,23 @@ export interface ProfileTuning {
   partial_cadence_ms: number;
 }
 
+export interface CachedModel {
+  repo_id: string;
+  size_bytes: number;
+  referenced: boolean;
+}
+
+export interface CacheUsage {
+  path: string;
+  total_bytes: number;
+  models: CachedModel[];
+}
+
+export interface PruneReport {
+  removed: string[];
+  freed_bytes: number;
+}
+
 export interface ModelStatus {
   profile: ModelProfile;
   hardware_tier: HardwareTier;
@@ -22,6 +39,7 @@ export interface ModelStatus {
   model_exists: boolean;
   checked_paths: string[];
   tuning: ProfileTuning;
+  faster_whisper_cache?: CacheUsage;
 }
 
 export async function getHardwareProfileStatus(): Promise<HardwareProfileStatus> {
@@ -32,10 +50,49 @@ export async function autoSelectHardwareProfile(): Promise<AppSettings> {
   return invoke<AppSettings>("phase3_auto_select_profile");
 }
 
+export async function toggleModelProfile(): Promise<"fast" | "balanced"> {
+  return invoke<"fast" | "balanced">("phase3_toggle_model_profile");
+}
+
 export async function getModelStatus(): Promise<ModelStatus> {
   return invoke<ModelStatus>("phase3_get_model_status");
 }
 
+export async function pruneModelCache(): Promise<PruneReport> {
+  return invoke<PruneReport>("phase3_prune_model_cache");
+}
+
+export interface ModelAlias {
+  alias: string;
+  relative_path: string;
+  english_only: boolean;
+}
+
+export async function listModelAliases(): Promise<ModelAlias[]> {
+  return invoke<ModelAlias[]>("phase3_list_model_aliases");
+}
+
+export type QuantizationLevel = "q5_0" | "q5_1" | "q8_0";
+
+export interface ModelConversionProgress {
+  source: string;
+  target: string;
+  level: QuantizationLevel;
+  stage: "running" | "completed" | "interrupted" | "failed";
+  percent: number;
+  error?: string;
+}
+
+export async function getModelConversion(): Promise<ModelConversionProgress | null> {
+  return invoke<ModelConversionProgress | null>("phase3_get_model_conversion");
+}
+
+export async function convertModel(
+  level?: QuantizationLevel,
+): Promise<ModelConversionProgress> {
+  return invoke<ModelConversionProgress>("phase3_convert_model", { level: level ?? null });
+}
+
 export async function setModelPath(path: string | null): Promise<AppSettings> {
   return invoke<AppSettings>("phase3_set_model_path", { path });
 }
@@ -1,12 +1,20 @@
 import { invoke } from "@tauri-apps/api/core";
+import type { SonoraError } from "./errors";
 
 export type SessionType = "x11" | "wayland" | "unknown";
 export type PermissionState = "ready" | "needs_setup" | "unknown";
 
+export interface KeyboardLayout {
+  name: string;
+  variant?: string;
+  us_qwerty: boolean;
+}
+
 export interface EnvironmentHealth {
   os: string;
   session_type: SessionType;
   input_injection_permission: PermissionState;
+  keyboard_layout: KeyboardLayout | null;
   notes: string[];
 }
 
@@ -18,6 +26,20 @@ export interface RecoveryCheckpoint {
   last_shutdown_unix_ms: number | null;
 }
 
+export type SidecarIntegrity =
+  | { status: "verified" }
+  | { status: "modified"; expected: string; actual: string }
+  | { status: "unrecorded" }
+  | { status: "path_resolved" }
+  | { status: "unreadable"; reason: string };
+
+export interface GpuDevice {
+  index: number;
+  name: string;
+  pci_bus_id: string;
+  memory_total_mb?: number;
+}
+
 export interface TranscriberStatus {
   ready: boolean;
   active_engine: string;
@@ -28,12 +50,137 @@ export interface TranscriberStatus {
   checked_binary_paths: string[];
   resolved_model_path: string;
   model_exists: boolean;
+  binary_integrity: SidecarIntegrity | null;
+  gpu_device: GpuDevice | null;
+  available_gpus: GpuDevice[];
+  sandbox: string;
+  error?: SonoraError;
+}
+
+export interface SessionStats {
+  started_unix_ms: number | null;
+  utterance_count: number;
+  word_count: number;
+  speech_ms: number;
+  pause_ms: number;
+  words_per_minute: number;
+  pause_ratio: number;
+  average_utterance_words: number;
+  latency: LatencyStats;
+  hallucinations_suppressed: number;
+}
+
+export interface ViewerStatus {
+  url: string;
+  port: number;
+  viewers: number;
+}
+
+export interface UtteranceLatency {
+  speech_end_unix_ms: number;
+  transcript_ms: number;
+  finalized_ms: number;
+  inserted_ms: number | null;
+}
+
+export interface LatencyStats {
+  utterances: number;
+  avg_transcript_ms: number;
+  avg_end_to_end_ms: number;
+  max_end_to_end_ms: number;
+  last: UtteranceLatency | null;
+}
+
+export interface CommandStats {
+  name: string;
+  invocations: number;
+  errors: number;
+  total_ms: number;
+  max_ms: number;
+  last_error_code: string | null;
+}
+
+export type AudioContainer = "wav" | "mp3" | "mp4" | "ogg" | "flac" | "webm" | "unknown";
+
+export interface FileTranscriptionPayload {
+  path: string;
+  container?: AudioContainer;
+  duration_ms: number;
+  transcript?: string;
+  error?: SonoraError;
+}
+
+export type JobStatus = "completed" | "failed";
+
+export interface JobRecord {
+  id: number;
+  source_path: string;
+  transcript_path?: string;
+  status: JobStatus;
+  error?: string;
+  audio_duration_ms: number;
+  started_unix_ms: number;
+  finished_unix_ms: number;
+}
+
+export interface OfflineStatus {
+  enabled: boolean;
+  downloads_disabled: boolean;
+  sidecar_network_blocked: boolean;
+  viewer_blocked: boolean;
+  sandbox_tool?: string;
+  notes: string[];
+}
+
+export interface EventDescriptor {
+  name: string;
+  description: string;
+  since_version: number;
+}
+
+export interface EventSchema {
+  version: number;
+  prefix?: string;
+  events: EventDescriptor[];
+}
+
+export interface SidecarMemory {
+  pid: number | null;
+  resident_bytes: number | null;
+  cap_mb: number;
+  cap_restarts: number;
+}
+
+export interface ReadinessCheck {
+  ok: boolean;
+  detail?: string;
+}
+
+export interface ReadinessReport {
+  ready: boolean;
+  settings_loaded: ReadinessCheck;
+  engine_ready: ReadinessCheck;
+  mic_accessible: ReadinessCheck;
+  hotkey_registered: ReadinessCheck;
+  insertion_capable: ReadinessCheck;
+}
+
+export async function healthCheck(): Promise<ReadinessReport> {
+  return invoke<ReadinessReport>("health_check");
 }
 
 export async function getEnvironmentHealth(): Promise<EnvironmentHealth> {
   return invoke<EnvironmentHealth>("phase4_get_environment_health");
 }
 
+export async function getOfflineStatus(): Promise<OfflineStatus> {
+  return invoke<OfflineStatus>("phase4_get_offline_status");
+}
+
+export async function getEventSchema(): Promise<EventSchema> {
+  return invoke<EventSchema>("phase4_get_event_schema");
+}
+
 export async function getRuntimeLogs(limit?: number): Promise<string[]> {
   return invoke<string[]>("phase4_get_runtime_logs", { limit });
 }
@@ -42,10 +189,124 @@ export async function clearRuntimeLogs(): Promise<void> {
   return invoke<void>("phase4_clear_runtime_logs");
 }
 
+export interface StartupPhase {
+  name: string;
+  started_ms: number;
+  duration_ms: number;
+}
+
+export interface StartupTimings {
+  phases: StartupPhase[];
+  engine_ready: boolean;
+}
+
+export interface StartupProgressPayload {
+  schema_version?: number;
+  phase: string;
+  finished: boolean;
+  duration_ms: number | null;
+  error: string | null;
+}
+
+// Payload of `app:degraded`.
+export interface DegradedPayload {
+  schema_version?: number;
+  state: string;
+  reset: boolean;
+  incidents: number;
+}
+
+export async function getStartupTimings(): Promise<StartupTimings> {
+  return invoke<StartupTimings>("phase4_get_startup_timings");
+}
+
+export async function getCommandMetrics(): Promise<CommandStats[]> {
+  return invoke<CommandStats[]>("phase4_get_command_metrics");
+}
+
+export async function getMeetingTranscriptPath(): Promise<string | null> {
+  return invoke<string | null>("phase4_get_meeting_transcript_path");
+}
+
+export async function transcribeFile(path: string): Promise<void> {
+  return invoke<void>("phase4_transcribe_file", { path });
+}
+
+export interface ShadowComparison {
+  chunk_id: number;
+  primary_engine: string;
+  primary_model: string;
+  primary_ms: number;
+  primary_text?: string | null;
+  shadow_engine: string;
+  shadow_model: string;
+  shadow_ms: number;
+  shadow_text?: string | null;
+  shadow_error?: string | null;
+  divergence?: number | null;
+}
+
+export async function getShadowComparisons(limit?: number): Promise<ShadowComparison[]> {
+  return invoke<ShadowComparison[]>("phase4_get_shadow_comparisons", { limit });
+}
+
+export interface BinaryDecision {
+  env_name: string;
+  candidates: string[];
+  resolved: string | null;
+}
+
+export interface ResolutionDecisions {
+  model_candidates: string[];
+  model_path: string;
+  binaries: BinaryDecision[];
+}
+
+export interface SupportSnapshotReplay {
+  decisions: ResolutionDecisions;
+  mismatches: string[];
+}
+
+export async function exportSupportSnapshot(path?: string): Promise<string> {
+  return invoke<string>("phase4_export_support_snapshot", { path });
+}
+
+export async function replaySupportSnapshot(path: string): Promise<SupportSnapshotReplay> {
+  return invoke<SupportSnapshotReplay>("phase4_replay_support_snapshot", { path });
+}
+
+export async function getSidecarMemory(): Promise<SidecarMemory> {
+  return invoke<SidecarMemory>("phase4_get_sidecar_memory");
+}
+
+export async function getTranscriptionJobs(): Promise<JobRecord[]> {
+  return invoke<JobRecord[]>("phase4_get_transcription_jobs");
+}
+
+export async function clearTranscriptionJobs(): Promise<void> {
+  return invoke<void>("phase4_clear_transcription_jobs");
+}
+
 export async function getTranscriberStatus(): Promise<TranscriberStatus> {
   return invoke<TranscriberStatus>("phase4_get_transcriber_status");
 }
 
+export async function getSessionStats(): Promise<SessionStats> {
+  return invoke<SessionStats>("phase4_get_session_stats");
+}
+
+export async function getTranscriptViewer(): Promise<ViewerStatus | null> {
+  return invoke<ViewerStatus | null>("phase4_get_transcript_viewer");
+}
+
+export async function startLiveCaptions(translate: boolean): Promise<boolean> {
+  return invoke<boolean>("phase4_start_live_captions", { translate });
+}
+
+export async function stopLiveCaptions(): Promise<boolean> {
+  return invoke<boolean>("phase4_stop_live_captions");
+}
+
 export async function markPerfTranscriptReceived(
   chunkId: number,
   emittedUnixMs: number,